@@ -0,0 +1,55 @@
+//! [Flow](https://flow.org/en/docs/types/) syntax without a TypeScript equivalent
+//!
+//! Flow annotations are parsed into the TypeScript nodes where the syntax matches,
+//! e.g. `type A = B` is a `TSTypeAliasDeclaration` and `?T` is a `JSDocNullableType`.
+
+use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ContentHash};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, Span};
+
+/// `opaque type ID: Super = string;`
+/// `declare opaque type ID: Super;`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowOpaqueTypeDeclaration<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub id: BindingIdentifier,
+    pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    /// The type visible outside of the file, `Super` above
+    pub supertype: Option<TSType<'a>>,
+    /// The underlying type, `None` for `declare opaque type`
+    pub impltype: Option<TSType<'a>>,
+    /// Valid Modifiers: `declare`, `export`
+    pub modifiers: Modifiers<'a>,
+}
+
+/// `declare module.exports: T;`, the type of the `CommonJS` exports of a declared module
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowDeclareModuleExports<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+}
+
+/// `{| a: string, b: number |}`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowExactObjectType<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub members: Vec<'a, TSSignature<'a>>,
+}
+
+/// `*`, a type inferred by Flow
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct FlowExistentialType {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+}
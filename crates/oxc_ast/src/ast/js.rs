@@ -2,13 +2,14 @@ use std::fmt::Display;
 
 use num_bigint::BigUint;
 use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ContentHash};
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
 #[allow(clippy::wildcard_imports)]
 use crate::{ast::*, Atom, SourceType, Span};
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 pub struct Program<'a> {
     pub span: Span,
     pub directives: Vec<'a, Directive<'a>>,
@@ -30,14 +31,15 @@ impl<'a> Program<'a> {
 }
 
 /// Section 13 Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
+#[content(skip_parentheses)]
 pub enum Expression<'a> {
     BooleanLiteral(Box<'a, BooleanLiteral>),
     NullLiteral(Box<'a, NullLiteral>),
     NumberLiteral(Box<'a, NumberLiteral<'a>>),
     BigintLiteral(Box<'a, BigintLiteral>),
-    RegExpLiteral(Box<'a, RegExpLiteral>),
+    RegExpLiteral(Box<'a, RegExpLiteral<'a>>),
     StringLiteral(Box<'a, StringLiteral>),
     TemplateLiteral(Box<'a, TemplateLiteral<'a>>),
 
@@ -78,6 +80,9 @@ pub enum Expression<'a> {
     TSTypeAssertion(Box<'a, TSTypeAssertion<'a>>),
     TSNonNullExpression(Box<'a, TSNonNullExpression<'a>>),
     TSInstantiationExpression(Box<'a, TSInstantiationExpression<'a>>),
+
+    /// Placeholder for an expression with a syntax error, see `Parser::recover_from_errors`
+    InvalidExpression(Box<'a, InvalidExpression>),
 }
 
 impl<'a> Expression<'a> {
@@ -228,7 +233,7 @@ impl<'a> Expression<'a> {
 }
 
 /// Section 12.6 `IdentifierName`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IdentifierName {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -237,7 +242,7 @@ pub struct IdentifierName {
 }
 
 /// Section 13.1 `IdentifierReference`
-#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[derive(Debug, Clone, PartialEq, Hash, Eq, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IdentifierReference {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -246,7 +251,7 @@ pub struct IdentifierReference {
 }
 
 /// Section 13.1 `BindingIdentifier`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BindingIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -255,7 +260,7 @@ pub struct BindingIdentifier {
 }
 
 /// Section 13.1 `LabelIdentifier`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LabelIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -264,7 +269,7 @@ pub struct LabelIdentifier {
 }
 
 /// Section 13.2.2 This Expression
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ThisExpression {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -272,33 +277,35 @@ pub struct ThisExpression {
 }
 
 /// Section 13.2.5 Array Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub elements: Vec<'a, Option<Argument<'a>>>,
+    #[content(skip)]
     pub trailing_comma: Option<Span>,
 }
 
 /// Section 13.2.6 Object Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub properties: Vec<'a, ObjectProperty<'a>>,
+    #[content(skip)]
     pub trailing_comma: Option<Span>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ObjectProperty<'a> {
     Property(Box<'a, Property<'a>>),
     SpreadProperty(Box<'a, SpreadElement<'a>>),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct Property<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -311,7 +318,7 @@ pub struct Property<'a> {
     pub computed: bool,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum PropertyKey<'a> {
     Identifier(Box<'a, IdentifierName>),
@@ -345,7 +352,7 @@ impl<'a> PropertyKey<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum PropertyValue<'a> {
     // For AssignmentProperty in ObjectPattern <https://github.com/estree/estree/blob/master/es2015.md#objectpattern>
@@ -353,7 +360,7 @@ pub enum PropertyValue<'a> {
     Expression(Expression<'a>),
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum PropertyKind {
     Init,
@@ -362,7 +369,7 @@ pub enum PropertyKind {
 }
 
 /// Section 13.2.9 Template Literal
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TemplateLiteral<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -384,7 +391,7 @@ impl<'a> TemplateLiteral<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TaggedTemplateExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -394,7 +401,7 @@ pub struct TaggedTemplateExpression<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TemplateElement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -403,7 +410,7 @@ pub struct TemplateElement {
     pub value: TemplateElementValue,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TemplateElementValue {
     pub raw: Atom,
@@ -411,7 +418,7 @@ pub struct TemplateElementValue {
 }
 
 /// Section 13.3 Member Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 pub enum MemberExpression<'a> {
     ComputedMemberExpression(ComputedMemberExpression<'a>),
     StaticMemberExpression(StaticMemberExpression<'a>),
@@ -464,7 +471,7 @@ impl<'a> MemberExpression<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 pub struct ComputedMemberExpression<'a> {
     pub span: Span,
     pub object: Expression<'a>,
@@ -472,7 +479,7 @@ pub struct ComputedMemberExpression<'a> {
     pub optional: bool, // for optional chaining
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 pub struct StaticMemberExpression<'a> {
     pub span: Span,
     pub object: Expression<'a>,
@@ -480,7 +487,7 @@ pub struct StaticMemberExpression<'a> {
     pub optional: bool, // for optional chaining
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 pub struct PrivateFieldExpression<'a> {
     pub span: Span,
     pub object: Expression<'a>,
@@ -489,7 +496,7 @@ pub struct PrivateFieldExpression<'a> {
 }
 
 /// Section 13.3 Call Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct CallExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -546,7 +553,7 @@ impl<'a> CallExpression<'a> {
 }
 
 /// Section 13.3 New Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct NewExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -558,7 +565,7 @@ pub struct NewExpression<'a> {
 
 /// Section 13.3 Meta Property
 /// `new.target` | `import.meta`
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct MetaProperty {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -568,7 +575,7 @@ pub struct MetaProperty {
 }
 
 /// Section 13.3 Spread Element
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SpreadElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -577,7 +584,7 @@ pub struct SpreadElement<'a> {
 }
 
 /// Section 13.3 Argument
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Argument<'a> {
     SpreadElement(Box<'a, SpreadElement<'a>>),
@@ -585,7 +592,7 @@ pub enum Argument<'a> {
 }
 
 /// Section 13.4 Update Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct UpdateExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -596,7 +603,7 @@ pub struct UpdateExpression<'a> {
 }
 
 /// Section 13.5 Unary Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct UnaryExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -607,7 +614,7 @@ pub struct UnaryExpression<'a> {
 }
 
 /// Section 13.6 - 13.13 Binary Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BinaryExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -619,7 +626,7 @@ pub struct BinaryExpression<'a> {
 
 /// `RelationalExpression`[In, Yield, Await] :
 ///     [+In] `PrivateIdentifier` in `ShiftExpression`[?Yield, ?Await]
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct PrivateInExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -630,7 +637,7 @@ pub struct PrivateInExpression<'a> {
 }
 
 /// Section 13.13 Binary Logical Operators
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LogicalExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -641,7 +648,7 @@ pub struct LogicalExpression<'a> {
 }
 
 /// Section 13.14 Conditional Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ConditionalExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -652,7 +659,7 @@ pub struct ConditionalExpression<'a> {
 }
 
 /// Section 13.15 Assignment Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -663,7 +670,7 @@ pub struct AssignmentExpression<'a> {
 }
 
 /// 13.15.5 Destructuring Assignment
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTarget<'a> {
     SimpleAssignmentTarget(SimpleAssignmentTarget<'a>),
@@ -677,7 +684,7 @@ impl<'a> AssignmentTarget<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum SimpleAssignmentTarget<'a> {
     AssignmentTargetIdentifier(Box<'a, IdentifierReference>),
@@ -701,24 +708,25 @@ impl<'a> SimpleAssignmentTarget<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetPattern<'a> {
     ArrayAssignmentTarget(Box<'a, ArrayAssignmentTarget<'a>>),
     ObjectAssignmentTarget(Box<'a, ObjectAssignmentTarget<'a>>),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayAssignmentTarget<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub elements: Vec<'a, Option<AssignmentTargetMaybeDefault<'a>>>,
     pub rest: Option<AssignmentTarget<'a>>,
+    #[content(skip)]
     pub trailing_comma: Option<Span>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectAssignmentTarget<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -727,7 +735,7 @@ pub struct ObjectAssignmentTarget<'a> {
     pub rest: Option<AssignmentTarget<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetMaybeDefault<'a> {
     AssignmentTarget(Box<'a, AssignmentTarget<'a>>),
@@ -753,7 +761,7 @@ impl<'a> AssignmentTargetMaybeDefault<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetWithDefault<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -762,7 +770,7 @@ pub struct AssignmentTargetWithDefault<'a> {
     pub init: Expression<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetProperty<'a> {
     AssignmentTargetPropertyIdentifier(Box<'a, AssignmentTargetPropertyIdentifier<'a>>),
@@ -771,7 +779,7 @@ pub enum AssignmentTargetProperty<'a> {
 
 /// `AssignmentProperty`[Yield, Await] :
 ///     `IdentifierReference`[?Yield, ?Await] Initializer[+In, ?Yield, ?Await]opt
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetPropertyIdentifier<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -782,7 +790,7 @@ pub struct AssignmentTargetPropertyIdentifier<'a> {
 
 /// `AssignmentProperty`[Yield, Await] :
 ///     `PropertyName`[?Yield, ?Await] : `AssignmentElement`[?Yield, ?Await]
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetPropertyProperty<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -792,7 +800,7 @@ pub struct AssignmentTargetPropertyProperty<'a> {
 }
 
 /// Section 13.16 Sequence Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SequenceExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -800,7 +808,7 @@ pub struct SequenceExpression<'a> {
     pub expressions: Vec<'a, Expression<'a>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct Super {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -808,7 +816,7 @@ pub struct Super {
 }
 
 /// Section 15.8 Await Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AwaitExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -816,7 +824,7 @@ pub struct AwaitExpression<'a> {
     pub argument: Expression<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ChainExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -824,7 +832,7 @@ pub struct ChainExpression<'a> {
     pub expression: ChainElement<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ChainElement<'a> {
     CallExpression(Box<'a, CallExpression<'a>>),
@@ -832,7 +840,7 @@ pub enum ChainElement<'a> {
 }
 
 // Section 13.2 ParenthesizedExpression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ParenthesizedExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -841,7 +849,7 @@ pub struct ParenthesizedExpression<'a> {
 }
 
 /// Section 14 Statements
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Statement<'a> {
     // Statements
@@ -866,10 +874,13 @@ pub enum Statement<'a> {
 
     ModuleDeclaration(Box<'a, ModuleDeclaration<'a>>),
     Declaration(Declaration<'a>),
+
+    /// Placeholder for a statement with a syntax error, see `Parser::recover_from_errors`
+    InvalidStatement(Box<'a, InvalidStatement>),
 }
 
 /// Section 11.2.1 Directive Prologue
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize),
@@ -884,7 +895,7 @@ pub struct Directive<'a> {
 }
 
 /// Section 14.2 Block Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BlockStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -893,7 +904,7 @@ pub struct BlockStatement<'a> {
 }
 
 /// Section 14.3 Declarations and the Variable Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Declaration<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -905,6 +916,9 @@ pub enum Declaration<'a> {
     TSEnumDeclaration(Box<'a, TSEnumDeclaration<'a>>),
     TSModuleDeclaration(Box<'a, TSModuleDeclaration<'a>>),
     TSImportEqualsDeclaration(Box<'a, TSImportEqualsDeclaration<'a>>),
+
+    FlowOpaqueTypeDeclaration(Box<'a, FlowOpaqueTypeDeclaration<'a>>),
+    FlowDeclareModuleExports(Box<'a, FlowDeclareModuleExports<'a>>),
 }
 
 impl<'a> Declaration<'a> {
@@ -920,7 +934,7 @@ impl<'a> Declaration<'a> {
 }
 
 /// Section 14.3.2 Variable Declaration
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct VariableDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -931,7 +945,7 @@ pub struct VariableDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum VariableDeclarationKind {
     Var,
@@ -962,7 +976,7 @@ impl Display for VariableDeclarationKind {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct VariableDeclarator<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -975,7 +989,7 @@ pub struct VariableDeclarator<'a> {
 }
 
 /// Section 14.4 Empty Statement
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct EmptyStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -983,7 +997,7 @@ pub struct EmptyStatement {
 }
 
 /// Section 14.5 Expression Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExpressionStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -992,7 +1006,7 @@ pub struct ExpressionStatement<'a> {
 }
 
 /// Section 14.6 If Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IfStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1003,7 +1017,7 @@ pub struct IfStatement<'a> {
 }
 
 /// Section 14.7.2 Do-While Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct DoWhileStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1013,7 +1027,7 @@ pub struct DoWhileStatement<'a> {
 }
 
 /// Section 14.7.3 While Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct WhileStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1023,7 +1037,7 @@ pub struct WhileStatement<'a> {
 }
 
 /// Section 14.7.4 For Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1034,7 +1048,7 @@ pub struct ForStatement<'a> {
     pub body: Statement<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ForStatementInit<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -1042,7 +1056,7 @@ pub enum ForStatementInit<'a> {
 }
 
 /// Section 14.7.5 For-In Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForInStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1053,7 +1067,7 @@ pub struct ForInStatement<'a> {
 }
 
 /// Section 14.7.5 For-Of Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForOfStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1064,7 +1078,7 @@ pub struct ForOfStatement<'a> {
     pub body: Statement<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ForStatementLeft<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -1072,7 +1086,7 @@ pub enum ForStatementLeft<'a> {
 }
 
 /// Section 14.8 Continue Statement
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ContinueStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1081,7 +1095,7 @@ pub struct ContinueStatement {
 }
 
 /// Section 14.9 Break Statement
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BreakStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1090,7 +1104,7 @@ pub struct BreakStatement {
 }
 
 /// Section 14.10 Return Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ReturnStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1099,7 +1113,7 @@ pub struct ReturnStatement<'a> {
 }
 
 /// Section 14.11 With Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct WithStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1109,7 +1123,7 @@ pub struct WithStatement<'a> {
 }
 
 /// Section 14.12 Switch Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SwitchStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1118,7 +1132,7 @@ pub struct SwitchStatement<'a> {
     pub cases: Vec<'a, SwitchCase<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SwitchCase<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1135,7 +1149,7 @@ impl<'a> SwitchCase<'a> {
 }
 
 /// Section 14.13 Labelled Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LabeledStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1145,7 +1159,7 @@ pub struct LabeledStatement<'a> {
 }
 
 /// Section 14.14 Throw Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ThrowStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1154,7 +1168,7 @@ pub struct ThrowStatement<'a> {
 }
 
 /// Section 14.15 Try Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TryStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1164,7 +1178,7 @@ pub struct TryStatement<'a> {
     pub finalizer: Option<Box<'a, BlockStatement<'a>>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct CatchClause<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1174,15 +1188,31 @@ pub struct CatchClause<'a> {
 }
 
 /// Section 14.16 Debugger Statement
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct DebuggerStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
 
+/// The skipped source text of a statement with a syntax error
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct InvalidStatement {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+}
+
+/// The skipped source text of an expression with a syntax error
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct InvalidExpression {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+}
+
 /// Section 14.3.3 Destructuring Binding Patterns
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub struct BindingPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1191,7 +1221,7 @@ pub struct BindingPattern<'a> {
     pub optional: bool,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum BindingPatternKind<'a> {
     BindingIdentifier(Box<'a, BindingIdentifier>),
@@ -1213,7 +1243,7 @@ impl<'a> BindingPatternKind<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1222,7 +1252,7 @@ pub struct AssignmentPattern<'a> {
     pub right: Expression<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1230,14 +1260,14 @@ pub struct ObjectPattern<'a> {
     pub properties: Vec<'a, ObjectPatternProperty<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ObjectPatternProperty<'a> {
     Property(Box<'a, Property<'a>>),
     RestElement(Box<'a, RestElement<'a>>),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1245,7 +1275,7 @@ pub struct ArrayPattern<'a> {
     pub elements: Vec<'a, Option<BindingPattern<'a>>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct RestElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1254,7 +1284,7 @@ pub struct RestElement<'a> {
 }
 
 /// Section 15.2 Function Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Function<'a> {
@@ -1300,7 +1330,7 @@ impl<'a> Function<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum FunctionType {
     FunctionDeclaration,
@@ -1308,14 +1338,14 @@ pub enum FunctionType {
     TSDeclareFunction,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 pub struct FormalParameters<'a> {
     pub span: Span,
     pub kind: FormalParameterKind,
     pub items: Vec<'a, FormalParameter<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct FormalParameter<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1327,7 +1357,7 @@ pub struct FormalParameter<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 pub enum FormalParameterKind {
     /// <https://tc39.es/ecma262/#prod-FormalParameters>
     FormalParameter,
@@ -1346,7 +1376,7 @@ impl<'a> FormalParameters<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 pub struct FunctionBody<'a> {
     pub span: Span,
     pub directives: Vec<'a, Directive<'a>>,
@@ -1361,7 +1391,7 @@ impl<'a> FunctionBody<'a> {
 }
 
 /// Section 15.3 Arrow Function Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 pub struct ArrowExpression<'a> {
     pub span: Span,
     pub expression: bool,
@@ -1384,7 +1414,7 @@ impl<'a> ArrowExpression<'a> {
 }
 
 /// Section 15.5 Generator Function Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct YieldExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1394,7 +1424,7 @@ pub struct YieldExpression<'a> {
 }
 
 /// Section 15.7 Class Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub struct Class<'a> {
     pub r#type: ClassType,
@@ -1428,14 +1458,14 @@ impl<'a> Class<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum ClassType {
     ClassDeclaration,
     ClassExpression,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ClassBody<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1443,7 +1473,7 @@ pub struct ClassBody<'a> {
     pub body: Vec<'a, ClassElement<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ClassElement<'a> {
     StaticBlock(Box<'a, StaticBlock<'a>>),
@@ -1531,7 +1561,7 @@ impl<'a> ClassElement<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct MethodDefinition<'a> {
@@ -1548,7 +1578,7 @@ pub struct MethodDefinition<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PropertyDefinition<'a> {
@@ -1568,7 +1598,7 @@ pub struct PropertyDefinition<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum MethodDefinitionKind {
     Constructor,
@@ -1577,7 +1607,7 @@ pub enum MethodDefinitionKind {
     Set,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct PrivateIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1585,7 +1615,7 @@ pub struct PrivateIdentifier {
     pub name: Atom,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct StaticBlock<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1594,7 +1624,7 @@ pub struct StaticBlock<'a> {
 }
 
 /// Section 16.2.2 Imports
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ModuleDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1603,7 +1633,7 @@ pub struct ModuleDeclaration<'a> {
     pub kind: ModuleDeclarationKind<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ModuleDeclarationKind<'a> {
     ImportDeclaration(Box<'a, ImportDeclaration<'a>>),
@@ -1629,7 +1659,7 @@ impl<'a> ModuleDeclarationKind<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AccessorProperty<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1640,7 +1670,7 @@ pub struct AccessorProperty<'a> {
     pub r#static: bool,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1649,7 +1679,7 @@ pub struct ImportExpression<'a> {
     pub arguments: Vec<'a, Expression<'a>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportDeclaration<'a> {
     pub specifiers: Vec<'a, ImportDeclarationSpecifier>,
@@ -1658,7 +1688,7 @@ pub struct ImportDeclaration<'a> {
     pub import_kind: Option<ImportOrExportKind>,      // `import type { foo } from 'bar'`
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ImportDeclarationSpecifier {
     ImportSpecifier(ImportSpecifier),
@@ -1668,7 +1698,7 @@ pub enum ImportDeclarationSpecifier {
 
 // import {imported} from "source"
 // import {imported as local} from "source"
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1678,7 +1708,7 @@ pub struct ImportSpecifier {
 }
 
 // import local from "source"
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportDefaultSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1687,7 +1717,7 @@ pub struct ImportDefaultSpecifier {
 }
 
 // import * as local from "source"
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportNamespaceSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1695,7 +1725,7 @@ pub struct ImportNamespaceSpecifier {
     pub local: BindingIdentifier,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportAttribute {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1704,7 +1734,7 @@ pub struct ImportAttribute {
     pub value: StringLiteral,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ImportAttributeKey {
     Identifier(IdentifierName),
@@ -1723,7 +1753,7 @@ impl ImportAttributeKey {
 
 /// Exports
 /// [tc39/ecma262#sec-exports](https://tc39.es/ecma262/#sec-exports)
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportNamedDeclaration<'a> {
     pub declaration: Option<Declaration<'a>>,
@@ -1740,14 +1770,14 @@ impl<'a> ExportNamedDeclaration<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportDefaultDeclaration<'a> {
     pub declaration: ExportDefaultDeclarationKind<'a>,
     pub exported: ModuleExportName, // `default`
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportAllDeclaration<'a> {
     pub exported: Option<ModuleExportName>,
@@ -1756,7 +1786,7 @@ pub struct ExportAllDeclaration<'a> {
     pub export_kind: Option<ImportOrExportKind>,      // `export type *`
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1765,7 +1795,7 @@ pub struct ExportSpecifier {
     pub exported: ModuleExportName,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ExportDefaultDeclarationKind<'a> {
     Expression(Expression<'a>),
@@ -1798,7 +1828,7 @@ impl<'a> ExportDefaultDeclarationKind<'a> {
 // support:
 //   import {"\0 any unicode" as foo} from "";
 //   export {foo as "\0 any unicode"};
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ModuleExportName {
     Identifier(IdentifierName),
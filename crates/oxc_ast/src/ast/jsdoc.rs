@@ -1,11 +1,17 @@
 //! [`JSDoc`](https://github.com/microsoft/TypeScript/blob/54a554d8af2657630307cbfa8a3e4f3946e36507/src/compiler/types.ts#L393)
+//! and [JSDoc tags](https://jsdoc.app/#block-tags)
 
+use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ContentHash};
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-use crate::{ast::TSType, Span};
+use crate::{
+    ast::{IdentifierName, TSType},
+    Atom, GetSpan, Span,
+};
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSDocNullableType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -14,9 +20,137 @@ pub struct JSDocNullableType<'a> {
     pub postfix: bool,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSDocUnknownType {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
 }
+
+/// A parsed `/** ... */` comment
+///
+/// Produced by `oxc_parser::JSDocParser`, it is not part of the `Program`.
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct JSDoc<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// The text before the first tag, with the leading `*` of each line removed
+    pub description: Atom,
+    pub tags: Vec<'a, JSDocTag<'a>>,
+}
+
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
+pub enum JSDocTag<'a> {
+    Param(Box<'a, JSDocParameterTag<'a>>),
+    Returns(Box<'a, JSDocReturnsTag<'a>>),
+    Deprecated(Box<'a, JSDocDeprecatedTag>),
+    Type(Box<'a, JSDocTypeTag<'a>>),
+    Template(Box<'a, JSDocTemplateTag<'a>>),
+    Unknown(Box<'a, JSDocUnknownTag>),
+}
+
+impl<'a> JSDocTag<'a> {
+    #[must_use]
+    pub fn tag_name(&self) -> &IdentifierName {
+        match self {
+            Self::Param(tag) => &tag.tag_name,
+            Self::Returns(tag) => &tag.tag_name,
+            Self::Deprecated(tag) => &tag.tag_name,
+            Self::Type(tag) => &tag.tag_name,
+            Self::Template(tag) => &tag.tag_name,
+            Self::Unknown(tag) => &tag.tag_name,
+        }
+    }
+}
+
+impl<'a> GetSpan for JSDocTag<'a> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Param(tag) => tag.span,
+            Self::Returns(tag) => tag.span,
+            Self::Deprecated(tag) => tag.span,
+            Self::Type(tag) => tag.span,
+            Self::Template(tag) => tag.span,
+            Self::Unknown(tag) => tag.span,
+        }
+    }
+}
+
+/// `{T}`, the span includes the braces
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct JSDocTypeExpression<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub type_annotation: TSType<'a>,
+}
+
+/// `@param {T} name description`, `@param {T} [name=default]`, also `@arg` and `@argument`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct JSDocParameterTag<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub tag_name: IdentifierName,
+    pub type_expression: Option<JSDocTypeExpression<'a>>,
+    /// May be a property path such as `options.name`
+    pub name: IdentifierName,
+    /// Written in brackets
+    pub optional: bool,
+    pub description: Atom,
+}
+
+/// `@returns {T} description`, also `@return`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct JSDocReturnsTag<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub tag_name: IdentifierName,
+    pub type_expression: Option<JSDocTypeExpression<'a>>,
+    pub description: Atom,
+}
+
+/// `@deprecated description`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct JSDocDeprecatedTag {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub tag_name: IdentifierName,
+    pub description: Atom,
+}
+
+/// `@type {T}`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct JSDocTypeTag<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub tag_name: IdentifierName,
+    pub type_expression: Option<JSDocTypeExpression<'a>>,
+}
+
+/// `@template {Constraint} T, U description`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct JSDocTemplateTag<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub tag_name: IdentifierName,
+    pub constraint: Option<JSDocTypeExpression<'a>>,
+    pub type_parameters: Vec<'a, IdentifierName>,
+    pub description: Atom,
+}
+
+/// Any other tag, such as `@example` or `@see`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
+pub struct JSDocUnknownTag {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub tag_name: IdentifierName,
+    pub description: Atom,
+}
@@ -8,12 +8,14 @@ use std::{
 use bitflags::bitflags;
 use num_bigint::BigUint;
 use ordered_float::NotNan;
+use oxc_allocator::Box;
+use oxc_macros::{CloneIn, ContentEq, ContentHash};
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-use crate::{Atom, Span};
+use crate::{ast::regexp, Atom, Span};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct BooleanLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -28,7 +30,7 @@ impl BooleanLiteral {
     }
 }
 
-#[derive(Debug, Clone, Eq)]
+#[derive(Debug, Clone, Eq, CloneIn, ContentEq, ContentHash)]
 pub struct NullLiteral {
     pub span: Span,
 }
@@ -45,15 +47,17 @@ impl PartialEq for NullLiteral {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct NumberLiteral<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub value: NotNan<f64>, // using NotNan for `Hash`
     #[cfg_attr(feature = "serde", serde(skip))]
+    #[content(skip)]
     pub raw: &'a str,
     #[cfg_attr(feature = "serde", serde(skip))]
+    #[content(skip)]
     pub base: NumberBase,
 }
 
@@ -72,7 +76,7 @@ impl<'a> Hash for NumberLiteral<'a> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct BigintLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -81,18 +85,23 @@ pub struct BigintLiteral {
     pub value: BigUint,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
-pub struct RegExpLiteral {
+pub struct RegExpLiteral<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     // valid regex is printed as {}
     // invalid regex is printed as null, which we can't implement yet
     pub value: EmptyObject,
     pub regex: RegExp,
+    /// The parsed `regex.pattern`, `None` when the pattern has a syntax error
+    /// or the AST is read from JSON with `Program::from_estree_json`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[content(skip)]
+    pub pattern: Option<Box<'a, regexp::Pattern<'a>>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct RegExp {
     pub pattern: Atom,
@@ -149,11 +158,11 @@ impl fmt::Display for RegExpFlags {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct EmptyObject;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct StringLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -181,7 +190,7 @@ impl StringLiteral {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 pub enum NumberBase {
     Decimal,
     Binary,
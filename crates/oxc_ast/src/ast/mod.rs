@@ -1,12 +1,15 @@
 //! AST Definitions
 
+mod flow;
 mod js;
 mod jsdoc;
 mod jsx;
 mod literal;
 mod operator;
+pub mod regexp;
 mod ts;
 
+pub use self::flow::*;
 pub use self::js::*;
 pub use self::jsdoc::*;
 pub use self::jsx::*;
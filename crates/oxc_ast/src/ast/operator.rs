@@ -1,9 +1,10 @@
 use std::fmt::{Display, Formatter, Result};
 
+use oxc_macros::{CloneIn, ContentEq, ContentHash};
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Operator {
     AssignmentOperator(AssignmentOperator),
@@ -43,8 +44,8 @@ impl From<UpdateOperator> for Operator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AssignmentOperator {
     #[cfg_attr(feature = "serde", serde(rename = "="))]
     Assign,
@@ -142,8 +143,8 @@ impl Display for AssignmentOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BinaryOperator {
     #[cfg_attr(feature = "serde", serde(rename = "=="))]
     Equality,
@@ -285,8 +286,8 @@ impl Display for BinaryOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LogicalOperator {
     #[cfg_attr(feature = "serde", serde(rename = "||"))]
     Or,
@@ -314,8 +315,8 @@ impl Display for LogicalOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UnaryOperator {
     #[cfg_attr(feature = "serde", serde(rename = "-"))]
     UnaryNegation,
@@ -375,8 +376,8 @@ impl Display for UnaryOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UpdateOperator {
     #[cfg_attr(feature = "serde", serde(rename = "++"))]
     Increment,
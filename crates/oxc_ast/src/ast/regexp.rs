@@ -0,0 +1,309 @@
+//! Regular Expression Pattern
+//!
+//! See [ECMAScript 22.2.1 Patterns](https://tc39.es/ecma262/#sec-patterns)
+//! and [Annex B.1.2 Regular Expressions Patterns](https://tc39.es/ecma262/#sec-regular-expressions-patterns).
+//!
+//! These nodes are not re-exported from `oxc_ast::ast` because names such as `Atom` and
+//! `Character` would clash with the JavaScript AST, use them via `oxc_ast::ast::regexp::*`.
+
+use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ContentHash};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{Atom, Span};
+
+/// The root of a parsed `RegExpLiteral::regex.pattern`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct Pattern<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub body: Disjunction<'a>,
+}
+
+/// `Alternative` | `Alternative` | ...
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct Disjunction<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub body: Vec<'a, Alternative<'a>>,
+}
+
+/// A sequence of `Term`s
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct Alternative<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub body: Vec<'a, Term<'a>>,
+}
+
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
+pub enum Term<'a> {
+    BoundaryAssertion(Box<'a, BoundaryAssertion>),
+    LookAroundAssertion(Box<'a, LookAroundAssertion<'a>>),
+    Quantifier(Box<'a, Quantifier<'a>>),
+    Character(Box<'a, Character>),
+    Dot(Box<'a, Dot>),
+    CharacterClassEscape(Box<'a, CharacterClassEscape>),
+    UnicodePropertyEscape(Box<'a, UnicodePropertyEscape>),
+    CharacterClass(Box<'a, CharacterClass<'a>>),
+    CapturingGroup(Box<'a, CapturingGroup<'a>>),
+    IgnoreGroup(Box<'a, IgnoreGroup<'a>>),
+    IndexedReference(Box<'a, IndexedReference>),
+    NamedReference(Box<'a, NamedReference>),
+}
+
+impl<'a> Term<'a> {
+    #[must_use]
+    pub fn span(&self) -> Span {
+        match self {
+            Self::BoundaryAssertion(t) => t.span,
+            Self::LookAroundAssertion(t) => t.span,
+            Self::Quantifier(t) => t.span,
+            Self::Character(t) => t.span,
+            Self::Dot(t) => t.span,
+            Self::CharacterClassEscape(t) => t.span,
+            Self::UnicodePropertyEscape(t) => t.span,
+            Self::CharacterClass(t) => t.span,
+            Self::CapturingGroup(t) => t.span,
+            Self::IgnoreGroup(t) => t.span,
+            Self::IndexedReference(t) => t.span,
+            Self::NamedReference(t) => t.span,
+        }
+    }
+}
+
+/// `^` `$` `\b` `\B`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct BoundaryAssertion {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub kind: BoundaryAssertionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum BoundaryAssertionKind {
+    Start,
+    End,
+    Boundary,
+    NegativeBoundary,
+}
+
+/// `(?=...)` `(?!...)` `(?<=...)` `(?<!...)`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct LookAroundAssertion<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub kind: LookAroundAssertionKind,
+    pub body: Disjunction<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum LookAroundAssertionKind {
+    Lookahead,
+    NegativeLookahead,
+    Lookbehind,
+    NegativeLookbehind,
+}
+
+impl LookAroundAssertionKind {
+    #[must_use]
+    pub fn is_lookbehind(self) -> bool {
+        matches!(self, Self::Lookbehind | Self::NegativeLookbehind)
+    }
+}
+
+/// `a*` `a+` `a?` `a{n}` `a{n,}` `a{n,m}`, optionally followed by `?` for non-greedy
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct Quantifier<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub min: u32,
+    /// `None` for an unbounded quantifier such as `*` `+` `{n,}`
+    pub max: Option<u32>,
+    pub greedy: bool,
+    pub body: Term<'a>,
+}
+
+/// A single character, which may be written as an escape sequence.
+/// `value` is the code point, or the code unit for each half of a surrogate pair
+/// outside of unicode mode.
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct Character {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub kind: CharacterKind,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum CharacterKind {
+    /// `\cJ`
+    ControlLetter,
+    /// `\x41`
+    HexadecimalEscape,
+    /// `\$`
+    Identifier,
+    /// `\0`
+    Null,
+    /// Annex B `\101`
+    Octal,
+    /// `\n`
+    SingleEscape,
+    /// `a`
+    Symbol,
+    /// `A` `\u{41}`
+    UnicodeEscape,
+}
+
+/// `.`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct Dot {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+}
+
+/// `\d` `\D` `\s` `\S` `\w` `\W`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct CharacterClassEscape {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub kind: CharacterClassEscapeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum CharacterClassEscapeKind {
+    D,
+    NegativeD,
+    S,
+    NegativeS,
+    W,
+    NegativeW,
+}
+
+/// `\p{Name}` `\p{Name=Value}` `\P{...}`, only available in unicode mode
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct UnicodePropertyEscape {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub negative: bool,
+    /// Whether this is a property of strings such as `RGI_Emoji`, only available with the `v` flag
+    pub strings: bool,
+    pub name: Atom,
+    pub value: Option<Atom>,
+}
+
+/// `[...]` `[^...]`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct CharacterClass<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub negative: bool,
+    /// Always `Union` without the `v` flag
+    pub kind: CharacterClassContentsKind,
+    pub body: Vec<'a, CharacterClassContents<'a>>,
+}
+
+/// `ClassSetExpression` from the `v` flag, `[a&&b]` is an intersection and `[a--b]` is a subtraction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum CharacterClassContentsKind {
+    Union,
+    Intersection,
+    Subtraction,
+}
+
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
+pub enum CharacterClassContents<'a> {
+    CharacterClassRange(Box<'a, CharacterClassRange>),
+    CharacterClassEscape(Box<'a, CharacterClassEscape>),
+    UnicodePropertyEscape(Box<'a, UnicodePropertyEscape>),
+    Character(Box<'a, Character>),
+    /// `v` flag only
+    NestedCharacterClass(Box<'a, CharacterClass<'a>>),
+    /// `v` flag only, `\q{abc|d}`
+    ClassStringDisjunction(Box<'a, ClassStringDisjunction<'a>>),
+}
+
+/// `a-z`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct CharacterClassRange {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub min: Character,
+    pub max: Character,
+}
+
+/// `\q{abc|d}`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct ClassStringDisjunction<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    /// Whether any of the strings is not a single character
+    pub strings: bool,
+    pub body: Vec<'a, ClassString<'a>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct ClassString<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub body: Vec<'a, Character>,
+}
+
+/// `(...)` `(?<name>...)`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct CapturingGroup<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub name: Option<Atom>,
+    pub body: Disjunction<'a>,
+}
+
+/// `(?:...)`
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct IgnoreGroup<'a> {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub body: Disjunction<'a>,
+}
+
+/// `\1`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct IndexedReference {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub index: u32,
+}
+
+/// `\k<name>`
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
+pub struct NamedReference {
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub span: Span,
+    pub name: Atom,
+}
@@ -0,0 +1,1946 @@
+// Auto-generated by `cargo run -p ast_codegen`, do not edit.
+
+#![allow(clippy::unused_self, clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+
+use num_bigint::BigUint;
+use ordered_float::NotNan;
+use oxc_allocator::{Box, Vec};
+
+use super::AstBuilder;
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, Atom, SourceType, Span};
+
+/// A constructor for every node of the AST, `new_*` takes all fields of the node.
+impl<'a> AstBuilder<'a> {
+    #[must_use]
+    #[inline]
+    pub fn new_program(
+        &self,
+        span: Span,
+        directives: Vec<'a, Directive<'a>>,
+        body: Vec<'a, Statement<'a>>,
+        source_type: SourceType,
+    ) -> Program<'a> {
+        Program { span, directives, body, source_type }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_identifier_name(&self, span: Span, name: Atom) -> IdentifierName {
+        IdentifierName { span, name }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_identifier_reference(&self, span: Span, name: Atom) -> IdentifierReference {
+        IdentifierReference { span, name }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_binding_identifier(&self, span: Span, name: Atom) -> BindingIdentifier {
+        BindingIdentifier { span, name }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_label_identifier(&self, span: Span, name: Atom) -> LabelIdentifier {
+        LabelIdentifier { span, name }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_this_expression(&self, span: Span) -> ThisExpression {
+        ThisExpression { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_array_expression(
+        &self,
+        span: Span,
+        elements: Vec<'a, Option<Argument<'a>>>,
+        trailing_comma: Option<Span>,
+    ) -> ArrayExpression<'a> {
+        ArrayExpression { span, elements, trailing_comma }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_object_expression(
+        &self,
+        span: Span,
+        properties: Vec<'a, ObjectProperty<'a>>,
+        trailing_comma: Option<Span>,
+    ) -> ObjectExpression<'a> {
+        ObjectExpression { span, properties, trailing_comma }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_property(
+        &self,
+        span: Span,
+        kind: PropertyKind,
+        key: PropertyKey<'a>,
+        value: PropertyValue<'a>,
+        method: bool,
+        shorthand: bool,
+        computed: bool,
+    ) -> Property<'a> {
+        Property { span, kind, key, value, method, shorthand, computed }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_template_literal(
+        &self,
+        span: Span,
+        quasis: Vec<'a, TemplateElement>,
+        expressions: Vec<'a, Expression<'a>>,
+    ) -> TemplateLiteral<'a> {
+        TemplateLiteral { span, quasis, expressions }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_tagged_template_expression(
+        &self,
+        span: Span,
+        tag: Expression<'a>,
+        quasi: TemplateLiteral<'a>,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> TaggedTemplateExpression<'a> {
+        TaggedTemplateExpression { span, tag, quasi, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_template_element(
+        &self,
+        span: Span,
+        tail: bool,
+        value: TemplateElementValue,
+    ) -> TemplateElement {
+        TemplateElement { span, tail, value }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_computed_member_expression(
+        &self,
+        span: Span,
+        object: Expression<'a>,
+        expression: Expression<'a>,
+        optional: bool,
+    ) -> ComputedMemberExpression<'a> {
+        ComputedMemberExpression { span, object, expression, optional }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_static_member_expression(
+        &self,
+        span: Span,
+        object: Expression<'a>,
+        property: IdentifierName,
+        optional: bool,
+    ) -> StaticMemberExpression<'a> {
+        StaticMemberExpression { span, object, property, optional }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_private_field_expression(
+        &self,
+        span: Span,
+        object: Expression<'a>,
+        field: PrivateIdentifier,
+        optional: bool,
+    ) -> PrivateFieldExpression<'a> {
+        PrivateFieldExpression { span, object, field, optional }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_call_expression(
+        &self,
+        span: Span,
+        callee: Expression<'a>,
+        arguments: Vec<'a, Argument<'a>>,
+        optional: bool,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> CallExpression<'a> {
+        CallExpression { span, callee, arguments, optional, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_new_expression(
+        &self,
+        span: Span,
+        callee: Expression<'a>,
+        arguments: Vec<'a, Argument<'a>>,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> NewExpression<'a> {
+        NewExpression { span, callee, arguments, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_meta_property(
+        &self,
+        span: Span,
+        meta: IdentifierName,
+        property: IdentifierName,
+    ) -> MetaProperty {
+        MetaProperty { span, meta, property }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_spread_element(&self, span: Span, argument: Expression<'a>) -> SpreadElement<'a> {
+        SpreadElement { span, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_update_expression(
+        &self,
+        span: Span,
+        operator: UpdateOperator,
+        prefix: bool,
+        argument: SimpleAssignmentTarget<'a>,
+    ) -> UpdateExpression<'a> {
+        UpdateExpression { span, operator, prefix, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_unary_expression(
+        &self,
+        span: Span,
+        operator: UnaryOperator,
+        prefix: bool,
+        argument: Expression<'a>,
+    ) -> UnaryExpression<'a> {
+        UnaryExpression { span, operator, prefix, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_binary_expression(
+        &self,
+        span: Span,
+        left: Expression<'a>,
+        operator: BinaryOperator,
+        right: Expression<'a>,
+    ) -> BinaryExpression<'a> {
+        BinaryExpression { span, left, operator, right }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_private_in_expression(
+        &self,
+        span: Span,
+        left: PrivateIdentifier,
+        operator: BinaryOperator,
+        right: Expression<'a>,
+    ) -> PrivateInExpression<'a> {
+        PrivateInExpression { span, left, operator, right }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_logical_expression(
+        &self,
+        span: Span,
+        left: Expression<'a>,
+        operator: LogicalOperator,
+        right: Expression<'a>,
+    ) -> LogicalExpression<'a> {
+        LogicalExpression { span, left, operator, right }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_conditional_expression(
+        &self,
+        span: Span,
+        test: Expression<'a>,
+        consequent: Expression<'a>,
+        alternate: Expression<'a>,
+    ) -> ConditionalExpression<'a> {
+        ConditionalExpression { span, test, consequent, alternate }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_assignment_expression(
+        &self,
+        span: Span,
+        operator: AssignmentOperator,
+        left: AssignmentTarget<'a>,
+        right: Expression<'a>,
+    ) -> AssignmentExpression<'a> {
+        AssignmentExpression { span, operator, left, right }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_array_assignment_target(
+        &self,
+        span: Span,
+        elements: Vec<'a, Option<AssignmentTargetMaybeDefault<'a>>>,
+        rest: Option<AssignmentTarget<'a>>,
+        trailing_comma: Option<Span>,
+    ) -> ArrayAssignmentTarget<'a> {
+        ArrayAssignmentTarget { span, elements, rest, trailing_comma }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_object_assignment_target(
+        &self,
+        span: Span,
+        properties: Vec<'a, AssignmentTargetProperty<'a>>,
+        rest: Option<AssignmentTarget<'a>>,
+    ) -> ObjectAssignmentTarget<'a> {
+        ObjectAssignmentTarget { span, properties, rest }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_assignment_target_with_default(
+        &self,
+        span: Span,
+        binding: AssignmentTarget<'a>,
+        init: Expression<'a>,
+    ) -> AssignmentTargetWithDefault<'a> {
+        AssignmentTargetWithDefault { span, binding, init }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_assignment_target_property_identifier(
+        &self,
+        span: Span,
+        binding: IdentifierReference,
+        init: Option<Expression<'a>>,
+    ) -> AssignmentTargetPropertyIdentifier<'a> {
+        AssignmentTargetPropertyIdentifier { span, binding, init }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_assignment_target_property_property(
+        &self,
+        span: Span,
+        name: PropertyKey<'a>,
+        binding: AssignmentTargetMaybeDefault<'a>,
+    ) -> AssignmentTargetPropertyProperty<'a> {
+        AssignmentTargetPropertyProperty { span, name, binding }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_sequence_expression(
+        &self,
+        span: Span,
+        expressions: Vec<'a, Expression<'a>>,
+    ) -> SequenceExpression<'a> {
+        SequenceExpression { span, expressions }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_super(&self, span: Span) -> Super {
+        Super { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_await_expression(
+        &self,
+        span: Span,
+        argument: Expression<'a>,
+    ) -> AwaitExpression<'a> {
+        AwaitExpression { span, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_chain_expression(
+        &self,
+        span: Span,
+        expression: ChainElement<'a>,
+    ) -> ChainExpression<'a> {
+        ChainExpression { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_parenthesized_expression(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+    ) -> ParenthesizedExpression<'a> {
+        ParenthesizedExpression { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_directive(
+        &self,
+        span: Span,
+        expression: StringLiteral,
+        directive: &'a str,
+    ) -> Directive<'a> {
+        Directive { span, expression, directive }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_block_statement(
+        &self,
+        span: Span,
+        body: Vec<'a, Statement<'a>>,
+    ) -> BlockStatement<'a> {
+        BlockStatement { span, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_variable_declaration(
+        &self,
+        span: Span,
+        kind: VariableDeclarationKind,
+        declarations: Vec<'a, VariableDeclarator<'a>>,
+        modifiers: Modifiers<'a>,
+    ) -> VariableDeclaration<'a> {
+        VariableDeclaration { span, kind, declarations, modifiers }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_variable_declarator(
+        &self,
+        span: Span,
+        kind: VariableDeclarationKind,
+        id: BindingPattern<'a>,
+        init: Option<Expression<'a>>,
+        definite: bool,
+    ) -> VariableDeclarator<'a> {
+        VariableDeclarator { span, kind, id, init, definite }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_empty_statement(&self, span: Span) -> EmptyStatement {
+        EmptyStatement { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_expression_statement(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+    ) -> ExpressionStatement<'a> {
+        ExpressionStatement { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_if_statement(
+        &self,
+        span: Span,
+        test: Expression<'a>,
+        consequent: Statement<'a>,
+        alternate: Option<Statement<'a>>,
+    ) -> IfStatement<'a> {
+        IfStatement { span, test, consequent, alternate }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_do_while_statement(
+        &self,
+        span: Span,
+        body: Statement<'a>,
+        test: Expression<'a>,
+    ) -> DoWhileStatement<'a> {
+        DoWhileStatement { span, body, test }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_while_statement(
+        &self,
+        span: Span,
+        test: Expression<'a>,
+        body: Statement<'a>,
+    ) -> WhileStatement<'a> {
+        WhileStatement { span, test, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_for_statement(
+        &self,
+        span: Span,
+        init: Option<ForStatementInit<'a>>,
+        test: Option<Expression<'a>>,
+        update: Option<Expression<'a>>,
+        body: Statement<'a>,
+    ) -> ForStatement<'a> {
+        ForStatement { span, init, test, update, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_for_in_statement(
+        &self,
+        span: Span,
+        left: ForStatementLeft<'a>,
+        right: Expression<'a>,
+        body: Statement<'a>,
+    ) -> ForInStatement<'a> {
+        ForInStatement { span, left, right, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_for_of_statement(
+        &self,
+        span: Span,
+        r#await: bool,
+        left: ForStatementLeft<'a>,
+        right: Expression<'a>,
+        body: Statement<'a>,
+    ) -> ForOfStatement<'a> {
+        ForOfStatement { span, r#await, left, right, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_continue_statement(
+        &self,
+        span: Span,
+        label: Option<LabelIdentifier>,
+    ) -> ContinueStatement {
+        ContinueStatement { span, label }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_break_statement(
+        &self,
+        span: Span,
+        label: Option<LabelIdentifier>,
+    ) -> BreakStatement {
+        BreakStatement { span, label }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_return_statement(
+        &self,
+        span: Span,
+        argument: Option<Expression<'a>>,
+    ) -> ReturnStatement<'a> {
+        ReturnStatement { span, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_with_statement(
+        &self,
+        span: Span,
+        object: Expression<'a>,
+        body: Statement<'a>,
+    ) -> WithStatement<'a> {
+        WithStatement { span, object, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_switch_statement(
+        &self,
+        span: Span,
+        discriminant: Expression<'a>,
+        cases: Vec<'a, SwitchCase<'a>>,
+    ) -> SwitchStatement<'a> {
+        SwitchStatement { span, discriminant, cases }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_switch_case(
+        &self,
+        span: Span,
+        test: Option<Expression<'a>>,
+        consequent: Vec<'a, Statement<'a>>,
+    ) -> SwitchCase<'a> {
+        SwitchCase { span, test, consequent }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_labeled_statement(
+        &self,
+        span: Span,
+        label: LabelIdentifier,
+        body: Statement<'a>,
+    ) -> LabeledStatement<'a> {
+        LabeledStatement { span, label, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_throw_statement(&self, span: Span, argument: Expression<'a>) -> ThrowStatement<'a> {
+        ThrowStatement { span, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_try_statement(
+        &self,
+        span: Span,
+        block: Box<'a, BlockStatement<'a>>,
+        handler: Option<Box<'a, CatchClause<'a>>>,
+        finalizer: Option<Box<'a, BlockStatement<'a>>>,
+    ) -> TryStatement<'a> {
+        TryStatement { span, block, handler, finalizer }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_catch_clause(
+        &self,
+        span: Span,
+        param: Option<BindingPattern<'a>>,
+        body: Box<'a, BlockStatement<'a>>,
+    ) -> CatchClause<'a> {
+        CatchClause { span, param, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_debugger_statement(&self, span: Span) -> DebuggerStatement {
+        DebuggerStatement { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_invalid_statement(&self, span: Span) -> InvalidStatement {
+        InvalidStatement { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_invalid_expression(&self, span: Span) -> InvalidExpression {
+        InvalidExpression { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_binding_pattern(
+        &self,
+        kind: BindingPatternKind<'a>,
+        type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        optional: bool,
+    ) -> BindingPattern<'a> {
+        BindingPattern { kind, type_annotation, optional }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_assignment_pattern(
+        &self,
+        span: Span,
+        left: BindingPattern<'a>,
+        right: Expression<'a>,
+    ) -> AssignmentPattern<'a> {
+        AssignmentPattern { span, left, right }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_object_pattern(
+        &self,
+        span: Span,
+        properties: Vec<'a, ObjectPatternProperty<'a>>,
+    ) -> ObjectPattern<'a> {
+        ObjectPattern { span, properties }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_array_pattern(
+        &self,
+        span: Span,
+        elements: Vec<'a, Option<BindingPattern<'a>>>,
+    ) -> ArrayPattern<'a> {
+        ArrayPattern { span, elements }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_rest_element(&self, span: Span, argument: BindingPattern<'a>) -> RestElement<'a> {
+        RestElement { span, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_function(
+        &self,
+        r#type: FunctionType,
+        span: Span,
+        id: Option<BindingIdentifier>,
+        expression: bool,
+        generator: bool,
+        r#async: bool,
+        params: Box<'a, FormalParameters<'a>>,
+        body: Option<Box<'a, FunctionBody<'a>>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        modifiers: Modifiers<'a>,
+    ) -> Function<'a> {
+        Function {
+            r#type,
+            span,
+            id,
+            expression,
+            generator,
+            r#async,
+            params,
+            body,
+            type_parameters,
+            return_type,
+            modifiers,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_formal_parameters(
+        &self,
+        span: Span,
+        kind: FormalParameterKind,
+        items: Vec<'a, FormalParameter<'a>>,
+    ) -> FormalParameters<'a> {
+        FormalParameters { span, kind, items }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_formal_parameter(
+        &self,
+        span: Span,
+        pattern: BindingPattern<'a>,
+        accessibility: Option<TSAccessibility>,
+        readonly: bool,
+        decorators: Vec<'a, Decorator<'a>>,
+    ) -> FormalParameter<'a> {
+        FormalParameter { span, pattern, accessibility, readonly, decorators }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_function_body(
+        &self,
+        span: Span,
+        directives: Vec<'a, Directive<'a>>,
+        statements: Vec<'a, Statement<'a>>,
+    ) -> FunctionBody<'a> {
+        FunctionBody { span, directives, statements }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_arrow_expression(
+        &self,
+        span: Span,
+        expression: bool,
+        generator: bool,
+        r#async: bool,
+        params: Box<'a, FormalParameters<'a>>,
+        body: Box<'a, FunctionBody<'a>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+    ) -> ArrowExpression<'a> {
+        ArrowExpression {
+            span,
+            expression,
+            generator,
+            r#async,
+            params,
+            body,
+            type_parameters,
+            return_type,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_yield_expression(
+        &self,
+        span: Span,
+        delegate: bool,
+        argument: Option<Expression<'a>>,
+    ) -> YieldExpression<'a> {
+        YieldExpression { span, delegate, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_class(
+        &self,
+        r#type: ClassType,
+        span: Span,
+        id: Option<BindingIdentifier>,
+        super_class: Option<Expression<'a>>,
+        body: Box<'a, ClassBody<'a>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        super_type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+        implements: Option<Vec<'a, Box<'a, TSClassImplements<'a>>>>,
+        decorators: Vec<'a, Decorator<'a>>,
+        modifiers: Modifiers<'a>,
+    ) -> Class<'a> {
+        Class {
+            r#type,
+            span,
+            id,
+            super_class,
+            body,
+            type_parameters,
+            super_type_parameters,
+            implements,
+            decorators,
+            modifiers,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_class_body(&self, span: Span, body: Vec<'a, ClassElement<'a>>) -> ClassBody<'a> {
+        ClassBody { span, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_method_definition(
+        &self,
+        span: Span,
+        key: PropertyKey<'a>,
+        value: Box<'a, Function<'a>>,
+        kind: MethodDefinitionKind,
+        computed: bool,
+        r#static: bool,
+        r#override: bool,
+        optional: bool,
+        accessibility: Option<TSAccessibility>,
+        decorators: Vec<'a, Decorator<'a>>,
+    ) -> MethodDefinition<'a> {
+        MethodDefinition {
+            span,
+            key,
+            value,
+            kind,
+            computed,
+            r#static,
+            r#override,
+            optional,
+            accessibility,
+            decorators,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_property_definition(
+        &self,
+        span: Span,
+        key: PropertyKey<'a>,
+        value: Option<Expression<'a>>,
+        computed: bool,
+        r#static: bool,
+        declare: bool,
+        r#override: bool,
+        optional: bool,
+        definite: bool,
+        readonly: bool,
+        type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        accessibility: Option<TSAccessibility>,
+        decorators: Vec<'a, Decorator<'a>>,
+    ) -> PropertyDefinition<'a> {
+        PropertyDefinition {
+            span,
+            key,
+            value,
+            computed,
+            r#static,
+            declare,
+            r#override,
+            optional,
+            definite,
+            readonly,
+            type_annotation,
+            accessibility,
+            decorators,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_private_identifier(&self, span: Span, name: Atom) -> PrivateIdentifier {
+        PrivateIdentifier { span, name }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_static_block(&self, span: Span, body: Vec<'a, Statement<'a>>) -> StaticBlock<'a> {
+        StaticBlock { span, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_module_declaration(
+        &self,
+        span: Span,
+        kind: ModuleDeclarationKind<'a>,
+    ) -> ModuleDeclaration<'a> {
+        ModuleDeclaration { span, kind }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_accessor_property(
+        &self,
+        span: Span,
+        key: PropertyKey<'a>,
+        value: Option<Expression<'a>>,
+        computed: bool,
+        r#static: bool,
+    ) -> AccessorProperty<'a> {
+        AccessorProperty { span, key, value, computed, r#static }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_import_expression(
+        &self,
+        span: Span,
+        source: Expression<'a>,
+        arguments: Vec<'a, Expression<'a>>,
+    ) -> ImportExpression<'a> {
+        ImportExpression { span, source, arguments }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_import_declaration(
+        &self,
+        specifiers: Vec<'a, ImportDeclarationSpecifier>,
+        source: StringLiteral,
+        assertions: Option<Vec<'a, ImportAttribute>>,
+        import_kind: Option<ImportOrExportKind>,
+    ) -> ImportDeclaration<'a> {
+        ImportDeclaration { specifiers, source, assertions, import_kind }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_import_specifier(
+        &self,
+        span: Span,
+        imported: ModuleExportName,
+        local: BindingIdentifier,
+    ) -> ImportSpecifier {
+        ImportSpecifier { span, imported, local }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_import_default_specifier(
+        &self,
+        span: Span,
+        local: BindingIdentifier,
+    ) -> ImportDefaultSpecifier {
+        ImportDefaultSpecifier { span, local }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_import_namespace_specifier(
+        &self,
+        span: Span,
+        local: BindingIdentifier,
+    ) -> ImportNamespaceSpecifier {
+        ImportNamespaceSpecifier { span, local }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_import_attribute(
+        &self,
+        span: Span,
+        key: ImportAttributeKey,
+        value: StringLiteral,
+    ) -> ImportAttribute {
+        ImportAttribute { span, key, value }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_export_named_declaration(
+        &self,
+        declaration: Option<Declaration<'a>>,
+        specifiers: Vec<'a, ExportSpecifier>,
+        source: Option<StringLiteral>,
+        export_kind: Option<ImportOrExportKind>,
+    ) -> ExportNamedDeclaration<'a> {
+        ExportNamedDeclaration { declaration, specifiers, source, export_kind }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_export_default_declaration(
+        &self,
+        declaration: ExportDefaultDeclarationKind<'a>,
+        exported: ModuleExportName,
+    ) -> ExportDefaultDeclaration<'a> {
+        ExportDefaultDeclaration { declaration, exported }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_export_all_declaration(
+        &self,
+        exported: Option<ModuleExportName>,
+        source: StringLiteral,
+        assertions: Option<Vec<'a, ImportAttribute>>,
+        export_kind: Option<ImportOrExportKind>,
+    ) -> ExportAllDeclaration<'a> {
+        ExportAllDeclaration { exported, source, assertions, export_kind }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_export_specifier(
+        &self,
+        span: Span,
+        local: ModuleExportName,
+        exported: ModuleExportName,
+    ) -> ExportSpecifier {
+        ExportSpecifier { span, local, exported }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_boolean_literal(&self, span: Span, value: bool) -> BooleanLiteral {
+        BooleanLiteral { span, value }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_null_literal(&self, span: Span) -> NullLiteral {
+        NullLiteral { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_number_literal(
+        &self,
+        span: Span,
+        value: NotNan<f64>,
+        raw: &'a str,
+        base: NumberBase,
+    ) -> NumberLiteral<'a> {
+        NumberLiteral { span, value, raw, base }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_bigint_literal(&self, span: Span, value: BigUint) -> BigintLiteral {
+        BigintLiteral { span, value }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_reg_exp_literal(
+        &self,
+        span: Span,
+        value: EmptyObject,
+        regex: RegExp,
+        pattern: Option<Box<'a, regexp::Pattern<'a>>>,
+    ) -> RegExpLiteral<'a> {
+        RegExpLiteral { span, value, regex, pattern }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_string_literal(&self, span: Span, value: Atom) -> StringLiteral {
+        StringLiteral { span, value }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_element(
+        &self,
+        span: Span,
+        opening_element: Box<'a, JSXOpeningElement<'a>>,
+        closing_element: Option<Box<'a, JSXClosingElement<'a>>>,
+        children: Vec<'a, JSXChild<'a>>,
+    ) -> JSXElement<'a> {
+        JSXElement { span, opening_element, closing_element, children }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_opening_element(
+        &self,
+        span: Span,
+        self_closing: bool,
+        name: JSXElementName<'a>,
+        attributes: Vec<'a, JSXAttributeItem<'a>>,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> JSXOpeningElement<'a> {
+        JSXOpeningElement { span, self_closing, name, attributes, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_closing_element(
+        &self,
+        span: Span,
+        name: JSXElementName<'a>,
+    ) -> JSXClosingElement<'a> {
+        JSXClosingElement { span, name }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_fragment(
+        &self,
+        span: Span,
+        opening_fragment: JSXOpeningFragment,
+        closing_fragment: JSXClosingFragment,
+        children: Vec<'a, JSXChild<'a>>,
+    ) -> JSXFragment<'a> {
+        JSXFragment { span, opening_fragment, closing_fragment, children }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_opening_fragment(&self, span: Span) -> JSXOpeningFragment {
+        JSXOpeningFragment { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_closing_fragment(&self, span: Span) -> JSXClosingFragment {
+        JSXClosingFragment { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_namespaced_name(
+        &self,
+        span: Span,
+        namespace: JSXIdentifier,
+        property: JSXIdentifier,
+    ) -> JSXNamespacedName {
+        JSXNamespacedName { span, namespace, property }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_member_expression(
+        &self,
+        span: Span,
+        object: JSXMemberExpressionObject<'a>,
+        property: JSXIdentifier,
+    ) -> JSXMemberExpression<'a> {
+        JSXMemberExpression { span, object, property }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_expression_container(
+        &self,
+        span: Span,
+        expression: JSXExpression<'a>,
+    ) -> JSXExpressionContainer<'a> {
+        JSXExpressionContainer { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_empty_expression(&self, span: Span) -> JSXEmptyExpression {
+        JSXEmptyExpression { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_attribute(
+        &self,
+        span: Span,
+        name: JSXAttributeName<'a>,
+        value: Option<JSXAttributeValue<'a>>,
+    ) -> JSXAttribute<'a> {
+        JSXAttribute { span, name, value }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_spread_attribute(
+        &self,
+        span: Span,
+        argument: Expression<'a>,
+    ) -> JSXSpreadAttribute<'a> {
+        JSXSpreadAttribute { span, argument }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_identifier(&self, span: Span, name: Atom) -> JSXIdentifier {
+        JSXIdentifier { span, name }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_spread_child(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+    ) -> JSXSpreadChild<'a> {
+        JSXSpreadChild { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_jsx_text(&self, span: Span, value: Atom) -> JSXText {
+        JSXText { span, value }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_enum_declaration(
+        &self,
+        span: Span,
+        id: BindingIdentifier,
+        members: Vec<'a, TSEnumMember<'a>>,
+        modifiers: Modifiers<'a>,
+    ) -> TSEnumDeclaration<'a> {
+        TSEnumDeclaration { span, id, members, modifiers }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_enum_member(
+        &self,
+        span: Span,
+        id: TSEnumMemberName<'a>,
+        initializer: Option<Expression<'a>>,
+    ) -> TSEnumMember<'a> {
+        TSEnumMember { span, id, initializer }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_annotation(
+        &self,
+        span: Span,
+        type_annotation: TSType<'a>,
+    ) -> TSTypeAnnotation<'a> {
+        TSTypeAnnotation { span, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_literal_type(&self, span: Span, literal: TSLiteral<'a>) -> TSLiteralType<'a> {
+        TSLiteralType { span, literal }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_conditional_type(
+        &self,
+        span: Span,
+        check_type: TSType<'a>,
+        extends_type: TSType<'a>,
+        true_type: TSType<'a>,
+        false_type: TSType<'a>,
+    ) -> TSConditionalType<'a> {
+        TSConditionalType { span, check_type, extends_type, true_type, false_type }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_union_type(&self, span: Span, types: Vec<'a, TSType<'a>>) -> TSUnionType<'a> {
+        TSUnionType { span, types }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_intersection_type(
+        &self,
+        span: Span,
+        types: Vec<'a, TSType<'a>>,
+    ) -> TSIntersectionType<'a> {
+        TSIntersectionType { span, types }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_operator_type(
+        &self,
+        span: Span,
+        operator: TSTypeOperator,
+        type_annotation: TSType<'a>,
+    ) -> TSTypeOperatorType<'a> {
+        TSTypeOperatorType { span, operator, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_array_type(&self, span: Span, element_type: TSType<'a>) -> TSArrayType<'a> {
+        TSArrayType { span, element_type }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_indexed_access_type(
+        &self,
+        span: Span,
+        object_type: TSType<'a>,
+        index_type: TSType<'a>,
+    ) -> TSIndexedAccessType<'a> {
+        TSIndexedAccessType { span, object_type, index_type }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_tuple_type(
+        &self,
+        span: Span,
+        element_types: Vec<'a, TSTupleElement<'a>>,
+    ) -> TSTupleType<'a> {
+        TSTupleType { span, element_types }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_named_tuple_member(
+        &self,
+        span: Span,
+        element_type: TSType<'a>,
+        label: IdentifierName,
+        optional: bool,
+    ) -> TSNamedTupleMember<'a> {
+        TSNamedTupleMember { span, element_type, label, optional }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_optional_type(
+        &self,
+        span: Span,
+        type_annotation: TSType<'a>,
+    ) -> TSOptionalType<'a> {
+        TSOptionalType { span, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_rest_type(&self, span: Span, type_annotation: TSType<'a>) -> TSRestType<'a> {
+        TSRestType { span, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_any_keyword(&self, span: Span) -> TSAnyKeyword {
+        TSAnyKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_string_keyword(&self, span: Span) -> TSStringKeyword {
+        TSStringKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_boolean_keyword(&self, span: Span) -> TSBooleanKeyword {
+        TSBooleanKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_number_keyword(&self, span: Span) -> TSNumberKeyword {
+        TSNumberKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_never_keyword(&self, span: Span) -> TSNeverKeyword {
+        TSNeverKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_unknown_keyword(&self, span: Span) -> TSUnknownKeyword {
+        TSUnknownKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_null_keyword(&self, span: Span) -> TSNullKeyword {
+        TSNullKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_undefined_keyword(&self, span: Span) -> TSUndefinedKeyword {
+        TSUndefinedKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_void_keyword(&self, span: Span) -> TSVoidKeyword {
+        TSVoidKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_symbol_keyword(&self, span: Span) -> TSSymbolKeyword {
+        TSSymbolKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_this_keyword(&self, span: Span) -> TSThisKeyword {
+        TSThisKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_object_keyword(&self, span: Span) -> TSObjectKeyword {
+        TSObjectKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_big_int_keyword(&self, span: Span) -> TSBigIntKeyword {
+        TSBigIntKeyword { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_reference(
+        &self,
+        span: Span,
+        type_name: TSTypeName<'a>,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> TSTypeReference<'a> {
+        TSTypeReference { span, type_name, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_qualified_name(
+        &self,
+        span: Span,
+        left: TSTypeName<'a>,
+        right: IdentifierName,
+    ) -> TSQualifiedName<'a> {
+        TSQualifiedName { span, left, right }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_parameter_instantiation(
+        &self,
+        span: Span,
+        params: Vec<'a, TSType<'a>>,
+    ) -> TSTypeParameterInstantiation<'a> {
+        TSTypeParameterInstantiation { span, params }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_parameter(
+        &self,
+        span: Span,
+        name: BindingIdentifier,
+        constraint: Option<TSType<'a>>,
+        default: Option<TSType<'a>>,
+        r#in: bool,
+        out: bool,
+    ) -> TSTypeParameter<'a> {
+        TSTypeParameter { span, name, constraint, default, r#in, out }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_parameter_declaration(
+        &self,
+        span: Span,
+        params: Vec<'a, Box<'a, TSTypeParameter<'a>>>,
+    ) -> TSTypeParameterDeclaration<'a> {
+        TSTypeParameterDeclaration { span, params }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_alias_declaration(
+        &self,
+        span: Span,
+        id: BindingIdentifier,
+        type_annotation: TSType<'a>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        modifiers: Modifiers<'a>,
+    ) -> TSTypeAliasDeclaration<'a> {
+        TSTypeAliasDeclaration { span, id, type_annotation, type_parameters, modifiers }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_abstract_method_definition(
+        &self,
+        method_definition: MethodDefinition<'a>,
+    ) -> TSAbstractMethodDefinition<'a> {
+        TSAbstractMethodDefinition { method_definition }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_abstract_property_definition(
+        &self,
+        property_definition: PropertyDefinition<'a>,
+    ) -> TSAbstractPropertyDefinition<'a> {
+        TSAbstractPropertyDefinition { property_definition }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_class_implements(
+        &self,
+        span: Span,
+        expression: TSTypeName<'a>,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> TSClassImplements<'a> {
+        TSClassImplements { span, expression, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_interface_declaration(
+        &self,
+        span: Span,
+        id: BindingIdentifier,
+        body: Box<'a, TSInterfaceBody<'a>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        extends: Option<Vec<'a, Box<'a, TSInterfaceHeritage<'a>>>>,
+        modifiers: Modifiers<'a>,
+    ) -> TSInterfaceDeclaration<'a> {
+        TSInterfaceDeclaration { span, id, body, type_parameters, extends, modifiers }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_interface_body(
+        &self,
+        span: Span,
+        body: Vec<'a, TSSignature<'a>>,
+    ) -> TSInterfaceBody<'a> {
+        TSInterfaceBody { span, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_property_signature(
+        &self,
+        span: Span,
+        computed: bool,
+        optional: bool,
+        readonly: bool,
+        key: PropertyKey<'a>,
+        type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
+    ) -> TSPropertySignature<'a> {
+        TSPropertySignature { span, computed, optional, readonly, key, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_index_signature(
+        &self,
+        span: Span,
+        parameters: Vec<'a, Box<'a, TSIndexSignatureName<'a>>>,
+        type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+    ) -> TSIndexSignature<'a> {
+        TSIndexSignature { span, parameters, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_call_signature_declaration(
+        &self,
+        span: Span,
+        params: Box<'a, FormalParameters<'a>>,
+        return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    ) -> TSCallSignatureDeclaration<'a> {
+        TSCallSignatureDeclaration { span, params, return_type, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_method_signature(
+        &self,
+        span: Span,
+        key: PropertyKey<'a>,
+        computed: bool,
+        optional: bool,
+        kind: TSMethodSignatureKind,
+        params: Box<'a, FormalParameters<'a>>,
+        return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    ) -> TSMethodSignature<'a> {
+        TSMethodSignature {
+            span,
+            key,
+            computed,
+            optional,
+            kind,
+            params,
+            return_type,
+            type_parameters,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_construct_signature_declaration(
+        &self,
+        span: Span,
+        params: Box<'a, FormalParameters<'a>>,
+        return_type: Option<Box<'a, TSTypeAnnotation<'a>>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    ) -> TSConstructSignatureDeclaration<'a> {
+        TSConstructSignatureDeclaration { span, params, return_type, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_index_signature_name(
+        &self,
+        span: Span,
+        name: Atom,
+        type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+    ) -> TSIndexSignatureName<'a> {
+        TSIndexSignatureName { span, name, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_interface_heritage(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> TSInterfaceHeritage<'a> {
+        TSInterfaceHeritage { span, expression, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_predicate(
+        &self,
+        span: Span,
+        parameter_name: TSTypePredicateName,
+        asserts: bool,
+        type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
+    ) -> TSTypePredicate<'a> {
+        TSTypePredicate { span, parameter_name, asserts, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_module_declaration(
+        &self,
+        span: Span,
+        id: TSModuleDeclarationName,
+        body: TSModuleDeclarationBody<'a>,
+        modifiers: Modifiers<'a>,
+    ) -> TSModuleDeclaration<'a> {
+        TSModuleDeclaration { span, id, body, modifiers }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_module_block(
+        &self,
+        span: Span,
+        body: Vec<'a, Statement<'a>>,
+    ) -> TSModuleBlock<'a> {
+        TSModuleBlock { span, body }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_literal(
+        &self,
+        span: Span,
+        members: Vec<'a, TSSignature<'a>>,
+    ) -> TSTypeLiteral<'a> {
+        TSTypeLiteral { span, members }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_infer_type(
+        &self,
+        span: Span,
+        type_parameter: Box<'a, TSTypeParameter<'a>>,
+    ) -> TSInferType<'a> {
+        TSInferType { span, type_parameter }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_query(
+        &self,
+        span: Span,
+        expr_name: TSTypeName<'a>,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> TSTypeQuery<'a> {
+        TSTypeQuery { span, expr_name, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_import_type(
+        &self,
+        span: Span,
+        is_type_of: bool,
+        parameter: TSType<'a>,
+        qualifier: Option<TSTypeName<'a>>,
+        type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
+    ) -> TSImportType<'a> {
+        TSImportType { span, is_type_of, parameter, qualifier, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_function_type(
+        &self,
+        span: Span,
+        params: Box<'a, FormalParameters<'a>>,
+        return_type: Box<'a, TSTypeAnnotation<'a>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    ) -> TSFunctionType<'a> {
+        TSFunctionType { span, params, return_type, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_constructor_type(
+        &self,
+        span: Span,
+        r#abstract: bool,
+        params: Box<'a, FormalParameters<'a>>,
+        return_type: Box<'a, TSTypeAnnotation<'a>>,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    ) -> TSConstructorType<'a> {
+        TSConstructorType { span, r#abstract, params, return_type, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_mapped_type(
+        &self,
+        span: Span,
+        type_parameter: Box<'a, TSTypeParameter<'a>>,
+        name_type: Option<TSType<'a>>,
+        type_annotation: TSType<'a>,
+        optional: TSMappedTypeModifierOperator,
+        readonly: TSMappedTypeModifierOperator,
+    ) -> TSMappedType<'a> {
+        TSMappedType { span, type_parameter, name_type, type_annotation, optional, readonly }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_template_literal_type(
+        &self,
+        span: Span,
+        quasis: Vec<'a, TemplateElement>,
+        types: Vec<'a, TSType<'a>>,
+    ) -> TSTemplateLiteralType<'a> {
+        TSTemplateLiteralType { span, quasis, types }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_as_expression(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: TSType<'a>,
+    ) -> TSAsExpression<'a> {
+        TSAsExpression { span, expression, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_satisfies_expression(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: TSType<'a>,
+    ) -> TSSatisfiesExpression<'a> {
+        TSSatisfiesExpression { span, expression, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_type_assertion(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+        type_annotation: TSType<'a>,
+    ) -> TSTypeAssertion<'a> {
+        TSTypeAssertion { span, expression, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_import_equals_declaration(
+        &self,
+        span: Span,
+        id: BindingIdentifier,
+        module_reference: Box<'a, TSModuleReference<'a>>,
+        is_export: bool,
+        import_kind: ImportOrExportKind,
+    ) -> TSImportEqualsDeclaration<'a> {
+        TSImportEqualsDeclaration { span, id, module_reference, is_export, import_kind }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_external_module_reference(
+        &self,
+        span: Span,
+        expression: StringLiteral,
+    ) -> TSExternalModuleReference {
+        TSExternalModuleReference { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_non_null_expression(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+    ) -> TSNonNullExpression<'a> {
+        TSNonNullExpression { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_decorator(&self, span: Span, expression: Expression<'a>) -> Decorator<'a> {
+        Decorator { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_export_assignment(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+    ) -> TSExportAssignment<'a> {
+        TSExportAssignment { span, expression }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_namespace_export_declaration(
+        &self,
+        span: Span,
+        id: IdentifierName,
+    ) -> TSNamespaceExportDeclaration {
+        TSNamespaceExportDeclaration { span, id }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_ts_instantiation_expression(
+        &self,
+        span: Span,
+        expression: Expression<'a>,
+        type_parameters: Box<'a, TSTypeParameterInstantiation<'a>>,
+    ) -> TSInstantiationExpression<'a> {
+        TSInstantiationExpression { span, expression, type_parameters }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_flow_opaque_type_declaration(
+        &self,
+        span: Span,
+        id: BindingIdentifier,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        modifiers: Modifiers<'a>,
+    ) -> FlowOpaqueTypeDeclaration<'a> {
+        FlowOpaqueTypeDeclaration { span, id, type_parameters, supertype, impltype, modifiers }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_flow_declare_module_exports(
+        &self,
+        span: Span,
+        type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+    ) -> FlowDeclareModuleExports<'a> {
+        FlowDeclareModuleExports { span, type_annotation }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_flow_exact_object_type(
+        &self,
+        span: Span,
+        members: Vec<'a, TSSignature<'a>>,
+    ) -> FlowExactObjectType<'a> {
+        FlowExactObjectType { span, members }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_flow_existential_type(&self, span: Span) -> FlowExistentialType {
+        FlowExistentialType { span }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_js_doc_nullable_type(
+        &self,
+        span: Span,
+        type_annotation: TSType<'a>,
+        postfix: bool,
+    ) -> JSDocNullableType<'a> {
+        JSDocNullableType { span, type_annotation, postfix }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn new_js_doc_unknown_type(&self, span: Span) -> JSDocUnknownType {
+        JSDocUnknownType { span }
+    }
+}
@@ -1,5 +1,7 @@
 #![allow(clippy::unused_self, clippy::too_many_arguments)]
 
+mod constructors;
+
 use oxc_allocator::{Allocator, Box, String, Vec};
 
 #[allow(clippy::wildcard_imports)]
@@ -78,7 +80,7 @@ impl<'a> AstBuilder<'a> {
 
     #[must_use]
     #[inline]
-    pub fn literal_regexp_expression(&self, literal: RegExpLiteral) -> Expression<'a> {
+    pub fn literal_regexp_expression(&self, literal: RegExpLiteral<'a>) -> Expression<'a> {
         Expression::RegExpLiteral(self.alloc(literal))
     }
 
@@ -147,6 +149,11 @@ impl<'a> AstBuilder<'a> {
         Statement::DebuggerStatement(self.alloc(DebuggerStatement { span }))
     }
 
+    #[must_use]
+    pub fn invalid_statement(&self, span: Span) -> Statement<'a> {
+        Statement::InvalidStatement(self.alloc(InvalidStatement { span }))
+    }
+
     #[must_use]
     #[inline]
     pub fn do_while_statement(
@@ -620,6 +627,11 @@ impl<'a> AstBuilder<'a> {
         Expression::ThisExpression(self.alloc(ThisExpression { span }))
     }
 
+    #[must_use]
+    pub fn invalid_expression(&self, span: Span) -> Expression<'a> {
+        Expression::InvalidExpression(self.alloc(InvalidExpression { span }))
+    }
+
     #[must_use]
     #[inline]
     pub fn unary_expression(
@@ -1775,4 +1787,54 @@ impl<'a> AstBuilder<'a> {
     pub fn js_doc_unknown_type(&self, span: Span) -> TSType<'a> {
         TSType::JSDocUnknownType(self.alloc(JSDocUnknownType { span }))
     }
+
+    /* Flow */
+    #[must_use]
+    #[inline]
+    pub fn flow_opaque_type_declaration(
+        &self,
+        span: Span,
+        id: BindingIdentifier,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        supertype: Option<TSType<'a>>,
+        impltype: Option<TSType<'a>>,
+        modifiers: Modifiers<'a>,
+    ) -> Declaration<'a> {
+        Declaration::FlowOpaqueTypeDeclaration(self.alloc(FlowOpaqueTypeDeclaration {
+            span,
+            id,
+            type_parameters,
+            supertype,
+            impltype,
+            modifiers,
+        }))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn flow_declare_module_exports(
+        &self,
+        span: Span,
+        type_annotation: Box<'a, TSTypeAnnotation<'a>>,
+    ) -> Declaration<'a> {
+        Declaration::FlowDeclareModuleExports(
+            self.alloc(FlowDeclareModuleExports { span, type_annotation }),
+        )
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn flow_exact_object_type(
+        &self,
+        span: Span,
+        members: Vec<'a, TSSignature<'a>>,
+    ) -> TSType<'a> {
+        TSType::FlowExactObjectType(self.alloc(FlowExactObjectType { span, members }))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn flow_existential_type(&self, span: Span) -> TSType<'a> {
+        TSType::FlowExistentialType(self.alloc(FlowExistentialType { span }))
+    }
 }
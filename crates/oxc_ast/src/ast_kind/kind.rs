@@ -1,5 +1,7 @@
+// Auto-generated by `cargo run -p ast_codegen`, do not edit.
+
 #[allow(clippy::wildcard_imports)]
-use crate::{ast::*, Atom, GetSpan, Span};
+use crate::{ast::*, GetSpan, Span};
 
 /// Untyped AST Node Kind
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -7,228 +9,261 @@ pub enum AstKind<'a> {
     Root,
 
     Program(&'a Program<'a>),
-    Directive(&'a Directive<'a>),
-
-    BlockStatement(&'a BlockStatement<'a>),
-    BreakStatement(&'a BreakStatement),
-    ContinueStatement(&'a ContinueStatement),
-    DebuggerStatement(&'a DebuggerStatement),
-    DoWhileStatement(&'a DoWhileStatement<'a>),
-    EmptyStatement(&'a EmptyStatement),
-    ExpressionStatement(&'a ExpressionStatement<'a>),
-    ForInStatement(&'a ForInStatement<'a>),
-    ForOfStatement(&'a ForOfStatement<'a>),
-    ForStatement(&'a ForStatement<'a>),
-    ForStatementInit(&'a ForStatementInit<'a>),
-    IfStatement(&'a IfStatement<'a>),
-    LabeledStatement(&'a LabeledStatement<'a>),
-    ReturnStatement(&'a ReturnStatement<'a>),
-    SwitchStatement(&'a SwitchStatement<'a>),
-    ThrowStatement(&'a ThrowStatement<'a>),
-    TryStatement(&'a TryStatement<'a>),
-    WhileStatement(&'a WhileStatement<'a>),
-    WithStatement(&'a WithStatement<'a>),
-
-    SwitchCase(&'a SwitchCase<'a>),
-    CatchClause(&'a CatchClause<'a>),
-    FinallyClause(&'a BlockStatement<'a>),
-
-    VariableDeclaration(&'a VariableDeclaration<'a>),
-    VariableDeclarator(&'a VariableDeclarator<'a>),
-
     IdentifierName(&'a IdentifierName),
     IdentifierReference(&'a IdentifierReference),
     BindingIdentifier(&'a BindingIdentifier),
     LabelIdentifier(&'a LabelIdentifier),
-    PrivateIdentifier(&'a PrivateIdentifier),
-
-    NumberLiteral(&'a NumberLiteral<'a>),
-    StringLiteral(&'a StringLiteral),
-    BooleanLiteral(&'a BooleanLiteral),
-    NullLiteral(&'a NullLiteral),
-    BigintLiteral(&'a BigintLiteral),
-    RegExpLiteral(&'a RegExpLiteral),
-    TemplateLiteral(&'a TemplateLiteral<'a>),
-
-    MetaProperty(&'a MetaProperty),
-    Super(&'a Super),
-
+    ThisExpression(&'a ThisExpression),
     ArrayExpression(&'a ArrayExpression<'a>),
-    ArrowExpression(&'a ArrowExpression<'a>),
-    AssignmentExpression(&'a AssignmentExpression<'a>),
-    AwaitExpression(&'a AwaitExpression<'a>),
-    BinaryExpression(&'a BinaryExpression<'a>),
-    CallExpression(&'a CallExpression<'a>),
-    ConditionalExpression(&'a ConditionalExpression<'a>),
-    LogicalExpression(&'a LogicalExpression<'a>),
-    MemberExpression(&'a MemberExpression<'a>),
-    NewExpression(&'a NewExpression<'a>),
     ObjectExpression(&'a ObjectExpression<'a>),
-    ParenthesizedExpression(&'a ParenthesizedExpression<'a>),
-    SequenceExpression(&'a SequenceExpression<'a>),
-    TaggedTemplateExpression(&'a TaggedTemplateExpression<'a>),
-    ThisExpression(&'a ThisExpression),
-    UnaryExpression(&'a UnaryExpression<'a>),
-    UpdateExpression(&'a UpdateExpression<'a>),
-    YieldExpression(&'a YieldExpression<'a>),
-
     Property(&'a Property<'a>),
     PropertyKey(&'a PropertyKey<'a>),
     PropertyValue(&'a PropertyValue<'a>),
+    TemplateLiteral(&'a TemplateLiteral<'a>),
+    TaggedTemplateExpression(&'a TaggedTemplateExpression<'a>),
+    TemplateElement(&'a TemplateElement),
+    MemberExpression(&'a MemberExpression<'a>),
+    ComputedMemberExpression(&'a ComputedMemberExpression<'a>),
+    StaticMemberExpression(&'a StaticMemberExpression<'a>),
+    PrivateFieldExpression(&'a PrivateFieldExpression<'a>),
+    CallExpression(&'a CallExpression<'a>),
+    NewExpression(&'a NewExpression<'a>),
+    MetaProperty(&'a MetaProperty),
+    SpreadElement(&'a SpreadElement<'a>),
     Argument(&'a Argument<'a>),
+    UpdateExpression(&'a UpdateExpression<'a>),
+    UnaryExpression(&'a UnaryExpression<'a>),
+    BinaryExpression(&'a BinaryExpression<'a>),
+    PrivateInExpression(&'a PrivateInExpression<'a>),
+    LogicalExpression(&'a LogicalExpression<'a>),
+    ConditionalExpression(&'a ConditionalExpression<'a>),
+    AssignmentExpression(&'a AssignmentExpression<'a>),
     AssignmentTarget(&'a AssignmentTarget<'a>),
     SimpleAssignmentTarget(&'a SimpleAssignmentTarget<'a>),
+    ArrayAssignmentTarget(&'a ArrayAssignmentTarget<'a>),
+    ObjectAssignmentTarget(&'a ObjectAssignmentTarget<'a>),
     AssignmentTargetWithDefault(&'a AssignmentTargetWithDefault<'a>),
-    SpreadElement(&'a SpreadElement<'a>),
+    AssignmentTargetPropertyIdentifier(&'a AssignmentTargetPropertyIdentifier<'a>),
+    AssignmentTargetPropertyProperty(&'a AssignmentTargetPropertyProperty<'a>),
+    SequenceExpression(&'a SequenceExpression<'a>),
+    Super(&'a Super),
+    AwaitExpression(&'a AwaitExpression<'a>),
+    ChainExpression(&'a ChainExpression<'a>),
+    ParenthesizedExpression(&'a ParenthesizedExpression<'a>),
+    Directive(&'a Directive<'a>),
+    BlockStatement(&'a BlockStatement<'a>),
+    VariableDeclaration(&'a VariableDeclaration<'a>),
+    VariableDeclarator(&'a VariableDeclarator<'a>),
+    EmptyStatement(&'a EmptyStatement),
+    ExpressionStatement(&'a ExpressionStatement<'a>),
+    IfStatement(&'a IfStatement<'a>),
+    DoWhileStatement(&'a DoWhileStatement<'a>),
+    WhileStatement(&'a WhileStatement<'a>),
+    ForStatement(&'a ForStatement<'a>),
+    ForStatementInit(&'a ForStatementInit<'a>),
+    ForInStatement(&'a ForInStatement<'a>),
+    ForOfStatement(&'a ForOfStatement<'a>),
+    ContinueStatement(&'a ContinueStatement),
+    BreakStatement(&'a BreakStatement),
+    ReturnStatement(&'a ReturnStatement<'a>),
+    WithStatement(&'a WithStatement<'a>),
+    SwitchStatement(&'a SwitchStatement<'a>),
+    SwitchCase(&'a SwitchCase<'a>),
+    LabeledStatement(&'a LabeledStatement<'a>),
+    ThrowStatement(&'a ThrowStatement<'a>),
+    TryStatement(&'a TryStatement<'a>),
+    FinallyClause(&'a BlockStatement<'a>),
+    CatchClause(&'a CatchClause<'a>),
+    DebuggerStatement(&'a DebuggerStatement),
+    InvalidStatement(&'a InvalidStatement),
+    InvalidExpression(&'a InvalidExpression),
+    BindingPattern(&'a BindingPattern<'a>),
+    AssignmentPattern(&'a AssignmentPattern<'a>),
+    ObjectPattern(&'a ObjectPattern<'a>),
+    ArrayPattern(&'a ArrayPattern<'a>),
     RestElement(&'a RestElement<'a>),
-
     Function(&'a Function<'a>),
-    FunctionBody(&'a FunctionBody<'a>),
     FormalParameters(&'a FormalParameters<'a>),
     FormalParameter(&'a FormalParameter<'a>),
-
+    FunctionBody(&'a FunctionBody<'a>),
+    ArrowExpression(&'a ArrowExpression<'a>),
+    YieldExpression(&'a YieldExpression<'a>),
     Class(&'a Class<'a>),
     ClassHeritage(&'a Expression<'a>),
-    StaticBlock(&'a StaticBlock<'a>),
-    PropertyDefinition(&'a PropertyDefinition<'a>),
+    ClassBody(&'a ClassBody<'a>),
     MethodDefinition(&'a MethodDefinition<'a>),
-
-    ArrayPattern(&'a ArrayPattern<'a>),
-    ObjectPattern(&'a ObjectPattern<'a>),
-    AssignmentPattern(&'a AssignmentPattern<'a>),
-
-    Decorator(&'a Decorator<'a>),
-
+    PropertyDefinition(&'a PropertyDefinition<'a>),
+    PrivateIdentifier(&'a PrivateIdentifier),
+    StaticBlock(&'a StaticBlock<'a>),
     ModuleDeclaration(&'a ModuleDeclaration<'a>),
-
-    // JSX
-    // Please make sure to add these to `is_jsx` below.
+    AccessorProperty(&'a AccessorProperty<'a>),
+    ImportExpression(&'a ImportExpression<'a>),
+    ImportSpecifier(&'a ImportSpecifier),
+    ImportDefaultSpecifier(&'a ImportDefaultSpecifier),
+    ImportNamespaceSpecifier(&'a ImportNamespaceSpecifier),
+    ImportAttribute(&'a ImportAttribute),
+    ExportSpecifier(&'a ExportSpecifier),
+    BooleanLiteral(&'a BooleanLiteral),
+    NullLiteral(&'a NullLiteral),
+    NumberLiteral(&'a NumberLiteral<'a>),
+    BigintLiteral(&'a BigintLiteral),
+    RegExpLiteral(&'a RegExpLiteral<'a>),
+    StringLiteral(&'a StringLiteral),
+    JSXElement(&'a JSXElement<'a>),
     JSXOpeningElement(&'a JSXOpeningElement<'a>),
+    JSXClosingElement(&'a JSXClosingElement<'a>),
+    JSXFragment(&'a JSXFragment<'a>),
+    JSXOpeningFragment(&'a JSXOpeningFragment),
+    JSXClosingFragment(&'a JSXClosingFragment),
     JSXElementName(&'a JSXElementName<'a>),
-
-    // TypeScript
-    TSModuleBlock(&'a TSModuleBlock<'a>),
-
-    // NOTE: make sure add these to AstKind::is_type below
-    TSAnyKeyword(&'a TSAnyKeyword),
-    TSIntersectionType(&'a TSIntersectionType<'a>),
-    TSLiteralType(&'a TSLiteralType<'a>),
-    TSMethodSignature(&'a TSMethodSignature<'a>),
-    TSNullKeyword(&'a TSNullKeyword),
-    TSTypeLiteral(&'a TSTypeLiteral<'a>),
-    TSTypeReference(&'a TSTypeReference<'a>),
-    TSUnionType(&'a TSUnionType<'a>),
-    TSVoidKeyword(&'a TSVoidKeyword),
-
-    TSIndexedAccessType(&'a TSIndexedAccessType<'a>),
-
-    TSAsExpression(&'a TSAsExpression<'a>),
-    TSSatisfiesExpression(&'a TSSatisfiesExpression<'a>),
-    TSNonNullExpression(&'a TSNonNullExpression<'a>),
-
+    JSXNamespacedName(&'a JSXNamespacedName),
+    JSXMemberExpression(&'a JSXMemberExpression<'a>),
+    JSXExpressionContainer(&'a JSXExpressionContainer<'a>),
+    JSXEmptyExpression(&'a JSXEmptyExpression),
+    JSXAttribute(&'a JSXAttribute<'a>),
+    JSXSpreadAttribute(&'a JSXSpreadAttribute<'a>),
+    JSXIdentifier(&'a JSXIdentifier),
+    JSXSpreadChild(&'a JSXSpreadChild<'a>),
+    JSXText(&'a JSXText),
     TSEnumDeclaration(&'a TSEnumDeclaration<'a>),
     TSEnumMember(&'a TSEnumMember<'a>),
-    TSImportEqualsDeclaration(&'a TSImportEqualsDeclaration<'a>),
-    TSInterfaceDeclaration(&'a TSInterfaceDeclaration<'a>),
-    TSModuleDeclaration(&'a TSModuleDeclaration<'a>),
-    TSTypeAliasDeclaration(&'a TSTypeAliasDeclaration<'a>),
     TSTypeAnnotation(&'a TSTypeAnnotation<'a>),
-    TSTypeAssertion(&'a TSTypeAssertion<'a>),
+    TSLiteralType(&'a TSLiteralType<'a>),
+    TSConditionalType(&'a TSConditionalType<'a>),
+    TSUnionType(&'a TSUnionType<'a>),
+    TSIntersectionType(&'a TSIntersectionType<'a>),
+    TSTypeOperatorType(&'a TSTypeOperatorType<'a>),
+    TSArrayType(&'a TSArrayType<'a>),
+    TSIndexedAccessType(&'a TSIndexedAccessType<'a>),
+    TSTupleType(&'a TSTupleType<'a>),
+    TSNamedTupleMember(&'a TSNamedTupleMember<'a>),
+    TSOptionalType(&'a TSOptionalType<'a>),
+    TSRestType(&'a TSRestType<'a>),
+    TSAnyKeyword(&'a TSAnyKeyword),
+    TSStringKeyword(&'a TSStringKeyword),
+    TSBooleanKeyword(&'a TSBooleanKeyword),
+    TSNumberKeyword(&'a TSNumberKeyword),
+    TSNeverKeyword(&'a TSNeverKeyword),
+    TSUnknownKeyword(&'a TSUnknownKeyword),
+    TSNullKeyword(&'a TSNullKeyword),
+    TSUndefinedKeyword(&'a TSUndefinedKeyword),
+    TSVoidKeyword(&'a TSVoidKeyword),
+    TSSymbolKeyword(&'a TSSymbolKeyword),
+    TSThisKeyword(&'a TSThisKeyword),
+    TSObjectKeyword(&'a TSObjectKeyword),
+    TSBigIntKeyword(&'a TSBigIntKeyword),
+    TSTypeReference(&'a TSTypeReference<'a>),
+    TSQualifiedName(&'a TSQualifiedName<'a>),
+    TSTypeParameterInstantiation(&'a TSTypeParameterInstantiation<'a>),
     TSTypeParameter(&'a TSTypeParameter<'a>),
     TSTypeParameterDeclaration(&'a TSTypeParameterDeclaration<'a>),
-    TSTypeParameterInstantiation(&'a TSTypeParameterInstantiation<'a>),
-
+    TSTypeAliasDeclaration(&'a TSTypeAliasDeclaration<'a>),
+    TSClassImplements(&'a TSClassImplements<'a>),
+    TSInterfaceDeclaration(&'a TSInterfaceDeclaration<'a>),
+    TSInterfaceBody(&'a TSInterfaceBody<'a>),
     TSPropertySignature(&'a TSPropertySignature<'a>),
+    TSIndexSignature(&'a TSIndexSignature<'a>),
+    TSCallSignatureDeclaration(&'a TSCallSignatureDeclaration<'a>),
+    TSMethodSignature(&'a TSMethodSignature<'a>),
+    TSConstructSignatureDeclaration(&'a TSConstructSignatureDeclaration<'a>),
+    TSIndexSignatureName(&'a TSIndexSignatureName<'a>),
+    TSInterfaceHeritage(&'a TSInterfaceHeritage<'a>),
+    TSTypePredicate(&'a TSTypePredicate<'a>),
+    TSModuleDeclaration(&'a TSModuleDeclaration<'a>),
+    TSModuleBlock(&'a TSModuleBlock<'a>),
+    TSTypeLiteral(&'a TSTypeLiteral<'a>),
+    TSInferType(&'a TSInferType<'a>),
+    TSTypeQuery(&'a TSTypeQuery<'a>),
+    TSImportType(&'a TSImportType<'a>),
+    TSFunctionType(&'a TSFunctionType<'a>),
+    TSConstructorType(&'a TSConstructorType<'a>),
+    TSMappedType(&'a TSMappedType<'a>),
+    TSTemplateLiteralType(&'a TSTemplateLiteralType<'a>),
+    TSAsExpression(&'a TSAsExpression<'a>),
+    TSSatisfiesExpression(&'a TSSatisfiesExpression<'a>),
+    TSTypeAssertion(&'a TSTypeAssertion<'a>),
+    TSImportEqualsDeclaration(&'a TSImportEqualsDeclaration<'a>),
+    TSExternalModuleReference(&'a TSExternalModuleReference),
+    TSNonNullExpression(&'a TSNonNullExpression<'a>),
+    Decorator(&'a Decorator<'a>),
+    TSExportAssignment(&'a TSExportAssignment<'a>),
+    TSNamespaceExportDeclaration(&'a TSNamespaceExportDeclaration),
+    TSInstantiationExpression(&'a TSInstantiationExpression<'a>),
+    FlowOpaqueTypeDeclaration(&'a FlowOpaqueTypeDeclaration<'a>),
+    FlowDeclareModuleExports(&'a FlowDeclareModuleExports<'a>),
+    FlowExactObjectType(&'a FlowExactObjectType<'a>),
+    FlowExistentialType(&'a FlowExistentialType),
+    JSDocNullableType(&'a JSDocNullableType<'a>),
+    JSDocUnknownType(&'a JSDocUnknownType),
 }
 
-// SAFETY: The AST is part of the bump allocator,
-// it is our responsibility to never simultaneously mutate across threads.
-unsafe impl<'a> Send for AstKind<'a> {}
-unsafe impl<'a> Sync for AstKind<'a> {}
-
 impl<'a> AstKind<'a> {
-    #[must_use]
-    #[rustfmt::skip]
-    pub fn is_statement(self) -> bool {
-        self.is_iteration_statement()
-            || matches!(self, Self::BlockStatement(_) | Self::BreakStatement(_) | Self::ContinueStatement(_)
-                    | Self::DebuggerStatement(_) | Self::EmptyStatement(_) | Self::ExpressionStatement(_)
-                    | Self::LabeledStatement(_) | Self::ReturnStatement(_) | Self::SwitchStatement(_)
-                    | Self::ThrowStatement(_) | Self::TryStatement(_) | Self::WithStatement(_)
-                    | Self::IfStatement(_) | Self::VariableDeclaration(_))
-    }
-
-    #[must_use]
-    #[rustfmt::skip]
-    pub fn is_declaration(self) -> bool {
-        matches!(
-            self,
-            Self::ModuleDeclaration(_) | Self::TSEnumDeclaration(_) | Self::TSModuleDeclaration(_)
-                | Self::VariableDeclaration(_) | Self::TSInterfaceDeclaration(_)
-                | Self::TSTypeAliasDeclaration(_) | Self::TSImportEqualsDeclaration(_)
-        )
-    }
-
-    #[must_use]
-    #[rustfmt::skip]
-    pub fn is_iteration_statement(self) -> bool {
-        matches!(self, Self::DoWhileStatement(_) | Self::WhileStatement(_) | Self::ForInStatement(_)
-                | Self::ForOfStatement(_) | Self::ForStatement(_))
-    }
-
-    #[must_use]
-    #[rustfmt::skip]
-    pub fn is_identifier(self) -> bool {
-        matches!(self, Self::BindingIdentifier(_)
-                | Self::IdentifierReference(_)
-                | Self::LabelIdentifier(_))
-    }
-
+    /// Whether this is a type, a variant of [`TSType`]
     #[must_use]
     pub fn is_type(self) -> bool {
         matches!(
             self,
-            Self::TSIntersectionType(_)
+            Self::TSAnyKeyword(_)
+                | Self::TSBigIntKeyword(_)
+                | Self::TSBooleanKeyword(_)
+                | Self::TSNeverKeyword(_)
+                | Self::TSNullKeyword(_)
+                | Self::TSNumberKeyword(_)
+                | Self::TSObjectKeyword(_)
+                | Self::TSStringKeyword(_)
+                | Self::TSSymbolKeyword(_)
+                | Self::TSThisKeyword(_)
+                | Self::TSUndefinedKeyword(_)
+                | Self::TSUnknownKeyword(_)
+                | Self::TSVoidKeyword(_)
+                | Self::TSArrayType(_)
+                | Self::TSConditionalType(_)
+                | Self::TSConstructorType(_)
+                | Self::TSFunctionType(_)
+                | Self::TSImportType(_)
+                | Self::TSIndexedAccessType(_)
+                | Self::TSInferType(_)
+                | Self::TSIntersectionType(_)
                 | Self::TSLiteralType(_)
+                | Self::TSMappedType(_)
+                | Self::TSQualifiedName(_)
+                | Self::TSTemplateLiteralType(_)
+                | Self::TSTupleType(_)
+                | Self::TSTypeLiteral(_)
+                | Self::TSTypeOperatorType(_)
+                | Self::TSTypePredicate(_)
+                | Self::TSTypeQuery(_)
                 | Self::TSTypeReference(_)
-                | Self::TSMethodSignature(_)
+                | Self::TSUnionType(_)
+                | Self::JSDocNullableType(_)
+                | Self::JSDocUnknownType(_)
+                | Self::FlowExactObjectType(_)
+                | Self::FlowExistentialType(_)
         )
     }
 
     #[must_use]
-    pub fn is_literal(self) -> bool {
+    pub fn is_jsx(self) -> bool {
         matches!(
             self,
-            Self::NumberLiteral(_)
-                | Self::StringLiteral(_)
-                | Self::BooleanLiteral(_)
-                | Self::NullLiteral(_)
-                | Self::BigintLiteral(_)
-                | Self::RegExpLiteral(_)
-                | Self::TemplateLiteral(_)
+            Self::JSXElement(_)
+                | Self::JSXOpeningElement(_)
+                | Self::JSXClosingElement(_)
+                | Self::JSXFragment(_)
+                | Self::JSXOpeningFragment(_)
+                | Self::JSXClosingFragment(_)
+                | Self::JSXElementName(_)
+                | Self::JSXNamespacedName(_)
+                | Self::JSXMemberExpression(_)
+                | Self::JSXExpressionContainer(_)
+                | Self::JSXEmptyExpression(_)
+                | Self::JSXAttribute(_)
+                | Self::JSXSpreadAttribute(_)
+                | Self::JSXIdentifier(_)
+                | Self::JSXSpreadChild(_)
+                | Self::JSXText(_)
         )
     }
-
-    #[must_use]
-    pub fn is_function_like(self) -> bool {
-        matches!(self, Self::Function(_) | Self::ArrowExpression(_))
-    }
-
-    #[must_use]
-    pub fn identifier_name(self) -> Option<Atom> {
-        match self {
-            Self::BindingIdentifier(ident) => Some(ident.name.clone()),
-            Self::IdentifierReference(ident) => Some(ident.name.clone()),
-            Self::LabelIdentifier(ident) => Some(ident.name.clone()),
-            Self::IdentifierName(ident) => Some(ident.name.clone()),
-            _ => None,
-        }
-    }
-
-    #[must_use]
-    pub fn is_jsx(self) -> bool {
-        matches!(self, Self::JSXOpeningElement(_) | Self::JSXElementName(_))
-    }
 }
 
 impl<'a> GetSpan for AstKind<'a> {
@@ -236,136 +271,193 @@ impl<'a> GetSpan for AstKind<'a> {
     fn span(&self) -> Span {
         match self {
             Self::Root => Span::default(),
-
             Self::Program(x) => x.span,
-            Self::Directive(x) => x.span,
-
-            Self::BlockStatement(x) => x.span,
-            Self::BreakStatement(x) => x.span,
-            Self::ContinueStatement(x) => x.span,
-            Self::DebuggerStatement(x) => x.span,
-            Self::DoWhileStatement(x) => x.span,
-            Self::EmptyStatement(x) => x.span,
-            Self::ExpressionStatement(x) => x.span,
-            Self::ForInStatement(x) => x.span,
-            Self::ForOfStatement(x) => x.span,
-            Self::ForStatement(x) => x.span,
-            Self::ForStatementInit(x) => x.span(),
-            Self::IfStatement(x) => x.span,
-            Self::LabeledStatement(x) => x.span,
-            Self::ReturnStatement(x) => x.span,
-            Self::SwitchStatement(x) => x.span,
-            Self::ThrowStatement(x) => x.span,
-            Self::TryStatement(x) => x.span,
-            Self::WhileStatement(x) => x.span,
-            Self::WithStatement(x) => x.span,
-
-            Self::SwitchCase(x) => x.span,
-            Self::CatchClause(x) => x.span,
-            Self::FinallyClause(x) => x.span,
-
-            Self::VariableDeclaration(x) => x.span,
-            Self::VariableDeclarator(x) => x.span,
-
             Self::IdentifierName(x) => x.span,
             Self::IdentifierReference(x) => x.span,
             Self::BindingIdentifier(x) => x.span,
             Self::LabelIdentifier(x) => x.span,
-            Self::PrivateIdentifier(x) => x.span,
-
-            Self::NumberLiteral(x) => x.span,
-            Self::StringLiteral(x) => x.span,
-            Self::BooleanLiteral(x) => x.span,
-            Self::NullLiteral(x) => x.span,
-            Self::BigintLiteral(x) => x.span,
-            Self::RegExpLiteral(x) => x.span,
-            Self::TemplateLiteral(x) => x.span,
-
-            Self::MetaProperty(x) => x.span,
-            Self::Super(x) => x.span,
-
+            Self::ThisExpression(x) => x.span,
             Self::ArrayExpression(x) => x.span,
-            Self::ArrowExpression(x) => x.span,
-            Self::AssignmentExpression(x) => x.span,
-            Self::AwaitExpression(x) => x.span,
-            Self::BinaryExpression(x) => x.span,
-            Self::CallExpression(x) => x.span,
-            Self::ConditionalExpression(x) => x.span,
-            Self::LogicalExpression(x) => x.span,
-            Self::MemberExpression(x) => x.span(),
-            Self::NewExpression(x) => x.span,
             Self::ObjectExpression(x) => x.span,
-            Self::ParenthesizedExpression(x) => x.span,
-            Self::SequenceExpression(x) => x.span,
-            Self::TaggedTemplateExpression(x) => x.span,
-            Self::ThisExpression(x) => x.span,
-            Self::UnaryExpression(x) => x.span,
-            Self::UpdateExpression(x) => x.span,
-            Self::YieldExpression(x) => x.span,
-
             Self::Property(x) => x.span,
             Self::PropertyKey(x) => x.span(),
             Self::PropertyValue(x) => x.span(),
+            Self::TemplateLiteral(x) => x.span,
+            Self::TaggedTemplateExpression(x) => x.span,
+            Self::TemplateElement(x) => x.span,
+            Self::MemberExpression(x) => x.span(),
+            Self::ComputedMemberExpression(x) => x.span,
+            Self::StaticMemberExpression(x) => x.span,
+            Self::PrivateFieldExpression(x) => x.span,
+            Self::CallExpression(x) => x.span,
+            Self::NewExpression(x) => x.span,
+            Self::MetaProperty(x) => x.span,
+            Self::SpreadElement(x) => x.span,
             Self::Argument(x) => x.span(),
+            Self::UpdateExpression(x) => x.span,
+            Self::UnaryExpression(x) => x.span,
+            Self::BinaryExpression(x) => x.span,
+            Self::PrivateInExpression(x) => x.span,
+            Self::LogicalExpression(x) => x.span,
+            Self::ConditionalExpression(x) => x.span,
+            Self::AssignmentExpression(x) => x.span,
             Self::AssignmentTarget(x) => x.span(),
             Self::SimpleAssignmentTarget(x) => x.span(),
+            Self::ArrayAssignmentTarget(x) => x.span,
+            Self::ObjectAssignmentTarget(x) => x.span,
             Self::AssignmentTargetWithDefault(x) => x.span,
-            Self::SpreadElement(x) => x.span,
+            Self::AssignmentTargetPropertyIdentifier(x) => x.span,
+            Self::AssignmentTargetPropertyProperty(x) => x.span,
+            Self::SequenceExpression(x) => x.span,
+            Self::Super(x) => x.span,
+            Self::AwaitExpression(x) => x.span,
+            Self::ChainExpression(x) => x.span,
+            Self::ParenthesizedExpression(x) => x.span,
+            Self::Directive(x) => x.span,
+            Self::BlockStatement(x) => x.span,
+            Self::VariableDeclaration(x) => x.span,
+            Self::VariableDeclarator(x) => x.span,
+            Self::EmptyStatement(x) => x.span,
+            Self::ExpressionStatement(x) => x.span,
+            Self::IfStatement(x) => x.span,
+            Self::DoWhileStatement(x) => x.span,
+            Self::WhileStatement(x) => x.span,
+            Self::ForStatement(x) => x.span,
+            Self::ForStatementInit(x) => x.span(),
+            Self::ForInStatement(x) => x.span,
+            Self::ForOfStatement(x) => x.span,
+            Self::ContinueStatement(x) => x.span,
+            Self::BreakStatement(x) => x.span,
+            Self::ReturnStatement(x) => x.span,
+            Self::WithStatement(x) => x.span,
+            Self::SwitchStatement(x) => x.span,
+            Self::SwitchCase(x) => x.span,
+            Self::LabeledStatement(x) => x.span,
+            Self::ThrowStatement(x) => x.span,
+            Self::TryStatement(x) => x.span,
+            Self::FinallyClause(x) => x.span,
+            Self::CatchClause(x) => x.span,
+            Self::DebuggerStatement(x) => x.span,
+            Self::InvalidStatement(x) => x.span,
+            Self::InvalidExpression(x) => x.span,
+            Self::BindingPattern(x) => x.span(),
+            Self::AssignmentPattern(x) => x.span,
+            Self::ObjectPattern(x) => x.span,
+            Self::ArrayPattern(x) => x.span,
             Self::RestElement(x) => x.span,
-
             Self::Function(x) => x.span,
-            Self::FunctionBody(x) => x.span,
             Self::FormalParameters(x) => x.span,
             Self::FormalParameter(x) => x.span,
-
+            Self::FunctionBody(x) => x.span,
+            Self::ArrowExpression(x) => x.span,
+            Self::YieldExpression(x) => x.span,
             Self::Class(x) => x.span,
             Self::ClassHeritage(x) => x.span(),
-            Self::StaticBlock(x) => x.span,
-            Self::PropertyDefinition(x) => x.span,
+            Self::ClassBody(x) => x.span,
             Self::MethodDefinition(x) => x.span,
-
-            Self::ArrayPattern(x) => x.span,
-            Self::ObjectPattern(x) => x.span,
-            Self::AssignmentPattern(x) => x.span,
-
-            Self::Decorator(x) => x.span,
-
+            Self::PropertyDefinition(x) => x.span,
+            Self::PrivateIdentifier(x) => x.span,
+            Self::StaticBlock(x) => x.span,
             Self::ModuleDeclaration(x) => x.span,
-
+            Self::AccessorProperty(x) => x.span,
+            Self::ImportExpression(x) => x.span,
+            Self::ImportSpecifier(x) => x.span,
+            Self::ImportDefaultSpecifier(x) => x.span,
+            Self::ImportNamespaceSpecifier(x) => x.span,
+            Self::ImportAttribute(x) => x.span,
+            Self::ExportSpecifier(x) => x.span,
+            Self::BooleanLiteral(x) => x.span,
+            Self::NullLiteral(x) => x.span,
+            Self::NumberLiteral(x) => x.span,
+            Self::BigintLiteral(x) => x.span,
+            Self::RegExpLiteral(x) => x.span,
+            Self::StringLiteral(x) => x.span,
+            Self::JSXElement(x) => x.span,
             Self::JSXOpeningElement(x) => x.span,
+            Self::JSXClosingElement(x) => x.span,
+            Self::JSXFragment(x) => x.span,
+            Self::JSXOpeningFragment(x) => x.span,
+            Self::JSXClosingFragment(x) => x.span,
             Self::JSXElementName(x) => x.span(),
-
-            Self::TSModuleBlock(x) => x.span,
-
-            Self::TSAnyKeyword(x) => x.span,
-            Self::TSIntersectionType(x) => x.span,
-            Self::TSLiteralType(x) => x.span,
-            Self::TSMethodSignature(x) => x.span,
-            Self::TSNullKeyword(x) => x.span,
-            Self::TSTypeLiteral(x) => x.span,
-            Self::TSTypeReference(x) => x.span,
-            Self::TSUnionType(x) => x.span,
-            Self::TSVoidKeyword(x) => x.span,
-
-            Self::TSIndexedAccessType(x) => x.span,
-
-            Self::TSAsExpression(x) => x.span,
-            Self::TSSatisfiesExpression(x) => x.span,
-            Self::TSNonNullExpression(x) => x.span,
-
+            Self::JSXNamespacedName(x) => x.span,
+            Self::JSXMemberExpression(x) => x.span,
+            Self::JSXExpressionContainer(x) => x.span,
+            Self::JSXEmptyExpression(x) => x.span,
+            Self::JSXAttribute(x) => x.span,
+            Self::JSXSpreadAttribute(x) => x.span,
+            Self::JSXIdentifier(x) => x.span,
+            Self::JSXSpreadChild(x) => x.span,
+            Self::JSXText(x) => x.span,
             Self::TSEnumDeclaration(x) => x.span,
             Self::TSEnumMember(x) => x.span,
-            Self::TSImportEqualsDeclaration(x) => x.span,
-            Self::TSInterfaceDeclaration(x) => x.span,
-            Self::TSModuleDeclaration(x) => x.span,
-            Self::TSTypeAliasDeclaration(x) => x.span,
             Self::TSTypeAnnotation(x) => x.span,
-            Self::TSTypeAssertion(x) => x.span,
+            Self::TSLiteralType(x) => x.span,
+            Self::TSConditionalType(x) => x.span,
+            Self::TSUnionType(x) => x.span,
+            Self::TSIntersectionType(x) => x.span,
+            Self::TSTypeOperatorType(x) => x.span,
+            Self::TSArrayType(x) => x.span,
+            Self::TSIndexedAccessType(x) => x.span,
+            Self::TSTupleType(x) => x.span,
+            Self::TSNamedTupleMember(x) => x.span,
+            Self::TSOptionalType(x) => x.span,
+            Self::TSRestType(x) => x.span,
+            Self::TSAnyKeyword(x) => x.span,
+            Self::TSStringKeyword(x) => x.span,
+            Self::TSBooleanKeyword(x) => x.span,
+            Self::TSNumberKeyword(x) => x.span,
+            Self::TSNeverKeyword(x) => x.span,
+            Self::TSUnknownKeyword(x) => x.span,
+            Self::TSNullKeyword(x) => x.span,
+            Self::TSUndefinedKeyword(x) => x.span,
+            Self::TSVoidKeyword(x) => x.span,
+            Self::TSSymbolKeyword(x) => x.span,
+            Self::TSThisKeyword(x) => x.span,
+            Self::TSObjectKeyword(x) => x.span,
+            Self::TSBigIntKeyword(x) => x.span,
+            Self::TSTypeReference(x) => x.span,
+            Self::TSQualifiedName(x) => x.span,
+            Self::TSTypeParameterInstantiation(x) => x.span,
             Self::TSTypeParameter(x) => x.span,
             Self::TSTypeParameterDeclaration(x) => x.span,
-            Self::TSTypeParameterInstantiation(x) => x.span,
-
+            Self::TSTypeAliasDeclaration(x) => x.span,
+            Self::TSClassImplements(x) => x.span,
+            Self::TSInterfaceDeclaration(x) => x.span,
+            Self::TSInterfaceBody(x) => x.span,
             Self::TSPropertySignature(x) => x.span,
+            Self::TSIndexSignature(x) => x.span,
+            Self::TSCallSignatureDeclaration(x) => x.span,
+            Self::TSMethodSignature(x) => x.span,
+            Self::TSConstructSignatureDeclaration(x) => x.span,
+            Self::TSIndexSignatureName(x) => x.span,
+            Self::TSInterfaceHeritage(x) => x.span,
+            Self::TSTypePredicate(x) => x.span,
+            Self::TSModuleDeclaration(x) => x.span,
+            Self::TSModuleBlock(x) => x.span,
+            Self::TSTypeLiteral(x) => x.span,
+            Self::TSInferType(x) => x.span,
+            Self::TSTypeQuery(x) => x.span,
+            Self::TSImportType(x) => x.span,
+            Self::TSFunctionType(x) => x.span,
+            Self::TSConstructorType(x) => x.span,
+            Self::TSMappedType(x) => x.span,
+            Self::TSTemplateLiteralType(x) => x.span,
+            Self::TSAsExpression(x) => x.span,
+            Self::TSSatisfiesExpression(x) => x.span,
+            Self::TSTypeAssertion(x) => x.span,
+            Self::TSImportEqualsDeclaration(x) => x.span,
+            Self::TSExternalModuleReference(x) => x.span,
+            Self::TSNonNullExpression(x) => x.span,
+            Self::Decorator(x) => x.span,
+            Self::TSExportAssignment(x) => x.span,
+            Self::TSNamespaceExportDeclaration(x) => x.span,
+            Self::TSInstantiationExpression(x) => x.span,
+            Self::FlowOpaqueTypeDeclaration(x) => x.span,
+            Self::FlowDeclareModuleExports(x) => x.span,
+            Self::FlowExactObjectType(x) => x.span,
+            Self::FlowExistentialType(x) => x.span,
+            Self::JSDocNullableType(x) => x.span,
+            Self::JSDocUnknownType(x) => x.span,
         }
     }
 }
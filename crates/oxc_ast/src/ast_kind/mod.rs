@@ -0,0 +1,79 @@
+mod kind;
+
+pub use self::kind::AstKind;
+use crate::Atom;
+
+// SAFETY: The AST is part of the bump allocator,
+// it is our responsibility to never simultaneously mutate across threads.
+unsafe impl<'a> Send for AstKind<'a> {}
+unsafe impl<'a> Sync for AstKind<'a> {}
+
+impl<'a> AstKind<'a> {
+    #[must_use]
+    #[rustfmt::skip]
+    pub fn is_statement(self) -> bool {
+        self.is_iteration_statement()
+            || matches!(self, Self::BlockStatement(_) | Self::BreakStatement(_) | Self::ContinueStatement(_)
+                    | Self::DebuggerStatement(_) | Self::EmptyStatement(_) | Self::ExpressionStatement(_)
+                    | Self::LabeledStatement(_) | Self::ReturnStatement(_) | Self::SwitchStatement(_)
+                    | Self::ThrowStatement(_) | Self::TryStatement(_) | Self::WithStatement(_)
+                    | Self::IfStatement(_) | Self::VariableDeclaration(_) | Self::InvalidStatement(_))
+    }
+
+    #[must_use]
+    #[rustfmt::skip]
+    pub fn is_declaration(self) -> bool {
+        matches!(
+            self,
+            Self::ModuleDeclaration(_) | Self::TSEnumDeclaration(_) | Self::TSModuleDeclaration(_)
+                | Self::VariableDeclaration(_) | Self::TSInterfaceDeclaration(_)
+                | Self::TSTypeAliasDeclaration(_) | Self::TSImportEqualsDeclaration(_)
+                | Self::FlowOpaqueTypeDeclaration(_) | Self::FlowDeclareModuleExports(_)
+        )
+    }
+
+    #[must_use]
+    #[rustfmt::skip]
+    pub fn is_iteration_statement(self) -> bool {
+        matches!(self, Self::DoWhileStatement(_) | Self::WhileStatement(_) | Self::ForInStatement(_)
+                | Self::ForOfStatement(_) | Self::ForStatement(_))
+    }
+
+    #[must_use]
+    #[rustfmt::skip]
+    pub fn is_identifier(self) -> bool {
+        matches!(self, Self::BindingIdentifier(_)
+                | Self::IdentifierReference(_)
+                | Self::LabelIdentifier(_))
+    }
+
+    #[must_use]
+    pub fn is_literal(self) -> bool {
+        matches!(
+            self,
+            Self::NumberLiteral(_)
+                | Self::StringLiteral(_)
+                | Self::BooleanLiteral(_)
+                | Self::NullLiteral(_)
+                | Self::BigintLiteral(_)
+                | Self::RegExpLiteral(_)
+                | Self::TemplateLiteral(_)
+        )
+    }
+
+    #[must_use]
+    pub fn is_function_like(self) -> bool {
+        matches!(self, Self::Function(_) | Self::ArrowExpression(_))
+    }
+
+    #[must_use]
+    pub fn identifier_name(self) -> Option<Atom> {
+        match self {
+            Self::BindingIdentifier(ident) => Some(ident.name.clone()),
+            Self::IdentifierReference(ident) => Some(ident.name.clone()),
+            Self::LabelIdentifier(ident) => Some(ident.name.clone()),
+            Self::IdentifierName(ident) => Some(ident.name.clone()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Deep cloning of AST nodes into another allocator, see [`CloneIn`]
+
+use num_bigint::BigUint;
+use ordered_float::NotNan;
+use oxc_allocator::{Allocator, Box, Vec};
+
+use crate::{ast::RegExpFlags, Atom, SourceType, Span};
+
+/// Deep clone a node into `allocator`.
+///
+/// AST nodes cannot implement [`Clone`] because their [`Box`]es and [`Vec`]s are allocated in an arena,
+/// `clone_in` allocates the whole subtree again in `allocator`,
+/// which can be the same allocator or the allocator of another AST, e.g. to copy nodes between files.
+///
+/// Implemented for all AST nodes with `#[derive(CloneIn)]`.
+pub trait CloneIn<'new_alloc>: Sized {
+    /// The type of the clone, the same type with the lifetime of `allocator`
+    type Cloned: 'new_alloc;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned;
+}
+
+impl<'old_alloc, 'new_alloc, T: CloneIn<'new_alloc>> CloneIn<'new_alloc> for Box<'old_alloc, T> {
+    type Cloned = Box<'new_alloc, T::Cloned>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        Box(allocator.alloc(self.0.clone_in(allocator)))
+    }
+}
+
+impl<'old_alloc, 'new_alloc, T: CloneIn<'new_alloc>> CloneIn<'new_alloc> for Vec<'old_alloc, T> {
+    type Cloned = Vec<'new_alloc, T::Cloned>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        Vec::from_iter_in(self.iter().map(|item| item.clone_in(allocator)), allocator)
+    }
+}
+
+impl<'alloc, T: CloneIn<'alloc>> CloneIn<'alloc> for Option<T> {
+    type Cloned = Option<T::Cloned>;
+
+    fn clone_in(&self, allocator: &'alloc Allocator) -> Self::Cloned {
+        self.as_ref().map(|value| value.clone_in(allocator))
+    }
+}
+
+impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for &'old_alloc str {
+    type Cloned = &'new_alloc str;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        allocator.alloc_str(self)
+    }
+}
+
+/// Types without references to an allocator are cloned with [`Clone`]
+macro_rules! impl_clone_in {
+    ($($ty:ty),*) => {
+        $(
+            impl<'alloc> CloneIn<'alloc> for $ty {
+                type Cloned = Self;
+
+                #[inline]
+                fn clone_in(&self, _: &'alloc Allocator) -> Self {
+                    self.clone()
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_in!(bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, char);
+impl_clone_in!(Atom, BigUint, NotNan<f64>, Span, SourceType, RegExpFlags);
@@ -0,0 +1,315 @@
+//! Evaluate expressions to JavaScript values at compile time
+//!
+//! Shared by constant folding, dead branch elimination and the constant condition lint rules.
+
+mod value;
+
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+pub use self::value::{number_to_string, string_to_number, to_int32, to_uint32, ConstantValue};
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, Atom};
+
+/// Evaluate expressions to a [`ConstantValue`].
+///
+/// The value of an expression is only computed when the expression has no side effects,
+/// so an expression with a value can be replaced by its value.
+///
+/// Identifier references are resolved by [`resolve_binding`](ConstantEvaluation::resolve_binding),
+/// implementors with scope information can override it to look up `const` bindings.
+pub trait ConstantEvaluation<'a> {
+    /// Value of the binding `ident` refers to.
+    ///
+    /// The default implementation only knows the globals `undefined`, `NaN` and `Infinity`,
+    /// and assumes they are not shadowed.
+    fn resolve_binding(&self, ident: &IdentifierReference) -> Option<ConstantValue> {
+        ConstantValue::global(&ident.name)
+    }
+
+    /// Value of `expr`, `None` when it is unknown or `expr` may have side effects.
+    fn eval_expression(&self, expr: &Expression<'a>) -> Option<ConstantValue> {
+        match expr {
+            Expression::NumberLiteral(lit) => Some(ConstantValue::Number(*lit.value)),
+            Expression::BigintLiteral(lit) => {
+                Some(ConstantValue::BigInt(BigInt::from(lit.value.clone())))
+            }
+            Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.clone())),
+            Expression::BooleanLiteral(lit) => Some(ConstantValue::Boolean(lit.value)),
+            Expression::NullLiteral(_) => Some(ConstantValue::Null),
+            Expression::TemplateLiteral(lit) => self.eval_template_literal(lit),
+            Expression::Identifier(ident) => self.resolve_binding(ident),
+            Expression::ParenthesizedExpression(expr) => self.eval_expression(&expr.expression),
+            Expression::SequenceExpression(expr) => {
+                let mut value = None;
+                for expr in &expr.expressions {
+                    value = Some(self.eval_expression(expr)?);
+                }
+                value
+            }
+            Expression::UnaryExpression(expr) => self.eval_unary_expression(expr),
+            // `a + b + c` is nested as `(a + b) + c`, the left operands are evaluated in a loop
+            // because long chains such as generated string concatenations would overflow the stack
+            Expression::BinaryExpression(_) | Expression::LogicalExpression(_) => {
+                let mut chain = vec![];
+                let mut left = expr;
+                while let Some(next) = binary_left_operand(left) {
+                    chain.push(left);
+                    left = next;
+                }
+                let mut value = self.eval_expression(left)?;
+                for expr in chain.into_iter().rev() {
+                    value = self.eval_with_left(expr, value)?;
+                }
+                Some(value)
+            }
+            Expression::ConditionalExpression(expr) => {
+                if self.eval_expression(&expr.test)?.to_boolean() {
+                    self.eval_expression(&expr.consequent)
+                } else {
+                    self.eval_expression(&expr.alternate)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether `expr` is truthy, `None` when it is unknown.
+    ///
+    /// Unlike [`eval_expression`](ConstantEvaluation::eval_expression), `expr` may have side effects,
+    /// e.g. `[f()]` is truthy because all objects are truthy.
+    fn eval_to_boolean(&self, expr: &Expression<'a>) -> Option<bool> {
+        match expr {
+            Expression::ArrayExpression(_)
+            | Expression::ObjectExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::NewExpression(_) => Some(true),
+            Expression::ParenthesizedExpression(expr) => self.eval_to_boolean(&expr.expression),
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.last().and_then(|expr| self.eval_to_boolean(expr))
+            }
+            Expression::UnaryExpression(unary) => match unary.operator {
+                UnaryOperator::LogicalNot => self.eval_to_boolean(&unary.argument).map(|b| !b),
+                UnaryOperator::Void => Some(false),
+                UnaryOperator::Typeof => Some(true),
+                _ => self.eval_unary_expression(unary).map(|value| value.to_boolean()),
+            },
+            Expression::AssignmentExpression(assign)
+                if assign.operator == AssignmentOperator::Assign =>
+            {
+                self.eval_to_boolean(&assign.right)
+            }
+            // The left operands of `a || b || c` are evaluated in a loop, see `eval_expression`
+            Expression::LogicalExpression(_) => {
+                let mut chain = vec![];
+                let mut left = expr;
+                while let Expression::LogicalExpression(logical) = left {
+                    chain.push((left, logical));
+                    left = &logical.left;
+                }
+                let mut truthy = self.eval_to_boolean(left);
+                let mut value = self.eval_expression(left);
+                for (expr, logical) in chain.into_iter().rev() {
+                    value = value.and_then(|value| self.eval_with_left(expr, value));
+                    let right = || self.eval_to_boolean(&logical.right);
+                    truthy = match (logical.operator, truthy) {
+                        (LogicalOperator::And, Some(false)) => Some(false),
+                        (LogicalOperator::Or, Some(true)) => Some(true),
+                        (LogicalOperator::And | LogicalOperator::Or, Some(_)) => right(),
+                        // `a && false` is falsy and `a || true` is truthy whatever `a` is
+                        (LogicalOperator::And, None) => right().filter(|b| !b),
+                        (LogicalOperator::Or, None) => right().filter(|b| *b),
+                        (LogicalOperator::Coalesce, _) => {
+                            value.as_ref().map(ConstantValue::to_boolean)
+                        }
+                    };
+                }
+                truthy
+            }
+            _ => self.eval_expression(expr).map(|value| value.to_boolean()),
+        }
+    }
+
+    /// Value of the binary or logical expression `expr` whose left operand has the value `left`,
+    /// for evaluating the left operands of `a + b + c` in a loop.
+    fn eval_with_left(&self, expr: &Expression<'a>, left: ConstantValue) -> Option<ConstantValue> {
+        match expr {
+            Expression::BinaryExpression(expr) => {
+                let right = self.eval_expression(&expr.right)?;
+                eval_binary_operation(expr.operator, &left, &right)
+            }
+            Expression::LogicalExpression(expr) => {
+                let short_circuit = match expr.operator {
+                    LogicalOperator::And => !left.to_boolean(),
+                    LogicalOperator::Or => left.to_boolean(),
+                    LogicalOperator::Coalesce => {
+                        !matches!(left, ConstantValue::Null | ConstantValue::Undefined)
+                    }
+                };
+                if short_circuit { Some(left) } else { self.eval_expression(&expr.right) }
+            }
+            _ => None,
+        }
+    }
+
+    /// Value of a template literal without tag, the expressions are converted with `ToString`
+    fn eval_template_literal(&self, lit: &TemplateLiteral<'a>) -> Option<ConstantValue> {
+        let mut value = String::new();
+        for (i, quasi) in lit.quasis.iter().enumerate() {
+            value.push_str(quasi.value.cooked.as_ref()?);
+            if let Some(expr) = lit.expressions.get(i) {
+                value.push_str(&self.eval_expression(expr)?.to_js_string());
+            }
+        }
+        Some(ConstantValue::String(Atom::from(value)))
+    }
+
+    fn eval_unary_expression(&self, expr: &UnaryExpression<'a>) -> Option<ConstantValue> {
+        match expr.operator {
+            UnaryOperator::Typeof => {
+                let type_of = match &expr.argument {
+                    Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+                        "function"
+                    }
+                    argument => self.eval_expression(argument)?.type_of(),
+                };
+                Some(ConstantValue::String(Atom::from(type_of)))
+            }
+            UnaryOperator::Void => {
+                self.eval_expression(&expr.argument).map(|_| ConstantValue::Undefined)
+            }
+            UnaryOperator::LogicalNot => {
+                Some(ConstantValue::Boolean(!self.eval_expression(&expr.argument)?.to_boolean()))
+            }
+            UnaryOperator::UnaryNegation => match self.eval_expression(&expr.argument)? {
+                ConstantValue::BigInt(n) => Some(ConstantValue::BigInt(-n)),
+                value => Some(ConstantValue::Number(-value.to_number()?)),
+            },
+            UnaryOperator::UnaryPlus => {
+                Some(ConstantValue::Number(self.eval_expression(&expr.argument)?.to_number()?))
+            }
+            UnaryOperator::BitwiseNot => match self.eval_expression(&expr.argument)? {
+                ConstantValue::BigInt(n) => Some(ConstantValue::BigInt(!n)),
+                value => Some(ConstantValue::Number(f64::from(!to_int32(value.to_number()?)))),
+            },
+            UnaryOperator::Delete => None,
+        }
+    }
+}
+
+/// The left operand of a binary or logical expression
+fn binary_left_operand<'e, 'a>(expr: &'e Expression<'a>) -> Option<&'e Expression<'a>> {
+    match expr {
+        Expression::BinaryExpression(expr) => Some(&expr.left),
+        Expression::LogicalExpression(expr) => Some(&expr.left),
+        _ => None,
+    }
+}
+
+/// Value of `left operator right`, `None` when it is not supported or throws
+fn eval_binary_operation(
+    operator: BinaryOperator,
+    left: &ConstantValue,
+    right: &ConstantValue,
+) -> Option<ConstantValue> {
+    let boolean = |b: bool| Some(ConstantValue::Boolean(b));
+    match operator {
+        BinaryOperator::StrictEquality => boolean(left.strict_equals(right)),
+        BinaryOperator::StrictInequality => boolean(!left.strict_equals(right)),
+        BinaryOperator::Equality => boolean(left.loose_equals(right)?),
+        BinaryOperator::Inequality => boolean(!left.loose_equals(right)?),
+        BinaryOperator::LessThan => boolean(left.compare(right)? == Some(Ordering::Less)),
+        BinaryOperator::GreaterThan => boolean(left.compare(right)? == Some(Ordering::Greater)),
+        BinaryOperator::LessEqualThan => {
+            boolean(matches!(left.compare(right)?, Some(Ordering::Less | Ordering::Equal)))
+        }
+        BinaryOperator::GreaterEqualThan => {
+            boolean(matches!(left.compare(right)?, Some(Ordering::Greater | Ordering::Equal)))
+        }
+        BinaryOperator::Addition => match (left, right) {
+            (ConstantValue::String(_), _) | (_, ConstantValue::String(_)) => {
+                let mut value = left.to_js_string();
+                value.push_str(&right.to_js_string());
+                Some(ConstantValue::String(value))
+            }
+            (ConstantValue::BigInt(a), ConstantValue::BigInt(b)) => {
+                Some(ConstantValue::BigInt(a + b))
+            }
+            _ => Some(ConstantValue::Number(left.to_number()? + right.to_number()?)),
+        },
+        BinaryOperator::Subtraction
+        | BinaryOperator::Multiplication
+        | BinaryOperator::Division
+        | BinaryOperator::Remainder
+        | BinaryOperator::Exponential
+        | BinaryOperator::BitwiseAnd
+        | BinaryOperator::BitwiseOR
+        | BinaryOperator::BitwiseXOR
+        | BinaryOperator::ShiftLeft
+        | BinaryOperator::ShiftRight
+        | BinaryOperator::ShiftRightZeroFill => {
+            if let (ConstantValue::BigInt(a), ConstantValue::BigInt(b)) = (left, right) {
+                return eval_bigint_operation(operator, a, b).map(ConstantValue::BigInt);
+            }
+            // Mixing a BigInt and a Number throws
+            let (a, b) = (left.to_number()?, right.to_number()?);
+            eval_number_operation(operator, a, b).map(ConstantValue::Number)
+        }
+        BinaryOperator::In | BinaryOperator::Instanceof => None,
+    }
+}
+
+/// [Number operations](https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate)
+#[allow(clippy::float_cmp)]
+fn eval_number_operation(operator: BinaryOperator, a: f64, b: f64) -> Option<f64> {
+    let shift = || to_uint32(b) & 31;
+    let value = match operator {
+        BinaryOperator::Subtraction => a - b,
+        BinaryOperator::Multiplication => a * b,
+        BinaryOperator::Division => a / b,
+        // `%` of `f64` truncates like JavaScript, the sign of the result is the sign of `a`
+        BinaryOperator::Remainder => a % b,
+        // `x ** NaN` and `(±1) ** ±Infinity` are `NaN` in JavaScript, `1 ** NaN` is `1` in Rust
+        BinaryOperator::Exponential if b.is_nan() || (a.abs() == 1.0 && b.is_infinite()) => {
+            f64::NAN
+        }
+        BinaryOperator::Exponential => a.powf(b),
+        BinaryOperator::BitwiseAnd => f64::from(to_int32(a) & to_int32(b)),
+        BinaryOperator::BitwiseOR => f64::from(to_int32(a) | to_int32(b)),
+        BinaryOperator::BitwiseXOR => f64::from(to_int32(a) ^ to_int32(b)),
+        BinaryOperator::ShiftLeft => f64::from(to_int32(a).wrapping_shl(shift())),
+        BinaryOperator::ShiftRight => f64::from(to_int32(a) >> shift()),
+        BinaryOperator::ShiftRightZeroFill => f64::from(to_uint32(a) >> shift()),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// [BigInt operations](https://tc39.es/ecma262/#sec-numeric-types-bigint-exponentiate),
+/// `None` when the operation throws or the result would be too large.
+fn eval_bigint_operation(operator: BinaryOperator, a: &BigInt, b: &BigInt) -> Option<BigInt> {
+    let value = match operator {
+        BinaryOperator::Subtraction => a - b,
+        BinaryOperator::Multiplication => a * b,
+        BinaryOperator::Division if b.is_zero() => return None,
+        // Truncating division, like JavaScript
+        BinaryOperator::Division => a / b,
+        BinaryOperator::Remainder if b.is_zero() => return None,
+        BinaryOperator::Remainder => a % b,
+        BinaryOperator::Exponential => {
+            // Negative exponents throw, keep the result reasonably small
+            let exponent = u32::try_from(b).ok().filter(|exponent| *exponent <= 1024)?;
+            a.pow(exponent)
+        }
+        BinaryOperator::BitwiseAnd => a & b,
+        BinaryOperator::BitwiseOR => a | b,
+        BinaryOperator::BitwiseXOR => a ^ b,
+        _ => return None,
+    };
+    Some(value)
+}
@@ -0,0 +1,240 @@
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Zero};
+
+use crate::Atom;
+
+/// A JavaScript value known at compile time
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Number(f64),
+    BigInt(BigInt),
+    String(Atom),
+    Boolean(bool),
+    Null,
+    Undefined,
+}
+
+impl ConstantValue {
+    /// Value of the global `undefined`, `NaN` or `Infinity` named `name`
+    #[must_use]
+    pub fn global(name: &str) -> Option<Self> {
+        match name {
+            "undefined" => Some(Self::Undefined),
+            "NaN" => Some(Self::Number(f64::NAN)),
+            "Infinity" => Some(Self::Number(f64::INFINITY)),
+            _ => None,
+        }
+    }
+
+    /// [`typeof`](https://tc39.es/ecma262/#sec-typeof-operator) of the value
+    #[must_use]
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "number",
+            Self::BigInt(_) => "bigint",
+            Self::String(_) => "string",
+            Self::Boolean(_) => "boolean",
+            Self::Null => "object",
+            Self::Undefined => "undefined",
+        }
+    }
+
+    /// [ToBoolean](https://tc39.es/ecma262/#sec-toboolean)
+    #[must_use]
+    pub fn to_boolean(&self) -> bool {
+        match self {
+            Self::Number(n) => !n.is_nan() && *n != 0.0,
+            Self::BigInt(n) => !n.is_zero(),
+            Self::String(s) => !s.is_empty(),
+            Self::Boolean(b) => *b,
+            Self::Null | Self::Undefined => false,
+        }
+    }
+
+    /// [ToNumber](https://tc39.es/ecma262/#sec-tonumber)
+    /// `None` for a `BigInt`, which throws a `TypeError`.
+    #[must_use]
+    pub fn to_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::BigInt(_) => None,
+            Self::String(s) => Some(string_to_number(s)),
+            Self::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+            Self::Null => Some(0.0),
+            Self::Undefined => Some(f64::NAN),
+        }
+    }
+
+    /// [ToString](https://tc39.es/ecma262/#sec-tostring)
+    #[must_use]
+    pub fn to_js_string(&self) -> Atom {
+        match self {
+            Self::Number(n) => number_to_string(*n),
+            Self::BigInt(n) => Atom::from(n.to_string()),
+            Self::String(s) => s.clone(),
+            Self::Boolean(b) => Atom::from(if *b { "true" } else { "false" }),
+            Self::Null => Atom::from("null"),
+            Self::Undefined => Atom::from("undefined"),
+        }
+    }
+
+    /// [IsStrictlyEqual](https://tc39.es/ecma262/#sec-isstrictlyequal)
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn strict_equals(&self, other: &Self) -> bool {
+        match (self, other) {
+            // `NaN !== NaN` and `0 === -0`
+            (Self::Number(a), Self::Number(b)) => a == b,
+            _ => self == other,
+        }
+    }
+
+    /// [IsLooselyEqual](https://tc39.es/ecma262/#sec-islooselyequal),
+    /// `None` when the comparison is not supported.
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn loose_equals(&self, other: &Self) -> Option<bool> {
+        match (self, other) {
+            (Self::Null | Self::Undefined, Self::Null | Self::Undefined) => Some(true),
+            (Self::Null | Self::Undefined, _) | (_, Self::Null | Self::Undefined) => Some(false),
+            (Self::Number(_), Self::String(_)) | (Self::String(_), Self::Number(_)) => {
+                Some(self.to_number()? == other.to_number()?)
+            }
+            (Self::BigInt(a), Self::Number(b)) | (Self::Number(b), Self::BigInt(a)) => {
+                Some(compare_bigint_number(a, *b) == Some(Ordering::Equal))
+            }
+            (Self::Boolean(b), _) => Self::Number(f64::from(u8::from(*b))).loose_equals(other),
+            (_, Self::Boolean(b)) => self.loose_equals(&Self::Number(f64::from(u8::from(*b)))),
+            // StringToBigInt is not supported
+            (Self::BigInt(_), Self::String(_)) | (Self::String(_), Self::BigInt(_)) => None,
+            _ => Some(self.strict_equals(other)),
+        }
+    }
+
+    /// [IsLessThan](https://tc39.es/ecma262/#sec-islessthan), the ordering of `self` and `other`.
+    /// `Some(None)` when the values are not comparable, e.g. for `NaN`,
+    /// `None` when the comparison is not supported.
+    #[must_use]
+    pub fn compare(&self, other: &Self) -> Option<Option<Ordering>> {
+        match (self, other) {
+            // Strings are compared by their UTF-16 code units
+            (Self::String(a), Self::String(b)) => {
+                Some(Some(a.encode_utf16().cmp(b.encode_utf16())))
+            }
+            (Self::BigInt(a), Self::BigInt(b)) => Some(Some(a.cmp(b))),
+            (Self::BigInt(a), Self::Number(b)) => Some(compare_bigint_number(a, *b)),
+            (Self::Number(a), Self::BigInt(b)) => {
+                Some(compare_bigint_number(b, *a).map(Ordering::reverse))
+            }
+            (Self::BigInt(_), _) | (_, Self::BigInt(_)) => None,
+            _ => Some(self.to_number()?.partial_cmp(&other.to_number()?)),
+        }
+    }
+}
+
+/// Compare a `BigInt` and a `Number` by their mathematical values
+fn compare_bigint_number(a: &BigInt, b: f64) -> Option<Ordering> {
+    if b.is_nan() {
+        return None;
+    }
+    if b.is_infinite() {
+        return Some(if b > 0.0 { Ordering::Less } else { Ordering::Greater });
+    }
+    let truncated = BigInt::from_f64(b.trunc())?;
+    match a.cmp(&truncated) {
+        Ordering::Equal => 0.0.partial_cmp(&b.fract()),
+        ordering => Some(ordering),
+    }
+}
+
+/// [Number::toString](https://tc39.es/ecma262/#sec-numeric-types-number-tostring) with radix 10
+#[must_use]
+pub fn number_to_string(n: f64) -> Atom {
+    let mut buffer = ryu_js::Buffer::new();
+    Atom::from(buffer.format(n))
+}
+
+/// [StringToNumber](https://tc39.es/ecma262/#sec-stringtonumber)
+#[must_use]
+pub fn string_to_number(s: &str) -> f64 {
+    let s = s.trim_matches(is_js_whitespace);
+    if s.is_empty() {
+        return 0.0;
+    }
+    // NonDecimalIntegerLiteral, without sign or numeric separators
+    let radix = match s.get(..2) {
+        Some("0x" | "0X") => Some(16),
+        Some("0o" | "0O") => Some(8),
+        Some("0b" | "0B") => Some(2),
+        _ => None,
+    };
+    if let Some(radix) = radix {
+        let digits = &s[2..];
+        if digits.is_empty() {
+            return f64::NAN;
+        }
+        return digits
+            .chars()
+            .try_fold(0.0, |n: f64, c| {
+                c.to_digit(radix).map(|digit| n.mul_add(f64::from(radix), f64::from(digit)))
+            })
+            .unwrap_or(f64::NAN);
+    }
+    let (sign, unsigned) = match s.as_bytes()[0] {
+        b'+' => (1.0, &s[1..]),
+        b'-' => (-1.0, &s[1..]),
+        _ => (1.0, s),
+    };
+    if unsigned == "Infinity" {
+        return sign * f64::INFINITY;
+    }
+    if !is_str_unsigned_decimal_literal(unsigned) {
+        return f64::NAN;
+    }
+    // Rust accepts everything `StrUnsignedDecimalLiteral` accepts, except for `Infinity`
+    unsigned.parse::<f64>().map_or(f64::NAN, |n| sign * n)
+}
+
+/// [StrUnsignedDecimalLiteral](https://tc39.es/ecma262/#prod-StrUnsignedDecimalLiteral) without `Infinity`:
+/// `1`, `1.`, `.1`, `1.1` with an optional exponent.
+fn is_str_unsigned_decimal_literal(s: &str) -> bool {
+    let (mantissa, exponent) = s
+        .split_once(['e', 'E'])
+        .map_or((s, None), |(mantissa, exponent)| (mantissa, Some(exponent)));
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty() && fraction.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+        return false;
+    }
+    exponent.map_or(true, |exponent| {
+        let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        !digits.is_empty() && is_digits(digits)
+    })
+}
+
+/// [WhiteSpace](https://tc39.es/ecma262/#prod-WhiteSpace) and
+/// [LineTerminator](https://tc39.es/ecma262/#prod-LineTerminator)
+fn is_js_whitespace(c: char) -> bool {
+    // Unicode `White_Space` has everything but `ZWNBSP`, and `NEL` which is not whitespace in JavaScript
+    c == '\u{FEFF}' || c.is_whitespace() && c != '\u{85}'
+}
+
+/// [ToInt32](https://tc39.es/ecma262/#sec-toint32)
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+pub fn to_int32(n: f64) -> i32 {
+    if !n.is_finite() {
+        return 0;
+    }
+    // In the range of `i64` after the remainder, the cast to `u32` keeps the low 32 bits
+    ((n.trunc() % 4_294_967_296.0) as i64 as u32) as i32
+}
+
+/// [ToUint32](https://tc39.es/ecma262/#sec-touint32)
+#[must_use]
+#[allow(clippy::cast_sign_loss)]
+pub fn to_uint32(n: f64) -> u32 {
+    to_int32(n) as u32
+}
@@ -0,0 +1,125 @@
+//! Structural equality and hashing of AST nodes, see [`ContentEq`] and [`ContentHash`]
+
+use std::hash::{Hash, Hasher};
+
+use num_bigint::BigUint;
+use ordered_float::NotNan;
+use oxc_allocator::{Box, Vec};
+
+use crate::{ast::RegExpFlags, Atom, SourceType, Span};
+
+/// Options for [`ContentEq`] and [`ContentHash`]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ContentOptions {
+    /// Treat `(a)` as `a`
+    pub ignore_parentheses: bool,
+}
+
+/// Structural equality of AST nodes.
+///
+/// Unlike [`PartialEq`], spans are ignored,
+/// so the same code at two different locations is equal, e.g. the two `a + b` in `a + b === a + b`.
+/// Information which is only about how the code is written is ignored as well,
+/// such as trailing commas and the raw text of numbers (`1`, `1.0` and `0x1` are equal).
+///
+/// Implemented for all AST nodes with `#[derive(ContentEq)]`.
+pub trait ContentEq {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.content_eq_with(other, ContentOptions::default())
+    }
+
+    fn content_eq_with(&self, other: &Self, options: ContentOptions) -> bool;
+}
+
+/// Hashing of AST nodes which is consistent with [`ContentEq`]:
+/// nodes which are `content_eq` with the same options have the same content hash.
+///
+/// Implemented for all AST nodes with `#[derive(ContentHash)]`.
+pub trait ContentHash {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.content_hash_with(state, ContentOptions::default());
+    }
+
+    fn content_hash_with<H: Hasher>(&self, state: &mut H, options: ContentOptions);
+}
+
+impl<'a, T: ContentEq> ContentEq for Box<'a, T> {
+    fn content_eq_with(&self, other: &Self, options: ContentOptions) -> bool {
+        self.0.content_eq_with(&*other.0, options)
+    }
+}
+
+impl<'a, T: ContentHash> ContentHash for Box<'a, T> {
+    fn content_hash_with<H: Hasher>(&self, state: &mut H, options: ContentOptions) {
+        self.0.content_hash_with(state, options);
+    }
+}
+
+impl<'a, T: ContentEq> ContentEq for Vec<'a, T> {
+    fn content_eq_with(&self, other: &Self, options: ContentOptions) -> bool {
+        self.len() == other.len()
+            && self.iter().zip(other.iter()).all(|(a, b)| a.content_eq_with(b, options))
+    }
+}
+
+impl<'a, T: ContentHash> ContentHash for Vec<'a, T> {
+    fn content_hash_with<H: Hasher>(&self, state: &mut H, options: ContentOptions) {
+        self.len().hash(state);
+        for item in self.iter() {
+            item.content_hash_with(state, options);
+        }
+    }
+}
+
+impl<T: ContentEq> ContentEq for Option<T> {
+    fn content_eq_with(&self, other: &Self, options: ContentOptions) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.content_eq_with(b, options),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: ContentHash> ContentHash for Option<T> {
+    fn content_hash_with<H: Hasher>(&self, state: &mut H, options: ContentOptions) {
+        self.is_some().hash(state);
+        if let Some(value) = self {
+            value.content_hash_with(state, options);
+        }
+    }
+}
+
+impl ContentEq for Span {
+    fn content_eq_with(&self, _: &Self, _: ContentOptions) -> bool {
+        true
+    }
+}
+
+impl ContentHash for Span {
+    fn content_hash_with<H: Hasher>(&self, _: &mut H, _: ContentOptions) {}
+}
+
+/// Types without nodes or spans are compared with [`PartialEq`] and hashed with [`Hash`]
+macro_rules! impl_content {
+    ($($ty:ty),*) => {
+        $(
+            impl ContentEq for $ty {
+                #[inline]
+                fn content_eq_with(&self, other: &Self, _: ContentOptions) -> bool {
+                    self == other
+                }
+            }
+
+            impl ContentHash for $ty {
+                #[inline]
+                fn content_hash_with<H: Hasher>(&self, state: &mut H, _: ContentOptions) {
+                    self.hash(state);
+                }
+            }
+        )*
+    };
+}
+
+impl_content!(bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, char, &str);
+impl_content!(Atom, BigUint, NotNan<f64>, SourceType, RegExpFlags);
@@ -0,0 +1,2418 @@
+//! Reading `ESTree` JSON into an AST, see [`Program::from_estree_json`]
+
+use num_bigint::BigUint;
+use oxc_allocator::{Allocator, Box, Vec};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use super::ESTreeOptions;
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, AstBuilder, Atom, LineIndex, SourceType, Span};
+
+/// Error of [`Program::from_estree_json`]
+#[derive(Debug, Error)]
+pub enum ESTreeError {
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    /// A node which is not allowed in its position, e.g. a statement where an expression is expected
+    #[error("Unexpected `{node_type}` node at {}..{}, expected {expected}", .span.start, .span.end)]
+    UnexpectedNode { node_type: String, span: Span, expected: &'static str },
+    /// A required field is missing or has the wrong type
+    #[error("Missing or invalid field `{field}` of `{node_type}` node at {}..{}", .span.start, .span.end)]
+    InvalidField { node_type: String, span: Span, field: &'static str },
+    /// A `start` or `end` which is not a position in the source text
+    #[error("Position {position} of `{node_type}` node is outside of the source text")]
+    InvalidPosition { node_type: String, position: u64 },
+}
+
+type Result<T> = std::result::Result<T, ESTreeError>;
+
+type Node = Map<String, Value>;
+
+impl<'a> Program<'a> {
+    /// Read an AST from `ESTree` JSON, the reverse of [`Program::to_estree_json`] and [`Program::to_json`].
+    ///
+    /// Nodes can be in the shape of `ESTree` with the TypeScript and JSX nodes of `typescript-estree`,
+    /// e.g. from `@babel/parser` with the `estree` plugin, `acorn`, `espree` or
+    /// `@typescript-eslint/typescript-estree`, or in the shape of [`Program::to_json`].
+    ///
+    /// Positions are read from `start` and `end`, or `range`, and are checked against `source_text`,
+    /// the source text the AST was parsed from. Nodes without positions get an empty span,
+    /// so an AST without any positions can be used with an empty source text.
+    /// Set [`ESTreeOptions::utf16`] for positions in UTF-16 code units, which JavaScript parsers produce,
+    /// the other options are not used.
+    ///
+    /// Regular expression patterns are not parsed, [`RegExpLiteral::pattern`] is `None`.
+    /// The JSON of [`Program::to_json`] does not preserve the operator of `keyof` and other
+    /// type operators, whether a class member is abstract, or the spans of `export =` and
+    /// parameter properties whose `start` and `end` are repeated,
+    /// use [`Program::to_estree_json`] to keep all details.
+    ///
+    /// # Errors
+    ///
+    /// * The JSON is invalid
+    /// * A node is missing a required field or is not allowed in its position
+    /// * A position is outside of `source_text`
+    pub fn from_estree_json(
+        allocator: &'a Allocator,
+        source_text: &str,
+        source_type: SourceType,
+        json: &str,
+        options: ESTreeOptions,
+    ) -> Result<Self> {
+        let value = serde_json::from_str::<Value>(json)?;
+        let reader = Reader {
+            ast: AstBuilder::new(allocator),
+            source_text,
+            line_index: options.utf16.then(|| LineIndex::new(source_text)),
+        };
+        reader.program(as_node(&value)?, source_type)
+    }
+}
+
+/// All node types of identifiers, `ESTree` only has `Identifier`
+const IDENTIFIERS: &[&str] = &[
+    "Identifier",
+    "IdentifierReference",
+    "IdentifierName",
+    "BindingIdentifier",
+    "LabelIdentifier",
+];
+
+const DECLARATIONS: &[&str] = &[
+    "VariableDeclaration",
+    "FunctionDeclaration",
+    "TSDeclareFunction",
+    "ClassDeclaration",
+    "TSTypeAliasDeclaration",
+    "TSInterfaceDeclaration",
+    "TSEnumDeclaration",
+    "TSModuleDeclaration",
+    "TSImportEqualsDeclaration",
+    "FlowOpaqueTypeDeclaration",
+    "FlowDeclareModuleExports",
+];
+
+const MODULE_DECLARATIONS: &[&str] = &[
+    "ImportDeclaration",
+    "ExportAllDeclaration",
+    "ExportDefaultDeclaration",
+    "ExportNamedDeclaration",
+    "TSExportAssignment",
+    "TSNamespaceExportDeclaration",
+];
+
+struct Reader<'a, 's> {
+    ast: AstBuilder<'a>,
+    source_text: &'s str,
+    /// Only built for UTF-16 positions
+    line_index: Option<LineIndex>,
+}
+
+/* ---------- Fields ---------- */
+
+/// Type of a node, empty when missing
+fn node_type(node: &Node) -> &str {
+    node.get("type").and_then(Value::as_str).unwrap_or_default()
+}
+
+/// A field which is neither missing nor `null`.
+/// Falls back to the snake case name, which [`Program::to_json`] uses for a few fields.
+fn get<'n>(node: &'n Node, key: &str) -> Option<&'n Value> {
+    let value = node.get(key).or_else(|| {
+        let snake_case = key.chars().fold(String::new(), |mut name, c| {
+            if c.is_ascii_uppercase() {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+            name
+        });
+        node.get(&snake_case)
+    });
+    value.filter(|value| !value.is_null())
+}
+
+fn get_bool(node: &Node, key: &str) -> bool {
+    get(node, key).and_then(Value::as_bool).unwrap_or_default()
+}
+
+fn get_str<'n>(node: &'n Node, key: &str) -> Option<&'n str> {
+    get(node, key).and_then(Value::as_str)
+}
+
+fn get_array<'n>(node: &'n Node, key: &str) -> &'n [Value] {
+    get(node, key).and_then(Value::as_array).map_or(&[], std::vec::Vec::as_slice)
+}
+
+fn as_node(value: &Value) -> Result<&Node> {
+    value.as_object().ok_or_else(|| ESTreeError::UnexpectedNode {
+        node_type: value.to_string().chars().take(32).collect(),
+        span: Span::default(),
+        expected: "a node",
+    })
+}
+
+/// Box the node read by `read`
+fn boxed<'a, 's, T>(
+    read: impl Fn(&Reader<'a, 's>, &Node) -> Result<T>,
+) -> impl Fn(&Reader<'a, 's>, &Node) -> Result<Box<'a, T>> {
+    move |reader, node| read(reader, node).map(|value| reader.ast.alloc(value))
+}
+
+impl<'a, 's> Reader<'a, 's> {
+    fn unexpected(&self, node: &Node, expected: &'static str) -> ESTreeError {
+        ESTreeError::UnexpectedNode {
+            node_type: node_type(node).to_string(),
+            span: self.span(node).unwrap_or_default(),
+            expected,
+        }
+    }
+
+    fn invalid(&self, node: &Node, field: &'static str) -> ESTreeError {
+        ESTreeError::InvalidField {
+            node_type: node_type(node).to_string(),
+            span: self.span(node).unwrap_or_default(),
+            field,
+        }
+    }
+
+    fn expect(&self, node: &Node, types: &[&str], expected: &'static str) -> Result<()> {
+        if types.contains(&node_type(node)) { Ok(()) } else { Err(self.unexpected(node, expected)) }
+    }
+
+    fn span(&self, node: &Node) -> Result<Span> {
+        let range = get_array(node, "range");
+        let start = get(node, "start").or_else(|| range.first()).and_then(Value::as_u64);
+        let end = get(node, "end").or_else(|| range.get(1)).and_then(Value::as_u64);
+        let (Some(start), Some(end)) = (start, end) else {
+            return Ok(Span::default());
+        };
+        let start = self.offset(node, start)?;
+        let end = self.offset(node, end)?;
+        Ok(Span::new(start, end.max(start)))
+    }
+
+    fn offset(&self, node: &Node, position: u64) -> Result<u32> {
+        let invalid =
+            || ESTreeError::InvalidPosition { node_type: node_type(node).to_string(), position };
+        let offset = u32::try_from(position).map_err(|_| invalid())?;
+        let offset = match &self.line_index {
+            Some(line_index) => {
+                let utf8_offset = line_index.offset_from_utf16(offset);
+                if line_index.utf16_offset(utf8_offset) != offset {
+                    return Err(invalid());
+                }
+                utf8_offset
+            }
+            None => offset,
+        };
+        if self.source_text.is_char_boundary(offset as usize) { Ok(offset) } else { Err(invalid()) }
+    }
+
+    /// A required child node
+    fn child<'n>(&self, node: &'n Node, key: &'static str) -> Result<&'n Node> {
+        get(node, key).and_then(Value::as_object).ok_or_else(|| self.invalid(node, key))
+    }
+
+    fn req<T>(
+        &self,
+        node: &Node,
+        key: &'static str,
+        read: impl FnOnce(&Self, &Node) -> Result<T>,
+    ) -> Result<T> {
+        read(self, self.child(node, key)?)
+    }
+
+    fn opt<T>(
+        &self,
+        node: &Node,
+        key: &'static str,
+        read: impl FnOnce(&Self, &Node) -> Result<T>,
+    ) -> Result<Option<T>> {
+        match get(node, key) {
+            None => Ok(None),
+            Some(Value::Object(child)) => read(self, child).map(Some),
+            Some(_) => Err(self.invalid(node, key)),
+        }
+    }
+
+    fn list<T>(
+        &self,
+        node: &Node,
+        key: &'static str,
+        read: impl Fn(&Self, &Node) -> Result<T>,
+    ) -> Result<Vec<'a, T>> {
+        let values = get_array(node, key);
+        let mut list = self.ast.new_vec_with_capacity(values.len());
+        for value in values {
+            list.push(read(self, as_node(value)?)?);
+        }
+        Ok(list)
+    }
+
+    /// A list with holes, e.g. the elements of `[a, , b]`
+    fn list_with_holes<T>(
+        &self,
+        node: &Node,
+        key: &'static str,
+        read: impl Fn(&Self, &Node) -> Result<T>,
+    ) -> Result<Vec<'a, Option<T>>> {
+        let values = get_array(node, key);
+        let mut list = self.ast.new_vec_with_capacity(values.len());
+        for value in values {
+            list.push(if value.is_null() { None } else { Some(read(self, as_node(value)?)?) });
+        }
+        Ok(list)
+    }
+
+    fn operator<T: DeserializeOwned>(&self, node: &Node) -> Result<T> {
+        get(node, "operator")
+            .and_then(|operator| T::deserialize(operator).ok())
+            .ok_or_else(|| self.invalid(node, "operator"))
+    }
+
+    /// The source text of a literal, from `raw` or from the source text
+    fn raw<'n>(&self, node: &'n Node, span: Span) -> Option<&'n str>
+    where
+        's: 'n,
+    {
+        get_str(node, "raw").or_else(|| {
+            (!span.is_empty()).then(|| span.source_text(self.source_text)).filter(|raw| {
+                // Positions of another source text
+                !raw.is_empty()
+            })
+        })
+    }
+
+    /// The span of `trailingComma`, which is only in [`Program::to_json`]
+    fn trailing_comma(&self, node: &Node) -> Result<Option<Span>> {
+        self.opt(node, "trailingComma", Self::span)
+    }
+
+    fn modifiers(&self, node: &Node) -> Result<Modifiers<'a>> {
+        let span = self.span(node)?;
+        let span = Span::new(span.start, span.start);
+        let mut modifiers = self.ast.new_vec();
+        for (key, kind) in [
+            ("declare", ModifierKind::Declare),
+            ("abstract", ModifierKind::Abstract),
+            ("const", ModifierKind::Const),
+        ] {
+            if get_bool(node, key) {
+                modifiers.push(Modifier { span, kind });
+            }
+        }
+        // `modifiers` of `Program::to_json`
+        for modifier in get_array(node, "modifiers") {
+            let modifier = as_node(modifier)?;
+            let kind = get(modifier, "kind")
+                .and_then(|kind| kind.as_str().or_else(|| kind.get("type")?.as_str()))
+                .and_then(modifier_kind)
+                .ok_or_else(|| self.invalid(modifier, "kind"))?;
+            if !modifiers.iter().any(|modifier| modifier.kind == kind) {
+                modifiers.push(Modifier { span: self.span(modifier)?, kind });
+            }
+        }
+        // `[]` and `null` are different in `Program::to_json`
+        let is_empty = modifiers.is_empty() && get(node, "modifiers").is_none();
+        Ok(if is_empty { Modifiers::empty() } else { Modifiers::new(modifiers) })
+    }
+
+    fn accessibility(&self, node: &Node) -> Result<Option<TSAccessibility>> {
+        Ok(match get_str(node, "accessibility") {
+            None => None,
+            Some("private") => Some(TSAccessibility::Private),
+            Some("protected") => Some(TSAccessibility::Protected),
+            Some("public") => Some(TSAccessibility::Public),
+            Some(_) => return Err(self.invalid(node, "accessibility")),
+        })
+    }
+
+    fn decorators(&self, node: &Node) -> Result<Vec<'a, Decorator<'a>>> {
+        self.list(node, "decorators", |r, node| {
+            r.expect(node, &["Decorator"], "decorator")?;
+            Ok(Decorator {
+                span: r.span(node)?,
+                expression: r.req(node, "expression", Self::expression)?,
+            })
+        })
+    }
+
+    /// `importKind` or `exportKind`, a string or the object of [`Program::to_json`]
+    fn import_or_export_kind(
+        &self,
+        node: &Node,
+        key: &'static str,
+    ) -> Result<Option<ImportOrExportKind>> {
+        let Some(kind) = get(node, key) else {
+            return Ok(None);
+        };
+        match kind.as_str().or_else(|| kind.get("type")?.as_str()) {
+            Some("value") => Ok(Some(ImportOrExportKind::Value)),
+            Some("type") => Ok(Some(ImportOrExportKind::Type)),
+            Some("typeof") => Ok(Some(ImportOrExportKind::Typeof)),
+            _ => Err(self.invalid(node, key)),
+        }
+    }
+
+    /// Type arguments, `typeArguments` in newer versions of `typescript-estree`
+    fn type_arguments(
+        &self,
+        node: &Node,
+    ) -> Result<Option<Box<'a, TSTypeParameterInstantiation<'a>>>> {
+        let key =
+            if get(node, "typeParameters").is_some() { "typeParameters" } else { "typeArguments" };
+        self.opt(node, key, boxed(Self::ts_type_parameter_instantiation))
+    }
+
+    fn type_parameters(
+        &self,
+        node: &Node,
+    ) -> Result<Option<Box<'a, TSTypeParameterDeclaration<'a>>>> {
+        self.opt(node, "typeParameters", boxed(Self::ts_type_parameter_declaration))
+    }
+
+    fn return_type(&self, node: &Node) -> Result<Option<Box<'a, TSTypeAnnotation<'a>>>> {
+        self.opt(node, "returnType", boxed(Self::ts_type_annotation))
+    }
+}
+
+fn modifier_kind(name: &str) -> Option<ModifierKind> {
+    Some(match name.to_ascii_lowercase().as_str() {
+        "abstract" => ModifierKind::Abstract,
+        "accessor" => ModifierKind::Accessor,
+        "async" => ModifierKind::Async,
+        "const" => ModifierKind::Const,
+        "declare" => ModifierKind::Declare,
+        "default" => ModifierKind::Default,
+        "export" => ModifierKind::Export,
+        "in" => ModifierKind::In,
+        "public" => ModifierKind::Public,
+        "private" => ModifierKind::Private,
+        "protected" => ModifierKind::Protected,
+        "readonly" => ModifierKind::Readonly,
+        "static" => ModifierKind::Static,
+        "out" => ModifierKind::Out,
+        "override" => ModifierKind::Override,
+        _ => return None,
+    })
+}
+
+/// Radix of a number or bigint literal
+fn radix(raw: &str) -> (u32, &str) {
+    match raw.get(..2) {
+        Some("0x" | "0X") => (16, &raw[2..]),
+        Some("0o" | "0O") => (8, &raw[2..]),
+        Some("0b" | "0B") => (2, &raw[2..]),
+        _ => (10, raw),
+    }
+}
+
+/* ---------- Program and statements ---------- */
+
+impl<'a, 's> Reader<'a, 's> {
+    fn program(&self, node: &Node, mut source_type: SourceType) -> Result<Program<'a>> {
+        self.expect(node, &["Program"], "Program")?;
+        match get_str(node, "sourceType") {
+            Some("module") => source_type = *source_type.with_module(true),
+            Some("script") => source_type = *source_type.with_script(true),
+            _ => {}
+        }
+        let (directives, body) = self.directives_and_statements(node)?;
+        Ok(self.ast.program(self.span(node)?, directives, body, source_type))
+    }
+
+    /// The directive prologue is made of `ExpressionStatement`s with a `directive`
+    fn directives_and_statements(
+        &self,
+        node: &Node,
+    ) -> Result<(Vec<'a, Directive<'a>>, Vec<'a, Statement<'a>>)> {
+        let values = get_array(node, "body");
+        let mut directives = self.ast.new_vec();
+        let mut statements = self.ast.new_vec_with_capacity(values.len());
+        for value in values {
+            let node = as_node(value)?;
+            match get_str(node, "directive") {
+                Some(directive)
+                    if statements.is_empty() && node_type(node) == "ExpressionStatement" =>
+                {
+                    directives.push(Directive {
+                        span: self.span(node)?,
+                        expression: self.req(node, "expression", Self::string_literal)?,
+                        directive: self.ast.new_str(directive),
+                    });
+                }
+                _ => statements.push(self.statement(node)?),
+            }
+        }
+        Ok((directives, statements))
+    }
+
+    fn statement(&self, node: &Node) -> Result<Statement<'a>> {
+        let span = self.span(node)?;
+        let ast = &self.ast;
+        Ok(match node_type(node) {
+            "BlockStatement" => Statement::BlockStatement(ast.alloc(self.block_statement(node)?)),
+            "BreakStatement" => Statement::BreakStatement(ast.alloc(BreakStatement {
+                span,
+                label: self.opt(node, "label", Self::label_identifier)?,
+            })),
+            "ContinueStatement" => Statement::ContinueStatement(ast.alloc(ContinueStatement {
+                span,
+                label: self.opt(node, "label", Self::label_identifier)?,
+            })),
+            "DebuggerStatement" => {
+                Statement::DebuggerStatement(ast.alloc(DebuggerStatement { span }))
+            }
+            "DoWhileStatement" => Statement::DoWhileStatement(ast.alloc(DoWhileStatement {
+                span,
+                body: self.req(node, "body", Self::statement)?,
+                test: self.req(node, "test", Self::expression)?,
+            })),
+            "EmptyStatement" => Statement::EmptyStatement(ast.alloc(EmptyStatement { span })),
+            "ExpressionStatement" => {
+                Statement::ExpressionStatement(ast.alloc(ExpressionStatement {
+                    span,
+                    expression: self.req(node, "expression", Self::expression)?,
+                }))
+            }
+            "ForInStatement" => Statement::ForInStatement(ast.alloc(ForInStatement {
+                span,
+                left: self.req(node, "left", Self::for_statement_left)?,
+                right: self.req(node, "right", Self::expression)?,
+                body: self.req(node, "body", Self::statement)?,
+            })),
+            "ForOfStatement" => Statement::ForOfStatement(ast.alloc(ForOfStatement {
+                span,
+                r#await: get_bool(node, "await"),
+                left: self.req(node, "left", Self::for_statement_left)?,
+                right: self.req(node, "right", Self::expression)?,
+                body: self.req(node, "body", Self::statement)?,
+            })),
+            "ForStatement" => Statement::ForStatement(ast.alloc(ForStatement {
+                span,
+                init: self.opt(node, "init", Self::for_statement_init)?,
+                test: self.opt(node, "test", Self::expression)?,
+                update: self.opt(node, "update", Self::expression)?,
+                body: self.req(node, "body", Self::statement)?,
+            })),
+            "IfStatement" => Statement::IfStatement(ast.alloc(IfStatement {
+                span,
+                test: self.req(node, "test", Self::expression)?,
+                consequent: self.req(node, "consequent", Self::statement)?,
+                alternate: self.opt(node, "alternate", Self::statement)?,
+            })),
+            "LabeledStatement" => Statement::LabeledStatement(ast.alloc(LabeledStatement {
+                span,
+                label: self.req(node, "label", Self::label_identifier)?,
+                body: self.req(node, "body", Self::statement)?,
+            })),
+            "ReturnStatement" => Statement::ReturnStatement(ast.alloc(ReturnStatement {
+                span,
+                argument: self.opt(node, "argument", Self::expression)?,
+            })),
+            "SwitchStatement" => Statement::SwitchStatement(ast.alloc(SwitchStatement {
+                span,
+                discriminant: self.req(node, "discriminant", Self::expression)?,
+                cases: self.list(node, "cases", Self::switch_case)?,
+            })),
+            "ThrowStatement" => Statement::ThrowStatement(ast.alloc(ThrowStatement {
+                span,
+                argument: self.req(node, "argument", Self::expression)?,
+            })),
+            "TryStatement" => Statement::TryStatement(ast.alloc(TryStatement {
+                span,
+                block: self.req(node, "block", boxed(Self::block_statement))?,
+                handler: self.opt(node, "handler", boxed(Self::catch_clause))?,
+                finalizer: self.opt(node, "finalizer", boxed(Self::block_statement))?,
+            })),
+            "WhileStatement" => Statement::WhileStatement(ast.alloc(WhileStatement {
+                span,
+                test: self.req(node, "test", Self::expression)?,
+                body: self.req(node, "body", Self::statement)?,
+            })),
+            "WithStatement" => Statement::WithStatement(ast.alloc(WithStatement {
+                span,
+                object: self.req(node, "object", Self::expression)?,
+                body: self.req(node, "body", Self::statement)?,
+            })),
+            "InvalidStatement" => Statement::InvalidStatement(ast.alloc(InvalidStatement { span })),
+            r#type if MODULE_DECLARATIONS.contains(&r#type) => {
+                Statement::ModuleDeclaration(ast.alloc(self.module_declaration(node)?))
+            }
+            r#type if DECLARATIONS.contains(&r#type) => {
+                Statement::Declaration(self.declaration(node)?)
+            }
+            _ => return Err(self.unexpected(node, "statement")),
+        })
+    }
+
+    fn block_statement(&self, node: &Node) -> Result<BlockStatement<'a>> {
+        self.expect(node, &["BlockStatement"], "BlockStatement")?;
+        Ok(BlockStatement {
+            span: self.span(node)?,
+            body: self.list(node, "body", Self::statement)?,
+        })
+    }
+
+    fn switch_case(&self, node: &Node) -> Result<SwitchCase<'a>> {
+        self.expect(node, &["SwitchCase"], "SwitchCase")?;
+        Ok(SwitchCase {
+            span: self.span(node)?,
+            test: self.opt(node, "test", Self::expression)?,
+            consequent: self.list(node, "consequent", Self::statement)?,
+        })
+    }
+
+    fn catch_clause(&self, node: &Node) -> Result<CatchClause<'a>> {
+        self.expect(node, &["CatchClause"], "CatchClause")?;
+        Ok(CatchClause {
+            span: self.span(node)?,
+            param: self.opt(node, "param", Self::binding_pattern)?,
+            body: self.req(node, "body", boxed(Self::block_statement))?,
+        })
+    }
+
+    fn for_statement_init(&self, node: &Node) -> Result<ForStatementInit<'a>> {
+        Ok(match node_type(node) {
+            "VariableDeclaration" => ForStatementInit::VariableDeclaration(
+                self.ast.alloc(self.variable_declaration(node)?),
+            ),
+            _ => ForStatementInit::Expression(self.expression(node)?),
+        })
+    }
+
+    fn for_statement_left(&self, node: &Node) -> Result<ForStatementLeft<'a>> {
+        Ok(match node_type(node) {
+            "VariableDeclaration" => ForStatementLeft::VariableDeclaration(
+                self.ast.alloc(self.variable_declaration(node)?),
+            ),
+            _ => ForStatementLeft::AssignmentTarget(self.assignment_target(node)?),
+        })
+    }
+
+    fn declaration(&self, node: &Node) -> Result<Declaration<'a>> {
+        let ast = &self.ast;
+        Ok(match node_type(node) {
+            "VariableDeclaration" => {
+                Declaration::VariableDeclaration(ast.alloc(self.variable_declaration(node)?))
+            }
+            "FunctionDeclaration" | "TSDeclareFunction" => {
+                Declaration::FunctionDeclaration(ast.alloc(self.function(node)?))
+            }
+            "ClassDeclaration" => Declaration::ClassDeclaration(ast.alloc(self.class(node)?)),
+            "TSTypeAliasDeclaration" => Declaration::TSTypeAliasDeclaration(
+                ast.alloc(self.ts_type_alias_declaration(node)?),
+            ),
+            "TSInterfaceDeclaration" => {
+                Declaration::TSInterfaceDeclaration(ast.alloc(self.ts_interface_declaration(node)?))
+            }
+            "TSEnumDeclaration" => {
+                Declaration::TSEnumDeclaration(ast.alloc(self.ts_enum_declaration(node)?))
+            }
+            "TSModuleDeclaration" => {
+                Declaration::TSModuleDeclaration(ast.alloc(self.ts_module_declaration(node)?))
+            }
+            "TSImportEqualsDeclaration" => Declaration::TSImportEqualsDeclaration(
+                ast.alloc(self.ts_import_equals_declaration(node)?),
+            ),
+            "FlowOpaqueTypeDeclaration" => {
+                Declaration::FlowOpaqueTypeDeclaration(ast.alloc(FlowOpaqueTypeDeclaration {
+                    span: self.span(node)?,
+                    id: self.req(node, "id", Self::binding_identifier)?,
+                    type_parameters: self.type_parameters(node)?,
+                    supertype: self.opt(node, "supertype", Self::ts_type)?,
+                    impltype: self.opt(node, "impltype", Self::ts_type)?,
+                    modifiers: self.modifiers(node)?,
+                }))
+            }
+            "FlowDeclareModuleExports" => {
+                Declaration::FlowDeclareModuleExports(ast.alloc(FlowDeclareModuleExports {
+                    span: self.span(node)?,
+                    type_annotation: self.req(
+                        node,
+                        "typeAnnotation",
+                        boxed(Self::ts_type_annotation),
+                    )?,
+                }))
+            }
+            _ => return Err(self.unexpected(node, "declaration")),
+        })
+    }
+
+    fn variable_declaration(&self, node: &Node) -> Result<VariableDeclaration<'a>> {
+        self.expect(node, &["VariableDeclaration"], "VariableDeclaration")?;
+        let kind = match get_str(node, "kind") {
+            Some("var") => VariableDeclarationKind::Var,
+            Some("let") => VariableDeclarationKind::Let,
+            Some("const") => VariableDeclarationKind::Const,
+            _ => return Err(self.invalid(node, "kind")),
+        };
+        let declarations = self.list(node, "declarations", |r, node| {
+            r.expect(node, &["VariableDeclarator"], "VariableDeclarator")?;
+            Ok(VariableDeclarator {
+                span: r.span(node)?,
+                kind,
+                id: r.req(node, "id", Self::binding_pattern)?,
+                init: r.opt(node, "init", Self::expression)?,
+                definite: get_bool(node, "definite"),
+            })
+        })?;
+        Ok(VariableDeclaration {
+            span: self.span(node)?,
+            kind,
+            declarations,
+            modifiers: self.modifiers(node)?,
+        })
+    }
+}
+
+/* ---------- Expressions ---------- */
+
+impl<'a, 's> Reader<'a, 's> {
+    #[allow(clippy::too_many_lines)]
+    fn expression(&self, node: &Node) -> Result<Expression<'a>> {
+        let span = self.span(node)?;
+        let ast = &self.ast;
+        Ok(match node_type(node) {
+            "Literal" => self.literal(node)?,
+            "TemplateLiteral" => {
+                Expression::TemplateLiteral(ast.alloc(self.template_literal(node)?))
+            }
+            r#type if IDENTIFIERS.contains(&r#type) => {
+                Expression::Identifier(ast.alloc(self.identifier_reference(node)?))
+            }
+            "MetaProperty" => Expression::MetaProperty(ast.alloc(MetaProperty {
+                span,
+                meta: self.req(node, "meta", Self::identifier_name)?,
+                property: self.req(node, "property", Self::identifier_name)?,
+            })),
+            "Super" => Expression::Super(ast.alloc(Super { span })),
+            "ArrayExpression" => Expression::ArrayExpression(ast.alloc(ArrayExpression {
+                span,
+                elements: self.list_with_holes(node, "elements", Self::argument)?,
+                trailing_comma: self.trailing_comma(node)?,
+            })),
+            "ArrowFunctionExpression" => {
+                Expression::ArrowFunctionExpression(ast.alloc(self.arrow_expression(node)?))
+            }
+            "AssignmentExpression" => {
+                Expression::AssignmentExpression(ast.alloc(AssignmentExpression {
+                    span,
+                    operator: self.operator(node)?,
+                    left: self.req(node, "left", Self::assignment_target)?,
+                    right: self.req(node, "right", Self::expression)?,
+                }))
+            }
+            "AwaitExpression" => Expression::AwaitExpression(ast.alloc(AwaitExpression {
+                span,
+                argument: self.req(node, "argument", Self::expression)?,
+            })),
+            "BinaryExpression" | "PrivateInExpression" => {
+                let left = self.child(node, "left")?;
+                if node_type(left) == "PrivateIdentifier" {
+                    Expression::PrivateInExpression(ast.alloc(PrivateInExpression {
+                        span,
+                        left: self.private_identifier(left)?,
+                        operator: BinaryOperator::In,
+                        right: self.req(node, "right", Self::expression)?,
+                    }))
+                } else {
+                    Expression::BinaryExpression(ast.alloc(BinaryExpression {
+                        span,
+                        left: self.expression(left)?,
+                        operator: self.operator(node)?,
+                        right: self.req(node, "right", Self::expression)?,
+                    }))
+                }
+            }
+            "CallExpression" => Expression::CallExpression(ast.alloc(self.call_expression(node)?)),
+            "ChainExpression" => Expression::ChainExpression(ast.alloc(ChainExpression {
+                span,
+                expression: self.req(node, "expression", Self::chain_element)?,
+            })),
+            "ClassExpression" => Expression::ClassExpression(ast.alloc(self.class(node)?)),
+            "ConditionalExpression" => {
+                Expression::ConditionalExpression(ast.alloc(ConditionalExpression {
+                    span,
+                    test: self.req(node, "test", Self::expression)?,
+                    consequent: self.req(node, "consequent", Self::expression)?,
+                    alternate: self.req(node, "alternate", Self::expression)?,
+                }))
+            }
+            "FunctionExpression" => Expression::FunctionExpression(ast.alloc(self.function(node)?)),
+            "ImportExpression" => {
+                // `attributes` in ESTree, `arguments` in `Program::to_json`
+                let arguments = match get(node, "attributes") {
+                    Some(_) => {
+                        ast.new_vec_single(self.req(node, "attributes", Self::expression)?)
+                    }
+                    None => self.list(node, "arguments", Self::expression)?,
+                };
+                Expression::ImportExpression(ast.alloc(ImportExpression {
+                    span,
+                    source: self.req(node, "source", Self::expression)?,
+                    arguments,
+                }))
+            }
+            "LogicalExpression" => Expression::LogicalExpression(ast.alloc(LogicalExpression {
+                span,
+                left: self.req(node, "left", Self::expression)?,
+                operator: self.operator(node)?,
+                right: self.req(node, "right", Self::expression)?,
+            })),
+            "MemberExpression" => {
+                Expression::MemberExpression(ast.alloc(self.member_expression(node)?))
+            }
+            "NewExpression" => Expression::NewExpression(ast.alloc(NewExpression {
+                span,
+                callee: self.req(node, "callee", Self::expression)?,
+                arguments: self.list(node, "arguments", Self::argument)?,
+                type_parameters: self.type_arguments(node)?,
+            })),
+            "ObjectExpression" => Expression::ObjectExpression(ast.alloc(ObjectExpression {
+                span,
+                properties: self.list(node, "properties", Self::object_property)?,
+                trailing_comma: self.trailing_comma(node)?,
+            })),
+            "ParenthesizedExpression" => {
+                Expression::ParenthesizedExpression(ast.alloc(ParenthesizedExpression {
+                    span,
+                    expression: self.req(node, "expression", Self::expression)?,
+                }))
+            }
+            "SequenceExpression" => Expression::SequenceExpression(ast.alloc(SequenceExpression {
+                span,
+                expressions: self.list(node, "expressions", Self::expression)?,
+            })),
+            "TaggedTemplateExpression" => {
+                Expression::TaggedTemplateExpression(ast.alloc(TaggedTemplateExpression {
+                    span,
+                    tag: self.req(node, "tag", Self::expression)?,
+                    quasi: self.req(node, "quasi", Self::template_literal)?,
+                    type_parameters: self.type_arguments(node)?,
+                }))
+            }
+            "ThisExpression" => Expression::ThisExpression(ast.alloc(ThisExpression { span })),
+            "UnaryExpression" => {
+                Expression::UnaryExpression(ast.alloc(self.unary_expression(node)?))
+            }
+            "UpdateExpression" => Expression::UpdateExpression(ast.alloc(UpdateExpression {
+                span,
+                operator: self.operator(node)?,
+                prefix: get_bool(node, "prefix"),
+                argument: self.req(node, "argument", Self::simple_assignment_target)?,
+            })),
+            "YieldExpression" => Expression::YieldExpression(ast.alloc(YieldExpression {
+                span,
+                delegate: get_bool(node, "delegate"),
+                argument: self.opt(node, "argument", Self::expression)?,
+            })),
+            "JSXElement" => Expression::JSXElement(ast.alloc(self.jsx_element(node)?)),
+            "JSXFragment" => Expression::JSXFragment(ast.alloc(self.jsx_fragment(node)?)),
+            "TSAsExpression" => Expression::TSAsExpression(ast.alloc(self.ts_as_expression(node)?)),
+            "TSSatisfiesExpression" => {
+                Expression::TSSatisfiesExpression(ast.alloc(self.ts_satisfies_expression(node)?))
+            }
+            "TSTypeAssertion" => {
+                Expression::TSTypeAssertion(ast.alloc(self.ts_type_assertion(node)?))
+            }
+            "TSNonNullExpression" => {
+                Expression::TSNonNullExpression(ast.alloc(self.ts_non_null_expression(node)?))
+            }
+            "TSInstantiationExpression" => {
+                let Some(type_parameters) = self.type_arguments(node)? else {
+                    return Err(self.invalid(node, "typeParameters"));
+                };
+                Expression::TSInstantiationExpression(ast.alloc(TSInstantiationExpression {
+                    span,
+                    expression: self.req(node, "expression", Self::expression)?,
+                    type_parameters,
+                }))
+            }
+            "InvalidExpression" => {
+                Expression::InvalidExpression(ast.alloc(InvalidExpression { span }))
+            }
+            _ => return Err(self.unexpected(node, "expression")),
+        })
+    }
+
+    fn identifier_reference(&self, node: &Node) -> Result<IdentifierReference> {
+        self.expect(node, IDENTIFIERS, "Identifier")?;
+        Ok(IdentifierReference { span: self.span(node)?, name: self.name(node)? })
+    }
+
+    fn identifier_name(&self, node: &Node) -> Result<IdentifierName> {
+        self.expect(node, IDENTIFIERS, "Identifier")?;
+        Ok(IdentifierName { span: self.span(node)?, name: self.name(node)? })
+    }
+
+    fn binding_identifier(&self, node: &Node) -> Result<BindingIdentifier> {
+        self.expect(node, IDENTIFIERS, "Identifier")?;
+        Ok(BindingIdentifier { span: self.span(node)?, name: self.name(node)? })
+    }
+
+    fn label_identifier(&self, node: &Node) -> Result<LabelIdentifier> {
+        self.expect(node, IDENTIFIERS, "Identifier")?;
+        Ok(LabelIdentifier { span: self.span(node)?, name: self.name(node)? })
+    }
+
+    fn private_identifier(&self, node: &Node) -> Result<PrivateIdentifier> {
+        self.expect(node, &["PrivateIdentifier"], "PrivateIdentifier")?;
+        Ok(PrivateIdentifier { span: self.span(node)?, name: self.name(node)? })
+    }
+
+    fn name(&self, node: &Node) -> Result<Atom> {
+        get_str(node, "name").map(Atom::from).ok_or_else(|| self.invalid(node, "name"))
+    }
+
+    /// All literals, which are told apart by their value, `regex` and `bigint`
+    fn literal(&self, node: &Node) -> Result<Expression<'a>> {
+        self.expect(node, &["Literal"], "Literal")?;
+        let span = self.span(node)?;
+        let ast = &self.ast;
+        let raw = self.raw(node, span);
+        if let Some(regex) = get(node, "regex").and_then(Value::as_object) {
+            let pattern = get_str(regex, "pattern").ok_or_else(|| self.invalid(node, "regex"))?;
+            let mut flags = RegExpFlags::empty();
+            for flag in get_str(regex, "flags").unwrap_or_default().chars() {
+                flags |= match flag {
+                    'g' => RegExpFlags::G,
+                    'i' => RegExpFlags::I,
+                    'm' => RegExpFlags::M,
+                    's' => RegExpFlags::S,
+                    'u' => RegExpFlags::U,
+                    'y' => RegExpFlags::Y,
+                    'd' => RegExpFlags::D,
+                    'v' => RegExpFlags::V,
+                    _ => return Err(self.invalid(node, "regex")),
+                };
+            }
+            return Ok(ast.literal_regexp_expression(RegExpLiteral {
+                span,
+                value: EmptyObject,
+                regex: RegExp { pattern: Atom::from(pattern), flags },
+                pattern: None,
+            }));
+        }
+        // `bigint` in ESTree, the raw value in `Program::to_json`
+        let bigint = get_str(node, "bigint").or_else(|| {
+            let raw = raw.filter(|raw| raw.starts_with(|c: char| c.is_ascii_digit()))?;
+            raw.strip_suffix('n')
+        });
+        if let Some(bigint) = bigint {
+            let (radix, digits) = radix(bigint);
+            let value = BigUint::parse_bytes(digits.replace('_', "").as_bytes(), radix)
+                .ok_or_else(|| self.invalid(node, "bigint"))?;
+            return Ok(ast.literal_bigint_expression(BigintLiteral { span, value }));
+        }
+        Ok(match get(node, "value") {
+            Some(Value::Bool(value)) => {
+                ast.literal_boolean_expression(BooleanLiteral { span, value: *value })
+            }
+            Some(Value::String(value)) => ast.literal_string_expression(StringLiteral {
+                span,
+                value: Atom::from(value.as_str()),
+            }),
+            Some(Value::Number(number)) => {
+                let value = number.as_f64().ok_or_else(|| self.invalid(node, "value"))?;
+                self.number_literal(span, value, raw)
+            }
+            // Infinity is `null` in JSON
+            None if raw.map_or(false, |raw| raw != "null") => {
+                self.number_literal(span, f64::INFINITY, raw)
+            }
+            None => ast.literal_null_expression(NullLiteral { span }),
+            Some(_) => return Err(self.invalid(node, "value")),
+        })
+    }
+
+    fn number_literal(&self, span: Span, value: f64, raw: Option<&str>) -> Expression<'a> {
+        let raw = raw
+            .map_or_else(|| ryu_js::Buffer::new().format(value).to_string(), ToString::to_string);
+        let base = match radix(&raw).0 {
+            16 => NumberBase::Hex,
+            8 => NumberBase::Octal,
+            2 => NumberBase::Binary,
+            _ => NumberBase::Decimal,
+        };
+        let raw = self.ast.new_str(&raw);
+        self.ast.literal_number_expression(NumberLiteral::new(span, value, raw, base))
+    }
+
+    fn string_literal(&self, node: &Node) -> Result<StringLiteral> {
+        self.expect(node, &["Literal", "StringLiteral"], "string Literal")?;
+        let value =
+            get_str(node, "value").ok_or_else(|| self.unexpected(node, "string Literal"))?;
+        Ok(StringLiteral { span: self.span(node)?, value: Atom::from(value) })
+    }
+
+    fn template_literal(&self, node: &Node) -> Result<TemplateLiteral<'a>> {
+        self.expect(node, &["TemplateLiteral"], "TemplateLiteral")?;
+        Ok(TemplateLiteral {
+            span: self.span(node)?,
+            quasis: self.list(node, "quasis", Self::template_element)?,
+            expressions: self.list(node, "expressions", Self::expression)?,
+        })
+    }
+
+    fn template_element(&self, node: &Node) -> Result<TemplateElement> {
+        self.expect(node, &["TemplateElement"], "TemplateElement")?;
+        let value = self.child(node, "value")?;
+        Ok(TemplateElement {
+            span: self.span(node)?,
+            tail: get_bool(node, "tail"),
+            value: TemplateElementValue {
+                raw: get_str(value, "raw")
+                    .map(Atom::from)
+                    .ok_or_else(|| self.invalid(node, "value"))?,
+                cooked: get_str(value, "cooked").map(Atom::from),
+            },
+        })
+    }
+
+    fn member_expression(&self, node: &Node) -> Result<MemberExpression<'a>> {
+        self.expect(node, &["MemberExpression"], "MemberExpression")?;
+        let span = self.span(node)?;
+        let object = self.req(node, "object", Self::expression)?;
+        let optional = get_bool(node, "optional");
+        let property = self.child(node, "property")?;
+        Ok(if get_bool(node, "computed") {
+            MemberExpression::ComputedMemberExpression(ComputedMemberExpression {
+                span,
+                object,
+                expression: self.expression(property)?,
+                optional,
+            })
+        } else if node_type(property) == "PrivateIdentifier" {
+            MemberExpression::PrivateFieldExpression(PrivateFieldExpression {
+                span,
+                object,
+                field: self.private_identifier(property)?,
+                optional,
+            })
+        } else {
+            MemberExpression::StaticMemberExpression(StaticMemberExpression {
+                span,
+                object,
+                property: self.identifier_name(property)?,
+                optional,
+            })
+        })
+    }
+
+    fn call_expression(&self, node: &Node) -> Result<CallExpression<'a>> {
+        self.expect(node, &["CallExpression"], "CallExpression")?;
+        Ok(CallExpression {
+            span: self.span(node)?,
+            callee: self.req(node, "callee", Self::expression)?,
+            arguments: self.list(node, "arguments", Self::argument)?,
+            optional: get_bool(node, "optional"),
+            type_parameters: self.type_arguments(node)?,
+        })
+    }
+
+    fn chain_element(&self, node: &Node) -> Result<ChainElement<'a>> {
+        Ok(match node_type(node) {
+            "CallExpression" => {
+                ChainElement::CallExpression(self.ast.alloc(self.call_expression(node)?))
+            }
+            "MemberExpression" => {
+                ChainElement::MemberExpression(self.ast.alloc(self.member_expression(node)?))
+            }
+            _ => return Err(self.unexpected(node, "CallExpression or MemberExpression")),
+        })
+    }
+
+    fn unary_expression(&self, node: &Node) -> Result<UnaryExpression<'a>> {
+        self.expect(node, &["UnaryExpression"], "UnaryExpression")?;
+        Ok(UnaryExpression {
+            span: self.span(node)?,
+            operator: self.operator(node)?,
+            prefix: true,
+            argument: self.req(node, "argument", Self::expression)?,
+        })
+    }
+
+    fn spread_element(&self, node: &Node) -> Result<SpreadElement<'a>> {
+        Ok(SpreadElement {
+            span: self.span(node)?,
+            argument: self.req(node, "argument", Self::expression)?,
+        })
+    }
+
+    fn argument(&self, node: &Node) -> Result<Argument<'a>> {
+        Ok(match node_type(node) {
+            "SpreadElement" => Argument::SpreadElement(self.ast.alloc(self.spread_element(node)?)),
+            _ => Argument::Expression(self.expression(node)?),
+        })
+    }
+
+    fn object_property(&self, node: &Node) -> Result<ObjectProperty<'a>> {
+        Ok(match node_type(node) {
+            "SpreadElement" => {
+                ObjectProperty::SpreadProperty(self.ast.alloc(self.spread_element(node)?))
+            }
+            "Property" => {
+                let computed = get_bool(node, "computed");
+                let kind = match get_str(node, "kind") {
+                    None | Some("init") => PropertyKind::Init,
+                    Some("get") => PropertyKind::Get,
+                    Some("set") => PropertyKind::Set,
+                    Some(_) => return Err(self.invalid(node, "kind")),
+                };
+                ObjectProperty::Property(self.ast.alloc(Property {
+                    span: self.span(node)?,
+                    kind,
+                    key: self.property_key(self.child(node, "key")?, computed)?,
+                    value: PropertyValue::Expression(self.req(node, "value", Self::expression)?),
+                    method: get_bool(node, "method"),
+                    shorthand: get_bool(node, "shorthand"),
+                    computed,
+                }))
+            }
+            _ => return Err(self.unexpected(node, "Property or SpreadElement")),
+        })
+    }
+
+    fn property_key(&self, node: &Node, computed: bool) -> Result<PropertyKey<'a>> {
+        Ok(match node_type(node) {
+            "Identifier" | "IdentifierName" if !computed => {
+                PropertyKey::Identifier(self.ast.alloc(self.identifier_name(node)?))
+            }
+            "PrivateIdentifier" => {
+                PropertyKey::PrivateIdentifier(self.ast.alloc(self.private_identifier(node)?))
+            }
+            _ => PropertyKey::Expression(self.expression(node)?),
+        })
+    }
+}
+
+/* ---------- Assignment targets and patterns ---------- */
+
+impl<'a, 's> Reader<'a, 's> {
+    fn assignment_target(&self, node: &Node) -> Result<AssignmentTarget<'a>> {
+        let ast = &self.ast;
+        Ok(match node_type(node) {
+            "ObjectPattern" | "ObjectAssignmentTarget" => {
+                AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ObjectAssignmentTarget(
+                        ast.alloc(self.object_assignment_target(node)?),
+                    ),
+                )
+            }
+            "ArrayPattern" | "ArrayAssignmentTarget" => AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ArrayAssignmentTarget(
+                    ast.alloc(self.array_assignment_target(node)?),
+                ),
+            ),
+            _ => AssignmentTarget::SimpleAssignmentTarget(self.simple_assignment_target(node)?),
+        })
+    }
+
+    fn simple_assignment_target(&self, node: &Node) -> Result<SimpleAssignmentTarget<'a>> {
+        let ast = &self.ast;
+        Ok(match node_type(node) {
+            r#type if IDENTIFIERS.contains(&r#type) => {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(
+                    ast.alloc(self.identifier_reference(node)?),
+                )
+            }
+            "MemberExpression" => SimpleAssignmentTarget::MemberAssignmentTarget(
+                ast.alloc(self.member_expression(node)?),
+            ),
+            "TSAsExpression" => {
+                SimpleAssignmentTarget::TSAsExpression(ast.alloc(self.ts_as_expression(node)?))
+            }
+            "TSSatisfiesExpression" => SimpleAssignmentTarget::TSSatisfiesExpression(
+                ast.alloc(self.ts_satisfies_expression(node)?),
+            ),
+            "TSNonNullExpression" => SimpleAssignmentTarget::TSNonNullExpression(
+                ast.alloc(self.ts_non_null_expression(node)?),
+            ),
+            "TSTypeAssertion" => {
+                SimpleAssignmentTarget::TSTypeAssertion(ast.alloc(self.ts_type_assertion(node)?))
+            }
+            "ParenthesizedExpression" => {
+                self.req(node, "expression", Self::simple_assignment_target)?
+            }
+            _ => return Err(self.unexpected(node, "assignment target")),
+        })
+    }
+
+    fn assignment_target_maybe_default(
+        &self,
+        node: &Node,
+    ) -> Result<AssignmentTargetMaybeDefault<'a>> {
+        let ast = &self.ast;
+        Ok(match node_type(node) {
+            "AssignmentPattern" => AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                ast.alloc(AssignmentTargetWithDefault {
+                    span: self.span(node)?,
+                    binding: self.req(node, "left", Self::assignment_target)?,
+                    init: self.req(node, "right", Self::expression)?,
+                }),
+            ),
+            "AssignmentTargetWithDefault" => {
+                AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(ast.alloc(
+                    AssignmentTargetWithDefault {
+                        span: self.span(node)?,
+                        binding: self.req(node, "binding", Self::assignment_target)?,
+                        init: self.req(node, "init", Self::expression)?,
+                    },
+                ))
+            }
+            _ => AssignmentTargetMaybeDefault::AssignmentTarget(
+                ast.alloc(self.assignment_target(node)?),
+            ),
+        })
+    }
+
+    /// `[a, b = 1, ...c]`, the rest element is the last element in `ESTree`
+    fn array_assignment_target(&self, node: &Node) -> Result<ArrayAssignmentTarget<'a>> {
+        let mut elements = self.ast.new_vec();
+        let mut rest = self.opt(node, "rest", Self::assignment_target)?;
+        for value in get_array(node, "elements") {
+            if value.is_null() {
+                elements.push(None);
+                continue;
+            }
+            let element = as_node(value)?;
+            if node_type(element) == "RestElement" {
+                rest = Some(self.req(element, "argument", Self::assignment_target)?);
+            } else {
+                elements.push(Some(self.assignment_target_maybe_default(element)?));
+            }
+        }
+        Ok(ArrayAssignmentTarget {
+            span: self.span(node)?,
+            elements,
+            rest,
+            trailing_comma: self.trailing_comma(node)?,
+        })
+    }
+
+    /// `{ a, b: c, ...d }`, the rest element is the last property in `ESTree`
+    fn object_assignment_target(&self, node: &Node) -> Result<ObjectAssignmentTarget<'a>> {
+        let ast = &self.ast;
+        let mut properties = ast.new_vec();
+        let mut rest = self.opt(node, "rest", Self::assignment_target)?;
+        for value in get_array(node, "properties") {
+            let property = as_node(value)?;
+            let span = self.span(property)?;
+            match node_type(property) {
+                "RestElement" => {
+                    rest = Some(self.req(property, "argument", Self::assignment_target)?);
+                }
+                "Property" => properties.push(self.assignment_target_property(property)?),
+                "AssignmentTargetPropertyIdentifier" => {
+                    properties.push(AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(
+                        ast.alloc(AssignmentTargetPropertyIdentifier {
+                            span,
+                            binding: self.req(property, "binding", Self::identifier_reference)?,
+                            init: self.opt(property, "init", Self::expression)?,
+                        }),
+                    ));
+                }
+                "AssignmentTargetPropertyProperty" => {
+                    properties.push(AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+                        ast.alloc(AssignmentTargetPropertyProperty {
+                            span,
+                            name: self.property_key(self.child(property, "name")?, false)?,
+                            binding: self.req(
+                                property,
+                                "binding",
+                                Self::assignment_target_maybe_default,
+                            )?,
+                        }),
+                    ));
+                }
+                _ => return Err(self.unexpected(property, "Property or RestElement")),
+            }
+        }
+        Ok(ObjectAssignmentTarget { span: self.span(node)?, properties, rest })
+    }
+
+    /// A `Property` of an `ESTree` `ObjectPattern` in an assignment
+    fn assignment_target_property(&self, node: &Node) -> Result<AssignmentTargetProperty<'a>> {
+        let span = self.span(node)?;
+        let computed = get_bool(node, "computed");
+        let value = self.child(node, "value")?;
+        if get_bool(node, "shorthand") && !computed {
+            let (binding, init) = match node_type(value) {
+                "AssignmentPattern" => {
+                    (self.child(value, "left")?, Some(self.child(value, "right")?))
+                }
+                _ => (value, None),
+            };
+            if IDENTIFIERS.contains(&node_type(binding)) {
+                return Ok(AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(
+                    self.ast.alloc(AssignmentTargetPropertyIdentifier {
+                        span,
+                        binding: self.identifier_reference(binding)?,
+                        init: init.map(|init| self.expression(init)).transpose()?,
+                    }),
+                ));
+            }
+        }
+        Ok(AssignmentTargetProperty::AssignmentTargetPropertyProperty(self.ast.alloc(
+            AssignmentTargetPropertyProperty {
+                span,
+                name: self.property_key(self.child(node, "key")?, computed)?,
+                binding: self.assignment_target_maybe_default(value)?,
+            },
+        )))
+    }
+
+    /// Binding patterns have their type annotation and optional mark, e.g. `a?: T`
+    fn binding_pattern(&self, node: &Node) -> Result<BindingPattern<'a>> {
+        let ast = &self.ast;
+        let mut span = self.span(node)?;
+        let type_annotation = self.opt(node, "typeAnnotation", boxed(Self::ts_type_annotation))?;
+        let optional = get_bool(node, "optional");
+        // The type annotation is part of the pattern in `typescript-estree`
+        if let Some(type_annotation) = &type_annotation {
+            if (span.start..span.end).contains(&type_annotation.span.start) {
+                span.end = type_annotation.span.start;
+            }
+        }
+        let kind = match node_type(node) {
+            r#type if IDENTIFIERS.contains(&r#type) => {
+                let name = self.name(node)?;
+                // Exclude the `?` of an optional parameter
+                if span.source_text(self.source_text).starts_with(name.as_str()) {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let end = span.start + name.len() as u32;
+                    span.end = span.end.min(end);
+                }
+                BindingPatternKind::BindingIdentifier(ast.alloc(BindingIdentifier { span, name }))
+            }
+            "ObjectPattern" => BindingPatternKind::ObjectPattern(ast.alloc(ObjectPattern {
+                span,
+                properties: self.list(node, "properties", Self::object_pattern_property)?,
+            })),
+            "ArrayPattern" => BindingPatternKind::ArrayPattern(ast.alloc(ArrayPattern {
+                span,
+                elements: self.list_with_holes(node, "elements", Self::binding_pattern)?,
+            })),
+            "RestElement" => BindingPatternKind::RestElement(ast.alloc(RestElement {
+                span,
+                argument: self.req(node, "argument", Self::binding_pattern)?,
+            })),
+            "AssignmentPattern" => {
+                BindingPatternKind::AssignmentPattern(ast.alloc(AssignmentPattern {
+                    span,
+                    left: self.req(node, "left", Self::binding_pattern)?,
+                    right: self.req(node, "right", Self::expression)?,
+                }))
+            }
+            _ => return Err(self.unexpected(node, "binding pattern")),
+        };
+        Ok(BindingPattern { kind, type_annotation, optional })
+    }
+
+    fn object_pattern_property(&self, node: &Node) -> Result<ObjectPatternProperty<'a>> {
+        let span = self.span(node)?;
+        Ok(match node_type(node) {
+            "RestElement" => ObjectPatternProperty::RestElement(self.ast.alloc(RestElement {
+                span,
+                argument: self.req(node, "argument", Self::binding_pattern)?,
+            })),
+            "Property" => {
+                let computed = get_bool(node, "computed");
+                ObjectPatternProperty::Property(self.ast.alloc(Property {
+                    span,
+                    kind: PropertyKind::Init,
+                    key: self.property_key(self.child(node, "key")?, computed)?,
+                    value: PropertyValue::Pattern(self.req(
+                        node,
+                        "value",
+                        Self::binding_pattern,
+                    )?),
+                    method: false,
+                    shorthand: get_bool(node, "shorthand"),
+                    computed,
+                }))
+            }
+            _ => return Err(self.unexpected(node, "Property or RestElement")),
+        })
+    }
+}
+
+/* ---------- Functions and classes ---------- */
+
+impl<'a, 's> Reader<'a, 's> {
+    fn function(&self, node: &Node) -> Result<Function<'a>> {
+        let r#type = match node_type(node) {
+            "FunctionDeclaration" => FunctionType::FunctionDeclaration,
+            "FunctionExpression" => FunctionType::FunctionExpression,
+            // Functions without a body are `TSDeclareFunction`s, the same as in the parser
+            "TSDeclareFunction" | "TSEmptyBodyFunctionExpression" => {
+                FunctionType::TSDeclareFunction
+            }
+            _ => return Err(self.unexpected(node, "function")),
+        };
+        let span = self.span(node)?;
+        Ok(Function {
+            r#type,
+            span,
+            id: self.opt(node, "id", Self::binding_identifier)?,
+            expression: false,
+            generator: get_bool(node, "generator"),
+            r#async: get_bool(node, "async"),
+            params: self.formal_parameters(node, FormalParameterKind::FormalParameter)?,
+            body: self.opt(node, "body", boxed(Self::function_body))?,
+            type_parameters: self.type_parameters(node)?,
+            return_type: self.return_type(node)?,
+            modifiers: self.modifiers(node)?,
+        })
+    }
+
+    /// `params` of a function or signature, spanning from the first to the last parameter
+    fn formal_parameters(
+        &self,
+        node: &Node,
+        kind: FormalParameterKind,
+    ) -> Result<Box<'a, FormalParameters<'a>>> {
+        let items = self.list(node, "params", Self::formal_parameter)?;
+        let span = match (items.first(), items.last()) {
+            (Some(first), Some(last)) => Span::new(first.span.start, last.span.end),
+            _ => Span::default(),
+        };
+        Ok(self.ast.formal_parameters(span, kind, items))
+    }
+
+    fn formal_parameter(&self, node: &Node) -> Result<FormalParameter<'a>> {
+        let pattern = match node_type(node) {
+            "TSParameterProperty" => self.req(node, "parameter", Self::binding_pattern)?,
+            _ => self.binding_pattern(node)?,
+        };
+        // The span of the flattened parameter of `Program::to_json` is the span of the pattern
+        let mut span = self.span(node)?;
+        if let Some(type_annotation) = &pattern.type_annotation {
+            span.end = span.end.max(type_annotation.span.end);
+        }
+        Ok(FormalParameter {
+            span,
+            pattern,
+            accessibility: self.accessibility(node)?,
+            readonly: get_bool(node, "readonly"),
+            decorators: self.decorators(node)?,
+        })
+    }
+
+    fn function_body(&self, node: &Node) -> Result<FunctionBody<'a>> {
+        self.expect(node, &["BlockStatement"], "BlockStatement")?;
+        let (directives, statements) = self.directives_and_statements(node)?;
+        Ok(FunctionBody { span: self.span(node)?, directives, statements })
+    }
+
+    fn arrow_expression(&self, node: &Node) -> Result<ArrowExpression<'a>> {
+        let body = self.child(node, "body")?;
+        let (expression, body) = if node_type(body) == "BlockStatement" {
+            (get_bool(node, "expression"), self.ast.alloc(self.function_body(body)?))
+        } else {
+            // The expression body is an expression statement in the AST
+            let span = self.span(body)?;
+            let expression = self.expression(body)?;
+            let statement = self.ast.expression_statement(span, expression);
+            (
+                true,
+                self.ast.function_body(
+                    span,
+                    self.ast.new_vec(),
+                    self.ast.new_vec_single(statement),
+                ),
+            )
+        };
+        Ok(ArrowExpression {
+            span: self.span(node)?,
+            expression,
+            generator: false,
+            r#async: get_bool(node, "async"),
+            params: self.formal_parameters(node, FormalParameterKind::ArrowFormalParameters)?,
+            body,
+            type_parameters: self.type_parameters(node)?,
+            return_type: self.return_type(node)?,
+        })
+    }
+
+    fn class(&self, node: &Node) -> Result<Class<'a>> {
+        let r#type = match node_type(node) {
+            "ClassDeclaration" => ClassType::ClassDeclaration,
+            "ClassExpression" => ClassType::ClassExpression,
+            _ => return Err(self.unexpected(node, "class")),
+        };
+        let body = self.child(node, "body")?;
+        self.expect(body, &["ClassBody"], "ClassBody")?;
+        let super_type_parameters = match get(node, "superTypeParameters") {
+            Some(_) => {
+                self.opt(node, "superTypeParameters", Self::ts_type_parameter_instantiation)?
+            }
+            None => self.opt(node, "superTypeArguments", Self::ts_type_parameter_instantiation)?,
+        };
+        let implements = match get(node, "implements") {
+            Some(_) => Some(self.list(node, "implements", boxed(Self::ts_class_implements))?),
+            None => None,
+        };
+        Ok(Class {
+            r#type,
+            span: self.span(node)?,
+            id: self.opt(node, "id", Self::binding_identifier)?,
+            super_class: self.opt(node, "superClass", Self::expression)?,
+            body: self.ast.alloc(ClassBody {
+                span: self.span(body)?,
+                body: self.list(body, "body", Self::class_element)?,
+            }),
+            type_parameters: self.type_parameters(node)?,
+            super_type_parameters: super_type_parameters.map(|params| self.ast.alloc(params)),
+            implements,
+            decorators: self.decorators(node)?,
+            modifiers: self.modifiers(node)?,
+        })
+    }
+
+    fn class_element(&self, node: &Node) -> Result<ClassElement<'a>> {
+        let ast = &self.ast;
+        let span = self.span(node)?;
+        Ok(match node_type(node) {
+            "StaticBlock" => ClassElement::StaticBlock(
+                ast.alloc(StaticBlock { span, body: self.list(node, "body", Self::statement)? }),
+            ),
+            "MethodDefinition" => {
+                ClassElement::MethodDefinition(ast.alloc(self.method_definition(node)?))
+            }
+            "TSAbstractMethodDefinition" => {
+                ClassElement::TSAbstractMethodDefinition(ast.alloc(TSAbstractMethodDefinition {
+                    method_definition: self.method_definition(node)?,
+                }))
+            }
+            "PropertyDefinition" => {
+                ClassElement::PropertyDefinition(ast.alloc(self.property_definition(node)?))
+            }
+            "TSAbstractPropertyDefinition" => ClassElement::TSAbstractPropertyDefinition(
+                ast.alloc(TSAbstractPropertyDefinition {
+                    property_definition: self.property_definition(node)?,
+                }),
+            ),
+            "AccessorProperty" => {
+                let computed = get_bool(node, "computed");
+                ClassElement::AccessorProperty(ast.alloc(AccessorProperty {
+                    span,
+                    key: self.property_key(self.child(node, "key")?, computed)?,
+                    value: self.opt(node, "value", Self::expression)?,
+                    computed,
+                    r#static: get_bool(node, "static"),
+                }))
+            }
+            "TSIndexSignature" => {
+                ClassElement::TSIndexSignature(ast.alloc(self.ts_index_signature(node)?))
+            }
+            _ => return Err(self.unexpected(node, "class element")),
+        })
+    }
+
+    fn method_definition(&self, node: &Node) -> Result<MethodDefinition<'a>> {
+        let computed = get_bool(node, "computed");
+        let kind = match get_str(node, "kind") {
+            Some("constructor") => MethodDefinitionKind::Constructor,
+            Some("method") => MethodDefinitionKind::Method,
+            Some("get") => MethodDefinitionKind::Get,
+            Some("set") => MethodDefinitionKind::Set,
+            _ => return Err(self.invalid(node, "kind")),
+        };
+        Ok(MethodDefinition {
+            span: self.span(node)?,
+            key: self.property_key(self.child(node, "key")?, computed)?,
+            value: self.req(node, "value", boxed(Self::function))?,
+            kind,
+            computed,
+            r#static: get_bool(node, "static"),
+            r#override: get_bool(node, "override"),
+            optional: get_bool(node, "optional"),
+            accessibility: self.accessibility(node)?,
+            decorators: self.decorators(node)?,
+        })
+    }
+
+    fn property_definition(&self, node: &Node) -> Result<PropertyDefinition<'a>> {
+        let computed = get_bool(node, "computed");
+        Ok(PropertyDefinition {
+            span: self.span(node)?,
+            key: self.property_key(self.child(node, "key")?, computed)?,
+            value: self.opt(node, "value", Self::expression)?,
+            computed,
+            r#static: get_bool(node, "static"),
+            declare: get_bool(node, "declare"),
+            r#override: get_bool(node, "override"),
+            optional: get_bool(node, "optional"),
+            definite: get_bool(node, "definite"),
+            readonly: get_bool(node, "readonly"),
+            type_annotation: self.opt(node, "typeAnnotation", boxed(Self::ts_type_annotation))?,
+            accessibility: self.accessibility(node)?,
+            decorators: self.decorators(node)?,
+        })
+    }
+}
+
+/* ---------- Modules ---------- */
+
+impl<'a, 's> Reader<'a, 's> {
+    fn module_declaration(&self, node: &Node) -> Result<ModuleDeclaration<'a>> {
+        let ast = &self.ast;
+        let span = self.span(node)?;
+        let kind = match node_type(node) {
+            "ImportDeclaration" => {
+                ModuleDeclarationKind::ImportDeclaration(ast.alloc(ImportDeclaration {
+                    specifiers: self.list(node, "specifiers", Self::import_specifier)?,
+                    source: self.req(node, "source", Self::string_literal)?,
+                    assertions: self.import_attributes(node)?,
+                    import_kind: self.import_or_export_kind(node, "importKind")?,
+                }))
+            }
+            "ExportAllDeclaration" => {
+                ModuleDeclarationKind::ExportAllDeclaration(ast.alloc(ExportAllDeclaration {
+                    exported: self.opt(node, "exported", Self::module_export_name)?,
+                    source: self.req(node, "source", Self::string_literal)?,
+                    assertions: self.import_attributes(node)?,
+                    export_kind: self.import_or_export_kind(node, "exportKind")?,
+                }))
+            }
+            "ExportDefaultDeclaration" => {
+                let exported = self.opt(node, "exported", Self::module_export_name)?;
+                let exported = exported.unwrap_or_else(|| self.default_keyword(span));
+                ModuleDeclarationKind::ExportDefaultDeclaration(ast.alloc(
+                    ExportDefaultDeclaration {
+                        declaration: self.req(
+                            node,
+                            "declaration",
+                            Self::export_default_declaration_kind,
+                        )?,
+                        exported,
+                    },
+                ))
+            }
+            "ExportNamedDeclaration" => {
+                ModuleDeclarationKind::ExportNamedDeclaration(ast.alloc(ExportNamedDeclaration {
+                    declaration: self.opt(node, "declaration", Self::declaration)?,
+                    specifiers: self.list(node, "specifiers", Self::export_specifier)?,
+                    source: self.opt(node, "source", Self::string_literal)?,
+                    export_kind: self.import_or_export_kind(node, "exportKind")?,
+                }))
+            }
+            "TSExportAssignment" => {
+                ModuleDeclarationKind::TSExportAssignment(ast.alloc(TSExportAssignment {
+                    span,
+                    expression: self.req(node, "expression", Self::expression)?,
+                }))
+            }
+            "TSNamespaceExportDeclaration" => ModuleDeclarationKind::TSNamespaceExportDeclaration(
+                ast.alloc(TSNamespaceExportDeclaration {
+                    span,
+                    id: self.req(node, "id", Self::identifier_name)?,
+                }),
+            ),
+            _ => return Err(self.unexpected(node, "module declaration")),
+        };
+        Ok(ModuleDeclaration { span, kind })
+    }
+
+    /// The `default` keyword after `export`, which is not a node in `ESTree`
+    fn default_keyword(&self, span: Span) -> ModuleExportName {
+        let start = span.start + 7;
+        let is_keyword = self
+            .source_text
+            .get(start as usize..)
+            .map_or(false, |text| text.starts_with("default"));
+        let span = if is_keyword {
+            Span::new(start, start + 7)
+        } else {
+            Span::new(span.start, span.start)
+        };
+        ModuleExportName::Identifier(IdentifierName { span, name: "default".into() })
+    }
+
+    /// `assertions`, or `attributes` in newer versions of `ESTree`
+    fn import_attributes(&self, node: &Node) -> Result<Option<Vec<'a, ImportAttribute>>> {
+        let key = if get(node, "assertions").is_some() { "assertions" } else { "attributes" };
+        let attributes = self.list(node, key, |r, node| {
+            r.expect(node, &["ImportAttribute"], "ImportAttribute")?;
+            let key = r.child(node, "key")?;
+            let key = match node_type(key) {
+                "Literal" => ImportAttributeKey::StringLiteral(r.string_literal(key)?),
+                _ => ImportAttributeKey::Identifier(r.identifier_name(key)?),
+            };
+            Ok(ImportAttribute {
+                span: r.span(node)?,
+                key,
+                value: r.req(node, "value", Self::string_literal)?,
+            })
+        })?;
+        Ok((!attributes.is_empty()).then_some(attributes))
+    }
+
+    fn import_specifier(&self, node: &Node) -> Result<ImportDeclarationSpecifier> {
+        let span = self.span(node)?;
+        let local = self.req(node, "local", Self::binding_identifier)?;
+        Ok(match node_type(node) {
+            "ImportSpecifier" => ImportDeclarationSpecifier::ImportSpecifier(ImportSpecifier {
+                span,
+                imported: self.req(node, "imported", Self::module_export_name)?,
+                local,
+            }),
+            "ImportDefaultSpecifier" => {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(ImportDefaultSpecifier {
+                    span,
+                    local,
+                })
+            }
+            "ImportNamespaceSpecifier" => {
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(ImportNamespaceSpecifier {
+                    span,
+                    local,
+                })
+            }
+            _ => return Err(self.unexpected(node, "import specifier")),
+        })
+    }
+
+    fn export_specifier(&self, node: &Node) -> Result<ExportSpecifier> {
+        self.expect(node, &["ExportSpecifier"], "ExportSpecifier")?;
+        Ok(ExportSpecifier {
+            span: self.span(node)?,
+            local: self.req(node, "local", Self::module_export_name)?,
+            exported: self.req(node, "exported", Self::module_export_name)?,
+        })
+    }
+
+    fn module_export_name(&self, node: &Node) -> Result<ModuleExportName> {
+        Ok(match node_type(node) {
+            "Literal" => ModuleExportName::StringLiteral(self.string_literal(node)?),
+            _ => ModuleExportName::Identifier(self.identifier_name(node)?),
+        })
+    }
+
+    fn export_default_declaration_kind(
+        &self,
+        node: &Node,
+    ) -> Result<ExportDefaultDeclarationKind<'a>> {
+        let ast = &self.ast;
+        Ok(match node_type(node) {
+            "FunctionDeclaration" | "TSDeclareFunction" => {
+                ExportDefaultDeclarationKind::FunctionDeclaration(ast.alloc(self.function(node)?))
+            }
+            "ClassDeclaration" => {
+                ExportDefaultDeclarationKind::ClassDeclaration(ast.alloc(self.class(node)?))
+            }
+            "TSInterfaceDeclaration" => ExportDefaultDeclarationKind::TSInterfaceDeclaration(
+                ast.alloc(self.ts_interface_declaration(node)?),
+            ),
+            "TSEnumDeclaration" => ExportDefaultDeclarationKind::TSEnumDeclaration(
+                ast.alloc(self.ts_enum_declaration(node)?),
+            ),
+            _ => ExportDefaultDeclarationKind::Expression(self.expression(node)?),
+        })
+    }
+}
+
+/* ---------- JSX ---------- */
+
+impl<'a, 's> Reader<'a, 's> {
+    fn jsx_element(&self, node: &Node) -> Result<JSXElement<'a>> {
+        self.expect(node, &["JSXElement"], "JSXElement")?;
+        let opening_element = self.child(node, "openingElement")?;
+        self.expect(opening_element, &["JSXOpeningElement"], "JSXOpeningElement")?;
+        let closing_element = self.opt(node, "closingElement", |r, node| {
+            r.expect(node, &["JSXClosingElement"], "JSXClosingElement")?;
+            Ok(r.ast.alloc(JSXClosingElement {
+                span: r.span(node)?,
+                name: r.req(node, "name", Self::jsx_element_name)?,
+            }))
+        })?;
+        Ok(JSXElement {
+            span: self.span(node)?,
+            opening_element: self.ast.alloc(JSXOpeningElement {
+                span: self.span(opening_element)?,
+                self_closing: get_bool(opening_element, "selfClosing"),
+                name: self.req(opening_element, "name", Self::jsx_element_name)?,
+                attributes: self.list(opening_element, "attributes", Self::jsx_attribute_item)?,
+                type_parameters: self.type_arguments(opening_element)?,
+            }),
+            closing_element,
+            children: self.list(node, "children", Self::jsx_child)?,
+        })
+    }
+
+    fn jsx_fragment(&self, node: &Node) -> Result<JSXFragment<'a>> {
+        self.expect(node, &["JSXFragment"], "JSXFragment")?;
+        Ok(JSXFragment {
+            span: self.span(node)?,
+            opening_fragment: JSXOpeningFragment {
+                span: self.req(node, "openingFragment", Self::span)?,
+            },
+            closing_fragment: JSXClosingFragment {
+                span: self.req(node, "closingFragment", Self::span)?,
+            },
+            children: self.list(node, "children", Self::jsx_child)?,
+        })
+    }
+
+    fn jsx_identifier(&self, node: &Node) -> Result<JSXIdentifier> {
+        self.expect(node, &["JSXIdentifier"], "JSXIdentifier")?;
+        Ok(JSXIdentifier { span: self.span(node)?, name: self.name(node)? })
+    }
+
+    fn jsx_namespaced_name(&self, node: &Node) -> Result<JSXNamespacedName> {
+        // `name` in ESTree, `property` in `Program::to_json`
+        let key = if get(node, "name").is_some() { "name" } else { "property" };
+        Ok(JSXNamespacedName {
+            span: self.span(node)?,
+            namespace: self.req(node, "namespace", Self::jsx_identifier)?,
+            property: self.req(node, key, Self::jsx_identifier)?,
+        })
+    }
+
+    fn jsx_member_expression(&self, node: &Node) -> Result<JSXMemberExpression<'a>> {
+        let object = self.child(node, "object")?;
+        let object = match node_type(object) {
+            "JSXMemberExpression" => JSXMemberExpressionObject::MemberExpression(
+                self.ast.alloc(self.jsx_member_expression(object)?),
+            ),
+            _ => JSXMemberExpressionObject::Identifier(self.jsx_identifier(object)?),
+        };
+        Ok(JSXMemberExpression {
+            span: self.span(node)?,
+            object,
+            property: self.req(node, "property", Self::jsx_identifier)?,
+        })
+    }
+
+    fn jsx_element_name(&self, node: &Node) -> Result<JSXElementName<'a>> {
+        Ok(match node_type(node) {
+            "JSXNamespacedName" => {
+                JSXElementName::NamespacedName(self.ast.alloc(self.jsx_namespaced_name(node)?))
+            }
+            "JSXMemberExpression" => {
+                JSXElementName::MemberExpression(self.ast.alloc(self.jsx_member_expression(node)?))
+            }
+            _ => JSXElementName::Identifier(self.jsx_identifier(node)?),
+        })
+    }
+
+    fn jsx_attribute_item(&self, node: &Node) -> Result<JSXAttributeItem<'a>> {
+        let ast = &self.ast;
+        let span = self.span(node)?;
+        Ok(match node_type(node) {
+            "JSXAttribute" => {
+                let name = self.child(node, "name")?;
+                let name = match node_type(name) {
+                    "JSXNamespacedName" => {
+                        JSXAttributeName::NamespacedName(ast.alloc(self.jsx_namespaced_name(name)?))
+                    }
+                    _ => JSXAttributeName::Identifier(self.jsx_identifier(name)?),
+                };
+                let value = self.opt(node, "value", |r, node| {
+                    Ok(match node_type(node) {
+                        "JSXExpressionContainer" => JSXAttributeValue::ExpressionContainer(
+                            r.jsx_expression_container(node)?,
+                        ),
+                        "JSXElement" => {
+                            JSXAttributeValue::Element(r.ast.alloc(r.jsx_element(node)?))
+                        }
+                        "JSXFragment" => {
+                            JSXAttributeValue::Fragment(r.ast.alloc(r.jsx_fragment(node)?))
+                        }
+                        _ => JSXAttributeValue::StringLiteral(r.string_literal(node)?),
+                    })
+                })?;
+                JSXAttributeItem::Attribute(ast.alloc(JSXAttribute { span, name, value }))
+            }
+            "JSXSpreadAttribute" => {
+                JSXAttributeItem::SpreadAttribute(ast.alloc(JSXSpreadAttribute {
+                    span,
+                    argument: self.req(node, "argument", Self::expression)?,
+                }))
+            }
+            _ => return Err(self.unexpected(node, "JSXAttribute or JSXSpreadAttribute")),
+        })
+    }
+
+    fn jsx_expression_container(&self, node: &Node) -> Result<JSXExpressionContainer<'a>> {
+        self.expect(node, &["JSXExpressionContainer"], "JSXExpressionContainer")?;
+        let expression = self.child(node, "expression")?;
+        let expression = match node_type(expression) {
+            "JSXEmptyExpression" => {
+                JSXExpression::EmptyExpression(JSXEmptyExpression { span: self.span(expression)? })
+            }
+            _ => JSXExpression::Expression(self.expression(expression)?),
+        };
+        Ok(JSXExpressionContainer { span: self.span(node)?, expression })
+    }
+
+    fn jsx_child(&self, node: &Node) -> Result<JSXChild<'a>> {
+        let span = self.span(node)?;
+        Ok(match node_type(node) {
+            "JSXText" => JSXChild::Text(JSXText {
+                span,
+                value: get_str(node, "value")
+                    .map(Atom::from)
+                    .ok_or_else(|| self.invalid(node, "value"))?,
+            }),
+            "JSXElement" => JSXChild::Element(self.ast.alloc(self.jsx_element(node)?)),
+            "JSXFragment" => JSXChild::Fragment(self.ast.alloc(self.jsx_fragment(node)?)),
+            "JSXExpressionContainer" => {
+                JSXChild::ExpressionContainer(self.jsx_expression_container(node)?)
+            }
+            "JSXSpreadChild" => JSXChild::Spread(JSXSpreadChild {
+                span,
+                expression: self.req(node, "expression", Self::expression)?,
+            }),
+            _ => return Err(self.unexpected(node, "JSX child")),
+        })
+    }
+}
+
+/* ---------- TypeScript ---------- */
+
+impl<'a, 's> Reader<'a, 's> {
+    fn ts_as_expression(&self, node: &Node) -> Result<TSAsExpression<'a>> {
+        self.expect(node, &["TSAsExpression"], "TSAsExpression")?;
+        Ok(TSAsExpression {
+            span: self.span(node)?,
+            expression: self.req(node, "expression", Self::expression)?,
+            type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+        })
+    }
+
+    fn ts_satisfies_expression(&self, node: &Node) -> Result<TSSatisfiesExpression<'a>> {
+        self.expect(node, &["TSSatisfiesExpression"], "TSSatisfiesExpression")?;
+        Ok(TSSatisfiesExpression {
+            span: self.span(node)?,
+            expression: self.req(node, "expression", Self::expression)?,
+            type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+        })
+    }
+
+    fn ts_type_assertion(&self, node: &Node) -> Result<TSTypeAssertion<'a>> {
+        self.expect(node, &["TSTypeAssertion"], "TSTypeAssertion")?;
+        Ok(TSTypeAssertion {
+            span: self.span(node)?,
+            expression: self.req(node, "expression", Self::expression)?,
+            type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+        })
+    }
+
+    fn ts_non_null_expression(&self, node: &Node) -> Result<TSNonNullExpression<'a>> {
+        self.expect(node, &["TSNonNullExpression"], "TSNonNullExpression")?;
+        Ok(TSNonNullExpression {
+            span: self.span(node)?,
+            expression: self.req(node, "expression", Self::expression)?,
+        })
+    }
+
+    fn ts_type_annotation(&self, node: &Node) -> Result<TSTypeAnnotation<'a>> {
+        self.expect(node, &["TSTypeAnnotation"], "TSTypeAnnotation")?;
+        Ok(TSTypeAnnotation {
+            span: self.span(node)?,
+            type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+        })
+    }
+
+    fn ts_type_parameter_declaration(&self, node: &Node) -> Result<TSTypeParameterDeclaration<'a>> {
+        self.expect(node, &["TSTypeParameterDeclaration"], "TSTypeParameterDeclaration")?;
+        Ok(TSTypeParameterDeclaration {
+            span: self.span(node)?,
+            params: self.list(node, "params", boxed(Self::ts_type_parameter))?,
+        })
+    }
+
+    fn ts_type_parameter_instantiation(
+        &self,
+        node: &Node,
+    ) -> Result<TSTypeParameterInstantiation<'a>> {
+        self.expect(node, &["TSTypeParameterInstantiation"], "TSTypeParameterInstantiation")?;
+        Ok(TSTypeParameterInstantiation {
+            span: self.span(node)?,
+            params: self.list(node, "params", Self::ts_type)?,
+        })
+    }
+
+    fn ts_type_parameter(&self, node: &Node) -> Result<TSTypeParameter<'a>> {
+        self.expect(node, &["TSTypeParameter"], "TSTypeParameter")?;
+        let span = self.span(node)?;
+        // The name is a string in older versions of `typescript-estree`
+        let name = match get(node, "name") {
+            Some(Value::String(name)) => {
+                #[allow(clippy::cast_possible_truncation)]
+                let end = span.start + name.len() as u32;
+                BindingIdentifier {
+                    span: Span::new(span.start, end.min(span.end)),
+                    name: Atom::from(name.as_str()),
+                }
+            }
+            _ => self.req(node, "name", Self::binding_identifier)?,
+        };
+        Ok(TSTypeParameter {
+            span,
+            name,
+            constraint: self.opt(node, "constraint", Self::ts_type)?,
+            default: self.opt(node, "default", Self::ts_type)?,
+            r#in: get_bool(node, "in"),
+            out: get_bool(node, "out"),
+        })
+    }
+
+    /// `A.B.C` in types, a `MemberExpression` in `implements` in some versions of `typescript-estree`
+    fn ts_type_name(&self, node: &Node) -> Result<TSTypeName<'a>> {
+        let ast = &self.ast;
+        Ok(match node_type(node) {
+            "TSQualifiedName" => {
+                TSTypeName::QualifiedName(ast.alloc(self.ts_qualified_name(node)?))
+            }
+            "MemberExpression" if !get_bool(node, "computed") => {
+                TSTypeName::QualifiedName(ast.alloc(TSQualifiedName {
+                    span: self.span(node)?,
+                    left: self.req(node, "object", Self::ts_type_name)?,
+                    right: self.req(node, "property", Self::identifier_name)?,
+                }))
+            }
+            r#type if IDENTIFIERS.contains(&r#type) => {
+                TSTypeName::IdentifierName(ast.alloc(self.identifier_name(node)?))
+            }
+            _ => return Err(self.unexpected(node, "type name")),
+        })
+    }
+
+    fn ts_qualified_name(&self, node: &Node) -> Result<TSQualifiedName<'a>> {
+        Ok(TSQualifiedName {
+            span: self.span(node)?,
+            left: self.req(node, "left", Self::ts_type_name)?,
+            right: self.req(node, "right", Self::identifier_name)?,
+        })
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn ts_type(&self, node: &Node) -> Result<TSType<'a>> {
+        let ast = &self.ast;
+        let span = self.span(node)?;
+        Ok(match node_type(node) {
+            "TSAnyKeyword" => TSType::TSAnyKeyword(ast.alloc(TSAnyKeyword { span })),
+            "TSBigIntKeyword" => TSType::TSBigIntKeyword(ast.alloc(TSBigIntKeyword { span })),
+            "TSBooleanKeyword" => TSType::TSBooleanKeyword(ast.alloc(TSBooleanKeyword { span })),
+            "TSNeverKeyword" => TSType::TSNeverKeyword(ast.alloc(TSNeverKeyword { span })),
+            "TSNullKeyword" => TSType::TSNullKeyword(ast.alloc(TSNullKeyword { span })),
+            "TSNumberKeyword" => TSType::TSNumberKeyword(ast.alloc(TSNumberKeyword { span })),
+            "TSObjectKeyword" => TSType::TSObjectKeyword(ast.alloc(TSObjectKeyword { span })),
+            "TSStringKeyword" => TSType::TSStringKeyword(ast.alloc(TSStringKeyword { span })),
+            "TSSymbolKeyword" => TSType::TSSymbolKeyword(ast.alloc(TSSymbolKeyword { span })),
+            "TSThisType" | "TSThisKeyword" => {
+                TSType::TSThisKeyword(ast.alloc(TSThisKeyword { span }))
+            }
+            "TSUndefinedKeyword" => {
+                TSType::TSUndefinedKeyword(ast.alloc(TSUndefinedKeyword { span }))
+            }
+            "TSUnknownKeyword" => TSType::TSUnknownKeyword(ast.alloc(TSUnknownKeyword { span })),
+            "TSVoidKeyword" => TSType::TSVoidKeyword(ast.alloc(TSVoidKeyword { span })),
+            "TSArrayType" => TSType::TSArrayType(ast.alloc(TSArrayType {
+                span,
+                element_type: self.req(node, "elementType", Self::ts_type)?,
+            })),
+            "TSConditionalType" => TSType::TSConditionalType(ast.alloc(TSConditionalType {
+                span,
+                check_type: self.req(node, "checkType", Self::ts_type)?,
+                extends_type: self.req(node, "extendsType", Self::ts_type)?,
+                true_type: self.req(node, "trueType", Self::ts_type)?,
+                false_type: self.req(node, "falseType", Self::ts_type)?,
+            })),
+            "TSConstructorType" => TSType::TSConstructorType(ast.alloc(TSConstructorType {
+                span,
+                r#abstract: get_bool(node, "abstract"),
+                params: self.formal_parameters(node, FormalParameterKind::Signature)?,
+                return_type: self.req(node, "returnType", boxed(Self::ts_type_annotation))?,
+                type_parameters: self.type_parameters(node)?,
+            })),
+            "TSFunctionType" => TSType::TSFunctionType(ast.alloc(TSFunctionType {
+                span,
+                params: self.formal_parameters(node, FormalParameterKind::Signature)?,
+                return_type: self.req(node, "returnType", boxed(Self::ts_type_annotation))?,
+                type_parameters: self.type_parameters(node)?,
+            })),
+            "TSImportType" => {
+                // `argument` in newer versions of `typescript-estree`
+                let key = if get(node, "parameter").is_some() { "parameter" } else { "argument" };
+                TSType::TSImportType(ast.alloc(TSImportType {
+                    span,
+                    is_type_of: get_bool(node, "isTypeOf"),
+                    parameter: self.req(node, key, Self::ts_type)?,
+                    qualifier: self.opt(node, "qualifier", Self::ts_type_name)?,
+                    type_parameters: self.type_arguments(node)?,
+                }))
+            }
+            "TSIndexedAccessType" => TSType::TSIndexedAccessType(ast.alloc(TSIndexedAccessType {
+                span,
+                object_type: self.req(node, "objectType", Self::ts_type)?,
+                index_type: self.req(node, "indexType", Self::ts_type)?,
+            })),
+            "TSInferType" => TSType::TSInferType(ast.alloc(TSInferType {
+                span,
+                type_parameter: self.req(node, "typeParameter", boxed(Self::ts_type_parameter))?,
+            })),
+            "TSIntersectionType" => TSType::TSIntersectionType(ast.alloc(TSIntersectionType {
+                span,
+                types: self.list(node, "types", Self::ts_type)?,
+            })),
+            "TSLiteralType" => TSType::TSLiteralType(ast.alloc(TSLiteralType {
+                span,
+                literal: self.req(node, "literal", Self::ts_literal)?,
+            })),
+            "TSMappedType" => TSType::TSMappedType(ast.alloc(TSMappedType {
+                span,
+                type_parameter: self.req(node, "typeParameter", boxed(Self::ts_type_parameter))?,
+                name_type: self.opt(node, "nameType", Self::ts_type)?,
+                type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+                optional: self.ts_mapped_type_modifier(node, "optional")?,
+                readonly: self.ts_mapped_type_modifier(node, "readonly")?,
+            })),
+            "TSQualifiedName" => TSType::TSQualifiedName(ast.alloc(self.ts_qualified_name(node)?)),
+            "TSTemplateLiteralType" => {
+                TSType::TSTemplateLiteralType(ast.alloc(TSTemplateLiteralType {
+                    span,
+                    quasis: self.list(node, "quasis", Self::template_element)?,
+                    types: self.list(node, "types", Self::ts_type)?,
+                }))
+            }
+            "TSTupleType" => TSType::TSTupleType(ast.alloc(TSTupleType {
+                span,
+                element_types: self.list(node, "elementTypes", Self::ts_tuple_element)?,
+            })),
+            "TSTypeLiteral" => TSType::TSTypeLiteral(ast.alloc(TSTypeLiteral {
+                span,
+                members: self.list(node, "members", Self::ts_signature)?,
+            })),
+            "TSTypeOperator" => {
+                let operator = match get_str(node, "operator") {
+                    // The operator is `null` in `Program::to_json`
+                    None | Some("keyof") => TSTypeOperator::Keyof,
+                    Some("unique") => TSTypeOperator::Unique,
+                    Some("readonly") => TSTypeOperator::Readonly,
+                    Some(_) => return Err(self.invalid(node, "operator")),
+                };
+                TSType::TSTypeOperatorType(ast.alloc(TSTypeOperatorType {
+                    span,
+                    operator,
+                    type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+                }))
+            }
+            "TSTypePredicate" => {
+                let parameter_name = self.child(node, "parameterName")?;
+                let parameter_name = match node_type(parameter_name) {
+                    "TSThisType" | "TSThisKeyword" => TSTypePredicateName::This(TSThisKeyword {
+                        span: self.span(parameter_name)?,
+                    }),
+                    _ => TSTypePredicateName::Identifier(self.identifier_name(parameter_name)?),
+                };
+                TSType::TSTypePredicate(ast.alloc(TSTypePredicate {
+                    span,
+                    parameter_name,
+                    asserts: get_bool(node, "asserts"),
+                    type_annotation: self.opt(
+                        node,
+                        "typeAnnotation",
+                        boxed(Self::ts_type_annotation),
+                    )?,
+                }))
+            }
+            "TSTypeQuery" => TSType::TSTypeQuery(ast.alloc(TSTypeQuery {
+                span,
+                expr_name: self.req(node, "exprName", Self::ts_type_name)?,
+                type_parameters: self.type_arguments(node)?,
+            })),
+            "TSTypeReference" => TSType::TSTypeReference(ast.alloc(TSTypeReference {
+                span,
+                type_name: self.req(node, "typeName", Self::ts_type_name)?,
+                type_parameters: self.type_arguments(node)?,
+            })),
+            "TSUnionType" => TSType::TSUnionType(
+                ast.alloc(TSUnionType { span, types: self.list(node, "types", Self::ts_type)? }),
+            ),
+            "JSDocNullableType" => TSType::JSDocNullableType(ast.alloc(JSDocNullableType {
+                span,
+                type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+                postfix: get_bool(node, "postfix"),
+            })),
+            "JSDocUnknownType" => TSType::JSDocUnknownType(ast.alloc(JSDocUnknownType { span })),
+            "FlowExactObjectType" => TSType::FlowExactObjectType(ast.alloc(FlowExactObjectType {
+                span,
+                members: self.list(node, "members", Self::ts_signature)?,
+            })),
+            "FlowExistentialType" => {
+                TSType::FlowExistentialType(ast.alloc(FlowExistentialType { span }))
+            }
+            _ => return Err(self.unexpected(node, "type")),
+        })
+    }
+
+    /// `true`, `"+"`, `"-"` or missing
+    fn ts_mapped_type_modifier(
+        &self,
+        node: &Node,
+        key: &'static str,
+    ) -> Result<TSMappedTypeModifierOperator> {
+        Ok(match get(node, key) {
+            None | Some(Value::Bool(false)) => TSMappedTypeModifierOperator::None,
+            Some(Value::Bool(true)) => TSMappedTypeModifierOperator::True,
+            Some(Value::String(operator)) if operator == "+" => TSMappedTypeModifierOperator::Plus,
+            Some(Value::String(operator)) if operator == "-" => TSMappedTypeModifierOperator::Minus,
+            Some(_) => return Err(self.invalid(node, key)),
+        })
+    }
+
+    fn ts_literal(&self, node: &Node) -> Result<TSLiteral<'a>> {
+        Ok(match node_type(node) {
+            "UnaryExpression" => {
+                TSLiteral::UnaryExpression(self.ast.alloc(self.unary_expression(node)?))
+            }
+            "TemplateLiteral" => {
+                TSLiteral::TemplateLiteral(self.ast.alloc(self.template_literal(node)?))
+            }
+            _ => match self.literal(node)? {
+                Expression::BooleanLiteral(literal) => TSLiteral::BooleanLiteral(literal),
+                Expression::NullLiteral(literal) => TSLiteral::NullLiteral(literal),
+                Expression::NumberLiteral(literal) => TSLiteral::NumberLiteral(literal),
+                Expression::BigintLiteral(literal) => TSLiteral::BigintLiteral(literal),
+                Expression::RegExpLiteral(literal) => TSLiteral::RegExpLiteral(literal),
+                Expression::StringLiteral(literal) => TSLiteral::StringLiteral(literal),
+                _ => unreachable!(),
+            },
+        })
+    }
+
+    fn ts_tuple_element(&self, node: &Node) -> Result<TSTupleElement<'a>> {
+        let ast = &self.ast;
+        let span = self.span(node)?;
+        Ok(match node_type(node) {
+            "TSOptionalType" => TSTupleElement::TSOptionalType(ast.alloc(TSOptionalType {
+                span,
+                type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+            })),
+            "TSRestType" => TSTupleElement::TSRestType(ast.alloc(TSRestType {
+                span,
+                type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+            })),
+            "TSNamedTupleMember" => {
+                TSTupleElement::TSNamedTupleMember(ast.alloc(TSNamedTupleMember {
+                    span,
+                    element_type: self.req(node, "elementType", Self::ts_type)?,
+                    label: self.req(node, "label", Self::identifier_name)?,
+                    optional: get_bool(node, "optional"),
+                }))
+            }
+            _ => TSTupleElement::TSType(self.ts_type(node)?),
+        })
+    }
+
+    fn ts_signature(&self, node: &Node) -> Result<TSSignature<'a>> {
+        let ast = &self.ast;
+        let span = self.span(node)?;
+        Ok(match node_type(node) {
+            "TSIndexSignature" => {
+                TSSignature::TSIndexSignature(ast.alloc(self.ts_index_signature(node)?))
+            }
+            "TSPropertySignature" => {
+                let computed = get_bool(node, "computed");
+                TSSignature::TSPropertySignature(ast.alloc(TSPropertySignature {
+                    span,
+                    computed,
+                    optional: get_bool(node, "optional"),
+                    readonly: get_bool(node, "readonly"),
+                    key: self.property_key(self.child(node, "key")?, computed)?,
+                    type_annotation: self.opt(
+                        node,
+                        "typeAnnotation",
+                        boxed(Self::ts_type_annotation),
+                    )?,
+                }))
+            }
+            "TSCallSignatureDeclaration" => {
+                TSSignature::TSCallSignatureDeclaration(ast.alloc(TSCallSignatureDeclaration {
+                    span,
+                    params: self.formal_parameters(node, FormalParameterKind::Signature)?,
+                    return_type: self.return_type(node)?,
+                    type_parameters: self.type_parameters(node)?,
+                }))
+            }
+            "TSConstructSignatureDeclaration" => TSSignature::TSConstructSignatureDeclaration(
+                ast.alloc(TSConstructSignatureDeclaration {
+                    span,
+                    params: self.formal_parameters(node, FormalParameterKind::Signature)?,
+                    return_type: self.return_type(node)?,
+                    type_parameters: self.type_parameters(node)?,
+                }),
+            ),
+            "TSMethodSignature" => {
+                let computed = get_bool(node, "computed");
+                let kind = match get_str(node, "kind") {
+                    None | Some("method") => TSMethodSignatureKind::Method,
+                    Some("get") => TSMethodSignatureKind::Get,
+                    Some("set") => TSMethodSignatureKind::Set,
+                    Some(_) => return Err(self.invalid(node, "kind")),
+                };
+                TSSignature::TSMethodSignature(ast.alloc(TSMethodSignature {
+                    span,
+                    key: self.property_key(self.child(node, "key")?, computed)?,
+                    computed,
+                    optional: get_bool(node, "optional"),
+                    kind,
+                    params: self.formal_parameters(node, FormalParameterKind::Signature)?,
+                    return_type: self.return_type(node)?,
+                    type_parameters: self.type_parameters(node)?,
+                }))
+            }
+            _ => return Err(self.unexpected(node, "type member")),
+        })
+    }
+
+    fn ts_index_signature(&self, node: &Node) -> Result<TSIndexSignature<'a>> {
+        let parameters = self.list(node, "parameters", |r, node| {
+            r.expect(node, IDENTIFIERS, "Identifier")?;
+            let type_annotation = r.req(node, "typeAnnotation", boxed(Self::ts_type_annotation))?;
+            let mut span = r.span(node)?;
+            if (span.start..span.end).contains(&type_annotation.span.start) {
+                span.end = type_annotation.span.start;
+            }
+            Ok(r.ast.alloc(TSIndexSignatureName { span, name: r.name(node)?, type_annotation }))
+        })?;
+        Ok(TSIndexSignature {
+            span: self.span(node)?,
+            parameters,
+            type_annotation: self.req(node, "typeAnnotation", boxed(Self::ts_type_annotation))?,
+        })
+    }
+
+    fn ts_class_implements(&self, node: &Node) -> Result<TSClassImplements<'a>> {
+        Ok(TSClassImplements {
+            span: self.span(node)?,
+            expression: self.req(node, "expression", Self::ts_type_name)?,
+            type_parameters: self.type_arguments(node)?,
+        })
+    }
+
+    fn ts_type_alias_declaration(&self, node: &Node) -> Result<TSTypeAliasDeclaration<'a>> {
+        Ok(TSTypeAliasDeclaration {
+            span: self.span(node)?,
+            id: self.req(node, "id", Self::binding_identifier)?,
+            type_annotation: self.req(node, "typeAnnotation", Self::ts_type)?,
+            type_parameters: self.type_parameters(node)?,
+            modifiers: self.modifiers(node)?,
+        })
+    }
+
+    fn ts_interface_declaration(&self, node: &Node) -> Result<TSInterfaceDeclaration<'a>> {
+        let body = self.child(node, "body")?;
+        let extends = match get(node, "extends") {
+            Some(_) => Some(self.list(node, "extends", |r, node| {
+                Ok(r.ast.alloc(TSInterfaceHeritage {
+                    span: r.span(node)?,
+                    expression: r.req(node, "expression", Self::expression)?,
+                    type_parameters: r.type_arguments(node)?,
+                }))
+            })?),
+            None => None,
+        };
+        Ok(TSInterfaceDeclaration {
+            span: self.span(node)?,
+            id: self.req(node, "id", Self::binding_identifier)?,
+            body: self.ast.alloc(TSInterfaceBody {
+                span: self.span(body)?,
+                body: self.list(body, "body", Self::ts_signature)?,
+            }),
+            type_parameters: self.type_parameters(node)?,
+            extends,
+            modifiers: self.modifiers(node)?,
+        })
+    }
+
+    fn ts_enum_declaration(&self, node: &Node) -> Result<TSEnumDeclaration<'a>> {
+        let members = self.list(node, "members", |r, node| {
+            let id = r.child(node, "id")?;
+            let id = match node_type(id) {
+                _ if get_bool(node, "computed") => {
+                    TSEnumMemberName::ComputedPropertyName(r.expression(id)?)
+                }
+                "Literal" => match r.literal(id)? {
+                    Expression::StringLiteral(literal) => {
+                        TSEnumMemberName::StringLiteral(literal.unbox())
+                    }
+                    Expression::NumberLiteral(literal) => {
+                        TSEnumMemberName::NumberLiteral(literal.unbox())
+                    }
+                    _ => return Err(r.unexpected(id, "string or number Literal")),
+                },
+                _ => TSEnumMemberName::Identifier(r.identifier_name(id)?),
+            };
+            Ok(TSEnumMember {
+                span: r.span(node)?,
+                id,
+                initializer: r.opt(node, "initializer", Self::expression)?,
+            })
+        })?;
+        Ok(TSEnumDeclaration {
+            span: self.span(node)?,
+            id: self.req(node, "id", Self::binding_identifier)?,
+            members,
+            modifiers: self.modifiers(node)?,
+        })
+    }
+
+    fn ts_module_declaration(&self, node: &Node) -> Result<TSModuleDeclaration<'a>> {
+        let span = self.span(node)?;
+        let id = self.child(node, "id")?;
+        let id = match node_type(id) {
+            "Literal" => TSModuleDeclarationName::StringLiteral(self.string_literal(id)?),
+            _ => TSModuleDeclarationName::Identifier(self.identifier_name(id)?),
+        };
+        let body = match get(node, "body").and_then(Value::as_object) {
+            Some(body) if node_type(body) == "TSModuleDeclaration" => {
+                TSModuleDeclarationBody::TSModuleDeclaration(
+                    self.ast.alloc(self.ts_module_declaration(body)?),
+                )
+            }
+            Some(body) => {
+                self.expect(body, &["TSModuleBlock"], "TSModuleBlock")?;
+                TSModuleDeclarationBody::TSModuleBlock(self.ast.alloc(TSModuleBlock {
+                    span: self.span(body)?,
+                    body: self.list(body, "body", Self::statement)?,
+                }))
+            }
+            // `declare module "m";`
+            None => TSModuleDeclarationBody::TSModuleBlock(self.ast.alloc(TSModuleBlock {
+                span: Span::new(span.end, span.end),
+                body: self.ast.new_vec(),
+            })),
+        };
+        Ok(TSModuleDeclaration { span, id, body, modifiers: self.modifiers(node)? })
+    }
+
+    fn ts_import_equals_declaration(&self, node: &Node) -> Result<TSImportEqualsDeclaration<'a>> {
+        let module_reference = self.child(node, "moduleReference")?;
+        let module_reference = match node_type(module_reference) {
+            "TSExternalModuleReference" => {
+                TSModuleReference::ExternalModuleReference(TSExternalModuleReference {
+                    span: self.span(module_reference)?,
+                    expression: self.req(module_reference, "expression", Self::string_literal)?,
+                })
+            }
+            _ => TSModuleReference::TypeName(self.ts_type_name(module_reference)?),
+        };
+        Ok(TSImportEqualsDeclaration {
+            span: self.span(node)?,
+            id: self.req(node, "id", Self::binding_identifier)?,
+            module_reference: self.ast.alloc(module_reference),
+            is_export: get_bool(node, "isExport"),
+            import_kind: self
+                .import_or_export_kind(node, "importKind")?
+                .unwrap_or(ImportOrExportKind::Value),
+        })
+    }
+}
@@ -0,0 +1,1964 @@
+//! [ESTree](https://github.com/estree/estree) JSON serialization, see [`Program::to_estree_json`],
+//! and deserialization, see [`Program::from_estree_json`]
+//!
+//! JavaScript nodes are serialized as `ESTree`, TypeScript and JSX nodes in the shape of
+//! [`@typescript-eslint/typescript-estree`](https://typescript-eslint.io/packages/typescript-estree/ast-spec),
+//! so the output can be used with tools such as `esquery`, `ESLint` rule runners and AST explorers.
+//!
+//! The oxc specific nodes are converted:
+//! * `BindingIdentifier`, `IdentifierReference` and `IdentifierName` are `Identifier`
+//! * `AssignmentTarget` patterns are `ObjectPattern`, `ArrayPattern`, `AssignmentPattern` and `RestElement`
+//! * Directives are `ExpressionStatement`s with a `directive` field
+//! * `ParenthesizedExpression` is omitted
+//! * A function without a body is a `TSDeclareFunction` or `TSEmptyBodyFunctionExpression`
+
+mod deserialize;
+
+use oxc_allocator::{Box, Vec};
+
+pub use self::deserialize::ESTreeError;
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, Atom, GetSpan, LineIndex, ModuleKind, Span};
+
+/// Options of [`Program::to_estree_json`]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ESTreeOptions {
+    /// Add a `range: [start, end]` field to every node, the same as `start` and `end`
+    pub range: bool,
+    /// Add a `loc: { start: { line, column }, end: { line, column } }` field to every node,
+    /// with one-based lines and zero-based columns
+    pub loc: bool,
+    /// Use UTF-16 offsets and columns instead of UTF-8 byte offsets, for use with JavaScript strings
+    pub utf16: bool,
+}
+
+impl<'a> Program<'a> {
+    /// Serialize to `ESTree` JSON, see the [module documentation](crate::estree)
+    #[must_use]
+    pub fn to_estree_json(&self, source_text: &str, options: ESTreeOptions) -> String {
+        let mut serializer = ESTreeSerializer::new(source_text, options);
+        self.serialize_estree(&mut serializer);
+        serializer.buffer
+    }
+}
+
+/// Writes `ESTree` JSON
+pub struct ESTreeSerializer<'s> {
+    buffer: String,
+    source_text: &'s str,
+    options: ESTreeOptions,
+    /// Only built for `loc` and `utf16`
+    line_index: Option<LineIndex>,
+}
+
+impl<'s> ESTreeSerializer<'s> {
+    #[must_use]
+    pub fn new(source_text: &'s str, options: ESTreeOptions) -> Self {
+        let line_index = (options.loc || options.utf16).then(|| LineIndex::new(source_text));
+        Self { buffer: String::new(), source_text, options, line_index }
+    }
+
+    #[must_use]
+    pub fn source_text(&self) -> &'s str {
+        self.source_text
+    }
+
+    /// Write a node with its `type`, position and the fields written by `fields`
+    pub fn node(&mut self, r#type: &str, span: Span, fields: impl FnOnce(&mut Self)) {
+        self.node_start(r#type, span);
+        fields(self);
+        self.node_end(span);
+    }
+
+    fn node_start(&mut self, r#type: &str, span: Span) {
+        self.buffer.push_str("{\"type\":");
+        self.str(r#type);
+        let (start, end) = (self.offset(span.start), self.offset(span.end));
+        self.buffer.push_str(&format!(",\"start\":{start},\"end\":{end}"));
+    }
+
+    fn node_end(&mut self, span: Span) {
+        let (start, end) = (self.offset(span.start), self.offset(span.end));
+        if self.options.range {
+            self.buffer.push_str(&format!(",\"range\":[{start},{end}]"));
+        }
+        if let Some(line_index) = self.line_index.as_ref().filter(|_| self.options.loc) {
+            let position = |offset| {
+                let position = line_index.line_column(offset);
+                let column =
+                    if self.options.utf16 { position.column_utf16 } else { position.column };
+                format!("{{\"line\":{},\"column\":{column}}}", position.line + 1)
+            };
+            let loc = format!(
+                ",\"loc\":{{\"start\":{},\"end\":{}}}",
+                position(span.start),
+                position(span.end)
+            );
+            self.buffer.push_str(&loc);
+        }
+        self.buffer.push('}');
+    }
+
+    /// Write a field of a node
+    pub fn field<T: ESTree + ?Sized>(&mut self, key: &str, value: &T) {
+        self.key(key);
+        value.serialize_estree(self);
+    }
+
+    /// Write an array field from any iterator of nodes
+    pub fn array_field<T: ESTree>(&mut self, key: &str, values: impl IntoIterator<Item = T>) {
+        self.key(key);
+        self.array(values, |s, value| value.serialize_estree(s));
+    }
+
+    fn key(&mut self, key: &str) {
+        self.buffer.push(',');
+        self.str(key);
+        self.buffer.push(':');
+    }
+
+    fn array<T>(&mut self, values: impl IntoIterator<Item = T>, mut f: impl FnMut(&mut Self, T)) {
+        self.buffer.push('[');
+        for (i, value) in values.into_iter().enumerate() {
+            if i > 0 {
+                self.buffer.push(',');
+            }
+            f(self, value);
+        }
+        self.buffer.push(']');
+    }
+
+    fn offset(&self, offset: u32) -> u32 {
+        match &self.line_index {
+            Some(line_index) if self.options.utf16 => line_index.utf16_offset(offset),
+            _ => offset,
+        }
+    }
+
+    fn str(&mut self, value: &str) {
+        self.buffer.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => self.buffer.push_str("\\\""),
+                '\\' => self.buffer.push_str("\\\\"),
+                '\n' => self.buffer.push_str("\\n"),
+                '\r' => self.buffer.push_str("\\r"),
+                '\t' => self.buffer.push_str("\\t"),
+                c if (c as u32) < 0x20 => self.buffer.push_str(&format!("\\u{:04x}", c as u32)),
+                c => self.buffer.push(c),
+            }
+        }
+        self.buffer.push('"');
+    }
+
+    fn null(&mut self) {
+        self.buffer.push_str("null");
+    }
+
+    fn source(&self, span: Span) -> &'s str {
+        span.source_text(self.source_text)
+    }
+}
+
+/// Serialize a node to `ESTree` JSON
+pub trait ESTree {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer);
+}
+
+impl<T: ESTree + ?Sized> ESTree for &T {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        (**self).serialize_estree(s);
+    }
+}
+
+impl<'a, T: ESTree> ESTree for Box<'a, T> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        (**self).serialize_estree(s);
+    }
+}
+
+impl<T: ESTree> ESTree for Option<T> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Some(value) => value.serialize_estree(s),
+            None => s.null(),
+        }
+    }
+}
+
+impl<'a, T: ESTree> ESTree for Vec<'a, T> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.array(self.iter(), |s, value| value.serialize_estree(s));
+    }
+}
+
+impl ESTree for str {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.str(self);
+    }
+}
+
+impl ESTree for Atom {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.str(self);
+    }
+}
+
+impl ESTree for bool {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.buffer.push_str(if *self { "true" } else { "false" });
+    }
+}
+
+impl ESTree for f64 {
+    /// Infinity, e.g. `1e400`, is `null` the same as `JSON.stringify`
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        if self.is_finite() {
+            s.buffer.push_str(ryu_js::Buffer::new().format(*self));
+        } else {
+            s.null();
+        }
+    }
+}
+
+/// Identifiers, the oxc identifier kinds are all `Identifier`
+fn identifier(s: &mut ESTreeSerializer, span: Span, name: &Atom) {
+    s.node("Identifier", span, |s| s.field("name", name));
+}
+
+/// `declare`, `abstract`, `const` and other modifiers of declarations, only written when present
+fn modifier(s: &mut ESTreeSerializer, key: &str, modifiers: &Modifiers, kind: ModifierKind) {
+    if modifiers.contains(kind) {
+        s.field(key, &true);
+    }
+}
+
+/// Optional TypeScript fields are only written when present, the same as `typescript-estree`
+fn optional_field<T: ESTree>(s: &mut ESTreeSerializer, key: &str, value: &Option<T>) {
+    if let Some(value) = value {
+        s.field(key, value);
+    }
+}
+
+fn flag(s: &mut ESTreeSerializer, key: &str, value: bool) {
+    if value {
+        s.field(key, &true);
+    }
+}
+
+fn decorators(s: &mut ESTreeSerializer, decorators: &[Decorator]) {
+    if !decorators.is_empty() {
+        s.array_field("decorators", decorators);
+    }
+}
+
+fn accessibility(s: &mut ESTreeSerializer, accessibility: Option<TSAccessibility>) {
+    if let Some(accessibility) = accessibility {
+        let accessibility = match accessibility {
+            TSAccessibility::Private => "private",
+            TSAccessibility::Protected => "protected",
+            TSAccessibility::Public => "public",
+        };
+        s.field("accessibility", accessibility);
+    }
+}
+
+fn import_or_export_kind(kind: Option<ImportOrExportKind>) -> &'static str {
+    match kind {
+        None | Some(ImportOrExportKind::Value) => "value",
+        Some(ImportOrExportKind::Type) => "type",
+        Some(ImportOrExportKind::Typeof) => "typeof",
+    }
+}
+
+/// Whether a property key is `[computed]`, which is not stored for assignment target properties
+fn is_computed(property_span: Span, key: &PropertyKey) -> bool {
+    matches!(key, PropertyKey::Expression(_)) && property_span.start < key.span().start
+}
+
+impl<'a> ESTree for Program<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Program", self.span, |s| {
+            s.key("body");
+            s.array(
+                self.directives
+                    .iter()
+                    .map(|d| d as &dyn ESTree)
+                    .chain(self.body.iter().map(|b| b as &dyn ESTree)),
+                |s, node| node.serialize_estree(s),
+            );
+            let source_type = match self.source_type.module_kind() {
+                ModuleKind::Script => "script",
+                ModuleKind::Module | ModuleKind::Unambiguous => "module",
+            };
+            s.field("sourceType", source_type);
+        });
+    }
+}
+
+impl<'a> ESTree for Directive<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ExpressionStatement", self.span, |s| {
+            s.field("expression", &self.expression);
+            s.field("directive", self.directive);
+        });
+    }
+}
+
+/* ---------- Literals ---------- */
+
+impl ESTree for BooleanLiteral {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Literal", self.span, |s| {
+            s.field("value", &self.value);
+            s.field("raw", s.source(self.span));
+        });
+    }
+}
+
+impl ESTree for NullLiteral {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Literal", self.span, |s| {
+            s.field("value", &None::<bool>);
+            s.field("raw", s.source(self.span));
+        });
+    }
+}
+
+impl<'a> ESTree for NumberLiteral<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Literal", self.span, |s| {
+            s.field("value", &*self.value);
+            s.field("raw", s.source(self.span));
+        });
+    }
+}
+
+impl ESTree for BigintLiteral {
+    /// The value is `null` in JSON, `bigint` is the value in decimal
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Literal", self.span, |s| {
+            s.field("value", &None::<bool>);
+            s.field("raw", s.source(self.span));
+            s.field("bigint", self.value.to_string().as_str());
+        });
+    }
+}
+
+impl<'a> ESTree for RegExpLiteral<'a> {
+    /// The value is `null` in JSON
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Literal", self.span, |s| {
+            s.field("value", &None::<bool>);
+            s.field("raw", s.source(self.span));
+            s.key("regex");
+            s.buffer.push_str("{\"pattern\":");
+            s.str(&self.regex.pattern);
+            s.buffer.push_str(",\"flags\":");
+            s.str(&self.regex.flags.to_string());
+            s.buffer.push('}');
+        });
+    }
+}
+
+impl ESTree for StringLiteral {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Literal", self.span, |s| {
+            s.field("value", &self.value);
+            s.field("raw", s.source(self.span));
+        });
+    }
+}
+
+impl<'a> ESTree for TemplateLiteral<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TemplateLiteral", self.span, |s| {
+            s.field("quasis", &self.quasis);
+            s.field("expressions", &self.expressions);
+        });
+    }
+}
+
+impl ESTree for TemplateElement {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TemplateElement", self.span, |s| {
+            s.key("value");
+            s.buffer.push_str("{\"raw\":");
+            s.str(&self.value.raw);
+            s.buffer.push_str(",\"cooked\":");
+            self.value.cooked.serialize_estree(s);
+            s.buffer.push('}');
+            s.field("tail", &self.tail);
+        });
+    }
+}
+
+/* ---------- Expressions ---------- */
+
+impl<'a> ESTree for Expression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::BooleanLiteral(lit) => lit.serialize_estree(s),
+            Self::NullLiteral(lit) => lit.serialize_estree(s),
+            Self::NumberLiteral(lit) => lit.serialize_estree(s),
+            Self::BigintLiteral(lit) => lit.serialize_estree(s),
+            Self::RegExpLiteral(lit) => lit.serialize_estree(s),
+            Self::StringLiteral(lit) => lit.serialize_estree(s),
+            Self::TemplateLiteral(lit) => lit.serialize_estree(s),
+            Self::Identifier(ident) => ident.serialize_estree(s),
+            Self::MetaProperty(expr) => expr.serialize_estree(s),
+            Self::Super(expr) => s.node("Super", expr.span, |_| {}),
+            Self::ArrayExpression(expr) => expr.serialize_estree(s),
+            Self::ArrowFunctionExpression(expr) => expr.serialize_estree(s),
+            Self::AssignmentExpression(expr) => expr.serialize_estree(s),
+            Self::AwaitExpression(expr) => {
+                s.node("AwaitExpression", expr.span, |s| s.field("argument", &expr.argument));
+            }
+            Self::BinaryExpression(_) | Self::LogicalExpression(_) => binary_chain(s, self),
+            Self::CallExpression(expr) => expr.serialize_estree(s),
+            Self::ChainExpression(expr) => {
+                s.node("ChainExpression", expr.span, |s| s.field("expression", &expr.expression));
+            }
+            Self::ClassExpression(class) => class.serialize_estree(s),
+            Self::ConditionalExpression(expr) => expr.serialize_estree(s),
+            Self::FunctionExpression(func) => func.serialize_estree(s),
+            Self::ImportExpression(expr) => expr.serialize_estree(s),
+            Self::MemberExpression(expr) => expr.serialize_estree(s),
+            Self::NewExpression(expr) => expr.serialize_estree(s),
+            Self::ObjectExpression(expr) => expr.serialize_estree(s),
+            Self::ParenthesizedExpression(expr) => expr.expression.serialize_estree(s),
+            Self::SequenceExpression(expr) => {
+                s.node("SequenceExpression", expr.span, |s| {
+                    s.field("expressions", &expr.expressions);
+                });
+            }
+            Self::TaggedTemplateExpression(expr) => expr.serialize_estree(s),
+            Self::ThisExpression(expr) => s.node("ThisExpression", expr.span, |_| {}),
+            Self::UnaryExpression(expr) => expr.serialize_estree(s),
+            Self::UpdateExpression(expr) => expr.serialize_estree(s),
+            Self::YieldExpression(expr) => {
+                s.node("YieldExpression", expr.span, |s| {
+                    s.field("delegate", &expr.delegate);
+                    s.field("argument", &expr.argument);
+                });
+            }
+            Self::PrivateInExpression(expr) => {
+                s.node("BinaryExpression", expr.span, |s| {
+                    s.field("left", &expr.left);
+                    s.field("operator", expr.operator.as_str());
+                    s.field("right", &expr.right);
+                });
+            }
+            Self::JSXElement(elem) => elem.serialize_estree(s),
+            Self::JSXFragment(elem) => elem.serialize_estree(s),
+            Self::TSAsExpression(expr) => expr.serialize_estree(s),
+            Self::TSSatisfiesExpression(expr) => expr.serialize_estree(s),
+            Self::TSTypeAssertion(expr) => expr.serialize_estree(s),
+            Self::TSNonNullExpression(expr) => expr.serialize_estree(s),
+            Self::TSInstantiationExpression(expr) => {
+                s.node("TSInstantiationExpression", expr.span, |s| {
+                    s.field("expression", &expr.expression);
+                    s.field("typeParameters", &expr.type_parameters);
+                });
+            }
+            Self::InvalidExpression(expr) => s.node("InvalidExpression", expr.span, |_| {}),
+        }
+    }
+}
+
+impl ESTree for IdentifierName {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        identifier(s, self.span, &self.name);
+    }
+}
+
+impl ESTree for IdentifierReference {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        identifier(s, self.span, &self.name);
+    }
+}
+
+impl ESTree for BindingIdentifier {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        identifier(s, self.span, &self.name);
+    }
+}
+
+impl ESTree for LabelIdentifier {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        identifier(s, self.span, &self.name);
+    }
+}
+
+impl ESTree for PrivateIdentifier {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("PrivateIdentifier", self.span, |s| s.field("name", &self.name));
+    }
+}
+
+impl ESTree for MetaProperty {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("MetaProperty", self.span, |s| {
+            s.field("meta", &self.meta);
+            s.field("property", &self.property);
+        });
+    }
+}
+
+impl<'a> ESTree for ArrayExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ArrayExpression", self.span, |s| s.field("elements", &self.elements));
+    }
+}
+
+impl<'a> ESTree for ObjectExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ObjectExpression", self.span, |s| s.field("properties", &self.properties));
+    }
+}
+
+impl<'a> ESTree for ObjectProperty<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Property(prop) => prop.serialize_estree(s),
+            Self::SpreadProperty(spread) => spread.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for Property<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Property", self.span, |s| {
+            s.field("key", &self.key);
+            s.field("value", &self.value);
+            let kind = match self.kind {
+                PropertyKind::Init => "init",
+                PropertyKind::Get => "get",
+                PropertyKind::Set => "set",
+            };
+            s.field("kind", kind);
+            s.field("method", &self.method);
+            s.field("shorthand", &self.shorthand);
+            s.field("computed", &self.computed);
+        });
+    }
+}
+
+impl<'a> ESTree for PropertyKey<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Identifier(ident) => ident.serialize_estree(s),
+            Self::PrivateIdentifier(ident) => ident.serialize_estree(s),
+            Self::Expression(expr) => expr.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for PropertyValue<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Pattern(pat) => pat.serialize_estree(s),
+            Self::Expression(expr) => expr.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for TaggedTemplateExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TaggedTemplateExpression", self.span, |s| {
+            s.field("tag", &self.tag);
+            s.field("quasi", &self.quasi);
+            optional_field(s, "typeParameters", &self.type_parameters);
+        });
+    }
+}
+
+impl<'a> ESTree for MemberExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        let (span, object, computed, optional) = match self {
+            Self::ComputedMemberExpression(expr) => (expr.span, &expr.object, true, expr.optional),
+            Self::StaticMemberExpression(expr) => (expr.span, &expr.object, false, expr.optional),
+            Self::PrivateFieldExpression(expr) => (expr.span, &expr.object, false, expr.optional),
+        };
+        s.node("MemberExpression", span, |s| {
+            s.field("object", object);
+            match self {
+                Self::ComputedMemberExpression(expr) => s.field("property", &expr.expression),
+                Self::StaticMemberExpression(expr) => s.field("property", &expr.property),
+                Self::PrivateFieldExpression(expr) => s.field("property", &expr.field),
+            }
+            s.field("computed", &computed);
+            s.field("optional", &optional);
+        });
+    }
+}
+
+impl<'a> ESTree for CallExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("CallExpression", self.span, |s| {
+            s.field("callee", &self.callee);
+            s.field("arguments", &self.arguments);
+            s.field("optional", &self.optional);
+            optional_field(s, "typeParameters", &self.type_parameters);
+        });
+    }
+}
+
+impl<'a> ESTree for NewExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("NewExpression", self.span, |s| {
+            s.field("callee", &self.callee);
+            s.field("arguments", &self.arguments);
+            optional_field(s, "typeParameters", &self.type_parameters);
+        });
+    }
+}
+
+impl<'a> ESTree for SpreadElement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("SpreadElement", self.span, |s| s.field("argument", &self.argument));
+    }
+}
+
+impl<'a> ESTree for Argument<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::SpreadElement(spread) => spread.serialize_estree(s),
+            Self::Expression(expr) => expr.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for UpdateExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("UpdateExpression", self.span, |s| {
+            s.field("operator", self.operator.as_str());
+            s.field("prefix", &self.prefix);
+            s.field("argument", &self.argument);
+        });
+    }
+}
+
+impl<'a> ESTree for UnaryExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("UnaryExpression", self.span, |s| {
+            s.field("operator", self.operator.as_str());
+            s.field("prefix", &true);
+            s.field("argument", &self.argument);
+        });
+    }
+}
+
+/// Write the chain of binary and logical expressions `a + b || c`, which is nested as `(a + b) || c`,
+/// in a loop, long chains such as generated string concatenations would overflow the stack in a recursion
+fn binary_chain(s: &mut ESTreeSerializer, expr: &Expression) {
+    let mut chain = vec![];
+    let mut left = expr;
+    loop {
+        match left {
+            Expression::BinaryExpression(expr) => {
+                s.node_start("BinaryExpression", expr.span);
+                s.key("left");
+                chain.push((expr.span, expr.operator.as_str(), &expr.right));
+                left = &expr.left;
+            }
+            Expression::LogicalExpression(expr) => {
+                s.node_start("LogicalExpression", expr.span);
+                s.key("left");
+                chain.push((expr.span, expr.operator.as_str(), &expr.right));
+                left = &expr.left;
+            }
+            Expression::ParenthesizedExpression(expr) => left = &expr.expression,
+            _ => break,
+        }
+    }
+    left.serialize_estree(s);
+    for (span, operator, right) in chain.into_iter().rev() {
+        s.field("operator", operator);
+        s.field("right", right);
+        s.node_end(span);
+    }
+}
+
+impl<'a> ESTree for BinaryExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("BinaryExpression", self.span, |s| {
+            s.field("left", &self.left);
+            s.field("operator", self.operator.as_str());
+            s.field("right", &self.right);
+        });
+    }
+}
+
+impl<'a> ESTree for LogicalExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("LogicalExpression", self.span, |s| {
+            s.field("left", &self.left);
+            s.field("operator", self.operator.as_str());
+            s.field("right", &self.right);
+        });
+    }
+}
+
+impl<'a> ESTree for ConditionalExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ConditionalExpression", self.span, |s| {
+            s.field("test", &self.test);
+            s.field("consequent", &self.consequent);
+            s.field("alternate", &self.alternate);
+        });
+    }
+}
+
+impl<'a> ESTree for AssignmentExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("AssignmentExpression", self.span, |s| {
+            s.field("operator", self.operator.as_str());
+            s.field("left", &self.left);
+            s.field("right", &self.right);
+        });
+    }
+}
+
+impl<'a> ESTree for AssignmentTarget<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::SimpleAssignmentTarget(target) => target.serialize_estree(s),
+            Self::AssignmentTargetPattern(AssignmentTargetPattern::ArrayAssignmentTarget(
+                target,
+            )) => target.serialize_estree(s),
+            Self::AssignmentTargetPattern(AssignmentTargetPattern::ObjectAssignmentTarget(
+                target,
+            )) => target.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for SimpleAssignmentTarget<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::AssignmentTargetIdentifier(ident) => ident.serialize_estree(s),
+            Self::MemberAssignmentTarget(expr) => expr.serialize_estree(s),
+            Self::TSAsExpression(expr) => expr.serialize_estree(s),
+            Self::TSSatisfiesExpression(expr) => expr.serialize_estree(s),
+            Self::TSNonNullExpression(expr) => expr.serialize_estree(s),
+            Self::TSTypeAssertion(expr) => expr.serialize_estree(s),
+        }
+    }
+}
+
+/// The `...rest` of an assignment target pattern, which has no node of its own
+struct AssignmentTargetRest<'b, 'a>(&'b AssignmentTarget<'a>);
+
+impl<'b, 'a> ESTree for AssignmentTargetRest<'b, 'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        let span = self.0.span();
+        let start =
+            s.source_text[..span.start as usize].rfind("...").unwrap_or(span.start as usize);
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(start as u32, span.end);
+        s.node("RestElement", span, |s| s.field("argument", self.0));
+    }
+}
+
+impl<'a> ESTree for ArrayAssignmentTarget<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ArrayPattern", self.span, |s| {
+            s.key("elements");
+            let elements = self.elements.iter().map(|element| element as &dyn ESTree);
+            let rest = self.rest.as_ref().map(AssignmentTargetRest);
+            s.array(elements.chain(rest.as_ref().map(|rest| rest as &dyn ESTree)), |s, element| {
+                element.serialize_estree(s);
+            });
+        });
+    }
+}
+
+impl<'a> ESTree for ObjectAssignmentTarget<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ObjectPattern", self.span, |s| {
+            s.key("properties");
+            let properties = self.properties.iter().map(|property| property as &dyn ESTree);
+            let rest = self.rest.as_ref().map(AssignmentTargetRest);
+            s.array(
+                properties.chain(rest.as_ref().map(|rest| rest as &dyn ESTree)),
+                |s, property| property.serialize_estree(s),
+            );
+        });
+    }
+}
+
+impl<'a> ESTree for AssignmentTargetMaybeDefault<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::AssignmentTarget(target) => target.serialize_estree(s),
+            Self::AssignmentTargetWithDefault(target) => {
+                s.node("AssignmentPattern", target.span, |s| {
+                    s.field("left", &target.binding);
+                    s.field("right", &target.init);
+                });
+            }
+        }
+    }
+}
+
+impl<'a> ESTree for AssignmentTargetProperty<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::AssignmentTargetPropertyIdentifier(prop) => {
+                s.node("Property", prop.span, |s| {
+                    s.field("key", &prop.binding);
+                    s.key("value");
+                    match &prop.init {
+                        Some(init) => s.node("AssignmentPattern", prop.span, |s| {
+                            s.field("left", &prop.binding);
+                            s.field("right", init);
+                        }),
+                        None => prop.binding.serialize_estree(s),
+                    }
+                    s.field("kind", "init");
+                    s.field("method", &false);
+                    s.field("shorthand", &true);
+                    s.field("computed", &false);
+                });
+            }
+            Self::AssignmentTargetPropertyProperty(prop) => {
+                s.node("Property", prop.span, |s| {
+                    s.field("key", &prop.name);
+                    s.field("value", &prop.binding);
+                    s.field("kind", "init");
+                    s.field("method", &false);
+                    s.field("shorthand", &false);
+                    s.field("computed", &is_computed(prop.span, &prop.name));
+                });
+            }
+        }
+    }
+}
+
+impl<'a> ESTree for ChainElement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::CallExpression(expr) => expr.serialize_estree(s),
+            Self::MemberExpression(expr) => expr.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for ImportExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ImportExpression", self.span, |s| {
+            s.field("source", &self.source);
+            s.field("attributes", &self.arguments.first());
+        });
+    }
+}
+
+/* ---------- Statements ---------- */
+
+impl<'a> ESTree for Statement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::BlockStatement(stmt) => stmt.serialize_estree(s),
+            Self::BreakStatement(stmt) => {
+                s.node("BreakStatement", stmt.span, |s| s.field("label", &stmt.label));
+            }
+            Self::ContinueStatement(stmt) => {
+                s.node("ContinueStatement", stmt.span, |s| s.field("label", &stmt.label));
+            }
+            Self::DebuggerStatement(stmt) => s.node("DebuggerStatement", stmt.span, |_| {}),
+            Self::DoWhileStatement(stmt) => {
+                s.node("DoWhileStatement", stmt.span, |s| {
+                    s.field("body", &stmt.body);
+                    s.field("test", &stmt.test);
+                });
+            }
+            Self::EmptyStatement(stmt) => s.node("EmptyStatement", stmt.span, |_| {}),
+            Self::ExpressionStatement(stmt) => {
+                s.node("ExpressionStatement", stmt.span, |s| {
+                    s.field("expression", &stmt.expression);
+                });
+            }
+            Self::ForInStatement(stmt) => {
+                s.node("ForInStatement", stmt.span, |s| {
+                    s.field("left", &stmt.left);
+                    s.field("right", &stmt.right);
+                    s.field("body", &stmt.body);
+                });
+            }
+            Self::ForOfStatement(stmt) => {
+                s.node("ForOfStatement", stmt.span, |s| {
+                    s.field("await", &stmt.r#await);
+                    s.field("left", &stmt.left);
+                    s.field("right", &stmt.right);
+                    s.field("body", &stmt.body);
+                });
+            }
+            Self::ForStatement(stmt) => {
+                s.node("ForStatement", stmt.span, |s| {
+                    s.field("init", &stmt.init);
+                    s.field("test", &stmt.test);
+                    s.field("update", &stmt.update);
+                    s.field("body", &stmt.body);
+                });
+            }
+            Self::IfStatement(stmt) => {
+                s.node("IfStatement", stmt.span, |s| {
+                    s.field("test", &stmt.test);
+                    s.field("consequent", &stmt.consequent);
+                    s.field("alternate", &stmt.alternate);
+                });
+            }
+            Self::LabeledStatement(stmt) => {
+                s.node("LabeledStatement", stmt.span, |s| {
+                    s.field("label", &stmt.label);
+                    s.field("body", &stmt.body);
+                });
+            }
+            Self::ReturnStatement(stmt) => {
+                s.node("ReturnStatement", stmt.span, |s| s.field("argument", &stmt.argument));
+            }
+            Self::SwitchStatement(stmt) => {
+                s.node("SwitchStatement", stmt.span, |s| {
+                    s.field("discriminant", &stmt.discriminant);
+                    s.field("cases", &stmt.cases);
+                });
+            }
+            Self::ThrowStatement(stmt) => {
+                s.node("ThrowStatement", stmt.span, |s| s.field("argument", &stmt.argument));
+            }
+            Self::TryStatement(stmt) => {
+                s.node("TryStatement", stmt.span, |s| {
+                    s.field("block", &stmt.block);
+                    s.field("handler", &stmt.handler);
+                    s.field("finalizer", &stmt.finalizer);
+                });
+            }
+            Self::WhileStatement(stmt) => {
+                s.node("WhileStatement", stmt.span, |s| {
+                    s.field("test", &stmt.test);
+                    s.field("body", &stmt.body);
+                });
+            }
+            Self::WithStatement(stmt) => {
+                s.node("WithStatement", stmt.span, |s| {
+                    s.field("object", &stmt.object);
+                    s.field("body", &stmt.body);
+                });
+            }
+            Self::ModuleDeclaration(decl) => decl.serialize_estree(s),
+            Self::Declaration(decl) => decl.serialize_estree(s),
+            Self::InvalidStatement(stmt) => s.node("InvalidStatement", stmt.span, |_| {}),
+        }
+    }
+}
+
+impl<'a> ESTree for BlockStatement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("BlockStatement", self.span, |s| s.field("body", &self.body));
+    }
+}
+
+impl<'a> ESTree for Declaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::VariableDeclaration(decl) => decl.serialize_estree(s),
+            Self::FunctionDeclaration(func) => func.serialize_estree(s),
+            Self::ClassDeclaration(class) => class.serialize_estree(s),
+            Self::TSTypeAliasDeclaration(decl) => decl.serialize_estree(s),
+            Self::TSInterfaceDeclaration(decl) => decl.serialize_estree(s),
+            Self::TSEnumDeclaration(decl) => decl.serialize_estree(s),
+            Self::TSModuleDeclaration(decl) => decl.serialize_estree(s),
+            Self::TSImportEqualsDeclaration(decl) => decl.serialize_estree(s),
+            Self::FlowOpaqueTypeDeclaration(decl) => {
+                s.node("FlowOpaqueTypeDeclaration", decl.span, |s| {
+                    s.field("id", &decl.id);
+                    s.field("typeParameters", &decl.type_parameters);
+                    s.field("supertype", &decl.supertype);
+                    s.field("impltype", &decl.impltype);
+                });
+            }
+            Self::FlowDeclareModuleExports(decl) => {
+                s.node("FlowDeclareModuleExports", decl.span, |s| {
+                    s.field("typeAnnotation", &decl.type_annotation);
+                });
+            }
+        }
+    }
+}
+
+impl<'a> ESTree for VariableDeclaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("VariableDeclaration", self.span, |s| {
+            s.field("declarations", &self.declarations);
+            s.field("kind", self.kind.to_string().as_str());
+            modifier(s, "declare", &self.modifiers, ModifierKind::Declare);
+        });
+    }
+}
+
+impl<'a> ESTree for VariableDeclarator<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("VariableDeclarator", self.span, |s| {
+            s.field("id", &self.id);
+            s.field("init", &self.init);
+            flag(s, "definite", self.definite);
+        });
+    }
+}
+
+impl<'a> ESTree for ForStatementInit<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::VariableDeclaration(decl) => decl.serialize_estree(s),
+            Self::Expression(expr) => expr.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for ForStatementLeft<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::VariableDeclaration(decl) => decl.serialize_estree(s),
+            Self::AssignmentTarget(target) => target.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for SwitchCase<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("SwitchCase", self.span, |s| {
+            s.field("test", &self.test);
+            s.field("consequent", &self.consequent);
+        });
+    }
+}
+
+impl<'a> ESTree for CatchClause<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("CatchClause", self.span, |s| {
+            s.field("param", &self.param);
+            s.field("body", &self.body);
+        });
+    }
+}
+
+/* ---------- Patterns ---------- */
+
+impl<'a> ESTree for BindingPattern<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        serialize_binding_pattern(s, self, &[]);
+    }
+}
+
+/// Binding patterns with the type annotation, the optional mark and the decorators of a parameter
+/// on the pattern node, the same as `typescript-estree`
+fn serialize_binding_pattern(
+    s: &mut ESTreeSerializer,
+    pattern: &BindingPattern,
+    param_decorators: &[Decorator],
+) {
+    let mut span = pattern.kind.span();
+    if let Some(type_annotation) = &pattern.type_annotation {
+        span.end = span.end.max(type_annotation.span.end);
+    }
+    let extra = |s: &mut ESTreeSerializer| {
+        decorators(s, param_decorators);
+        optional_field(s, "typeAnnotation", &pattern.type_annotation);
+        flag(s, "optional", pattern.optional);
+    };
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => s.node("Identifier", span, |s| {
+            s.field("name", &ident.name);
+            extra(s);
+        }),
+        BindingPatternKind::ObjectPattern(pat) => s.node("ObjectPattern", span, |s| {
+            s.field("properties", &pat.properties);
+            extra(s);
+        }),
+        BindingPatternKind::ArrayPattern(pat) => s.node("ArrayPattern", span, |s| {
+            s.field("elements", &pat.elements);
+            extra(s);
+        }),
+        BindingPatternKind::RestElement(pat) => s.node("RestElement", span, |s| {
+            s.field("argument", &pat.argument);
+            extra(s);
+        }),
+        BindingPatternKind::AssignmentPattern(pat) => s.node("AssignmentPattern", span, |s| {
+            s.field("left", &pat.left);
+            s.field("right", &pat.right);
+            extra(s);
+        }),
+    }
+}
+
+impl<'a> ESTree for ObjectPatternProperty<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Property(prop) => prop.serialize_estree(s),
+            Self::RestElement(rest) => {
+                s.node("RestElement", rest.span, |s| s.field("argument", &rest.argument));
+            }
+        }
+    }
+}
+
+/* ---------- Functions ---------- */
+
+impl<'a> ESTree for Function<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        let r#type = match (self.r#type, &self.body) {
+            (FunctionType::FunctionDeclaration, Some(_)) => "FunctionDeclaration",
+            (FunctionType::FunctionExpression, Some(_)) => "FunctionExpression",
+            (FunctionType::FunctionExpression, None) => "TSEmptyBodyFunctionExpression",
+            (FunctionType::FunctionDeclaration | FunctionType::TSDeclareFunction, _) => {
+                "TSDeclareFunction"
+            }
+        };
+        serialize_function(s, r#type, self);
+    }
+}
+
+fn serialize_function(s: &mut ESTreeSerializer, r#type: &str, func: &Function) {
+    s.node(r#type, func.span, |s| {
+        s.field("id", &func.id);
+        s.field("generator", &func.generator);
+        s.field("async", &func.r#async);
+        s.field("expression", &false);
+        s.field("params", &func.params);
+        s.field("body", &func.body);
+        modifier(s, "declare", &func.modifiers, ModifierKind::Declare);
+        optional_field(s, "typeParameters", &func.type_parameters);
+        optional_field(s, "returnType", &func.return_type);
+    });
+}
+
+impl<'a> ESTree for FormalParameters<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        self.items.serialize_estree(s);
+    }
+}
+
+impl<'a> ESTree for FormalParameter<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        if self.accessibility.is_none() && !self.readonly {
+            serialize_binding_pattern(s, &self.pattern, &self.decorators);
+            return;
+        }
+        s.node("TSParameterProperty", self.span, |s| {
+            accessibility(s, self.accessibility);
+            s.field("readonly", &self.readonly);
+            s.field("static", &false);
+            s.field("override", &false);
+            s.field("parameter", &self.pattern);
+            decorators(s, &self.decorators);
+        });
+    }
+}
+
+impl<'a> ESTree for FunctionBody<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("BlockStatement", self.span, |s| {
+            s.key("body");
+            let directives = self.directives.iter().map(|d| d as &dyn ESTree);
+            let statements = self.statements.iter().map(|stmt| stmt as &dyn ESTree);
+            s.array(directives.chain(statements), |s, node| node.serialize_estree(s));
+        });
+    }
+}
+
+impl<'a> ESTree for ArrowExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ArrowFunctionExpression", self.span, |s| {
+            s.field("id", &None::<bool>);
+            s.field("generator", &false);
+            s.field("async", &self.r#async);
+            s.field("expression", &self.expression);
+            s.field("params", &self.params);
+            match self.body.statements.first() {
+                Some(Statement::ExpressionStatement(stmt)) if self.expression => {
+                    s.field("body", &stmt.expression);
+                }
+                _ => s.field("body", &self.body),
+            }
+            optional_field(s, "typeParameters", &self.type_parameters);
+            optional_field(s, "returnType", &self.return_type);
+        });
+    }
+}
+
+/* ---------- Classes ---------- */
+
+impl<'a> ESTree for Class<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        let r#type = match self.r#type {
+            ClassType::ClassDeclaration => "ClassDeclaration",
+            ClassType::ClassExpression => "ClassExpression",
+        };
+        s.node(r#type, self.span, |s| {
+            s.field("id", &self.id);
+            s.field("superClass", &self.super_class);
+            s.key("body");
+            s.node("ClassBody", self.body.span, |s| s.field("body", &self.body.body));
+            decorators(s, &self.decorators);
+            optional_field(s, "typeParameters", &self.type_parameters);
+            optional_field(s, "superTypeParameters", &self.super_type_parameters);
+            optional_field(s, "implements", &self.implements);
+            modifier(s, "abstract", &self.modifiers, ModifierKind::Abstract);
+            modifier(s, "declare", &self.modifiers, ModifierKind::Declare);
+        });
+    }
+}
+
+impl<'a> ESTree for ClassElement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::StaticBlock(block) => {
+                s.node("StaticBlock", block.span, |s| s.field("body", &block.body));
+            }
+            Self::MethodDefinition(def) => serialize_method_definition(s, "MethodDefinition", def),
+            Self::PropertyDefinition(def) => {
+                serialize_property_definition(s, "PropertyDefinition", def);
+            }
+            Self::AccessorProperty(prop) => {
+                s.node("AccessorProperty", prop.span, |s| {
+                    s.field("key", &prop.key);
+                    s.field("value", &prop.value);
+                    s.field("computed", &prop.computed);
+                    s.field("static", &prop.r#static);
+                });
+            }
+            Self::TSAbstractMethodDefinition(def) => {
+                serialize_method_definition(
+                    s,
+                    "TSAbstractMethodDefinition",
+                    &def.method_definition,
+                );
+            }
+            Self::TSAbstractPropertyDefinition(def) => {
+                serialize_property_definition(
+                    s,
+                    "TSAbstractPropertyDefinition",
+                    &def.property_definition,
+                );
+            }
+            Self::TSIndexSignature(sig) => sig.serialize_estree(s),
+        }
+    }
+}
+
+fn serialize_method_definition(s: &mut ESTreeSerializer, r#type: &str, def: &MethodDefinition) {
+    s.node(r#type, def.span, |s| {
+        s.field("key", &def.key);
+        s.key("value");
+        // Methods without a body are parsed as `TSDeclareFunction`
+        let r#type = if def.value.body.is_some() {
+            "FunctionExpression"
+        } else {
+            "TSEmptyBodyFunctionExpression"
+        };
+        serialize_function(s, r#type, &def.value);
+        let kind = match def.kind {
+            MethodDefinitionKind::Constructor => "constructor",
+            MethodDefinitionKind::Method => "method",
+            MethodDefinitionKind::Get => "get",
+            MethodDefinitionKind::Set => "set",
+        };
+        s.field("kind", kind);
+        s.field("computed", &def.computed);
+        s.field("static", &def.r#static);
+        flag(s, "override", def.r#override);
+        flag(s, "optional", def.optional);
+        accessibility(s, def.accessibility);
+        decorators(s, &def.decorators);
+    });
+}
+
+fn serialize_property_definition(s: &mut ESTreeSerializer, r#type: &str, def: &PropertyDefinition) {
+    s.node(r#type, def.span, |s| {
+        s.field("key", &def.key);
+        s.field("value", &def.value);
+        s.field("computed", &def.computed);
+        s.field("static", &def.r#static);
+        flag(s, "declare", def.declare);
+        flag(s, "override", def.r#override);
+        flag(s, "optional", def.optional);
+        flag(s, "definite", def.definite);
+        flag(s, "readonly", def.readonly);
+        optional_field(s, "typeAnnotation", &def.type_annotation);
+        accessibility(s, def.accessibility);
+        decorators(s, &def.decorators);
+    });
+}
+
+impl<'a> ESTree for Decorator<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("Decorator", self.span, |s| s.field("expression", &self.expression));
+    }
+}
+
+/* ---------- Modules ---------- */
+
+impl<'a> ESTree for ModuleDeclaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match &self.kind {
+            ModuleDeclarationKind::ImportDeclaration(decl) => {
+                s.node("ImportDeclaration", self.span, |s| {
+                    s.field("specifiers", &decl.specifiers);
+                    s.field("source", &decl.source);
+                    s.field("importKind", import_or_export_kind(decl.import_kind));
+                    s.array_field("assertions", decl.assertions.iter().flatten());
+                });
+            }
+            ModuleDeclarationKind::ExportAllDeclaration(decl) => {
+                s.node("ExportAllDeclaration", self.span, |s| {
+                    s.field("exported", &decl.exported);
+                    s.field("source", &decl.source);
+                    s.field("exportKind", import_or_export_kind(decl.export_kind));
+                    s.array_field("assertions", decl.assertions.iter().flatten());
+                });
+            }
+            ModuleDeclarationKind::ExportDefaultDeclaration(decl) => {
+                s.node("ExportDefaultDeclaration", self.span, |s| {
+                    s.field("declaration", &decl.declaration);
+                    s.field("exportKind", "value");
+                });
+            }
+            ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                s.node("ExportNamedDeclaration", self.span, |s| {
+                    s.field("declaration", &decl.declaration);
+                    s.field("specifiers", &decl.specifiers);
+                    s.field("source", &decl.source);
+                    s.field("exportKind", import_or_export_kind(decl.export_kind));
+                    s.array_field("assertions", std::iter::empty::<&ImportAttribute>());
+                });
+            }
+            ModuleDeclarationKind::TSExportAssignment(decl) => {
+                s.node("TSExportAssignment", self.span, |s| {
+                    s.field("expression", &decl.expression);
+                });
+            }
+            ModuleDeclarationKind::TSNamespaceExportDeclaration(decl) => {
+                s.node("TSNamespaceExportDeclaration", self.span, |s| s.field("id", &decl.id));
+            }
+        }
+    }
+}
+
+impl ESTree for ImportDeclarationSpecifier {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::ImportSpecifier(spec) => s.node("ImportSpecifier", spec.span, |s| {
+                s.field("imported", &spec.imported);
+                s.field("local", &spec.local);
+                s.field("importKind", "value");
+            }),
+            Self::ImportDefaultSpecifier(spec) => {
+                s.node("ImportDefaultSpecifier", spec.span, |s| s.field("local", &spec.local));
+            }
+            Self::ImportNamespaceSpecifier(spec) => {
+                s.node("ImportNamespaceSpecifier", spec.span, |s| s.field("local", &spec.local));
+            }
+        }
+    }
+}
+
+impl ESTree for ImportAttribute {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ImportAttribute", self.span, |s| {
+            match &self.key {
+                ImportAttributeKey::Identifier(ident) => s.field("key", ident),
+                ImportAttributeKey::StringLiteral(lit) => s.field("key", lit),
+            }
+            s.field("value", &self.value);
+        });
+    }
+}
+
+impl ESTree for ExportSpecifier {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("ExportSpecifier", self.span, |s| {
+            s.field("local", &self.local);
+            s.field("exported", &self.exported);
+            s.field("exportKind", "value");
+        });
+    }
+}
+
+impl<'a> ESTree for ExportDefaultDeclarationKind<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Expression(expr) => expr.serialize_estree(s),
+            Self::FunctionDeclaration(func) => func.serialize_estree(s),
+            Self::ClassDeclaration(class) => class.serialize_estree(s),
+            Self::TSInterfaceDeclaration(decl) => decl.serialize_estree(s),
+            Self::TSEnumDeclaration(decl) => decl.serialize_estree(s),
+        }
+    }
+}
+
+impl ESTree for ModuleExportName {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Identifier(ident) => ident.serialize_estree(s),
+            Self::StringLiteral(lit) => lit.serialize_estree(s),
+        }
+    }
+}
+
+/* ---------- JSX ---------- */
+
+impl<'a> ESTree for JSXElement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("JSXElement", self.span, |s| {
+            s.field("openingElement", &self.opening_element);
+            s.field("closingElement", &self.closing_element);
+            s.field("children", &self.children);
+        });
+    }
+}
+
+impl<'a> ESTree for JSXOpeningElement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("JSXOpeningElement", self.span, |s| {
+            s.field("name", &self.name);
+            s.field("attributes", &self.attributes);
+            s.field("selfClosing", &self.self_closing);
+            optional_field(s, "typeParameters", &self.type_parameters);
+        });
+    }
+}
+
+impl<'a> ESTree for JSXClosingElement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("JSXClosingElement", self.span, |s| s.field("name", &self.name));
+    }
+}
+
+impl<'a> ESTree for JSXFragment<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("JSXFragment", self.span, |s| {
+            s.key("openingFragment");
+            s.node("JSXOpeningFragment", self.opening_fragment.span, |_| {});
+            s.key("closingFragment");
+            s.node("JSXClosingFragment", self.closing_fragment.span, |_| {});
+            s.field("children", &self.children);
+        });
+    }
+}
+
+impl<'a> ESTree for JSXElementName<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Identifier(ident) => ident.serialize_estree(s),
+            Self::NamespacedName(name) => name.serialize_estree(s),
+            Self::MemberExpression(expr) => expr.serialize_estree(s),
+        }
+    }
+}
+
+impl ESTree for JSXNamespacedName {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("JSXNamespacedName", self.span, |s| {
+            s.field("namespace", &self.namespace);
+            s.field("name", &self.property);
+        });
+    }
+}
+
+impl<'a> ESTree for JSXMemberExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("JSXMemberExpression", self.span, |s| {
+            match &self.object {
+                JSXMemberExpressionObject::Identifier(ident) => s.field("object", ident),
+                JSXMemberExpressionObject::MemberExpression(expr) => s.field("object", expr),
+            }
+            s.field("property", &self.property);
+        });
+    }
+}
+
+impl<'a> ESTree for JSXExpressionContainer<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("JSXExpressionContainer", self.span, |s| {
+            s.key("expression");
+            match &self.expression {
+                JSXExpression::Expression(expr) => expr.serialize_estree(s),
+                JSXExpression::EmptyExpression(expr) => {
+                    s.node("JSXEmptyExpression", expr.span, |_| {});
+                }
+            }
+        });
+    }
+}
+
+impl<'a> ESTree for JSXAttributeItem<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Attribute(attr) => s.node("JSXAttribute", attr.span, |s| {
+                match &attr.name {
+                    JSXAttributeName::Identifier(ident) => s.field("name", ident),
+                    JSXAttributeName::NamespacedName(name) => s.field("name", name),
+                }
+                s.field("value", &attr.value);
+            }),
+            Self::SpreadAttribute(attr) => {
+                s.node("JSXSpreadAttribute", attr.span, |s| s.field("argument", &attr.argument));
+            }
+        }
+    }
+}
+
+impl<'a> ESTree for JSXAttributeValue<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::StringLiteral(lit) => lit.serialize_estree(s),
+            Self::ExpressionContainer(expr) => expr.serialize_estree(s),
+            Self::Element(elem) => elem.serialize_estree(s),
+            Self::Fragment(elem) => elem.serialize_estree(s),
+        }
+    }
+}
+
+impl ESTree for JSXIdentifier {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("JSXIdentifier", self.span, |s| s.field("name", &self.name));
+    }
+}
+
+impl<'a> ESTree for JSXChild<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::Text(text) => s.node("JSXText", text.span, |s| {
+                s.field("value", &text.value);
+                s.field("raw", s.source(text.span));
+            }),
+            Self::Element(elem) => elem.serialize_estree(s),
+            Self::Fragment(elem) => elem.serialize_estree(s),
+            Self::ExpressionContainer(expr) => expr.serialize_estree(s),
+            Self::Spread(spread) => {
+                s.node("JSXSpreadChild", spread.span, |s| {
+                    s.field("expression", &spread.expression);
+                });
+            }
+        }
+    }
+}
+
+/* ---------- TypeScript ---------- */
+
+impl<'a> ESTree for TSAsExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSAsExpression", self.span, |s| {
+            s.field("expression", &self.expression);
+            s.field("typeAnnotation", &self.type_annotation);
+        });
+    }
+}
+
+impl<'a> ESTree for TSSatisfiesExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSSatisfiesExpression", self.span, |s| {
+            s.field("expression", &self.expression);
+            s.field("typeAnnotation", &self.type_annotation);
+        });
+    }
+}
+
+impl<'a> ESTree for TSTypeAssertion<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSTypeAssertion", self.span, |s| {
+            s.field("typeAnnotation", &self.type_annotation);
+            s.field("expression", &self.expression);
+        });
+    }
+}
+
+impl<'a> ESTree for TSNonNullExpression<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSNonNullExpression", self.span, |s| s.field("expression", &self.expression));
+    }
+}
+
+impl<'a> ESTree for TSTypeAnnotation<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSTypeAnnotation", self.span, |s| {
+            s.field("typeAnnotation", &self.type_annotation);
+        });
+    }
+}
+
+impl<'a> ESTree for TSTypeParameterDeclaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSTypeParameterDeclaration", self.span, |s| s.field("params", &self.params));
+    }
+}
+
+impl<'a> ESTree for TSTypeParameterInstantiation<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSTypeParameterInstantiation", self.span, |s| s.field("params", &self.params));
+    }
+}
+
+impl<'a> ESTree for TSTypeParameter<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSTypeParameter", self.span, |s| {
+            s.field("name", &self.name);
+            s.field("constraint", &self.constraint);
+            s.field("default", &self.default);
+            s.field("in", &self.r#in);
+            s.field("out", &self.out);
+        });
+    }
+}
+
+impl<'a> ESTree for TSTypeName<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::IdentifierName(ident) => ident.serialize_estree(s),
+            Self::QualifiedName(name) => name.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for TSQualifiedName<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSQualifiedName", self.span, |s| {
+            s.field("left", &self.left);
+            s.field("right", &self.right);
+        });
+    }
+}
+
+impl<'a> ESTree for TSType<'a> {
+    #[allow(clippy::too_many_lines)]
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::TSAnyKeyword(ty) => s.node("TSAnyKeyword", ty.span, |_| {}),
+            Self::TSBigIntKeyword(ty) => s.node("TSBigIntKeyword", ty.span, |_| {}),
+            Self::TSBooleanKeyword(ty) => s.node("TSBooleanKeyword", ty.span, |_| {}),
+            Self::TSNeverKeyword(ty) => s.node("TSNeverKeyword", ty.span, |_| {}),
+            Self::TSNullKeyword(ty) => s.node("TSNullKeyword", ty.span, |_| {}),
+            Self::TSNumberKeyword(ty) => s.node("TSNumberKeyword", ty.span, |_| {}),
+            Self::TSObjectKeyword(ty) => s.node("TSObjectKeyword", ty.span, |_| {}),
+            Self::TSStringKeyword(ty) => s.node("TSStringKeyword", ty.span, |_| {}),
+            Self::TSSymbolKeyword(ty) => s.node("TSSymbolKeyword", ty.span, |_| {}),
+            Self::TSThisKeyword(ty) => s.node("TSThisType", ty.span, |_| {}),
+            Self::TSUndefinedKeyword(ty) => s.node("TSUndefinedKeyword", ty.span, |_| {}),
+            Self::TSUnknownKeyword(ty) => s.node("TSUnknownKeyword", ty.span, |_| {}),
+            Self::TSVoidKeyword(ty) => s.node("TSVoidKeyword", ty.span, |_| {}),
+            Self::TSArrayType(ty) => {
+                s.node("TSArrayType", ty.span, |s| s.field("elementType", &ty.element_type));
+            }
+            Self::TSConditionalType(ty) => s.node("TSConditionalType", ty.span, |s| {
+                s.field("checkType", &ty.check_type);
+                s.field("extendsType", &ty.extends_type);
+                s.field("trueType", &ty.true_type);
+                s.field("falseType", &ty.false_type);
+            }),
+            Self::TSConstructorType(ty) => s.node("TSConstructorType", ty.span, |s| {
+                s.field("abstract", &ty.r#abstract);
+                s.field("params", &ty.params);
+                s.field("returnType", &ty.return_type);
+                optional_field(s, "typeParameters", &ty.type_parameters);
+            }),
+            Self::TSFunctionType(ty) => s.node("TSFunctionType", ty.span, |s| {
+                s.field("params", &ty.params);
+                s.field("returnType", &ty.return_type);
+                optional_field(s, "typeParameters", &ty.type_parameters);
+            }),
+            Self::TSImportType(ty) => s.node("TSImportType", ty.span, |s| {
+                s.field("isTypeOf", &ty.is_type_of);
+                s.field("parameter", &ty.parameter);
+                s.field("qualifier", &ty.qualifier);
+                s.field("typeParameters", &ty.type_parameters);
+            }),
+            Self::TSIndexedAccessType(ty) => s.node("TSIndexedAccessType", ty.span, |s| {
+                s.field("objectType", &ty.object_type);
+                s.field("indexType", &ty.index_type);
+            }),
+            Self::TSInferType(ty) => {
+                s.node("TSInferType", ty.span, |s| s.field("typeParameter", &ty.type_parameter));
+            }
+            Self::TSIntersectionType(ty) => {
+                s.node("TSIntersectionType", ty.span, |s| s.field("types", &ty.types));
+            }
+            Self::TSLiteralType(ty) => {
+                s.node("TSLiteralType", ty.span, |s| s.field("literal", &ty.literal));
+            }
+            Self::TSMappedType(ty) => s.node("TSMappedType", ty.span, |s| {
+                s.field("typeParameter", &ty.type_parameter);
+                s.field("nameType", &ty.name_type);
+                s.field("typeAnnotation", &ty.type_annotation);
+                mapped_type_modifier(s, "optional", &ty.optional);
+                mapped_type_modifier(s, "readonly", &ty.readonly);
+            }),
+            Self::TSQualifiedName(name) => name.serialize_estree(s),
+            Self::TSTemplateLiteralType(ty) => s.node("TSTemplateLiteralType", ty.span, |s| {
+                s.field("quasis", &ty.quasis);
+                s.field("types", &ty.types);
+            }),
+            Self::TSTupleType(ty) => {
+                s.node("TSTupleType", ty.span, |s| s.field("elementTypes", &ty.element_types));
+            }
+            Self::TSTypeLiteral(ty) => {
+                s.node("TSTypeLiteral", ty.span, |s| s.field("members", &ty.members));
+            }
+            Self::TSTypeOperatorType(ty) => s.node("TSTypeOperator", ty.span, |s| {
+                let operator = match ty.operator {
+                    TSTypeOperator::Keyof => "keyof",
+                    TSTypeOperator::Unique => "unique",
+                    TSTypeOperator::Readonly => "readonly",
+                };
+                s.field("operator", operator);
+                s.field("typeAnnotation", &ty.type_annotation);
+            }),
+            Self::TSTypePredicate(ty) => s.node("TSTypePredicate", ty.span, |s| {
+                s.field("asserts", &ty.asserts);
+                s.key("parameterName");
+                match &ty.parameter_name {
+                    TSTypePredicateName::Identifier(ident) => ident.serialize_estree(s),
+                    TSTypePredicateName::This(this) => s.node("TSThisType", this.span, |_| {}),
+                }
+                s.field("typeAnnotation", &ty.type_annotation);
+            }),
+            Self::TSTypeQuery(ty) => s.node("TSTypeQuery", ty.span, |s| {
+                s.field("exprName", &ty.expr_name);
+                optional_field(s, "typeParameters", &ty.type_parameters);
+            }),
+            Self::TSTypeReference(ty) => s.node("TSTypeReference", ty.span, |s| {
+                s.field("typeName", &ty.type_name);
+                optional_field(s, "typeParameters", &ty.type_parameters);
+            }),
+            Self::TSUnionType(ty) => {
+                s.node("TSUnionType", ty.span, |s| s.field("types", &ty.types));
+            }
+            Self::JSDocNullableType(ty) => s.node("JSDocNullableType", ty.span, |s| {
+                s.field("typeAnnotation", &ty.type_annotation);
+                s.field("postfix", &ty.postfix);
+            }),
+            Self::JSDocUnknownType(ty) => s.node("JSDocUnknownType", ty.span, |_| {}),
+            Self::FlowExactObjectType(ty) => {
+                s.node("FlowExactObjectType", ty.span, |s| s.field("members", &ty.members));
+            }
+            Self::FlowExistentialType(ty) => s.node("FlowExistentialType", ty.span, |_| {}),
+        }
+    }
+}
+
+fn mapped_type_modifier(
+    s: &mut ESTreeSerializer,
+    key: &str,
+    modifier: &TSMappedTypeModifierOperator,
+) {
+    match modifier {
+        TSMappedTypeModifierOperator::True => s.field(key, &true),
+        TSMappedTypeModifierOperator::Plus => s.field(key, "+"),
+        TSMappedTypeModifierOperator::Minus => s.field(key, "-"),
+        TSMappedTypeModifierOperator::None => {}
+    }
+}
+
+impl<'a> ESTree for TSLiteral<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::BooleanLiteral(lit) => lit.serialize_estree(s),
+            Self::NullLiteral(lit) => lit.serialize_estree(s),
+            Self::NumberLiteral(lit) => lit.serialize_estree(s),
+            Self::BigintLiteral(lit) => lit.serialize_estree(s),
+            Self::RegExpLiteral(lit) => lit.serialize_estree(s),
+            Self::StringLiteral(lit) => lit.serialize_estree(s),
+            Self::TemplateLiteral(lit) => lit.serialize_estree(s),
+            Self::UnaryExpression(expr) => expr.serialize_estree(s),
+        }
+    }
+}
+
+impl<'a> ESTree for TSTupleElement<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::TSType(ty) => ty.serialize_estree(s),
+            Self::TSOptionalType(ty) => s.node("TSOptionalType", ty.span, |s| {
+                s.field("typeAnnotation", &ty.type_annotation);
+            }),
+            Self::TSRestType(ty) => {
+                s.node("TSRestType", ty.span, |s| s.field("typeAnnotation", &ty.type_annotation));
+            }
+            Self::TSNamedTupleMember(ty) => s.node("TSNamedTupleMember", ty.span, |s| {
+                s.field("elementType", &ty.element_type);
+                s.field("label", &ty.label);
+                s.field("optional", &ty.optional);
+            }),
+        }
+    }
+}
+
+impl<'a> ESTree for TSSignature<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        match self {
+            Self::TSIndexSignature(sig) => sig.serialize_estree(s),
+            Self::TSPropertySignature(sig) => s.node("TSPropertySignature", sig.span, |s| {
+                s.field("key", &sig.key);
+                s.field("computed", &sig.computed);
+                s.field("optional", &sig.optional);
+                s.field("readonly", &sig.readonly);
+                s.field("static", &false);
+                optional_field(s, "typeAnnotation", &sig.type_annotation);
+            }),
+            Self::TSCallSignatureDeclaration(sig) => {
+                s.node("TSCallSignatureDeclaration", sig.span, |s| {
+                    s.field("params", &sig.params);
+                    optional_field(s, "returnType", &sig.return_type);
+                    optional_field(s, "typeParameters", &sig.type_parameters);
+                });
+            }
+            Self::TSConstructSignatureDeclaration(sig) => {
+                s.node("TSConstructSignatureDeclaration", sig.span, |s| {
+                    s.field("params", &sig.params);
+                    optional_field(s, "returnType", &sig.return_type);
+                    optional_field(s, "typeParameters", &sig.type_parameters);
+                });
+            }
+            Self::TSMethodSignature(sig) => s.node("TSMethodSignature", sig.span, |s| {
+                s.field("key", &sig.key);
+                s.field("computed", &sig.computed);
+                s.field("optional", &sig.optional);
+                let kind = match sig.kind {
+                    TSMethodSignatureKind::Method => "method",
+                    TSMethodSignatureKind::Get => "get",
+                    TSMethodSignatureKind::Set => "set",
+                };
+                s.field("kind", kind);
+                s.field("params", &sig.params);
+                optional_field(s, "returnType", &sig.return_type);
+                optional_field(s, "typeParameters", &sig.type_parameters);
+            }),
+        }
+    }
+}
+
+impl<'a> ESTree for TSIndexSignature<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSIndexSignature", self.span, |s| {
+            s.key("parameters");
+            s.array(self.parameters.iter(), |s, param| {
+                s.node("Identifier", param.span, |s| {
+                    s.field("name", &param.name);
+                    s.field("typeAnnotation", &param.type_annotation);
+                });
+            });
+            s.field("typeAnnotation", &self.type_annotation);
+        });
+    }
+}
+
+impl<'a> ESTree for TSTypeAliasDeclaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSTypeAliasDeclaration", self.span, |s| {
+            s.field("id", &self.id);
+            s.field("typeAnnotation", &self.type_annotation);
+            optional_field(s, "typeParameters", &self.type_parameters);
+            modifier(s, "declare", &self.modifiers, ModifierKind::Declare);
+        });
+    }
+}
+
+impl<'a> ESTree for TSInterfaceDeclaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSInterfaceDeclaration", self.span, |s| {
+            s.field("id", &self.id);
+            s.key("body");
+            s.node("TSInterfaceBody", self.body.span, |s| s.field("body", &self.body.body));
+            s.array_field("extends", self.extends.iter().flatten());
+            optional_field(s, "typeParameters", &self.type_parameters);
+            modifier(s, "declare", &self.modifiers, ModifierKind::Declare);
+        });
+    }
+}
+
+impl<'a> ESTree for TSInterfaceHeritage<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSInterfaceHeritage", self.span, |s| {
+            s.field("expression", &self.expression);
+            optional_field(s, "typeParameters", &self.type_parameters);
+        });
+    }
+}
+
+impl<'a> ESTree for TSClassImplements<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSClassImplements", self.span, |s| {
+            s.field("expression", &self.expression);
+            optional_field(s, "typeParameters", &self.type_parameters);
+        });
+    }
+}
+
+impl<'a> ESTree for TSEnumDeclaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSEnumDeclaration", self.span, |s| {
+            s.field("id", &self.id);
+            s.field("members", &self.members);
+            modifier(s, "const", &self.modifiers, ModifierKind::Const);
+            modifier(s, "declare", &self.modifiers, ModifierKind::Declare);
+        });
+    }
+}
+
+impl<'a> ESTree for TSEnumMember<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSEnumMember", self.span, |s| {
+            match &self.id {
+                TSEnumMemberName::Identifier(ident) => s.field("id", ident),
+                TSEnumMemberName::StringLiteral(lit) => s.field("id", lit),
+                TSEnumMemberName::ComputedPropertyName(expr) => {
+                    s.field("id", expr);
+                    s.field("computed", &true);
+                }
+                TSEnumMemberName::NumberLiteral(lit) => s.field("id", lit),
+            }
+            optional_field(s, "initializer", &self.initializer);
+        });
+    }
+}
+
+impl<'a> ESTree for TSModuleDeclaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSModuleDeclaration", self.span, |s| {
+            match &self.id {
+                TSModuleDeclarationName::Identifier(ident) => {
+                    s.field("id", ident);
+                    flag(s, "global", ident.name == "global");
+                }
+                TSModuleDeclarationName::StringLiteral(lit) => s.field("id", lit),
+            }
+            s.key("body");
+            match &self.body {
+                TSModuleDeclarationBody::TSModuleDeclaration(decl) => decl.serialize_estree(s),
+                TSModuleDeclarationBody::TSModuleBlock(block) => {
+                    s.node("TSModuleBlock", block.span, |s| s.field("body", &block.body));
+                }
+            }
+            modifier(s, "declare", &self.modifiers, ModifierKind::Declare);
+        });
+    }
+}
+
+impl<'a> ESTree for TSImportEqualsDeclaration<'a> {
+    fn serialize_estree(&self, s: &mut ESTreeSerializer) {
+        s.node("TSImportEqualsDeclaration", self.span, |s| {
+            s.field("id", &self.id);
+            s.key("moduleReference");
+            match &*self.module_reference {
+                TSModuleReference::TypeName(name) => name.serialize_estree(s),
+                TSModuleReference::ExternalModuleReference(reference) => {
+                    s.node("TSExternalModuleReference", reference.span, |s| {
+                        s.field("expression", &reference.expression);
+                    });
+                }
+            }
+            s.field("importKind", import_or_export_kind(Some(self.import_kind)));
+            s.field("isExport", &self.is_export);
+        });
+    }
+}
@@ -5,16 +5,25 @@
 #![feature(let_chains)]
 #![feature(is_some_and)]
 
+#[cfg(feature = "serde")]
+pub mod estree;
 #[cfg(feature = "serde")]
 mod serialize;
 
 pub mod ast;
 mod ast_builder;
 mod ast_kind;
+mod clone_in;
+pub mod constant_evaluation;
+mod content;
+mod line_index;
 pub mod module_record;
+mod shift;
+mod side_effects;
 mod source_type;
 mod span;
 pub mod syntax_directed_operations;
+pub mod traverse;
 mod trivia;
 pub mod visit;
 pub mod visit_mut;
@@ -24,9 +33,20 @@ pub use num_bigint::BigUint;
 
 pub use crate::ast_builder::AstBuilder;
 pub use crate::ast_kind::AstKind;
+pub use crate::clone_in::CloneIn;
+pub use crate::constant_evaluation::{ConstantEvaluation, ConstantValue};
+pub use crate::content::{ContentEq, ContentHash, ContentOptions};
+pub use crate::line_index::{LineColumn, LineIndex};
+pub use crate::shift::ShiftSpan;
+pub use crate::side_effects::SideEffects;
 pub use crate::source_type::{Language, LanguageVariant, ModuleKind, SourceType, VALID_EXTENSIONS};
 pub use crate::span::{GetSpan, Span};
-pub use crate::trivia::{CommentKind, Trivias};
+pub use crate::trivia::{AttachedComment, Comment, CommentAnnotation, CommentKind, Trivias};
+
+/// Default limit for the nesting depth of statements, expressions, patterns and types
+/// in the parser, the semantic builder and the printer.
+/// Deeper input is rejected instead of overflowing the stack.
+pub const MAX_NESTING_DEPTH: u32 = 512;
 
 /// Type alis for [`CompactString`]
 pub type Atom = CompactString;
@@ -0,0 +1,217 @@
+//! Line, column and UTF-16 offset lookups for [`Span`](crate::Span) offsets
+
+use rustc_hash::FxHashMap;
+
+/// A zero-based line and column position.
+///
+/// `column` counts UTF-8 bytes and `column_utf16` counts UTF-16 code units from the start of the line,
+/// the latter is what editors, the LSP and JavaScript strings use.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LineColumn {
+    pub line: u32,
+    pub column: u32,
+    pub column_utf16: u32,
+}
+
+/// A non-ASCII character, which takes more than one byte in UTF-8
+#[derive(Debug, Clone, Copy)]
+struct WideChar {
+    /// Byte column of the character
+    column: u32,
+    /// Length in UTF-8 bytes
+    len: u32,
+    /// Length in UTF-16 code units
+    len_utf16: u32,
+}
+
+/// Index of the line starts of a source text, built once and queried in `O(log lines)`.
+///
+/// Lines are terminated by `\n`, `\r\n` or `\r`, the same as editors and the diagnostic reporters.
+/// Offsets are byte offsets into the source text, as stored in [`Span`](crate::Span).
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    len: u32,
+    /// Byte offset of the start of each line
+    line_starts: Vec<u32>,
+    /// UTF-16 offset of the start of each line
+    line_starts_utf16: Vec<u32>,
+    /// Non-ASCII characters of the lines containing any
+    wide_chars: FxHashMap<u32, Vec<WideChar>>,
+}
+
+impl LineIndex {
+    /// # Panics
+    /// When the source text is larger than 4GB, which cannot be addressed by a `Span`.
+    #[must_use]
+    pub fn new(source_text: &str) -> Self {
+        let len = u32::try_from(source_text.len()).expect("source text is larger than 4GB");
+        let mut line_starts = vec![0];
+        let mut line_starts_utf16 = vec![0];
+        let mut wide_chars: FxHashMap<u32, Vec<WideChar>> = FxHashMap::default();
+        let mut line_start = 0;
+        let mut offset_utf16 = 0;
+        let mut chars = source_text.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            #[allow(clippy::cast_possible_truncation)]
+            let (offset, len, len_utf16) =
+                (offset as u32, c.len_utf8() as u32, c.len_utf16() as u32);
+            offset_utf16 += len_utf16;
+            match c {
+                '\r' if chars.next_if(|(_, c)| *c == '\n').is_some() => {
+                    offset_utf16 += 1;
+                    line_start = offset + 2;
+                }
+                '\n' | '\r' => line_start = offset + 1,
+                _ => {
+                    if len > 1 {
+                        #[allow(clippy::cast_possible_truncation)]
+                        let line = line_starts.len() as u32 - 1;
+                        let column = offset - line_start;
+                        wide_chars.entry(line).or_default().push(WideChar {
+                            column,
+                            len,
+                            len_utf16,
+                        });
+                    }
+                    continue;
+                }
+            }
+            line_starts.push(line_start);
+            line_starts_utf16.push(offset_utf16);
+        }
+        Self { len, line_starts, line_starts_utf16, wide_chars }
+    }
+
+    /// Number of lines, an empty source text has one line
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Byte offset of the start of `line`
+    #[must_use]
+    pub fn line_start(&self, line: u32) -> Option<u32> {
+        self.line_starts.get(line as usize).copied()
+    }
+
+    /// Line and column of a byte offset, offsets past the end are clamped to the end of the source text
+    #[must_use]
+    pub fn line_column(&self, offset: u32) -> LineColumn {
+        let offset = offset.min(self.len);
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let column = offset - self.line_starts[line];
+        #[allow(clippy::cast_possible_truncation)]
+        let line = line as u32;
+        LineColumn { line, column, column_utf16: self.utf16_column(line, column) }
+    }
+
+    /// Byte offset of a line and byte column, `None` if the position is outside of the source text.
+    #[must_use]
+    pub fn offset(&self, line: u32, column: u32) -> Option<u32> {
+        let start = self.line_start(line)?;
+        let offset = start.checked_add(column)?;
+        (offset <= self.line_end(line)).then_some(offset)
+    }
+
+    /// Byte offset of a line and UTF-16 column, `None` if the position is outside of the source text.
+    #[must_use]
+    pub fn offset_utf16(&self, line: u32, column_utf16: u32) -> Option<u32> {
+        let start = self.line_start(line)?;
+        let offset = start.checked_add(self.utf8_column(line, column_utf16))?;
+        (offset <= self.line_end(line)).then_some(offset)
+    }
+
+    /// Converts a byte offset to a UTF-16 offset, e.g. for use with JavaScript strings
+    #[must_use]
+    pub fn utf16_offset(&self, offset: u32) -> u32 {
+        let LineColumn { line, column_utf16, .. } = self.line_column(offset);
+        self.line_starts_utf16[line as usize] + column_utf16
+    }
+
+    /// Converts a UTF-16 offset to a byte offset, offsets past the end are clamped to the end of the source text
+    #[must_use]
+    pub fn offset_from_utf16(&self, offset_utf16: u32) -> u32 {
+        let line = self.line_starts_utf16.partition_point(|&start| start <= offset_utf16) - 1;
+        let column_utf16 = offset_utf16 - self.line_starts_utf16[line];
+        #[allow(clippy::cast_possible_truncation)]
+        let line = line as u32;
+        let offset = self.line_starts[line as usize] + self.utf8_column(line, column_utf16);
+        offset.min(self.line_end(line))
+    }
+
+    /// Byte offset of the start of the next line, or the end of the source text
+    fn line_end(&self, line: u32) -> u32 {
+        self.line_start(line + 1).unwrap_or(self.len)
+    }
+
+    fn utf16_column(&self, line: u32, column: u32) -> u32 {
+        let Some(wide_chars) = self.wide_chars.get(&line) else {
+            return column;
+        };
+        let mut column_utf16 = column;
+        for c in wide_chars {
+            if c.column >= column {
+                break;
+            }
+            column_utf16 -= c.len - c.len_utf16;
+        }
+        column_utf16
+    }
+
+    fn utf8_column(&self, line: u32, column_utf16: u32) -> u32 {
+        let Some(wide_chars) = self.wide_chars.get(&line) else {
+            return column_utf16;
+        };
+        let mut column = column_utf16;
+        for c in wide_chars {
+            if c.column >= column {
+                break;
+            }
+            column += c.len - c.len_utf16;
+        }
+        column
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LineColumn, LineIndex};
+
+    #[test]
+    fn line_column() {
+        let source_text = "let a;\r\nlet é = '😀';\rb\n\nc";
+        let index = LineIndex::new(source_text);
+        assert_eq!(index.line_count(), 5);
+
+        let lc = |line, column, column_utf16| LineColumn { line, column, column_utf16 };
+        let offset_of = |s: &str| u32::try_from(source_text.find(s).unwrap()).unwrap();
+        assert_eq!(index.line_column(0), lc(0, 0, 0));
+        assert_eq!(index.line_column(offset_of("\r\n")), lc(0, 6, 6));
+        assert_eq!(index.line_column(offset_of("é")), lc(1, 4, 4));
+        assert_eq!(index.line_column(offset_of(" = ")), lc(1, 6, 5));
+        assert_eq!(index.line_column(offset_of("';")), lc(1, 14, 11));
+        assert_eq!(index.line_column(offset_of("b")), lc(2, 0, 0));
+        assert_eq!(index.line_column(offset_of("c")), lc(4, 0, 0));
+        assert_eq!(index.line_column(1000), lc(4, 1, 1));
+
+        assert_eq!(index.offset(1, 6), Some(offset_of(" = ")));
+        assert_eq!(index.offset_utf16(1, 5), Some(offset_of(" = ")));
+        assert_eq!(index.offset_utf16(1, 11), Some(offset_of("';")));
+        assert_eq!(index.offset(3, 1), Some(offset_of("c")));
+        assert_eq!(index.offset(3, 2), None);
+        assert_eq!(index.offset(5, 0), None);
+
+        let utf16 = source_text.encode_utf16().collect::<Vec<_>>();
+        for (offset, _) in source_text.char_indices() {
+            #[allow(clippy::cast_possible_truncation)]
+            let offset = offset as u32;
+            let offset_utf16 = index.utf16_offset(offset);
+            let prefix = String::from_utf16(&utf16[..offset_utf16 as usize]).unwrap();
+            assert_eq!(prefix, source_text[..offset as usize]);
+            assert_eq!(index.offset_from_utf16(offset_utf16), offset);
+            let LineColumn { line, column, column_utf16 } = index.line_column(offset);
+            assert_eq!(index.offset(line, column), Some(offset));
+            assert_eq!(index.offset_utf16(line, column_utf16), Some(offset));
+        }
+    }
+}
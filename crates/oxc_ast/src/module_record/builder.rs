@@ -1,7 +1,7 @@
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{
-    ast::*, module_record::*, syntax_directed_operations::BoundNames, Atom, GetSpan, Span,
-};
+use super::*;
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, syntax_directed_operations::BoundNames, Atom, GetSpan, Span};
 
 #[derive(Debug, Default)]
 pub struct ModuleRecordBuilder {
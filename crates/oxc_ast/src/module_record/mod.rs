@@ -1,7 +1,10 @@
 //! [ECMAScript Module Record](https://tc39.es/ecma262/#sec-abstract-module-records)
 
+mod builder;
+
 use rustc_hash::FxHashMap;
 
+pub use self::builder::ModuleRecordBuilder;
 use crate::{Atom, Span};
 
 /// Source Text Module Record
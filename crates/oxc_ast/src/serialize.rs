@@ -49,6 +49,7 @@ impl<'a> Serialize for Program<'a> {
         let source_type = match self.source_type.module_kind() {
             ModuleKind::Script => "script",
             ModuleKind::Module => "module",
+            ModuleKind::Unambiguous => "unambiguous",
         };
         state.serialize_field("sourceType", &source_type)?;
         let body = BlockWrapper { directives: &self.directives, body: &self.body };
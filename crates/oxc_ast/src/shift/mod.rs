@@ -0,0 +1,60 @@
+//! Moving the source positions of AST nodes, for reusing nodes after a text edit
+//!
+//! The implementations for the nodes are generated in `nodes.rs` by `cargo run -p ast_codegen`.
+
+mod nodes;
+
+use oxc_allocator::{Box, Vec};
+
+use crate::{ast::Modifiers, Span};
+
+/// Move the source positions of a node and all of its descendants.
+///
+/// Used by incremental parsing to reuse the nodes after an edited range,
+/// where `offset` is the length of the inserted text minus the length of the removed text.
+pub trait ShiftSpan {
+    /// Add `offset` to every position at or after `from`
+    fn shift_span(&mut self, from: u32, offset: i64);
+}
+
+impl ShiftSpan for Span {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        if self.start >= from {
+            self.start = (i64::from(self.start) + offset) as u32;
+        }
+        if self.end >= from {
+            self.end = (i64::from(self.end) + offset) as u32;
+        }
+    }
+}
+
+impl<T: ShiftSpan> ShiftSpan for Option<T> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        if let Some(it) = self {
+            it.shift_span(from, offset);
+        }
+    }
+}
+
+impl<'a, T: ShiftSpan> ShiftSpan for Box<'a, T> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        (**self).shift_span(from, offset);
+    }
+}
+
+impl<'a, T: ShiftSpan> ShiftSpan for Vec<'a, T> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        for it in self.iter_mut() {
+            it.shift_span(from, offset);
+        }
+    }
+}
+
+impl<'a> ShiftSpan for Modifiers<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        for modifier in self.iter_mut() {
+            modifier.span.shift_span(from, offset);
+        }
+    }
+}
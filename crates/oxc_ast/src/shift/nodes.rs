@@ -0,0 +1,2055 @@
+// Auto-generated by `cargo run -p ast_codegen`, do not edit.
+
+#![allow(clippy::match_same_arms)]
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, ShiftSpan};
+
+impl<'a> ShiftSpan for Program<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.directives.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for Expression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::BooleanLiteral(it) => it.shift_span(from, offset),
+            Self::NullLiteral(it) => it.shift_span(from, offset),
+            Self::NumberLiteral(it) => it.shift_span(from, offset),
+            Self::BigintLiteral(it) => it.shift_span(from, offset),
+            Self::RegExpLiteral(it) => it.shift_span(from, offset),
+            Self::StringLiteral(it) => it.shift_span(from, offset),
+            Self::TemplateLiteral(it) => it.shift_span(from, offset),
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::MetaProperty(it) => it.shift_span(from, offset),
+            Self::Super(it) => it.shift_span(from, offset),
+            Self::ArrayExpression(it) => it.shift_span(from, offset),
+            Self::ArrowFunctionExpression(it) => it.shift_span(from, offset),
+            Self::AssignmentExpression(it) => it.shift_span(from, offset),
+            Self::AwaitExpression(it) => it.shift_span(from, offset),
+            Self::BinaryExpression(it) => it.shift_span(from, offset),
+            Self::CallExpression(it) => it.shift_span(from, offset),
+            Self::ChainExpression(it) => it.shift_span(from, offset),
+            Self::ClassExpression(it) => it.shift_span(from, offset),
+            Self::ConditionalExpression(it) => it.shift_span(from, offset),
+            Self::FunctionExpression(it) => it.shift_span(from, offset),
+            Self::ImportExpression(it) => it.shift_span(from, offset),
+            Self::LogicalExpression(it) => it.shift_span(from, offset),
+            Self::MemberExpression(it) => it.shift_span(from, offset),
+            Self::NewExpression(it) => it.shift_span(from, offset),
+            Self::ObjectExpression(it) => it.shift_span(from, offset),
+            Self::ParenthesizedExpression(it) => it.shift_span(from, offset),
+            Self::SequenceExpression(it) => it.shift_span(from, offset),
+            Self::TaggedTemplateExpression(it) => it.shift_span(from, offset),
+            Self::ThisExpression(it) => it.shift_span(from, offset),
+            Self::UnaryExpression(it) => it.shift_span(from, offset),
+            Self::UpdateExpression(it) => it.shift_span(from, offset),
+            Self::YieldExpression(it) => it.shift_span(from, offset),
+            Self::PrivateInExpression(it) => it.shift_span(from, offset),
+            Self::JSXElement(it) => it.shift_span(from, offset),
+            Self::JSXFragment(it) => it.shift_span(from, offset),
+            Self::TSAsExpression(it) => it.shift_span(from, offset),
+            Self::TSSatisfiesExpression(it) => it.shift_span(from, offset),
+            Self::TSTypeAssertion(it) => it.shift_span(from, offset),
+            Self::TSNonNullExpression(it) => it.shift_span(from, offset),
+            Self::TSInstantiationExpression(it) => it.shift_span(from, offset),
+            Self::InvalidExpression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for IdentifierName {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for IdentifierReference {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for BindingIdentifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for LabelIdentifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for ThisExpression {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ArrayExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.elements.shift_span(from, offset);
+        self.trailing_comma.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ObjectExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.properties.shift_span(from, offset);
+        self.trailing_comma.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ObjectProperty<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Property(it) => it.shift_span(from, offset),
+            Self::SpreadProperty(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for Property<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.key.shift_span(from, offset);
+        self.value.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for PropertyKey<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::PrivateIdentifier(it) => it.shift_span(from, offset),
+            Self::Expression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for PropertyValue<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Pattern(it) => it.shift_span(from, offset),
+            Self::Expression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TemplateLiteral<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.quasis.shift_span(from, offset);
+        self.expressions.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TaggedTemplateExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.tag.shift_span(from, offset);
+        self.quasi.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TemplateElement {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for MemberExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::ComputedMemberExpression(it) => it.shift_span(from, offset),
+            Self::StaticMemberExpression(it) => it.shift_span(from, offset),
+            Self::PrivateFieldExpression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for ComputedMemberExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.object.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for StaticMemberExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.object.shift_span(from, offset);
+        self.property.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for PrivateFieldExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.object.shift_span(from, offset);
+        self.field.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for CallExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.callee.shift_span(from, offset);
+        self.arguments.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for NewExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.callee.shift_span(from, offset);
+        self.arguments.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for MetaProperty {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.meta.shift_span(from, offset);
+        self.property.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for SpreadElement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for Argument<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::SpreadElement(it) => it.shift_span(from, offset),
+            Self::Expression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for UpdateExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for UnaryExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for BinaryExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.left.shift_span(from, offset);
+        self.right.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for PrivateInExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.left.shift_span(from, offset);
+        self.right.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for LogicalExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.left.shift_span(from, offset);
+        self.right.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ConditionalExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.test.shift_span(from, offset);
+        self.consequent.shift_span(from, offset);
+        self.alternate.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.left.shift_span(from, offset);
+        self.right.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentTarget<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::SimpleAssignmentTarget(it) => it.shift_span(from, offset),
+            Self::AssignmentTargetPattern(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for SimpleAssignmentTarget<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::AssignmentTargetIdentifier(it) => it.shift_span(from, offset),
+            Self::MemberAssignmentTarget(it) => it.shift_span(from, offset),
+            Self::TSAsExpression(it) => it.shift_span(from, offset),
+            Self::TSSatisfiesExpression(it) => it.shift_span(from, offset),
+            Self::TSNonNullExpression(it) => it.shift_span(from, offset),
+            Self::TSTypeAssertion(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentTargetPattern<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::ArrayAssignmentTarget(it) => it.shift_span(from, offset),
+            Self::ObjectAssignmentTarget(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for ArrayAssignmentTarget<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.elements.shift_span(from, offset);
+        self.rest.shift_span(from, offset);
+        self.trailing_comma.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ObjectAssignmentTarget<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.properties.shift_span(from, offset);
+        self.rest.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentTargetMaybeDefault<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::AssignmentTarget(it) => it.shift_span(from, offset),
+            Self::AssignmentTargetWithDefault(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentTargetWithDefault<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.binding.shift_span(from, offset);
+        self.init.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentTargetProperty<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::AssignmentTargetPropertyIdentifier(it) => it.shift_span(from, offset),
+            Self::AssignmentTargetPropertyProperty(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentTargetPropertyIdentifier<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.binding.shift_span(from, offset);
+        self.init.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentTargetPropertyProperty<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.name.shift_span(from, offset);
+        self.binding.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for SequenceExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expressions.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for Super {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for AwaitExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ChainExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ChainElement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::CallExpression(it) => it.shift_span(from, offset),
+            Self::MemberExpression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for ParenthesizedExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for Statement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::BlockStatement(it) => it.shift_span(from, offset),
+            Self::BreakStatement(it) => it.shift_span(from, offset),
+            Self::ContinueStatement(it) => it.shift_span(from, offset),
+            Self::DebuggerStatement(it) => it.shift_span(from, offset),
+            Self::DoWhileStatement(it) => it.shift_span(from, offset),
+            Self::EmptyStatement(it) => it.shift_span(from, offset),
+            Self::ExpressionStatement(it) => it.shift_span(from, offset),
+            Self::ForInStatement(it) => it.shift_span(from, offset),
+            Self::ForOfStatement(it) => it.shift_span(from, offset),
+            Self::ForStatement(it) => it.shift_span(from, offset),
+            Self::IfStatement(it) => it.shift_span(from, offset),
+            Self::LabeledStatement(it) => it.shift_span(from, offset),
+            Self::ReturnStatement(it) => it.shift_span(from, offset),
+            Self::SwitchStatement(it) => it.shift_span(from, offset),
+            Self::ThrowStatement(it) => it.shift_span(from, offset),
+            Self::TryStatement(it) => it.shift_span(from, offset),
+            Self::WhileStatement(it) => it.shift_span(from, offset),
+            Self::WithStatement(it) => it.shift_span(from, offset),
+            Self::ModuleDeclaration(it) => it.shift_span(from, offset),
+            Self::Declaration(it) => it.shift_span(from, offset),
+            Self::InvalidStatement(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for Directive<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for BlockStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for Declaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::VariableDeclaration(it) => it.shift_span(from, offset),
+            Self::FunctionDeclaration(it) => it.shift_span(from, offset),
+            Self::ClassDeclaration(it) => it.shift_span(from, offset),
+            Self::TSTypeAliasDeclaration(it) => it.shift_span(from, offset),
+            Self::TSInterfaceDeclaration(it) => it.shift_span(from, offset),
+            Self::TSEnumDeclaration(it) => it.shift_span(from, offset),
+            Self::TSModuleDeclaration(it) => it.shift_span(from, offset),
+            Self::TSImportEqualsDeclaration(it) => it.shift_span(from, offset),
+            Self::FlowOpaqueTypeDeclaration(it) => it.shift_span(from, offset),
+            Self::FlowDeclareModuleExports(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for VariableDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.declarations.shift_span(from, offset);
+        self.modifiers.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for VariableDeclarator<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.init.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for EmptyStatement {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ExpressionStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for IfStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.test.shift_span(from, offset);
+        self.consequent.shift_span(from, offset);
+        self.alternate.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for DoWhileStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+        self.test.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for WhileStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.test.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ForStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.init.shift_span(from, offset);
+        self.test.shift_span(from, offset);
+        self.update.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ForStatementInit<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::VariableDeclaration(it) => it.shift_span(from, offset),
+            Self::Expression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for ForInStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.left.shift_span(from, offset);
+        self.right.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ForOfStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.left.shift_span(from, offset);
+        self.right.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ForStatementLeft<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::VariableDeclaration(it) => it.shift_span(from, offset),
+            Self::AssignmentTarget(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for ContinueStatement {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.label.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for BreakStatement {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.label.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ReturnStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for WithStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.object.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for SwitchStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.discriminant.shift_span(from, offset);
+        self.cases.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for SwitchCase<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.test.shift_span(from, offset);
+        self.consequent.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for LabeledStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.label.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ThrowStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TryStatement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.block.shift_span(from, offset);
+        self.handler.shift_span(from, offset);
+        self.finalizer.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for CatchClause<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.param.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for DebuggerStatement {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for InvalidStatement {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for InvalidExpression {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for BindingPattern<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.kind.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for BindingPatternKind<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::BindingIdentifier(it) => it.shift_span(from, offset),
+            Self::ObjectPattern(it) => it.shift_span(from, offset),
+            Self::ArrayPattern(it) => it.shift_span(from, offset),
+            Self::RestElement(it) => it.shift_span(from, offset),
+            Self::AssignmentPattern(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for AssignmentPattern<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.left.shift_span(from, offset);
+        self.right.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ObjectPattern<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.properties.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ObjectPatternProperty<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Property(it) => it.shift_span(from, offset),
+            Self::RestElement(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for ArrayPattern<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.elements.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for RestElement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for Function<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+        self.return_type.shift_span(from, offset);
+        self.modifiers.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for FormalParameters<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.items.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for FormalParameter<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.pattern.shift_span(from, offset);
+        self.decorators.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for FunctionBody<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.directives.shift_span(from, offset);
+        self.statements.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ArrowExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+        self.return_type.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for YieldExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for Class<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.super_class.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+        self.super_type_parameters.shift_span(from, offset);
+        self.implements.shift_span(from, offset);
+        self.decorators.shift_span(from, offset);
+        self.modifiers.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ClassBody<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ClassElement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::StaticBlock(it) => it.shift_span(from, offset),
+            Self::MethodDefinition(it) => it.shift_span(from, offset),
+            Self::PropertyDefinition(it) => it.shift_span(from, offset),
+            Self::AccessorProperty(it) => it.shift_span(from, offset),
+            Self::TSAbstractMethodDefinition(it) => it.shift_span(from, offset),
+            Self::TSAbstractPropertyDefinition(it) => it.shift_span(from, offset),
+            Self::TSIndexSignature(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for MethodDefinition<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.key.shift_span(from, offset);
+        self.value.shift_span(from, offset);
+        self.decorators.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for PropertyDefinition<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.key.shift_span(from, offset);
+        self.value.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+        self.decorators.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for PrivateIdentifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for StaticBlock<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ModuleDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.kind.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ModuleDeclarationKind<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::ImportDeclaration(it) => it.shift_span(from, offset),
+            Self::ExportAllDeclaration(it) => it.shift_span(from, offset),
+            Self::ExportDefaultDeclaration(it) => it.shift_span(from, offset),
+            Self::ExportNamedDeclaration(it) => it.shift_span(from, offset),
+            Self::TSExportAssignment(it) => it.shift_span(from, offset),
+            Self::TSNamespaceExportDeclaration(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for AccessorProperty<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.key.shift_span(from, offset);
+        self.value.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ImportExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.source.shift_span(from, offset);
+        self.arguments.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ImportDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.specifiers.shift_span(from, offset);
+        self.source.shift_span(from, offset);
+        self.assertions.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for ImportDeclarationSpecifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::ImportSpecifier(it) => it.shift_span(from, offset),
+            Self::ImportDefaultSpecifier(it) => it.shift_span(from, offset),
+            Self::ImportNamespaceSpecifier(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for ImportSpecifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.imported.shift_span(from, offset);
+        self.local.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for ImportDefaultSpecifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.local.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for ImportNamespaceSpecifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.local.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for ImportAttribute {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.key.shift_span(from, offset);
+        self.value.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for ImportAttributeKey {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::StringLiteral(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for ExportNamedDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.declaration.shift_span(from, offset);
+        self.specifiers.shift_span(from, offset);
+        self.source.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ExportDefaultDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.declaration.shift_span(from, offset);
+        self.exported.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ExportAllDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.exported.shift_span(from, offset);
+        self.source.shift_span(from, offset);
+        self.assertions.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for ExportSpecifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.local.shift_span(from, offset);
+        self.exported.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for ExportDefaultDeclarationKind<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Expression(it) => it.shift_span(from, offset),
+            Self::FunctionDeclaration(it) => it.shift_span(from, offset),
+            Self::ClassDeclaration(it) => it.shift_span(from, offset),
+            Self::TSInterfaceDeclaration(it) => it.shift_span(from, offset),
+            Self::TSEnumDeclaration(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for ModuleExportName {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::StringLiteral(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for BooleanLiteral {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for NullLiteral {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for NumberLiteral<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for BigintLiteral {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for RegExpLiteral<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.pattern.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for StringLiteral {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXElement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.opening_element.shift_span(from, offset);
+        self.closing_element.shift_span(from, offset);
+        self.children.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXOpeningElement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.name.shift_span(from, offset);
+        self.attributes.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXClosingElement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.name.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXFragment<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.opening_fragment.shift_span(from, offset);
+        self.closing_fragment.shift_span(from, offset);
+        self.children.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for JSXOpeningFragment {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for JSXClosingFragment {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXElementName<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::NamespacedName(it) => it.shift_span(from, offset),
+            Self::MemberExpression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for JSXNamespacedName {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.namespace.shift_span(from, offset);
+        self.property.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXMemberExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.object.shift_span(from, offset);
+        self.property.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXMemberExpressionObject<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::MemberExpression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for JSXExpressionContainer<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Expression(it) => it.shift_span(from, offset),
+            Self::EmptyExpression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for JSXEmptyExpression {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXAttributeItem<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Attribute(it) => it.shift_span(from, offset),
+            Self::SpreadAttribute(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for JSXAttribute<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.name.shift_span(from, offset);
+        self.value.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXSpreadAttribute<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.argument.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXAttributeName<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::NamespacedName(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for JSXAttributeValue<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::StringLiteral(it) => it.shift_span(from, offset),
+            Self::ExpressionContainer(it) => it.shift_span(from, offset),
+            Self::Element(it) => it.shift_span(from, offset),
+            Self::Fragment(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for JSXIdentifier {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSXChild<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Text(it) => it.shift_span(from, offset),
+            Self::Element(it) => it.shift_span(from, offset),
+            Self::Fragment(it) => it.shift_span(from, offset),
+            Self::ExpressionContainer(it) => it.shift_span(from, offset),
+            Self::Spread(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for JSXSpreadChild<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for JSXText {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSEnumDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.members.shift_span(from, offset);
+        self.modifiers.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSEnumMember<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.initializer.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSEnumMemberName<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::StringLiteral(it) => it.shift_span(from, offset),
+            Self::ComputedPropertyName(it) => it.shift_span(from, offset),
+            Self::NumberLiteral(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeAnnotation<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSLiteralType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.literal.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSLiteral<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::BooleanLiteral(it) => it.shift_span(from, offset),
+            Self::NullLiteral(it) => it.shift_span(from, offset),
+            Self::NumberLiteral(it) => it.shift_span(from, offset),
+            Self::BigintLiteral(it) => it.shift_span(from, offset),
+            Self::RegExpLiteral(it) => it.shift_span(from, offset),
+            Self::StringLiteral(it) => it.shift_span(from, offset),
+            Self::TemplateLiteral(it) => it.shift_span(from, offset),
+            Self::UnaryExpression(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TSType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::TSAnyKeyword(it) => it.shift_span(from, offset),
+            Self::TSBigIntKeyword(it) => it.shift_span(from, offset),
+            Self::TSBooleanKeyword(it) => it.shift_span(from, offset),
+            Self::TSNeverKeyword(it) => it.shift_span(from, offset),
+            Self::TSNullKeyword(it) => it.shift_span(from, offset),
+            Self::TSNumberKeyword(it) => it.shift_span(from, offset),
+            Self::TSObjectKeyword(it) => it.shift_span(from, offset),
+            Self::TSStringKeyword(it) => it.shift_span(from, offset),
+            Self::TSSymbolKeyword(it) => it.shift_span(from, offset),
+            Self::TSThisKeyword(it) => it.shift_span(from, offset),
+            Self::TSUndefinedKeyword(it) => it.shift_span(from, offset),
+            Self::TSUnknownKeyword(it) => it.shift_span(from, offset),
+            Self::TSVoidKeyword(it) => it.shift_span(from, offset),
+            Self::TSArrayType(it) => it.shift_span(from, offset),
+            Self::TSConditionalType(it) => it.shift_span(from, offset),
+            Self::TSConstructorType(it) => it.shift_span(from, offset),
+            Self::TSFunctionType(it) => it.shift_span(from, offset),
+            Self::TSImportType(it) => it.shift_span(from, offset),
+            Self::TSIndexedAccessType(it) => it.shift_span(from, offset),
+            Self::TSInferType(it) => it.shift_span(from, offset),
+            Self::TSIntersectionType(it) => it.shift_span(from, offset),
+            Self::TSLiteralType(it) => it.shift_span(from, offset),
+            Self::TSMappedType(it) => it.shift_span(from, offset),
+            Self::TSQualifiedName(it) => it.shift_span(from, offset),
+            Self::TSTemplateLiteralType(it) => it.shift_span(from, offset),
+            Self::TSTupleType(it) => it.shift_span(from, offset),
+            Self::TSTypeLiteral(it) => it.shift_span(from, offset),
+            Self::TSTypeOperatorType(it) => it.shift_span(from, offset),
+            Self::TSTypePredicate(it) => it.shift_span(from, offset),
+            Self::TSTypeQuery(it) => it.shift_span(from, offset),
+            Self::TSTypeReference(it) => it.shift_span(from, offset),
+            Self::TSUnionType(it) => it.shift_span(from, offset),
+            Self::JSDocNullableType(it) => it.shift_span(from, offset),
+            Self::JSDocUnknownType(it) => it.shift_span(from, offset),
+            Self::FlowExactObjectType(it) => it.shift_span(from, offset),
+            Self::FlowExistentialType(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TSConditionalType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.check_type.shift_span(from, offset);
+        self.extends_type.shift_span(from, offset);
+        self.true_type.shift_span(from, offset);
+        self.false_type.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSUnionType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.types.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSIntersectionType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.types.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeOperatorType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSArrayType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.element_type.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSIndexedAccessType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.object_type.shift_span(from, offset);
+        self.index_type.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTupleType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.element_types.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSNamedTupleMember<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.element_type.shift_span(from, offset);
+        self.label.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSOptionalType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSRestType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTupleElement<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::TSType(it) => it.shift_span(from, offset),
+            Self::TSOptionalType(it) => it.shift_span(from, offset),
+            Self::TSRestType(it) => it.shift_span(from, offset),
+            Self::TSNamedTupleMember(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for TSAnyKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSStringKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSBooleanKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSNumberKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSNeverKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSUnknownKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSNullKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSUndefinedKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSVoidKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSSymbolKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSThisKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSObjectKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSBigIntKeyword {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeReference<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_name.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeName<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::IdentifierName(it) => it.shift_span(from, offset),
+            Self::QualifiedName(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TSQualifiedName<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.left.shift_span(from, offset);
+        self.right.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeParameterInstantiation<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeParameter<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.name.shift_span(from, offset);
+        self.constraint.shift_span(from, offset);
+        self.default.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeParameterDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeAliasDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+        self.modifiers.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSAbstractMethodDefinition<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.method_definition.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSAbstractPropertyDefinition<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.property_definition.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSClassImplements<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSInterfaceDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+        self.extends.shift_span(from, offset);
+        self.modifiers.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSInterfaceBody<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSPropertySignature<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.key.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSSignature<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::TSIndexSignature(it) => it.shift_span(from, offset),
+            Self::TSPropertySignature(it) => it.shift_span(from, offset),
+            Self::TSCallSignatureDeclaration(it) => it.shift_span(from, offset),
+            Self::TSConstructSignatureDeclaration(it) => it.shift_span(from, offset),
+            Self::TSMethodSignature(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TSIndexSignature<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.parameters.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSCallSignatureDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+        self.return_type.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSMethodSignature<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.key.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+        self.return_type.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSConstructSignatureDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+        self.return_type.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSIndexSignatureName<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSInterfaceHeritage<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypePredicate<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.parameter_name.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSTypePredicateName {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::This(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TSModuleDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+        self.modifiers.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSModuleDeclarationName {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::Identifier(it) => it.shift_span(from, offset),
+            Self::StringLiteral(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TSModuleDeclarationBody<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::TSModuleDeclaration(it) => it.shift_span(from, offset),
+            Self::TSModuleBlock(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl<'a> ShiftSpan for TSModuleBlock<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.body.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeLiteral<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.members.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSInferType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_parameter.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeQuery<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expr_name.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSImportType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.parameter.shift_span(from, offset);
+        self.qualifier.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSFunctionType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+        self.return_type.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSConstructorType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.params.shift_span(from, offset);
+        self.return_type.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSMappedType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_parameter.shift_span(from, offset);
+        self.name_type.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTemplateLiteralType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.quasis.shift_span(from, offset);
+        self.types.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSAsExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSSatisfiesExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSTypeAssertion<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSImportEqualsDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.module_reference.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSModuleReference<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        match self {
+            Self::TypeName(it) => it.shift_span(from, offset),
+            Self::ExternalModuleReference(it) => it.shift_span(from, offset),
+        }
+    }
+}
+
+impl ShiftSpan for TSExternalModuleReference {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSNonNullExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for Decorator<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSExportAssignment<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for TSNamespaceExportDeclaration {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for TSInstantiationExpression<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.expression.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for FlowOpaqueTypeDeclaration<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.id.shift_span(from, offset);
+        self.type_parameters.shift_span(from, offset);
+        self.supertype.shift_span(from, offset);
+        self.impltype.shift_span(from, offset);
+        self.modifiers.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for FlowDeclareModuleExports<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for FlowExactObjectType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.members.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for FlowExistentialType {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+impl<'a> ShiftSpan for JSDocNullableType<'a> {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+        self.type_annotation.shift_span(from, offset);
+    }
+}
+
+impl ShiftSpan for JSDocUnknownType {
+    fn shift_span(&mut self, from: u32, offset: i64) {
+        self.span.shift_span(from, offset);
+    }
+}
+
+/// Nodes of [`RegExpLiteral::pattern`]
+mod regexp {
+    #[allow(clippy::wildcard_imports)]
+    use crate::{ast::regexp::*, ShiftSpan};
+
+    impl<'a> ShiftSpan for Pattern<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for Disjunction<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for Alternative<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for Term<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            match self {
+                Self::BoundaryAssertion(it) => it.shift_span(from, offset),
+                Self::LookAroundAssertion(it) => it.shift_span(from, offset),
+                Self::Quantifier(it) => it.shift_span(from, offset),
+                Self::Character(it) => it.shift_span(from, offset),
+                Self::Dot(it) => it.shift_span(from, offset),
+                Self::CharacterClassEscape(it) => it.shift_span(from, offset),
+                Self::UnicodePropertyEscape(it) => it.shift_span(from, offset),
+                Self::CharacterClass(it) => it.shift_span(from, offset),
+                Self::CapturingGroup(it) => it.shift_span(from, offset),
+                Self::IgnoreGroup(it) => it.shift_span(from, offset),
+                Self::IndexedReference(it) => it.shift_span(from, offset),
+                Self::NamedReference(it) => it.shift_span(from, offset),
+            }
+        }
+    }
+
+    impl ShiftSpan for BoundaryAssertion {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for LookAroundAssertion<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for Quantifier<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl ShiftSpan for Character {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+        }
+    }
+
+    impl ShiftSpan for Dot {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+        }
+    }
+
+    impl ShiftSpan for CharacterClassEscape {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+        }
+    }
+
+    impl ShiftSpan for UnicodePropertyEscape {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for CharacterClass<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for CharacterClassContents<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            match self {
+                Self::CharacterClassRange(it) => it.shift_span(from, offset),
+                Self::CharacterClassEscape(it) => it.shift_span(from, offset),
+                Self::UnicodePropertyEscape(it) => it.shift_span(from, offset),
+                Self::Character(it) => it.shift_span(from, offset),
+                Self::NestedCharacterClass(it) => it.shift_span(from, offset),
+                Self::ClassStringDisjunction(it) => it.shift_span(from, offset),
+            }
+        }
+    }
+
+    impl ShiftSpan for CharacterClassRange {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.min.shift_span(from, offset);
+            self.max.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for ClassStringDisjunction<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for ClassString<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for CapturingGroup<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl<'a> ShiftSpan for IgnoreGroup<'a> {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+            self.body.shift_span(from, offset);
+        }
+    }
+
+    impl ShiftSpan for IndexedReference {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+        }
+    }
+
+    impl ShiftSpan for NamedReference {
+        fn shift_span(&mut self, from: u32, offset: i64) {
+            self.span.shift_span(from, offset);
+        }
+    }
+}
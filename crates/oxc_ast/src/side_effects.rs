@@ -0,0 +1,725 @@
+//! Whether evaluating expressions and statements can have observable side effects or throw
+//!
+//! Shared by dead code elimination, tree shaking and lint rules which need to know
+//! whether code can be removed or reordered.
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, ConstantEvaluation, Span};
+
+/// Side effect analysis.
+///
+/// Code can be removed when it has no side effects and can not throw.
+/// Throwing is answered separately, e.g. reading an undeclared global has no side effects
+/// but throws a `ReferenceError`.
+///
+/// The analysis is conservative: `true` means the code *may* have side effects or throw.
+/// It assumes builtins are not modified and objects of unknown type may have getters and
+/// `valueOf` / `toString` methods with side effects.
+///
+/// Expressions with a constant value, see [`ConstantEvaluation`], have no side effects and do not throw.
+pub trait SideEffects<'a>: ConstantEvaluation<'a> {
+    /// Whether the call or `new` expression at `span` is annotated with `/*#__PURE__*/`,
+    /// which makes the call itself free of side effects.
+    ///
+    /// The default implementation has no comments and returns `false`.
+    fn has_pure_annotation(&self, _span: Span) -> bool {
+        false
+    }
+
+    /// Whether `ident` refers to a global variable rather than a binding in the program.
+    ///
+    /// The default implementation has no scope information and returns `true`.
+    fn is_global_reference(&self, _ident: &IdentifierReference) -> bool {
+        true
+    }
+
+    /// Whether the global variable `name` always exists, so reading it does not throw.
+    ///
+    /// The default implementation only knows `undefined`, `NaN` and `Infinity`.
+    fn is_known_global(&self, name: &str) -> bool {
+        matches!(name, "undefined" | "NaN" | "Infinity")
+    }
+
+    /// Whether evaluating `expr` may have observable side effects
+    #[allow(clippy::too_many_lines)]
+    fn expression_has_side_effects(&self, expr: &Expression<'a>) -> bool {
+        if self.eval_expression(expr).is_some() {
+            return false;
+        }
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumberLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::Identifier(_)
+            | Expression::MetaProperty(_)
+            | Expression::ThisExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_) => false,
+            // `ToString` of the expressions
+            Expression::TemplateLiteral(lit) => {
+                lit.expressions.iter().any(|expr| self.has_side_effects_to_primitive(expr))
+            }
+            Expression::ArrayExpression(array) => array.elements.iter().flatten().any(|element| {
+                match element {
+                    // Spreading runs the iterator
+                    Argument::SpreadElement(_) => true,
+                    Argument::Expression(expr) => self.expression_has_side_effects(expr),
+                }
+            }),
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().any(|property| match property {
+                    // Spreading runs the getters
+                    ObjectProperty::SpreadProperty(_) => true,
+                    ObjectProperty::Property(property) => {
+                        self.property_key_has_side_effects(&property.key)
+                            || matches!(&property.value, PropertyValue::Expression(expr) if self.expression_has_side_effects(expr))
+                    }
+                })
+            }
+            Expression::ClassExpression(class) => self.class_has_side_effects(class),
+            Expression::UnaryExpression(unary) => match unary.operator {
+                UnaryOperator::Delete => true,
+                UnaryOperator::Typeof | UnaryOperator::Void | UnaryOperator::LogicalNot => {
+                    self.expression_has_side_effects(&unary.argument)
+                }
+                // `ToNumeric` of the argument
+                UnaryOperator::UnaryNegation
+                | UnaryOperator::UnaryPlus
+                | UnaryOperator::BitwiseNot => self.has_side_effects_to_primitive(&unary.argument),
+            },
+            Expression::BinaryExpression(_) | Expression::LogicalExpression(_) => {
+                chain_has_side_effects(self, expr)
+            }
+            Expression::PrivateInExpression(expr) => self.expression_has_side_effects(&expr.right),
+            Expression::ConditionalExpression(cond) => {
+                self.expression_has_side_effects(&cond.test)
+                    || self.expression_has_side_effects(&cond.consequent)
+                    || self.expression_has_side_effects(&cond.alternate)
+            }
+            Expression::SequenceExpression(seq) => {
+                seq.expressions.iter().any(|expr| self.expression_has_side_effects(expr))
+            }
+            Expression::ParenthesizedExpression(expr) => {
+                self.expression_has_side_effects(&expr.expression)
+            }
+            Expression::CallExpression(call) => {
+                !self.has_pure_annotation(call.span)
+                    || self.arguments_have_side_effects(&call.arguments)
+            }
+            Expression::NewExpression(new) => {
+                !self.has_pure_annotation(new.span)
+                    || self.arguments_have_side_effects(&new.arguments)
+            }
+            Expression::TSAsExpression(expr) => self.expression_has_side_effects(&expr.expression),
+            Expression::TSSatisfiesExpression(expr) => {
+                self.expression_has_side_effects(&expr.expression)
+            }
+            Expression::TSTypeAssertion(expr) => self.expression_has_side_effects(&expr.expression),
+            Expression::TSNonNullExpression(expr) => {
+                self.expression_has_side_effects(&expr.expression)
+            }
+            Expression::TSInstantiationExpression(expr) => {
+                self.expression_has_side_effects(&expr.expression)
+            }
+            // Member expressions may run getters
+            Expression::MemberExpression(_)
+            | Expression::ChainExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::UpdateExpression(_)
+            | Expression::AwaitExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::ImportExpression(_)
+            | Expression::TaggedTemplateExpression(_)
+            | Expression::Super(_)
+            | Expression::JSXElement(_)
+            | Expression::JSXFragment(_)
+            | Expression::InvalidExpression(_) => true,
+        }
+    }
+
+    /// Whether evaluating `expr` may throw an exception
+    #[allow(clippy::too_many_lines)]
+    fn expression_may_throw(&self, expr: &Expression<'a>) -> bool {
+        if self.eval_expression(expr).is_some() {
+            return false;
+        }
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumberLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::MetaProperty(_)
+            | Expression::ThisExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_) => false,
+            // `ReferenceError` for an undeclared global
+            Expression::Identifier(ident) => {
+                self.is_global_reference(ident) && !self.is_known_global(&ident.name)
+            }
+            // `ToString` of a symbol throws
+            Expression::TemplateLiteral(lit) => {
+                lit.expressions.iter().any(|expr| self.may_throw_to_primitive(expr))
+            }
+            Expression::ArrayExpression(array) => {
+                array.elements.iter().flatten().any(|element| match element {
+                    Argument::SpreadElement(_) => true,
+                    Argument::Expression(expr) => self.expression_may_throw(expr),
+                })
+            }
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().any(|property| match property {
+                    ObjectProperty::SpreadProperty(_) => true,
+                    ObjectProperty::Property(property) => {
+                        self.property_key_may_throw(&property.key)
+                            || matches!(&property.value, PropertyValue::Expression(expr) if self.expression_may_throw(expr))
+                    }
+                })
+            }
+            // `extends` throws for values which are not constructors
+            Expression::ClassExpression(class) => {
+                class.super_class.is_some() || self.class_has_side_effects(class)
+            }
+            Expression::UnaryExpression(unary) => match unary.operator {
+                UnaryOperator::Delete => true,
+                // `typeof` does not throw for undeclared globals
+                UnaryOperator::Typeof => match &unary.argument {
+                    Expression::Identifier(_) => false,
+                    argument => self.expression_may_throw(argument),
+                },
+                UnaryOperator::Void | UnaryOperator::LogicalNot => {
+                    self.expression_may_throw(&unary.argument)
+                }
+                // `+1n` throws
+                UnaryOperator::UnaryPlus => {
+                    self.may_throw_to_primitive(&unary.argument) || may_be_bigint(&unary.argument)
+                }
+                UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
+                    self.may_throw_to_primitive(&unary.argument)
+                }
+            },
+            Expression::BinaryExpression(_) | Expression::LogicalExpression(_) => {
+                chain_may_throw(self, expr)
+            }
+            Expression::ConditionalExpression(cond) => {
+                self.expression_may_throw(&cond.test)
+                    || self.expression_may_throw(&cond.consequent)
+                    || self.expression_may_throw(&cond.alternate)
+            }
+            Expression::SequenceExpression(seq) => {
+                seq.expressions.iter().any(|expr| self.expression_may_throw(expr))
+            }
+            Expression::ParenthesizedExpression(expr) => self.expression_may_throw(&expr.expression),
+            // A call annotated as pure is assumed to not throw
+            Expression::CallExpression(call) => {
+                !self.has_pure_annotation(call.span) || self.arguments_may_throw(&call.arguments)
+            }
+            Expression::NewExpression(new) => {
+                !self.has_pure_annotation(new.span) || self.arguments_may_throw(&new.arguments)
+            }
+            Expression::TSAsExpression(expr) => self.expression_may_throw(&expr.expression),
+            Expression::TSSatisfiesExpression(expr) => self.expression_may_throw(&expr.expression),
+            Expression::TSTypeAssertion(expr) => self.expression_may_throw(&expr.expression),
+            Expression::TSNonNullExpression(expr) => self.expression_may_throw(&expr.expression),
+            Expression::TSInstantiationExpression(expr) => {
+                self.expression_may_throw(&expr.expression)
+            }
+            // Member expressions throw for `null` and `undefined`, and may run getters
+            Expression::MemberExpression(_)
+            | Expression::ChainExpression(_)
+            | Expression::PrivateInExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::UpdateExpression(_)
+            | Expression::AwaitExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::ImportExpression(_)
+            | Expression::TaggedTemplateExpression(_)
+            | Expression::Super(_)
+            | Expression::JSXElement(_)
+            | Expression::JSXFragment(_)
+            | Expression::InvalidExpression(_) => true,
+        }
+    }
+
+    /// Whether executing `stmt` may have observable side effects,
+    /// including changing the control flow with `return`, `break`, `continue` and `throw`
+    fn statement_has_side_effects(&self, stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::EmptyStatement(_) => false,
+            Statement::ExpressionStatement(stmt) => {
+                self.expression_has_side_effects(&stmt.expression)
+            }
+            Statement::BlockStatement(block) => {
+                block.body.iter().any(|stmt| self.statement_has_side_effects(stmt))
+            }
+            Statement::IfStatement(stmt) => {
+                self.expression_has_side_effects(&stmt.test)
+                    || self.statement_has_side_effects(&stmt.consequent)
+                    || stmt
+                        .alternate
+                        .as_ref()
+                        .map_or(false, |alternate| self.statement_has_side_effects(alternate))
+            }
+            Statement::LabeledStatement(stmt) => self.statement_has_side_effects(&stmt.body),
+            Statement::TryStatement(stmt) => {
+                stmt.block.body.iter().any(|stmt| self.statement_has_side_effects(stmt))
+                    || stmt.handler.as_ref().map_or(false, |handler| {
+                        handler.body.body.iter().any(|stmt| self.statement_has_side_effects(stmt))
+                    })
+                    || stmt.finalizer.as_ref().map_or(false, |finalizer| {
+                        finalizer.body.iter().any(|stmt| self.statement_has_side_effects(stmt))
+                    })
+            }
+            Statement::Declaration(decl) => match decl {
+                Declaration::VariableDeclaration(decl) => decl.declarations.iter().any(|decl| {
+                    self.binding_has_side_effects(&decl.id)
+                        || decl
+                            .init
+                            .as_ref()
+                            .map_or(false, |init| self.expression_has_side_effects(init))
+                }),
+                Declaration::FunctionDeclaration(_)
+                | Declaration::TSTypeAliasDeclaration(_)
+                | Declaration::TSInterfaceDeclaration(_) => false,
+                Declaration::ClassDeclaration(class) => self.class_has_side_effects(class),
+                _ => true,
+            },
+            // Loops may not terminate
+            _ => true,
+        }
+    }
+
+    /// Whether executing `stmt` may throw an exception
+    fn statement_may_throw(&self, stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::EmptyStatement(_)
+            | Statement::DebuggerStatement(_)
+            | Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_) => false,
+            Statement::ReturnStatement(stmt) => {
+                stmt.argument.as_ref().map_or(false, |argument| self.expression_may_throw(argument))
+            }
+            Statement::ExpressionStatement(stmt) => self.expression_may_throw(&stmt.expression),
+            Statement::BlockStatement(block) => {
+                block.body.iter().any(|stmt| self.statement_may_throw(stmt))
+            }
+            Statement::IfStatement(stmt) => {
+                self.expression_may_throw(&stmt.test)
+                    || self.statement_may_throw(&stmt.consequent)
+                    || stmt
+                        .alternate
+                        .as_ref()
+                        .map_or(false, |alternate| self.statement_may_throw(alternate))
+            }
+            Statement::LabeledStatement(stmt) => self.statement_may_throw(&stmt.body),
+            Statement::WhileStatement(stmt) => {
+                self.expression_may_throw(&stmt.test) || self.statement_may_throw(&stmt.body)
+            }
+            Statement::DoWhileStatement(stmt) => {
+                self.expression_may_throw(&stmt.test) || self.statement_may_throw(&stmt.body)
+            }
+            Statement::ForStatement(stmt) => {
+                let init_may_throw = match &stmt.init {
+                    Some(ForStatementInit::Expression(expr)) => self.expression_may_throw(expr),
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.variable_declaration_may_throw(decl)
+                    }
+                    None => false,
+                };
+                init_may_throw
+                    || stmt.test.as_ref().map_or(false, |test| self.expression_may_throw(test))
+                    || stmt
+                        .update
+                        .as_ref()
+                        .map_or(false, |update| self.expression_may_throw(update))
+                    || self.statement_may_throw(&stmt.body)
+            }
+            Statement::TryStatement(stmt) => {
+                let finalizer_may_throw = stmt.finalizer.as_ref().map_or(false, |finalizer| {
+                    finalizer.body.iter().any(|stmt| self.statement_may_throw(stmt))
+                });
+                // Exceptions of the block are caught by the handler
+                let block_may_throw = stmt.handler.as_ref().map_or_else(
+                    || stmt.block.body.iter().any(|stmt| self.statement_may_throw(stmt)),
+                    |handler| {
+                        handler.param.as_ref().map_or(false, |param| {
+                            !matches!(param.kind, BindingPatternKind::BindingIdentifier(_))
+                        }) || handler.body.body.iter().any(|stmt| self.statement_may_throw(stmt))
+                    },
+                );
+                block_may_throw || finalizer_may_throw
+            }
+            Statement::SwitchStatement(stmt) => {
+                self.expression_may_throw(&stmt.discriminant)
+                    || stmt.cases.iter().any(|case| {
+                        case.test.as_ref().map_or(false, |test| self.expression_may_throw(test))
+                            || case.consequent.iter().any(|stmt| self.statement_may_throw(stmt))
+                    })
+            }
+            Statement::Declaration(decl) => match decl {
+                Declaration::VariableDeclaration(decl) => self.variable_declaration_may_throw(decl),
+                Declaration::FunctionDeclaration(_)
+                | Declaration::TSTypeAliasDeclaration(_)
+                | Declaration::TSInterfaceDeclaration(_) => false,
+                Declaration::ClassDeclaration(class) => {
+                    class.super_class.is_some() || self.class_has_side_effects(class)
+                }
+                _ => true,
+            },
+            // `throw`, and loops with `for in` and `for of` which throw for `null` and `undefined`
+            _ => true,
+        }
+    }
+
+    /// Whether the arguments of a call have side effects, spreading runs the iterator
+    fn arguments_have_side_effects(&self, arguments: &[Argument<'a>]) -> bool {
+        arguments.iter().any(|argument| match argument {
+            Argument::SpreadElement(_) => true,
+            Argument::Expression(expr) => self.expression_has_side_effects(expr),
+        })
+    }
+
+    fn arguments_may_throw(&self, arguments: &[Argument<'a>]) -> bool {
+        arguments.iter().any(|argument| match argument {
+            Argument::SpreadElement(_) => true,
+            Argument::Expression(expr) => self.expression_may_throw(expr),
+        })
+    }
+
+    /// Whether converting the value of `expr` to a primitive, e.g. with `ToNumber`, may have side effects.
+    /// Objects may have a `valueOf` or `toString` method with side effects.
+    fn has_side_effects_to_primitive(&self, expr: &Expression<'a>) -> bool {
+        !is_primitive(expr) || self.expression_has_side_effects(expr)
+    }
+
+    /// Whether converting the value of `expr` to a primitive may throw,
+    /// converting a symbol to a number or string throws.
+    fn may_throw_to_primitive(&self, expr: &Expression<'a>) -> bool {
+        !is_primitive(expr) || self.expression_may_throw(expr)
+    }
+
+    /// Computed keys are converted with `ToPropertyKey`
+    fn property_key_has_side_effects(&self, key: &PropertyKey<'a>) -> bool {
+        match key {
+            PropertyKey::Expression(expr) => self.has_side_effects_to_primitive(expr),
+            PropertyKey::Identifier(_) | PropertyKey::PrivateIdentifier(_) => false,
+        }
+    }
+
+    fn property_key_may_throw(&self, key: &PropertyKey<'a>) -> bool {
+        match key {
+            PropertyKey::Expression(expr) => self.may_throw_to_primitive(expr),
+            PropertyKey::Identifier(_) | PropertyKey::PrivateIdentifier(_) => false,
+        }
+    }
+
+    /// Destructuring runs getters and iterators
+    fn binding_has_side_effects(&self, pattern: &BindingPattern<'a>) -> bool {
+        !matches!(pattern.kind, BindingPatternKind::BindingIdentifier(_))
+    }
+
+    fn variable_declaration_may_throw(&self, decl: &VariableDeclaration<'a>) -> bool {
+        decl.declarations.iter().any(|decl| {
+            self.binding_has_side_effects(&decl.id)
+                || decl.init.as_ref().map_or(false, |init| self.expression_may_throw(init))
+        })
+    }
+
+    /// Whether defining `class` has side effects:
+    /// evaluating the super class, decorators, computed keys, static properties and static blocks
+    fn class_has_side_effects(&self, class: &Class<'a>) -> bool {
+        if !class.decorators.is_empty()
+            || class
+                .super_class
+                .as_ref()
+                .map_or(false, |super_class| self.expression_has_side_effects(super_class))
+        {
+            return true;
+        }
+        class.body.body.iter().any(|element| match element {
+            ClassElement::StaticBlock(block) => {
+                block.body.iter().any(|stmt| self.statement_has_side_effects(stmt))
+                    || block.body.iter().any(|stmt| self.statement_may_throw(stmt))
+            }
+            ClassElement::MethodDefinition(method) => {
+                !method.decorators.is_empty()
+                    || method.computed && self.property_key_has_side_effects(&method.key)
+            }
+            ClassElement::PropertyDefinition(property) => {
+                !property.decorators.is_empty()
+                    || property.computed && self.property_key_has_side_effects(&property.key)
+                    || property.r#static
+                        && property.value.as_ref().map_or(false, |value| {
+                            self.expression_has_side_effects(value)
+                                || self.expression_may_throw(value)
+                        })
+            }
+            ClassElement::AccessorProperty(_) => true,
+            ClassElement::TSAbstractMethodDefinition(_)
+            | ClassElement::TSAbstractPropertyDefinition(_)
+            | ClassElement::TSIndexSignature(_) => false,
+        })
+    }
+}
+
+/// An operand in a chain of binary and logical expressions `a + b || c`,
+/// with the facts the analysis needs about it
+struct Operand<'e, 'a> {
+    expr: &'e Expression<'a>,
+    /// Whether it has a constant value
+    constant: bool,
+    primitive: bool,
+    bigint: bool,
+}
+
+/// The operands of a chain of binary and logical expressions `a + b || c`, which is nested as
+/// `(a + b) || c`, from the outermost expression to the leftmost operand `a`.
+///
+/// The facts are computed from the leftmost operand in a loop and the chain is then checked in a loop,
+/// long chains such as generated string concatenations would overflow the stack in a recursion.
+fn binary_chain<'e, 'a, S: ConstantEvaluation<'a> + ?Sized>(
+    evaluation: &S,
+    expr: &'e Expression<'a>,
+) -> Vec<Operand<'e, 'a>> {
+    let mut exprs = vec![];
+    let mut leaf = expr;
+    loop {
+        match leaf {
+            Expression::BinaryExpression(binary) => {
+                exprs.push(leaf);
+                leaf = &binary.left;
+            }
+            Expression::LogicalExpression(logical) => {
+                exprs.push(leaf);
+                leaf = &logical.left;
+            }
+            _ => break,
+        }
+    }
+    let mut value = evaluation.eval_expression(leaf);
+    let mut operand = Operand {
+        expr: leaf,
+        constant: value.is_some(),
+        primitive: is_primitive(leaf),
+        bigint: may_be_bigint(leaf),
+    };
+    let mut chain = Vec::with_capacity(exprs.len() + 1);
+    for expr in exprs.into_iter().rev() {
+        value = value.and_then(|left| evaluation.eval_with_left(expr, left));
+        let (primitive, bigint) = match expr {
+            Expression::BinaryExpression(binary) => (
+                true,
+                is_arithmetic(binary.operator) && (operand.bigint || may_be_bigint(&binary.right)),
+            ),
+            Expression::LogicalExpression(logical) => (
+                operand.primitive && is_primitive(&logical.right),
+                operand.bigint || may_be_bigint(&logical.right),
+            ),
+            _ => unreachable!(),
+        };
+        chain.push(operand);
+        operand = Operand { expr, constant: value.is_some(), primitive, bigint };
+    }
+    chain.push(operand);
+    chain.reverse();
+    chain
+}
+
+/// Whether evaluating the chain of binary and logical expressions `expr` may have side effects,
+/// the side effects of each left operand are checked in the next iteration
+fn chain_has_side_effects<'a, S: SideEffects<'a> + ?Sized>(
+    analysis: &S,
+    expr: &Expression<'a>,
+) -> bool {
+    let chain = binary_chain(analysis, expr);
+    for operands in chain.windows(2) {
+        let [expr, left] = operands else { unreachable!() };
+        if expr.constant {
+            return false;
+        }
+        let has_side_effects = match expr.expr {
+            Expression::BinaryExpression(binary) => match binary.operator {
+                BinaryOperator::StrictEquality | BinaryOperator::StrictInequality => {
+                    analysis.expression_has_side_effects(&binary.right)
+                }
+                // `Symbol.hasInstance` and proxies
+                BinaryOperator::In | BinaryOperator::Instanceof => true,
+                // `ToPrimitive` of the operands
+                _ => !left.primitive || analysis.has_side_effects_to_primitive(&binary.right),
+            },
+            Expression::LogicalExpression(logical) => {
+                analysis.expression_has_side_effects(&logical.right)
+            }
+            _ => unreachable!(),
+        };
+        if has_side_effects {
+            return true;
+        }
+    }
+    chain.last().map_or(false, |leaf| analysis.expression_has_side_effects(leaf.expr))
+}
+
+/// Whether evaluating the chain of binary and logical expressions `expr` may throw,
+/// each left operand is checked in the next iteration
+fn chain_may_throw<'a, S: SideEffects<'a> + ?Sized>(analysis: &S, expr: &Expression<'a>) -> bool {
+    let chain = binary_chain(analysis, expr);
+    for operands in chain.windows(2) {
+        let [expr, left] = operands else { unreachable!() };
+        if expr.constant {
+            return false;
+        }
+        let may_throw = match expr.expr {
+            Expression::BinaryExpression(binary) => {
+                let right = &binary.right;
+                match binary.operator {
+                    BinaryOperator::StrictEquality | BinaryOperator::StrictInequality => {
+                        analysis.expression_may_throw(right)
+                    }
+                    BinaryOperator::Equality
+                    | BinaryOperator::Inequality
+                    | BinaryOperator::LessThan
+                    | BinaryOperator::LessEqualThan
+                    | BinaryOperator::GreaterThan
+                    | BinaryOperator::GreaterEqualThan => {
+                        !left.primitive || analysis.may_throw_to_primitive(right)
+                    }
+                    // String concatenation does not mix BigInts and Numbers
+                    BinaryOperator::Addition if is_string(left.expr) || is_string(right) => {
+                        !left.primitive || analysis.may_throw_to_primitive(right)
+                    }
+                    BinaryOperator::In | BinaryOperator::Instanceof => true,
+                    // Mixing BigInts and Numbers throws, and `>>>` throws for BigInts
+                    _ => {
+                        !left.primitive
+                            || analysis.may_throw_to_primitive(right)
+                            || left.bigint
+                            || may_be_bigint(right)
+                    }
+                }
+            }
+            Expression::LogicalExpression(logical) => analysis.expression_may_throw(&logical.right),
+            _ => unreachable!(),
+        };
+        if may_throw {
+            return true;
+        }
+    }
+    chain.last().map_or(false, |leaf| analysis.expression_may_throw(leaf.expr))
+}
+
+/// Whether the value of `expr` is a primitive which is not a symbol,
+/// so converting it to a number or a string does not run code
+fn is_primitive(expr: &Expression) -> bool {
+    let mut expr = expr;
+    // The left operands of `a || b || c` in a loop, see `binary_chain`
+    while let Expression::LogicalExpression(logical) = expr {
+        if !is_primitive(&logical.right) {
+            return false;
+        }
+        expr = &logical.left;
+    }
+    match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::UpdateExpression(_)
+        | Expression::UnaryExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::PrivateInExpression(_) => true,
+        Expression::Identifier(ident) => {
+            matches!(ident.name.as_str(), "undefined" | "NaN" | "Infinity")
+        }
+        Expression::ConditionalExpression(cond) => {
+            is_primitive(&cond.consequent) && is_primitive(&cond.alternate)
+        }
+        Expression::SequenceExpression(seq) => seq.expressions.last().map_or(false, is_primitive),
+        Expression::ParenthesizedExpression(expr) => is_primitive(&expr.expression),
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a string
+fn is_string(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::UnaryExpression(unary) => unary.operator == UnaryOperator::Typeof,
+        Expression::ParenthesizedExpression(expr) => is_string(&expr.expression),
+        _ => false,
+    }
+}
+
+/// Whether the value of `expr` may be a `BigInt`
+fn may_be_bigint(expr: &Expression) -> bool {
+    let mut expr = expr;
+    // The left operands of `a + b + c` in a loop, see `binary_chain`
+    loop {
+        let right = match expr {
+            Expression::BinaryExpression(binary) if is_arithmetic(binary.operator) => {
+                expr = &binary.left;
+                &binary.right
+            }
+            Expression::LogicalExpression(logical) => {
+                expr = &logical.left;
+                &logical.right
+            }
+            _ => break,
+        };
+        if may_be_bigint(right) {
+            return true;
+        }
+    }
+    match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ArrayExpression(_)
+        | Expression::ObjectExpression(_)
+        // Comparisons, the arithmetic operators are handled in the loop
+        | Expression::BinaryExpression(_) => false,
+        Expression::Identifier(ident) => {
+            !matches!(ident.name.as_str(), "undefined" | "NaN" | "Infinity")
+        }
+        Expression::UnaryExpression(unary) => match unary.operator {
+            UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
+                may_be_bigint(&unary.argument)
+            }
+            _ => false,
+        },
+        Expression::ConditionalExpression(cond) => {
+            may_be_bigint(&cond.consequent) || may_be_bigint(&cond.alternate)
+        }
+        Expression::SequenceExpression(seq) => seq.expressions.last().map_or(true, may_be_bigint),
+        Expression::ParenthesizedExpression(expr) => may_be_bigint(&expr.expression),
+        _ => true,
+    }
+}
+
+/// Whether the result of `operator` may be a `BigInt`
+const fn is_arithmetic(operator: BinaryOperator) -> bool {
+    matches!(
+        operator,
+        BinaryOperator::Addition
+            | BinaryOperator::Subtraction
+            | BinaryOperator::Multiplication
+            | BinaryOperator::Division
+            | BinaryOperator::Remainder
+            | BinaryOperator::Exponential
+            | BinaryOperator::BitwiseAnd
+            | BinaryOperator::BitwiseOR
+            | BinaryOperator::BitwiseXOR
+            | BinaryOperator::ShiftLeft
+            | BinaryOperator::ShiftRight
+    )
+}
@@ -8,7 +8,7 @@ pub struct SourceType {
     /// JavaScript or TypeScript, default JavaScript
     language: Language,
 
-    /// Script or Module, default Module
+    /// Script, Module or Unambiguous, default Script
     module_kind: ModuleKind,
 
     /// Support JSX for JavaScript and TypeScript? default without JSX
@@ -17,6 +17,9 @@ pub struct SourceType {
     /// Mark strict mode as always strict
     /// See <https://github.com/tc39/test262/blob/main/INTERPRETING.md#strict-mode>
     always_strict: bool,
+
+    /// Parse Flow type annotations in JavaScript, default false
+    flow: bool,
 }
 
 /// JavaScript or TypeScript
@@ -31,6 +34,10 @@ pub enum Language {
 pub enum ModuleKind {
     Script,
     Module,
+    /// Module if the source text contains `import`, `export`, `import.meta` or top-level await,
+    /// otherwise Script.
+    /// Resolved by the parser, the parsed `Program` is always a Script or a Module.
+    Unambiguous,
 }
 
 /// JSX for JavaScript and TypeScript
@@ -53,6 +60,7 @@ impl Default for SourceType {
             module_kind: ModuleKind::Script,
             variant: LanguageVariant::Standard,
             always_strict: false,
+            flow: false,
         }
     }
 }
@@ -70,6 +78,11 @@ impl SourceType {
         self.module_kind == ModuleKind::Module
     }
 
+    #[must_use]
+    pub fn is_unambiguous(self) -> bool {
+        self.module_kind == ModuleKind::Unambiguous
+    }
+
     #[must_use]
     pub fn module_kind(self) -> ModuleKind {
         self.module_kind
@@ -100,6 +113,11 @@ impl SourceType {
         self.always_strict
     }
 
+    #[must_use]
+    pub fn is_flow(self) -> bool {
+        self.flow && self.is_javascript()
+    }
+
     #[must_use]
     pub fn with_script(&mut self, yes: bool) -> &mut Self {
         if yes {
@@ -116,6 +134,16 @@ impl SourceType {
         self
     }
 
+    /// Decide between Script and Module from the source text,
+    /// like Babel's [`sourceType: "unambiguous"`](https://babeljs.io/docs/options#sourcetype)
+    #[must_use]
+    pub fn with_unambiguous(&mut self, yes: bool) -> &mut Self {
+        if yes {
+            self.module_kind = ModuleKind::Unambiguous;
+        }
+        self
+    }
+
     #[must_use]
     pub fn with_typescript(&mut self, yes: bool) -> &mut Self {
         if yes {
@@ -146,6 +174,14 @@ impl SourceType {
         self
     }
 
+    /// Parse [Flow](https://flow.org) type annotations, `type`, `opaque type` and `declare` statements.
+    /// Only applies to JavaScript.
+    #[must_use]
+    pub fn with_flow(&mut self, yes: bool) -> &mut Self {
+        self.flow = yes;
+        self
+    }
+
     /// Converts file path to `SourceType`
     /// returns `SourceTypeError::UnknownExtension` if:
     ///   * there is no file name
@@ -184,6 +220,12 @@ impl SourceType {
             _ => LanguageVariant::Standard,
         };
 
-        Ok(Self { language, module_kind: ModuleKind::Module, variant, always_strict: false })
+        Ok(Self {
+            language,
+            module_kind: ModuleKind::Module,
+            variant,
+            always_strict: false,
+            flow: false,
+        })
     }
 }
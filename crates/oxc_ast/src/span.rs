@@ -85,6 +85,7 @@ impl<'a> GetSpan for Statement<'a> {
             Self::WithStatement(stmt) => stmt.span,
             Self::ModuleDeclaration(decl) => decl.span,
             Self::Declaration(decl) => decl.span(),
+            Self::InvalidStatement(stmt) => stmt.span,
         }
     }
 }
@@ -132,6 +133,7 @@ impl<'a> GetSpan for Expression<'a> {
             Self::TSTypeAssertion(e) => e.span,
             Self::TSNonNullExpression(e) => e.span,
             Self::TSInstantiationExpression(e) => e.span,
+            Self::InvalidExpression(e) => e.span,
         }
     }
 }
@@ -223,6 +225,8 @@ impl<'a> GetSpan for Declaration<'a> {
             Self::TSEnumDeclaration(decl) => decl.span,
             Self::TSModuleDeclaration(decl) => decl.span,
             Self::TSImportEqualsDeclaration(decl) => decl.span,
+            Self::FlowOpaqueTypeDeclaration(decl) => decl.span,
+            Self::FlowDeclareModuleExports(decl) => decl.span,
         }
     }
 }
@@ -367,6 +371,8 @@ impl<'a> GetSpan for TSType<'a> {
             Self::TSObjectKeyword(t) => t.span,
             Self::JSDocNullableType(t) => t.span,
             Self::JSDocUnknownType(t) => t.span,
+            Self::FlowExactObjectType(t) => t.span,
+            Self::FlowExistentialType(t) => t.span,
         }
     }
 }
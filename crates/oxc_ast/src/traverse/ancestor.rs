@@ -0,0 +1,855 @@
+// Auto-generated by `cargo run -p ast_codegen`, do not edit.
+
+/// Type of an AST node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AstType {
+    Program,
+    Expression,
+    IdentifierName,
+    IdentifierReference,
+    BindingIdentifier,
+    LabelIdentifier,
+    ThisExpression,
+    ArrayExpression,
+    ObjectExpression,
+    ObjectProperty,
+    Property,
+    PropertyKey,
+    PropertyValue,
+    TemplateLiteral,
+    TaggedTemplateExpression,
+    TemplateElement,
+    MemberExpression,
+    ComputedMemberExpression,
+    StaticMemberExpression,
+    PrivateFieldExpression,
+    CallExpression,
+    NewExpression,
+    MetaProperty,
+    SpreadElement,
+    Argument,
+    UpdateExpression,
+    UnaryExpression,
+    BinaryExpression,
+    PrivateInExpression,
+    LogicalExpression,
+    ConditionalExpression,
+    AssignmentExpression,
+    AssignmentTarget,
+    SimpleAssignmentTarget,
+    AssignmentTargetPattern,
+    ArrayAssignmentTarget,
+    ObjectAssignmentTarget,
+    AssignmentTargetMaybeDefault,
+    AssignmentTargetWithDefault,
+    AssignmentTargetProperty,
+    AssignmentTargetPropertyIdentifier,
+    AssignmentTargetPropertyProperty,
+    SequenceExpression,
+    Super,
+    AwaitExpression,
+    ChainExpression,
+    ChainElement,
+    ParenthesizedExpression,
+    Statement,
+    Directive,
+    BlockStatement,
+    Declaration,
+    VariableDeclaration,
+    VariableDeclarator,
+    EmptyStatement,
+    ExpressionStatement,
+    IfStatement,
+    DoWhileStatement,
+    WhileStatement,
+    ForStatement,
+    ForStatementInit,
+    ForInStatement,
+    ForOfStatement,
+    ForStatementLeft,
+    ContinueStatement,
+    BreakStatement,
+    ReturnStatement,
+    WithStatement,
+    SwitchStatement,
+    SwitchCase,
+    LabeledStatement,
+    ThrowStatement,
+    TryStatement,
+    CatchClause,
+    DebuggerStatement,
+    InvalidStatement,
+    InvalidExpression,
+    BindingPattern,
+    BindingPatternKind,
+    AssignmentPattern,
+    ObjectPattern,
+    ObjectPatternProperty,
+    ArrayPattern,
+    RestElement,
+    Function,
+    FormalParameters,
+    FormalParameter,
+    FunctionBody,
+    ArrowExpression,
+    YieldExpression,
+    Class,
+    ClassBody,
+    ClassElement,
+    MethodDefinition,
+    PropertyDefinition,
+    PrivateIdentifier,
+    StaticBlock,
+    ModuleDeclaration,
+    ModuleDeclarationKind,
+    AccessorProperty,
+    ImportExpression,
+    ImportDeclaration,
+    ImportDeclarationSpecifier,
+    ImportSpecifier,
+    ImportDefaultSpecifier,
+    ImportNamespaceSpecifier,
+    ImportAttribute,
+    ImportAttributeKey,
+    ExportNamedDeclaration,
+    ExportDefaultDeclaration,
+    ExportAllDeclaration,
+    ExportSpecifier,
+    ExportDefaultDeclarationKind,
+    ModuleExportName,
+    BooleanLiteral,
+    NullLiteral,
+    NumberLiteral,
+    BigintLiteral,
+    RegExpLiteral,
+    StringLiteral,
+    JSXElement,
+    JSXOpeningElement,
+    JSXClosingElement,
+    JSXFragment,
+    JSXOpeningFragment,
+    JSXClosingFragment,
+    JSXElementName,
+    JSXNamespacedName,
+    JSXMemberExpression,
+    JSXMemberExpressionObject,
+    JSXExpressionContainer,
+    JSXExpression,
+    JSXEmptyExpression,
+    JSXAttributeItem,
+    JSXAttribute,
+    JSXSpreadAttribute,
+    JSXAttributeName,
+    JSXAttributeValue,
+    JSXIdentifier,
+    JSXChild,
+    JSXSpreadChild,
+    JSXText,
+    TSEnumDeclaration,
+    TSEnumMember,
+    TSEnumMemberName,
+    TSTypeAnnotation,
+    TSLiteralType,
+    TSLiteral,
+    TSType,
+    TSConditionalType,
+    TSUnionType,
+    TSIntersectionType,
+    TSTypeOperatorType,
+    TSArrayType,
+    TSIndexedAccessType,
+    TSTupleType,
+    TSNamedTupleMember,
+    TSOptionalType,
+    TSRestType,
+    TSTupleElement,
+    TSAnyKeyword,
+    TSStringKeyword,
+    TSBooleanKeyword,
+    TSNumberKeyword,
+    TSNeverKeyword,
+    TSUnknownKeyword,
+    TSNullKeyword,
+    TSUndefinedKeyword,
+    TSVoidKeyword,
+    TSSymbolKeyword,
+    TSThisKeyword,
+    TSObjectKeyword,
+    TSBigIntKeyword,
+    TSTypeReference,
+    TSTypeName,
+    TSQualifiedName,
+    TSTypeParameterInstantiation,
+    TSTypeParameter,
+    TSTypeParameterDeclaration,
+    TSTypeAliasDeclaration,
+    TSAbstractMethodDefinition,
+    TSAbstractPropertyDefinition,
+    TSClassImplements,
+    TSInterfaceDeclaration,
+    TSInterfaceBody,
+    TSPropertySignature,
+    TSSignature,
+    TSIndexSignature,
+    TSCallSignatureDeclaration,
+    TSMethodSignature,
+    TSConstructSignatureDeclaration,
+    TSIndexSignatureName,
+    TSInterfaceHeritage,
+    TSTypePredicate,
+    TSTypePredicateName,
+    TSModuleDeclaration,
+    TSModuleDeclarationName,
+    TSModuleDeclarationBody,
+    TSModuleBlock,
+    TSTypeLiteral,
+    TSInferType,
+    TSTypeQuery,
+    TSImportType,
+    TSFunctionType,
+    TSConstructorType,
+    TSMappedType,
+    TSTemplateLiteralType,
+    TSAsExpression,
+    TSSatisfiesExpression,
+    TSTypeAssertion,
+    TSImportEqualsDeclaration,
+    TSModuleReference,
+    TSExternalModuleReference,
+    TSNonNullExpression,
+    Decorator,
+    TSExportAssignment,
+    TSNamespaceExportDeclaration,
+    TSInstantiationExpression,
+    FlowOpaqueTypeDeclaration,
+    FlowDeclareModuleExports,
+    FlowExactObjectType,
+    FlowExistentialType,
+    JSDocNullableType,
+    JSDocUnknownType,
+}
+
+/// An ancestor of the current node during [`traverse_mut`](super::traverse_mut),
+/// the type of the ancestor and the field which contains the current node,
+/// e.g. [`Ancestor::CallExpressionCallee`] for `f` in `f()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ancestor {
+    /// No ancestor, the parent of `Program`
+    None,
+    ProgramDirectives,
+    ProgramBody,
+    ArrayExpressionElements,
+    ObjectExpressionProperties,
+    PropertyKey,
+    PropertyValue,
+    TemplateLiteralQuasis,
+    TemplateLiteralExpressions,
+    TaggedTemplateExpressionTag,
+    TaggedTemplateExpressionQuasi,
+    TaggedTemplateExpressionTypeParameters,
+    ComputedMemberExpressionObject,
+    ComputedMemberExpressionExpression,
+    StaticMemberExpressionObject,
+    StaticMemberExpressionProperty,
+    PrivateFieldExpressionObject,
+    PrivateFieldExpressionField,
+    CallExpressionCallee,
+    CallExpressionArguments,
+    CallExpressionTypeParameters,
+    NewExpressionCallee,
+    NewExpressionArguments,
+    NewExpressionTypeParameters,
+    MetaPropertyMeta,
+    MetaPropertyProperty,
+    SpreadElementArgument,
+    UpdateExpressionArgument,
+    UnaryExpressionArgument,
+    BinaryExpressionLeft,
+    BinaryExpressionRight,
+    PrivateInExpressionLeft,
+    PrivateInExpressionRight,
+    LogicalExpressionLeft,
+    LogicalExpressionRight,
+    ConditionalExpressionTest,
+    ConditionalExpressionConsequent,
+    ConditionalExpressionAlternate,
+    AssignmentExpressionLeft,
+    AssignmentExpressionRight,
+    ArrayAssignmentTargetElements,
+    ArrayAssignmentTargetRest,
+    ObjectAssignmentTargetProperties,
+    ObjectAssignmentTargetRest,
+    AssignmentTargetWithDefaultBinding,
+    AssignmentTargetWithDefaultInit,
+    AssignmentTargetPropertyIdentifierBinding,
+    AssignmentTargetPropertyIdentifierInit,
+    AssignmentTargetPropertyPropertyName,
+    AssignmentTargetPropertyPropertyBinding,
+    SequenceExpressionExpressions,
+    AwaitExpressionArgument,
+    ChainExpressionExpression,
+    ParenthesizedExpressionExpression,
+    DirectiveExpression,
+    BlockStatementBody,
+    VariableDeclarationDeclarations,
+    VariableDeclaratorId,
+    VariableDeclaratorInit,
+    ExpressionStatementExpression,
+    IfStatementTest,
+    IfStatementConsequent,
+    IfStatementAlternate,
+    DoWhileStatementBody,
+    DoWhileStatementTest,
+    WhileStatementTest,
+    WhileStatementBody,
+    ForStatementInit,
+    ForStatementTest,
+    ForStatementUpdate,
+    ForStatementBody,
+    ForInStatementLeft,
+    ForInStatementRight,
+    ForInStatementBody,
+    ForOfStatementLeft,
+    ForOfStatementRight,
+    ForOfStatementBody,
+    ContinueStatementLabel,
+    BreakStatementLabel,
+    ReturnStatementArgument,
+    WithStatementObject,
+    WithStatementBody,
+    SwitchStatementDiscriminant,
+    SwitchStatementCases,
+    SwitchCaseTest,
+    SwitchCaseConsequent,
+    LabeledStatementLabel,
+    LabeledStatementBody,
+    ThrowStatementArgument,
+    TryStatementBlock,
+    TryStatementHandler,
+    TryStatementFinalizer,
+    CatchClauseParam,
+    CatchClauseBody,
+    BindingPatternKind,
+    BindingPatternTypeAnnotation,
+    AssignmentPatternLeft,
+    AssignmentPatternRight,
+    ObjectPatternProperties,
+    ArrayPatternElements,
+    RestElementArgument,
+    FunctionId,
+    FunctionParams,
+    FunctionBody,
+    FunctionTypeParameters,
+    FunctionReturnType,
+    FormalParametersItems,
+    FormalParameterPattern,
+    FormalParameterDecorators,
+    FunctionBodyDirectives,
+    FunctionBodyStatements,
+    ArrowExpressionParams,
+    ArrowExpressionBody,
+    ArrowExpressionTypeParameters,
+    ArrowExpressionReturnType,
+    YieldExpressionArgument,
+    ClassId,
+    ClassSuperClass,
+    ClassBody,
+    ClassTypeParameters,
+    ClassSuperTypeParameters,
+    ClassImplements,
+    ClassDecorators,
+    ClassBodyBody,
+    MethodDefinitionKey,
+    MethodDefinitionValue,
+    MethodDefinitionDecorators,
+    PropertyDefinitionKey,
+    PropertyDefinitionValue,
+    PropertyDefinitionTypeAnnotation,
+    PropertyDefinitionDecorators,
+    StaticBlockBody,
+    ModuleDeclarationKind,
+    AccessorPropertyKey,
+    AccessorPropertyValue,
+    ImportExpressionSource,
+    ImportExpressionArguments,
+    ImportDeclarationSpecifiers,
+    ImportDeclarationSource,
+    ImportDeclarationAssertions,
+    ImportSpecifierImported,
+    ImportSpecifierLocal,
+    ImportDefaultSpecifierLocal,
+    ImportNamespaceSpecifierLocal,
+    ImportAttributeKey,
+    ImportAttributeValue,
+    ExportNamedDeclarationDeclaration,
+    ExportNamedDeclarationSpecifiers,
+    ExportNamedDeclarationSource,
+    ExportDefaultDeclarationDeclaration,
+    ExportDefaultDeclarationExported,
+    ExportAllDeclarationExported,
+    ExportAllDeclarationSource,
+    ExportAllDeclarationAssertions,
+    ExportSpecifierLocal,
+    ExportSpecifierExported,
+    JSXElementOpeningElement,
+    JSXElementClosingElement,
+    JSXElementChildren,
+    JSXOpeningElementName,
+    JSXOpeningElementAttributes,
+    JSXOpeningElementTypeParameters,
+    JSXClosingElementName,
+    JSXFragmentOpeningFragment,
+    JSXFragmentClosingFragment,
+    JSXFragmentChildren,
+    JSXNamespacedNameNamespace,
+    JSXNamespacedNameProperty,
+    JSXMemberExpressionObject,
+    JSXMemberExpressionProperty,
+    JSXExpressionContainerExpression,
+    JSXAttributeName,
+    JSXAttributeValue,
+    JSXSpreadAttributeArgument,
+    JSXSpreadChildExpression,
+    TSEnumDeclarationId,
+    TSEnumDeclarationMembers,
+    TSEnumMemberId,
+    TSEnumMemberInitializer,
+    TSTypeAnnotationTypeAnnotation,
+    TSLiteralTypeLiteral,
+    TSConditionalTypeCheckType,
+    TSConditionalTypeExtendsType,
+    TSConditionalTypeTrueType,
+    TSConditionalTypeFalseType,
+    TSUnionTypeTypes,
+    TSIntersectionTypeTypes,
+    TSTypeOperatorTypeTypeAnnotation,
+    TSArrayTypeElementType,
+    TSIndexedAccessTypeObjectType,
+    TSIndexedAccessTypeIndexType,
+    TSTupleTypeElementTypes,
+    TSNamedTupleMemberElementType,
+    TSNamedTupleMemberLabel,
+    TSOptionalTypeTypeAnnotation,
+    TSRestTypeTypeAnnotation,
+    TSTypeReferenceTypeName,
+    TSTypeReferenceTypeParameters,
+    TSQualifiedNameLeft,
+    TSQualifiedNameRight,
+    TSTypeParameterInstantiationParams,
+    TSTypeParameterName,
+    TSTypeParameterConstraint,
+    TSTypeParameterDefault,
+    TSTypeParameterDeclarationParams,
+    TSTypeAliasDeclarationId,
+    TSTypeAliasDeclarationTypeAnnotation,
+    TSTypeAliasDeclarationTypeParameters,
+    TSAbstractMethodDefinitionMethodDefinition,
+    TSAbstractPropertyDefinitionPropertyDefinition,
+    TSClassImplementsExpression,
+    TSClassImplementsTypeParameters,
+    TSInterfaceDeclarationId,
+    TSInterfaceDeclarationBody,
+    TSInterfaceDeclarationTypeParameters,
+    TSInterfaceDeclarationExtends,
+    TSInterfaceBodyBody,
+    TSPropertySignatureKey,
+    TSPropertySignatureTypeAnnotation,
+    TSIndexSignatureParameters,
+    TSIndexSignatureTypeAnnotation,
+    TSCallSignatureDeclarationParams,
+    TSCallSignatureDeclarationReturnType,
+    TSCallSignatureDeclarationTypeParameters,
+    TSMethodSignatureKey,
+    TSMethodSignatureParams,
+    TSMethodSignatureReturnType,
+    TSMethodSignatureTypeParameters,
+    TSConstructSignatureDeclarationParams,
+    TSConstructSignatureDeclarationReturnType,
+    TSConstructSignatureDeclarationTypeParameters,
+    TSIndexSignatureNameTypeAnnotation,
+    TSInterfaceHeritageExpression,
+    TSInterfaceHeritageTypeParameters,
+    TSTypePredicateParameterName,
+    TSTypePredicateTypeAnnotation,
+    TSModuleDeclarationId,
+    TSModuleDeclarationBody,
+    TSModuleBlockBody,
+    TSTypeLiteralMembers,
+    TSInferTypeTypeParameter,
+    TSTypeQueryExprName,
+    TSTypeQueryTypeParameters,
+    TSImportTypeParameter,
+    TSImportTypeQualifier,
+    TSImportTypeTypeParameters,
+    TSFunctionTypeParams,
+    TSFunctionTypeReturnType,
+    TSFunctionTypeTypeParameters,
+    TSConstructorTypeParams,
+    TSConstructorTypeReturnType,
+    TSConstructorTypeTypeParameters,
+    TSMappedTypeTypeParameter,
+    TSMappedTypeNameType,
+    TSMappedTypeTypeAnnotation,
+    TSTemplateLiteralTypeQuasis,
+    TSTemplateLiteralTypeTypes,
+    TSAsExpressionExpression,
+    TSAsExpressionTypeAnnotation,
+    TSSatisfiesExpressionExpression,
+    TSSatisfiesExpressionTypeAnnotation,
+    TSTypeAssertionExpression,
+    TSTypeAssertionTypeAnnotation,
+    TSImportEqualsDeclarationId,
+    TSImportEqualsDeclarationModuleReference,
+    TSExternalModuleReferenceExpression,
+    TSNonNullExpressionExpression,
+    DecoratorExpression,
+    TSExportAssignmentExpression,
+    TSNamespaceExportDeclarationId,
+    TSInstantiationExpressionExpression,
+    TSInstantiationExpressionTypeParameters,
+    FlowOpaqueTypeDeclarationId,
+    FlowOpaqueTypeDeclarationTypeParameters,
+    FlowOpaqueTypeDeclarationSupertype,
+    FlowOpaqueTypeDeclarationImpltype,
+    FlowDeclareModuleExportsTypeAnnotation,
+    FlowExactObjectTypeMembers,
+    JSDocNullableTypeTypeAnnotation,
+}
+
+impl Ancestor {
+    /// Type of the ancestor, `None` for [`Ancestor::None`]
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn ty(self) -> Option<AstType> {
+        match self {
+            Self::None => None,
+            Self::ProgramDirectives | Self::ProgramBody => Some(AstType::Program),
+            Self::ArrayExpressionElements => Some(AstType::ArrayExpression),
+            Self::ObjectExpressionProperties => Some(AstType::ObjectExpression),
+            Self::PropertyKey | Self::PropertyValue => Some(AstType::Property),
+            Self::TemplateLiteralQuasis | Self::TemplateLiteralExpressions => {
+                Some(AstType::TemplateLiteral)
+            }
+            Self::TaggedTemplateExpressionTag
+            | Self::TaggedTemplateExpressionQuasi
+            | Self::TaggedTemplateExpressionTypeParameters => {
+                Some(AstType::TaggedTemplateExpression)
+            }
+            Self::ComputedMemberExpressionObject | Self::ComputedMemberExpressionExpression => {
+                Some(AstType::ComputedMemberExpression)
+            }
+            Self::StaticMemberExpressionObject | Self::StaticMemberExpressionProperty => {
+                Some(AstType::StaticMemberExpression)
+            }
+            Self::PrivateFieldExpressionObject | Self::PrivateFieldExpressionField => {
+                Some(AstType::PrivateFieldExpression)
+            }
+            Self::CallExpressionCallee
+            | Self::CallExpressionArguments
+            | Self::CallExpressionTypeParameters => Some(AstType::CallExpression),
+            Self::NewExpressionCallee
+            | Self::NewExpressionArguments
+            | Self::NewExpressionTypeParameters => Some(AstType::NewExpression),
+            Self::MetaPropertyMeta | Self::MetaPropertyProperty => Some(AstType::MetaProperty),
+            Self::SpreadElementArgument => Some(AstType::SpreadElement),
+            Self::UpdateExpressionArgument => Some(AstType::UpdateExpression),
+            Self::UnaryExpressionArgument => Some(AstType::UnaryExpression),
+            Self::BinaryExpressionLeft | Self::BinaryExpressionRight => {
+                Some(AstType::BinaryExpression)
+            }
+            Self::PrivateInExpressionLeft | Self::PrivateInExpressionRight => {
+                Some(AstType::PrivateInExpression)
+            }
+            Self::LogicalExpressionLeft | Self::LogicalExpressionRight => {
+                Some(AstType::LogicalExpression)
+            }
+            Self::ConditionalExpressionTest
+            | Self::ConditionalExpressionConsequent
+            | Self::ConditionalExpressionAlternate => Some(AstType::ConditionalExpression),
+            Self::AssignmentExpressionLeft | Self::AssignmentExpressionRight => {
+                Some(AstType::AssignmentExpression)
+            }
+            Self::ArrayAssignmentTargetElements | Self::ArrayAssignmentTargetRest => {
+                Some(AstType::ArrayAssignmentTarget)
+            }
+            Self::ObjectAssignmentTargetProperties | Self::ObjectAssignmentTargetRest => {
+                Some(AstType::ObjectAssignmentTarget)
+            }
+            Self::AssignmentTargetWithDefaultBinding | Self::AssignmentTargetWithDefaultInit => {
+                Some(AstType::AssignmentTargetWithDefault)
+            }
+            Self::AssignmentTargetPropertyIdentifierBinding
+            | Self::AssignmentTargetPropertyIdentifierInit => {
+                Some(AstType::AssignmentTargetPropertyIdentifier)
+            }
+            Self::AssignmentTargetPropertyPropertyName
+            | Self::AssignmentTargetPropertyPropertyBinding => {
+                Some(AstType::AssignmentTargetPropertyProperty)
+            }
+            Self::SequenceExpressionExpressions => Some(AstType::SequenceExpression),
+            Self::AwaitExpressionArgument => Some(AstType::AwaitExpression),
+            Self::ChainExpressionExpression => Some(AstType::ChainExpression),
+            Self::ParenthesizedExpressionExpression => Some(AstType::ParenthesizedExpression),
+            Self::DirectiveExpression => Some(AstType::Directive),
+            Self::BlockStatementBody => Some(AstType::BlockStatement),
+            Self::VariableDeclarationDeclarations => Some(AstType::VariableDeclaration),
+            Self::VariableDeclaratorId | Self::VariableDeclaratorInit => {
+                Some(AstType::VariableDeclarator)
+            }
+            Self::ExpressionStatementExpression => Some(AstType::ExpressionStatement),
+            Self::IfStatementTest | Self::IfStatementConsequent | Self::IfStatementAlternate => {
+                Some(AstType::IfStatement)
+            }
+            Self::DoWhileStatementBody | Self::DoWhileStatementTest => {
+                Some(AstType::DoWhileStatement)
+            }
+            Self::WhileStatementTest | Self::WhileStatementBody => Some(AstType::WhileStatement),
+            Self::ForStatementInit
+            | Self::ForStatementTest
+            | Self::ForStatementUpdate
+            | Self::ForStatementBody => Some(AstType::ForStatement),
+            Self::ForInStatementLeft | Self::ForInStatementRight | Self::ForInStatementBody => {
+                Some(AstType::ForInStatement)
+            }
+            Self::ForOfStatementLeft | Self::ForOfStatementRight | Self::ForOfStatementBody => {
+                Some(AstType::ForOfStatement)
+            }
+            Self::ContinueStatementLabel => Some(AstType::ContinueStatement),
+            Self::BreakStatementLabel => Some(AstType::BreakStatement),
+            Self::ReturnStatementArgument => Some(AstType::ReturnStatement),
+            Self::WithStatementObject | Self::WithStatementBody => Some(AstType::WithStatement),
+            Self::SwitchStatementDiscriminant | Self::SwitchStatementCases => {
+                Some(AstType::SwitchStatement)
+            }
+            Self::SwitchCaseTest | Self::SwitchCaseConsequent => Some(AstType::SwitchCase),
+            Self::LabeledStatementLabel | Self::LabeledStatementBody => {
+                Some(AstType::LabeledStatement)
+            }
+            Self::ThrowStatementArgument => Some(AstType::ThrowStatement),
+            Self::TryStatementBlock | Self::TryStatementHandler | Self::TryStatementFinalizer => {
+                Some(AstType::TryStatement)
+            }
+            Self::CatchClauseParam | Self::CatchClauseBody => Some(AstType::CatchClause),
+            Self::BindingPatternKind | Self::BindingPatternTypeAnnotation => {
+                Some(AstType::BindingPattern)
+            }
+            Self::AssignmentPatternLeft | Self::AssignmentPatternRight => {
+                Some(AstType::AssignmentPattern)
+            }
+            Self::ObjectPatternProperties => Some(AstType::ObjectPattern),
+            Self::ArrayPatternElements => Some(AstType::ArrayPattern),
+            Self::RestElementArgument => Some(AstType::RestElement),
+            Self::FunctionId
+            | Self::FunctionParams
+            | Self::FunctionBody
+            | Self::FunctionTypeParameters
+            | Self::FunctionReturnType => Some(AstType::Function),
+            Self::FormalParametersItems => Some(AstType::FormalParameters),
+            Self::FormalParameterPattern | Self::FormalParameterDecorators => {
+                Some(AstType::FormalParameter)
+            }
+            Self::FunctionBodyDirectives | Self::FunctionBodyStatements => {
+                Some(AstType::FunctionBody)
+            }
+            Self::ArrowExpressionParams
+            | Self::ArrowExpressionBody
+            | Self::ArrowExpressionTypeParameters
+            | Self::ArrowExpressionReturnType => Some(AstType::ArrowExpression),
+            Self::YieldExpressionArgument => Some(AstType::YieldExpression),
+            Self::ClassId
+            | Self::ClassSuperClass
+            | Self::ClassBody
+            | Self::ClassTypeParameters
+            | Self::ClassSuperTypeParameters
+            | Self::ClassImplements
+            | Self::ClassDecorators => Some(AstType::Class),
+            Self::ClassBodyBody => Some(AstType::ClassBody),
+            Self::MethodDefinitionKey
+            | Self::MethodDefinitionValue
+            | Self::MethodDefinitionDecorators => Some(AstType::MethodDefinition),
+            Self::PropertyDefinitionKey
+            | Self::PropertyDefinitionValue
+            | Self::PropertyDefinitionTypeAnnotation
+            | Self::PropertyDefinitionDecorators => Some(AstType::PropertyDefinition),
+            Self::StaticBlockBody => Some(AstType::StaticBlock),
+            Self::ModuleDeclarationKind => Some(AstType::ModuleDeclaration),
+            Self::AccessorPropertyKey | Self::AccessorPropertyValue => {
+                Some(AstType::AccessorProperty)
+            }
+            Self::ImportExpressionSource | Self::ImportExpressionArguments => {
+                Some(AstType::ImportExpression)
+            }
+            Self::ImportDeclarationSpecifiers
+            | Self::ImportDeclarationSource
+            | Self::ImportDeclarationAssertions => Some(AstType::ImportDeclaration),
+            Self::ImportSpecifierImported | Self::ImportSpecifierLocal => {
+                Some(AstType::ImportSpecifier)
+            }
+            Self::ImportDefaultSpecifierLocal => Some(AstType::ImportDefaultSpecifier),
+            Self::ImportNamespaceSpecifierLocal => Some(AstType::ImportNamespaceSpecifier),
+            Self::ImportAttributeKey | Self::ImportAttributeValue => Some(AstType::ImportAttribute),
+            Self::ExportNamedDeclarationDeclaration
+            | Self::ExportNamedDeclarationSpecifiers
+            | Self::ExportNamedDeclarationSource => Some(AstType::ExportNamedDeclaration),
+            Self::ExportDefaultDeclarationDeclaration | Self::ExportDefaultDeclarationExported => {
+                Some(AstType::ExportDefaultDeclaration)
+            }
+            Self::ExportAllDeclarationExported
+            | Self::ExportAllDeclarationSource
+            | Self::ExportAllDeclarationAssertions => Some(AstType::ExportAllDeclaration),
+            Self::ExportSpecifierLocal | Self::ExportSpecifierExported => {
+                Some(AstType::ExportSpecifier)
+            }
+            Self::JSXElementOpeningElement
+            | Self::JSXElementClosingElement
+            | Self::JSXElementChildren => Some(AstType::JSXElement),
+            Self::JSXOpeningElementName
+            | Self::JSXOpeningElementAttributes
+            | Self::JSXOpeningElementTypeParameters => Some(AstType::JSXOpeningElement),
+            Self::JSXClosingElementName => Some(AstType::JSXClosingElement),
+            Self::JSXFragmentOpeningFragment
+            | Self::JSXFragmentClosingFragment
+            | Self::JSXFragmentChildren => Some(AstType::JSXFragment),
+            Self::JSXNamespacedNameNamespace | Self::JSXNamespacedNameProperty => {
+                Some(AstType::JSXNamespacedName)
+            }
+            Self::JSXMemberExpressionObject | Self::JSXMemberExpressionProperty => {
+                Some(AstType::JSXMemberExpression)
+            }
+            Self::JSXExpressionContainerExpression => Some(AstType::JSXExpressionContainer),
+            Self::JSXAttributeName | Self::JSXAttributeValue => Some(AstType::JSXAttribute),
+            Self::JSXSpreadAttributeArgument => Some(AstType::JSXSpreadAttribute),
+            Self::JSXSpreadChildExpression => Some(AstType::JSXSpreadChild),
+            Self::TSEnumDeclarationId | Self::TSEnumDeclarationMembers => {
+                Some(AstType::TSEnumDeclaration)
+            }
+            Self::TSEnumMemberId | Self::TSEnumMemberInitializer => Some(AstType::TSEnumMember),
+            Self::TSTypeAnnotationTypeAnnotation => Some(AstType::TSTypeAnnotation),
+            Self::TSLiteralTypeLiteral => Some(AstType::TSLiteralType),
+            Self::TSConditionalTypeCheckType
+            | Self::TSConditionalTypeExtendsType
+            | Self::TSConditionalTypeTrueType
+            | Self::TSConditionalTypeFalseType => Some(AstType::TSConditionalType),
+            Self::TSUnionTypeTypes => Some(AstType::TSUnionType),
+            Self::TSIntersectionTypeTypes => Some(AstType::TSIntersectionType),
+            Self::TSTypeOperatorTypeTypeAnnotation => Some(AstType::TSTypeOperatorType),
+            Self::TSArrayTypeElementType => Some(AstType::TSArrayType),
+            Self::TSIndexedAccessTypeObjectType | Self::TSIndexedAccessTypeIndexType => {
+                Some(AstType::TSIndexedAccessType)
+            }
+            Self::TSTupleTypeElementTypes => Some(AstType::TSTupleType),
+            Self::TSNamedTupleMemberElementType | Self::TSNamedTupleMemberLabel => {
+                Some(AstType::TSNamedTupleMember)
+            }
+            Self::TSOptionalTypeTypeAnnotation => Some(AstType::TSOptionalType),
+            Self::TSRestTypeTypeAnnotation => Some(AstType::TSRestType),
+            Self::TSTypeReferenceTypeName | Self::TSTypeReferenceTypeParameters => {
+                Some(AstType::TSTypeReference)
+            }
+            Self::TSQualifiedNameLeft | Self::TSQualifiedNameRight => {
+                Some(AstType::TSQualifiedName)
+            }
+            Self::TSTypeParameterInstantiationParams => Some(AstType::TSTypeParameterInstantiation),
+            Self::TSTypeParameterName
+            | Self::TSTypeParameterConstraint
+            | Self::TSTypeParameterDefault => Some(AstType::TSTypeParameter),
+            Self::TSTypeParameterDeclarationParams => Some(AstType::TSTypeParameterDeclaration),
+            Self::TSTypeAliasDeclarationId
+            | Self::TSTypeAliasDeclarationTypeAnnotation
+            | Self::TSTypeAliasDeclarationTypeParameters => Some(AstType::TSTypeAliasDeclaration),
+            Self::TSAbstractMethodDefinitionMethodDefinition => {
+                Some(AstType::TSAbstractMethodDefinition)
+            }
+            Self::TSAbstractPropertyDefinitionPropertyDefinition => {
+                Some(AstType::TSAbstractPropertyDefinition)
+            }
+            Self::TSClassImplementsExpression | Self::TSClassImplementsTypeParameters => {
+                Some(AstType::TSClassImplements)
+            }
+            Self::TSInterfaceDeclarationId
+            | Self::TSInterfaceDeclarationBody
+            | Self::TSInterfaceDeclarationTypeParameters
+            | Self::TSInterfaceDeclarationExtends => Some(AstType::TSInterfaceDeclaration),
+            Self::TSInterfaceBodyBody => Some(AstType::TSInterfaceBody),
+            Self::TSPropertySignatureKey | Self::TSPropertySignatureTypeAnnotation => {
+                Some(AstType::TSPropertySignature)
+            }
+            Self::TSIndexSignatureParameters | Self::TSIndexSignatureTypeAnnotation => {
+                Some(AstType::TSIndexSignature)
+            }
+            Self::TSCallSignatureDeclarationParams
+            | Self::TSCallSignatureDeclarationReturnType
+            | Self::TSCallSignatureDeclarationTypeParameters => {
+                Some(AstType::TSCallSignatureDeclaration)
+            }
+            Self::TSMethodSignatureKey
+            | Self::TSMethodSignatureParams
+            | Self::TSMethodSignatureReturnType
+            | Self::TSMethodSignatureTypeParameters => Some(AstType::TSMethodSignature),
+            Self::TSConstructSignatureDeclarationParams
+            | Self::TSConstructSignatureDeclarationReturnType
+            | Self::TSConstructSignatureDeclarationTypeParameters => {
+                Some(AstType::TSConstructSignatureDeclaration)
+            }
+            Self::TSIndexSignatureNameTypeAnnotation => Some(AstType::TSIndexSignatureName),
+            Self::TSInterfaceHeritageExpression | Self::TSInterfaceHeritageTypeParameters => {
+                Some(AstType::TSInterfaceHeritage)
+            }
+            Self::TSTypePredicateParameterName | Self::TSTypePredicateTypeAnnotation => {
+                Some(AstType::TSTypePredicate)
+            }
+            Self::TSModuleDeclarationId | Self::TSModuleDeclarationBody => {
+                Some(AstType::TSModuleDeclaration)
+            }
+            Self::TSModuleBlockBody => Some(AstType::TSModuleBlock),
+            Self::TSTypeLiteralMembers => Some(AstType::TSTypeLiteral),
+            Self::TSInferTypeTypeParameter => Some(AstType::TSInferType),
+            Self::TSTypeQueryExprName | Self::TSTypeQueryTypeParameters => {
+                Some(AstType::TSTypeQuery)
+            }
+            Self::TSImportTypeParameter
+            | Self::TSImportTypeQualifier
+            | Self::TSImportTypeTypeParameters => Some(AstType::TSImportType),
+            Self::TSFunctionTypeParams
+            | Self::TSFunctionTypeReturnType
+            | Self::TSFunctionTypeTypeParameters => Some(AstType::TSFunctionType),
+            Self::TSConstructorTypeParams
+            | Self::TSConstructorTypeReturnType
+            | Self::TSConstructorTypeTypeParameters => Some(AstType::TSConstructorType),
+            Self::TSMappedTypeTypeParameter
+            | Self::TSMappedTypeNameType
+            | Self::TSMappedTypeTypeAnnotation => Some(AstType::TSMappedType),
+            Self::TSTemplateLiteralTypeQuasis | Self::TSTemplateLiteralTypeTypes => {
+                Some(AstType::TSTemplateLiteralType)
+            }
+            Self::TSAsExpressionExpression | Self::TSAsExpressionTypeAnnotation => {
+                Some(AstType::TSAsExpression)
+            }
+            Self::TSSatisfiesExpressionExpression | Self::TSSatisfiesExpressionTypeAnnotation => {
+                Some(AstType::TSSatisfiesExpression)
+            }
+            Self::TSTypeAssertionExpression | Self::TSTypeAssertionTypeAnnotation => {
+                Some(AstType::TSTypeAssertion)
+            }
+            Self::TSImportEqualsDeclarationId | Self::TSImportEqualsDeclarationModuleReference => {
+                Some(AstType::TSImportEqualsDeclaration)
+            }
+            Self::TSExternalModuleReferenceExpression => Some(AstType::TSExternalModuleReference),
+            Self::TSNonNullExpressionExpression => Some(AstType::TSNonNullExpression),
+            Self::DecoratorExpression => Some(AstType::Decorator),
+            Self::TSExportAssignmentExpression => Some(AstType::TSExportAssignment),
+            Self::TSNamespaceExportDeclarationId => Some(AstType::TSNamespaceExportDeclaration),
+            Self::TSInstantiationExpressionExpression
+            | Self::TSInstantiationExpressionTypeParameters => {
+                Some(AstType::TSInstantiationExpression)
+            }
+            Self::FlowOpaqueTypeDeclarationId
+            | Self::FlowOpaqueTypeDeclarationTypeParameters
+            | Self::FlowOpaqueTypeDeclarationSupertype
+            | Self::FlowOpaqueTypeDeclarationImpltype => Some(AstType::FlowOpaqueTypeDeclaration),
+            Self::FlowDeclareModuleExportsTypeAnnotation => Some(AstType::FlowDeclareModuleExports),
+            Self::FlowExactObjectTypeMembers => Some(AstType::FlowExactObjectType),
+            Self::JSDocNullableTypeTypeAnnotation => Some(AstType::JSDocNullableType),
+        }
+    }
+}
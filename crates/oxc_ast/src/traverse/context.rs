@@ -0,0 +1,60 @@
+use oxc_allocator::Allocator;
+
+use super::Ancestor;
+use crate::AstBuilder;
+
+/// Context of the current node in [`Traverse`](super::Traverse) hooks
+pub struct TraverseCtx<'a> {
+    /// Builder for new nodes, e.g. to replace the current node
+    pub ast: AstBuilder<'a>,
+    /// Ancestors of the current node, the parent is last
+    stack: Vec<Ancestor>,
+}
+
+impl<'a> TraverseCtx<'a> {
+    #[must_use]
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator), stack: Vec::with_capacity(64) }
+    }
+
+    /// The parent of the current node, [`Ancestor::None`] for `Program`
+    #[must_use]
+    pub fn parent(&self) -> Ancestor {
+        self.ancestor(1)
+    }
+
+    /// The ancestor `level` levels up, `1` is the parent.
+    /// [`Ancestor::None`] when there are fewer ancestors.
+    #[must_use]
+    pub fn ancestor(&self, level: usize) -> Ancestor {
+        self.stack
+            .len()
+            .checked_sub(level)
+            .and_then(|index| self.stack.get(index).copied())
+            .unwrap_or(Ancestor::None)
+    }
+
+    /// Ancestors of the current node, starting from the parent
+    pub fn ancestors(&self) -> impl Iterator<Item = Ancestor> + '_ {
+        self.stack.iter().rev().copied()
+    }
+
+    /// Number of ancestors of the current node
+    #[must_use]
+    pub fn ancestors_depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub(super) fn push_stack(&mut self, ancestor: Ancestor) {
+        self.stack.push(ancestor);
+    }
+
+    /// Move to the next field of the parent
+    pub(super) fn retag_stack(&mut self, ancestor: Ancestor) {
+        *self.stack.last_mut().unwrap() = ancestor;
+    }
+
+    pub(super) fn pop_stack(&mut self) {
+        self.stack.pop();
+    }
+}
@@ -0,0 +1,2132 @@
+// Auto-generated by `cargo run -p ast_codegen`, do not edit.
+
+use super::TraverseCtx;
+#[allow(clippy::wildcard_imports)]
+use crate::ast::*;
+
+/// Hooks called by [`traverse_mut`](super::traverse_mut) for every node of the AST.
+///
+/// `enter_*` is called before the children of a node are walked and `exit_*` after.
+/// A hook can replace the node it is given, in `exit_*` this is always safe
+/// because the children of the node have been walked already,
+/// a node replaced in `enter_*` is walked instead of the original node.
+/// Use [`TraverseCtx`] to look at the ancestors of the node and to build new nodes.
+pub trait Traverse<'a> {
+    fn enter_program(&mut self, _node: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_program(&mut self, _node: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_expression(&mut self, _node: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_expression(&mut self, _node: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_identifier_name(&mut self, _node: &mut IdentifierName, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_identifier_name(&mut self, _node: &mut IdentifierName, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_identifier_reference(
+        &mut self,
+        _node: &mut IdentifierReference,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_identifier_reference(
+        &mut self,
+        _node: &mut IdentifierReference,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        _node: &mut BindingIdentifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_binding_identifier(
+        &mut self,
+        _node: &mut BindingIdentifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_label_identifier(&mut self, _node: &mut LabelIdentifier, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_label_identifier(&mut self, _node: &mut LabelIdentifier, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_this_expression(&mut self, _node: &mut ThisExpression, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_this_expression(&mut self, _node: &mut ThisExpression, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_array_expression(
+        &mut self,
+        _node: &mut ArrayExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_array_expression(
+        &mut self,
+        _node: &mut ArrayExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_object_expression(
+        &mut self,
+        _node: &mut ObjectExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_object_expression(
+        &mut self,
+        _node: &mut ObjectExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_object_property(
+        &mut self,
+        _node: &mut ObjectProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_object_property(&mut self, _node: &mut ObjectProperty<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_property(&mut self, _node: &mut Property<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_property(&mut self, _node: &mut Property<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_property_key(&mut self, _node: &mut PropertyKey<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_property_key(&mut self, _node: &mut PropertyKey<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_property_value(&mut self, _node: &mut PropertyValue<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_property_value(&mut self, _node: &mut PropertyValue<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_template_literal(
+        &mut self,
+        _node: &mut TemplateLiteral<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_template_literal(
+        &mut self,
+        _node: &mut TemplateLiteral<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_tagged_template_expression(
+        &mut self,
+        _node: &mut TaggedTemplateExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_tagged_template_expression(
+        &mut self,
+        _node: &mut TaggedTemplateExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_template_element(&mut self, _node: &mut TemplateElement, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_template_element(&mut self, _node: &mut TemplateElement, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_member_expression(
+        &mut self,
+        _node: &mut MemberExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_member_expression(
+        &mut self,
+        _node: &mut MemberExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_computed_member_expression(
+        &mut self,
+        _node: &mut ComputedMemberExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_computed_member_expression(
+        &mut self,
+        _node: &mut ComputedMemberExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_static_member_expression(
+        &mut self,
+        _node: &mut StaticMemberExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_static_member_expression(
+        &mut self,
+        _node: &mut StaticMemberExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_private_field_expression(
+        &mut self,
+        _node: &mut PrivateFieldExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_private_field_expression(
+        &mut self,
+        _node: &mut PrivateFieldExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_call_expression(
+        &mut self,
+        _node: &mut CallExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_call_expression(&mut self, _node: &mut CallExpression<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_new_expression(&mut self, _node: &mut NewExpression<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_new_expression(&mut self, _node: &mut NewExpression<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_meta_property(&mut self, _node: &mut MetaProperty, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_meta_property(&mut self, _node: &mut MetaProperty, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_spread_element(&mut self, _node: &mut SpreadElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_spread_element(&mut self, _node: &mut SpreadElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_argument(&mut self, _node: &mut Argument<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_argument(&mut self, _node: &mut Argument<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_update_expression(
+        &mut self,
+        _node: &mut UpdateExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_update_expression(
+        &mut self,
+        _node: &mut UpdateExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_unary_expression(
+        &mut self,
+        _node: &mut UnaryExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_unary_expression(
+        &mut self,
+        _node: &mut UnaryExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_binary_expression(
+        &mut self,
+        _node: &mut BinaryExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_binary_expression(
+        &mut self,
+        _node: &mut BinaryExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_private_in_expression(
+        &mut self,
+        _node: &mut PrivateInExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_private_in_expression(
+        &mut self,
+        _node: &mut PrivateInExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_logical_expression(
+        &mut self,
+        _node: &mut LogicalExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_logical_expression(
+        &mut self,
+        _node: &mut LogicalExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_conditional_expression(
+        &mut self,
+        _node: &mut ConditionalExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_conditional_expression(
+        &mut self,
+        _node: &mut ConditionalExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_expression(
+        &mut self,
+        _node: &mut AssignmentExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_expression(
+        &mut self,
+        _node: &mut AssignmentExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_target(
+        &mut self,
+        _node: &mut AssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_target(
+        &mut self,
+        _node: &mut AssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_simple_assignment_target(
+        &mut self,
+        _node: &mut SimpleAssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_simple_assignment_target(
+        &mut self,
+        _node: &mut SimpleAssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_target_pattern(
+        &mut self,
+        _node: &mut AssignmentTargetPattern<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_target_pattern(
+        &mut self,
+        _node: &mut AssignmentTargetPattern<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_array_assignment_target(
+        &mut self,
+        _node: &mut ArrayAssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_array_assignment_target(
+        &mut self,
+        _node: &mut ArrayAssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_object_assignment_target(
+        &mut self,
+        _node: &mut ObjectAssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_object_assignment_target(
+        &mut self,
+        _node: &mut ObjectAssignmentTarget<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_target_maybe_default(
+        &mut self,
+        _node: &mut AssignmentTargetMaybeDefault<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_target_maybe_default(
+        &mut self,
+        _node: &mut AssignmentTargetMaybeDefault<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_target_with_default(
+        &mut self,
+        _node: &mut AssignmentTargetWithDefault<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_target_with_default(
+        &mut self,
+        _node: &mut AssignmentTargetWithDefault<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_target_property(
+        &mut self,
+        _node: &mut AssignmentTargetProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_target_property(
+        &mut self,
+        _node: &mut AssignmentTargetProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_target_property_identifier(
+        &mut self,
+        _node: &mut AssignmentTargetPropertyIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_target_property_identifier(
+        &mut self,
+        _node: &mut AssignmentTargetPropertyIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_target_property_property(
+        &mut self,
+        _node: &mut AssignmentTargetPropertyProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_target_property_property(
+        &mut self,
+        _node: &mut AssignmentTargetPropertyProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_sequence_expression(
+        &mut self,
+        _node: &mut SequenceExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_sequence_expression(
+        &mut self,
+        _node: &mut SequenceExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_super(&mut self, _node: &mut Super, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_super(&mut self, _node: &mut Super, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_await_expression(
+        &mut self,
+        _node: &mut AwaitExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_await_expression(
+        &mut self,
+        _node: &mut AwaitExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_chain_expression(
+        &mut self,
+        _node: &mut ChainExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_chain_expression(
+        &mut self,
+        _node: &mut ChainExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_chain_element(&mut self, _node: &mut ChainElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_chain_element(&mut self, _node: &mut ChainElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_parenthesized_expression(
+        &mut self,
+        _node: &mut ParenthesizedExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_parenthesized_expression(
+        &mut self,
+        _node: &mut ParenthesizedExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_statement(&mut self, _node: &mut Statement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_statement(&mut self, _node: &mut Statement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_directive(&mut self, _node: &mut Directive<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_directive(&mut self, _node: &mut Directive<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_block_statement(
+        &mut self,
+        _node: &mut BlockStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_block_statement(&mut self, _node: &mut BlockStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_declaration(&mut self, _node: &mut Declaration<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_declaration(&mut self, _node: &mut Declaration<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_variable_declaration(
+        &mut self,
+        _node: &mut VariableDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_variable_declaration(
+        &mut self,
+        _node: &mut VariableDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_variable_declarator(
+        &mut self,
+        _node: &mut VariableDeclarator<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_variable_declarator(
+        &mut self,
+        _node: &mut VariableDeclarator<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_empty_statement(&mut self, _node: &mut EmptyStatement, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_empty_statement(&mut self, _node: &mut EmptyStatement, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_expression_statement(
+        &mut self,
+        _node: &mut ExpressionStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_expression_statement(
+        &mut self,
+        _node: &mut ExpressionStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_if_statement(&mut self, _node: &mut IfStatement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_if_statement(&mut self, _node: &mut IfStatement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_do_while_statement(
+        &mut self,
+        _node: &mut DoWhileStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_do_while_statement(
+        &mut self,
+        _node: &mut DoWhileStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_while_statement(
+        &mut self,
+        _node: &mut WhileStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_while_statement(&mut self, _node: &mut WhileStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_for_statement(&mut self, _node: &mut ForStatement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_for_statement(&mut self, _node: &mut ForStatement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_for_statement_init(
+        &mut self,
+        _node: &mut ForStatementInit<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_for_statement_init(
+        &mut self,
+        _node: &mut ForStatementInit<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_for_in_statement(
+        &mut self,
+        _node: &mut ForInStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_for_in_statement(
+        &mut self,
+        _node: &mut ForInStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_for_of_statement(
+        &mut self,
+        _node: &mut ForOfStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_for_of_statement(
+        &mut self,
+        _node: &mut ForOfStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_for_statement_left(
+        &mut self,
+        _node: &mut ForStatementLeft<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_for_statement_left(
+        &mut self,
+        _node: &mut ForStatementLeft<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_continue_statement(
+        &mut self,
+        _node: &mut ContinueStatement,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_continue_statement(
+        &mut self,
+        _node: &mut ContinueStatement,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_break_statement(&mut self, _node: &mut BreakStatement, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_break_statement(&mut self, _node: &mut BreakStatement, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_return_statement(
+        &mut self,
+        _node: &mut ReturnStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_return_statement(
+        &mut self,
+        _node: &mut ReturnStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_with_statement(&mut self, _node: &mut WithStatement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_with_statement(&mut self, _node: &mut WithStatement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_switch_statement(
+        &mut self,
+        _node: &mut SwitchStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_switch_statement(
+        &mut self,
+        _node: &mut SwitchStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_switch_case(&mut self, _node: &mut SwitchCase<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_switch_case(&mut self, _node: &mut SwitchCase<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_labeled_statement(
+        &mut self,
+        _node: &mut LabeledStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_labeled_statement(
+        &mut self,
+        _node: &mut LabeledStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_throw_statement(
+        &mut self,
+        _node: &mut ThrowStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_throw_statement(&mut self, _node: &mut ThrowStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_try_statement(&mut self, _node: &mut TryStatement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_try_statement(&mut self, _node: &mut TryStatement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_catch_clause(&mut self, _node: &mut CatchClause<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_catch_clause(&mut self, _node: &mut CatchClause<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_debugger_statement(
+        &mut self,
+        _node: &mut DebuggerStatement,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_debugger_statement(
+        &mut self,
+        _node: &mut DebuggerStatement,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_invalid_statement(
+        &mut self,
+        _node: &mut InvalidStatement,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_invalid_statement(&mut self, _node: &mut InvalidStatement, _ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_invalid_expression(
+        &mut self,
+        _node: &mut InvalidExpression,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_invalid_expression(
+        &mut self,
+        _node: &mut InvalidExpression,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_binding_pattern(
+        &mut self,
+        _node: &mut BindingPattern<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_binding_pattern(&mut self, _node: &mut BindingPattern<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_binding_pattern_kind(
+        &mut self,
+        _node: &mut BindingPatternKind<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_binding_pattern_kind(
+        &mut self,
+        _node: &mut BindingPatternKind<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_assignment_pattern(
+        &mut self,
+        _node: &mut AssignmentPattern<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_assignment_pattern(
+        &mut self,
+        _node: &mut AssignmentPattern<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_object_pattern(&mut self, _node: &mut ObjectPattern<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_object_pattern(&mut self, _node: &mut ObjectPattern<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_object_pattern_property(
+        &mut self,
+        _node: &mut ObjectPatternProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_object_pattern_property(
+        &mut self,
+        _node: &mut ObjectPatternProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_array_pattern(&mut self, _node: &mut ArrayPattern<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_array_pattern(&mut self, _node: &mut ArrayPattern<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_rest_element(&mut self, _node: &mut RestElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_rest_element(&mut self, _node: &mut RestElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_function(&mut self, _node: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_function(&mut self, _node: &mut Function<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_formal_parameters(
+        &mut self,
+        _node: &mut FormalParameters<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_formal_parameters(
+        &mut self,
+        _node: &mut FormalParameters<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_formal_parameter(
+        &mut self,
+        _node: &mut FormalParameter<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_formal_parameter(
+        &mut self,
+        _node: &mut FormalParameter<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_function_body(&mut self, _node: &mut FunctionBody<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_function_body(&mut self, _node: &mut FunctionBody<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_arrow_expression(
+        &mut self,
+        _node: &mut ArrowExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_arrow_expression(
+        &mut self,
+        _node: &mut ArrowExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_yield_expression(
+        &mut self,
+        _node: &mut YieldExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_yield_expression(
+        &mut self,
+        _node: &mut YieldExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_class(&mut self, _node: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_class(&mut self, _node: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_class_body(&mut self, _node: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_class_body(&mut self, _node: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_class_element(&mut self, _node: &mut ClassElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_class_element(&mut self, _node: &mut ClassElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_method_definition(
+        &mut self,
+        _node: &mut MethodDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_method_definition(
+        &mut self,
+        _node: &mut MethodDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_property_definition(
+        &mut self,
+        _node: &mut PropertyDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_property_definition(
+        &mut self,
+        _node: &mut PropertyDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_private_identifier(
+        &mut self,
+        _node: &mut PrivateIdentifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_private_identifier(
+        &mut self,
+        _node: &mut PrivateIdentifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_static_block(&mut self, _node: &mut StaticBlock<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_static_block(&mut self, _node: &mut StaticBlock<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_module_declaration(
+        &mut self,
+        _node: &mut ModuleDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_module_declaration(
+        &mut self,
+        _node: &mut ModuleDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_module_declaration_kind(
+        &mut self,
+        _node: &mut ModuleDeclarationKind<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_module_declaration_kind(
+        &mut self,
+        _node: &mut ModuleDeclarationKind<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_accessor_property(
+        &mut self,
+        _node: &mut AccessorProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_accessor_property(
+        &mut self,
+        _node: &mut AccessorProperty<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_import_expression(
+        &mut self,
+        _node: &mut ImportExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_import_expression(
+        &mut self,
+        _node: &mut ImportExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_import_declaration(
+        &mut self,
+        _node: &mut ImportDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_import_declaration(
+        &mut self,
+        _node: &mut ImportDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_import_declaration_specifier(
+        &mut self,
+        _node: &mut ImportDeclarationSpecifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_import_declaration_specifier(
+        &mut self,
+        _node: &mut ImportDeclarationSpecifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_import_specifier(&mut self, _node: &mut ImportSpecifier, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_import_specifier(&mut self, _node: &mut ImportSpecifier, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_import_default_specifier(
+        &mut self,
+        _node: &mut ImportDefaultSpecifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_import_default_specifier(
+        &mut self,
+        _node: &mut ImportDefaultSpecifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_import_namespace_specifier(
+        &mut self,
+        _node: &mut ImportNamespaceSpecifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_import_namespace_specifier(
+        &mut self,
+        _node: &mut ImportNamespaceSpecifier,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_import_attribute(&mut self, _node: &mut ImportAttribute, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_import_attribute(&mut self, _node: &mut ImportAttribute, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_import_attribute_key(
+        &mut self,
+        _node: &mut ImportAttributeKey,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_import_attribute_key(
+        &mut self,
+        _node: &mut ImportAttributeKey,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_export_named_declaration(
+        &mut self,
+        _node: &mut ExportNamedDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_export_named_declaration(
+        &mut self,
+        _node: &mut ExportNamedDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_export_default_declaration(
+        &mut self,
+        _node: &mut ExportDefaultDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_export_default_declaration(
+        &mut self,
+        _node: &mut ExportDefaultDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_export_all_declaration(
+        &mut self,
+        _node: &mut ExportAllDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_export_all_declaration(
+        &mut self,
+        _node: &mut ExportAllDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_export_specifier(&mut self, _node: &mut ExportSpecifier, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_export_specifier(&mut self, _node: &mut ExportSpecifier, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_export_default_declaration_kind(
+        &mut self,
+        _node: &mut ExportDefaultDeclarationKind<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_export_default_declaration_kind(
+        &mut self,
+        _node: &mut ExportDefaultDeclarationKind<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_module_export_name(
+        &mut self,
+        _node: &mut ModuleExportName,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_module_export_name(
+        &mut self,
+        _node: &mut ModuleExportName,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_boolean_literal(&mut self, _node: &mut BooleanLiteral, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_boolean_literal(&mut self, _node: &mut BooleanLiteral, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_null_literal(&mut self, _node: &mut NullLiteral, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_null_literal(&mut self, _node: &mut NullLiteral, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_number_literal(&mut self, _node: &mut NumberLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_number_literal(&mut self, _node: &mut NumberLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_bigint_literal(&mut self, _node: &mut BigintLiteral, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_bigint_literal(&mut self, _node: &mut BigintLiteral, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_reg_exp_literal(&mut self, _node: &mut RegExpLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+    fn exit_reg_exp_literal(&mut self, _node: &mut RegExpLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_string_literal(&mut self, _node: &mut StringLiteral, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_string_literal(&mut self, _node: &mut StringLiteral, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_jsx_element(&mut self, _node: &mut JSXElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_jsx_element(&mut self, _node: &mut JSXElement<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_jsx_opening_element(
+        &mut self,
+        _node: &mut JSXOpeningElement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_opening_element(
+        &mut self,
+        _node: &mut JSXOpeningElement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_closing_element(
+        &mut self,
+        _node: &mut JSXClosingElement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_closing_element(
+        &mut self,
+        _node: &mut JSXClosingElement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_fragment(&mut self, _node: &mut JSXFragment<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_jsx_fragment(&mut self, _node: &mut JSXFragment<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_jsx_opening_fragment(
+        &mut self,
+        _node: &mut JSXOpeningFragment,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_opening_fragment(
+        &mut self,
+        _node: &mut JSXOpeningFragment,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_closing_fragment(
+        &mut self,
+        _node: &mut JSXClosingFragment,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_closing_fragment(
+        &mut self,
+        _node: &mut JSXClosingFragment,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_element_name(
+        &mut self,
+        _node: &mut JSXElementName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_element_name(
+        &mut self,
+        _node: &mut JSXElementName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_namespaced_name(
+        &mut self,
+        _node: &mut JSXNamespacedName,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_namespaced_name(
+        &mut self,
+        _node: &mut JSXNamespacedName,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_member_expression(
+        &mut self,
+        _node: &mut JSXMemberExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_member_expression(
+        &mut self,
+        _node: &mut JSXMemberExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_member_expression_object(
+        &mut self,
+        _node: &mut JSXMemberExpressionObject<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_member_expression_object(
+        &mut self,
+        _node: &mut JSXMemberExpressionObject<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_expression_container(
+        &mut self,
+        _node: &mut JSXExpressionContainer<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_expression_container(
+        &mut self,
+        _node: &mut JSXExpressionContainer<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_expression(&mut self, _node: &mut JSXExpression<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_jsx_expression(&mut self, _node: &mut JSXExpression<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_jsx_empty_expression(
+        &mut self,
+        _node: &mut JSXEmptyExpression,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_empty_expression(
+        &mut self,
+        _node: &mut JSXEmptyExpression,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_attribute_item(
+        &mut self,
+        _node: &mut JSXAttributeItem<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_attribute_item(
+        &mut self,
+        _node: &mut JSXAttributeItem<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_attribute(&mut self, _node: &mut JSXAttribute<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_jsx_attribute(&mut self, _node: &mut JSXAttribute<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_jsx_spread_attribute(
+        &mut self,
+        _node: &mut JSXSpreadAttribute<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_spread_attribute(
+        &mut self,
+        _node: &mut JSXSpreadAttribute<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_attribute_name(
+        &mut self,
+        _node: &mut JSXAttributeName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_attribute_name(
+        &mut self,
+        _node: &mut JSXAttributeName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_attribute_value(
+        &mut self,
+        _node: &mut JSXAttributeValue<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_attribute_value(
+        &mut self,
+        _node: &mut JSXAttributeValue<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_identifier(&mut self, _node: &mut JSXIdentifier, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_jsx_identifier(&mut self, _node: &mut JSXIdentifier, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_jsx_child(&mut self, _node: &mut JSXChild<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_jsx_child(&mut self, _node: &mut JSXChild<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_jsx_spread_child(
+        &mut self,
+        _node: &mut JSXSpreadChild<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_jsx_spread_child(
+        &mut self,
+        _node: &mut JSXSpreadChild<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_jsx_text(&mut self, _node: &mut JSXText, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_jsx_text(&mut self, _node: &mut JSXText, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_enum_declaration(
+        &mut self,
+        _node: &mut TSEnumDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_enum_declaration(
+        &mut self,
+        _node: &mut TSEnumDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_enum_member(&mut self, _node: &mut TSEnumMember<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_enum_member(&mut self, _node: &mut TSEnumMember<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_enum_member_name(
+        &mut self,
+        _node: &mut TSEnumMemberName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_enum_member_name(
+        &mut self,
+        _node: &mut TSEnumMemberName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_annotation(
+        &mut self,
+        _node: &mut TSTypeAnnotation<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_annotation(
+        &mut self,
+        _node: &mut TSTypeAnnotation<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_literal_type(&mut self, _node: &mut TSLiteralType<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+    fn exit_ts_literal_type(&mut self, _node: &mut TSLiteralType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_literal(&mut self, _node: &mut TSLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_literal(&mut self, _node: &mut TSLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_type(&mut self, _node: &mut TSType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_type(&mut self, _node: &mut TSType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_conditional_type(
+        &mut self,
+        _node: &mut TSConditionalType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_conditional_type(
+        &mut self,
+        _node: &mut TSConditionalType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_union_type(&mut self, _node: &mut TSUnionType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_union_type(&mut self, _node: &mut TSUnionType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_intersection_type(
+        &mut self,
+        _node: &mut TSIntersectionType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_intersection_type(
+        &mut self,
+        _node: &mut TSIntersectionType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_operator_type(
+        &mut self,
+        _node: &mut TSTypeOperatorType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_operator_type(
+        &mut self,
+        _node: &mut TSTypeOperatorType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_array_type(&mut self, _node: &mut TSArrayType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_array_type(&mut self, _node: &mut TSArrayType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_indexed_access_type(
+        &mut self,
+        _node: &mut TSIndexedAccessType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_indexed_access_type(
+        &mut self,
+        _node: &mut TSIndexedAccessType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_tuple_type(&mut self, _node: &mut TSTupleType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_tuple_type(&mut self, _node: &mut TSTupleType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_named_tuple_member(
+        &mut self,
+        _node: &mut TSNamedTupleMember<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_named_tuple_member(
+        &mut self,
+        _node: &mut TSNamedTupleMember<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_optional_type(
+        &mut self,
+        _node: &mut TSOptionalType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_optional_type(
+        &mut self,
+        _node: &mut TSOptionalType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_rest_type(&mut self, _node: &mut TSRestType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_rest_type(&mut self, _node: &mut TSRestType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_tuple_element(
+        &mut self,
+        _node: &mut TSTupleElement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_tuple_element(
+        &mut self,
+        _node: &mut TSTupleElement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_any_keyword(&mut self, _node: &mut TSAnyKeyword, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_any_keyword(&mut self, _node: &mut TSAnyKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_string_keyword(&mut self, _node: &mut TSStringKeyword, _ctx: &mut TraverseCtx<'a>) {
+    }
+    fn exit_ts_string_keyword(&mut self, _node: &mut TSStringKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_boolean_keyword(
+        &mut self,
+        _node: &mut TSBooleanKeyword,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_boolean_keyword(
+        &mut self,
+        _node: &mut TSBooleanKeyword,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_number_keyword(&mut self, _node: &mut TSNumberKeyword, _ctx: &mut TraverseCtx<'a>) {
+    }
+    fn exit_ts_number_keyword(&mut self, _node: &mut TSNumberKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_never_keyword(&mut self, _node: &mut TSNeverKeyword, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_never_keyword(&mut self, _node: &mut TSNeverKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_unknown_keyword(
+        &mut self,
+        _node: &mut TSUnknownKeyword,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_unknown_keyword(
+        &mut self,
+        _node: &mut TSUnknownKeyword,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_null_keyword(&mut self, _node: &mut TSNullKeyword, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_null_keyword(&mut self, _node: &mut TSNullKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_undefined_keyword(
+        &mut self,
+        _node: &mut TSUndefinedKeyword,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_undefined_keyword(
+        &mut self,
+        _node: &mut TSUndefinedKeyword,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_void_keyword(&mut self, _node: &mut TSVoidKeyword, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_void_keyword(&mut self, _node: &mut TSVoidKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_symbol_keyword(&mut self, _node: &mut TSSymbolKeyword, _ctx: &mut TraverseCtx<'a>) {
+    }
+    fn exit_ts_symbol_keyword(&mut self, _node: &mut TSSymbolKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_this_keyword(&mut self, _node: &mut TSThisKeyword, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_this_keyword(&mut self, _node: &mut TSThisKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_object_keyword(&mut self, _node: &mut TSObjectKeyword, _ctx: &mut TraverseCtx<'a>) {
+    }
+    fn exit_ts_object_keyword(&mut self, _node: &mut TSObjectKeyword, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_big_int_keyword(
+        &mut self,
+        _node: &mut TSBigIntKeyword,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_big_int_keyword(&mut self, _node: &mut TSBigIntKeyword, _ctx: &mut TraverseCtx<'a>) {
+    }
+
+    fn enter_ts_type_reference(
+        &mut self,
+        _node: &mut TSTypeReference<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_reference(
+        &mut self,
+        _node: &mut TSTypeReference<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_name(&mut self, _node: &mut TSTypeName<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_type_name(&mut self, _node: &mut TSTypeName<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_qualified_name(
+        &mut self,
+        _node: &mut TSQualifiedName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_qualified_name(
+        &mut self,
+        _node: &mut TSQualifiedName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_parameter_instantiation(
+        &mut self,
+        _node: &mut TSTypeParameterInstantiation<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_parameter_instantiation(
+        &mut self,
+        _node: &mut TSTypeParameterInstantiation<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_parameter(
+        &mut self,
+        _node: &mut TSTypeParameter<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_parameter(
+        &mut self,
+        _node: &mut TSTypeParameter<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_parameter_declaration(
+        &mut self,
+        _node: &mut TSTypeParameterDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_parameter_declaration(
+        &mut self,
+        _node: &mut TSTypeParameterDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_alias_declaration(
+        &mut self,
+        _node: &mut TSTypeAliasDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_alias_declaration(
+        &mut self,
+        _node: &mut TSTypeAliasDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_abstract_method_definition(
+        &mut self,
+        _node: &mut TSAbstractMethodDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_abstract_method_definition(
+        &mut self,
+        _node: &mut TSAbstractMethodDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_abstract_property_definition(
+        &mut self,
+        _node: &mut TSAbstractPropertyDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_abstract_property_definition(
+        &mut self,
+        _node: &mut TSAbstractPropertyDefinition<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_class_implements(
+        &mut self,
+        _node: &mut TSClassImplements<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_class_implements(
+        &mut self,
+        _node: &mut TSClassImplements<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_interface_declaration(
+        &mut self,
+        _node: &mut TSInterfaceDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_interface_declaration(
+        &mut self,
+        _node: &mut TSInterfaceDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_interface_body(
+        &mut self,
+        _node: &mut TSInterfaceBody<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_interface_body(
+        &mut self,
+        _node: &mut TSInterfaceBody<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_property_signature(
+        &mut self,
+        _node: &mut TSPropertySignature<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_property_signature(
+        &mut self,
+        _node: &mut TSPropertySignature<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_signature(&mut self, _node: &mut TSSignature<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_signature(&mut self, _node: &mut TSSignature<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_index_signature(
+        &mut self,
+        _node: &mut TSIndexSignature<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_index_signature(
+        &mut self,
+        _node: &mut TSIndexSignature<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_call_signature_declaration(
+        &mut self,
+        _node: &mut TSCallSignatureDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_call_signature_declaration(
+        &mut self,
+        _node: &mut TSCallSignatureDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_method_signature(
+        &mut self,
+        _node: &mut TSMethodSignature<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_method_signature(
+        &mut self,
+        _node: &mut TSMethodSignature<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_construct_signature_declaration(
+        &mut self,
+        _node: &mut TSConstructSignatureDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_construct_signature_declaration(
+        &mut self,
+        _node: &mut TSConstructSignatureDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_index_signature_name(
+        &mut self,
+        _node: &mut TSIndexSignatureName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_index_signature_name(
+        &mut self,
+        _node: &mut TSIndexSignatureName<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_interface_heritage(
+        &mut self,
+        _node: &mut TSInterfaceHeritage<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_interface_heritage(
+        &mut self,
+        _node: &mut TSInterfaceHeritage<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_predicate(
+        &mut self,
+        _node: &mut TSTypePredicate<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_predicate(
+        &mut self,
+        _node: &mut TSTypePredicate<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_predicate_name(
+        &mut self,
+        _node: &mut TSTypePredicateName,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_predicate_name(
+        &mut self,
+        _node: &mut TSTypePredicateName,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_module_declaration(
+        &mut self,
+        _node: &mut TSModuleDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_module_declaration(
+        &mut self,
+        _node: &mut TSModuleDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_module_declaration_name(
+        &mut self,
+        _node: &mut TSModuleDeclarationName,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_module_declaration_name(
+        &mut self,
+        _node: &mut TSModuleDeclarationName,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_module_declaration_body(
+        &mut self,
+        _node: &mut TSModuleDeclarationBody<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_module_declaration_body(
+        &mut self,
+        _node: &mut TSModuleDeclarationBody<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_module_block(&mut self, _node: &mut TSModuleBlock<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+    fn exit_ts_module_block(&mut self, _node: &mut TSModuleBlock<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_type_literal(&mut self, _node: &mut TSTypeLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {
+    }
+    fn exit_ts_type_literal(&mut self, _node: &mut TSTypeLiteral<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_infer_type(&mut self, _node: &mut TSInferType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_infer_type(&mut self, _node: &mut TSInferType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_type_query(&mut self, _node: &mut TSTypeQuery<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_type_query(&mut self, _node: &mut TSTypeQuery<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_import_type(&mut self, _node: &mut TSImportType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_import_type(&mut self, _node: &mut TSImportType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_function_type(
+        &mut self,
+        _node: &mut TSFunctionType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_function_type(
+        &mut self,
+        _node: &mut TSFunctionType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_constructor_type(
+        &mut self,
+        _node: &mut TSConstructorType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_constructor_type(
+        &mut self,
+        _node: &mut TSConstructorType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_mapped_type(&mut self, _node: &mut TSMappedType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_ts_mapped_type(&mut self, _node: &mut TSMappedType<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_template_literal_type(
+        &mut self,
+        _node: &mut TSTemplateLiteralType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_template_literal_type(
+        &mut self,
+        _node: &mut TSTemplateLiteralType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_as_expression(
+        &mut self,
+        _node: &mut TSAsExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_as_expression(
+        &mut self,
+        _node: &mut TSAsExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_satisfies_expression(
+        &mut self,
+        _node: &mut TSSatisfiesExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_satisfies_expression(
+        &mut self,
+        _node: &mut TSSatisfiesExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_type_assertion(
+        &mut self,
+        _node: &mut TSTypeAssertion<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_type_assertion(
+        &mut self,
+        _node: &mut TSTypeAssertion<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_import_equals_declaration(
+        &mut self,
+        _node: &mut TSImportEqualsDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_import_equals_declaration(
+        &mut self,
+        _node: &mut TSImportEqualsDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_module_reference(
+        &mut self,
+        _node: &mut TSModuleReference<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_module_reference(
+        &mut self,
+        _node: &mut TSModuleReference<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_external_module_reference(
+        &mut self,
+        _node: &mut TSExternalModuleReference,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_external_module_reference(
+        &mut self,
+        _node: &mut TSExternalModuleReference,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_non_null_expression(
+        &mut self,
+        _node: &mut TSNonNullExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_non_null_expression(
+        &mut self,
+        _node: &mut TSNonNullExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_decorator(&mut self, _node: &mut Decorator<'a>, _ctx: &mut TraverseCtx<'a>) {}
+    fn exit_decorator(&mut self, _node: &mut Decorator<'a>, _ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_ts_export_assignment(
+        &mut self,
+        _node: &mut TSExportAssignment<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_export_assignment(
+        &mut self,
+        _node: &mut TSExportAssignment<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_namespace_export_declaration(
+        &mut self,
+        _node: &mut TSNamespaceExportDeclaration,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_namespace_export_declaration(
+        &mut self,
+        _node: &mut TSNamespaceExportDeclaration,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_ts_instantiation_expression(
+        &mut self,
+        _node: &mut TSInstantiationExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_ts_instantiation_expression(
+        &mut self,
+        _node: &mut TSInstantiationExpression<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_flow_opaque_type_declaration(
+        &mut self,
+        _node: &mut FlowOpaqueTypeDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_flow_opaque_type_declaration(
+        &mut self,
+        _node: &mut FlowOpaqueTypeDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_flow_declare_module_exports(
+        &mut self,
+        _node: &mut FlowDeclareModuleExports<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_flow_declare_module_exports(
+        &mut self,
+        _node: &mut FlowDeclareModuleExports<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_flow_exact_object_type(
+        &mut self,
+        _node: &mut FlowExactObjectType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_flow_exact_object_type(
+        &mut self,
+        _node: &mut FlowExactObjectType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_flow_existential_type(
+        &mut self,
+        _node: &mut FlowExistentialType,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_flow_existential_type(
+        &mut self,
+        _node: &mut FlowExistentialType,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_js_doc_nullable_type(
+        &mut self,
+        _node: &mut JSDocNullableType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_js_doc_nullable_type(
+        &mut self,
+        _node: &mut JSDocNullableType<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+
+    fn enter_js_doc_unknown_type(
+        &mut self,
+        _node: &mut JSDocUnknownType,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+    fn exit_js_doc_unknown_type(
+        &mut self,
+        _node: &mut JSDocUnknownType,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+    }
+}
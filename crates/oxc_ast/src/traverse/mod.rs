@@ -0,0 +1,44 @@
+//! AST traversal with `enter_*` and `exit_*` hooks for every node and the ancestors of each node
+//!
+//! Unlike [`VisitMut`](crate::visit_mut::VisitMut), which has one method per node,
+//! [`Traverse`] hooks are called both before and after the children of a node are walked,
+//! and receive a [`TraverseCtx`] which knows the ancestors of the node,
+//! e.g. whether an identifier is the callee of a call expression:
+//!
+//! ```ignore
+//! impl<'a> Traverse<'a> for MyTransform {
+//!     fn enter_identifier_reference(&mut self, ident: &mut IdentifierReference, ctx: &mut TraverseCtx<'a>) {
+//!         if ctx.parent() == Ancestor::CallExpressionCallee {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! traverse_mut(&mut MyTransform, &mut program, &allocator);
+//! ```
+//!
+//! `ancestor.rs`, `hooks.rs` and `walk.rs` are generated from the AST definitions,
+//! run `cargo run -p ast_codegen` after changing the AST.
+
+mod ancestor;
+mod context;
+mod hooks;
+mod walk;
+
+use oxc_allocator::Allocator;
+
+pub use self::ancestor::{Ancestor, AstType};
+pub use self::context::TraverseCtx;
+pub use self::hooks::Traverse;
+use crate::ast::Program;
+
+/// Walk `program` depth first in source order, calling the hooks of `traverser` for every node.
+pub fn traverse_mut<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    program: &mut Program<'a>,
+    allocator: &'a Allocator,
+) {
+    let mut ctx = TraverseCtx::new(allocator);
+    walk::walk_program(traverser, program, &mut ctx);
+    debug_assert_eq!(ctx.ancestors_depth(), 0);
+}
@@ -0,0 +1,3709 @@
+// Auto-generated by `cargo run -p ast_codegen`, do not edit.
+
+#![allow(clippy::too_many_lines)]
+
+use super::{Ancestor, Traverse, TraverseCtx};
+#[allow(clippy::wildcard_imports)]
+use crate::ast::*;
+
+pub(super) fn walk_program<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Program<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_program(node, ctx);
+    ctx.push_stack(Ancestor::ProgramDirectives);
+    for item in node.directives.iter_mut() {
+        walk_directive(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ProgramBody);
+    for item in node.body.iter_mut() {
+        walk_statement(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_program(node, ctx);
+}
+
+pub(super) fn walk_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_expression(node, ctx);
+    match node {
+        Expression::BooleanLiteral(node) => {
+            walk_boolean_literal(traverser, node, ctx);
+        }
+        Expression::NullLiteral(node) => {
+            walk_null_literal(traverser, node, ctx);
+        }
+        Expression::NumberLiteral(node) => {
+            walk_number_literal(traverser, node, ctx);
+        }
+        Expression::BigintLiteral(node) => {
+            walk_bigint_literal(traverser, node, ctx);
+        }
+        Expression::RegExpLiteral(node) => {
+            walk_reg_exp_literal(traverser, node, ctx);
+        }
+        Expression::StringLiteral(node) => {
+            walk_string_literal(traverser, node, ctx);
+        }
+        Expression::TemplateLiteral(node) => {
+            walk_template_literal(traverser, node, ctx);
+        }
+        Expression::Identifier(node) => {
+            walk_identifier_reference(traverser, node, ctx);
+        }
+        Expression::MetaProperty(node) => {
+            walk_meta_property(traverser, node, ctx);
+        }
+        Expression::Super(node) => {
+            walk_super(traverser, node, ctx);
+        }
+        Expression::ArrayExpression(node) => {
+            walk_array_expression(traverser, node, ctx);
+        }
+        Expression::ArrowFunctionExpression(node) => {
+            walk_arrow_expression(traverser, node, ctx);
+        }
+        Expression::AssignmentExpression(node) => {
+            walk_assignment_expression(traverser, node, ctx);
+        }
+        Expression::AwaitExpression(node) => {
+            walk_await_expression(traverser, node, ctx);
+        }
+        Expression::BinaryExpression(node) => {
+            walk_binary_expression(traverser, node, ctx);
+        }
+        Expression::CallExpression(node) => {
+            walk_call_expression(traverser, node, ctx);
+        }
+        Expression::ChainExpression(node) => {
+            walk_chain_expression(traverser, node, ctx);
+        }
+        Expression::ClassExpression(node) => {
+            walk_class(traverser, node, ctx);
+        }
+        Expression::ConditionalExpression(node) => {
+            walk_conditional_expression(traverser, node, ctx);
+        }
+        Expression::FunctionExpression(node) => {
+            walk_function(traverser, node, ctx);
+        }
+        Expression::ImportExpression(node) => {
+            walk_import_expression(traverser, node, ctx);
+        }
+        Expression::LogicalExpression(node) => {
+            walk_logical_expression(traverser, node, ctx);
+        }
+        Expression::MemberExpression(node) => {
+            walk_member_expression(traverser, node, ctx);
+        }
+        Expression::NewExpression(node) => {
+            walk_new_expression(traverser, node, ctx);
+        }
+        Expression::ObjectExpression(node) => {
+            walk_object_expression(traverser, node, ctx);
+        }
+        Expression::ParenthesizedExpression(node) => {
+            walk_parenthesized_expression(traverser, node, ctx);
+        }
+        Expression::SequenceExpression(node) => {
+            walk_sequence_expression(traverser, node, ctx);
+        }
+        Expression::TaggedTemplateExpression(node) => {
+            walk_tagged_template_expression(traverser, node, ctx);
+        }
+        Expression::ThisExpression(node) => {
+            walk_this_expression(traverser, node, ctx);
+        }
+        Expression::UnaryExpression(node) => {
+            walk_unary_expression(traverser, node, ctx);
+        }
+        Expression::UpdateExpression(node) => {
+            walk_update_expression(traverser, node, ctx);
+        }
+        Expression::YieldExpression(node) => {
+            walk_yield_expression(traverser, node, ctx);
+        }
+        Expression::PrivateInExpression(node) => {
+            walk_private_in_expression(traverser, node, ctx);
+        }
+        Expression::JSXElement(node) => {
+            walk_jsx_element(traverser, node, ctx);
+        }
+        Expression::JSXFragment(node) => {
+            walk_jsx_fragment(traverser, node, ctx);
+        }
+        Expression::TSAsExpression(node) => {
+            walk_ts_as_expression(traverser, node, ctx);
+        }
+        Expression::TSSatisfiesExpression(node) => {
+            walk_ts_satisfies_expression(traverser, node, ctx);
+        }
+        Expression::TSTypeAssertion(node) => {
+            walk_ts_type_assertion(traverser, node, ctx);
+        }
+        Expression::TSNonNullExpression(node) => {
+            walk_ts_non_null_expression(traverser, node, ctx);
+        }
+        Expression::TSInstantiationExpression(node) => {
+            walk_ts_instantiation_expression(traverser, node, ctx);
+        }
+        Expression::InvalidExpression(node) => {
+            walk_invalid_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_expression(node, ctx);
+}
+
+pub(super) fn walk_identifier_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut IdentifierName,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_identifier_name(node, ctx);
+    traverser.exit_identifier_name(node, ctx);
+}
+
+pub(super) fn walk_identifier_reference<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut IdentifierReference,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_identifier_reference(node, ctx);
+    traverser.exit_identifier_reference(node, ctx);
+}
+
+pub(super) fn walk_binding_identifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut BindingIdentifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_binding_identifier(node, ctx);
+    traverser.exit_binding_identifier(node, ctx);
+}
+
+pub(super) fn walk_label_identifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut LabelIdentifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_label_identifier(node, ctx);
+    traverser.exit_label_identifier(node, ctx);
+}
+
+pub(super) fn walk_this_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ThisExpression,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_this_expression(node, ctx);
+    traverser.exit_this_expression(node, ctx);
+}
+
+pub(super) fn walk_array_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ArrayExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_array_expression(node, ctx);
+    ctx.push_stack(Ancestor::ArrayExpressionElements);
+    for item in node.elements.iter_mut().flatten() {
+        walk_argument(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_array_expression(node, ctx);
+}
+
+pub(super) fn walk_object_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ObjectExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_object_expression(node, ctx);
+    ctx.push_stack(Ancestor::ObjectExpressionProperties);
+    for item in node.properties.iter_mut() {
+        walk_object_property(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_object_expression(node, ctx);
+}
+
+pub(super) fn walk_object_property<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ObjectProperty<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_object_property(node, ctx);
+    match node {
+        ObjectProperty::Property(node) => {
+            walk_property(traverser, node, ctx);
+        }
+        ObjectProperty::SpreadProperty(node) => {
+            walk_spread_element(traverser, node, ctx);
+        }
+    }
+    traverser.exit_object_property(node, ctx);
+}
+
+pub(super) fn walk_property<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Property<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_property(node, ctx);
+    ctx.push_stack(Ancestor::PropertyKey);
+    walk_property_key(traverser, &mut node.key, ctx);
+    ctx.retag_stack(Ancestor::PropertyValue);
+    walk_property_value(traverser, &mut node.value, ctx);
+    ctx.pop_stack();
+    traverser.exit_property(node, ctx);
+}
+
+pub(super) fn walk_property_key<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut PropertyKey<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_property_key(node, ctx);
+    match node {
+        PropertyKey::Identifier(node) => {
+            walk_identifier_name(traverser, node, ctx);
+        }
+        PropertyKey::PrivateIdentifier(node) => {
+            walk_private_identifier(traverser, node, ctx);
+        }
+        PropertyKey::Expression(node) => {
+            walk_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_property_key(node, ctx);
+}
+
+pub(super) fn walk_property_value<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut PropertyValue<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_property_value(node, ctx);
+    match node {
+        PropertyValue::Pattern(node) => {
+            walk_binding_pattern(traverser, node, ctx);
+        }
+        PropertyValue::Expression(node) => {
+            walk_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_property_value(node, ctx);
+}
+
+pub(super) fn walk_template_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TemplateLiteral<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_template_literal(node, ctx);
+    ctx.push_stack(Ancestor::TemplateLiteralQuasis);
+    for item in node.quasis.iter_mut() {
+        walk_template_element(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TemplateLiteralExpressions);
+    for item in node.expressions.iter_mut() {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_template_literal(node, ctx);
+}
+
+pub(super) fn walk_tagged_template_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TaggedTemplateExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_tagged_template_expression(node, ctx);
+    ctx.push_stack(Ancestor::TaggedTemplateExpressionTag);
+    walk_expression(traverser, &mut node.tag, ctx);
+    ctx.retag_stack(Ancestor::TaggedTemplateExpressionQuasi);
+    walk_template_literal(traverser, &mut node.quasi, ctx);
+    ctx.retag_stack(Ancestor::TaggedTemplateExpressionTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_tagged_template_expression(node, ctx);
+}
+
+pub(super) fn walk_template_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TemplateElement,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_template_element(node, ctx);
+    traverser.exit_template_element(node, ctx);
+}
+
+pub(super) fn walk_member_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut MemberExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_member_expression(node, ctx);
+    match node {
+        MemberExpression::ComputedMemberExpression(node) => {
+            walk_computed_member_expression(traverser, node, ctx);
+        }
+        MemberExpression::StaticMemberExpression(node) => {
+            walk_static_member_expression(traverser, node, ctx);
+        }
+        MemberExpression::PrivateFieldExpression(node) => {
+            walk_private_field_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_member_expression(node, ctx);
+}
+
+pub(super) fn walk_computed_member_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ComputedMemberExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_computed_member_expression(node, ctx);
+    ctx.push_stack(Ancestor::ComputedMemberExpressionObject);
+    walk_expression(traverser, &mut node.object, ctx);
+    ctx.retag_stack(Ancestor::ComputedMemberExpressionExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_computed_member_expression(node, ctx);
+}
+
+pub(super) fn walk_static_member_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut StaticMemberExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_static_member_expression(node, ctx);
+    ctx.push_stack(Ancestor::StaticMemberExpressionObject);
+    walk_expression(traverser, &mut node.object, ctx);
+    ctx.retag_stack(Ancestor::StaticMemberExpressionProperty);
+    walk_identifier_name(traverser, &mut node.property, ctx);
+    ctx.pop_stack();
+    traverser.exit_static_member_expression(node, ctx);
+}
+
+pub(super) fn walk_private_field_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut PrivateFieldExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_private_field_expression(node, ctx);
+    ctx.push_stack(Ancestor::PrivateFieldExpressionObject);
+    walk_expression(traverser, &mut node.object, ctx);
+    ctx.retag_stack(Ancestor::PrivateFieldExpressionField);
+    walk_private_identifier(traverser, &mut node.field, ctx);
+    ctx.pop_stack();
+    traverser.exit_private_field_expression(node, ctx);
+}
+
+pub(super) fn walk_call_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut CallExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_call_expression(node, ctx);
+    ctx.push_stack(Ancestor::CallExpressionCallee);
+    walk_expression(traverser, &mut node.callee, ctx);
+    ctx.retag_stack(Ancestor::CallExpressionArguments);
+    for item in node.arguments.iter_mut() {
+        walk_argument(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::CallExpressionTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_call_expression(node, ctx);
+}
+
+pub(super) fn walk_new_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut NewExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_new_expression(node, ctx);
+    ctx.push_stack(Ancestor::NewExpressionCallee);
+    walk_expression(traverser, &mut node.callee, ctx);
+    ctx.retag_stack(Ancestor::NewExpressionArguments);
+    for item in node.arguments.iter_mut() {
+        walk_argument(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::NewExpressionTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_new_expression(node, ctx);
+}
+
+pub(super) fn walk_meta_property<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut MetaProperty,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_meta_property(node, ctx);
+    ctx.push_stack(Ancestor::MetaPropertyMeta);
+    walk_identifier_name(traverser, &mut node.meta, ctx);
+    ctx.retag_stack(Ancestor::MetaPropertyProperty);
+    walk_identifier_name(traverser, &mut node.property, ctx);
+    ctx.pop_stack();
+    traverser.exit_meta_property(node, ctx);
+}
+
+pub(super) fn walk_spread_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut SpreadElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_spread_element(node, ctx);
+    ctx.push_stack(Ancestor::SpreadElementArgument);
+    walk_expression(traverser, &mut node.argument, ctx);
+    ctx.pop_stack();
+    traverser.exit_spread_element(node, ctx);
+}
+
+pub(super) fn walk_argument<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Argument<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_argument(node, ctx);
+    match node {
+        Argument::SpreadElement(node) => {
+            walk_spread_element(traverser, node, ctx);
+        }
+        Argument::Expression(node) => {
+            walk_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_argument(node, ctx);
+}
+
+pub(super) fn walk_update_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut UpdateExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_update_expression(node, ctx);
+    ctx.push_stack(Ancestor::UpdateExpressionArgument);
+    walk_simple_assignment_target(traverser, &mut node.argument, ctx);
+    ctx.pop_stack();
+    traverser.exit_update_expression(node, ctx);
+}
+
+pub(super) fn walk_unary_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut UnaryExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_unary_expression(node, ctx);
+    ctx.push_stack(Ancestor::UnaryExpressionArgument);
+    walk_expression(traverser, &mut node.argument, ctx);
+    ctx.pop_stack();
+    traverser.exit_unary_expression(node, ctx);
+}
+
+pub(super) fn walk_binary_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut BinaryExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_binary_expression(node, ctx);
+    ctx.push_stack(Ancestor::BinaryExpressionLeft);
+    walk_expression(traverser, &mut node.left, ctx);
+    ctx.retag_stack(Ancestor::BinaryExpressionRight);
+    walk_expression(traverser, &mut node.right, ctx);
+    ctx.pop_stack();
+    traverser.exit_binary_expression(node, ctx);
+}
+
+pub(super) fn walk_private_in_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut PrivateInExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_private_in_expression(node, ctx);
+    ctx.push_stack(Ancestor::PrivateInExpressionLeft);
+    walk_private_identifier(traverser, &mut node.left, ctx);
+    ctx.retag_stack(Ancestor::PrivateInExpressionRight);
+    walk_expression(traverser, &mut node.right, ctx);
+    ctx.pop_stack();
+    traverser.exit_private_in_expression(node, ctx);
+}
+
+pub(super) fn walk_logical_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut LogicalExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_logical_expression(node, ctx);
+    ctx.push_stack(Ancestor::LogicalExpressionLeft);
+    walk_expression(traverser, &mut node.left, ctx);
+    ctx.retag_stack(Ancestor::LogicalExpressionRight);
+    walk_expression(traverser, &mut node.right, ctx);
+    ctx.pop_stack();
+    traverser.exit_logical_expression(node, ctx);
+}
+
+pub(super) fn walk_conditional_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ConditionalExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_conditional_expression(node, ctx);
+    ctx.push_stack(Ancestor::ConditionalExpressionTest);
+    walk_expression(traverser, &mut node.test, ctx);
+    ctx.retag_stack(Ancestor::ConditionalExpressionConsequent);
+    walk_expression(traverser, &mut node.consequent, ctx);
+    ctx.retag_stack(Ancestor::ConditionalExpressionAlternate);
+    walk_expression(traverser, &mut node.alternate, ctx);
+    ctx.pop_stack();
+    traverser.exit_conditional_expression(node, ctx);
+}
+
+pub(super) fn walk_assignment_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_expression(node, ctx);
+    ctx.push_stack(Ancestor::AssignmentExpressionLeft);
+    walk_assignment_target(traverser, &mut node.left, ctx);
+    ctx.retag_stack(Ancestor::AssignmentExpressionRight);
+    walk_expression(traverser, &mut node.right, ctx);
+    ctx.pop_stack();
+    traverser.exit_assignment_expression(node, ctx);
+}
+
+pub(super) fn walk_assignment_target<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentTarget<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_target(node, ctx);
+    match node {
+        AssignmentTarget::SimpleAssignmentTarget(node) => {
+            walk_simple_assignment_target(traverser, node, ctx);
+        }
+        AssignmentTarget::AssignmentTargetPattern(node) => {
+            walk_assignment_target_pattern(traverser, node, ctx);
+        }
+    }
+    traverser.exit_assignment_target(node, ctx);
+}
+
+pub(super) fn walk_simple_assignment_target<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut SimpleAssignmentTarget<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_simple_assignment_target(node, ctx);
+    match node {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(node) => {
+            walk_identifier_reference(traverser, node, ctx);
+        }
+        SimpleAssignmentTarget::MemberAssignmentTarget(node) => {
+            walk_member_expression(traverser, node, ctx);
+        }
+        SimpleAssignmentTarget::TSAsExpression(node) => {
+            walk_ts_as_expression(traverser, node, ctx);
+        }
+        SimpleAssignmentTarget::TSSatisfiesExpression(node) => {
+            walk_ts_satisfies_expression(traverser, node, ctx);
+        }
+        SimpleAssignmentTarget::TSNonNullExpression(node) => {
+            walk_ts_non_null_expression(traverser, node, ctx);
+        }
+        SimpleAssignmentTarget::TSTypeAssertion(node) => {
+            walk_ts_type_assertion(traverser, node, ctx);
+        }
+    }
+    traverser.exit_simple_assignment_target(node, ctx);
+}
+
+pub(super) fn walk_assignment_target_pattern<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentTargetPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_target_pattern(node, ctx);
+    match node {
+        AssignmentTargetPattern::ArrayAssignmentTarget(node) => {
+            walk_array_assignment_target(traverser, node, ctx);
+        }
+        AssignmentTargetPattern::ObjectAssignmentTarget(node) => {
+            walk_object_assignment_target(traverser, node, ctx);
+        }
+    }
+    traverser.exit_assignment_target_pattern(node, ctx);
+}
+
+pub(super) fn walk_array_assignment_target<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ArrayAssignmentTarget<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_array_assignment_target(node, ctx);
+    ctx.push_stack(Ancestor::ArrayAssignmentTargetElements);
+    for item in node.elements.iter_mut().flatten() {
+        walk_assignment_target_maybe_default(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ArrayAssignmentTargetRest);
+    if let Some(item) = &mut node.rest {
+        walk_assignment_target(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_array_assignment_target(node, ctx);
+}
+
+pub(super) fn walk_object_assignment_target<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ObjectAssignmentTarget<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_object_assignment_target(node, ctx);
+    ctx.push_stack(Ancestor::ObjectAssignmentTargetProperties);
+    for item in node.properties.iter_mut() {
+        walk_assignment_target_property(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ObjectAssignmentTargetRest);
+    if let Some(item) = &mut node.rest {
+        walk_assignment_target(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_object_assignment_target(node, ctx);
+}
+
+pub(super) fn walk_assignment_target_maybe_default<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentTargetMaybeDefault<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_target_maybe_default(node, ctx);
+    match node {
+        AssignmentTargetMaybeDefault::AssignmentTarget(node) => {
+            walk_assignment_target(traverser, node, ctx);
+        }
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(node) => {
+            walk_assignment_target_with_default(traverser, node, ctx);
+        }
+    }
+    traverser.exit_assignment_target_maybe_default(node, ctx);
+}
+
+pub(super) fn walk_assignment_target_with_default<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentTargetWithDefault<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_target_with_default(node, ctx);
+    ctx.push_stack(Ancestor::AssignmentTargetWithDefaultBinding);
+    walk_assignment_target(traverser, &mut node.binding, ctx);
+    ctx.retag_stack(Ancestor::AssignmentTargetWithDefaultInit);
+    walk_expression(traverser, &mut node.init, ctx);
+    ctx.pop_stack();
+    traverser.exit_assignment_target_with_default(node, ctx);
+}
+
+pub(super) fn walk_assignment_target_property<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentTargetProperty<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_target_property(node, ctx);
+    match node {
+        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(node) => {
+            walk_assignment_target_property_identifier(traverser, node, ctx);
+        }
+        AssignmentTargetProperty::AssignmentTargetPropertyProperty(node) => {
+            walk_assignment_target_property_property(traverser, node, ctx);
+        }
+    }
+    traverser.exit_assignment_target_property(node, ctx);
+}
+
+pub(super) fn walk_assignment_target_property_identifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentTargetPropertyIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_target_property_identifier(node, ctx);
+    ctx.push_stack(Ancestor::AssignmentTargetPropertyIdentifierBinding);
+    walk_identifier_reference(traverser, &mut node.binding, ctx);
+    ctx.retag_stack(Ancestor::AssignmentTargetPropertyIdentifierInit);
+    if let Some(item) = &mut node.init {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_assignment_target_property_identifier(node, ctx);
+}
+
+pub(super) fn walk_assignment_target_property_property<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentTargetPropertyProperty<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_target_property_property(node, ctx);
+    ctx.push_stack(Ancestor::AssignmentTargetPropertyPropertyName);
+    walk_property_key(traverser, &mut node.name, ctx);
+    ctx.retag_stack(Ancestor::AssignmentTargetPropertyPropertyBinding);
+    walk_assignment_target_maybe_default(traverser, &mut node.binding, ctx);
+    ctx.pop_stack();
+    traverser.exit_assignment_target_property_property(node, ctx);
+}
+
+pub(super) fn walk_sequence_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut SequenceExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_sequence_expression(node, ctx);
+    ctx.push_stack(Ancestor::SequenceExpressionExpressions);
+    for item in node.expressions.iter_mut() {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_sequence_expression(node, ctx);
+}
+
+pub(super) fn walk_super<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Super,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_super(node, ctx);
+    traverser.exit_super(node, ctx);
+}
+
+pub(super) fn walk_await_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AwaitExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_await_expression(node, ctx);
+    ctx.push_stack(Ancestor::AwaitExpressionArgument);
+    walk_expression(traverser, &mut node.argument, ctx);
+    ctx.pop_stack();
+    traverser.exit_await_expression(node, ctx);
+}
+
+pub(super) fn walk_chain_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ChainExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_chain_expression(node, ctx);
+    ctx.push_stack(Ancestor::ChainExpressionExpression);
+    walk_chain_element(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_chain_expression(node, ctx);
+}
+
+pub(super) fn walk_chain_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ChainElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_chain_element(node, ctx);
+    match node {
+        ChainElement::CallExpression(node) => {
+            walk_call_expression(traverser, node, ctx);
+        }
+        ChainElement::MemberExpression(node) => {
+            walk_member_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_chain_element(node, ctx);
+}
+
+pub(super) fn walk_parenthesized_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ParenthesizedExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_parenthesized_expression(node, ctx);
+    ctx.push_stack(Ancestor::ParenthesizedExpressionExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_parenthesized_expression(node, ctx);
+}
+
+pub(super) fn walk_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Statement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_statement(node, ctx);
+    match node {
+        Statement::BlockStatement(node) => {
+            walk_block_statement(traverser, node, ctx);
+        }
+        Statement::BreakStatement(node) => {
+            walk_break_statement(traverser, node, ctx);
+        }
+        Statement::ContinueStatement(node) => {
+            walk_continue_statement(traverser, node, ctx);
+        }
+        Statement::DebuggerStatement(node) => {
+            walk_debugger_statement(traverser, node, ctx);
+        }
+        Statement::DoWhileStatement(node) => {
+            walk_do_while_statement(traverser, node, ctx);
+        }
+        Statement::EmptyStatement(node) => {
+            walk_empty_statement(traverser, node, ctx);
+        }
+        Statement::ExpressionStatement(node) => {
+            walk_expression_statement(traverser, node, ctx);
+        }
+        Statement::ForInStatement(node) => {
+            walk_for_in_statement(traverser, node, ctx);
+        }
+        Statement::ForOfStatement(node) => {
+            walk_for_of_statement(traverser, node, ctx);
+        }
+        Statement::ForStatement(node) => {
+            walk_for_statement(traverser, node, ctx);
+        }
+        Statement::IfStatement(node) => {
+            walk_if_statement(traverser, node, ctx);
+        }
+        Statement::LabeledStatement(node) => {
+            walk_labeled_statement(traverser, node, ctx);
+        }
+        Statement::ReturnStatement(node) => {
+            walk_return_statement(traverser, node, ctx);
+        }
+        Statement::SwitchStatement(node) => {
+            walk_switch_statement(traverser, node, ctx);
+        }
+        Statement::ThrowStatement(node) => {
+            walk_throw_statement(traverser, node, ctx);
+        }
+        Statement::TryStatement(node) => {
+            walk_try_statement(traverser, node, ctx);
+        }
+        Statement::WhileStatement(node) => {
+            walk_while_statement(traverser, node, ctx);
+        }
+        Statement::WithStatement(node) => {
+            walk_with_statement(traverser, node, ctx);
+        }
+        Statement::ModuleDeclaration(node) => {
+            walk_module_declaration(traverser, node, ctx);
+        }
+        Statement::Declaration(node) => {
+            walk_declaration(traverser, node, ctx);
+        }
+        Statement::InvalidStatement(node) => {
+            walk_invalid_statement(traverser, node, ctx);
+        }
+    }
+    traverser.exit_statement(node, ctx);
+}
+
+pub(super) fn walk_directive<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Directive<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_directive(node, ctx);
+    ctx.push_stack(Ancestor::DirectiveExpression);
+    walk_string_literal(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_directive(node, ctx);
+}
+
+pub(super) fn walk_block_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut BlockStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_block_statement(node, ctx);
+    ctx.push_stack(Ancestor::BlockStatementBody);
+    for item in node.body.iter_mut() {
+        walk_statement(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_block_statement(node, ctx);
+}
+
+pub(super) fn walk_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Declaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_declaration(node, ctx);
+    match node {
+        Declaration::VariableDeclaration(node) => {
+            walk_variable_declaration(traverser, node, ctx);
+        }
+        Declaration::FunctionDeclaration(node) => {
+            walk_function(traverser, node, ctx);
+        }
+        Declaration::ClassDeclaration(node) => {
+            walk_class(traverser, node, ctx);
+        }
+        Declaration::TSTypeAliasDeclaration(node) => {
+            walk_ts_type_alias_declaration(traverser, node, ctx);
+        }
+        Declaration::TSInterfaceDeclaration(node) => {
+            walk_ts_interface_declaration(traverser, node, ctx);
+        }
+        Declaration::TSEnumDeclaration(node) => {
+            walk_ts_enum_declaration(traverser, node, ctx);
+        }
+        Declaration::TSModuleDeclaration(node) => {
+            walk_ts_module_declaration(traverser, node, ctx);
+        }
+        Declaration::TSImportEqualsDeclaration(node) => {
+            walk_ts_import_equals_declaration(traverser, node, ctx);
+        }
+        Declaration::FlowOpaqueTypeDeclaration(node) => {
+            walk_flow_opaque_type_declaration(traverser, node, ctx);
+        }
+        Declaration::FlowDeclareModuleExports(node) => {
+            walk_flow_declare_module_exports(traverser, node, ctx);
+        }
+    }
+    traverser.exit_declaration(node, ctx);
+}
+
+pub(super) fn walk_variable_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut VariableDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_variable_declaration(node, ctx);
+    ctx.push_stack(Ancestor::VariableDeclarationDeclarations);
+    for item in node.declarations.iter_mut() {
+        walk_variable_declarator(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_variable_declaration(node, ctx);
+}
+
+pub(super) fn walk_variable_declarator<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut VariableDeclarator<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_variable_declarator(node, ctx);
+    ctx.push_stack(Ancestor::VariableDeclaratorId);
+    walk_binding_pattern(traverser, &mut node.id, ctx);
+    ctx.retag_stack(Ancestor::VariableDeclaratorInit);
+    if let Some(item) = &mut node.init {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_variable_declarator(node, ctx);
+}
+
+pub(super) fn walk_empty_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut EmptyStatement,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_empty_statement(node, ctx);
+    traverser.exit_empty_statement(node, ctx);
+}
+
+pub(super) fn walk_expression_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ExpressionStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_expression_statement(node, ctx);
+    ctx.push_stack(Ancestor::ExpressionStatementExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_expression_statement(node, ctx);
+}
+
+pub(super) fn walk_if_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut IfStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_if_statement(node, ctx);
+    ctx.push_stack(Ancestor::IfStatementTest);
+    walk_expression(traverser, &mut node.test, ctx);
+    ctx.retag_stack(Ancestor::IfStatementConsequent);
+    walk_statement(traverser, &mut node.consequent, ctx);
+    ctx.retag_stack(Ancestor::IfStatementAlternate);
+    if let Some(item) = &mut node.alternate {
+        walk_statement(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_if_statement(node, ctx);
+}
+
+pub(super) fn walk_do_while_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut DoWhileStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_do_while_statement(node, ctx);
+    ctx.push_stack(Ancestor::DoWhileStatementBody);
+    walk_statement(traverser, &mut node.body, ctx);
+    ctx.retag_stack(Ancestor::DoWhileStatementTest);
+    walk_expression(traverser, &mut node.test, ctx);
+    ctx.pop_stack();
+    traverser.exit_do_while_statement(node, ctx);
+}
+
+pub(super) fn walk_while_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut WhileStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_while_statement(node, ctx);
+    ctx.push_stack(Ancestor::WhileStatementTest);
+    walk_expression(traverser, &mut node.test, ctx);
+    ctx.retag_stack(Ancestor::WhileStatementBody);
+    walk_statement(traverser, &mut node.body, ctx);
+    ctx.pop_stack();
+    traverser.exit_while_statement(node, ctx);
+}
+
+pub(super) fn walk_for_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ForStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_for_statement(node, ctx);
+    ctx.push_stack(Ancestor::ForStatementInit);
+    if let Some(item) = &mut node.init {
+        walk_for_statement_init(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ForStatementTest);
+    if let Some(item) = &mut node.test {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ForStatementUpdate);
+    if let Some(item) = &mut node.update {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ForStatementBody);
+    walk_statement(traverser, &mut node.body, ctx);
+    ctx.pop_stack();
+    traverser.exit_for_statement(node, ctx);
+}
+
+pub(super) fn walk_for_statement_init<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ForStatementInit<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_for_statement_init(node, ctx);
+    match node {
+        ForStatementInit::VariableDeclaration(node) => {
+            walk_variable_declaration(traverser, node, ctx);
+        }
+        ForStatementInit::Expression(node) => {
+            walk_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_for_statement_init(node, ctx);
+}
+
+pub(super) fn walk_for_in_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ForInStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_for_in_statement(node, ctx);
+    ctx.push_stack(Ancestor::ForInStatementLeft);
+    walk_for_statement_left(traverser, &mut node.left, ctx);
+    ctx.retag_stack(Ancestor::ForInStatementRight);
+    walk_expression(traverser, &mut node.right, ctx);
+    ctx.retag_stack(Ancestor::ForInStatementBody);
+    walk_statement(traverser, &mut node.body, ctx);
+    ctx.pop_stack();
+    traverser.exit_for_in_statement(node, ctx);
+}
+
+pub(super) fn walk_for_of_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ForOfStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_for_of_statement(node, ctx);
+    ctx.push_stack(Ancestor::ForOfStatementLeft);
+    walk_for_statement_left(traverser, &mut node.left, ctx);
+    ctx.retag_stack(Ancestor::ForOfStatementRight);
+    walk_expression(traverser, &mut node.right, ctx);
+    ctx.retag_stack(Ancestor::ForOfStatementBody);
+    walk_statement(traverser, &mut node.body, ctx);
+    ctx.pop_stack();
+    traverser.exit_for_of_statement(node, ctx);
+}
+
+pub(super) fn walk_for_statement_left<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ForStatementLeft<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_for_statement_left(node, ctx);
+    match node {
+        ForStatementLeft::VariableDeclaration(node) => {
+            walk_variable_declaration(traverser, node, ctx);
+        }
+        ForStatementLeft::AssignmentTarget(node) => {
+            walk_assignment_target(traverser, node, ctx);
+        }
+    }
+    traverser.exit_for_statement_left(node, ctx);
+}
+
+pub(super) fn walk_continue_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ContinueStatement,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_continue_statement(node, ctx);
+    ctx.push_stack(Ancestor::ContinueStatementLabel);
+    if let Some(item) = &mut node.label {
+        walk_label_identifier(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_continue_statement(node, ctx);
+}
+
+pub(super) fn walk_break_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut BreakStatement,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_break_statement(node, ctx);
+    ctx.push_stack(Ancestor::BreakStatementLabel);
+    if let Some(item) = &mut node.label {
+        walk_label_identifier(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_break_statement(node, ctx);
+}
+
+pub(super) fn walk_return_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ReturnStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_return_statement(node, ctx);
+    ctx.push_stack(Ancestor::ReturnStatementArgument);
+    if let Some(item) = &mut node.argument {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_return_statement(node, ctx);
+}
+
+pub(super) fn walk_with_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut WithStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_with_statement(node, ctx);
+    ctx.push_stack(Ancestor::WithStatementObject);
+    walk_expression(traverser, &mut node.object, ctx);
+    ctx.retag_stack(Ancestor::WithStatementBody);
+    walk_statement(traverser, &mut node.body, ctx);
+    ctx.pop_stack();
+    traverser.exit_with_statement(node, ctx);
+}
+
+pub(super) fn walk_switch_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut SwitchStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_switch_statement(node, ctx);
+    ctx.push_stack(Ancestor::SwitchStatementDiscriminant);
+    walk_expression(traverser, &mut node.discriminant, ctx);
+    ctx.retag_stack(Ancestor::SwitchStatementCases);
+    for item in node.cases.iter_mut() {
+        walk_switch_case(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_switch_statement(node, ctx);
+}
+
+pub(super) fn walk_switch_case<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut SwitchCase<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_switch_case(node, ctx);
+    ctx.push_stack(Ancestor::SwitchCaseTest);
+    if let Some(item) = &mut node.test {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::SwitchCaseConsequent);
+    for item in node.consequent.iter_mut() {
+        walk_statement(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_switch_case(node, ctx);
+}
+
+pub(super) fn walk_labeled_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut LabeledStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_labeled_statement(node, ctx);
+    ctx.push_stack(Ancestor::LabeledStatementLabel);
+    walk_label_identifier(traverser, &mut node.label, ctx);
+    ctx.retag_stack(Ancestor::LabeledStatementBody);
+    walk_statement(traverser, &mut node.body, ctx);
+    ctx.pop_stack();
+    traverser.exit_labeled_statement(node, ctx);
+}
+
+pub(super) fn walk_throw_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ThrowStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_throw_statement(node, ctx);
+    ctx.push_stack(Ancestor::ThrowStatementArgument);
+    walk_expression(traverser, &mut node.argument, ctx);
+    ctx.pop_stack();
+    traverser.exit_throw_statement(node, ctx);
+}
+
+pub(super) fn walk_try_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TryStatement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_try_statement(node, ctx);
+    ctx.push_stack(Ancestor::TryStatementBlock);
+    walk_block_statement(traverser, &mut node.block, ctx);
+    ctx.retag_stack(Ancestor::TryStatementHandler);
+    if let Some(item) = &mut node.handler {
+        walk_catch_clause(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TryStatementFinalizer);
+    if let Some(item) = &mut node.finalizer {
+        walk_block_statement(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_try_statement(node, ctx);
+}
+
+pub(super) fn walk_catch_clause<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut CatchClause<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_catch_clause(node, ctx);
+    ctx.push_stack(Ancestor::CatchClauseParam);
+    if let Some(item) = &mut node.param {
+        walk_binding_pattern(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::CatchClauseBody);
+    walk_block_statement(traverser, &mut node.body, ctx);
+    ctx.pop_stack();
+    traverser.exit_catch_clause(node, ctx);
+}
+
+pub(super) fn walk_debugger_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut DebuggerStatement,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_debugger_statement(node, ctx);
+    traverser.exit_debugger_statement(node, ctx);
+}
+
+pub(super) fn walk_invalid_statement<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut InvalidStatement,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_invalid_statement(node, ctx);
+    traverser.exit_invalid_statement(node, ctx);
+}
+
+pub(super) fn walk_invalid_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut InvalidExpression,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_invalid_expression(node, ctx);
+    traverser.exit_invalid_expression(node, ctx);
+}
+
+pub(super) fn walk_binding_pattern<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_binding_pattern(node, ctx);
+    ctx.push_stack(Ancestor::BindingPatternKind);
+    walk_binding_pattern_kind(traverser, &mut node.kind, ctx);
+    ctx.retag_stack(Ancestor::BindingPatternTypeAnnotation);
+    if let Some(item) = &mut node.type_annotation {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_binding_pattern(node, ctx);
+}
+
+pub(super) fn walk_binding_pattern_kind<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut BindingPatternKind<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_binding_pattern_kind(node, ctx);
+    match node {
+        BindingPatternKind::BindingIdentifier(node) => {
+            walk_binding_identifier(traverser, node, ctx);
+        }
+        BindingPatternKind::ObjectPattern(node) => {
+            walk_object_pattern(traverser, node, ctx);
+        }
+        BindingPatternKind::ArrayPattern(node) => {
+            walk_array_pattern(traverser, node, ctx);
+        }
+        BindingPatternKind::RestElement(node) => {
+            walk_rest_element(traverser, node, ctx);
+        }
+        BindingPatternKind::AssignmentPattern(node) => {
+            walk_assignment_pattern(traverser, node, ctx);
+        }
+    }
+    traverser.exit_binding_pattern_kind(node, ctx);
+}
+
+pub(super) fn walk_assignment_pattern<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AssignmentPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_assignment_pattern(node, ctx);
+    ctx.push_stack(Ancestor::AssignmentPatternLeft);
+    walk_binding_pattern(traverser, &mut node.left, ctx);
+    ctx.retag_stack(Ancestor::AssignmentPatternRight);
+    walk_expression(traverser, &mut node.right, ctx);
+    ctx.pop_stack();
+    traverser.exit_assignment_pattern(node, ctx);
+}
+
+pub(super) fn walk_object_pattern<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ObjectPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_object_pattern(node, ctx);
+    ctx.push_stack(Ancestor::ObjectPatternProperties);
+    for item in node.properties.iter_mut() {
+        walk_object_pattern_property(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_object_pattern(node, ctx);
+}
+
+pub(super) fn walk_object_pattern_property<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ObjectPatternProperty<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_object_pattern_property(node, ctx);
+    match node {
+        ObjectPatternProperty::Property(node) => {
+            walk_property(traverser, node, ctx);
+        }
+        ObjectPatternProperty::RestElement(node) => {
+            walk_rest_element(traverser, node, ctx);
+        }
+    }
+    traverser.exit_object_pattern_property(node, ctx);
+}
+
+pub(super) fn walk_array_pattern<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ArrayPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_array_pattern(node, ctx);
+    ctx.push_stack(Ancestor::ArrayPatternElements);
+    for item in node.elements.iter_mut().flatten() {
+        walk_binding_pattern(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_array_pattern(node, ctx);
+}
+
+pub(super) fn walk_rest_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut RestElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_rest_element(node, ctx);
+    ctx.push_stack(Ancestor::RestElementArgument);
+    walk_binding_pattern(traverser, &mut node.argument, ctx);
+    ctx.pop_stack();
+    traverser.exit_rest_element(node, ctx);
+}
+
+pub(super) fn walk_function<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Function<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_function(node, ctx);
+    ctx.push_stack(Ancestor::FunctionId);
+    if let Some(item) = &mut node.id {
+        walk_binding_identifier(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::FunctionParams);
+    walk_formal_parameters(traverser, &mut node.params, ctx);
+    ctx.retag_stack(Ancestor::FunctionBody);
+    if let Some(item) = &mut node.body {
+        walk_function_body(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::FunctionTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::FunctionReturnType);
+    if let Some(item) = &mut node.return_type {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_function(node, ctx);
+}
+
+pub(super) fn walk_formal_parameters<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut FormalParameters<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_formal_parameters(node, ctx);
+    ctx.push_stack(Ancestor::FormalParametersItems);
+    for item in node.items.iter_mut() {
+        walk_formal_parameter(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_formal_parameters(node, ctx);
+}
+
+pub(super) fn walk_formal_parameter<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut FormalParameter<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_formal_parameter(node, ctx);
+    ctx.push_stack(Ancestor::FormalParameterPattern);
+    walk_binding_pattern(traverser, &mut node.pattern, ctx);
+    ctx.retag_stack(Ancestor::FormalParameterDecorators);
+    for item in node.decorators.iter_mut() {
+        walk_decorator(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_formal_parameter(node, ctx);
+}
+
+pub(super) fn walk_function_body<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut FunctionBody<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_function_body(node, ctx);
+    ctx.push_stack(Ancestor::FunctionBodyDirectives);
+    for item in node.directives.iter_mut() {
+        walk_directive(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::FunctionBodyStatements);
+    for item in node.statements.iter_mut() {
+        walk_statement(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_function_body(node, ctx);
+}
+
+pub(super) fn walk_arrow_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ArrowExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_arrow_expression(node, ctx);
+    ctx.push_stack(Ancestor::ArrowExpressionParams);
+    walk_formal_parameters(traverser, &mut node.params, ctx);
+    ctx.retag_stack(Ancestor::ArrowExpressionBody);
+    walk_function_body(traverser, &mut node.body, ctx);
+    ctx.retag_stack(Ancestor::ArrowExpressionTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ArrowExpressionReturnType);
+    if let Some(item) = &mut node.return_type {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_arrow_expression(node, ctx);
+}
+
+pub(super) fn walk_yield_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut YieldExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_yield_expression(node, ctx);
+    ctx.push_stack(Ancestor::YieldExpressionArgument);
+    if let Some(item) = &mut node.argument {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_yield_expression(node, ctx);
+}
+
+pub(super) fn walk_class<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Class<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_class(node, ctx);
+    ctx.push_stack(Ancestor::ClassId);
+    if let Some(item) = &mut node.id {
+        walk_binding_identifier(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ClassSuperClass);
+    if let Some(item) = &mut node.super_class {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ClassBody);
+    walk_class_body(traverser, &mut node.body, ctx);
+    ctx.retag_stack(Ancestor::ClassTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ClassSuperTypeParameters);
+    if let Some(item) = &mut node.super_type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ClassImplements);
+    if let Some(item) = &mut node.implements {
+        for item in item.iter_mut() {
+            walk_ts_class_implements(traverser, item, ctx);
+        }
+    }
+    ctx.retag_stack(Ancestor::ClassDecorators);
+    for item in node.decorators.iter_mut() {
+        walk_decorator(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_class(node, ctx);
+}
+
+pub(super) fn walk_class_body<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ClassBody<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_class_body(node, ctx);
+    ctx.push_stack(Ancestor::ClassBodyBody);
+    for item in node.body.iter_mut() {
+        walk_class_element(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_class_body(node, ctx);
+}
+
+pub(super) fn walk_class_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ClassElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_class_element(node, ctx);
+    match node {
+        ClassElement::StaticBlock(node) => {
+            walk_static_block(traverser, node, ctx);
+        }
+        ClassElement::MethodDefinition(node) => {
+            walk_method_definition(traverser, node, ctx);
+        }
+        ClassElement::PropertyDefinition(node) => {
+            walk_property_definition(traverser, node, ctx);
+        }
+        ClassElement::AccessorProperty(node) => {
+            walk_accessor_property(traverser, node, ctx);
+        }
+        ClassElement::TSAbstractMethodDefinition(node) => {
+            walk_ts_abstract_method_definition(traverser, node, ctx);
+        }
+        ClassElement::TSAbstractPropertyDefinition(node) => {
+            walk_ts_abstract_property_definition(traverser, node, ctx);
+        }
+        ClassElement::TSIndexSignature(node) => {
+            walk_ts_index_signature(traverser, node, ctx);
+        }
+    }
+    traverser.exit_class_element(node, ctx);
+}
+
+pub(super) fn walk_method_definition<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut MethodDefinition<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_method_definition(node, ctx);
+    ctx.push_stack(Ancestor::MethodDefinitionKey);
+    walk_property_key(traverser, &mut node.key, ctx);
+    ctx.retag_stack(Ancestor::MethodDefinitionValue);
+    walk_function(traverser, &mut node.value, ctx);
+    ctx.retag_stack(Ancestor::MethodDefinitionDecorators);
+    for item in node.decorators.iter_mut() {
+        walk_decorator(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_method_definition(node, ctx);
+}
+
+pub(super) fn walk_property_definition<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut PropertyDefinition<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_property_definition(node, ctx);
+    ctx.push_stack(Ancestor::PropertyDefinitionKey);
+    walk_property_key(traverser, &mut node.key, ctx);
+    ctx.retag_stack(Ancestor::PropertyDefinitionValue);
+    if let Some(item) = &mut node.value {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::PropertyDefinitionTypeAnnotation);
+    if let Some(item) = &mut node.type_annotation {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::PropertyDefinitionDecorators);
+    for item in node.decorators.iter_mut() {
+        walk_decorator(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_property_definition(node, ctx);
+}
+
+pub(super) fn walk_private_identifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut PrivateIdentifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_private_identifier(node, ctx);
+    traverser.exit_private_identifier(node, ctx);
+}
+
+pub(super) fn walk_static_block<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut StaticBlock<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_static_block(node, ctx);
+    ctx.push_stack(Ancestor::StaticBlockBody);
+    for item in node.body.iter_mut() {
+        walk_statement(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_static_block(node, ctx);
+}
+
+pub(super) fn walk_module_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ModuleDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_module_declaration(node, ctx);
+    ctx.push_stack(Ancestor::ModuleDeclarationKind);
+    walk_module_declaration_kind(traverser, &mut node.kind, ctx);
+    ctx.pop_stack();
+    traverser.exit_module_declaration(node, ctx);
+}
+
+pub(super) fn walk_module_declaration_kind<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ModuleDeclarationKind<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_module_declaration_kind(node, ctx);
+    match node {
+        ModuleDeclarationKind::ImportDeclaration(node) => {
+            walk_import_declaration(traverser, node, ctx);
+        }
+        ModuleDeclarationKind::ExportAllDeclaration(node) => {
+            walk_export_all_declaration(traverser, node, ctx);
+        }
+        ModuleDeclarationKind::ExportDefaultDeclaration(node) => {
+            walk_export_default_declaration(traverser, node, ctx);
+        }
+        ModuleDeclarationKind::ExportNamedDeclaration(node) => {
+            walk_export_named_declaration(traverser, node, ctx);
+        }
+        ModuleDeclarationKind::TSExportAssignment(node) => {
+            walk_ts_export_assignment(traverser, node, ctx);
+        }
+        ModuleDeclarationKind::TSNamespaceExportDeclaration(node) => {
+            walk_ts_namespace_export_declaration(traverser, node, ctx);
+        }
+    }
+    traverser.exit_module_declaration_kind(node, ctx);
+}
+
+pub(super) fn walk_accessor_property<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut AccessorProperty<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_accessor_property(node, ctx);
+    ctx.push_stack(Ancestor::AccessorPropertyKey);
+    walk_property_key(traverser, &mut node.key, ctx);
+    ctx.retag_stack(Ancestor::AccessorPropertyValue);
+    if let Some(item) = &mut node.value {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_accessor_property(node, ctx);
+}
+
+pub(super) fn walk_import_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ImportExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_import_expression(node, ctx);
+    ctx.push_stack(Ancestor::ImportExpressionSource);
+    walk_expression(traverser, &mut node.source, ctx);
+    ctx.retag_stack(Ancestor::ImportExpressionArguments);
+    for item in node.arguments.iter_mut() {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_import_expression(node, ctx);
+}
+
+pub(super) fn walk_import_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ImportDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_import_declaration(node, ctx);
+    ctx.push_stack(Ancestor::ImportDeclarationSpecifiers);
+    for item in node.specifiers.iter_mut() {
+        walk_import_declaration_specifier(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ImportDeclarationSource);
+    walk_string_literal(traverser, &mut node.source, ctx);
+    ctx.retag_stack(Ancestor::ImportDeclarationAssertions);
+    if let Some(item) = &mut node.assertions {
+        for item in item.iter_mut() {
+            walk_import_attribute(traverser, item, ctx);
+        }
+    }
+    ctx.pop_stack();
+    traverser.exit_import_declaration(node, ctx);
+}
+
+pub(super) fn walk_import_declaration_specifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ImportDeclarationSpecifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_import_declaration_specifier(node, ctx);
+    match node {
+        ImportDeclarationSpecifier::ImportSpecifier(node) => {
+            walk_import_specifier(traverser, node, ctx);
+        }
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(node) => {
+            walk_import_default_specifier(traverser, node, ctx);
+        }
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(node) => {
+            walk_import_namespace_specifier(traverser, node, ctx);
+        }
+    }
+    traverser.exit_import_declaration_specifier(node, ctx);
+}
+
+pub(super) fn walk_import_specifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ImportSpecifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_import_specifier(node, ctx);
+    ctx.push_stack(Ancestor::ImportSpecifierImported);
+    walk_module_export_name(traverser, &mut node.imported, ctx);
+    ctx.retag_stack(Ancestor::ImportSpecifierLocal);
+    walk_binding_identifier(traverser, &mut node.local, ctx);
+    ctx.pop_stack();
+    traverser.exit_import_specifier(node, ctx);
+}
+
+pub(super) fn walk_import_default_specifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ImportDefaultSpecifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_import_default_specifier(node, ctx);
+    ctx.push_stack(Ancestor::ImportDefaultSpecifierLocal);
+    walk_binding_identifier(traverser, &mut node.local, ctx);
+    ctx.pop_stack();
+    traverser.exit_import_default_specifier(node, ctx);
+}
+
+pub(super) fn walk_import_namespace_specifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ImportNamespaceSpecifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_import_namespace_specifier(node, ctx);
+    ctx.push_stack(Ancestor::ImportNamespaceSpecifierLocal);
+    walk_binding_identifier(traverser, &mut node.local, ctx);
+    ctx.pop_stack();
+    traverser.exit_import_namespace_specifier(node, ctx);
+}
+
+pub(super) fn walk_import_attribute<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ImportAttribute,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_import_attribute(node, ctx);
+    ctx.push_stack(Ancestor::ImportAttributeKey);
+    walk_import_attribute_key(traverser, &mut node.key, ctx);
+    ctx.retag_stack(Ancestor::ImportAttributeValue);
+    walk_string_literal(traverser, &mut node.value, ctx);
+    ctx.pop_stack();
+    traverser.exit_import_attribute(node, ctx);
+}
+
+pub(super) fn walk_import_attribute_key<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ImportAttributeKey,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_import_attribute_key(node, ctx);
+    match node {
+        ImportAttributeKey::Identifier(node) => {
+            walk_identifier_name(traverser, node, ctx);
+        }
+        ImportAttributeKey::StringLiteral(node) => {
+            walk_string_literal(traverser, node, ctx);
+        }
+    }
+    traverser.exit_import_attribute_key(node, ctx);
+}
+
+pub(super) fn walk_export_named_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ExportNamedDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_export_named_declaration(node, ctx);
+    ctx.push_stack(Ancestor::ExportNamedDeclarationDeclaration);
+    if let Some(item) = &mut node.declaration {
+        walk_declaration(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ExportNamedDeclarationSpecifiers);
+    for item in node.specifiers.iter_mut() {
+        walk_export_specifier(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ExportNamedDeclarationSource);
+    if let Some(item) = &mut node.source {
+        walk_string_literal(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_export_named_declaration(node, ctx);
+}
+
+pub(super) fn walk_export_default_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ExportDefaultDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_export_default_declaration(node, ctx);
+    ctx.push_stack(Ancestor::ExportDefaultDeclarationDeclaration);
+    walk_export_default_declaration_kind(traverser, &mut node.declaration, ctx);
+    ctx.retag_stack(Ancestor::ExportDefaultDeclarationExported);
+    walk_module_export_name(traverser, &mut node.exported, ctx);
+    ctx.pop_stack();
+    traverser.exit_export_default_declaration(node, ctx);
+}
+
+pub(super) fn walk_export_all_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ExportAllDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_export_all_declaration(node, ctx);
+    ctx.push_stack(Ancestor::ExportAllDeclarationExported);
+    if let Some(item) = &mut node.exported {
+        walk_module_export_name(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::ExportAllDeclarationSource);
+    walk_string_literal(traverser, &mut node.source, ctx);
+    ctx.retag_stack(Ancestor::ExportAllDeclarationAssertions);
+    if let Some(item) = &mut node.assertions {
+        for item in item.iter_mut() {
+            walk_import_attribute(traverser, item, ctx);
+        }
+    }
+    ctx.pop_stack();
+    traverser.exit_export_all_declaration(node, ctx);
+}
+
+pub(super) fn walk_export_specifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ExportSpecifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_export_specifier(node, ctx);
+    ctx.push_stack(Ancestor::ExportSpecifierLocal);
+    walk_module_export_name(traverser, &mut node.local, ctx);
+    ctx.retag_stack(Ancestor::ExportSpecifierExported);
+    walk_module_export_name(traverser, &mut node.exported, ctx);
+    ctx.pop_stack();
+    traverser.exit_export_specifier(node, ctx);
+}
+
+pub(super) fn walk_export_default_declaration_kind<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ExportDefaultDeclarationKind<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_export_default_declaration_kind(node, ctx);
+    match node {
+        ExportDefaultDeclarationKind::Expression(node) => {
+            walk_expression(traverser, node, ctx);
+        }
+        ExportDefaultDeclarationKind::FunctionDeclaration(node) => {
+            walk_function(traverser, node, ctx);
+        }
+        ExportDefaultDeclarationKind::ClassDeclaration(node) => {
+            walk_class(traverser, node, ctx);
+        }
+        ExportDefaultDeclarationKind::TSInterfaceDeclaration(node) => {
+            walk_ts_interface_declaration(traverser, node, ctx);
+        }
+        ExportDefaultDeclarationKind::TSEnumDeclaration(node) => {
+            walk_ts_enum_declaration(traverser, node, ctx);
+        }
+    }
+    traverser.exit_export_default_declaration_kind(node, ctx);
+}
+
+pub(super) fn walk_module_export_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut ModuleExportName,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_module_export_name(node, ctx);
+    match node {
+        ModuleExportName::Identifier(node) => {
+            walk_identifier_name(traverser, node, ctx);
+        }
+        ModuleExportName::StringLiteral(node) => {
+            walk_string_literal(traverser, node, ctx);
+        }
+    }
+    traverser.exit_module_export_name(node, ctx);
+}
+
+pub(super) fn walk_boolean_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut BooleanLiteral,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_boolean_literal(node, ctx);
+    traverser.exit_boolean_literal(node, ctx);
+}
+
+pub(super) fn walk_null_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut NullLiteral,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_null_literal(node, ctx);
+    traverser.exit_null_literal(node, ctx);
+}
+
+pub(super) fn walk_number_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut NumberLiteral<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_number_literal(node, ctx);
+    traverser.exit_number_literal(node, ctx);
+}
+
+pub(super) fn walk_bigint_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut BigintLiteral,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_bigint_literal(node, ctx);
+    traverser.exit_bigint_literal(node, ctx);
+}
+
+pub(super) fn walk_reg_exp_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut RegExpLiteral<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_reg_exp_literal(node, ctx);
+    traverser.exit_reg_exp_literal(node, ctx);
+}
+
+pub(super) fn walk_string_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut StringLiteral,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_string_literal(node, ctx);
+    traverser.exit_string_literal(node, ctx);
+}
+
+pub(super) fn walk_jsx_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_element(node, ctx);
+    ctx.push_stack(Ancestor::JSXElementOpeningElement);
+    walk_jsx_opening_element(traverser, &mut node.opening_element, ctx);
+    ctx.retag_stack(Ancestor::JSXElementClosingElement);
+    if let Some(item) = &mut node.closing_element {
+        walk_jsx_closing_element(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::JSXElementChildren);
+    for item in node.children.iter_mut() {
+        walk_jsx_child(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_jsx_element(node, ctx);
+}
+
+pub(super) fn walk_jsx_opening_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXOpeningElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_opening_element(node, ctx);
+    ctx.push_stack(Ancestor::JSXOpeningElementName);
+    walk_jsx_element_name(traverser, &mut node.name, ctx);
+    ctx.retag_stack(Ancestor::JSXOpeningElementAttributes);
+    for item in node.attributes.iter_mut() {
+        walk_jsx_attribute_item(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::JSXOpeningElementTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_jsx_opening_element(node, ctx);
+}
+
+pub(super) fn walk_jsx_closing_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXClosingElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_closing_element(node, ctx);
+    ctx.push_stack(Ancestor::JSXClosingElementName);
+    walk_jsx_element_name(traverser, &mut node.name, ctx);
+    ctx.pop_stack();
+    traverser.exit_jsx_closing_element(node, ctx);
+}
+
+pub(super) fn walk_jsx_fragment<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXFragment<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_fragment(node, ctx);
+    ctx.push_stack(Ancestor::JSXFragmentOpeningFragment);
+    walk_jsx_opening_fragment(traverser, &mut node.opening_fragment, ctx);
+    ctx.retag_stack(Ancestor::JSXFragmentClosingFragment);
+    walk_jsx_closing_fragment(traverser, &mut node.closing_fragment, ctx);
+    ctx.retag_stack(Ancestor::JSXFragmentChildren);
+    for item in node.children.iter_mut() {
+        walk_jsx_child(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_jsx_fragment(node, ctx);
+}
+
+pub(super) fn walk_jsx_opening_fragment<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXOpeningFragment,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_opening_fragment(node, ctx);
+    traverser.exit_jsx_opening_fragment(node, ctx);
+}
+
+pub(super) fn walk_jsx_closing_fragment<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXClosingFragment,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_closing_fragment(node, ctx);
+    traverser.exit_jsx_closing_fragment(node, ctx);
+}
+
+pub(super) fn walk_jsx_element_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXElementName<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_element_name(node, ctx);
+    match node {
+        JSXElementName::Identifier(node) => {
+            walk_jsx_identifier(traverser, node, ctx);
+        }
+        JSXElementName::NamespacedName(node) => {
+            walk_jsx_namespaced_name(traverser, node, ctx);
+        }
+        JSXElementName::MemberExpression(node) => {
+            walk_jsx_member_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_jsx_element_name(node, ctx);
+}
+
+pub(super) fn walk_jsx_namespaced_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXNamespacedName,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_namespaced_name(node, ctx);
+    ctx.push_stack(Ancestor::JSXNamespacedNameNamespace);
+    walk_jsx_identifier(traverser, &mut node.namespace, ctx);
+    ctx.retag_stack(Ancestor::JSXNamespacedNameProperty);
+    walk_jsx_identifier(traverser, &mut node.property, ctx);
+    ctx.pop_stack();
+    traverser.exit_jsx_namespaced_name(node, ctx);
+}
+
+pub(super) fn walk_jsx_member_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXMemberExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_member_expression(node, ctx);
+    ctx.push_stack(Ancestor::JSXMemberExpressionObject);
+    walk_jsx_member_expression_object(traverser, &mut node.object, ctx);
+    ctx.retag_stack(Ancestor::JSXMemberExpressionProperty);
+    walk_jsx_identifier(traverser, &mut node.property, ctx);
+    ctx.pop_stack();
+    traverser.exit_jsx_member_expression(node, ctx);
+}
+
+pub(super) fn walk_jsx_member_expression_object<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXMemberExpressionObject<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_member_expression_object(node, ctx);
+    match node {
+        JSXMemberExpressionObject::Identifier(node) => {
+            walk_jsx_identifier(traverser, node, ctx);
+        }
+        JSXMemberExpressionObject::MemberExpression(node) => {
+            walk_jsx_member_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_jsx_member_expression_object(node, ctx);
+}
+
+pub(super) fn walk_jsx_expression_container<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXExpressionContainer<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_expression_container(node, ctx);
+    ctx.push_stack(Ancestor::JSXExpressionContainerExpression);
+    walk_jsx_expression(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_jsx_expression_container(node, ctx);
+}
+
+pub(super) fn walk_jsx_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_expression(node, ctx);
+    match node {
+        JSXExpression::Expression(node) => {
+            walk_expression(traverser, node, ctx);
+        }
+        JSXExpression::EmptyExpression(node) => {
+            walk_jsx_empty_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_jsx_expression(node, ctx);
+}
+
+pub(super) fn walk_jsx_empty_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXEmptyExpression,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_empty_expression(node, ctx);
+    traverser.exit_jsx_empty_expression(node, ctx);
+}
+
+pub(super) fn walk_jsx_attribute_item<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXAttributeItem<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_attribute_item(node, ctx);
+    match node {
+        JSXAttributeItem::Attribute(node) => {
+            walk_jsx_attribute(traverser, node, ctx);
+        }
+        JSXAttributeItem::SpreadAttribute(node) => {
+            walk_jsx_spread_attribute(traverser, node, ctx);
+        }
+    }
+    traverser.exit_jsx_attribute_item(node, ctx);
+}
+
+pub(super) fn walk_jsx_attribute<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXAttribute<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_attribute(node, ctx);
+    ctx.push_stack(Ancestor::JSXAttributeName);
+    walk_jsx_attribute_name(traverser, &mut node.name, ctx);
+    ctx.retag_stack(Ancestor::JSXAttributeValue);
+    if let Some(item) = &mut node.value {
+        walk_jsx_attribute_value(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_jsx_attribute(node, ctx);
+}
+
+pub(super) fn walk_jsx_spread_attribute<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXSpreadAttribute<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_spread_attribute(node, ctx);
+    ctx.push_stack(Ancestor::JSXSpreadAttributeArgument);
+    walk_expression(traverser, &mut node.argument, ctx);
+    ctx.pop_stack();
+    traverser.exit_jsx_spread_attribute(node, ctx);
+}
+
+pub(super) fn walk_jsx_attribute_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXAttributeName<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_attribute_name(node, ctx);
+    match node {
+        JSXAttributeName::Identifier(node) => {
+            walk_jsx_identifier(traverser, node, ctx);
+        }
+        JSXAttributeName::NamespacedName(node) => {
+            walk_jsx_namespaced_name(traverser, node, ctx);
+        }
+    }
+    traverser.exit_jsx_attribute_name(node, ctx);
+}
+
+pub(super) fn walk_jsx_attribute_value<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXAttributeValue<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_attribute_value(node, ctx);
+    match node {
+        JSXAttributeValue::StringLiteral(node) => {
+            walk_string_literal(traverser, node, ctx);
+        }
+        JSXAttributeValue::ExpressionContainer(node) => {
+            walk_jsx_expression_container(traverser, node, ctx);
+        }
+        JSXAttributeValue::Element(node) => {
+            walk_jsx_element(traverser, node, ctx);
+        }
+        JSXAttributeValue::Fragment(node) => {
+            walk_jsx_fragment(traverser, node, ctx);
+        }
+    }
+    traverser.exit_jsx_attribute_value(node, ctx);
+}
+
+pub(super) fn walk_jsx_identifier<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXIdentifier,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_identifier(node, ctx);
+    traverser.exit_jsx_identifier(node, ctx);
+}
+
+pub(super) fn walk_jsx_child<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXChild<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_child(node, ctx);
+    match node {
+        JSXChild::Text(node) => {
+            walk_jsx_text(traverser, node, ctx);
+        }
+        JSXChild::Element(node) => {
+            walk_jsx_element(traverser, node, ctx);
+        }
+        JSXChild::Fragment(node) => {
+            walk_jsx_fragment(traverser, node, ctx);
+        }
+        JSXChild::ExpressionContainer(node) => {
+            walk_jsx_expression_container(traverser, node, ctx);
+        }
+        JSXChild::Spread(node) => {
+            walk_jsx_spread_child(traverser, node, ctx);
+        }
+    }
+    traverser.exit_jsx_child(node, ctx);
+}
+
+pub(super) fn walk_jsx_spread_child<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXSpreadChild<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_spread_child(node, ctx);
+    ctx.push_stack(Ancestor::JSXSpreadChildExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_jsx_spread_child(node, ctx);
+}
+
+pub(super) fn walk_jsx_text<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSXText,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_jsx_text(node, ctx);
+    traverser.exit_jsx_text(node, ctx);
+}
+
+pub(super) fn walk_ts_enum_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSEnumDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_enum_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSEnumDeclarationId);
+    walk_binding_identifier(traverser, &mut node.id, ctx);
+    ctx.retag_stack(Ancestor::TSEnumDeclarationMembers);
+    for item in node.members.iter_mut() {
+        walk_ts_enum_member(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_enum_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_enum_member<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSEnumMember<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_enum_member(node, ctx);
+    ctx.push_stack(Ancestor::TSEnumMemberId);
+    walk_ts_enum_member_name(traverser, &mut node.id, ctx);
+    ctx.retag_stack(Ancestor::TSEnumMemberInitializer);
+    if let Some(item) = &mut node.initializer {
+        walk_expression(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_enum_member(node, ctx);
+}
+
+pub(super) fn walk_ts_enum_member_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSEnumMemberName<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_enum_member_name(node, ctx);
+    match node {
+        TSEnumMemberName::Identifier(node) => {
+            walk_identifier_name(traverser, node, ctx);
+        }
+        TSEnumMemberName::StringLiteral(node) => {
+            walk_string_literal(traverser, node, ctx);
+        }
+        TSEnumMemberName::ComputedPropertyName(node) => {
+            walk_expression(traverser, node, ctx);
+        }
+        TSEnumMemberName::NumberLiteral(node) => {
+            walk_number_literal(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_enum_member_name(node, ctx);
+}
+
+pub(super) fn walk_ts_type_annotation<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeAnnotation<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_annotation(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeAnnotationTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_type_annotation(node, ctx);
+}
+
+pub(super) fn walk_ts_literal_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSLiteralType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_literal_type(node, ctx);
+    ctx.push_stack(Ancestor::TSLiteralTypeLiteral);
+    walk_ts_literal(traverser, &mut node.literal, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_literal_type(node, ctx);
+}
+
+pub(super) fn walk_ts_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSLiteral<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_literal(node, ctx);
+    match node {
+        TSLiteral::BooleanLiteral(node) => {
+            walk_boolean_literal(traverser, node, ctx);
+        }
+        TSLiteral::NullLiteral(node) => {
+            walk_null_literal(traverser, node, ctx);
+        }
+        TSLiteral::NumberLiteral(node) => {
+            walk_number_literal(traverser, node, ctx);
+        }
+        TSLiteral::BigintLiteral(node) => {
+            walk_bigint_literal(traverser, node, ctx);
+        }
+        TSLiteral::RegExpLiteral(node) => {
+            walk_reg_exp_literal(traverser, node, ctx);
+        }
+        TSLiteral::StringLiteral(node) => {
+            walk_string_literal(traverser, node, ctx);
+        }
+        TSLiteral::TemplateLiteral(node) => {
+            walk_template_literal(traverser, node, ctx);
+        }
+        TSLiteral::UnaryExpression(node) => {
+            walk_unary_expression(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_literal(node, ctx);
+}
+
+pub(super) fn walk_ts_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type(node, ctx);
+    match node {
+        TSType::TSAnyKeyword(node) => {
+            walk_ts_any_keyword(traverser, node, ctx);
+        }
+        TSType::TSBigIntKeyword(node) => {
+            walk_ts_big_int_keyword(traverser, node, ctx);
+        }
+        TSType::TSBooleanKeyword(node) => {
+            walk_ts_boolean_keyword(traverser, node, ctx);
+        }
+        TSType::TSNeverKeyword(node) => {
+            walk_ts_never_keyword(traverser, node, ctx);
+        }
+        TSType::TSNullKeyword(node) => {
+            walk_ts_null_keyword(traverser, node, ctx);
+        }
+        TSType::TSNumberKeyword(node) => {
+            walk_ts_number_keyword(traverser, node, ctx);
+        }
+        TSType::TSObjectKeyword(node) => {
+            walk_ts_object_keyword(traverser, node, ctx);
+        }
+        TSType::TSStringKeyword(node) => {
+            walk_ts_string_keyword(traverser, node, ctx);
+        }
+        TSType::TSSymbolKeyword(node) => {
+            walk_ts_symbol_keyword(traverser, node, ctx);
+        }
+        TSType::TSThisKeyword(node) => {
+            walk_ts_this_keyword(traverser, node, ctx);
+        }
+        TSType::TSUndefinedKeyword(node) => {
+            walk_ts_undefined_keyword(traverser, node, ctx);
+        }
+        TSType::TSUnknownKeyword(node) => {
+            walk_ts_unknown_keyword(traverser, node, ctx);
+        }
+        TSType::TSVoidKeyword(node) => {
+            walk_ts_void_keyword(traverser, node, ctx);
+        }
+        TSType::TSArrayType(node) => {
+            walk_ts_array_type(traverser, node, ctx);
+        }
+        TSType::TSConditionalType(node) => {
+            walk_ts_conditional_type(traverser, node, ctx);
+        }
+        TSType::TSConstructorType(node) => {
+            walk_ts_constructor_type(traverser, node, ctx);
+        }
+        TSType::TSFunctionType(node) => {
+            walk_ts_function_type(traverser, node, ctx);
+        }
+        TSType::TSImportType(node) => {
+            walk_ts_import_type(traverser, node, ctx);
+        }
+        TSType::TSIndexedAccessType(node) => {
+            walk_ts_indexed_access_type(traverser, node, ctx);
+        }
+        TSType::TSInferType(node) => {
+            walk_ts_infer_type(traverser, node, ctx);
+        }
+        TSType::TSIntersectionType(node) => {
+            walk_ts_intersection_type(traverser, node, ctx);
+        }
+        TSType::TSLiteralType(node) => {
+            walk_ts_literal_type(traverser, node, ctx);
+        }
+        TSType::TSMappedType(node) => {
+            walk_ts_mapped_type(traverser, node, ctx);
+        }
+        TSType::TSQualifiedName(node) => {
+            walk_ts_qualified_name(traverser, node, ctx);
+        }
+        TSType::TSTemplateLiteralType(node) => {
+            walk_ts_template_literal_type(traverser, node, ctx);
+        }
+        TSType::TSTupleType(node) => {
+            walk_ts_tuple_type(traverser, node, ctx);
+        }
+        TSType::TSTypeLiteral(node) => {
+            walk_ts_type_literal(traverser, node, ctx);
+        }
+        TSType::TSTypeOperatorType(node) => {
+            walk_ts_type_operator_type(traverser, node, ctx);
+        }
+        TSType::TSTypePredicate(node) => {
+            walk_ts_type_predicate(traverser, node, ctx);
+        }
+        TSType::TSTypeQuery(node) => {
+            walk_ts_type_query(traverser, node, ctx);
+        }
+        TSType::TSTypeReference(node) => {
+            walk_ts_type_reference(traverser, node, ctx);
+        }
+        TSType::TSUnionType(node) => {
+            walk_ts_union_type(traverser, node, ctx);
+        }
+        TSType::JSDocNullableType(node) => {
+            walk_js_doc_nullable_type(traverser, node, ctx);
+        }
+        TSType::JSDocUnknownType(node) => {
+            walk_js_doc_unknown_type(traverser, node, ctx);
+        }
+        TSType::FlowExactObjectType(node) => {
+            walk_flow_exact_object_type(traverser, node, ctx);
+        }
+        TSType::FlowExistentialType(node) => {
+            walk_flow_existential_type(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_type(node, ctx);
+}
+
+pub(super) fn walk_ts_conditional_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSConditionalType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_conditional_type(node, ctx);
+    ctx.push_stack(Ancestor::TSConditionalTypeCheckType);
+    walk_ts_type(traverser, &mut node.check_type, ctx);
+    ctx.retag_stack(Ancestor::TSConditionalTypeExtendsType);
+    walk_ts_type(traverser, &mut node.extends_type, ctx);
+    ctx.retag_stack(Ancestor::TSConditionalTypeTrueType);
+    walk_ts_type(traverser, &mut node.true_type, ctx);
+    ctx.retag_stack(Ancestor::TSConditionalTypeFalseType);
+    walk_ts_type(traverser, &mut node.false_type, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_conditional_type(node, ctx);
+}
+
+pub(super) fn walk_ts_union_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSUnionType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_union_type(node, ctx);
+    ctx.push_stack(Ancestor::TSUnionTypeTypes);
+    for item in node.types.iter_mut() {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_union_type(node, ctx);
+}
+
+pub(super) fn walk_ts_intersection_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSIntersectionType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_intersection_type(node, ctx);
+    ctx.push_stack(Ancestor::TSIntersectionTypeTypes);
+    for item in node.types.iter_mut() {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_intersection_type(node, ctx);
+}
+
+pub(super) fn walk_ts_type_operator_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeOperatorType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_operator_type(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeOperatorTypeTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_type_operator_type(node, ctx);
+}
+
+pub(super) fn walk_ts_array_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSArrayType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_array_type(node, ctx);
+    ctx.push_stack(Ancestor::TSArrayTypeElementType);
+    walk_ts_type(traverser, &mut node.element_type, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_array_type(node, ctx);
+}
+
+pub(super) fn walk_ts_indexed_access_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSIndexedAccessType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_indexed_access_type(node, ctx);
+    ctx.push_stack(Ancestor::TSIndexedAccessTypeObjectType);
+    walk_ts_type(traverser, &mut node.object_type, ctx);
+    ctx.retag_stack(Ancestor::TSIndexedAccessTypeIndexType);
+    walk_ts_type(traverser, &mut node.index_type, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_indexed_access_type(node, ctx);
+}
+
+pub(super) fn walk_ts_tuple_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTupleType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_tuple_type(node, ctx);
+    ctx.push_stack(Ancestor::TSTupleTypeElementTypes);
+    for item in node.element_types.iter_mut() {
+        walk_ts_tuple_element(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_tuple_type(node, ctx);
+}
+
+pub(super) fn walk_ts_named_tuple_member<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSNamedTupleMember<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_named_tuple_member(node, ctx);
+    ctx.push_stack(Ancestor::TSNamedTupleMemberElementType);
+    walk_ts_type(traverser, &mut node.element_type, ctx);
+    ctx.retag_stack(Ancestor::TSNamedTupleMemberLabel);
+    walk_identifier_name(traverser, &mut node.label, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_named_tuple_member(node, ctx);
+}
+
+pub(super) fn walk_ts_optional_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSOptionalType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_optional_type(node, ctx);
+    ctx.push_stack(Ancestor::TSOptionalTypeTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_optional_type(node, ctx);
+}
+
+pub(super) fn walk_ts_rest_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSRestType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_rest_type(node, ctx);
+    ctx.push_stack(Ancestor::TSRestTypeTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_rest_type(node, ctx);
+}
+
+pub(super) fn walk_ts_tuple_element<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTupleElement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_tuple_element(node, ctx);
+    match node {
+        TSTupleElement::TSType(node) => {
+            walk_ts_type(traverser, node, ctx);
+        }
+        TSTupleElement::TSOptionalType(node) => {
+            walk_ts_optional_type(traverser, node, ctx);
+        }
+        TSTupleElement::TSRestType(node) => {
+            walk_ts_rest_type(traverser, node, ctx);
+        }
+        TSTupleElement::TSNamedTupleMember(node) => {
+            walk_ts_named_tuple_member(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_tuple_element(node, ctx);
+}
+
+pub(super) fn walk_ts_any_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSAnyKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_any_keyword(node, ctx);
+    traverser.exit_ts_any_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_string_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSStringKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_string_keyword(node, ctx);
+    traverser.exit_ts_string_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_boolean_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSBooleanKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_boolean_keyword(node, ctx);
+    traverser.exit_ts_boolean_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_number_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSNumberKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_number_keyword(node, ctx);
+    traverser.exit_ts_number_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_never_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSNeverKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_never_keyword(node, ctx);
+    traverser.exit_ts_never_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_unknown_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSUnknownKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_unknown_keyword(node, ctx);
+    traverser.exit_ts_unknown_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_null_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSNullKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_null_keyword(node, ctx);
+    traverser.exit_ts_null_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_undefined_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSUndefinedKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_undefined_keyword(node, ctx);
+    traverser.exit_ts_undefined_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_void_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSVoidKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_void_keyword(node, ctx);
+    traverser.exit_ts_void_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_symbol_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSSymbolKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_symbol_keyword(node, ctx);
+    traverser.exit_ts_symbol_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_this_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSThisKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_this_keyword(node, ctx);
+    traverser.exit_ts_this_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_object_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSObjectKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_object_keyword(node, ctx);
+    traverser.exit_ts_object_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_big_int_keyword<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSBigIntKeyword,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_big_int_keyword(node, ctx);
+    traverser.exit_ts_big_int_keyword(node, ctx);
+}
+
+pub(super) fn walk_ts_type_reference<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeReference<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_reference(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeReferenceTypeName);
+    walk_ts_type_name(traverser, &mut node.type_name, ctx);
+    ctx.retag_stack(Ancestor::TSTypeReferenceTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_type_reference(node, ctx);
+}
+
+pub(super) fn walk_ts_type_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeName<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_name(node, ctx);
+    match node {
+        TSTypeName::IdentifierName(node) => {
+            walk_identifier_name(traverser, node, ctx);
+        }
+        TSTypeName::QualifiedName(node) => {
+            walk_ts_qualified_name(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_type_name(node, ctx);
+}
+
+pub(super) fn walk_ts_qualified_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSQualifiedName<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_qualified_name(node, ctx);
+    ctx.push_stack(Ancestor::TSQualifiedNameLeft);
+    walk_ts_type_name(traverser, &mut node.left, ctx);
+    ctx.retag_stack(Ancestor::TSQualifiedNameRight);
+    walk_identifier_name(traverser, &mut node.right, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_qualified_name(node, ctx);
+}
+
+pub(super) fn walk_ts_type_parameter_instantiation<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeParameterInstantiation<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_parameter_instantiation(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeParameterInstantiationParams);
+    for item in node.params.iter_mut() {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_type_parameter_instantiation(node, ctx);
+}
+
+pub(super) fn walk_ts_type_parameter<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeParameter<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_parameter(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeParameterName);
+    walk_binding_identifier(traverser, &mut node.name, ctx);
+    ctx.retag_stack(Ancestor::TSTypeParameterConstraint);
+    if let Some(item) = &mut node.constraint {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSTypeParameterDefault);
+    if let Some(item) = &mut node.default {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_type_parameter(node, ctx);
+}
+
+pub(super) fn walk_ts_type_parameter_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeParameterDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_parameter_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeParameterDeclarationParams);
+    for item in node.params.iter_mut() {
+        walk_ts_type_parameter(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_type_parameter_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_type_alias_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeAliasDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_alias_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeAliasDeclarationId);
+    walk_binding_identifier(traverser, &mut node.id, ctx);
+    ctx.retag_stack(Ancestor::TSTypeAliasDeclarationTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.retag_stack(Ancestor::TSTypeAliasDeclarationTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_type_alias_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_abstract_method_definition<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSAbstractMethodDefinition<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_abstract_method_definition(node, ctx);
+    ctx.push_stack(Ancestor::TSAbstractMethodDefinitionMethodDefinition);
+    walk_method_definition(traverser, &mut node.method_definition, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_abstract_method_definition(node, ctx);
+}
+
+pub(super) fn walk_ts_abstract_property_definition<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSAbstractPropertyDefinition<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_abstract_property_definition(node, ctx);
+    ctx.push_stack(Ancestor::TSAbstractPropertyDefinitionPropertyDefinition);
+    walk_property_definition(traverser, &mut node.property_definition, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_abstract_property_definition(node, ctx);
+}
+
+pub(super) fn walk_ts_class_implements<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSClassImplements<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_class_implements(node, ctx);
+    ctx.push_stack(Ancestor::TSClassImplementsExpression);
+    walk_ts_type_name(traverser, &mut node.expression, ctx);
+    ctx.retag_stack(Ancestor::TSClassImplementsTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_class_implements(node, ctx);
+}
+
+pub(super) fn walk_ts_interface_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSInterfaceDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_interface_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSInterfaceDeclarationId);
+    walk_binding_identifier(traverser, &mut node.id, ctx);
+    ctx.retag_stack(Ancestor::TSInterfaceDeclarationBody);
+    walk_ts_interface_body(traverser, &mut node.body, ctx);
+    ctx.retag_stack(Ancestor::TSInterfaceDeclarationTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSInterfaceDeclarationExtends);
+    if let Some(item) = &mut node.extends {
+        for item in item.iter_mut() {
+            walk_ts_interface_heritage(traverser, item, ctx);
+        }
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_interface_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_interface_body<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSInterfaceBody<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_interface_body(node, ctx);
+    ctx.push_stack(Ancestor::TSInterfaceBodyBody);
+    for item in node.body.iter_mut() {
+        walk_ts_signature(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_interface_body(node, ctx);
+}
+
+pub(super) fn walk_ts_property_signature<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSPropertySignature<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_property_signature(node, ctx);
+    ctx.push_stack(Ancestor::TSPropertySignatureKey);
+    walk_property_key(traverser, &mut node.key, ctx);
+    ctx.retag_stack(Ancestor::TSPropertySignatureTypeAnnotation);
+    if let Some(item) = &mut node.type_annotation {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_property_signature(node, ctx);
+}
+
+pub(super) fn walk_ts_signature<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSSignature<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_signature(node, ctx);
+    match node {
+        TSSignature::TSIndexSignature(node) => {
+            walk_ts_index_signature(traverser, node, ctx);
+        }
+        TSSignature::TSPropertySignature(node) => {
+            walk_ts_property_signature(traverser, node, ctx);
+        }
+        TSSignature::TSCallSignatureDeclaration(node) => {
+            walk_ts_call_signature_declaration(traverser, node, ctx);
+        }
+        TSSignature::TSConstructSignatureDeclaration(node) => {
+            walk_ts_construct_signature_declaration(traverser, node, ctx);
+        }
+        TSSignature::TSMethodSignature(node) => {
+            walk_ts_method_signature(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_signature(node, ctx);
+}
+
+pub(super) fn walk_ts_index_signature<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSIndexSignature<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_index_signature(node, ctx);
+    ctx.push_stack(Ancestor::TSIndexSignatureParameters);
+    for item in node.parameters.iter_mut() {
+        walk_ts_index_signature_name(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSIndexSignatureTypeAnnotation);
+    walk_ts_type_annotation(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_index_signature(node, ctx);
+}
+
+pub(super) fn walk_ts_call_signature_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSCallSignatureDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_call_signature_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSCallSignatureDeclarationParams);
+    walk_formal_parameters(traverser, &mut node.params, ctx);
+    ctx.retag_stack(Ancestor::TSCallSignatureDeclarationReturnType);
+    if let Some(item) = &mut node.return_type {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSCallSignatureDeclarationTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_call_signature_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_method_signature<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSMethodSignature<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_method_signature(node, ctx);
+    ctx.push_stack(Ancestor::TSMethodSignatureKey);
+    walk_property_key(traverser, &mut node.key, ctx);
+    ctx.retag_stack(Ancestor::TSMethodSignatureParams);
+    walk_formal_parameters(traverser, &mut node.params, ctx);
+    ctx.retag_stack(Ancestor::TSMethodSignatureReturnType);
+    if let Some(item) = &mut node.return_type {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSMethodSignatureTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_method_signature(node, ctx);
+}
+
+pub(super) fn walk_ts_construct_signature_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSConstructSignatureDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_construct_signature_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSConstructSignatureDeclarationParams);
+    walk_formal_parameters(traverser, &mut node.params, ctx);
+    ctx.retag_stack(Ancestor::TSConstructSignatureDeclarationReturnType);
+    if let Some(item) = &mut node.return_type {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSConstructSignatureDeclarationTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_construct_signature_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_index_signature_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSIndexSignatureName<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_index_signature_name(node, ctx);
+    ctx.push_stack(Ancestor::TSIndexSignatureNameTypeAnnotation);
+    walk_ts_type_annotation(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_index_signature_name(node, ctx);
+}
+
+pub(super) fn walk_ts_interface_heritage<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSInterfaceHeritage<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_interface_heritage(node, ctx);
+    ctx.push_stack(Ancestor::TSInterfaceHeritageExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.retag_stack(Ancestor::TSInterfaceHeritageTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_interface_heritage(node, ctx);
+}
+
+pub(super) fn walk_ts_type_predicate<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypePredicate<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_predicate(node, ctx);
+    ctx.push_stack(Ancestor::TSTypePredicateParameterName);
+    walk_ts_type_predicate_name(traverser, &mut node.parameter_name, ctx);
+    ctx.retag_stack(Ancestor::TSTypePredicateTypeAnnotation);
+    if let Some(item) = &mut node.type_annotation {
+        walk_ts_type_annotation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_type_predicate(node, ctx);
+}
+
+pub(super) fn walk_ts_type_predicate_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypePredicateName,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_predicate_name(node, ctx);
+    match node {
+        TSTypePredicateName::Identifier(node) => {
+            walk_identifier_name(traverser, node, ctx);
+        }
+        TSTypePredicateName::This(node) => {
+            walk_ts_this_keyword(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_type_predicate_name(node, ctx);
+}
+
+pub(super) fn walk_ts_module_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSModuleDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_module_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSModuleDeclarationId);
+    walk_ts_module_declaration_name(traverser, &mut node.id, ctx);
+    ctx.retag_stack(Ancestor::TSModuleDeclarationBody);
+    walk_ts_module_declaration_body(traverser, &mut node.body, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_module_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_module_declaration_name<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSModuleDeclarationName,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_module_declaration_name(node, ctx);
+    match node {
+        TSModuleDeclarationName::Identifier(node) => {
+            walk_identifier_name(traverser, node, ctx);
+        }
+        TSModuleDeclarationName::StringLiteral(node) => {
+            walk_string_literal(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_module_declaration_name(node, ctx);
+}
+
+pub(super) fn walk_ts_module_declaration_body<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSModuleDeclarationBody<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_module_declaration_body(node, ctx);
+    match node {
+        TSModuleDeclarationBody::TSModuleDeclaration(node) => {
+            walk_ts_module_declaration(traverser, node, ctx);
+        }
+        TSModuleDeclarationBody::TSModuleBlock(node) => {
+            walk_ts_module_block(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_module_declaration_body(node, ctx);
+}
+
+pub(super) fn walk_ts_module_block<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSModuleBlock<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_module_block(node, ctx);
+    ctx.push_stack(Ancestor::TSModuleBlockBody);
+    for item in node.body.iter_mut() {
+        walk_statement(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_module_block(node, ctx);
+}
+
+pub(super) fn walk_ts_type_literal<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeLiteral<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_literal(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeLiteralMembers);
+    for item in node.members.iter_mut() {
+        walk_ts_signature(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_type_literal(node, ctx);
+}
+
+pub(super) fn walk_ts_infer_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSInferType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_infer_type(node, ctx);
+    ctx.push_stack(Ancestor::TSInferTypeTypeParameter);
+    walk_ts_type_parameter(traverser, &mut node.type_parameter, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_infer_type(node, ctx);
+}
+
+pub(super) fn walk_ts_type_query<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeQuery<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_query(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeQueryExprName);
+    walk_ts_type_name(traverser, &mut node.expr_name, ctx);
+    ctx.retag_stack(Ancestor::TSTypeQueryTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_type_query(node, ctx);
+}
+
+pub(super) fn walk_ts_import_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSImportType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_import_type(node, ctx);
+    ctx.push_stack(Ancestor::TSImportTypeParameter);
+    walk_ts_type(traverser, &mut node.parameter, ctx);
+    ctx.retag_stack(Ancestor::TSImportTypeQualifier);
+    if let Some(item) = &mut node.qualifier {
+        walk_ts_type_name(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSImportTypeTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_instantiation(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_import_type(node, ctx);
+}
+
+pub(super) fn walk_ts_function_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSFunctionType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_function_type(node, ctx);
+    ctx.push_stack(Ancestor::TSFunctionTypeParams);
+    walk_formal_parameters(traverser, &mut node.params, ctx);
+    ctx.retag_stack(Ancestor::TSFunctionTypeReturnType);
+    walk_ts_type_annotation(traverser, &mut node.return_type, ctx);
+    ctx.retag_stack(Ancestor::TSFunctionTypeTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_function_type(node, ctx);
+}
+
+pub(super) fn walk_ts_constructor_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSConstructorType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_constructor_type(node, ctx);
+    ctx.push_stack(Ancestor::TSConstructorTypeParams);
+    walk_formal_parameters(traverser, &mut node.params, ctx);
+    ctx.retag_stack(Ancestor::TSConstructorTypeReturnType);
+    walk_ts_type_annotation(traverser, &mut node.return_type, ctx);
+    ctx.retag_stack(Ancestor::TSConstructorTypeTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_constructor_type(node, ctx);
+}
+
+pub(super) fn walk_ts_mapped_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSMappedType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_mapped_type(node, ctx);
+    ctx.push_stack(Ancestor::TSMappedTypeTypeParameter);
+    walk_ts_type_parameter(traverser, &mut node.type_parameter, ctx);
+    ctx.retag_stack(Ancestor::TSMappedTypeNameType);
+    if let Some(item) = &mut node.name_type {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSMappedTypeTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_mapped_type(node, ctx);
+}
+
+pub(super) fn walk_ts_template_literal_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTemplateLiteralType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_template_literal_type(node, ctx);
+    ctx.push_stack(Ancestor::TSTemplateLiteralTypeQuasis);
+    for item in node.quasis.iter_mut() {
+        walk_template_element(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::TSTemplateLiteralTypeTypes);
+    for item in node.types.iter_mut() {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_ts_template_literal_type(node, ctx);
+}
+
+pub(super) fn walk_ts_as_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSAsExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_as_expression(node, ctx);
+    ctx.push_stack(Ancestor::TSAsExpressionExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.retag_stack(Ancestor::TSAsExpressionTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_as_expression(node, ctx);
+}
+
+pub(super) fn walk_ts_satisfies_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSSatisfiesExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_satisfies_expression(node, ctx);
+    ctx.push_stack(Ancestor::TSSatisfiesExpressionExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.retag_stack(Ancestor::TSSatisfiesExpressionTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_satisfies_expression(node, ctx);
+}
+
+pub(super) fn walk_ts_type_assertion<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSTypeAssertion<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_type_assertion(node, ctx);
+    ctx.push_stack(Ancestor::TSTypeAssertionExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.retag_stack(Ancestor::TSTypeAssertionTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_type_assertion(node, ctx);
+}
+
+pub(super) fn walk_ts_import_equals_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSImportEqualsDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_import_equals_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSImportEqualsDeclarationId);
+    walk_binding_identifier(traverser, &mut node.id, ctx);
+    ctx.retag_stack(Ancestor::TSImportEqualsDeclarationModuleReference);
+    walk_ts_module_reference(traverser, &mut node.module_reference, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_import_equals_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_module_reference<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSModuleReference<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_module_reference(node, ctx);
+    match node {
+        TSModuleReference::TypeName(node) => {
+            walk_ts_type_name(traverser, node, ctx);
+        }
+        TSModuleReference::ExternalModuleReference(node) => {
+            walk_ts_external_module_reference(traverser, node, ctx);
+        }
+    }
+    traverser.exit_ts_module_reference(node, ctx);
+}
+
+pub(super) fn walk_ts_external_module_reference<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSExternalModuleReference,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_external_module_reference(node, ctx);
+    ctx.push_stack(Ancestor::TSExternalModuleReferenceExpression);
+    walk_string_literal(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_external_module_reference(node, ctx);
+}
+
+pub(super) fn walk_ts_non_null_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSNonNullExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_non_null_expression(node, ctx);
+    ctx.push_stack(Ancestor::TSNonNullExpressionExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_non_null_expression(node, ctx);
+}
+
+pub(super) fn walk_decorator<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut Decorator<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_decorator(node, ctx);
+    ctx.push_stack(Ancestor::DecoratorExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_decorator(node, ctx);
+}
+
+pub(super) fn walk_ts_export_assignment<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSExportAssignment<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_export_assignment(node, ctx);
+    ctx.push_stack(Ancestor::TSExportAssignmentExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_export_assignment(node, ctx);
+}
+
+pub(super) fn walk_ts_namespace_export_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSNamespaceExportDeclaration,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_namespace_export_declaration(node, ctx);
+    ctx.push_stack(Ancestor::TSNamespaceExportDeclarationId);
+    walk_identifier_name(traverser, &mut node.id, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_namespace_export_declaration(node, ctx);
+}
+
+pub(super) fn walk_ts_instantiation_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut TSInstantiationExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_ts_instantiation_expression(node, ctx);
+    ctx.push_stack(Ancestor::TSInstantiationExpressionExpression);
+    walk_expression(traverser, &mut node.expression, ctx);
+    ctx.retag_stack(Ancestor::TSInstantiationExpressionTypeParameters);
+    walk_ts_type_parameter_instantiation(traverser, &mut node.type_parameters, ctx);
+    ctx.pop_stack();
+    traverser.exit_ts_instantiation_expression(node, ctx);
+}
+
+pub(super) fn walk_flow_opaque_type_declaration<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut FlowOpaqueTypeDeclaration<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_flow_opaque_type_declaration(node, ctx);
+    ctx.push_stack(Ancestor::FlowOpaqueTypeDeclarationId);
+    walk_binding_identifier(traverser, &mut node.id, ctx);
+    ctx.retag_stack(Ancestor::FlowOpaqueTypeDeclarationTypeParameters);
+    if let Some(item) = &mut node.type_parameters {
+        walk_ts_type_parameter_declaration(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::FlowOpaqueTypeDeclarationSupertype);
+    if let Some(item) = &mut node.supertype {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.retag_stack(Ancestor::FlowOpaqueTypeDeclarationImpltype);
+    if let Some(item) = &mut node.impltype {
+        walk_ts_type(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_flow_opaque_type_declaration(node, ctx);
+}
+
+pub(super) fn walk_flow_declare_module_exports<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut FlowDeclareModuleExports<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_flow_declare_module_exports(node, ctx);
+    ctx.push_stack(Ancestor::FlowDeclareModuleExportsTypeAnnotation);
+    walk_ts_type_annotation(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_flow_declare_module_exports(node, ctx);
+}
+
+pub(super) fn walk_flow_exact_object_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut FlowExactObjectType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_flow_exact_object_type(node, ctx);
+    ctx.push_stack(Ancestor::FlowExactObjectTypeMembers);
+    for item in node.members.iter_mut() {
+        walk_ts_signature(traverser, item, ctx);
+    }
+    ctx.pop_stack();
+    traverser.exit_flow_exact_object_type(node, ctx);
+}
+
+pub(super) fn walk_flow_existential_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut FlowExistentialType,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_flow_existential_type(node, ctx);
+    traverser.exit_flow_existential_type(node, ctx);
+}
+
+pub(super) fn walk_js_doc_nullable_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSDocNullableType<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_js_doc_nullable_type(node, ctx);
+    ctx.push_stack(Ancestor::JSDocNullableTypeTypeAnnotation);
+    walk_ts_type(traverser, &mut node.type_annotation, ctx);
+    ctx.pop_stack();
+    traverser.exit_js_doc_nullable_type(node, ctx);
+}
+
+pub(super) fn walk_js_doc_unknown_type<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: &mut JSDocUnknownType,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_js_doc_unknown_type(node, ctx);
+    traverser.exit_js_doc_unknown_type(node, ctx);
+}
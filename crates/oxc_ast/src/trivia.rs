@@ -1,23 +1,23 @@
 use std::collections::BTreeMap;
 
-use crate::Span;
+use crate::{ShiftSpan, Span};
 
 /// Trivias such as comments
 ///
 /// Trivia (called that because it's trivial) represent the parts of the source text that are largely insignificant for normal understanding of the code.
 /// For example: whitespace, comments, and even conflict markers.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Trivias {
     /// Keyed by span.start
     comments: BTreeMap<u32, Comment>,
 }
 
 /// Single or multi line comment
-#[derive(Debug, Clone, Copy)]
-#[allow(unused)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct Comment {
     kind: CommentKind,
     end: u32,
+    annotation: Option<CommentAnnotation>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -26,10 +26,85 @@ pub enum CommentKind {
     MultiLine,
 }
 
+/// Comments with a special meaning to tools
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CommentAnnotation {
+    /// `/** ... */`
+    JSDoc,
+    /// `/*#__PURE__*/` or `/*@__PURE__*/`
+    Pure,
+    /// `/*#__NO_SIDE_EFFECTS__*/` or `/*@__NO_SIDE_EFFECTS__*/`
+    NoSideEffects,
+    /// `/*! ... */`, or a comment containing `@license` or `@preserve`
+    License,
+    /// `// @ts-ignore`, `// @ts-expect-error`, `// @ts-check` or `// @ts-nocheck`
+    TsDirective,
+}
+
+impl CommentAnnotation {
+    /// Classify a comment from its text, excluding `//`, `/*` and `*/`
+    #[must_use]
+    pub fn from_comment_text(kind: CommentKind, text: &str) -> Option<Self> {
+        if kind == CommentKind::MultiLine {
+            // `/**/` is an empty comment, and `/***/` is not a JSDoc block either
+            if text.starts_with('*') && !text.starts_with("**") && text.len() > 1 {
+                return Some(if text.contains("@license") || text.contains("@preserve") {
+                    Self::License
+                } else {
+                    Self::JSDoc
+                });
+            }
+            if text.starts_with('!') {
+                return Some(Self::License);
+            }
+        }
+        let trimmed = text.trim();
+        if let Some(name) = trimmed.strip_prefix('#').or_else(|| trimmed.strip_prefix('@')) {
+            match name {
+                "__PURE__" => return Some(Self::Pure),
+                "__NO_SIDE_EFFECTS__" => return Some(Self::NoSideEffects),
+                _ => {}
+            }
+        }
+        if kind == CommentKind::SingleLine {
+            let directive = trimmed.trim_start_matches('/').trim_start();
+            let is_ts_directive =
+                ["@ts-ignore", "@ts-expect-error", "@ts-check", "@ts-nocheck"].iter().any(|name| {
+                    directive.strip_prefix(name).is_some_and(|rest| {
+                        rest.chars().next().map_or(true, |c| !c.is_ascii_alphanumeric() && c != '-')
+                    })
+                });
+            if is_ts_directive {
+                return Some(Self::TsDirective);
+            }
+        }
+        if text.contains("@license") || text.contains("@preserve") {
+            return Some(Self::License);
+        }
+        None
+    }
+}
+
+/// A comment attached to a node, see [`Trivias::leading_comments`] and [`Trivias::trailing_comments`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct AttachedComment {
+    /// Span of the comment text, excluding `//`, `/*` and `*/`
+    pub span: Span,
+    pub comment: Comment,
+    /// Whether there is a line break between the comment and the node
+    pub has_newline: bool,
+}
+
 impl Comment {
     #[must_use]
     pub fn new(end: u32, kind: CommentKind) -> Self {
-        Self { kind, end }
+        Self { kind, end, annotation: None }
+    }
+
+    #[must_use]
+    pub fn with_annotation(mut self, annotation: Option<CommentAnnotation>) -> Self {
+        self.annotation = annotation;
+        self
     }
 
     #[must_use]
@@ -37,6 +112,16 @@ impl Comment {
         self.end
     }
 
+    #[must_use]
+    pub fn kind(self) -> CommentKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub fn annotation(self) -> Option<CommentAnnotation> {
+        self.annotation
+    }
+
     #[must_use]
     pub fn is_single_line(self) -> bool {
         matches!(self.kind, CommentKind::SingleLine)
@@ -46,6 +131,49 @@ impl Comment {
     pub fn is_multi_line(self) -> bool {
         matches!(self.kind, CommentKind::MultiLine)
     }
+
+    #[must_use]
+    pub fn is_jsdoc(self) -> bool {
+        matches!(self.annotation, Some(CommentAnnotation::JSDoc))
+    }
+
+    #[must_use]
+    pub fn is_pure(self) -> bool {
+        matches!(self.annotation, Some(CommentAnnotation::Pure))
+    }
+
+    #[must_use]
+    pub fn is_license(self) -> bool {
+        matches!(self.annotation, Some(CommentAnnotation::License))
+    }
+
+    /// Span of the comment text excluding `//`, `/*` and `*/`, `start` is the key in [`Trivias::comments`].
+    ///
+    /// The line terminator after a single line comment is part of its [`Comment::end`] but not of the text.
+    #[must_use]
+    pub fn text_span(self, start: u32, source_text: &str) -> Span {
+        let mut end = self.end;
+        if self.is_single_line() {
+            if let Some(c) = source_text[start as usize..end as usize].chars().next_back() {
+                if is_line_terminator(c) {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let len = c.len_utf8() as u32;
+                    end -= len;
+                }
+            }
+        }
+        Span::new(start, end)
+    }
+
+    /// Span of the comment including `//`, `/*` and `*/`, see [`Comment::text_span`]
+    #[must_use]
+    pub fn full_span(self, start: u32, source_text: &str) -> Span {
+        let span = self.text_span(start, source_text);
+        match self.kind {
+            CommentKind::SingleLine => Span::new(start - 2, span.end),
+            CommentKind::MultiLine => Span::new(start - 2, span.end + 2),
+        }
+    }
 }
 
 impl Trivias {
@@ -59,8 +187,220 @@ impl Trivias {
         self.comments.range(span.start..span.end).count() > 0
     }
 
-    pub fn add_comment(&mut self, span: Span, kind: CommentKind) {
-        let comment = Comment::new(span.end, kind);
+    pub fn add_comment(&mut self, span: Span, kind: CommentKind, text: &str) {
+        let annotation = CommentAnnotation::from_comment_text(kind, text);
+        let comment = Comment::new(span.end, kind).with_annotation(annotation);
         self.comments.insert(span.start, comment);
     }
+
+    /// Replace the comments inside `span` with the ones from `trivias`,
+    /// moving the comments after `span` by `offset` as in [`ShiftSpan`].
+    ///
+    /// Used by incremental parsing, where `trivias` are the comments of the re-parsed source text.
+    pub fn splice(&mut self, span: Span, offset: i64, trivias: &Self) {
+        let mut after = self.comments.split_off(&span.start).split_off(&span.end);
+        for (start, mut comment) in std::mem::take(&mut after) {
+            let mut comment_span = Span::new(start, comment.end);
+            comment_span.shift_span(span.end, offset);
+            comment.end = comment_span.end;
+            after.insert(comment_span.start, comment);
+        }
+        self.comments.extend(after);
+        self.comments.extend(trivias.comments.iter().map(|(start, comment)| (*start, *comment)));
+    }
+
+    /// Whether the node at `span` is directly preceded by a `/*#__PURE__*/` annotation.
+    ///
+    /// Unlike [`Trivias::leading_comments`], a comment on the same line as a preceding token
+    /// counts, as in `return /*#__PURE__*/ foo()`.
+    #[must_use]
+    pub fn has_pure_annotation(&self, source_text: &str, span: Span) -> bool {
+        let mut next_start = span.start;
+        for (start, comment) in self.comments.range(..span.start).rev() {
+            let full_span = comment.full_span(*start, source_text);
+            if !is_whitespace(source_text, full_span.end, next_start) {
+                return false;
+            }
+            if comment.is_pure() {
+                return true;
+            }
+            next_start = full_span.start;
+        }
+        false
+    }
+
+    /// Comments before `span` that belong to the node, in source order.
+    ///
+    /// These are the comments separated from the node by whitespace only,
+    /// excluding the ones on the same line as a preceding node such as `a; // comment`,
+    /// which are trailing comments of that node.
+    #[must_use]
+    pub fn leading_comments(&self, source_text: &str, span: Span) -> Vec<AttachedComment> {
+        let mut run = vec![];
+        let mut next_start = span.start;
+        for (start, comment) in self.comments.range(..span.start).rev() {
+            let full_span = comment.full_span(*start, source_text);
+            if !is_whitespace(source_text, full_span.end, next_start) {
+                break;
+            }
+            run.push((*start, *comment));
+            next_start = full_span.start;
+        }
+        run.reverse();
+
+        // `next_start` is now the start of the first comment in the run
+        let preceding_text =
+            source_text[..next_start as usize].trim_end_matches(is_whitespace_char);
+        let first_leading = match preceding_text.chars().last() {
+            Some(c) if can_end_node(c) => {
+                #[allow(clippy::cast_possible_truncation)]
+                let mut prev_end = preceding_text.len() as u32;
+                let mut index = run.len();
+                for (i, (start, comment)) in run.iter().enumerate() {
+                    let full_span = comment.full_span(*start, source_text);
+                    if has_newline(source_text, prev_end, full_span.start) {
+                        index = i;
+                        break;
+                    }
+                    // a single line comment ends the line
+                    if comment.is_single_line() {
+                        index = i + 1;
+                        break;
+                    }
+                    prev_end = full_span.end;
+                }
+                index
+            }
+            _ => 0,
+        };
+
+        run[first_leading..]
+            .iter()
+            .map(|(start, comment)| AttachedComment {
+                span: comment.text_span(*start, source_text),
+                comment: *comment,
+                has_newline: has_newline(
+                    source_text,
+                    comment.full_span(*start, source_text).end,
+                    span.start,
+                ),
+            })
+            .collect()
+    }
+
+    /// Comments after `span` that belong to the node, in source order.
+    ///
+    /// These are the comments on the same line as the end of the node,
+    /// or all comments up to a closing `}`, `)`, `]` or the end of the file.
+    #[must_use]
+    pub fn trailing_comments(&self, source_text: &str, span: Span) -> Vec<AttachedComment> {
+        let mut run = vec![];
+        let mut prev_end = span.end;
+        for (start, comment) in self.comments.range(span.end..) {
+            let full_span = comment.full_span(*start, source_text);
+            if !is_whitespace(source_text, prev_end, full_span.start) {
+                break;
+            }
+            run.push((*start, *comment));
+            prev_end = full_span.end;
+        }
+
+        let following_code =
+            source_text[prev_end as usize..].trim_start_matches(is_whitespace_char).chars().next();
+        let count = match following_code {
+            None | Some('}' | ')' | ']') => run.len(),
+            Some(_) => {
+                let mut prev_end = span.end;
+                let mut count = run.len();
+                for (i, (start, comment)) in run.iter().enumerate() {
+                    let full_span = comment.full_span(*start, source_text);
+                    if has_newline(source_text, prev_end, full_span.start) {
+                        count = i;
+                        break;
+                    }
+                    if comment.is_single_line() {
+                        count = i + 1;
+                        break;
+                    }
+                    prev_end = full_span.end;
+                }
+                count
+            }
+        };
+
+        run[..count]
+            .iter()
+            .map(|(start, comment)| AttachedComment {
+                span: comment.text_span(*start, source_text),
+                comment: *comment,
+                has_newline: has_newline(
+                    source_text,
+                    span.end,
+                    comment.full_span(*start, source_text).start,
+                ),
+            })
+            .collect()
+    }
+
+    /// Comments inside `span` that do not belong to any of the node's `children`,
+    /// such as the comment of an empty block statement `{ /* empty */ }`.
+    ///
+    /// Comments inside a child, or leading or trailing a child, are left to the child.
+    pub fn dangling_comments<'s>(
+        &'s self,
+        source_text: &'s str,
+        span: Span,
+        children: &[Span],
+    ) -> impl Iterator<Item = (Span, Comment)> + 's {
+        let mut excluded = children.to_vec();
+        for child in children {
+            excluded.extend(
+                self.leading_comments(source_text, *child)
+                    .into_iter()
+                    .chain(self.trailing_comments(source_text, *child))
+                    .map(|comment| comment.span),
+            );
+        }
+        self.comments.range(span.start..span.end).filter_map(move |(start, comment)| {
+            let text_span = comment.text_span(*start, source_text);
+            let is_excluded = excluded
+                .iter()
+                .any(|excluded| excluded.start <= text_span.start && text_span.end <= excluded.end);
+            (!is_excluded).then_some((text_span, *comment))
+        })
+    }
+}
+
+const fn is_whitespace_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{9}'
+            | '\u{b}'
+            | '\u{c}'
+            | ' '
+            | '\u{a0}'
+            | '\u{feff}'
+            | '\n'
+            | '\r'
+            | '\u{2028}'
+            | '\u{2029}'
+    )
+}
+
+/// Whether `c` can be the last character of a statement or expression,
+/// as opposed to punctuators such as `{` `,` `=` which are followed by another node
+fn can_end_node(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, ';' | '}' | ')' | ']' | '_' | '$' | '"' | '\'' | '`' | '/')
+}
+
+fn is_whitespace(source_text: &str, start: u32, end: u32) -> bool {
+    source_text[start as usize..end as usize].chars().all(is_whitespace_char)
+}
+
+const fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+fn has_newline(source_text: &str, start: u32, end: u32) -> bool {
+    source_text[start as usize..end as usize].chars().any(is_line_terminator)
 }
@@ -1,1622 +1,2554 @@
+// Auto-generated by `cargo run -p ast_codegen`, do not edit.
+
 //! AST Visitor Pattern
 //!
 //! See:
 //! * [visitor pattern](https://rust-unofficial.github.io/patterns/patterns/behavioural/visitor.html)
 //! * [rustc visitor](https://github.com/rust-lang/rust/blob/master/compiler/rustc_ast/src/visit.rs)
 
+#![allow(clippy::too_many_lines)]
+
 use oxc_allocator::Vec;
 
 #[allow(clippy::wildcard_imports)]
 use crate::{ast::*, ast_kind::AstKind};
 
+/// Visits every node of the AST in the order of the fields of the nodes.
+///
+/// `visit_*` is called for every node, the default implementations visit the children of the node.
+/// [`enter_node`](Visit::enter_node) and [`leave_node`](Visit::leave_node) are called
+/// around the children of every node with an [`AstKind`].
 pub trait Visit<'a>: Sized {
     fn enter_node(&mut self, _kind: AstKind<'a>) {}
     fn leave_node(&mut self, _kind: AstKind<'a>) {}
 
-    fn visit_program(&mut self, program: &'a Program<'a>) {
-        let kind = AstKind::Program(program);
+    fn visit_program(&mut self, node: &'a Program<'a>) {
+        let kind = AstKind::Program(node);
         self.enter_node(kind);
-        for directive in &program.directives {
-            self.visit_directive(directive);
+        for item in &node.directives {
+            self.visit_directive(item);
         }
-        self.visit_statements(&program.body);
+        self.visit_statements(&node.body);
         self.leave_node(kind);
     }
 
-    /* ----------  Statement ---------- */
+    fn visit_expression(&mut self, node: &'a Expression<'a>) {
+        self.visit_expression_match(node);
+    }
 
-    fn visit_statements(&mut self, stmts: &'a Vec<'a, Statement<'a>>) {
-        for stmt in stmts {
-            self.visit_statement(stmt);
+    fn visit_expression_match(&mut self, node: &'a Expression<'a>) {
+        match node {
+            Expression::BooleanLiteral(it) => {
+                self.visit_boolean_literal(it);
+            }
+            Expression::NullLiteral(it) => {
+                self.visit_null_literal(it);
+            }
+            Expression::NumberLiteral(it) => {
+                self.visit_number_literal(it);
+            }
+            Expression::BigintLiteral(it) => {
+                self.visit_bigint_literal(it);
+            }
+            Expression::RegExpLiteral(it) => {
+                self.visit_reg_exp_literal(it);
+            }
+            Expression::StringLiteral(it) => {
+                self.visit_string_literal(it);
+            }
+            Expression::TemplateLiteral(it) => {
+                self.visit_template_literal(it);
+            }
+            Expression::Identifier(it) => {
+                self.visit_identifier_reference(it);
+            }
+            Expression::MetaProperty(it) => {
+                self.visit_meta_property(it);
+            }
+            Expression::Super(it) => {
+                self.visit_super(it);
+            }
+            Expression::ArrayExpression(it) => {
+                self.visit_array_expression(it);
+            }
+            Expression::ArrowFunctionExpression(it) => {
+                self.visit_arrow_expression(it);
+            }
+            Expression::AssignmentExpression(it) => {
+                self.visit_assignment_expression(it);
+            }
+            Expression::AwaitExpression(it) => {
+                self.visit_await_expression(it);
+            }
+            Expression::BinaryExpression(it) => {
+                self.visit_binary_expression(it);
+            }
+            Expression::CallExpression(it) => {
+                self.visit_call_expression(it);
+            }
+            Expression::ChainExpression(it) => {
+                self.visit_chain_expression(it);
+            }
+            Expression::ClassExpression(it) => {
+                self.visit_class(it);
+            }
+            Expression::ConditionalExpression(it) => {
+                self.visit_conditional_expression(it);
+            }
+            Expression::FunctionExpression(it) => {
+                self.visit_function(it);
+            }
+            Expression::ImportExpression(it) => {
+                self.visit_import_expression(it);
+            }
+            Expression::LogicalExpression(it) => {
+                self.visit_logical_expression(it);
+            }
+            Expression::MemberExpression(it) => {
+                self.visit_member_expression(it);
+            }
+            Expression::NewExpression(it) => {
+                self.visit_new_expression(it);
+            }
+            Expression::ObjectExpression(it) => {
+                self.visit_object_expression(it);
+            }
+            Expression::ParenthesizedExpression(it) => {
+                self.visit_parenthesized_expression(it);
+            }
+            Expression::SequenceExpression(it) => {
+                self.visit_sequence_expression(it);
+            }
+            Expression::TaggedTemplateExpression(it) => {
+                self.visit_tagged_template_expression(it);
+            }
+            Expression::ThisExpression(it) => {
+                self.visit_this_expression(it);
+            }
+            Expression::UnaryExpression(it) => {
+                self.visit_unary_expression(it);
+            }
+            Expression::UpdateExpression(it) => {
+                self.visit_update_expression(it);
+            }
+            Expression::YieldExpression(it) => {
+                self.visit_yield_expression(it);
+            }
+            Expression::PrivateInExpression(it) => {
+                self.visit_private_in_expression(it);
+            }
+            Expression::JSXElement(it) => {
+                self.visit_jsx_element(it);
+            }
+            Expression::JSXFragment(it) => {
+                self.visit_jsx_fragment(it);
+            }
+            Expression::TSAsExpression(it) => {
+                self.visit_ts_as_expression(it);
+            }
+            Expression::TSSatisfiesExpression(it) => {
+                self.visit_ts_satisfies_expression(it);
+            }
+            Expression::TSTypeAssertion(it) => {
+                self.visit_ts_type_assertion(it);
+            }
+            Expression::TSNonNullExpression(it) => {
+                self.visit_ts_non_null_expression(it);
+            }
+            Expression::TSInstantiationExpression(it) => {
+                self.visit_ts_instantiation_expression(it);
+            }
+            Expression::InvalidExpression(it) => {
+                self.visit_invalid_expression(it);
+            }
         }
     }
 
-    fn visit_statement(&mut self, stmt: &'a Statement<'a>) {
-        self.visit_statement_match(stmt);
+    fn visit_identifier_name(&mut self, node: &'a IdentifierName) {
+        let kind = AstKind::IdentifierName(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_statement_match(&mut self, stmt: &'a Statement<'a>) {
-        match stmt {
-            Statement::BlockStatement(stmt) => self.visit_block_statement(stmt),
-            Statement::BreakStatement(stmt) => self.visit_break_statement(stmt),
-            Statement::ContinueStatement(stmt) => self.visit_continue_statement(stmt),
-            Statement::DebuggerStatement(stmt) => self.visit_debugger_statement(stmt),
-            Statement::DoWhileStatement(stmt) => self.visit_do_while_statement(stmt),
-            Statement::EmptyStatement(stmt) => self.visit_empty_statement(stmt),
-            Statement::ExpressionStatement(stmt) => self.visit_expression_statement(stmt),
-            Statement::ForInStatement(stmt) => self.visit_for_in_statement(stmt),
-            Statement::ForOfStatement(stmt) => self.visit_for_of_statement(stmt),
-            Statement::ForStatement(stmt) => self.visit_for_statement(stmt),
-            Statement::IfStatement(stmt) => self.visit_if_statement(stmt),
-            Statement::LabeledStatement(stmt) => self.visit_labeled_statement(stmt),
-            Statement::ReturnStatement(stmt) => self.visit_return_statement(stmt),
-            Statement::SwitchStatement(stmt) => self.visit_switch_statement(stmt),
-            Statement::ThrowStatement(stmt) => self.visit_throw_statement(stmt),
-            Statement::TryStatement(stmt) => self.visit_try_statement(stmt),
-            Statement::WhileStatement(stmt) => self.visit_while_statement(stmt),
-            Statement::WithStatement(stmt) => self.visit_with_statement(stmt),
-
-            Statement::ModuleDeclaration(decl) => self.visit_module_declaration(decl),
-            Statement::Declaration(decl) => self.visit_declaration(decl),
-        }
+    fn visit_identifier_reference(&mut self, node: &'a IdentifierReference) {
+        let kind = AstKind::IdentifierReference(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_block_statement(&mut self, stmt: &'a BlockStatement<'a>) {
-        let kind = AstKind::BlockStatement(stmt);
+    fn visit_binding_identifier(&mut self, node: &'a BindingIdentifier) {
+        let kind = AstKind::BindingIdentifier(node);
         self.enter_node(kind);
-        self.visit_statements(&stmt.body);
         self.leave_node(kind);
     }
 
-    fn visit_break_statement(&mut self, stmt: &'a BreakStatement) {
-        let kind = AstKind::BreakStatement(stmt);
+    fn visit_label_identifier(&mut self, node: &'a LabelIdentifier) {
+        let kind = AstKind::LabelIdentifier(node);
         self.enter_node(kind);
-        if let Some(break_target) = &stmt.label {
-            self.visit_label_identifier(break_target);
-        }
         self.leave_node(kind);
     }
 
-    fn visit_continue_statement(&mut self, stmt: &'a ContinueStatement) {
-        let kind = AstKind::ContinueStatement(stmt);
+    fn visit_this_expression(&mut self, node: &'a ThisExpression) {
+        let kind = AstKind::ThisExpression(node);
         self.enter_node(kind);
-        if let Some(continue_target) = &stmt.label {
-            self.visit_label_identifier(continue_target);
-        }
         self.leave_node(kind);
     }
 
-    fn visit_debugger_statement(&mut self, stmt: &'a DebuggerStatement) {
-        let kind = AstKind::DebuggerStatement(stmt);
+    fn visit_array_expression(&mut self, node: &'a ArrayExpression<'a>) {
+        let kind = AstKind::ArrayExpression(node);
         self.enter_node(kind);
+        for item in node.elements.iter().flatten() {
+            self.visit_argument(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_do_while_statement(&mut self, stmt: &'a DoWhileStatement<'a>) {
-        let kind = AstKind::DoWhileStatement(stmt);
+    fn visit_object_expression(&mut self, node: &'a ObjectExpression<'a>) {
+        let kind = AstKind::ObjectExpression(node);
         self.enter_node(kind);
-        self.visit_statement(&stmt.body);
-        self.visit_expression(&stmt.test);
+        for item in &node.properties {
+            self.visit_object_property(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_empty_statement(&mut self, stmt: &'a EmptyStatement) {
-        let kind = AstKind::EmptyStatement(stmt);
+    fn visit_object_property(&mut self, node: &'a ObjectProperty<'a>) {
+        match node {
+            ObjectProperty::Property(it) => {
+                self.visit_property(it);
+            }
+            ObjectProperty::SpreadProperty(it) => {
+                self.visit_spread_element(it);
+            }
+        }
+    }
+
+    fn visit_property(&mut self, node: &'a Property<'a>) {
+        let kind = AstKind::Property(node);
         self.enter_node(kind);
+        self.visit_property_key(&node.key);
+        self.visit_property_value(&node.value);
         self.leave_node(kind);
     }
 
-    fn visit_expression_statement(&mut self, stmt: &'a ExpressionStatement<'a>) {
-        let kind = AstKind::ExpressionStatement(stmt);
+    fn visit_property_key(&mut self, node: &'a PropertyKey<'a>) {
+        let kind = AstKind::PropertyKey(node);
         self.enter_node(kind);
-        self.visit_expression(&stmt.expression);
+        match node {
+            PropertyKey::Identifier(it) => {
+                self.visit_identifier_name(it);
+            }
+            PropertyKey::PrivateIdentifier(it) => {
+                self.visit_private_identifier(it);
+            }
+            PropertyKey::Expression(it) => {
+                self.visit_expression(it);
+            }
+        }
         self.leave_node(kind);
     }
 
-    fn visit_for_statement(&mut self, stmt: &'a ForStatement<'a>) {
-        let kind = AstKind::ForStatement(stmt);
+    fn visit_property_value(&mut self, node: &'a PropertyValue<'a>) {
+        let kind = AstKind::PropertyValue(node);
         self.enter_node(kind);
-        if let Some(init) = &stmt.init {
-            self.visit_for_statement_init(init);
-        }
-        if let Some(test) = &stmt.test {
-            self.visit_expression(test);
-        }
-        if let Some(update) = &stmt.update {
-            self.visit_expression(update);
+        match node {
+            PropertyValue::Pattern(it) => {
+                self.visit_binding_pattern(it);
+            }
+            PropertyValue::Expression(it) => {
+                self.visit_expression(it);
+            }
         }
-        self.visit_statement(&stmt.body);
         self.leave_node(kind);
     }
 
-    fn visit_for_statement_init(&mut self, init: &'a ForStatementInit<'a>) {
-        let kind = AstKind::ForStatementInit(init);
+    fn visit_template_literal(&mut self, node: &'a TemplateLiteral<'a>) {
+        let kind = AstKind::TemplateLiteral(node);
         self.enter_node(kind);
-        match init {
-            ForStatementInit::VariableDeclaration(decl) => {
-                self.visit_variable_declaration(decl);
-            }
-            ForStatementInit::Expression(expr) => self.visit_expression(expr),
+        for item in &node.quasis {
+            self.visit_template_element(item);
+        }
+        for item in &node.expressions {
+            self.visit_expression(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_for_in_statement(&mut self, stmt: &'a ForInStatement<'a>) {
-        let kind = AstKind::ForInStatement(stmt);
+    fn visit_tagged_template_expression(&mut self, node: &'a TaggedTemplateExpression<'a>) {
+        let kind = AstKind::TaggedTemplateExpression(node);
         self.enter_node(kind);
-        self.visit_for_statement_left(&stmt.left);
-        self.visit_expression(&stmt.right);
-        self.visit_statement(&stmt.body);
+        self.visit_expression(&node.tag);
+        self.visit_template_literal(&node.quasi);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_for_of_statement(&mut self, stmt: &'a ForOfStatement<'a>) {
-        let kind = AstKind::ForOfStatement(stmt);
+    fn visit_template_element(&mut self, node: &'a TemplateElement) {
+        let kind = AstKind::TemplateElement(node);
         self.enter_node(kind);
-        self.visit_for_statement_left(&stmt.left);
-        self.visit_expression(&stmt.right);
-        self.visit_statement(&stmt.body);
         self.leave_node(kind);
     }
 
-    fn visit_for_statement_left(&mut self, left: &'a ForStatementLeft<'a>) {
-        match left {
-            ForStatementLeft::VariableDeclaration(decl) => {
-                self.visit_variable_declaration(decl);
+    fn visit_member_expression(&mut self, node: &'a MemberExpression<'a>) {
+        let kind = AstKind::MemberExpression(node);
+        self.enter_node(kind);
+        match node {
+            MemberExpression::ComputedMemberExpression(it) => {
+                self.visit_computed_member_expression(it);
+            }
+            MemberExpression::StaticMemberExpression(it) => {
+                self.visit_static_member_expression(it);
+            }
+            MemberExpression::PrivateFieldExpression(it) => {
+                self.visit_private_field_expression(it);
             }
-            ForStatementLeft::AssignmentTarget(target) => self.visit_assignment_target(target),
         }
+        self.leave_node(kind);
     }
 
-    fn visit_if_statement(&mut self, stmt: &'a IfStatement<'a>) {
-        let kind = AstKind::IfStatement(stmt);
+    fn visit_computed_member_expression(&mut self, node: &'a ComputedMemberExpression<'a>) {
+        let kind = AstKind::ComputedMemberExpression(node);
         self.enter_node(kind);
-        self.visit_expression(&stmt.test);
-        self.visit_statement(&stmt.consequent);
-        if let Some(alternate) = &stmt.alternate {
-            self.visit_statement(alternate);
-        }
+        self.visit_expression(&node.object);
+        self.visit_expression(&node.expression);
         self.leave_node(kind);
     }
 
-    fn visit_labeled_statement(&mut self, stmt: &'a LabeledStatement<'a>) {
-        let kind = AstKind::LabeledStatement(stmt);
+    fn visit_static_member_expression(&mut self, node: &'a StaticMemberExpression<'a>) {
+        let kind = AstKind::StaticMemberExpression(node);
         self.enter_node(kind);
-        self.visit_label_identifier(&stmt.label);
-        self.visit_statement(&stmt.body);
+        self.visit_expression(&node.object);
+        self.visit_identifier_name(&node.property);
         self.leave_node(kind);
     }
 
-    fn visit_return_statement(&mut self, stmt: &'a ReturnStatement<'a>) {
-        let kind = AstKind::ReturnStatement(stmt);
+    fn visit_private_field_expression(&mut self, node: &'a PrivateFieldExpression<'a>) {
+        let kind = AstKind::PrivateFieldExpression(node);
         self.enter_node(kind);
-        if let Some(arg) = &stmt.argument {
-            self.visit_expression(arg);
-        }
+        self.visit_expression(&node.object);
+        self.visit_private_identifier(&node.field);
         self.leave_node(kind);
     }
 
-    fn visit_switch_statement(&mut self, stmt: &'a SwitchStatement<'a>) {
-        let kind = AstKind::SwitchStatement(stmt);
+    fn visit_call_expression(&mut self, node: &'a CallExpression<'a>) {
+        let kind = AstKind::CallExpression(node);
         self.enter_node(kind);
-        self.visit_expression(&stmt.discriminant);
-        for case in &stmt.cases {
-            self.visit_switch_case(case);
+        self.visit_expression(&node.callee);
+        for item in &node.arguments {
+            self.visit_argument(item);
+        }
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_switch_case(&mut self, case: &'a SwitchCase<'a>) {
-        let kind = AstKind::SwitchCase(case);
+    fn visit_new_expression(&mut self, node: &'a NewExpression<'a>) {
+        let kind = AstKind::NewExpression(node);
         self.enter_node(kind);
-        if let Some(expr) = &case.test {
-            self.visit_expression(expr);
+        self.visit_expression(&node.callee);
+        for item in &node.arguments {
+            self.visit_argument(item);
+        }
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
         }
-        self.visit_statements(&case.consequent);
         self.leave_node(kind);
     }
 
-    fn visit_throw_statement(&mut self, stmt: &'a ThrowStatement<'a>) {
-        let kind = AstKind::ThrowStatement(stmt);
+    fn visit_meta_property(&mut self, node: &'a MetaProperty) {
+        let kind = AstKind::MetaProperty(node);
         self.enter_node(kind);
-        self.visit_expression(&stmt.argument);
+        self.visit_identifier_name(&node.meta);
+        self.visit_identifier_name(&node.property);
         self.leave_node(kind);
     }
 
-    fn visit_try_statement(&mut self, stmt: &'a TryStatement<'a>) {
-        let kind = AstKind::TryStatement(stmt);
+    fn visit_spread_element(&mut self, node: &'a SpreadElement<'a>) {
+        let kind = AstKind::SpreadElement(node);
         self.enter_node(kind);
-        self.visit_block_statement(&stmt.block);
-        if let Some(handler) = &stmt.handler {
-            self.visit_catch_clause(handler);
-        }
-        if let Some(finalizer) = &stmt.finalizer {
-            self.visit_finally_clause(finalizer);
-        }
+        self.visit_expression(&node.argument);
         self.leave_node(kind);
     }
 
-    fn visit_catch_clause(&mut self, clause: &'a CatchClause<'a>) {
-        let kind = AstKind::CatchClause(clause);
+    fn visit_argument(&mut self, node: &'a Argument<'a>) {
+        let kind = AstKind::Argument(node);
         self.enter_node(kind);
-        if let Some(param) = &clause.param {
-            self.visit_pattern(param);
+        match node {
+            Argument::SpreadElement(it) => {
+                self.visit_spread_element(it);
+            }
+            Argument::Expression(it) => {
+                self.visit_expression(it);
+            }
         }
-        self.visit_statements(&clause.body.body);
         self.leave_node(kind);
     }
 
-    fn visit_finally_clause(&mut self, clause: &'a BlockStatement<'a>) {
-        let kind = AstKind::FinallyClause(clause);
+    fn visit_update_expression(&mut self, node: &'a UpdateExpression<'a>) {
+        let kind = AstKind::UpdateExpression(node);
         self.enter_node(kind);
-        self.visit_block_statement(clause);
+        self.visit_simple_assignment_target(&node.argument);
         self.leave_node(kind);
     }
 
-    fn visit_while_statement(&mut self, stmt: &'a WhileStatement<'a>) {
-        let kind = AstKind::WhileStatement(stmt);
+    fn visit_unary_expression(&mut self, node: &'a UnaryExpression<'a>) {
+        let kind = AstKind::UnaryExpression(node);
         self.enter_node(kind);
-        self.visit_expression(&stmt.test);
-        self.visit_statement(&stmt.body);
+        self.visit_expression(&node.argument);
         self.leave_node(kind);
     }
 
-    fn visit_with_statement(&mut self, stmt: &'a WithStatement<'a>) {
-        let kind = AstKind::WithStatement(stmt);
+    fn visit_binary_expression(&mut self, node: &'a BinaryExpression<'a>) {
+        let kind = AstKind::BinaryExpression(node);
         self.enter_node(kind);
-        self.visit_expression(&stmt.object);
-        self.visit_statement(&stmt.body);
+        self.visit_expression(&node.left);
+        self.visit_expression(&node.right);
         self.leave_node(kind);
     }
 
-    fn visit_directive(&mut self, directive: &'a Directive<'a>) {
-        let kind = AstKind::Directive(directive);
+    fn visit_private_in_expression(&mut self, node: &'a PrivateInExpression<'a>) {
+        let kind = AstKind::PrivateInExpression(node);
         self.enter_node(kind);
-        self.visit_string_literal(&directive.expression);
+        self.visit_private_identifier(&node.left);
+        self.visit_expression(&node.right);
         self.leave_node(kind);
     }
 
-    /* ----------  Declaration ---------- */
-
-    fn visit_variable_declaration(&mut self, decl: &'a VariableDeclaration<'a>) {
-        let kind = AstKind::VariableDeclaration(decl);
+    fn visit_logical_expression(&mut self, node: &'a LogicalExpression<'a>) {
+        let kind = AstKind::LogicalExpression(node);
         self.enter_node(kind);
-        for declarator in &decl.declarations {
-            self.visit_variable_declarator(declarator);
-        }
+        self.visit_expression(&node.left);
+        self.visit_expression(&node.right);
         self.leave_node(kind);
     }
 
-    fn visit_variable_declarator(&mut self, declarator: &'a VariableDeclarator<'a>) {
-        let kind = AstKind::VariableDeclarator(declarator);
+    fn visit_conditional_expression(&mut self, node: &'a ConditionalExpression<'a>) {
+        let kind = AstKind::ConditionalExpression(node);
         self.enter_node(kind);
-        self.visit_pattern(&declarator.id);
-        if let Some(init) = &declarator.init {
-            self.visit_expression(init);
-        }
+        self.visit_expression(&node.test);
+        self.visit_expression(&node.consequent);
+        self.visit_expression(&node.alternate);
         self.leave_node(kind);
     }
 
-    /* ----------  Function ---------- */
-
-    fn visit_function(&mut self, func: &'a Function<'a>) {
-        let kind = AstKind::Function(func);
+    fn visit_assignment_expression(&mut self, node: &'a AssignmentExpression<'a>) {
+        let kind = AstKind::AssignmentExpression(node);
         self.enter_node(kind);
-        if let Some(ident) = &func.id {
-            self.visit_binding_identifier(ident);
-        }
-        self.visit_formal_parameters(&func.params);
-        if let Some(body) = &func.body {
-            self.visit_function_body(body);
-        }
-        if let Some(parameters) = &func.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
-        }
-        if let Some(annotation) = &func.return_type {
-            self.visit_ts_type_annotation(annotation);
-        }
+        self.visit_assignment_target(&node.left);
+        self.visit_expression(&node.right);
         self.leave_node(kind);
     }
 
-    fn visit_function_body(&mut self, body: &'a FunctionBody<'a>) {
-        let kind = AstKind::FunctionBody(body);
+    fn visit_assignment_target(&mut self, node: &'a AssignmentTarget<'a>) {
+        let kind = AstKind::AssignmentTarget(node);
         self.enter_node(kind);
-        for directive in &body.directives {
-            self.visit_directive(directive);
+        match node {
+            AssignmentTarget::SimpleAssignmentTarget(it) => {
+                self.visit_simple_assignment_target(it);
+            }
+            AssignmentTarget::AssignmentTargetPattern(it) => {
+                self.visit_assignment_target_pattern(it);
+            }
         }
-        self.visit_statements(&body.statements);
         self.leave_node(kind);
     }
 
-    fn visit_formal_parameters(&mut self, params: &'a FormalParameters<'a>) {
-        let kind = AstKind::FormalParameters(params);
+    fn visit_simple_assignment_target(&mut self, node: &'a SimpleAssignmentTarget<'a>) {
+        let kind = AstKind::SimpleAssignmentTarget(node);
         self.enter_node(kind);
-        for param in &params.items {
-            self.visit_formal_parameter(param);
+        match node {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(it) => {
+                self.visit_identifier_reference(it);
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(it) => {
+                self.visit_member_expression(it);
+            }
+            SimpleAssignmentTarget::TSAsExpression(it) => {
+                self.visit_ts_as_expression(it);
+            }
+            SimpleAssignmentTarget::TSSatisfiesExpression(it) => {
+                self.visit_ts_satisfies_expression(it);
+            }
+            SimpleAssignmentTarget::TSNonNullExpression(it) => {
+                self.visit_ts_non_null_expression(it);
+            }
+            SimpleAssignmentTarget::TSTypeAssertion(it) => {
+                self.visit_ts_type_assertion(it);
+            }
         }
         self.leave_node(kind);
     }
 
-    fn visit_formal_parameter(&mut self, param: &'a FormalParameter<'a>) {
-        let kind = AstKind::FormalParameter(param);
-        self.enter_node(kind);
-        for decorator in &param.decorators {
-            self.visit_decorator(decorator);
+    fn visit_assignment_target_pattern(&mut self, node: &'a AssignmentTargetPattern<'a>) {
+        match node {
+            AssignmentTargetPattern::ArrayAssignmentTarget(it) => {
+                self.visit_array_assignment_target(it);
+            }
+            AssignmentTargetPattern::ObjectAssignmentTarget(it) => {
+                self.visit_object_assignment_target(it);
+            }
         }
-        self.visit_pattern(&param.pattern);
-        self.leave_node(kind);
     }
 
-    /* ----------  Class ---------- */
-
-    fn visit_decorator(&mut self, decorator: &'a Decorator<'a>) {
-        let kind = AstKind::Decorator(decorator);
+    fn visit_array_assignment_target(&mut self, node: &'a ArrayAssignmentTarget<'a>) {
+        let kind = AstKind::ArrayAssignmentTarget(node);
         self.enter_node(kind);
-        self.visit_expression(&decorator.expression);
+        for item in node.elements.iter().flatten() {
+            self.visit_assignment_target_maybe_default(item);
+        }
+        if let Some(item) = &node.rest {
+            self.visit_assignment_target(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_class(&mut self, class: &'a Class<'a>) {
-        // Class level decorators are transpiled as functions outside of the class taking the class
-        // itself as argument. They should be visited before class is entered. E.g., they inherit
-        // strict mode from the enclosing scope rather than from class.
-        for decorator in &class.decorators {
-            self.visit_decorator(decorator);
-        }
-        let kind = AstKind::Class(class);
+    fn visit_object_assignment_target(&mut self, node: &'a ObjectAssignmentTarget<'a>) {
+        let kind = AstKind::ObjectAssignmentTarget(node);
         self.enter_node(kind);
-        if let Some(id) = &class.id {
-            self.visit_binding_identifier(id);
+        for item in &node.properties {
+            self.visit_assignment_target_property(item);
         }
-        if let Some(parameters) = &class.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+        if let Some(item) = &node.rest {
+            self.visit_assignment_target(item);
         }
+        self.leave_node(kind);
+    }
 
-        if let Some(super_class) = &class.super_class {
-            self.visit_class_heritage(super_class);
-        }
-        if let Some(super_parameters) = &class.super_type_parameters {
-            self.visit_ts_type_parameter_instantiation(super_parameters);
+    fn visit_assignment_target_maybe_default(
+        &mut self,
+        node: &'a AssignmentTargetMaybeDefault<'a>,
+    ) {
+        match node {
+            AssignmentTargetMaybeDefault::AssignmentTarget(it) => {
+                self.visit_assignment_target(it);
+            }
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(it) => {
+                self.visit_assignment_target_with_default(it);
+            }
         }
-        self.visit_class_body(&class.body);
-        self.leave_node(kind);
     }
 
-    fn visit_class_heritage(&mut self, expr: &'a Expression<'a>) {
-        let kind = AstKind::ClassHeritage(expr);
+    fn visit_assignment_target_with_default(&mut self, node: &'a AssignmentTargetWithDefault<'a>) {
+        let kind = AstKind::AssignmentTargetWithDefault(node);
         self.enter_node(kind);
-        self.visit_expression(expr);
+        self.visit_assignment_target(&node.binding);
+        self.visit_expression(&node.init);
         self.leave_node(kind);
     }
 
-    fn visit_class_body(&mut self, body: &'a ClassBody<'a>) {
-        for elem in &body.body {
-            self.visit_class_element(elem);
-        }
-    }
-
-    fn visit_class_element(&mut self, elem: &'a ClassElement<'a>) {
-        match elem {
-            ClassElement::StaticBlock(block) => self.visit_static_block(block),
-            ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
-            ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
-            ClassElement::AccessorProperty(_def) => { /* TODO */ }
-            ClassElement::TSAbstractMethodDefinition(def) => {
-                self.visit_method_definition(&def.method_definition);
+    fn visit_assignment_target_property(&mut self, node: &'a AssignmentTargetProperty<'a>) {
+        match node {
+            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(it) => {
+                self.visit_assignment_target_property_identifier(it);
             }
-            ClassElement::TSAbstractPropertyDefinition(def) => {
-                self.visit_property_definition(&def.property_definition);
+            AssignmentTargetProperty::AssignmentTargetPropertyProperty(it) => {
+                self.visit_assignment_target_property_property(it);
             }
-            ClassElement::TSIndexSignature(_def) => {}
         }
     }
 
-    fn visit_static_block(&mut self, block: &'a StaticBlock<'a>) {
-        let kind = AstKind::StaticBlock(block);
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        node: &'a AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        let kind = AstKind::AssignmentTargetPropertyIdentifier(node);
         self.enter_node(kind);
-        self.visit_statements(&block.body);
+        self.visit_identifier_reference(&node.binding);
+        if let Some(item) = &node.init {
+            self.visit_expression(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_method_definition(&mut self, def: &'a MethodDefinition<'a>) {
-        let kind = AstKind::MethodDefinition(def);
+    fn visit_assignment_target_property_property(
+        &mut self,
+        node: &'a AssignmentTargetPropertyProperty<'a>,
+    ) {
+        let kind = AstKind::AssignmentTargetPropertyProperty(node);
         self.enter_node(kind);
-        for decorator in &def.decorators {
-            self.visit_decorator(decorator);
-        }
-        self.visit_property_key(&def.key);
-        self.visit_function(&def.value);
+        self.visit_property_key(&node.name);
+        self.visit_assignment_target_maybe_default(&node.binding);
         self.leave_node(kind);
     }
 
-    fn visit_property_definition(&mut self, def: &'a PropertyDefinition<'a>) {
-        let kind = AstKind::PropertyDefinition(def);
+    fn visit_sequence_expression(&mut self, node: &'a SequenceExpression<'a>) {
+        let kind = AstKind::SequenceExpression(node);
         self.enter_node(kind);
-        for decorator in &def.decorators {
-            self.visit_decorator(decorator);
-        }
-        self.visit_property_key(&def.key);
-        if let Some(value) = &def.value {
-            self.visit_expression(value);
-        }
-        if let Some(annotation) = &def.type_annotation {
-            self.visit_ts_type_annotation(annotation);
+        for item in &node.expressions {
+            self.visit_expression(item);
         }
         self.leave_node(kind);
     }
 
-    /* ----------  Expression ---------- */
-
-    fn visit_expression(&mut self, expr: &'a Expression<'a>) {
-        self.visit_expression_match(expr);
+    fn visit_super(&mut self, node: &'a Super) {
+        let kind = AstKind::Super(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_expression_match(&mut self, expr: &'a Expression<'a>) {
-        match expr {
-            Expression::BigintLiteral(lit) => self.visit_bigint_literal(lit),
-            Expression::BooleanLiteral(lit) => self.visit_boolean_literal(lit),
-            Expression::NullLiteral(lit) => self.visit_null_literal(lit),
-            Expression::NumberLiteral(lit) => self.visit_number_literal(lit),
-            Expression::RegExpLiteral(lit) => self.visit_reg_expr_literal(lit),
-            Expression::StringLiteral(lit) => self.visit_string_literal(lit),
-            Expression::TemplateLiteral(lit) => self.visit_template_literal(lit),
+    fn visit_await_expression(&mut self, node: &'a AwaitExpression<'a>) {
+        let kind = AstKind::AwaitExpression(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.argument);
+        self.leave_node(kind);
+    }
 
-            Expression::Identifier(ident) => self.visit_identifier_reference(ident),
-            Expression::MetaProperty(meta) => self.visit_meta_property(meta),
+    fn visit_chain_expression(&mut self, node: &'a ChainExpression<'a>) {
+        let kind = AstKind::ChainExpression(node);
+        self.enter_node(kind);
+        self.visit_chain_element(&node.expression);
+        self.leave_node(kind);
+    }
 
-            Expression::ArrayExpression(expr) => self.visit_array_expression(expr),
-            Expression::ArrowFunctionExpression(expr) => self.visit_arrow_expression(expr),
-            Expression::AssignmentExpression(expr) => self.visit_assignment_expression(expr),
-            Expression::AwaitExpression(expr) => self.visit_await_expression(expr),
-            Expression::BinaryExpression(expr) => self.visit_binary_expression(expr),
-            Expression::CallExpression(expr) => self.visit_call_expression(expr),
-            Expression::ChainExpression(expr) => self.visit_chain_expression(expr),
-            Expression::ClassExpression(expr) => self.visit_class(expr),
-            Expression::ConditionalExpression(expr) => self.visit_conditional_expression(expr),
-            Expression::FunctionExpression(expr) => self.visit_function(expr),
-            Expression::ImportExpression(expr) => self.visit_import_expression(expr),
-            Expression::LogicalExpression(expr) => self.visit_logical_expression(expr),
-            Expression::MemberExpression(expr) => self.visit_member_expression(expr),
-            Expression::NewExpression(expr) => self.visit_new_expression(expr),
-            Expression::ObjectExpression(expr) => self.visit_object_expression(expr),
-            Expression::ParenthesizedExpression(expr) => {
-                self.visit_parenthesized_expression(expr);
+    fn visit_chain_element(&mut self, node: &'a ChainElement<'a>) {
+        match node {
+            ChainElement::CallExpression(it) => {
+                self.visit_call_expression(it);
             }
-            Expression::PrivateInExpression(expr) => self.visit_private_in_expression(expr),
-            Expression::SequenceExpression(expr) => self.visit_sequence_expression(expr),
-            Expression::TaggedTemplateExpression(expr) => {
-                self.visit_tagged_template_expression(expr);
-            }
-            Expression::ThisExpression(expr) => self.visit_this_expression(expr),
-            Expression::UnaryExpression(expr) => self.visit_unary_expression(expr),
-            Expression::UpdateExpression(expr) => self.visit_update_expression(expr),
-            Expression::YieldExpression(expr) => self.visit_yield_expression(expr),
-            Expression::Super(expr) => self.visit_super(expr),
-            Expression::JSXElement(elem) => self.visit_jsx_element(elem),
-            Expression::JSXFragment(elem) => self.visit_jsx_fragment(elem),
-
-            Expression::TSAsExpression(expr) => self.visit_ts_as_expression(expr),
-            Expression::TSSatisfiesExpression(expr) => self.visit_ts_satisfies_expression(expr),
-            Expression::TSNonNullExpression(expr) => self.visit_ts_non_null_expression(expr),
-            Expression::TSTypeAssertion(expr) => self.visit_ts_type_assertion(expr),
-            Expression::TSInstantiationExpression(expr) => {
-                self.visit_ts_instantiation_expression(expr);
+            ChainElement::MemberExpression(it) => {
+                self.visit_member_expression(it);
             }
         }
     }
 
-    fn visit_meta_property(&mut self, meta: &'a MetaProperty) {
-        let kind = AstKind::MetaProperty(meta);
+    fn visit_parenthesized_expression(&mut self, node: &'a ParenthesizedExpression<'a>) {
+        let kind = AstKind::ParenthesizedExpression(node);
         self.enter_node(kind);
+        self.visit_expression(&node.expression);
         self.leave_node(kind);
     }
 
-    fn visit_array_expression(&mut self, expr: &'a ArrayExpression<'a>) {
-        let kind = AstKind::ArrayExpression(expr);
-        self.enter_node(kind);
-        for elem in expr.elements.iter().flatten() {
-            self.visit_argument(elem);
+    fn visit_statements(&mut self, node: &'a Vec<'a, Statement<'a>>) {
+        for item in node {
+            self.visit_statement(item);
         }
-        self.leave_node(kind);
     }
 
-    fn visit_argument(&mut self, arg: &'a Argument<'a>) {
-        let kind = AstKind::Argument(arg);
-        self.enter_node(kind);
-        match arg {
-            Argument::SpreadElement(spread) => self.visit_spread_element(spread),
-            Argument::Expression(expr) => self.visit_expression(expr),
+    fn visit_statement(&mut self, node: &'a Statement<'a>) {
+        self.visit_statement_match(node);
+    }
+
+    fn visit_statement_match(&mut self, node: &'a Statement<'a>) {
+        match node {
+            Statement::BlockStatement(it) => {
+                self.visit_block_statement(it);
+            }
+            Statement::BreakStatement(it) => {
+                self.visit_break_statement(it);
+            }
+            Statement::ContinueStatement(it) => {
+                self.visit_continue_statement(it);
+            }
+            Statement::DebuggerStatement(it) => {
+                self.visit_debugger_statement(it);
+            }
+            Statement::DoWhileStatement(it) => {
+                self.visit_do_while_statement(it);
+            }
+            Statement::EmptyStatement(it) => {
+                self.visit_empty_statement(it);
+            }
+            Statement::ExpressionStatement(it) => {
+                self.visit_expression_statement(it);
+            }
+            Statement::ForInStatement(it) => {
+                self.visit_for_in_statement(it);
+            }
+            Statement::ForOfStatement(it) => {
+                self.visit_for_of_statement(it);
+            }
+            Statement::ForStatement(it) => {
+                self.visit_for_statement(it);
+            }
+            Statement::IfStatement(it) => {
+                self.visit_if_statement(it);
+            }
+            Statement::LabeledStatement(it) => {
+                self.visit_labeled_statement(it);
+            }
+            Statement::ReturnStatement(it) => {
+                self.visit_return_statement(it);
+            }
+            Statement::SwitchStatement(it) => {
+                self.visit_switch_statement(it);
+            }
+            Statement::ThrowStatement(it) => {
+                self.visit_throw_statement(it);
+            }
+            Statement::TryStatement(it) => {
+                self.visit_try_statement(it);
+            }
+            Statement::WhileStatement(it) => {
+                self.visit_while_statement(it);
+            }
+            Statement::WithStatement(it) => {
+                self.visit_with_statement(it);
+            }
+            Statement::ModuleDeclaration(it) => {
+                self.visit_module_declaration(it);
+            }
+            Statement::Declaration(it) => {
+                self.visit_declaration(it);
+            }
+            Statement::InvalidStatement(it) => {
+                self.visit_invalid_statement(it);
+            }
         }
-        self.leave_node(kind);
     }
 
-    fn visit_spread_element(&mut self, elem: &'a SpreadElement<'a>) {
-        let kind = AstKind::SpreadElement(elem);
+    fn visit_directive(&mut self, node: &'a Directive<'a>) {
+        let kind = AstKind::Directive(node);
         self.enter_node(kind);
-        self.visit_expression(&elem.argument);
+        self.visit_string_literal(&node.expression);
         self.leave_node(kind);
     }
 
-    fn visit_assignment_expression(&mut self, expr: &'a AssignmentExpression<'a>) {
-        let kind = AstKind::AssignmentExpression(expr);
+    fn visit_block_statement(&mut self, node: &'a BlockStatement<'a>) {
+        let kind = AstKind::BlockStatement(node);
         self.enter_node(kind);
-        self.visit_assignment_target(&expr.left);
-        self.visit_expression(&expr.right);
+        self.visit_statements(&node.body);
         self.leave_node(kind);
     }
 
-    fn visit_arrow_expression(&mut self, expr: &'a ArrowExpression<'a>) {
-        let kind = AstKind::ArrowExpression(expr);
-        self.enter_node(kind);
-        self.visit_formal_parameters(&expr.params);
-        self.visit_function_body(&expr.body);
-        if let Some(parameters) = &expr.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+    fn visit_declaration(&mut self, node: &'a Declaration<'a>) {
+        match node {
+            Declaration::VariableDeclaration(it) => {
+                self.visit_variable_declaration(it);
+            }
+            Declaration::FunctionDeclaration(it) => {
+                self.visit_function(it);
+            }
+            Declaration::ClassDeclaration(it) => {
+                self.visit_class(it);
+            }
+            Declaration::TSTypeAliasDeclaration(it) => {
+                self.visit_ts_type_alias_declaration(it);
+            }
+            Declaration::TSInterfaceDeclaration(it) => {
+                self.visit_ts_interface_declaration(it);
+            }
+            Declaration::TSEnumDeclaration(it) => {
+                self.visit_ts_enum_declaration(it);
+            }
+            Declaration::TSModuleDeclaration(it) => {
+                self.visit_ts_module_declaration(it);
+            }
+            Declaration::TSImportEqualsDeclaration(it) => {
+                self.visit_ts_import_equals_declaration(it);
+            }
+            Declaration::FlowOpaqueTypeDeclaration(it) => {
+                self.visit_flow_opaque_type_declaration(it);
+            }
+            Declaration::FlowDeclareModuleExports(it) => {
+                self.visit_flow_declare_module_exports(it);
+            }
         }
-        self.leave_node(kind);
     }
 
-    fn visit_await_expression(&mut self, expr: &'a AwaitExpression<'a>) {
-        let kind = AstKind::AwaitExpression(expr);
+    fn visit_variable_declaration(&mut self, node: &'a VariableDeclaration<'a>) {
+        let kind = AstKind::VariableDeclaration(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.argument);
+        for item in &node.declarations {
+            self.visit_variable_declarator(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_binary_expression(&mut self, expr: &'a BinaryExpression<'a>) {
-        let kind = AstKind::BinaryExpression(expr);
+    fn visit_variable_declarator(&mut self, node: &'a VariableDeclarator<'a>) {
+        let kind = AstKind::VariableDeclarator(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.left);
-        self.visit_expression(&expr.right);
+        self.visit_binding_pattern(&node.id);
+        if let Some(item) = &node.init {
+            self.visit_expression(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_call_expression(&mut self, expr: &'a CallExpression<'a>) {
-        let kind = AstKind::CallExpression(expr);
+    fn visit_empty_statement(&mut self, node: &'a EmptyStatement) {
+        let kind = AstKind::EmptyStatement(node);
         self.enter_node(kind);
-        for arg in &expr.arguments {
-            self.visit_argument(arg);
-        }
-        self.visit_expression(&expr.callee);
-        if let Some(parameters) = &expr.type_parameters {
-            self.visit_ts_type_parameter_instantiation(parameters);
-        }
         self.leave_node(kind);
     }
 
-    fn visit_chain_expression(&mut self, expr: &'a ChainExpression<'a>) {
-        self.visit_chain_element(&expr.expression);
+    fn visit_expression_statement(&mut self, node: &'a ExpressionStatement<'a>) {
+        let kind = AstKind::ExpressionStatement(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
+        self.leave_node(kind);
     }
 
-    fn visit_chain_element(&mut self, elem: &'a ChainElement<'a>) {
-        match elem {
-            ChainElement::CallExpression(expr) => self.visit_call_expression(expr),
-            ChainElement::MemberExpression(expr) => self.visit_member_expression(expr),
+    fn visit_if_statement(&mut self, node: &'a IfStatement<'a>) {
+        let kind = AstKind::IfStatement(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.test);
+        self.visit_statement(&node.consequent);
+        if let Some(item) = &node.alternate {
+            self.visit_statement(item);
         }
+        self.leave_node(kind);
     }
 
-    fn visit_conditional_expression(&mut self, expr: &'a ConditionalExpression<'a>) {
-        let kind = AstKind::ConditionalExpression(expr);
+    fn visit_do_while_statement(&mut self, node: &'a DoWhileStatement<'a>) {
+        let kind = AstKind::DoWhileStatement(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.test);
-        self.visit_expression(&expr.consequent);
-        self.visit_expression(&expr.alternate);
+        self.visit_statement(&node.body);
+        self.visit_expression(&node.test);
         self.leave_node(kind);
     }
 
-    fn visit_import_expression(&mut self, expr: &'a ImportExpression<'a>) {
-        self.visit_expression(&expr.source);
-        for arg in &expr.arguments {
-            self.visit_expression(arg);
-        }
+    fn visit_while_statement(&mut self, node: &'a WhileStatement<'a>) {
+        let kind = AstKind::WhileStatement(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.test);
+        self.visit_statement(&node.body);
+        self.leave_node(kind);
     }
 
-    fn visit_logical_expression(&mut self, expr: &'a LogicalExpression<'a>) {
-        let kind = AstKind::LogicalExpression(expr);
+    fn visit_for_statement(&mut self, node: &'a ForStatement<'a>) {
+        let kind = AstKind::ForStatement(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.left);
-        self.visit_expression(&expr.right);
+        if let Some(item) = &node.init {
+            self.visit_for_statement_init(item);
+        }
+        if let Some(item) = &node.test {
+            self.visit_expression(item);
+        }
+        if let Some(item) = &node.update {
+            self.visit_expression(item);
+        }
+        self.visit_statement(&node.body);
         self.leave_node(kind);
     }
 
-    fn visit_member_expression(&mut self, expr: &'a MemberExpression<'a>) {
-        let kind = AstKind::MemberExpression(expr);
+    fn visit_for_statement_init(&mut self, node: &'a ForStatementInit<'a>) {
+        let kind = AstKind::ForStatementInit(node);
         self.enter_node(kind);
-        match expr {
-            MemberExpression::ComputedMemberExpression(expr) => {
-                self.visit_computed_member_expression(expr);
-            }
-            MemberExpression::StaticMemberExpression(expr) => {
-                self.visit_static_member_expression(expr);
+        match node {
+            ForStatementInit::VariableDeclaration(it) => {
+                self.visit_variable_declaration(it);
             }
-            MemberExpression::PrivateFieldExpression(expr) => {
-                self.visit_private_field_expression(expr);
+            ForStatementInit::Expression(it) => {
+                self.visit_expression(it);
             }
         }
         self.leave_node(kind);
     }
 
-    fn visit_computed_member_expression(&mut self, expr: &'a ComputedMemberExpression<'a>) {
-        self.visit_expression(&expr.object);
-        self.visit_expression(&expr.expression);
+    fn visit_for_in_statement(&mut self, node: &'a ForInStatement<'a>) {
+        let kind = AstKind::ForInStatement(node);
+        self.enter_node(kind);
+        self.visit_for_statement_left(&node.left);
+        self.visit_expression(&node.right);
+        self.visit_statement(&node.body);
+        self.leave_node(kind);
     }
 
-    fn visit_static_member_expression(&mut self, expr: &'a StaticMemberExpression<'a>) {
-        self.visit_expression(&expr.object);
-        self.visit_identifier_name(&expr.property);
+    fn visit_for_of_statement(&mut self, node: &'a ForOfStatement<'a>) {
+        let kind = AstKind::ForOfStatement(node);
+        self.enter_node(kind);
+        self.visit_for_statement_left(&node.left);
+        self.visit_expression(&node.right);
+        self.visit_statement(&node.body);
+        self.leave_node(kind);
     }
 
-    fn visit_private_field_expression(&mut self, expr: &'a PrivateFieldExpression<'a>) {
-        self.visit_expression(&expr.object);
-        self.visit_private_identifier(&expr.field);
+    fn visit_for_statement_left(&mut self, node: &'a ForStatementLeft<'a>) {
+        match node {
+            ForStatementLeft::VariableDeclaration(it) => {
+                self.visit_variable_declaration(it);
+            }
+            ForStatementLeft::AssignmentTarget(it) => {
+                self.visit_assignment_target(it);
+            }
+        }
     }
 
-    fn visit_new_expression(&mut self, expr: &'a NewExpression<'a>) {
-        let kind = AstKind::NewExpression(expr);
+    fn visit_continue_statement(&mut self, node: &'a ContinueStatement) {
+        let kind = AstKind::ContinueStatement(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.callee);
-        if let Some(parameters) = &expr.type_parameters {
-            self.visit_ts_type_parameter_instantiation(parameters);
-        }
-        for arg in &expr.arguments {
-            self.visit_argument(arg);
+        if let Some(item) = &node.label {
+            self.visit_label_identifier(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_object_expression(&mut self, expr: &'a ObjectExpression<'a>) {
-        let kind = AstKind::ObjectExpression(expr);
+    fn visit_break_statement(&mut self, node: &'a BreakStatement) {
+        let kind = AstKind::BreakStatement(node);
         self.enter_node(kind);
-        for prop in &expr.properties {
-            self.visit_object_property(prop);
+        if let Some(item) = &node.label {
+            self.visit_label_identifier(item);
         }
         self.leave_node(kind);
     }
 
-    fn enter_object_expression(&mut self, _expr: &'a ObjectExpression<'a>) {}
-
-    fn visit_object_property(&mut self, prop: &'a ObjectProperty<'a>) {
-        match prop {
-            ObjectProperty::Property(prop) => self.visit_property(prop),
-            ObjectProperty::SpreadProperty(elem) => self.visit_spread_element(elem),
+    fn visit_return_statement(&mut self, node: &'a ReturnStatement<'a>) {
+        let kind = AstKind::ReturnStatement(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.argument {
+            self.visit_expression(item);
         }
+        self.leave_node(kind);
     }
 
-    fn visit_property(&mut self, prop: &'a Property<'a>) {
-        let kind = AstKind::Property(prop);
+    fn visit_with_statement(&mut self, node: &'a WithStatement<'a>) {
+        let kind = AstKind::WithStatement(node);
         self.enter_node(kind);
-        self.visit_property_key(&prop.key);
-        self.visit_property_value(&prop.value);
+        self.visit_expression(&node.object);
+        self.visit_statement(&node.body);
         self.leave_node(kind);
     }
 
-    fn visit_property_key(&mut self, key: &'a PropertyKey<'a>) {
-        let kind = AstKind::PropertyKey(key);
+    fn visit_switch_statement(&mut self, node: &'a SwitchStatement<'a>) {
+        let kind = AstKind::SwitchStatement(node);
         self.enter_node(kind);
-        match key {
-            PropertyKey::Identifier(ident) => self.visit_identifier_name(ident),
-            PropertyKey::PrivateIdentifier(ident) => self.visit_private_identifier(ident),
-            PropertyKey::Expression(expr) => self.visit_expression(expr),
+        self.visit_expression(&node.discriminant);
+        for item in &node.cases {
+            self.visit_switch_case(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_property_value(&mut self, value: &'a PropertyValue<'a>) {
-        let kind = AstKind::PropertyValue(value);
+    fn visit_switch_case(&mut self, node: &'a SwitchCase<'a>) {
+        let kind = AstKind::SwitchCase(node);
         self.enter_node(kind);
-        match value {
-            PropertyValue::Pattern(pat) => self.visit_pattern(pat),
-            PropertyValue::Expression(expr) => self.visit_expression(expr),
+        if let Some(item) = &node.test {
+            self.visit_expression(item);
         }
+        self.visit_statements(&node.consequent);
         self.leave_node(kind);
     }
 
-    fn visit_parenthesized_expression(&mut self, expr: &'a ParenthesizedExpression<'a>) {
-        let kind = AstKind::ParenthesizedExpression(expr);
+    fn visit_labeled_statement(&mut self, node: &'a LabeledStatement<'a>) {
+        let kind = AstKind::LabeledStatement(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.expression);
+        self.visit_label_identifier(&node.label);
+        self.visit_statement(&node.body);
         self.leave_node(kind);
     }
 
-    fn visit_private_in_expression(&mut self, expr: &'a PrivateInExpression<'a>) {
-        self.visit_private_identifier(&expr.left);
-        self.visit_expression(&expr.right);
-    }
-
-    fn visit_sequence_expression(&mut self, expr: &'a SequenceExpression<'a>) {
-        let kind = AstKind::SequenceExpression(expr);
+    fn visit_throw_statement(&mut self, node: &'a ThrowStatement<'a>) {
+        let kind = AstKind::ThrowStatement(node);
         self.enter_node(kind);
-        for expr in &expr.expressions {
-            self.visit_expression(expr);
-        }
+        self.visit_expression(&node.argument);
         self.leave_node(kind);
     }
 
-    fn visit_tagged_template_expression(&mut self, expr: &'a TaggedTemplateExpression<'a>) {
-        let kind = AstKind::TaggedTemplateExpression(expr);
+    fn visit_try_statement(&mut self, node: &'a TryStatement<'a>) {
+        let kind = AstKind::TryStatement(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.tag);
-        self.visit_template_literal(&expr.quasi);
+        self.visit_block_statement(&node.block);
+        if let Some(item) = &node.handler {
+            self.visit_catch_clause(item);
+        }
+        if let Some(item) = &node.finalizer {
+            self.visit_finally_clause(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_this_expression(&mut self, expr: &'a ThisExpression) {
-        let kind = AstKind::ThisExpression(expr);
+    fn visit_finally_clause(&mut self, node: &'a BlockStatement<'a>) {
+        let kind = AstKind::FinallyClause(node);
         self.enter_node(kind);
+        self.visit_block_statement(node);
         self.leave_node(kind);
     }
 
-    fn visit_unary_expression(&mut self, expr: &'a UnaryExpression<'a>) {
-        let kind = AstKind::UnaryExpression(expr);
+    fn visit_catch_clause(&mut self, node: &'a CatchClause<'a>) {
+        let kind = AstKind::CatchClause(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.argument);
+        if let Some(item) = &node.param {
+            self.visit_binding_pattern(item);
+        }
+        self.visit_block_statement(&node.body);
         self.leave_node(kind);
     }
 
-    fn visit_update_expression(&mut self, expr: &'a UpdateExpression<'a>) {
-        let kind = AstKind::UpdateExpression(expr);
+    fn visit_debugger_statement(&mut self, node: &'a DebuggerStatement) {
+        let kind = AstKind::DebuggerStatement(node);
         self.enter_node(kind);
-        self.visit_simple_assignment_target(&expr.argument);
         self.leave_node(kind);
     }
 
-    fn visit_yield_expression(&mut self, expr: &'a YieldExpression<'a>) {
-        let kind = AstKind::YieldExpression(expr);
+    fn visit_invalid_statement(&mut self, node: &'a InvalidStatement) {
+        let kind = AstKind::InvalidStatement(node);
         self.enter_node(kind);
-        if let Some(argument) = &expr.argument {
-            self.visit_expression(argument);
-        }
         self.leave_node(kind);
     }
 
-    fn visit_super(&mut self, expr: &'a Super) {
-        let kind = AstKind::Super(expr);
+    fn visit_invalid_expression(&mut self, node: &'a InvalidExpression) {
+        let kind = AstKind::InvalidExpression(node);
         self.enter_node(kind);
         self.leave_node(kind);
     }
 
-    fn visit_assignment_target(&mut self, target: &'a AssignmentTarget<'a>) {
-        let kind = AstKind::AssignmentTarget(target);
+    fn visit_binding_pattern(&mut self, node: &'a BindingPattern<'a>) {
+        let kind = AstKind::BindingPattern(node);
         self.enter_node(kind);
-        match target {
-            AssignmentTarget::SimpleAssignmentTarget(target) => {
-                self.visit_simple_assignment_target(target);
-            }
-            AssignmentTarget::AssignmentTargetPattern(pat) => {
-                self.visit_assignment_target_pattern(pat);
-            }
+        self.visit_binding_pattern_kind(&node.kind);
+        if let Some(item) = &node.type_annotation {
+            self.visit_ts_type_annotation(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_simple_assignment_target(&mut self, target: &'a SimpleAssignmentTarget<'a>) {
-        let kind = AstKind::SimpleAssignmentTarget(target);
-        self.enter_node(kind);
-        match target {
-            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
-                self.visit_identifier_reference(ident);
-            }
-            SimpleAssignmentTarget::MemberAssignmentTarget(expr) => {
-                self.visit_member_expression(expr);
+    fn visit_binding_pattern_kind(&mut self, node: &'a BindingPatternKind<'a>) {
+        match node {
+            BindingPatternKind::BindingIdentifier(it) => {
+                self.visit_binding_identifier(it);
             }
-            SimpleAssignmentTarget::TSAsExpression(expr) => {
-                self.visit_expression(&expr.expression);
+            BindingPatternKind::ObjectPattern(it) => {
+                self.visit_object_pattern(it);
             }
-            SimpleAssignmentTarget::TSSatisfiesExpression(expr) => {
-                self.visit_expression(&expr.expression);
+            BindingPatternKind::ArrayPattern(it) => {
+                self.visit_array_pattern(it);
             }
-            SimpleAssignmentTarget::TSNonNullExpression(expr) => {
-                self.visit_expression(&expr.expression);
+            BindingPatternKind::RestElement(it) => {
+                self.visit_rest_element(it);
             }
-            SimpleAssignmentTarget::TSTypeAssertion(expr) => {
-                self.visit_expression(&expr.expression);
+            BindingPatternKind::AssignmentPattern(it) => {
+                self.visit_assignment_pattern(it);
             }
         }
-        self.leave_node(kind);
     }
 
-    fn visit_assignment_target_pattern(&mut self, pat: &'a AssignmentTargetPattern<'a>) {
-        match pat {
-            AssignmentTargetPattern::ArrayAssignmentTarget(target) => {
-                self.visit_array_assignment_target(target);
-            }
-            AssignmentTargetPattern::ObjectAssignmentTarget(target) => {
-                self.visit_object_assignment_target(target);
-            }
-        }
+    fn visit_assignment_pattern(&mut self, node: &'a AssignmentPattern<'a>) {
+        let kind = AstKind::AssignmentPattern(node);
+        self.enter_node(kind);
+        self.visit_binding_pattern(&node.left);
+        self.visit_expression(&node.right);
+        self.leave_node(kind);
     }
 
-    fn visit_array_assignment_target(&mut self, target: &'a ArrayAssignmentTarget<'a>) {
-        for element in target.elements.iter().flatten() {
-            self.visit_assignment_target_maybe_default(element);
-        }
-        if let Some(target) = &target.rest {
-            self.visit_assignment_target(target);
+    fn visit_object_pattern(&mut self, node: &'a ObjectPattern<'a>) {
+        let kind = AstKind::ObjectPattern(node);
+        self.enter_node(kind);
+        for item in &node.properties {
+            self.visit_object_pattern_property(item);
         }
+        self.leave_node(kind);
     }
 
-    fn visit_assignment_target_maybe_default(
-        &mut self,
-        target: &'a AssignmentTargetMaybeDefault<'a>,
-    ) {
-        match target {
-            AssignmentTargetMaybeDefault::AssignmentTarget(target) => {
-                self.visit_assignment_target(target);
+    fn visit_object_pattern_property(&mut self, node: &'a ObjectPatternProperty<'a>) {
+        match node {
+            ObjectPatternProperty::Property(it) => {
+                self.visit_property(it);
             }
-            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
-                self.visit_assignment_target_with_default(target);
+            ObjectPatternProperty::RestElement(it) => {
+                self.visit_rest_element(it);
             }
         }
     }
 
-    fn visit_assignment_target_with_default(
-        &mut self,
-        target: &'a AssignmentTargetWithDefault<'a>,
-    ) {
-        let kind = AstKind::AssignmentTargetWithDefault(target);
+    fn visit_array_pattern(&mut self, node: &'a ArrayPattern<'a>) {
+        let kind = AstKind::ArrayPattern(node);
         self.enter_node(kind);
-        self.visit_assignment_target(&target.binding);
-        self.visit_expression(&target.init);
+        for item in node.elements.iter().flatten() {
+            self.visit_binding_pattern(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_object_assignment_target(&mut self, target: &'a ObjectAssignmentTarget<'a>) {
-        for property in &target.properties {
-            self.visit_assignment_target_property(property);
-        }
-        if let Some(target) = &target.rest {
-            self.visit_assignment_target(target);
-        }
+    fn visit_rest_element(&mut self, node: &'a RestElement<'a>) {
+        let kind = AstKind::RestElement(node);
+        self.enter_node(kind);
+        self.visit_binding_pattern(&node.argument);
+        self.leave_node(kind);
     }
 
-    fn visit_assignment_target_property(&mut self, property: &'a AssignmentTargetProperty<'a>) {
-        match property {
-            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
-                self.visit_assignment_target_property_identifier(ident);
-            }
-            AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
-                self.visit_assignment_target_property_property(prop);
-            }
+    fn visit_function(&mut self, node: &'a Function<'a>) {
+        let kind = AstKind::Function(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
         }
-    }
-
-    fn visit_assignment_target_property_identifier(
-        &mut self,
-        ident: &'a AssignmentTargetPropertyIdentifier<'a>,
-    ) {
-        self.visit_identifier_reference(&ident.binding);
-        if let Some(expr) = &ident.init {
-            self.visit_expression(expr);
+        if let Some(item) = &node.id {
+            self.visit_binding_identifier(item);
         }
-    }
-
-    fn visit_assignment_target_property_property(
-        &mut self,
-        property: &'a AssignmentTargetPropertyProperty<'a>,
-    ) {
-        self.visit_property_key(&property.name);
-        self.visit_assignment_target_maybe_default(&property.binding);
-    }
-
-    /* ----------  Expression ---------- */
-
-    fn visit_jsx_element(&mut self, elem: &'a JSXElement<'a>) {
-        self.visit_jsx_opening_element(&elem.opening_element);
-        for child in &elem.children {
-            self.visit_jsx_child(child);
+        self.visit_formal_parameters(&node.params);
+        if let Some(item) = &node.body {
+            self.visit_function_body(item);
+        }
+        if let Some(item) = &node.return_type {
+            self.visit_ts_type_annotation(item);
         }
+        self.leave_node(kind);
     }
 
-    fn visit_jsx_opening_element(&mut self, elem: &'a JSXOpeningElement<'a>) {
-        let kind = AstKind::JSXOpeningElement(elem);
+    fn visit_formal_parameters(&mut self, node: &'a FormalParameters<'a>) {
+        let kind = AstKind::FormalParameters(node);
         self.enter_node(kind);
-        self.visit_jsx_element_name(&elem.name);
-        for attribute in &elem.attributes {
-            self.visit_jsx_attribute_item(attribute);
+        for item in &node.items {
+            self.visit_formal_parameter(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_jsx_element_name(&mut self, name: &'a JSXElementName<'a>) {
-        let kind = AstKind::JSXElementName(name);
+    fn visit_formal_parameter(&mut self, node: &'a FormalParameter<'a>) {
+        let kind = AstKind::FormalParameter(node);
         self.enter_node(kind);
+        self.visit_binding_pattern(&node.pattern);
+        for item in &node.decorators {
+            self.visit_decorator(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_jsx_attribute_item(&mut self, item: &'a JSXAttributeItem<'a>) {
-        match &item {
-            JSXAttributeItem::Attribute(attribute) => self.visit_jsx_attribute(attribute),
-            JSXAttributeItem::SpreadAttribute(attribute) => {
-                self.visit_jsx_spread_attribute(attribute);
-            }
+    fn visit_function_body(&mut self, node: &'a FunctionBody<'a>) {
+        let kind = AstKind::FunctionBody(node);
+        self.enter_node(kind);
+        for item in &node.directives {
+            self.visit_directive(item);
         }
+        self.visit_statements(&node.statements);
+        self.leave_node(kind);
     }
 
-    fn visit_jsx_attribute(&mut self, attribute: &'a JSXAttribute<'a>) {
-        if let Some(value) = &attribute.value {
-            self.visit_jsx_attribute_value(value);
+    fn visit_arrow_expression(&mut self, node: &'a ArrowExpression<'a>) {
+        let kind = AstKind::ArrowExpression(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
         }
+        self.visit_formal_parameters(&node.params);
+        self.visit_function_body(&node.body);
+        if let Some(item) = &node.return_type {
+            self.visit_ts_type_annotation(item);
+        }
+        self.leave_node(kind);
     }
 
-    fn visit_jsx_spread_attribute(&mut self, attribute: &'a JSXSpreadAttribute<'a>) {
-        self.visit_expression(&attribute.argument);
+    fn visit_yield_expression(&mut self, node: &'a YieldExpression<'a>) {
+        let kind = AstKind::YieldExpression(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.argument {
+            self.visit_expression(item);
+        }
+        self.leave_node(kind);
     }
 
-    fn visit_jsx_attribute_value(&mut self, value: &'a JSXAttributeValue<'a>) {
-        match value {
-            JSXAttributeValue::ExpressionContainer(expr) => {
-                self.visit_jsx_expression_container(expr);
+    fn visit_class(&mut self, node: &'a Class<'a>) {
+        // Class level decorators are transpiled as functions outside of the class taking the class
+        // itself as argument. They should be visited before class is entered. E.g., they inherit
+        // strict mode from the enclosing scope rather than from class.
+        for item in &node.decorators {
+            self.visit_decorator(item);
+        }
+        let kind = AstKind::Class(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
+        }
+        if let Some(item) = &node.id {
+            self.visit_binding_identifier(item);
+        }
+        if let Some(item) = &node.super_class {
+            self.visit_class_heritage(item);
+        }
+        self.visit_class_body(&node.body);
+        if let Some(item) = &node.super_type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
+        }
+        if let Some(item) = &node.implements {
+            for item in item {
+                self.visit_ts_class_implements(item);
             }
-            JSXAttributeValue::Element(elem) => self.visit_jsx_element(elem),
-            JSXAttributeValue::Fragment(elem) => self.visit_jsx_fragment(elem),
-            JSXAttributeValue::StringLiteral(_) => {}
         }
+        self.leave_node(kind);
     }
 
-    fn visit_jsx_expression_container(&mut self, expr: &'a JSXExpressionContainer<'a>) {
-        self.visit_jsx_expression(&expr.expression);
+    fn visit_class_heritage(&mut self, node: &'a Expression<'a>) {
+        let kind = AstKind::ClassHeritage(node);
+        self.enter_node(kind);
+        self.visit_expression(node);
+        self.leave_node(kind);
     }
 
-    fn visit_jsx_expression(&mut self, expr: &'a JSXExpression<'a>) {
-        match expr {
-            JSXExpression::Expression(expr) => self.visit_expression(expr),
-            JSXExpression::EmptyExpression(_) => {}
+    fn visit_class_body(&mut self, node: &'a ClassBody<'a>) {
+        let kind = AstKind::ClassBody(node);
+        self.enter_node(kind);
+        for item in &node.body {
+            self.visit_class_element(item);
         }
+        self.leave_node(kind);
     }
 
-    fn visit_jsx_fragment(&mut self, elem: &'a JSXFragment<'a>) {
-        for child in &elem.children {
-            self.visit_jsx_child(child);
+    fn visit_class_element(&mut self, node: &'a ClassElement<'a>) {
+        match node {
+            ClassElement::StaticBlock(it) => {
+                self.visit_static_block(it);
+            }
+            ClassElement::MethodDefinition(it) => {
+                self.visit_method_definition(it);
+            }
+            ClassElement::PropertyDefinition(it) => {
+                self.visit_property_definition(it);
+            }
+            ClassElement::AccessorProperty(it) => {
+                self.visit_accessor_property(it);
+            }
+            ClassElement::TSAbstractMethodDefinition(it) => {
+                self.visit_ts_abstract_method_definition(it);
+            }
+            ClassElement::TSAbstractPropertyDefinition(it) => {
+                self.visit_ts_abstract_property_definition(it);
+            }
+            ClassElement::TSIndexSignature(it) => {
+                self.visit_ts_index_signature(it);
+            }
         }
     }
 
-    fn visit_jsx_child(&mut self, child: &'a JSXChild<'a>) {
-        match child {
-            JSXChild::Element(elem) => self.visit_jsx_element(elem),
-            JSXChild::Fragment(elem) => self.visit_jsx_fragment(elem),
-            JSXChild::ExpressionContainer(expr) => self.visit_jsx_expression_container(expr),
-            JSXChild::Spread(expr) => self.visit_jsx_spread_child(expr),
-            JSXChild::Text(_) => {}
+    fn visit_method_definition(&mut self, node: &'a MethodDefinition<'a>) {
+        let kind = AstKind::MethodDefinition(node);
+        self.enter_node(kind);
+        self.visit_property_key(&node.key);
+        self.visit_function(&node.value);
+        for item in &node.decorators {
+            self.visit_decorator(item);
         }
+        self.leave_node(kind);
+    }
+
+    fn visit_property_definition(&mut self, node: &'a PropertyDefinition<'a>) {
+        let kind = AstKind::PropertyDefinition(node);
+        self.enter_node(kind);
+        self.visit_property_key(&node.key);
+        if let Some(item) = &node.value {
+            self.visit_expression(item);
+        }
+        if let Some(item) = &node.type_annotation {
+            self.visit_ts_type_annotation(item);
+        }
+        for item in &node.decorators {
+            self.visit_decorator(item);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_private_identifier(&mut self, node: &'a PrivateIdentifier) {
+        let kind = AstKind::PrivateIdentifier(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_jsx_spread_child(&mut self, child: &'a JSXSpreadChild<'a>) {
-        self.visit_expression(&child.expression);
+    fn visit_static_block(&mut self, node: &'a StaticBlock<'a>) {
+        let kind = AstKind::StaticBlock(node);
+        self.enter_node(kind);
+        self.visit_statements(&node.body);
+        self.leave_node(kind);
     }
 
-    /* ----------  Pattern ---------- */
+    fn visit_module_declaration(&mut self, node: &'a ModuleDeclaration<'a>) {
+        let kind = AstKind::ModuleDeclaration(node);
+        self.enter_node(kind);
+        self.visit_module_declaration_kind(&node.kind);
+        self.leave_node(kind);
+    }
 
-    fn visit_pattern(&mut self, pat: &'a BindingPattern<'a>) {
-        match &pat.kind {
-            BindingPatternKind::BindingIdentifier(ident) => {
-                self.visit_binding_identifier(ident);
+    fn visit_module_declaration_kind(&mut self, node: &'a ModuleDeclarationKind<'a>) {
+        match node {
+            ModuleDeclarationKind::ImportDeclaration(it) => {
+                self.visit_import_declaration(it);
+            }
+            ModuleDeclarationKind::ExportAllDeclaration(it) => {
+                self.visit_export_all_declaration(it);
+            }
+            ModuleDeclarationKind::ExportDefaultDeclaration(it) => {
+                self.visit_export_default_declaration(it);
+            }
+            ModuleDeclarationKind::ExportNamedDeclaration(it) => {
+                self.visit_export_named_declaration(it);
+            }
+            ModuleDeclarationKind::TSExportAssignment(it) => {
+                self.visit_ts_export_assignment(it);
+            }
+            ModuleDeclarationKind::TSNamespaceExportDeclaration(it) => {
+                self.visit_ts_namespace_export_declaration(it);
             }
-            BindingPatternKind::ObjectPattern(pat) => self.visit_object_pattern(pat),
-            BindingPatternKind::ArrayPattern(pat) => self.visit_array_pattern(pat),
-            BindingPatternKind::RestElement(pat) => self.visit_rest_element(pat),
-            BindingPatternKind::AssignmentPattern(pat) => self.visit_assignment_pattern(pat),
-        }
-        if let Some(type_annotation) = &pat.type_annotation {
-            self.visit_ts_type_annotation(type_annotation);
         }
     }
 
-    fn visit_binding_identifier(&mut self, ident: &'a BindingIdentifier) {
-        let kind = AstKind::BindingIdentifier(ident);
+    fn visit_accessor_property(&mut self, node: &'a AccessorProperty<'a>) {
+        let kind = AstKind::AccessorProperty(node);
         self.enter_node(kind);
+        self.visit_property_key(&node.key);
+        if let Some(item) = &node.value {
+            self.visit_expression(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_object_pattern(&mut self, pat: &'a ObjectPattern<'a>) {
-        let kind = AstKind::ObjectPattern(pat);
+    fn visit_import_expression(&mut self, node: &'a ImportExpression<'a>) {
+        let kind = AstKind::ImportExpression(node);
         self.enter_node(kind);
-        for prop in &pat.properties {
-            self.visit_object_pattern_property(prop);
+        self.visit_expression(&node.source);
+        for item in &node.arguments {
+            self.visit_expression(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_object_pattern_property(&mut self, prop: &'a ObjectPatternProperty<'a>) {
-        match prop {
-            ObjectPatternProperty::Property(prop) => self.visit_property(prop),
-            ObjectPatternProperty::RestElement(prop) => self.visit_rest_element(prop),
+    fn visit_import_declaration(&mut self, node: &'a ImportDeclaration<'a>) {
+        for item in &node.specifiers {
+            self.visit_import_declaration_specifier(item);
+        }
+        self.visit_string_literal(&node.source);
+        if let Some(item) = &node.assertions {
+            for item in item {
+                self.visit_import_attribute(item);
+            }
         }
     }
 
-    fn visit_array_pattern(&mut self, pat: &'a ArrayPattern<'a>) {
-        let kind = AstKind::ArrayPattern(pat);
-        self.enter_node(kind);
-        for pat in pat.elements.iter().flatten() {
-            self.visit_pattern(pat);
+    fn visit_import_declaration_specifier(&mut self, node: &'a ImportDeclarationSpecifier) {
+        match node {
+            ImportDeclarationSpecifier::ImportSpecifier(it) => {
+                self.visit_import_specifier(it);
+            }
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(it) => {
+                self.visit_import_default_specifier(it);
+            }
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(it) => {
+                self.visit_import_namespace_specifier(it);
+            }
         }
-        self.leave_node(kind);
     }
 
-    fn visit_rest_element(&mut self, pat: &'a RestElement<'a>) {
-        let kind = AstKind::RestElement(pat);
+    fn visit_import_specifier(&mut self, node: &'a ImportSpecifier) {
+        let kind = AstKind::ImportSpecifier(node);
         self.enter_node(kind);
-        self.visit_pattern(&pat.argument);
+        self.visit_module_export_name(&node.imported);
+        self.visit_binding_identifier(&node.local);
         self.leave_node(kind);
     }
 
-    fn visit_assignment_pattern(&mut self, pat: &'a AssignmentPattern<'a>) {
-        let kind = AstKind::AssignmentPattern(pat);
+    fn visit_import_default_specifier(&mut self, node: &'a ImportDefaultSpecifier) {
+        let kind = AstKind::ImportDefaultSpecifier(node);
         self.enter_node(kind);
-        self.visit_pattern(&pat.left);
-        self.visit_expression(&pat.right);
+        self.visit_binding_identifier(&node.local);
         self.leave_node(kind);
     }
 
-    /* ----------  Identifier ---------- */
-
-    fn visit_identifier_reference(&mut self, ident: &'a IdentifierReference) {
-        let kind = AstKind::IdentifierReference(ident);
+    fn visit_import_namespace_specifier(&mut self, node: &'a ImportNamespaceSpecifier) {
+        let kind = AstKind::ImportNamespaceSpecifier(node);
         self.enter_node(kind);
+        self.visit_binding_identifier(&node.local);
         self.leave_node(kind);
     }
 
-    fn visit_private_identifier(&mut self, ident: &'a PrivateIdentifier) {
-        let kind = AstKind::PrivateIdentifier(ident);
+    fn visit_import_attribute(&mut self, node: &'a ImportAttribute) {
+        let kind = AstKind::ImportAttribute(node);
         self.enter_node(kind);
+        self.visit_import_attribute_key(&node.key);
+        self.visit_string_literal(&node.value);
         self.leave_node(kind);
     }
 
-    fn visit_label_identifier(&mut self, ident: &'a LabelIdentifier) {
-        let kind = AstKind::LabelIdentifier(ident);
+    fn visit_import_attribute_key(&mut self, node: &'a ImportAttributeKey) {
+        match node {
+            ImportAttributeKey::Identifier(it) => {
+                self.visit_identifier_name(it);
+            }
+            ImportAttributeKey::StringLiteral(it) => {
+                self.visit_string_literal(it);
+            }
+        }
+    }
+
+    fn visit_export_named_declaration(&mut self, node: &'a ExportNamedDeclaration<'a>) {
+        if let Some(item) = &node.declaration {
+            self.visit_declaration(item);
+        }
+        for item in &node.specifiers {
+            self.visit_export_specifier(item);
+        }
+        if let Some(item) = &node.source {
+            self.visit_string_literal(item);
+        }
+    }
+
+    fn visit_export_default_declaration(&mut self, node: &'a ExportDefaultDeclaration<'a>) {
+        self.visit_export_default_declaration_kind(&node.declaration);
+        self.visit_module_export_name(&node.exported);
+    }
+
+    fn visit_export_all_declaration(&mut self, node: &'a ExportAllDeclaration<'a>) {
+        if let Some(item) = &node.exported {
+            self.visit_module_export_name(item);
+        }
+        self.visit_string_literal(&node.source);
+        if let Some(item) = &node.assertions {
+            for item in item {
+                self.visit_import_attribute(item);
+            }
+        }
+    }
+
+    fn visit_export_specifier(&mut self, node: &'a ExportSpecifier) {
+        let kind = AstKind::ExportSpecifier(node);
         self.enter_node(kind);
+        self.visit_module_export_name(&node.local);
+        self.visit_module_export_name(&node.exported);
         self.leave_node(kind);
     }
 
-    fn visit_identifier_name(&mut self, ident: &'a IdentifierName) {
-        let kind = AstKind::IdentifierName(ident);
+    fn visit_export_default_declaration_kind(
+        &mut self,
+        node: &'a ExportDefaultDeclarationKind<'a>,
+    ) {
+        match node {
+            ExportDefaultDeclarationKind::Expression(it) => {
+                self.visit_expression(it);
+            }
+            ExportDefaultDeclarationKind::FunctionDeclaration(it) => {
+                self.visit_function(it);
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(it) => {
+                self.visit_class(it);
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(it) => {
+                self.visit_ts_interface_declaration(it);
+            }
+            ExportDefaultDeclarationKind::TSEnumDeclaration(it) => {
+                self.visit_ts_enum_declaration(it);
+            }
+        }
+    }
+
+    fn visit_module_export_name(&mut self, node: &'a ModuleExportName) {
+        match node {
+            ModuleExportName::Identifier(it) => {
+                self.visit_identifier_name(it);
+            }
+            ModuleExportName::StringLiteral(it) => {
+                self.visit_string_literal(it);
+            }
+        }
+    }
+
+    fn visit_boolean_literal(&mut self, node: &'a BooleanLiteral) {
+        let kind = AstKind::BooleanLiteral(node);
         self.enter_node(kind);
         self.leave_node(kind);
     }
 
-    /* ----------  Literal ---------- */
+    fn visit_null_literal(&mut self, node: &'a NullLiteral) {
+        let kind = AstKind::NullLiteral(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
 
-    fn visit_number_literal(&mut self, lit: &'a NumberLiteral<'a>) {
-        let kind = AstKind::NumberLiteral(lit);
+    fn visit_number_literal(&mut self, node: &'a NumberLiteral<'a>) {
+        let kind = AstKind::NumberLiteral(node);
         self.enter_node(kind);
         self.leave_node(kind);
     }
 
-    fn visit_boolean_literal(&mut self, lit: &'a BooleanLiteral) {
-        let kind = AstKind::BooleanLiteral(lit);
+    fn visit_bigint_literal(&mut self, node: &'a BigintLiteral) {
+        let kind = AstKind::BigintLiteral(node);
         self.enter_node(kind);
         self.leave_node(kind);
     }
 
-    fn visit_null_literal(&mut self, lit: &'a NullLiteral) {
-        let kind = AstKind::NullLiteral(lit);
+    fn visit_reg_exp_literal(&mut self, node: &'a RegExpLiteral<'a>) {
+        let kind = AstKind::RegExpLiteral(node);
         self.enter_node(kind);
         self.leave_node(kind);
     }
 
-    fn visit_bigint_literal(&mut self, lit: &'a BigintLiteral) {
-        let kind = AstKind::BigintLiteral(lit);
+    fn visit_string_literal(&mut self, node: &'a StringLiteral) {
+        let kind = AstKind::StringLiteral(node);
         self.enter_node(kind);
         self.leave_node(kind);
     }
 
-    fn visit_string_literal(&mut self, lit: &'a StringLiteral) {
-        let kind = AstKind::StringLiteral(lit);
+    fn visit_jsx_element(&mut self, node: &'a JSXElement<'a>) {
+        let kind = AstKind::JSXElement(node);
         self.enter_node(kind);
+        self.visit_jsx_opening_element(&node.opening_element);
+        if let Some(item) = &node.closing_element {
+            self.visit_jsx_closing_element(item);
+        }
+        for item in &node.children {
+            self.visit_jsx_child(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_template_literal(&mut self, lit: &'a TemplateLiteral<'a>) {
-        let kind = AstKind::TemplateLiteral(lit);
+    fn visit_jsx_opening_element(&mut self, node: &'a JSXOpeningElement<'a>) {
+        let kind = AstKind::JSXOpeningElement(node);
         self.enter_node(kind);
-        for elem in &lit.quasis {
-            self.visit_template_element(elem);
+        self.visit_jsx_element_name(&node.name);
+        for item in &node.attributes {
+            self.visit_jsx_attribute_item(item);
         }
-        for expr in &lit.expressions {
-            self.visit_expression(expr);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_reg_expr_literal(&mut self, lit: &'a RegExpLiteral) {
-        let kind = AstKind::RegExpLiteral(lit);
+    fn visit_jsx_closing_element(&mut self, node: &'a JSXClosingElement<'a>) {
+        let kind = AstKind::JSXClosingElement(node);
         self.enter_node(kind);
+        self.visit_jsx_element_name(&node.name);
         self.leave_node(kind);
     }
 
-    fn visit_template_element(&mut self, _elem: &'a TemplateElement) {}
+    fn visit_jsx_fragment(&mut self, node: &'a JSXFragment<'a>) {
+        let kind = AstKind::JSXFragment(node);
+        self.enter_node(kind);
+        self.visit_jsx_opening_fragment(&node.opening_fragment);
+        self.visit_jsx_closing_fragment(&node.closing_fragment);
+        for item in &node.children {
+            self.visit_jsx_child(item);
+        }
+        self.leave_node(kind);
+    }
 
-    /* ----------  Module ---------- */
+    fn visit_jsx_opening_fragment(&mut self, node: &'a JSXOpeningFragment) {
+        let kind = AstKind::JSXOpeningFragment(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
 
-    fn visit_module_declaration(&mut self, decl: &'a ModuleDeclaration<'a>) {
-        let kind = AstKind::ModuleDeclaration(decl);
+    fn visit_jsx_closing_fragment(&mut self, node: &'a JSXClosingFragment) {
+        let kind = AstKind::JSXClosingFragment(node);
         self.enter_node(kind);
-        match &decl.kind {
-            ModuleDeclarationKind::ImportDeclaration(decl) => {
-                self.visit_import_declaration(decl);
-            }
-            ModuleDeclarationKind::ExportAllDeclaration(decl) => {
-                self.visit_export_all_declaration(decl);
-            }
-            ModuleDeclarationKind::ExportDefaultDeclaration(decl) => {
-                self.visit_export_default_declaration(decl);
+        self.leave_node(kind);
+    }
+
+    fn visit_jsx_element_name(&mut self, node: &'a JSXElementName<'a>) {
+        let kind = AstKind::JSXElementName(node);
+        self.enter_node(kind);
+        match node {
+            JSXElementName::Identifier(it) => {
+                self.visit_jsx_identifier(it);
             }
-            ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
-                self.visit_export_named_declaration(decl);
+            JSXElementName::NamespacedName(it) => {
+                self.visit_jsx_namespaced_name(it);
             }
-            ModuleDeclarationKind::TSExportAssignment(decl) => {
-                self.visit_expression(&decl.expression);
+            JSXElementName::MemberExpression(it) => {
+                self.visit_jsx_member_expression(it);
             }
-            ModuleDeclarationKind::TSNamespaceExportDeclaration(_) => {}
         }
         self.leave_node(kind);
     }
 
-    fn visit_import_declaration(&mut self, decl: &'a ImportDeclaration<'a>) {
-        for specifier in &decl.specifiers {
-            self.visit_import_declaration_specifier(specifier);
-        }
-        // TODO: source
-        // TODO: assertions
+    fn visit_jsx_namespaced_name(&mut self, node: &'a JSXNamespacedName) {
+        let kind = AstKind::JSXNamespacedName(node);
+        self.enter_node(kind);
+        self.visit_jsx_identifier(&node.namespace);
+        self.visit_jsx_identifier(&node.property);
+        self.leave_node(kind);
+    }
+
+    fn visit_jsx_member_expression(&mut self, node: &'a JSXMemberExpression<'a>) {
+        let kind = AstKind::JSXMemberExpression(node);
+        self.enter_node(kind);
+        self.visit_jsx_member_expression_object(&node.object);
+        self.visit_jsx_identifier(&node.property);
+        self.leave_node(kind);
     }
 
-    fn visit_import_declaration_specifier(&mut self, specifier: &'a ImportDeclarationSpecifier) {
-        match &specifier {
-            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
-                self.visit_import_specifier(specifier);
+    fn visit_jsx_member_expression_object(&mut self, node: &'a JSXMemberExpressionObject<'a>) {
+        match node {
+            JSXMemberExpressionObject::Identifier(it) => {
+                self.visit_jsx_identifier(it);
             }
-            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
-                self.visit_import_default_specifier(specifier);
+            JSXMemberExpressionObject::MemberExpression(it) => {
+                self.visit_jsx_member_expression(it);
             }
-            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
-                self.visit_import_name_specifier(specifier);
+        }
+    }
+
+    fn visit_jsx_expression_container(&mut self, node: &'a JSXExpressionContainer<'a>) {
+        let kind = AstKind::JSXExpressionContainer(node);
+        self.enter_node(kind);
+        self.visit_jsx_expression(&node.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_jsx_expression(&mut self, node: &'a JSXExpression<'a>) {
+        match node {
+            JSXExpression::Expression(it) => {
+                self.visit_expression(it);
+            }
+            JSXExpression::EmptyExpression(it) => {
+                self.visit_jsx_empty_expression(it);
             }
         }
     }
 
-    fn visit_import_specifier(&mut self, specifier: &'a ImportSpecifier) {
-        // TODO: imported
-        self.visit_binding_identifier(&specifier.local);
+    fn visit_jsx_empty_expression(&mut self, node: &'a JSXEmptyExpression) {
+        let kind = AstKind::JSXEmptyExpression(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_import_default_specifier(&mut self, specifier: &'a ImportDefaultSpecifier) {
-        self.visit_binding_identifier(&specifier.local);
+    fn visit_jsx_attribute_item(&mut self, node: &'a JSXAttributeItem<'a>) {
+        match node {
+            JSXAttributeItem::Attribute(it) => {
+                self.visit_jsx_attribute(it);
+            }
+            JSXAttributeItem::SpreadAttribute(it) => {
+                self.visit_jsx_spread_attribute(it);
+            }
+        }
     }
 
-    fn visit_import_name_specifier(&mut self, specifier: &'a ImportNamespaceSpecifier) {
-        self.visit_binding_identifier(&specifier.local);
+    fn visit_jsx_attribute(&mut self, node: &'a JSXAttribute<'a>) {
+        let kind = AstKind::JSXAttribute(node);
+        self.enter_node(kind);
+        self.visit_jsx_attribute_name(&node.name);
+        if let Some(item) = &node.value {
+            self.visit_jsx_attribute_value(item);
+        }
+        self.leave_node(kind);
     }
 
-    fn visit_export_all_declaration(&mut self, _decl: &'a ExportAllDeclaration<'a>) {}
+    fn visit_jsx_spread_attribute(&mut self, node: &'a JSXSpreadAttribute<'a>) {
+        let kind = AstKind::JSXSpreadAttribute(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.argument);
+        self.leave_node(kind);
+    }
 
-    fn visit_export_default_declaration(&mut self, decl: &'a ExportDefaultDeclaration<'a>) {
-        match &decl.declaration {
-            ExportDefaultDeclarationKind::Expression(expr) => self.visit_expression(expr),
-            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
-                self.visit_function(func);
+    fn visit_jsx_attribute_name(&mut self, node: &'a JSXAttributeName<'a>) {
+        match node {
+            JSXAttributeName::Identifier(it) => {
+                self.visit_jsx_identifier(it);
+            }
+            JSXAttributeName::NamespacedName(it) => {
+                self.visit_jsx_namespaced_name(it);
             }
-            ExportDefaultDeclarationKind::ClassDeclaration(class) => self.visit_class(class),
-            _ => {}
         }
     }
 
-    fn visit_export_named_declaration(&mut self, decl: &'a ExportNamedDeclaration<'a>) {
-        if let Some(decl) = &decl.declaration {
-            self.visit_declaration(decl);
+    fn visit_jsx_attribute_value(&mut self, node: &'a JSXAttributeValue<'a>) {
+        match node {
+            JSXAttributeValue::StringLiteral(it) => {
+                self.visit_string_literal(it);
+            }
+            JSXAttributeValue::ExpressionContainer(it) => {
+                self.visit_jsx_expression_container(it);
+            }
+            JSXAttributeValue::Element(it) => {
+                self.visit_jsx_element(it);
+            }
+            JSXAttributeValue::Fragment(it) => {
+                self.visit_jsx_fragment(it);
+            }
         }
     }
 
-    fn visit_enum_member(&mut self, member: &'a TSEnumMember<'a>) {
-        let kind = AstKind::TSEnumMember(member);
+    fn visit_jsx_identifier(&mut self, node: &'a JSXIdentifier) {
+        let kind = AstKind::JSXIdentifier(node);
         self.enter_node(kind);
+        self.leave_node(kind);
+    }
 
-        if let Some(initializer) = &member.initializer {
-            self.visit_expression(initializer);
+    fn visit_jsx_child(&mut self, node: &'a JSXChild<'a>) {
+        match node {
+            JSXChild::Text(it) => {
+                self.visit_jsx_text(it);
+            }
+            JSXChild::Element(it) => {
+                self.visit_jsx_element(it);
+            }
+            JSXChild::Fragment(it) => {
+                self.visit_jsx_fragment(it);
+            }
+            JSXChild::ExpressionContainer(it) => {
+                self.visit_jsx_expression_container(it);
+            }
+            JSXChild::Spread(it) => {
+                self.visit_jsx_spread_child(it);
+            }
         }
+    }
 
+    fn visit_jsx_spread_child(&mut self, node: &'a JSXSpreadChild<'a>) {
+        let kind = AstKind::JSXSpreadChild(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
         self.leave_node(kind);
     }
 
-    fn visit_enum(&mut self, decl: &'a TSEnumDeclaration<'a>) {
-        let kind = AstKind::TSEnumDeclaration(decl);
+    fn visit_jsx_text(&mut self, node: &'a JSXText) {
+        let kind = AstKind::JSXText(node);
         self.enter_node(kind);
-        self.visit_binding_identifier(&decl.id);
-        for member in &decl.members {
-            self.visit_enum_member(member);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_enum_declaration(&mut self, node: &'a TSEnumDeclaration<'a>) {
+        let kind = AstKind::TSEnumDeclaration(node);
+        self.enter_node(kind);
+        self.visit_binding_identifier(&node.id);
+        for item in &node.members {
+            self.visit_ts_enum_member(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_declaration(&mut self, decl: &'a Declaration<'a>) {
-        match decl {
-            Declaration::VariableDeclaration(decl) => self.visit_variable_declaration(decl),
-            Declaration::FunctionDeclaration(func) => self.visit_function(func),
-            Declaration::ClassDeclaration(class) => self.visit_class(class),
-            Declaration::TSModuleDeclaration(module) => {
-                self.visit_ts_module_declaration(module);
+    fn visit_ts_enum_member(&mut self, node: &'a TSEnumMember<'a>) {
+        let kind = AstKind::TSEnumMember(node);
+        self.enter_node(kind);
+        self.visit_ts_enum_member_name(&node.id);
+        if let Some(item) = &node.initializer {
+            self.visit_expression(item);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_enum_member_name(&mut self, node: &'a TSEnumMemberName<'a>) {
+        match node {
+            TSEnumMemberName::Identifier(it) => {
+                self.visit_identifier_name(it);
             }
-            Declaration::TSTypeAliasDeclaration(decl) => {
-                self.visit_ts_type_alias_declaration(decl);
+            TSEnumMemberName::StringLiteral(it) => {
+                self.visit_string_literal(it);
             }
-            Declaration::TSEnumDeclaration(decl) => self.visit_enum(decl),
-            Declaration::TSImportEqualsDeclaration(decl) => {
-                self.visit_ts_import_equals_declaration(decl);
+            TSEnumMemberName::ComputedPropertyName(it) => {
+                self.visit_expression(it);
             }
-            Declaration::TSInterfaceDeclaration(decl) => {
-                self.visit_ts_interface_declaration(decl);
+            TSEnumMemberName::NumberLiteral(it) => {
+                self.visit_number_literal(it);
             }
         }
     }
 
-    fn visit_ts_import_equals_declaration(&mut self, decl: &'a TSImportEqualsDeclaration<'a>) {
-        let kind = AstKind::TSImportEqualsDeclaration(decl);
+    fn visit_ts_type_annotation(&mut self, node: &'a TSTypeAnnotation<'a>) {
+        let kind = AstKind::TSTypeAnnotation(node);
         self.enter_node(kind);
-        self.visit_binding_identifier(&decl.id);
+        self.visit_ts_type(&node.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_module_declaration(&mut self, decl: &'a TSModuleDeclaration<'a>) {
-        let kind = AstKind::TSModuleDeclaration(decl);
+    fn visit_ts_literal_type(&mut self, node: &'a TSLiteralType<'a>) {
+        let kind = AstKind::TSLiteralType(node);
         self.enter_node(kind);
-        match &decl.id {
-            TSModuleDeclarationName::Identifier(ident) => self.visit_identifier_name(ident),
-            TSModuleDeclarationName::StringLiteral(lit) => self.visit_string_literal(lit),
+        self.visit_ts_literal(&node.literal);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_literal(&mut self, node: &'a TSLiteral<'a>) {
+        match node {
+            TSLiteral::BooleanLiteral(it) => {
+                self.visit_boolean_literal(it);
+            }
+            TSLiteral::NullLiteral(it) => {
+                self.visit_null_literal(it);
+            }
+            TSLiteral::NumberLiteral(it) => {
+                self.visit_number_literal(it);
+            }
+            TSLiteral::BigintLiteral(it) => {
+                self.visit_bigint_literal(it);
+            }
+            TSLiteral::RegExpLiteral(it) => {
+                self.visit_reg_exp_literal(it);
+            }
+            TSLiteral::StringLiteral(it) => {
+                self.visit_string_literal(it);
+            }
+            TSLiteral::TemplateLiteral(it) => {
+                self.visit_template_literal(it);
+            }
+            TSLiteral::UnaryExpression(it) => {
+                self.visit_unary_expression(it);
+            }
         }
-        match &decl.body {
-            TSModuleDeclarationBody::TSModuleDeclaration(decl) => {
-                self.visit_ts_module_declaration(decl);
+    }
+
+    fn visit_ts_type(&mut self, node: &'a TSType<'a>) {
+        match node {
+            TSType::TSAnyKeyword(it) => {
+                self.visit_ts_any_keyword(it);
+            }
+            TSType::TSBigIntKeyword(it) => {
+                self.visit_ts_big_int_keyword(it);
+            }
+            TSType::TSBooleanKeyword(it) => {
+                self.visit_ts_boolean_keyword(it);
+            }
+            TSType::TSNeverKeyword(it) => {
+                self.visit_ts_never_keyword(it);
+            }
+            TSType::TSNullKeyword(it) => {
+                self.visit_ts_null_keyword(it);
+            }
+            TSType::TSNumberKeyword(it) => {
+                self.visit_ts_number_keyword(it);
+            }
+            TSType::TSObjectKeyword(it) => {
+                self.visit_ts_object_keyword(it);
+            }
+            TSType::TSStringKeyword(it) => {
+                self.visit_ts_string_keyword(it);
+            }
+            TSType::TSSymbolKeyword(it) => {
+                self.visit_ts_symbol_keyword(it);
+            }
+            TSType::TSThisKeyword(it) => {
+                self.visit_ts_this_keyword(it);
+            }
+            TSType::TSUndefinedKeyword(it) => {
+                self.visit_ts_undefined_keyword(it);
+            }
+            TSType::TSUnknownKeyword(it) => {
+                self.visit_ts_unknown_keyword(it);
+            }
+            TSType::TSVoidKeyword(it) => {
+                self.visit_ts_void_keyword(it);
+            }
+            TSType::TSArrayType(it) => {
+                self.visit_ts_array_type(it);
+            }
+            TSType::TSConditionalType(it) => {
+                self.visit_ts_conditional_type(it);
+            }
+            TSType::TSConstructorType(it) => {
+                self.visit_ts_constructor_type(it);
+            }
+            TSType::TSFunctionType(it) => {
+                self.visit_ts_function_type(it);
+            }
+            TSType::TSImportType(it) => {
+                self.visit_ts_import_type(it);
+            }
+            TSType::TSIndexedAccessType(it) => {
+                self.visit_ts_indexed_access_type(it);
+            }
+            TSType::TSInferType(it) => {
+                self.visit_ts_infer_type(it);
+            }
+            TSType::TSIntersectionType(it) => {
+                self.visit_ts_intersection_type(it);
+            }
+            TSType::TSLiteralType(it) => {
+                self.visit_ts_literal_type(it);
+            }
+            TSType::TSMappedType(it) => {
+                self.visit_ts_mapped_type(it);
+            }
+            TSType::TSQualifiedName(it) => {
+                self.visit_ts_qualified_name(it);
+            }
+            TSType::TSTemplateLiteralType(it) => {
+                self.visit_ts_template_literal_type(it);
+            }
+            TSType::TSTupleType(it) => {
+                self.visit_ts_tuple_type(it);
+            }
+            TSType::TSTypeLiteral(it) => {
+                self.visit_ts_type_literal(it);
+            }
+            TSType::TSTypeOperatorType(it) => {
+                self.visit_ts_type_operator_type(it);
+            }
+            TSType::TSTypePredicate(it) => {
+                self.visit_ts_type_predicate(it);
+            }
+            TSType::TSTypeQuery(it) => {
+                self.visit_ts_type_query(it);
+            }
+            TSType::TSTypeReference(it) => {
+                self.visit_ts_type_reference(it);
+            }
+            TSType::TSUnionType(it) => {
+                self.visit_ts_union_type(it);
+            }
+            TSType::JSDocNullableType(it) => {
+                self.visit_js_doc_nullable_type(it);
+            }
+            TSType::JSDocUnknownType(it) => {
+                self.visit_js_doc_unknown_type(it);
+            }
+            TSType::FlowExactObjectType(it) => {
+                self.visit_flow_exact_object_type(it);
+            }
+            TSType::FlowExistentialType(it) => {
+                self.visit_flow_existential_type(it);
             }
-            TSModuleDeclarationBody::TSModuleBlock(block) => self.visit_ts_module_block(block),
         }
+    }
+
+    fn visit_ts_conditional_type(&mut self, node: &'a TSConditionalType<'a>) {
+        let kind = AstKind::TSConditionalType(node);
+        self.enter_node(kind);
+        self.visit_ts_type(&node.check_type);
+        self.visit_ts_type(&node.extends_type);
+        self.visit_ts_type(&node.true_type);
+        self.visit_ts_type(&node.false_type);
         self.leave_node(kind);
     }
 
-    fn visit_ts_module_block(&mut self, block: &'a TSModuleBlock<'a>) {
-        let kind = AstKind::TSModuleBlock(block);
+    fn visit_ts_union_type(&mut self, node: &'a TSUnionType<'a>) {
+        let kind = AstKind::TSUnionType(node);
         self.enter_node(kind);
-        self.visit_statements(&block.body);
+        for item in &node.types {
+            self.visit_ts_type(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_ts_type_alias_declaration(&mut self, decl: &'a TSTypeAliasDeclaration<'a>) {
-        let kind = AstKind::TSTypeAliasDeclaration(decl);
+    fn visit_ts_intersection_type(&mut self, node: &'a TSIntersectionType<'a>) {
+        let kind = AstKind::TSIntersectionType(node);
         self.enter_node(kind);
-        self.visit_binding_identifier(&decl.id);
-        if let Some(parameters) = &decl.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+        for item in &node.types {
+            self.visit_ts_type(item);
         }
-        self.visit_ts_type(&decl.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_interface_declaration(&mut self, decl: &'a TSInterfaceDeclaration<'a>) {
-        let kind = AstKind::TSInterfaceDeclaration(decl);
+    fn visit_ts_type_operator_type(&mut self, node: &'a TSTypeOperatorType<'a>) {
+        let kind = AstKind::TSTypeOperatorType(node);
+        self.enter_node(kind);
+        self.visit_ts_type(&node.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_array_type(&mut self, node: &'a TSArrayType<'a>) {
+        let kind = AstKind::TSArrayType(node);
         self.enter_node(kind);
-        self.visit_binding_identifier(&decl.id);
-        if let Some(parameters) = &decl.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+        self.visit_ts_type(&node.element_type);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_indexed_access_type(&mut self, node: &'a TSIndexedAccessType<'a>) {
+        let kind = AstKind::TSIndexedAccessType(node);
+        self.enter_node(kind);
+        self.visit_ts_type(&node.object_type);
+        self.visit_ts_type(&node.index_type);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_tuple_type(&mut self, node: &'a TSTupleType<'a>) {
+        let kind = AstKind::TSTupleType(node);
+        self.enter_node(kind);
+        for item in &node.element_types {
+            self.visit_ts_tuple_element(item);
         }
-        for signature in &decl.body.body {
-            self.visit_ts_signature(signature);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_named_tuple_member(&mut self, node: &'a TSNamedTupleMember<'a>) {
+        let kind = AstKind::TSNamedTupleMember(node);
+        self.enter_node(kind);
+        self.visit_ts_type(&node.element_type);
+        self.visit_identifier_name(&node.label);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_optional_type(&mut self, node: &'a TSOptionalType<'a>) {
+        let kind = AstKind::TSOptionalType(node);
+        self.enter_node(kind);
+        self.visit_ts_type(&node.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_rest_type(&mut self, node: &'a TSRestType<'a>) {
+        let kind = AstKind::TSRestType(node);
+        self.enter_node(kind);
+        self.visit_ts_type(&node.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_tuple_element(&mut self, node: &'a TSTupleElement<'a>) {
+        match node {
+            TSTupleElement::TSType(it) => {
+                self.visit_ts_type(it);
+            }
+            TSTupleElement::TSOptionalType(it) => {
+                self.visit_ts_optional_type(it);
+            }
+            TSTupleElement::TSRestType(it) => {
+                self.visit_ts_rest_type(it);
+            }
+            TSTupleElement::TSNamedTupleMember(it) => {
+                self.visit_ts_named_tuple_member(it);
+            }
         }
+    }
+
+    fn visit_ts_any_keyword(&mut self, node: &'a TSAnyKeyword) {
+        let kind = AstKind::TSAnyKeyword(node);
+        self.enter_node(kind);
         self.leave_node(kind);
     }
 
-    fn visit_ts_as_expression(&mut self, expr: &'a TSAsExpression<'a>) {
-        let kind = AstKind::TSAsExpression(expr);
+    fn visit_ts_string_keyword(&mut self, node: &'a TSStringKeyword) {
+        let kind = AstKind::TSStringKeyword(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.expression);
-        self.visit_ts_type(&expr.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_satisfies_expression(&mut self, expr: &'a TSSatisfiesExpression<'a>) {
-        let kind = AstKind::TSSatisfiesExpression(expr);
+    fn visit_ts_boolean_keyword(&mut self, node: &'a TSBooleanKeyword) {
+        let kind = AstKind::TSBooleanKeyword(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.expression);
-        self.visit_ts_type(&expr.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_non_null_expression(&mut self, expr: &'a TSNonNullExpression<'a>) {
-        let kind = AstKind::TSNonNullExpression(expr);
+    fn visit_ts_number_keyword(&mut self, node: &'a TSNumberKeyword) {
+        let kind = AstKind::TSNumberKeyword(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.expression);
         self.leave_node(kind);
     }
 
-    fn visit_ts_type_assertion(&mut self, expr: &'a TSTypeAssertion<'a>) {
-        let kind = AstKind::TSTypeAssertion(expr);
+    fn visit_ts_never_keyword(&mut self, node: &'a TSNeverKeyword) {
+        let kind = AstKind::TSNeverKeyword(node);
         self.enter_node(kind);
-        self.visit_expression(&expr.expression);
-        self.visit_ts_type(&expr.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_instantiation_expression(&mut self, expr: &'a TSInstantiationExpression<'a>) {
-        self.visit_expression(&expr.expression);
-        self.visit_ts_type_parameter_instantiation(&expr.type_parameters);
+    fn visit_ts_unknown_keyword(&mut self, node: &'a TSUnknownKeyword) {
+        let kind = AstKind::TSUnknownKeyword(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_ts_type_annotation(&mut self, annotation: &'a TSTypeAnnotation<'a>) {
-        let kind = AstKind::TSTypeAnnotation(annotation);
+    fn visit_ts_null_keyword(&mut self, node: &'a TSNullKeyword) {
+        let kind = AstKind::TSNullKeyword(node);
         self.enter_node(kind);
-        self.visit_ts_type(&annotation.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_type(&mut self, ty: &'a TSType<'a>) {
-        match ty {
-            TSType::TSAnyKeyword(ty) => self.visit_ts_any_keyword(ty),
-            TSType::TSNullKeyword(ty) => self.visit_ts_null_keyword(ty),
-            TSType::TSVoidKeyword(ty) => self.visit_ts_void_keyword(ty),
-            TSType::TSIntersectionType(ty) => self.visit_ts_intersection_type(ty),
-            TSType::TSTypeReference(ty) => self.visit_ts_type_reference(ty),
-            TSType::TSUnionType(ty) => self.visit_ts_union_type(ty),
-            TSType::TSLiteralType(ty) => self.visit_ts_literal_type(ty),
-            TSType::TSArrayType(ty) => self.visit_ts_array_type(ty),
-            TSType::TSConditionalType(ty) => self.visit_ts_conditional_type(ty),
-            TSType::TSConstructorType(ty) => self.visit_ts_constructor_type(ty),
-            TSType::TSFunctionType(ty) => self.visit_ts_function_type(ty),
-            TSType::TSMappedType(ty) => self.visit_ts_mapped_type(ty),
-            TSType::TSTupleType(ty) => self.visit_ts_tuple_type(ty),
-            TSType::TSTypeOperatorType(ty) => self.visit_ts_type_operator_type(ty),
-            TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
-            TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
-            TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
-            _ => {}
-        }
+    fn visit_ts_undefined_keyword(&mut self, node: &'a TSUndefinedKeyword) {
+        let kind = AstKind::TSUndefinedKeyword(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_ts_type_literal(&mut self, ty: &'a TSTypeLiteral<'a>) {
-        let kind = AstKind::TSTypeLiteral(ty);
+    fn visit_ts_void_keyword(&mut self, node: &'a TSVoidKeyword) {
+        let kind = AstKind::TSVoidKeyword(node);
         self.enter_node(kind);
-        for signature in &ty.members {
-            self.visit_ts_signature(signature);
-        }
         self.leave_node(kind);
     }
 
-    fn visit_ts_indexed_access_type(&mut self, ty: &'a TSIndexedAccessType<'a>) {
-        let kind = AstKind::TSIndexedAccessType(ty);
+    fn visit_ts_symbol_keyword(&mut self, node: &'a TSSymbolKeyword) {
+        let kind = AstKind::TSSymbolKeyword(node);
         self.enter_node(kind);
-        self.visit_ts_type(&ty.object_type);
-        self.visit_ts_type(&ty.index_type);
         self.leave_node(kind);
     }
 
-    fn visit_ts_type_predicate(&mut self, ty: &'a TSTypePredicate<'a>) {
-        if let Some(annotation) = &ty.type_annotation {
-            self.visit_ts_type_annotation(annotation);
-        }
+    fn visit_ts_this_keyword(&mut self, node: &'a TSThisKeyword) {
+        let kind = AstKind::TSThisKeyword(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_ts_type_operator_type(&mut self, ty: &'a TSTypeOperatorType<'a>) {
-        self.visit_ts_type(&ty.type_annotation);
+    fn visit_ts_object_keyword(&mut self, node: &'a TSObjectKeyword) {
+        let kind = AstKind::TSObjectKeyword(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_ts_tuple_type(&mut self, ty: &'a TSTupleType<'a>) {
-        for element in &ty.element_types {
-            self.visit_ts_tuple_element(element);
-        }
+    fn visit_ts_big_int_keyword(&mut self, node: &'a TSBigIntKeyword) {
+        let kind = AstKind::TSBigIntKeyword(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
     }
 
-    fn visit_ts_tuple_element(&mut self, ty: &'a TSTupleElement<'a>) {
-        match ty {
-            TSTupleElement::TSType(ty) => self.visit_ts_type(ty),
-            TSTupleElement::TSOptionalType(ty) => self.visit_ts_type(&ty.type_annotation),
-            TSTupleElement::TSRestType(ty) => self.visit_ts_type(&ty.type_annotation),
-            TSTupleElement::TSNamedTupleMember(ty) => self.visit_ts_type(&ty.element_type),
-        };
+    fn visit_ts_type_reference(&mut self, node: &'a TSTypeReference<'a>) {
+        let kind = AstKind::TSTypeReference(node);
+        self.enter_node(kind);
+        self.visit_ts_type_name(&node.type_name);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
+        }
+        self.leave_node(kind);
     }
 
-    fn visit_ts_mapped_type(&mut self, ty: &'a TSMappedType<'a>) {
-        self.visit_ts_type_parameter(&ty.type_parameter);
-        if let Some(name) = &ty.name_type {
-            self.visit_ts_type(name);
+    fn visit_ts_type_name(&mut self, node: &'a TSTypeName<'a>) {
+        match node {
+            TSTypeName::IdentifierName(it) => {
+                self.visit_identifier_name(it);
+            }
+            TSTypeName::QualifiedName(it) => {
+                self.visit_ts_qualified_name(it);
+            }
         }
-        self.visit_ts_type(&ty.type_annotation);
     }
 
-    fn visit_ts_function_type(&mut self, ty: &'a TSFunctionType<'a>) {
-        self.visit_formal_parameters(&ty.params);
-        if let Some(parameters) = &ty.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+    fn visit_ts_qualified_name(&mut self, node: &'a TSQualifiedName<'a>) {
+        let kind = AstKind::TSQualifiedName(node);
+        self.enter_node(kind);
+        self.visit_ts_type_name(&node.left);
+        self.visit_identifier_name(&node.right);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_parameter_instantiation(
+        &mut self,
+        node: &'a TSTypeParameterInstantiation<'a>,
+    ) {
+        let kind = AstKind::TSTypeParameterInstantiation(node);
+        self.enter_node(kind);
+        for item in &node.params {
+            self.visit_ts_type(item);
         }
-        self.visit_ts_type_annotation(&ty.return_type);
+        self.leave_node(kind);
     }
 
-    fn visit_ts_type_parameter(&mut self, ty: &'a TSTypeParameter<'a>) {
-        let kind = AstKind::TSTypeParameter(ty);
+    fn visit_ts_type_parameter(&mut self, node: &'a TSTypeParameter<'a>) {
+        let kind = AstKind::TSTypeParameter(node);
         self.enter_node(kind);
-        if let Some(constraint) = &ty.constraint {
-            self.visit_ts_type(constraint);
+        self.visit_binding_identifier(&node.name);
+        if let Some(item) = &node.constraint {
+            self.visit_ts_type(item);
+        }
+        if let Some(item) = &node.default {
+            self.visit_ts_type(item);
         }
+        self.leave_node(kind);
+    }
 
-        if let Some(default) = &ty.default {
-            self.visit_ts_type(default);
+    fn visit_ts_type_parameter_declaration(&mut self, node: &'a TSTypeParameterDeclaration<'a>) {
+        let kind = AstKind::TSTypeParameterDeclaration(node);
+        self.enter_node(kind);
+        for item in &node.params {
+            self.visit_ts_type_parameter(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_ts_type_parameter_instantiation(&mut self, ty: &'a TSTypeParameterInstantiation<'a>) {
-        let kind = AstKind::TSTypeParameterInstantiation(ty);
+    fn visit_ts_type_alias_declaration(&mut self, node: &'a TSTypeAliasDeclaration<'a>) {
+        let kind = AstKind::TSTypeAliasDeclaration(node);
         self.enter_node(kind);
-        for ts_parameter in &ty.params {
-            self.visit_ts_type(ts_parameter);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
         }
+        self.visit_binding_identifier(&node.id);
+        self.visit_ts_type(&node.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_type_parameter_declaration(&mut self, ty: &'a TSTypeParameterDeclaration<'a>) {
-        let kind = AstKind::TSTypeParameterDeclaration(ty);
+    fn visit_ts_abstract_method_definition(&mut self, node: &'a TSAbstractMethodDefinition<'a>) {
+        self.visit_method_definition(&node.method_definition);
+    }
+
+    fn visit_ts_abstract_property_definition(
+        &mut self,
+        node: &'a TSAbstractPropertyDefinition<'a>,
+    ) {
+        self.visit_property_definition(&node.property_definition);
+    }
+
+    fn visit_ts_class_implements(&mut self, node: &'a TSClassImplements<'a>) {
+        let kind = AstKind::TSClassImplements(node);
         self.enter_node(kind);
-        for ts_parameter in &ty.params {
-            self.visit_ts_type_parameter(ts_parameter);
+        self.visit_ts_type_name(&node.expression);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_ts_constructor_type(&mut self, ty: &'a TSConstructorType<'a>) {
-        self.visit_formal_parameters(&ty.params);
-        if let Some(parameters) = &ty.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+    fn visit_ts_interface_declaration(&mut self, node: &'a TSInterfaceDeclaration<'a>) {
+        let kind = AstKind::TSInterfaceDeclaration(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
+        }
+        self.visit_binding_identifier(&node.id);
+        self.visit_ts_interface_body(&node.body);
+        if let Some(item) = &node.extends {
+            for item in item {
+                self.visit_ts_interface_heritage(item);
+            }
         }
-        self.visit_ts_type_annotation(&ty.return_type);
+        self.leave_node(kind);
     }
 
-    fn visit_ts_conditional_type(&mut self, ty: &'a TSConditionalType<'a>) {
-        self.visit_ts_type(&ty.check_type);
-        self.visit_ts_type(&ty.extends_type);
-        self.visit_ts_type(&ty.true_type);
-        self.visit_ts_type(&ty.false_type);
+    fn visit_ts_interface_body(&mut self, node: &'a TSInterfaceBody<'a>) {
+        let kind = AstKind::TSInterfaceBody(node);
+        self.enter_node(kind);
+        for item in &node.body {
+            self.visit_ts_signature(item);
+        }
+        self.leave_node(kind);
     }
 
-    fn visit_ts_array_type(&mut self, ty: &'a TSArrayType<'a>) {
-        self.visit_ts_type(&ty.element_type);
+    fn visit_ts_property_signature(&mut self, node: &'a TSPropertySignature<'a>) {
+        let kind = AstKind::TSPropertySignature(node);
+        self.enter_node(kind);
+        self.visit_property_key(&node.key);
+        if let Some(item) = &node.type_annotation {
+            self.visit_ts_type_annotation(item);
+        }
+        self.leave_node(kind);
     }
 
-    fn visit_ts_type_name(&mut self, name: &'a TSTypeName<'a>) {
-        match &name {
-            TSTypeName::IdentifierName(ident) => self.visit_identifier_name(ident),
-            TSTypeName::QualifiedName(_) => {}
+    fn visit_ts_signature(&mut self, node: &'a TSSignature<'a>) {
+        match node {
+            TSSignature::TSIndexSignature(it) => {
+                self.visit_ts_index_signature(it);
+            }
+            TSSignature::TSPropertySignature(it) => {
+                self.visit_ts_property_signature(it);
+            }
+            TSSignature::TSCallSignatureDeclaration(it) => {
+                self.visit_ts_call_signature_declaration(it);
+            }
+            TSSignature::TSConstructSignatureDeclaration(it) => {
+                self.visit_ts_construct_signature_declaration(it);
+            }
+            TSSignature::TSMethodSignature(it) => {
+                self.visit_ts_method_signature(it);
+            }
         }
     }
 
-    fn visit_ts_null_keyword(&mut self, ty: &'a TSNullKeyword) {
-        let kind = AstKind::TSNullKeyword(ty);
+    fn visit_ts_index_signature(&mut self, node: &'a TSIndexSignature<'a>) {
+        let kind = AstKind::TSIndexSignature(node);
         self.enter_node(kind);
+        for item in &node.parameters {
+            self.visit_ts_index_signature_name(item);
+        }
+        self.visit_ts_type_annotation(&node.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_any_keyword(&mut self, ty: &'a TSAnyKeyword) {
-        let kind = AstKind::TSAnyKeyword(ty);
+    fn visit_ts_call_signature_declaration(&mut self, node: &'a TSCallSignatureDeclaration<'a>) {
+        let kind = AstKind::TSCallSignatureDeclaration(node);
         self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
+        }
+        self.visit_formal_parameters(&node.params);
+        if let Some(item) = &node.return_type {
+            self.visit_ts_type_annotation(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_ts_void_keyword(&mut self, ty: &'a TSVoidKeyword) {
-        let kind = AstKind::TSVoidKeyword(ty);
+    fn visit_ts_method_signature(&mut self, node: &'a TSMethodSignature<'a>) {
+        let kind = AstKind::TSMethodSignature(node);
         self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
+        }
+        self.visit_property_key(&node.key);
+        self.visit_formal_parameters(&node.params);
+        if let Some(item) = &node.return_type {
+            self.visit_ts_type_annotation(item);
+        }
         self.leave_node(kind);
     }
 
-    fn visit_ts_intersection_type(&mut self, ty: &'a TSIntersectionType<'a>) {
-        let kind = AstKind::TSIntersectionType(ty);
+    fn visit_ts_construct_signature_declaration(
+        &mut self,
+        node: &'a TSConstructSignatureDeclaration<'a>,
+    ) {
+        let kind = AstKind::TSConstructSignatureDeclaration(node);
         self.enter_node(kind);
-        for ty in &ty.types {
-            self.visit_ts_type(ty);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
+        }
+        self.visit_formal_parameters(&node.params);
+        if let Some(item) = &node.return_type {
+            self.visit_ts_type_annotation(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_ts_type_reference(&mut self, ty: &'a TSTypeReference<'a>) {
-        let kind = AstKind::TSTypeReference(ty);
+    fn visit_ts_index_signature_name(&mut self, node: &'a TSIndexSignatureName<'a>) {
+        let kind = AstKind::TSIndexSignatureName(node);
         self.enter_node(kind);
-        self.visit_ts_type_name(&ty.type_name);
-        if let Some(parameters) = &ty.type_parameters {
-            self.visit_ts_type_parameter_instantiation(parameters);
-        }
+        self.visit_ts_type_annotation(&node.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_union_type(&mut self, ty: &'a TSUnionType<'a>) {
-        let kind = AstKind::TSUnionType(ty);
+    fn visit_ts_interface_heritage(&mut self, node: &'a TSInterfaceHeritage<'a>) {
+        let kind = AstKind::TSInterfaceHeritage(node);
         self.enter_node(kind);
-        for ty in &ty.types {
-            self.visit_ts_type(ty);
+        self.visit_expression(&node.expression);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_ts_literal_type(&mut self, ty: &'a TSLiteralType<'a>) {
-        let kind = AstKind::TSLiteralType(ty);
+    fn visit_ts_type_predicate(&mut self, node: &'a TSTypePredicate<'a>) {
+        let kind = AstKind::TSTypePredicate(node);
         self.enter_node(kind);
-        match &ty.literal {
-            TSLiteral::BigintLiteral(lit) => self.visit_bigint_literal(lit),
-            TSLiteral::BooleanLiteral(lit) => self.visit_boolean_literal(lit),
-            TSLiteral::NullLiteral(lit) => self.visit_null_literal(lit),
-            TSLiteral::NumberLiteral(lit) => self.visit_number_literal(lit),
-            TSLiteral::RegExpLiteral(lit) => self.visit_reg_expr_literal(lit),
-            TSLiteral::StringLiteral(lit) => self.visit_string_literal(lit),
-            TSLiteral::TemplateLiteral(lit) => self.visit_template_literal(lit),
-            TSLiteral::UnaryExpression(expr) => self.visit_unary_expression(expr),
+        self.visit_ts_type_predicate_name(&node.parameter_name);
+        if let Some(item) = &node.type_annotation {
+            self.visit_ts_type_annotation(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_ts_signature(&mut self, signature: &'a TSSignature<'a>) {
-        match &signature {
-            TSSignature::TSPropertySignature(sig) => self.visit_ts_property_signature(sig),
-            TSSignature::TSCallSignatureDeclaration(sig) => {
-                self.visit_ts_call_signature_declaration(sig);
+    fn visit_ts_type_predicate_name(&mut self, node: &'a TSTypePredicateName) {
+        match node {
+            TSTypePredicateName::Identifier(it) => {
+                self.visit_identifier_name(it);
             }
-            TSSignature::TSIndexSignature(sig) => self.visit_ts_index_signature(sig),
-            TSSignature::TSMethodSignature(sig) => self.visit_ts_method_signature(sig),
-            TSSignature::TSConstructSignatureDeclaration(sig) => {
-                self.visit_ts_construct_signature_declaration(sig);
+            TSTypePredicateName::This(it) => {
+                self.visit_ts_this_keyword(it);
             }
         }
     }
 
-    fn visit_ts_construct_signature_declaration(
-        &mut self,
-        signature: &'a TSConstructSignatureDeclaration<'a>,
-    ) {
-        self.visit_formal_parameters(&signature.params);
-        if let Some(parameters) = &signature.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+    fn visit_ts_module_declaration(&mut self, node: &'a TSModuleDeclaration<'a>) {
+        let kind = AstKind::TSModuleDeclaration(node);
+        self.enter_node(kind);
+        self.visit_ts_module_declaration_name(&node.id);
+        self.visit_ts_module_declaration_body(&node.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_module_declaration_name(&mut self, node: &'a TSModuleDeclarationName) {
+        match node {
+            TSModuleDeclarationName::Identifier(it) => {
+                self.visit_identifier_name(it);
+            }
+            TSModuleDeclarationName::StringLiteral(it) => {
+                self.visit_string_literal(it);
+            }
         }
-        if let Some(annotation) = &signature.return_type {
-            self.visit_ts_type_annotation(annotation);
+    }
+
+    fn visit_ts_module_declaration_body(&mut self, node: &'a TSModuleDeclarationBody<'a>) {
+        match node {
+            TSModuleDeclarationBody::TSModuleDeclaration(it) => {
+                self.visit_ts_module_declaration(it);
+            }
+            TSModuleDeclarationBody::TSModuleBlock(it) => {
+                self.visit_ts_module_block(it);
+            }
         }
     }
 
-    fn visit_ts_method_signature(&mut self, signature: &'a TSMethodSignature<'a>) {
-        let kind = AstKind::TSMethodSignature(signature);
+    fn visit_ts_module_block(&mut self, node: &'a TSModuleBlock<'a>) {
+        let kind = AstKind::TSModuleBlock(node);
         self.enter_node(kind);
-        self.visit_formal_parameters(&signature.params);
-        if let Some(parameters) = &signature.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+        self.visit_statements(&node.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_literal(&mut self, node: &'a TSTypeLiteral<'a>) {
+        let kind = AstKind::TSTypeLiteral(node);
+        self.enter_node(kind);
+        for item in &node.members {
+            self.visit_ts_signature(item);
         }
-        if let Some(annotation) = &signature.return_type {
-            self.visit_ts_type_annotation(annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_infer_type(&mut self, node: &'a TSInferType<'a>) {
+        let kind = AstKind::TSInferType(node);
+        self.enter_node(kind);
+        self.visit_ts_type_parameter(&node.type_parameter);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_query(&mut self, node: &'a TSTypeQuery<'a>) {
+        let kind = AstKind::TSTypeQuery(node);
+        self.enter_node(kind);
+        self.visit_ts_type_name(&node.expr_name);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
         }
         self.leave_node(kind);
     }
 
-    fn visit_ts_index_signature_name(&mut self, name: &'a TSIndexSignatureName<'a>) {
-        self.visit_ts_type_annotation(&name.type_annotation);
+    fn visit_ts_import_type(&mut self, node: &'a TSImportType<'a>) {
+        let kind = AstKind::TSImportType(node);
+        self.enter_node(kind);
+        self.visit_ts_type(&node.parameter);
+        if let Some(item) = &node.qualifier {
+            self.visit_ts_type_name(item);
+        }
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_instantiation(item);
+        }
+        self.leave_node(kind);
     }
 
-    fn visit_ts_index_signature(&mut self, signature: &'a TSIndexSignature<'a>) {
-        for name in &signature.parameters {
-            self.visit_ts_index_signature_name(name);
+    fn visit_ts_function_type(&mut self, node: &'a TSFunctionType<'a>) {
+        let kind = AstKind::TSFunctionType(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
         }
+        self.visit_formal_parameters(&node.params);
+        self.visit_ts_type_annotation(&node.return_type);
+        self.leave_node(kind);
+    }
 
-        self.visit_ts_type_annotation(&signature.type_annotation);
+    fn visit_ts_constructor_type(&mut self, node: &'a TSConstructorType<'a>) {
+        let kind = AstKind::TSConstructorType(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
+        }
+        self.visit_formal_parameters(&node.params);
+        self.visit_ts_type_annotation(&node.return_type);
+        self.leave_node(kind);
     }
 
-    fn visit_ts_property_signature(&mut self, signature: &'a TSPropertySignature<'a>) {
-        let kind = AstKind::TSPropertySignature(signature);
+    fn visit_ts_mapped_type(&mut self, node: &'a TSMappedType<'a>) {
+        let kind = AstKind::TSMappedType(node);
         self.enter_node(kind);
-        self.visit_property_key(&signature.key);
-        if let Some(annotation) = &signature.type_annotation {
-            self.visit_ts_type_annotation(annotation);
+        self.visit_ts_type_parameter(&node.type_parameter);
+        if let Some(item) = &node.name_type {
+            self.visit_ts_type(item);
         }
+        self.visit_ts_type(&node.type_annotation);
         self.leave_node(kind);
     }
 
-    fn visit_ts_call_signature_declaration(
-        &mut self,
-        signature: &'a TSCallSignatureDeclaration<'a>,
-    ) {
-        self.visit_formal_parameters(&signature.params);
-        if let Some(parameters) = &signature.type_parameters {
-            self.visit_ts_type_parameter_declaration(parameters);
+    fn visit_ts_template_literal_type(&mut self, node: &'a TSTemplateLiteralType<'a>) {
+        let kind = AstKind::TSTemplateLiteralType(node);
+        self.enter_node(kind);
+        for item in &node.quasis {
+            self.visit_template_element(item);
+        }
+        for item in &node.types {
+            self.visit_ts_type(item);
         }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_as_expression(&mut self, node: &'a TSAsExpression<'a>) {
+        let kind = AstKind::TSAsExpression(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
+        self.visit_ts_type(&node.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_satisfies_expression(&mut self, node: &'a TSSatisfiesExpression<'a>) {
+        let kind = AstKind::TSSatisfiesExpression(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
+        self.visit_ts_type(&node.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_assertion(&mut self, node: &'a TSTypeAssertion<'a>) {
+        let kind = AstKind::TSTypeAssertion(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
+        self.visit_ts_type(&node.type_annotation);
+        self.leave_node(kind);
+    }
 
-        if let Some(annotation) = &signature.return_type {
-            self.visit_ts_type_annotation(annotation);
+    fn visit_ts_import_equals_declaration(&mut self, node: &'a TSImportEqualsDeclaration<'a>) {
+        let kind = AstKind::TSImportEqualsDeclaration(node);
+        self.enter_node(kind);
+        self.visit_binding_identifier(&node.id);
+        self.visit_ts_module_reference(&node.module_reference);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_module_reference(&mut self, node: &'a TSModuleReference<'a>) {
+        match node {
+            TSModuleReference::TypeName(it) => {
+                self.visit_ts_type_name(it);
+            }
+            TSModuleReference::ExternalModuleReference(it) => {
+                self.visit_ts_external_module_reference(it);
+            }
         }
     }
+
+    fn visit_ts_external_module_reference(&mut self, node: &'a TSExternalModuleReference) {
+        let kind = AstKind::TSExternalModuleReference(node);
+        self.enter_node(kind);
+        self.visit_string_literal(&node.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_non_null_expression(&mut self, node: &'a TSNonNullExpression<'a>) {
+        let kind = AstKind::TSNonNullExpression(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_decorator(&mut self, node: &'a Decorator<'a>) {
+        let kind = AstKind::Decorator(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_export_assignment(&mut self, node: &'a TSExportAssignment<'a>) {
+        let kind = AstKind::TSExportAssignment(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_namespace_export_declaration(&mut self, node: &'a TSNamespaceExportDeclaration) {
+        let kind = AstKind::TSNamespaceExportDeclaration(node);
+        self.enter_node(kind);
+        self.visit_identifier_name(&node.id);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_instantiation_expression(&mut self, node: &'a TSInstantiationExpression<'a>) {
+        let kind = AstKind::TSInstantiationExpression(node);
+        self.enter_node(kind);
+        self.visit_expression(&node.expression);
+        self.visit_ts_type_parameter_instantiation(&node.type_parameters);
+        self.leave_node(kind);
+    }
+
+    fn visit_flow_opaque_type_declaration(&mut self, node: &'a FlowOpaqueTypeDeclaration<'a>) {
+        let kind = AstKind::FlowOpaqueTypeDeclaration(node);
+        self.enter_node(kind);
+        if let Some(item) = &node.type_parameters {
+            self.visit_ts_type_parameter_declaration(item);
+        }
+        self.visit_binding_identifier(&node.id);
+        if let Some(item) = &node.supertype {
+            self.visit_ts_type(item);
+        }
+        if let Some(item) = &node.impltype {
+            self.visit_ts_type(item);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_flow_declare_module_exports(&mut self, node: &'a FlowDeclareModuleExports<'a>) {
+        let kind = AstKind::FlowDeclareModuleExports(node);
+        self.enter_node(kind);
+        self.visit_ts_type_annotation(&node.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_flow_exact_object_type(&mut self, node: &'a FlowExactObjectType<'a>) {
+        let kind = AstKind::FlowExactObjectType(node);
+        self.enter_node(kind);
+        for item in &node.members {
+            self.visit_ts_signature(item);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_flow_existential_type(&mut self, node: &'a FlowExistentialType) {
+        let kind = AstKind::FlowExistentialType(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_js_doc_nullable_type(&mut self, node: &'a JSDocNullableType<'a>) {
+        let kind = AstKind::JSDocNullableType(node);
+        self.enter_node(kind);
+        self.visit_ts_type(&node.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_js_doc_unknown_type(&mut self, node: &'a JSDocUnknownType) {
+        let kind = AstKind::JSDocUnknownType(node);
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
 }
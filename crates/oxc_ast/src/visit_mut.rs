@@ -49,6 +49,7 @@ pub trait VisitMut<'a, 'b>: Sized {
 
             Statement::ModuleDeclaration(decl) => self.visit_module_declaration(decl),
             Statement::Declaration(decl) => self.visit_declaration(decl),
+            Statement::InvalidStatement(stmt) => self.visit_invalid_statement(stmt),
         }
     }
 
@@ -68,6 +69,8 @@ pub trait VisitMut<'a, 'b>: Sized {
         }
     }
 
+    fn visit_invalid_statement(&mut self, _stmt: &'b mut InvalidStatement) {}
+
     fn visit_debugger_statement(&mut self, _stmt: &'b mut DebuggerStatement) {}
 
     fn visit_do_while_statement(&mut self, stmt: &'b mut DoWhileStatement<'a>) {
@@ -383,9 +386,13 @@ pub trait VisitMut<'a, 'b>: Sized {
             Expression::TSInstantiationExpression(expr) => {
                 self.visit_ts_instantiation_expression(expr);
             }
+
+            Expression::InvalidExpression(expr) => self.visit_invalid_expression(expr),
         }
     }
 
+    fn visit_invalid_expression(&mut self, _expr: &'b mut InvalidExpression) {}
+
     fn visit_meta_property(&mut self, _meta: &'b mut MetaProperty) {}
 
     fn visit_array_expression(&mut self, expr: &'b mut ArrayExpression<'a>) {
@@ -849,7 +856,7 @@ pub trait VisitMut<'a, 'b>: Sized {
         }
     }
 
-    fn visit_reg_expr_literal(&mut self, _lit: &'b mut RegExpLiteral) {}
+    fn visit_reg_expr_literal(&mut self, _lit: &'b mut RegExpLiteral<'a>) {}
 
     fn visit_template_element(&mut self, _elem: &'b mut TemplateElement) {}
 
@@ -964,6 +971,12 @@ pub trait VisitMut<'a, 'b>: Sized {
             Declaration::TSInterfaceDeclaration(decl) => {
                 self.visit_ts_interface_declaration(decl);
             }
+            Declaration::FlowOpaqueTypeDeclaration(decl) => {
+                self.visit_flow_opaque_type_declaration(decl);
+            }
+            Declaration::FlowDeclareModuleExports(decl) => {
+                self.visit_flow_declare_module_exports(decl);
+            }
         }
     }
 
@@ -996,6 +1009,23 @@ pub trait VisitMut<'a, 'b>: Sized {
         self.visit_ts_type(&mut decl.type_annotation);
     }
 
+    fn visit_flow_opaque_type_declaration(&mut self, decl: &'b mut FlowOpaqueTypeDeclaration<'a>) {
+        self.visit_binding_identifier(&mut decl.id);
+        if let Some(parameters) = &mut decl.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(supertype) = &mut decl.supertype {
+            self.visit_ts_type(supertype);
+        }
+        if let Some(impltype) = &mut decl.impltype {
+            self.visit_ts_type(impltype);
+        }
+    }
+
+    fn visit_flow_declare_module_exports(&mut self, decl: &'b mut FlowDeclareModuleExports<'a>) {
+        self.visit_ts_type_annotation(&mut decl.type_annotation);
+    }
+
     fn visit_ts_interface_declaration(&mut self, decl: &'b mut TSInterfaceDeclaration<'a>) {
         self.visit_binding_identifier(&mut decl.id);
         if let Some(parameters) = &mut decl.type_parameters {
@@ -1053,6 +1083,7 @@ pub trait VisitMut<'a, 'b>: Sized {
             TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+            TSType::FlowExactObjectType(ty) => self.visit_flow_exact_object_type(ty),
             _ => {}
         }
     }
@@ -1063,6 +1094,12 @@ pub trait VisitMut<'a, 'b>: Sized {
         }
     }
 
+    fn visit_flow_exact_object_type(&mut self, ty: &'b mut FlowExactObjectType<'a>) {
+        for signature in ty.members.iter_mut() {
+            self.visit_ts_signature(signature);
+        }
+    }
+
     fn visit_ts_indexed_access_type(&mut self, ty: &'b mut TSIndexedAccessType<'a>) {
         self.visit_ts_type(&mut ty.object_type);
         self.visit_ts_type(&mut ty.index_type);
@@ -0,0 +1,42 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Declaration, Statement},
+    AstBuilder, CloneIn, SourceType, Span,
+};
+
+use crate::parse;
+
+const SOURCE: &str =
+    "'use strict'; let { a = 1n, ...b }: T<U> = /x/g; class C { #p = () => <a>{b}</a> }";
+
+fn source_type() -> SourceType {
+    *SourceType::default().with_typescript(true).with_jsx(true)
+}
+
+#[test]
+fn clone_into_another_allocator() {
+    let new_allocator = Allocator::default();
+    let allocator = Allocator::default();
+    let ret = parse(&allocator, SOURCE, source_type());
+    let expected = format!("{:?}", ret.program);
+    let program = ret.program.clone_in(&new_allocator);
+    // the clone does not borrow the original allocator
+    drop(ret);
+    drop(allocator);
+    assert_eq!(format!("{program:?}"), expected, "the clone has the same nodes and spans");
+}
+
+#[test]
+fn clone_is_independent() {
+    let allocator = Allocator::default();
+    let program = parse(&allocator, SOURCE, source_type()).program;
+    let mut statement = program.body[0].clone_in(&allocator);
+    assert_eq!(statement, program.body[0], "the clone equals the original");
+    let Statement::Declaration(Declaration::VariableDeclaration(declaration)) = &mut statement
+    else {
+        unreachable!("expected a variable declaration")
+    };
+    declaration.declarations[0].init =
+        Some(AstBuilder::new(&allocator).this_expression(Span::default()));
+    assert_ne!(statement, program.body[0], "changing the clone leaves the original unchanged");
+}
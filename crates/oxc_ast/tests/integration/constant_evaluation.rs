@@ -0,0 +1,195 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::IdentifierReference, ConstantEvaluation, ConstantValue, SourceType};
+
+use crate::{first_expression, parse};
+
+/// Knows `x` is `1`, and the globals
+struct Evaluator;
+
+impl<'a> ConstantEvaluation<'a> for Evaluator {
+    fn resolve_binding(&self, ident: &IdentifierReference) -> Option<ConstantValue> {
+        match ident.name.as_str() {
+            "x" => Some(ConstantValue::Number(1.0)),
+            name => ConstantValue::global(name),
+        }
+    }
+}
+
+/// Evaluate each source and compare the result converted to a string
+fn assert_values(cases: &[(&str, &str)]) {
+    for (source, expected) in cases {
+        assert_eq!(eval(source).as_deref(), Some(*expected), "value of `{source}`");
+    }
+}
+
+fn eval(source: &str) -> Option<String> {
+    let allocator = Allocator::default();
+    let program = parse(&allocator, source, SourceType::default()).program;
+    Evaluator
+        .eval_expression(first_expression(&program))
+        .map(|value| value.to_js_string().to_string())
+}
+
+#[test]
+fn numbers() {
+    assert_values(&[
+        ("1 + 2 * 3", "7"),
+        ("0.1 + 0.2", "0.30000000000000004"),
+        ("1 / 0", "Infinity"),
+        ("-0", "0"),
+        ("1e21", "1e+21"),
+        ("0 / 0", "NaN"),
+        ("-7 % 2", "-1"),
+        ("2 ** 10", "1024"),
+        ("1 ** Infinity", "NaN"),
+        ("1 ** NaN", "NaN"),
+        ("1 ** undefined", "NaN"),
+    ]);
+}
+
+#[test]
+fn number_conversion() {
+    assert_values(&[
+        ("'1' + 2", "12"),
+        ("'3' * '4'", "12"),
+        ("' 0x10 ' - 0", "16"),
+        ("'1e3' - 0", "1000"),
+        ("'Infinity' - 0", "Infinity"),
+        ("'inf' - 0", "NaN"),
+        ("'' - 0", "0"),
+        ("+'1_0'", "NaN"),
+        ("null + 1", "1"),
+        ("undefined + 1", "NaN"),
+        ("true + true", "2"),
+    ]);
+}
+
+#[test]
+fn bitwise() {
+    assert_values(&[
+        ("~5", "-6"),
+        ("1 << 31", "-2147483648"),
+        ("-1 >>> 0", "4294967295"),
+        ("2 ** 32 | 0", "0"),
+    ]);
+}
+
+#[test]
+fn bigints() {
+    assert_values(&[("2n ** 64n", "18446744073709551616"), ("-7n / 2n", "-3"), ("~1n", "-2")]);
+}
+
+#[test]
+fn templates() {
+    assert_values(&[("`a${1 + 1}b${null}`", "a2bnull")]);
+}
+
+#[test]
+fn unary() {
+    assert_values(&[
+        ("typeof 1", "number"),
+        ("typeof 1n", "bigint"),
+        ("typeof null", "object"),
+        ("typeof undefined", "undefined"),
+        ("typeof function () {}", "function"),
+        ("typeof `${x}`", "string"),
+        ("void 0", "undefined"),
+        ("!''", "true"),
+        ("!!NaN", "false"),
+    ]);
+}
+
+#[test]
+fn equality() {
+    assert_values(&[
+        ("null == undefined", "true"),
+        ("null == 0", "false"),
+        ("'1' == 1", "true"),
+        ("true == 1", "true"),
+        ("1n == 1", "true"),
+        ("1n === 1", "false"),
+        ("NaN === NaN", "false"),
+        ("0 === -0", "true"),
+    ]);
+}
+
+#[test]
+fn relational() {
+    assert_values(&[
+        ("'b' > 'a'", "true"),
+        ("'10' < '9'", "true"),
+        ("'10' < 9", "false"),
+        ("1n < 1.5", "true"),
+        ("NaN <= NaN", "false"),
+    ]);
+}
+
+#[test]
+fn logical_and_conditional() {
+    assert_values(&[
+        ("0 || 'a'", "a"),
+        ("1 && 2", "2"),
+        ("null ?? 3", "3"),
+        ("0 ?? 3", "0"),
+        ("x ? 'yes' : 'no'", "yes"),
+        ("(x, x + 1)", "2"),
+    ]);
+}
+
+#[test]
+fn unknown_values() {
+    for source in ["y", "f()", "1 + y", "typeof y", "'a' in {}", "tag`a`"] {
+        assert_eq!(eval(source), None, "`{source}` has an unknown value");
+    }
+}
+
+#[test]
+fn side_effects() {
+    for source in ["void f()", "delete x", "(f(), 1)", "x++"] {
+        assert_eq!(eval(source), None, "`{source}` has side effects");
+    }
+}
+
+#[test]
+fn errors() {
+    for source in ["1n + 1", "+1n", "1n / 0n", "2n ** -1n"] {
+        assert_eq!(eval(source), None, "`{source}` throws");
+    }
+}
+
+#[test]
+fn truthiness() {
+    let allocator = Allocator::default();
+    for (source, expected) in [
+        ("[f()]", Some(true)),
+        ("!{}", Some(false)),
+        ("y && false", Some(false)),
+        ("y || 'a'", Some(true)),
+        ("y && true", None),
+        ("void f()", Some(false)),
+        ("a = 0", Some(false)),
+        ("(f(), 1)", Some(true)),
+        ("new Foo()", Some(true)),
+        ("y", None),
+    ] {
+        let program = parse(&allocator, source, SourceType::default()).program;
+        assert_eq!(
+            Evaluator.eval_to_boolean(first_expression(&program)),
+            expected,
+            "truthiness of `{source}`"
+        );
+    }
+}
+
+#[test]
+fn long_chains() {
+    // The left operands are evaluated in a loop, a recursion would overflow the stack
+    let chain = |operand: &str, operator: &str| vec![operand; 100_000].join(operator);
+    assert_eq!(eval(&chain("1", " + ")).as_deref(), Some("100000"));
+    assert_eq!(eval(&chain("y", " + ")), None);
+    assert_eq!(eval(&format!("{} ?? 1", chain("null", " ?? "))).as_deref(), Some("1"));
+    let allocator = Allocator::default();
+    let source = chain("y", " || ");
+    let program = parse(&allocator, &source, SourceType::default()).program;
+    assert_eq!(Evaluator.eval_to_boolean(first_expression(&program)), None);
+}
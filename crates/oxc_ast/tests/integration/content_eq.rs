@@ -0,0 +1,51 @@
+use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, ContentEq, ContentHash, ContentOptions, SourceType};
+
+use crate::parse;
+
+fn hash(program: &Program, options: ContentOptions) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    program.content_hash_with(&mut hasher, options);
+    hasher.finish()
+}
+
+const SOURCE: &str = "f(a + b, [1, 2,], { x: 0x10 })";
+
+#[test]
+fn ignores_formatting() {
+    let allocator = Allocator::default();
+    let a = parse(&allocator, SOURCE, SourceType::default()).program;
+    let b = parse(&allocator, "  f( a+b , [1.0, 2], { x: 16, } )", SourceType::default()).program;
+    assert_ne!(a, b, "spans differ");
+    assert!(a.content_eq(&b), "spans, raw numbers and trailing commas are ignored");
+    assert_eq!(
+        hash(&a, ContentOptions::default()),
+        hash(&b, ContentOptions::default()),
+        "equal content has equal hashes"
+    );
+}
+
+#[test]
+fn ignore_parentheses() {
+    let allocator = Allocator::default();
+    let options = ContentOptions { ignore_parentheses: true };
+    let a = parse(&allocator, SOURCE, SourceType::default()).program;
+    let b = parse(&allocator, "f((a + b), [(1), 2], { x: ((16)) })", SourceType::default()).program;
+    assert!(!a.content_eq(&b), "parentheses are compared by default");
+    assert!(a.content_eq_with(&b, options), "parentheses are ignored with the option");
+    assert_eq!(hash(&a, options), hash(&b, options), "hashes ignore parentheses with the option");
+}
+
+#[test]
+fn different_content() {
+    let allocator = Allocator::default();
+    let options = ContentOptions { ignore_parentheses: true };
+    let a = parse(&allocator, SOURCE, SourceType::default()).program;
+    for source in ["f(a - b, [1, 2], { x: 16 })", "f(a + b, [1, 2], { y: 16 })", "f(a + b)"] {
+        let b = parse(&allocator, source, SourceType::default()).program;
+        assert!(!a.content_eq_with(&b, options), "{source} has different content");
+        assert_ne!(hash(&a, options), hash(&b, options), "{source} has a different hash");
+    }
+}
@@ -0,0 +1,197 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, estree::ESTreeOptions, GetSpan, SourceType};
+use serde_json::{json, Value};
+
+use crate::parse;
+
+/// Parse the TypeScript `source` and serialize it to `ESTree` JSON
+fn to_estree(source: &str, options: ESTreeOptions) -> Value {
+    let allocator = Allocator::default();
+    let source_type = *SourceType::default().with_typescript(true);
+    let ret = parse(&allocator, source, source_type);
+    serde_json::from_str(&ret.program.to_estree_json(source, options)).unwrap()
+}
+
+#[test]
+fn type_annotations() {
+    let program = to_estree("let a: number = 1.0 as T;", ESTreeOptions::default());
+    let declarator = &program["body"][0]["declarations"][0];
+    assert_eq!(declarator["id"]["type"], "Identifier", "declarator id");
+    assert_eq!(
+        declarator["id"]["typeAnnotation"]["typeAnnotation"]["type"], "TSNumberKeyword",
+        "type annotation of the declarator id"
+    );
+    assert_eq!(declarator["init"]["type"], "TSAsExpression", "declarator init");
+}
+
+#[test]
+fn positions() {
+    let options = ESTreeOptions { range: true, loc: true, utf16: true };
+    let program = to_estree("let a: number = 1.0 as T;", options);
+    let declarator = &program["body"][0]["declarations"][0];
+    assert_eq!(declarator["id"]["range"], json!([4, 13]), "range includes the type annotation");
+    assert_eq!(
+        declarator["init"]["expression"],
+        json!({
+            "type": "Literal", "start": 16, "end": 19, "value": 1, "raw": "1.0", "range": [16, 19],
+            "loc": { "start": { "line": 1, "column": 16 }, "end": { "line": 1, "column": 19 } },
+        }),
+        "literal with start, end, range and loc"
+    );
+}
+
+#[test]
+fn utf16_positions() {
+    let options = ESTreeOptions { range: true, loc: true, utf16: true };
+    let program = to_estree("'😀'; [x, ...y] = z;\n[x, ...y] = z;", options);
+    let statement = &program["body"][0];
+    assert_eq!(statement["range"], json!([0, 5]), "the emoji is two UTF-16 code units");
+    assert_eq!(statement["expression"]["raw"], "'😀'", "raw is the source text");
+    let rest = &program["body"][1]["expression"]["left"]["elements"][1];
+    assert_eq!(rest["type"], "RestElement", "rest element in an array pattern");
+    assert_eq!(rest["range"], json!([10, 14]), "range after the emoji");
+    let rest = &program["body"][2]["expression"]["left"]["elements"][1];
+    assert_eq!(rest["loc"]["start"], json!({ "line": 2, "column": 4 }), "loc on the next line");
+}
+
+#[test]
+fn functions_without_body() {
+    let program = to_estree(
+        "declare function f(): void; abstract class C { abstract m(): void }",
+        ESTreeOptions::default(),
+    );
+    assert_eq!(program["body"][0]["type"], "TSDeclareFunction", "declared function");
+    let method = &program["body"][1]["body"]["body"][0];
+    assert_eq!(method["type"], "TSAbstractMethodDefinition", "abstract method");
+    assert_eq!(
+        method["value"]["type"], "TSEmptyBodyFunctionExpression",
+        "value of a method without a body"
+    );
+}
+
+/// Read back the `ESTree` JSON of `source` and check it serializes to the same JSON
+fn assert_round_trip(source: &str) {
+    let allocator = Allocator::default();
+    let source_type = *SourceType::default().with_typescript(true).with_jsx(true);
+    let ret = parse(&allocator, source, source_type);
+    for options in
+        [ESTreeOptions::default(), ESTreeOptions { utf16: true, ..ESTreeOptions::default() }]
+    {
+        let json = ret.program.to_estree_json(source, options);
+        let program =
+            Program::from_estree_json(&allocator, source, source_type, &json, options).unwrap();
+        assert_eq!(program.to_estree_json(source, options), json, "{source} with {options:?}");
+    }
+    // the JSON of `Program::to_json`
+    let json = ret.program.to_json();
+    let program =
+        Program::from_estree_json(&allocator, source, source_type, &json, ESTreeOptions::default())
+            .unwrap();
+    assert_eq!(program.to_json(), json, "{source} from `to_json`");
+}
+
+#[test]
+fn round_trip_patterns() {
+    assert_round_trip(
+        "'use strict'; let { a, b: [c = 1, , ...d], ...e } = f?.g[h]!(`x${y}z`) as const;",
+    );
+    assert_round_trip(
+        "for (const [k, v] of m) { ({ a, b: c.d = 1, ...e } = f); label: break label; }",
+    );
+}
+
+#[test]
+fn round_trip_classes() {
+    assert_round_trip(
+        "export default class C<T> extends B<T> implements I { #p = 0n; static { this.#p in this; } }",
+    );
+}
+
+#[test]
+fn round_trip_modules() {
+    assert_round_trip("import x, { y as z } from 'm'; export * as ns from 'n'; export { x };");
+    assert_round_trip("enum E { A = 1, 'B' }\ndeclare module 'm' { export const x = 1; }");
+}
+
+#[test]
+fn round_trip_functions() {
+    assert_round_trip(
+        "async function* f(this: void, a?: number, ...b: string[]): AsyncGenerator<0x1F> {}",
+    );
+    assert_round_trip("x ??= async (a) => a ** 2; new.target; import('m', { with: {} });");
+}
+
+#[test]
+fn round_trip_types() {
+    assert_round_trip(
+        "type T<K extends keyof U> = { readonly [P in K]-?: U[P] } | [a: string, b?: number];",
+    );
+}
+
+#[test]
+fn round_trip_literals_and_jsx() {
+    assert_round_trip("const re = /a+/giu;");
+    assert_round_trip("const el = <a:b c={d} {...e}>t</a:b>;");
+}
+
+#[test]
+fn from_estree_utf16_positions() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let source = "a = '😀'; b";
+    let ret = parse(&allocator, source, source_type);
+    let options = ESTreeOptions { utf16: true, ..ESTreeOptions::default() };
+    let json = ret.program.to_estree_json(source, options);
+    let program =
+        Program::from_estree_json(&allocator, source, source_type, &json, options).unwrap();
+    assert_eq!(
+        program.body[1].span(),
+        ret.program.body[1].span(),
+        "UTF-16 positions are converted back to byte offsets"
+    );
+}
+
+#[test]
+fn from_estree_errors() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let options = ESTreeOptions { utf16: true, ..ESTreeOptions::default() };
+
+    let json = r#"{ "type": "Program", "body": [{ "type": "Identifier", "name": "a" }] }"#;
+    let error = Program::from_estree_json(&allocator, "", source_type, json, options).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unexpected `Identifier` node at 0..0, expected statement",
+        "expression in statement position"
+    );
+
+    let json =
+        r#"{ "type": "Program", "body": [{ "type": "EmptyStatement", "start": 1, "end": 2 }] }"#;
+    assert!(
+        Program::from_estree_json(&allocator, "😀", source_type, json, options).is_err(),
+        "position inside a surrogate pair"
+    );
+    assert!(
+        Program::from_estree_json(&allocator, "", source_type, "{", options).is_err(),
+        "malformed JSON"
+    );
+}
+
+#[test]
+fn long_chains() {
+    // The chains are written in a loop, a recursion would overflow the stack
+    let source = format!("{} || (a ?? a);", vec!["a"; 100_000].join(" + "));
+    let allocator = Allocator::default();
+    let ret = parse(&allocator, &source, SourceType::default());
+    let json = ret.program.to_estree_json(&source, ESTreeOptions::default());
+    assert_eq!(json.matches(r#""type":"BinaryExpression""#).count(), 99_999);
+    assert_eq!(json.matches(r#""type":"LogicalExpression""#).count(), 2);
+    assert!(json.contains(concat!(
+        r#"{"type":"BinaryExpression","start":0,"end":5,"#,
+        r#""left":{"type":"Identifier","start":0,"end":1,"name":"a"},"operator":"+","#,
+        r#""right":{"type":"Identifier","start":4,"end":5,"name":"a"}}"#
+    )));
+    assert!(json.contains(
+        r#""operator":"||","right":{"type":"LogicalExpression","start":400002,"end":400008,"#
+    ));
+}
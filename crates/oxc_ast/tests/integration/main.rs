@@ -0,0 +1,35 @@
+//! Tests of the AST utilities on parsed source text
+
+mod clone_in;
+mod constant_evaluation;
+mod content_eq;
+#[cfg(feature = "serde")]
+mod estree;
+mod side_effects;
+mod traverse;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Expression, Program, Statement},
+    SourceType,
+};
+use oxc_parser::{Parser, ParserReturn};
+
+/// Parse `source`, which must not have syntax errors
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source: &'a str,
+    source_type: SourceType,
+) -> ParserReturn<'a> {
+    let ret = Parser::new(allocator, source, source_type).parse();
+    assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+    ret
+}
+
+/// The expression of the first statement, which must be an expression statement
+fn first_expression<'p, 'a>(program: &'p Program<'a>) -> &'p Expression<'a> {
+    let Some(Statement::ExpressionStatement(stmt)) = program.body.first() else {
+        unreachable!("expected an expression statement")
+    };
+    &stmt.expression
+}
@@ -0,0 +1,169 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ConstantEvaluation, SideEffects, SourceType, Span, Trivias};
+
+use crate::{first_expression, parse};
+
+/// Knows the pure annotations, and `Math` as a global
+struct Analyzer<'s> {
+    source_text: &'s str,
+    trivias: &'s Trivias,
+}
+
+impl<'a, 's> ConstantEvaluation<'a> for Analyzer<'s> {}
+
+impl<'a, 's> SideEffects<'a> for Analyzer<'s> {
+    fn has_pure_annotation(&self, span: Span) -> bool {
+        self.trivias.has_pure_annotation(self.source_text, span)
+    }
+
+    fn is_known_global(&self, name: &str) -> bool {
+        matches!(name, "Math" | "undefined" | "NaN" | "Infinity")
+    }
+}
+
+/// Check each `(source, has side effects, may throw)` expression case
+fn assert_expressions(cases: &[(&str, bool, bool)]) {
+    let allocator = Allocator::default();
+    for &(source, has_side_effects, may_throw) in cases {
+        let ret = parse(&allocator, source, SourceType::default());
+        let analyzer = Analyzer { source_text: source, trivias: &ret.trivias };
+        let expr = first_expression(&ret.program);
+        assert_eq!(
+            analyzer.expression_has_side_effects(expr),
+            has_side_effects,
+            "side effects of `{source}`"
+        );
+        assert_eq!(analyzer.expression_may_throw(expr), may_throw, "`{source}` may throw");
+    }
+}
+
+/// Check each `(source, has side effects, may throw)` case for all the statements of a program
+fn assert_statements(cases: &[(&str, bool, bool)]) {
+    let allocator = Allocator::default();
+    let source_type = *SourceType::default().with_typescript(true);
+    for &(source, has_side_effects, may_throw) in cases {
+        let ret = parse(&allocator, source, source_type);
+        let analyzer = Analyzer { source_text: source, trivias: &ret.trivias };
+        let has = ret.program.body.iter().any(|stmt| analyzer.statement_has_side_effects(stmt));
+        let throws = ret.program.body.iter().any(|stmt| analyzer.statement_may_throw(stmt));
+        assert_eq!(has, has_side_effects, "side effects of `{source}`");
+        assert_eq!(throws, may_throw, "`{source}` may throw");
+    }
+}
+
+#[test]
+fn literals_and_globals() {
+    assert_expressions(&[
+        ("1 + 2", false, false),
+        ("'a' + x", true, true),
+        ("Math", false, false),
+        ("typeof y", false, false),
+        ("typeof y.z", true, true),
+        ("`${1}${'a'}`", false, false),
+        ("`${y}`", true, true),
+    ]);
+}
+
+#[test]
+fn arrays_and_objects() {
+    assert_expressions(&[
+        ("[1, 'a', function () {}]", false, false),
+        ("[...a]", true, true),
+        ("({ a: 1, [1 + 1]: 2 })", false, false),
+        ("({ [a]: 1 })", true, true),
+        ("({ ...a })", true, true),
+    ]);
+}
+
+#[test]
+fn operators() {
+    assert_expressions(&[
+        ("-1", false, false),
+        ("-a", true, true),
+        ("1n + 1n", false, false),
+        ("1n + 1", false, true),
+        ("+1n", false, true),
+        ("'a' === 1", false, false),
+        ("Math === 1", false, false),
+        ("Math < 1", true, true),
+        ("a = 1", true, true),
+        ("a++", true, true),
+        ("delete a.b", true, true),
+        ("'a' in b", true, true),
+        ("(1, 2)", false, false),
+        ("(f(), 2)", true, true),
+        ("x ? 1 : 2", false, true),
+        ("0 && f()", false, false),
+    ]);
+}
+
+#[test]
+fn calls_and_members() {
+    assert_expressions(&[
+        ("a.b", true, true),
+        ("a?.b", true, true),
+        ("f()", true, true),
+        ("new F()", true, true),
+    ]);
+}
+
+#[test]
+fn pure_annotations() {
+    assert_expressions(&[
+        ("/*#__PURE__*/ f()", false, false),
+        ("/*#__PURE__*/ new F(1)", false, false),
+        ("/*#__PURE__*/ f(g())", true, true),
+        ("/* @__PURE__ */ f(...a)", true, true),
+    ]);
+}
+
+#[test]
+fn classes() {
+    assert_expressions(&[
+        ("(class {})", false, false),
+        ("(class extends Math {})", false, true),
+        ("(class { static a = f() })", true, true),
+        ("(class { a = f() })", false, false),
+        ("(class { [a]() {} })", true, true),
+        ("(class { static { f() } })", true, true),
+    ]);
+}
+
+#[test]
+fn declarations() {
+    assert_statements(&[
+        (";", false, false),
+        ("{ 1; 'a'; }", false, false),
+        ("if (Math) 1; else { 2 }", false, false),
+        ("var a = 1, b = function () {};", false, false),
+        ("let { a } = b;", true, true),
+        ("function f() { g(); }", false, false),
+        ("class A extends B {}", false, true),
+        ("type A = B; interface C {}", false, false),
+    ]);
+}
+
+#[test]
+fn control_flow() {
+    assert_statements(&[
+        ("try { f() } catch {}", true, false),
+        ("try { f() } catch { g() }", true, true),
+        ("try { f() } finally { g() }", true, true),
+        ("throw 1;", true, true),
+        ("debugger;", true, false),
+        ("while (1) {}", true, false),
+        ("for (const a of b) {}", true, true),
+    ]);
+}
+
+#[test]
+fn long_chains() {
+    // The operands are checked in a loop, a recursion would overflow the stack
+    let chain = |operand: &str, operator: &str| vec![operand; 100_000].join(operator);
+    assert_expressions(&[
+        (&chain("typeof y", " + "), false, false),
+        (&chain("1", " - "), false, false),
+        (&format!("{} - 1", chain("y", " || ")), true, true),
+        (&format!("{} === 1", chain("-y", " - ")), true, true),
+    ]);
+}
@@ -0,0 +1,91 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{BinaryOperator, Expression, IdentifierReference, NumberBase, NumberLiteral, Program},
+    traverse::{traverse_mut, Ancestor, AstType, Traverse, TraverseCtx},
+    ContentEq, SourceType,
+};
+
+use crate::parse;
+
+/// Collect callees and fold additions and multiplications of numbers
+#[derive(Default)]
+struct Folder {
+    callees: Vec<String>,
+    methods: Vec<String>,
+    in_function: usize,
+}
+
+impl<'a> Traverse<'a> for Folder {
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match (ctx.parent(), ctx.ancestor(2)) {
+            (Ancestor::CallExpressionCallee, _) => {
+                self.callees.push(ident.name.to_string());
+            }
+            (Ancestor::StaticMemberExpressionObject, Ancestor::CallExpressionCallee) => {
+                self.methods.push(ident.name.to_string());
+            }
+            _ => {}
+        }
+        if ctx.ancestors().any(|ancestor| ancestor.ty() == Some(AstType::Function)) {
+            self.in_function += 1;
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::BinaryExpression(binary) = expr else { return };
+        let (Expression::NumberLiteral(left), Expression::NumberLiteral(right)) =
+            (&binary.left, &binary.right)
+        else {
+            return;
+        };
+        let value = match binary.operator {
+            BinaryOperator::Addition => *left.value + *right.value,
+            BinaryOperator::Multiplication => *left.value * *right.value,
+            _ => return,
+        };
+        let literal = NumberLiteral::new(binary.span, value, "", NumberBase::Decimal);
+        *expr = ctx.ast.literal_number_expression(literal);
+    }
+}
+
+/// Traverse `source` with a [`Folder`] and return it with the traversed program
+fn fold<'a>(allocator: &'a Allocator, source: &'a str) -> (Folder, Program<'a>) {
+    let mut program = parse(allocator, source, SourceType::default()).program;
+    let mut folder = Folder::default();
+    traverse_mut(&mut folder, &mut program, allocator);
+    (folder, program)
+}
+
+#[test]
+fn parent() {
+    let allocator = Allocator::default();
+    let (folder, _) = fold(&allocator, "f(1); g.h(x); (0, k)(); function a() { b(y) }");
+    assert_eq!(folder.callees, ["f", "b"], "identifiers whose parent is a callee");
+}
+
+#[test]
+fn grandparent() {
+    let allocator = Allocator::default();
+    let (folder, _) = fold(&allocator, "f(1); g.h(x); (0, k)(); i.j;");
+    assert_eq!(folder.methods, ["g"], "objects of member expressions which are callees");
+}
+
+#[test]
+fn ancestors() {
+    let allocator = Allocator::default();
+    let (folder, _) = fold(&allocator, "f(x); function a() { b(y) }");
+    assert_eq!(folder.in_function, 2, "identifiers with a function ancestor");
+}
+
+#[test]
+fn replace_on_exit() {
+    let allocator = Allocator::default();
+    let (_, program) = fold(&allocator, "f(1 + 2 * 3); function a() { b(4 * 5 + y) }");
+    let expected = "f(7); function a() { b(20 + y) }";
+    let expected = parse(&allocator, expected, SourceType::default()).program;
+    assert!(program.content_eq(&expected), "nested binary expressions are folded bottom up");
+}
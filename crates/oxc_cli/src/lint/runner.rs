@@ -9,11 +9,12 @@ use std::{
     },
 };
 
-use miette::NamedSource;
 use oxc_allocator::Allocator;
 use oxc_ast::SourceType;
-use oxc_diagnostics::{Error, GraphicalReportHandler, MinifiedFileError, Severity};
-use oxc_linter::{Fixer, Linter, RuleCategory, RuleEnum, RULES};
+use oxc_diagnostics::{
+    with_offset, Error, GraphicalReportHandler, IndexedSource, MinifiedFileError, Severity,
+};
+use oxc_linter::{Fixer, Linter, Message, PartialLoader, RuleCategory, RuleEnum, RULES};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use rustc_hash::FxHashSet;
@@ -188,45 +189,87 @@ impl LintRunner {
     fn lint_path(linter: &Linter, path: &Path) -> Option<(PathBuf, Vec<Error>)> {
         let source_text = fs::read_to_string(path).unwrap_or_else(|_| panic!("{path:?} not found"));
         let allocator = Allocator::default();
-        let source_type =
-            SourceType::from_path(path).unwrap_or_else(|_| panic!("incorrect {path:?}"));
-        let ret = Parser::new(&allocator, &source_text, source_type).parse();
-
-        if !ret.errors.is_empty() {
-            return Some(Self::wrap_diagnostics(path, &source_text, ret.errors));
-        };
-
-        let program = allocator.alloc(ret.program);
-        let semantic_ret =
-            SemanticBuilder::new(&source_text, source_type, &ret.trivias).build(program);
-
-        if !semantic_ret.errors.is_empty() {
-            return Some(Self::wrap_diagnostics(path, &source_text, semantic_ret.errors));
-        };
+        let extension = path.extension().and_then(std::ffi::OsStr::to_str).unwrap_or_default();
+        // Scripts embedded in Vue, Svelte, Astro and HTML files and the code blocks of Markdown files
+        // are linted on their own, with the offset of the script in the file
+        let sources = PartialLoader::parse(extension, &source_text).map_or_else(
+            || {
+                let mut source_type =
+                    SourceType::from_path(path).unwrap_or_else(|_| panic!("incorrect {path:?}"));
+                // `.js` files can be either CommonJS or ES modules
+                if matches!(extension, "js" | "jsx") {
+                    source_type = *source_type.with_unambiguous(true);
+                }
+                vec![(source_text.as_str(), source_type, 0)]
+            },
+            |sources| {
+                sources
+                    .into_iter()
+                    .map(|source| (source.source_text, source.source_type, source.span.start))
+                    .collect()
+            },
+        );
 
-        let result = linter.run(&Rc::new(semantic_ret.semantic));
+        // A syntax error in one script block does not stop the others from being linted
+        let mut errors = vec![];
+        let mut messages = vec![];
+        for &(text, source_type, offset) in &sources {
+            match Self::lint_source(linter, &allocator, text, source_type) {
+                Ok(result) => {
+                    messages.extend(result.into_iter().map(|message| message.with_offset(offset)));
+                }
+                Err(source_errors) => {
+                    errors
+                        .extend(source_errors.into_iter().map(|error| with_offset(error, offset)));
+                }
+            }
+        }
 
-        if result.is_empty() {
+        if errors.is_empty() && messages.is_empty() {
             return None;
         }
 
-        if linter.has_fix() {
-            let fix_result = Fixer::new(&source_text, result).fix();
+        if linter.has_fix() && !messages.is_empty() {
+            let fix_result = Fixer::new(&source_text, messages).fix();
             fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-            let errors = fix_result.messages.into_iter().map(|m| m.error).collect();
+            errors.extend(fix_result.messages.into_iter().map(|m| m.error));
             return Some(Self::wrap_diagnostics(path, &source_text, errors));
         }
 
-        let errors = result.into_iter().map(|diagnostic| diagnostic.error).collect();
+        errors.extend(messages.into_iter().map(|diagnostic| diagnostic.error));
         Some(Self::wrap_diagnostics(path, &source_text, errors))
     }
 
+    /// Returns the syntax and semantic errors, or the lint results if there are none
+    fn lint_source<'a>(
+        linter: &Linter,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Result<Vec<Message<'a>>, Vec<Error>> {
+        let ret = Parser::new(allocator, source_text, source_type).parse();
+
+        if !ret.errors.is_empty() {
+            return Err(ret.errors);
+        };
+
+        let program = allocator.alloc(ret.program);
+        let semantic_ret =
+            SemanticBuilder::new(source_text, program.source_type, &ret.trivias).build(program);
+
+        if !semantic_ret.errors.is_empty() {
+            return Err(semantic_ret.errors);
+        };
+
+        Ok(linter.run(&Rc::new(semantic_ret.semantic)))
+    }
+
     fn wrap_diagnostics(
         path: &Path,
         source_text: &str,
         diagnostics: Vec<Error>,
     ) -> (PathBuf, Vec<Error>) {
-        let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
+        let source = Arc::new(IndexedSource::new(path.to_string_lossy(), source_text.to_owned()));
         let diagnostics = diagnostics
             .into_iter()
             .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
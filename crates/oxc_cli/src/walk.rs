@@ -2,6 +2,7 @@ use std::path::Path;
 
 use ignore::{overrides::OverrideBuilder, DirEntry, WalkBuilder};
 use oxc_ast::VALID_EXTENSIONS;
+use oxc_linter::LINT_PARTIAL_LOADER_EXT;
 
 use crate::LintOptions;
 
@@ -58,6 +59,8 @@ impl Walk {
             return false;
         }
         let Some(extension) = dir_entry.path().extension() else { return false };
-        VALID_EXTENSIONS.contains(&extension.to_string_lossy().as_ref())
+        let extension = extension.to_string_lossy();
+        VALID_EXTENSIONS.contains(&extension.as_ref())
+            || LINT_PARTIAL_LOADER_EXT.contains(&extension.as_ref())
     }
 }
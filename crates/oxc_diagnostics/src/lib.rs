@@ -3,13 +3,17 @@
 
 mod graphic_reporter;
 mod graphical_theme;
+mod offset;
+mod source;
 
 use std::path::PathBuf;
 
 pub use graphic_reporter::GraphicalReportHandler;
 pub use miette;
 use miette::Diagnostic;
+pub use offset::with_offset;
 use oxc_ast::{Atom, Span};
+pub use source::IndexedSource;
 pub use thiserror;
 use thiserror::Error;
 
@@ -28,6 +32,16 @@ pub struct Redeclaration(
     #[label("It can not be redeclared here")] pub Span,
 );
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("The code is too deeply nested")]
+#[diagnostic(help("The nesting depth exceeds the limit of {0}"))]
+pub struct NestingTooDeep(pub u32, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The program contains code which failed to parse")]
+#[diagnostic(help("Fix the syntax errors, or parse without error recovery"))]
+pub struct InvalidNode(#[label("This code failed to parse")] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("File is too long to fit on the screen")]
 #[diagnostic(help("{0:?} seems like a minified file"))]
@@ -0,0 +1,93 @@
+use std::fmt::{self, Display};
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+
+use crate::Error;
+
+/// Move the labels of `error` by `offset` bytes,
+/// for code which is parsed apart from the rest of its file, e.g. a `<script>` block of a Vue file.
+#[must_use]
+pub fn with_offset(error: Error, offset: u32) -> Error {
+    if offset == 0 {
+        return error;
+    }
+    Error::new(OffsetDiagnostic { error, offset: offset as usize })
+}
+
+#[derive(Debug)]
+struct OffsetDiagnostic {
+    error: Error,
+    offset: usize,
+}
+
+impl Display for OffsetDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for OffsetDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for OffsetDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.error.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let labels = self.error.labels()?;
+        Some(Box::new(labels.map(|label| {
+            let label_text = label.label().map(ToString::to_string);
+            LabeledSpan::new(label_text, label.offset() + self.offset, label.len())
+        })))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.error.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.error.diagnostic_source()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::Span;
+
+    use super::with_offset;
+    use crate::{Error, Redeclaration};
+
+    #[test]
+    fn labels() {
+        let error: Error = Redeclaration("a".into(), Span::new(1, 2), Span::new(5, 8)).into();
+        let error = with_offset(error, 10);
+        assert_eq!(error.to_string(), "Identifier `a` has already been declared");
+        let labels = error.labels().unwrap().collect::<Vec<_>>();
+        assert_eq!(
+            labels.iter().map(|label| (label.offset(), label.len())).collect::<Vec<_>>(),
+            [(11, 1), (15, 3)],
+            "labels are moved by the offset"
+        );
+        assert_eq!(labels[0].label(), Some("`a` has already been declared here"));
+    }
+}
@@ -0,0 +1,125 @@
+use miette::{MietteError, MietteSpanContents, SourceCode, SourceSpan, SpanContents};
+use oxc_ast::LineIndex;
+
+/// Named source code with a [`LineIndex`], for attaching to diagnostics with `with_source_code`.
+///
+/// Same as [`miette::NamedSource`], but the lines of the labels are looked up in the index
+/// instead of scanning the source text from the start for every label,
+/// which is quadratic for files with many diagnostics.
+#[derive(Debug)]
+pub struct IndexedSource {
+    name: String,
+    source_text: String,
+    line_index: LineIndex,
+}
+
+impl IndexedSource {
+    #[must_use]
+    pub fn new(name: impl AsRef<str>, source_text: String) -> Self {
+        let line_index = LineIndex::new(&source_text);
+        Self { name: name.as_ref().to_string(), source_text, line_index }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn source_text(&self) -> &str {
+        &self.source_text
+    }
+
+    #[must_use]
+    pub fn line_index(&self) -> &LineIndex {
+        &self.line_index
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+impl SourceCode for IndexedSource {
+    /// Reads the lines of `span` with the same contents, lines and columns as the `SourceCode`
+    /// implementation of `str` in `miette`.
+    fn read_span<'a>(
+        &'a self,
+        span: &SourceSpan,
+        context_lines_before: usize,
+        context_lines_after: usize,
+    ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        let text = self.source_text.as_bytes();
+        let index = &self.line_index;
+        let len = text.len();
+        // The last byte of the span, or the byte before an empty span
+        let last = (span.offset() + span.len()).saturating_sub(1);
+        if last > len {
+            return Err(MietteError::OutOfBounds);
+        }
+        let start = span.offset().min(len);
+        let start_position = index.line_column(start as u32);
+
+        let first_line = (start_position.line as usize).saturating_sub(context_lines_before);
+        let (data_start, column) = if context_lines_before == 0 {
+            (start, start_position.column as usize)
+        } else {
+            (index.line_start(first_line as u32).unwrap_or_default() as usize, 0)
+        };
+
+        let is_newline = |offset: usize| matches!(text.get(offset), Some(b'\r' | b'\n'));
+        let data_end = if context_lines_after == 0 {
+            let crlf = text.get(last) == Some(&b'\r') && text.get(last + 1) == Some(&b'\n');
+            (last + 1 + usize::from(crlf)).min(len)
+        } else {
+            // A span ending with a line break ends on the next line
+            let last_line =
+                index.line_column(last as u32).line as usize + usize::from(is_newline(last));
+            let end_line = last_line + context_lines_after;
+            index.line_start(end_line as u32 + 1).map_or(len, |start| start as usize)
+        };
+
+        // The number of line breaks before the end of the contents
+        let line_count = index.line_column(data_end as u32).line as usize;
+
+        Ok(Box::new(MietteSpanContents::new_named(
+            self.name.clone(),
+            &text[data_start..data_end],
+            (data_start, data_end - data_start).into(),
+            first_line,
+            column,
+            line_count,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use miette::{SourceCode, SourceSpan};
+
+    use super::IndexedSource;
+
+    #[test]
+    fn same_as_miette() {
+        let source_text = "let a;\r\nlet é = '😀';\rb\n\nc\n  d;";
+        let source = IndexedSource::new("test.js", source_text.to_string());
+        let bytes = source_text.as_bytes();
+        for start in 0..=bytes.len() {
+            for len in 0..=bytes.len() - start {
+                // Miette counts the columns of a span starting at the `\n` of a `\r\n` from the `\r`
+                if start > 0 && bytes[start - 1] == b'\r' && bytes.get(start) == Some(&b'\n') {
+                    continue;
+                }
+                let span = SourceSpan::from((start, len));
+                for context_lines in 0..3 {
+                    let expected =
+                        source_text.read_span(&span, context_lines, context_lines).unwrap();
+                    let actual = source.read_span(&span, context_lines, context_lines).unwrap();
+                    assert_eq!(actual.data(), expected.data(), "{start} {len} {context_lines}");
+                    assert_eq!(actual.span(), expected.span());
+                    assert_eq!(actual.line(), expected.line());
+                    assert_eq!(actual.column(), expected.column());
+                    assert_eq!(actual.line_count(), expected.line_count());
+                    assert_eq!(actual.name(), Some("test.js"));
+                }
+            }
+        }
+    }
+}
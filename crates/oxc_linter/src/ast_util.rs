@@ -1,6 +1,6 @@
 use std::hash::{Hash, Hasher};
 
-use oxc_ast::{AstKind, GetSpan};
+use oxc_ast::{AstKind, ContentHash, ContentOptions, GetSpan};
 use oxc_semantic::AstNode;
 use rustc_hash::FxHasher;
 
@@ -9,6 +9,13 @@ pub fn calculate_hash<T: Hash>(t: &T) -> u64 {
     t.hash(&mut hasher);
     hasher.finish()
 }
+
+/// Hash of the content of an AST node, ignoring spans, see [`ContentHash`]
+pub fn calculate_content_hash<T: ContentHash>(t: &T, options: ContentOptions) -> u64 {
+    let mut hasher = FxHasher::default();
+    t.content_hash_with(&mut hasher, options);
+    hasher.finish()
+}
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
 use phf::{phf_set, Set};
@@ -1,7 +1,10 @@
 use std::{cell::RefCell, rc::Rc};
 
 use indextree::{Ancestors, NodeId};
-use oxc_ast::{ast::IdentifierReference, AstKind, SourceType};
+use oxc_ast::{
+    ast::IdentifierReference, AstKind, ConstantEvaluation, ConstantValue, SideEffects, SourceType,
+    Span,
+};
 use oxc_diagnostics::Error;
 use oxc_printer::{Printer, PrinterOptions};
 use oxc_semantic::{AstNodes, Scope, ScopeTree, Semantic, SemanticNode, SymbolTable};
@@ -9,6 +12,7 @@ use oxc_semantic::{AstNodes, Scope, ScopeTree, Semantic, SemanticNode, SymbolTab
 use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
     fixer::{Fix, Message},
+    globals::BUILTINS,
     AstNode,
 };
 
@@ -153,3 +157,24 @@ impl<'a> LintContext<'a> {
         Printer::new(0, PrinterOptions::default())
     }
 }
+
+impl<'a> ConstantEvaluation<'a> for LintContext<'a> {
+    fn resolve_binding(&self, ident: &IdentifierReference) -> Option<ConstantValue> {
+        self.semantic().resolve_binding(ident)
+    }
+}
+
+impl<'a> SideEffects<'a> for LintContext<'a> {
+    fn has_pure_annotation(&self, span: Span) -> bool {
+        self.semantic().has_pure_annotation(span)
+    }
+
+    fn is_global_reference(&self, ident: &IdentifierReference) -> bool {
+        self.semantic().is_global_reference(ident)
+    }
+
+    /// Reading the builtin globals of [`BUILTINS`] does not throw
+    fn is_known_global(&self, name: &str) -> bool {
+        BUILTINS.contains_key(name)
+    }
+}
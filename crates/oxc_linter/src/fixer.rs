@@ -58,6 +58,17 @@ impl<'a> Message<'a> {
     pub fn end(&self) -> u32 {
         self.end
     }
+
+    /// Move the message and its fix by `offset` bytes,
+    /// for code which is linted apart from the rest of its file, see [`crate::PartialLoader`]
+    #[must_use]
+    pub fn with_offset(self, offset: u32) -> Self {
+        let error = oxc_diagnostics::with_offset(self.error, offset);
+        let fix = self.fix.map(|fix| {
+            Fix::new(fix.content, Span::new(fix.span.start + offset, fix.span.end + offset))
+        });
+        Self { error, start: self.start + offset, end: self.end + offset, fix, fixed: self.fixed }
+    }
 }
 
 /// The fixer of the code.
@@ -397,4 +408,19 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn fix_with_offset() {
+        let source_text = format!("<script>{TEST_CODE}</script>");
+        let messages = vec![
+            Message::new(RemoveMiddle(Span::new(5, 10)).into(), Some(REMOVE_MIDDLE)).with_offset(8),
+            Message::new(NoFix(Span::new(0, 3)).into(), None).with_offset(8),
+        ];
+        let result = Fixer::new(&source_text, messages).fix();
+        assert_eq!(result.fixed_code, source_text.replace("answer", "a"), "the fix is moved");
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!((result.messages[0].start(), result.messages[0].end()), (8, 11));
+        let label = result.messages[0].error.labels().unwrap().next().unwrap();
+        assert_eq!((label.offset(), label.len()), (8, 3), "the label is moved");
+    }
 }
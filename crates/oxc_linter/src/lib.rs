@@ -9,6 +9,7 @@ mod context;
 mod disable_directives;
 mod fixer;
 mod globals;
+mod partial_loader;
 pub mod rule;
 mod rules;
 
@@ -17,6 +18,7 @@ use std::{fs, rc::Rc};
 pub use fixer::{Fixer, Message};
 pub(crate) use oxc_semantic::AstNode;
 use oxc_semantic::Semantic;
+pub use partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT};
 
 use crate::{
     context::LintContext, rule::Rule, rules::early_error::javascript::EarlyErrorJavaScript,
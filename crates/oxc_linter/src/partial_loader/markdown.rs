@@ -0,0 +1,156 @@
+//! Fenced code blocks of Markdown and MDX files
+
+use oxc_ast::{SourceType, Span};
+
+use super::JavaScriptSource;
+
+/// Skips the next code block, same as in `eslint-plugin-markdown`
+const SKIP_COMMENT: &str = "<!-- eslint-skip -->";
+
+/// An opening code fence, e.g. ```` ```ts ````
+struct Fence {
+    ch: char,
+    len: usize,
+    source_type: Option<SourceType>,
+}
+
+/// The ```` ``` ```` and `~~~` fenced code blocks with a `js`, `jsx`, `mjs`, `cjs`, `ts`, `tsx`, `mts` or `cts`
+/// info string (or `javascript` and `typescript`).
+///
+/// Blocks preceded by `<!-- eslint-skip -->` are skipped.
+/// The code is parsed as a script or a module depending on the presence of `import` and `export`.
+/// The source text of a block is its content only, from the line after the opening fence
+/// to the start of the closing fence line, so diagnostics are moved by `span.start`.
+pub fn code_blocks(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+    let mut sources = vec![];
+    let mut open: Option<(Fence, usize, bool)> = None;
+    let mut skip = false;
+    let mut pos = 0;
+    for line in source_text.split_inclusive('\n') {
+        let line_start = pos;
+        pos += line.len();
+        let trimmed = line.trim();
+        if let Some((fence, content_start, skipped)) = &open {
+            if is_closing_fence(trimmed, fence) {
+                if let (Some(source_type), false) = (fence.source_type, *skipped) {
+                    let span = span(*content_start, line_start);
+                    sources.push(JavaScriptSource::new(source_text, source_type, span, false));
+                }
+                open = None;
+            }
+            continue;
+        }
+        if let Some(fence) = opening_fence(trimmed) {
+            open = Some((fence, pos, skip));
+            skip = false;
+        } else if trimmed == SKIP_COMMENT {
+            skip = true;
+        } else if !trimmed.is_empty() {
+            skip = false;
+        }
+    }
+    // An unclosed code block runs to the end of the document
+    if let Some((Fence { source_type: Some(source_type), .. }, content_start, false)) = open {
+        let span = span(content_start, source_text.len());
+        sources.push(JavaScriptSource::new(source_text, source_type, span, false));
+    }
+    sources
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn span(start: usize, end: usize) -> Span {
+    Span::new(start as u32, end as u32)
+}
+
+fn opening_fence(line: &str) -> Option<Fence> {
+    let ch = line.chars().next().filter(|ch| matches!(ch, '`' | '~'))?;
+    let len = line.len() - line.trim_start_matches(ch).len();
+    if len < 3 {
+        return None;
+    }
+    let info = &line[len..];
+    // The info string of a backtick fence cannot contain backticks
+    if ch == '`' && info.contains('`') {
+        return None;
+    }
+    let lang = info.trim_start().split(|c: char| c.is_whitespace() || c == '{').next();
+    Some(Fence { ch, len, source_type: lang.and_then(source_type) })
+}
+
+fn is_closing_fence(line: &str, fence: &Fence) -> bool {
+    let len = line.len() - line.trim_start_matches(fence.ch).len();
+    len >= fence.len && line[len..].trim().is_empty()
+}
+
+fn source_type(lang: &str) -> Option<SourceType> {
+    let mut source_type = SourceType::default();
+    _ = source_type.with_unambiguous(true);
+    match lang.to_ascii_lowercase().as_str() {
+        "js" | "javascript" | "jsx" | "mjs" | "cjs" => _ = source_type.with_jsx(true),
+        "ts" | "typescript" | "mts" | "cts" => _ = source_type.with_typescript(true),
+        "tsx" => _ = source_type.with_typescript(true).with_jsx(true),
+        _ => return None,
+    }
+    Some(source_type)
+}
+
+#[cfg(test)]
+mod test {
+    use super::code_blocks;
+
+    #[test]
+    fn fenced_code_blocks() {
+        let source_text = "# Title
+
+```js
+import a from 'a';
+```
+
+<!-- eslint-skip -->
+
+```ts
+const skipped: = 1;
+```
+
+- item
+
+  ~~~tsx title=\"a.tsx\"
+  const a = <div />;
+  ~~~
+
+```json
+{ \"a\": 1 }
+```
+
+````javascript
+```
+debugger;
+````
+
+```jsx
+unclosed
+";
+        let sources = code_blocks(source_text);
+        let blocks =
+            sources.iter().map(|source| source.span.source_text(source_text)).collect::<Vec<_>>();
+        assert_eq!(
+            blocks,
+            ["import a from 'a';\n", "  const a = <div />;\n", "```\ndebugger;\n", "unclosed\n"]
+        );
+        assert!(sources[0].source_type.is_unambiguous() && sources[0].source_type.is_jsx());
+        assert!(sources[1].source_type.is_typescript() && sources[1].source_type.is_jsx());
+        for source in &sources {
+            assert_eq!(source.source_text, source.span.source_text(source_text));
+        }
+    }
+
+    #[test]
+    fn offsets() {
+        let source_text = "Text\n\n```js\nlet a;\n```\n\n- item\n  ```ts\n  let b;\n  ```\n";
+        let sources = code_blocks(source_text);
+        let offsets = sources.iter().map(|source| source.span.start).collect::<Vec<_>>();
+        assert_eq!(offsets, [12, 39], "blocks start on the line after the opening fence");
+        assert_eq!(sources[0].source_text, "let a;\n", "only the block is parsed");
+        assert_eq!(sources[1].source_text, "  let b;\n", "the indentation of a list item is kept");
+    }
+}
@@ -0,0 +1,312 @@
+//! Extract the scripts of Vue, Svelte, Astro, HTML and Markdown files, see [`PartialLoader`]
+
+mod markdown;
+
+use oxc_ast::{SourceType, Span};
+
+/// File extensions handled by [`PartialLoader::parse`]
+pub const LINT_PARTIAL_LOADER_EXT: [&str; 6] = ["vue", "svelte", "astro", "html", "md", "mdx"];
+
+/// A script block of a file
+#[derive(Debug)]
+pub struct JavaScriptSource<'a> {
+    /// The content of the script block,
+    /// the spans of its diagnostics and fixes are moved by `span.start` to get their offsets in the file
+    pub source_text: &'a str,
+    pub source_type: SourceType,
+    /// The content of the script block in the file
+    pub span: Span,
+    /// Vue `<script setup>`
+    pub setup: bool,
+}
+
+pub struct PartialLoader;
+
+impl PartialLoader {
+    /// Find the script blocks of a file with one of the [`LINT_PARTIAL_LOADER_EXT`] extensions.
+    ///
+    /// * `<script>` tags, with `lang="ts"`, `lang="tsx"` or `lang="jsx"` in Vue and Svelte
+    /// * the `---` frontmatter of Astro files
+    /// * `<script>` and `<script type="module">` in HTML files
+    /// * fenced code blocks with a JavaScript or TypeScript info string in Markdown files
+    ///
+    /// Returns `None` for other extensions.
+    /// Blocks in other languages, e.g. `<script type="text/template">` or `<script lang="coffee">`, are skipped.
+    #[must_use]
+    pub fn parse<'a>(extension: &str, source_text: &'a str) -> Option<Vec<JavaScriptSource<'a>>> {
+        let framework = match extension {
+            "vue" => Framework::Vue,
+            "svelte" => Framework::Svelte,
+            "astro" => Framework::Astro,
+            "html" => Framework::Html,
+            "md" | "mdx" => return Some(markdown::code_blocks(source_text)),
+            _ => return None,
+        };
+        let mut sources = vec![];
+        let mut tags = ScriptTags::new(source_text);
+        if framework == Framework::Astro {
+            if let Some(span) = astro_frontmatter(source_text) {
+                let source_type = *SourceType::default().with_module(true).with_typescript(true);
+                sources.push(JavaScriptSource::new(source_text, source_type, span, false));
+                tags.pos = span.end as usize + 3;
+            }
+        }
+        for tag in tags {
+            if tag.span.source_text(source_text).trim().is_empty() {
+                continue;
+            }
+            if let Some(source_type) = framework.source_type(&tag) {
+                let setup = tag.attribute("setup").is_some();
+                sources.push(JavaScriptSource::new(source_text, source_type, tag.span, setup));
+            }
+        }
+        Some(sources)
+    }
+}
+
+impl<'a> JavaScriptSource<'a> {
+    /// The script block at `span` of the file `source_text`
+    pub(crate) fn new(
+        source_text: &'a str,
+        source_type: SourceType,
+        span: Span,
+        setup: bool,
+    ) -> Self {
+        Self { source_text: span.source_text(source_text), source_type, span, setup }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Framework {
+    Vue,
+    Svelte,
+    Astro,
+    Html,
+}
+
+impl Framework {
+    fn source_type(self, tag: &ScriptTag) -> Option<SourceType> {
+        let mut source_type = SourceType::default();
+        match self {
+            Self::Vue | Self::Svelte => {
+                _ = source_type.with_module(true);
+                match tag.attribute("lang") {
+                    None | Some("js") => {}
+                    Some("jsx") => _ = source_type.with_jsx(true),
+                    Some("ts") => _ = source_type.with_typescript(true),
+                    Some("tsx") => _ = source_type.with_typescript(true).with_jsx(true),
+                    Some(_) => return None,
+                }
+            }
+            // Astro processes scripts as TypeScript modules
+            Self::Astro => _ = source_type.with_module(true).with_typescript(true),
+            Self::Html => match tag.attribute("type") {
+                None | Some("text/javascript" | "application/javascript") => {}
+                Some("module") => _ = source_type.with_module(true),
+                Some(_) => return None,
+            },
+        }
+        Some(source_type)
+    }
+}
+
+/// Span of the content between the `---` fences at the start of an Astro file
+fn astro_frontmatter(source_text: &str) -> Option<Span> {
+    let start = source_text.len() - source_text.trim_start().len();
+    let rest = source_text[start..].strip_prefix("---")?;
+    let content_start = start + 3;
+    let content_end = content_start + rest.find("\n---").map(|i| i + 1)?;
+    #[allow(clippy::cast_possible_truncation)]
+    Some(Span::new(content_start as u32, content_end as u32))
+}
+
+struct ScriptTag<'a> {
+    attributes: Vec<(String, Option<&'a str>)>,
+    /// The content between `<script>` and `</script>`
+    span: Span,
+}
+
+impl<'a> ScriptTag<'a> {
+    fn attribute(&self, name: &str) -> Option<&'a str> {
+        self.attributes.iter().find(|(n, _)| n == name).map(|(_, value)| value.unwrap_or_default())
+    }
+}
+
+/// Iterator over the `<script>` tags of an HTML-like document, skipping `<!-- -->` comments
+struct ScriptTags<'a> {
+    source_text: &'a str,
+    pos: usize,
+}
+
+impl<'a> ScriptTags<'a> {
+    fn new(source_text: &'a str) -> Self {
+        Self { source_text, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source_text[self.pos..]
+    }
+
+    fn starts_with_ignore_case(&self, prefix: &str) -> bool {
+        self.rest()
+            .as_bytes()
+            .get(..prefix.len())
+            .map_or(false, |s| s.eq_ignore_ascii_case(prefix.as_bytes()))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Attributes of the opening tag, the position is after `<script`.
+    /// Returns `None` for a self-closing tag or at the end of file.
+    fn parse_attributes(&mut self) -> Option<Vec<(String, Option<&'a str>)>> {
+        let mut attributes = vec![];
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.is_empty() || rest.starts_with("/>") {
+                return None;
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                return Some(attributes);
+            }
+            let name_len = rest
+                .find(|c: char| c.is_ascii_whitespace() || matches!(c, '=' | '>' | '/'))
+                .unwrap_or(rest.len())
+                .max(1);
+            let name = rest[..name_len].to_ascii_lowercase();
+            self.pos += name_len;
+            self.skip_whitespace();
+            let value = if self.rest().starts_with('=') {
+                self.pos += 1;
+                self.skip_whitespace();
+                Some(self.parse_attribute_value())
+            } else {
+                None
+            };
+            attributes.push((name, value));
+        }
+    }
+
+    fn parse_attribute_value(&mut self) -> &'a str {
+        let rest = self.rest();
+        if let Some(quote @ ('"' | '\'')) = rest.chars().next() {
+            let len = rest[1..].find(quote).unwrap_or(rest.len() - 1);
+            self.pos = (self.pos + len + 2).min(self.source_text.len());
+            &rest[1..=len]
+        } else {
+            let len =
+                rest.find(|c: char| c.is_ascii_whitespace() || c == '>').unwrap_or(rest.len());
+            self.pos += len;
+            &rest[..len]
+        }
+    }
+}
+
+impl<'a> Iterator for ScriptTags<'a> {
+    type Item = ScriptTag<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.pos += self.rest().find('<')?;
+            if self.rest().starts_with("<!--") {
+                self.pos += self.rest().find("-->").map_or(self.rest().len(), |i| i + 3);
+                continue;
+            }
+            let at_script = self.starts_with_ignore_case("<script")
+                && self.rest()[7..]
+                    .starts_with(|c: char| c.is_ascii_whitespace() || matches!(c, '>' | '/'));
+            if !at_script {
+                self.pos += 1;
+                continue;
+            }
+            self.pos += 7;
+            let Some(attributes) = self.parse_attributes() else { continue };
+            let start = self.pos;
+            let end = loop {
+                let Some(i) = self.rest().find("</") else { break self.source_text.len() };
+                self.pos += i;
+                if self.starts_with_ignore_case("</script") {
+                    break self.pos;
+                }
+                self.pos += 2;
+            };
+            self.pos = end;
+            #[allow(clippy::cast_possible_truncation)]
+            let span = Span::new(start as u32, end as u32);
+            return Some(ScriptTag { attributes, span });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, PartialLoader};
+
+    fn parse<'a>(extension: &str, source_text: &'a str) -> Vec<JavaScriptSource<'a>> {
+        PartialLoader::parse(extension, source_text).unwrap()
+    }
+
+    #[test]
+    fn vue() {
+        let source_text = r#"
+<template><div>{{ a }}</div></template>
+<script lang="ts">
+export default { name: "A" }
+</script>
+<script setup lang='tsx'>
+const a: number = <div />
+</script>
+<SCRIPT lang="coffee">a = 1</SCRIPT>
+"#;
+        let sources = parse("vue", source_text);
+        assert_eq!(sources.len(), 2);
+        assert!(sources.iter().all(|s| s.source_type.is_module() && s.source_type.is_typescript()));
+        assert!(!sources[0].setup && !sources[0].source_type.is_jsx());
+        assert!(sources[1].setup && sources[1].source_type.is_jsx());
+        assert_eq!(sources[1].span.source_text(source_text), "\nconst a: number = <div />\n");
+        for source in &sources {
+            assert_eq!(source.source_text, source.span.source_text(source_text));
+        }
+    }
+
+    #[test]
+    fn svelte() {
+        let source_text = "<script context=\"module\">\nexport const a = 1;\n</script>\n<script>let b = 2;</script>\n<h1>{b}</h1>";
+        let sources = parse("svelte", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[1].span.source_text(source_text), "let b = 2;");
+    }
+
+    #[test]
+    fn astro() {
+        let source_text =
+            "---\nconst a: string = 'a';\n---\n<h1>{a}</h1>\n<script>\nconsole.log(1)\n</script>";
+        let sources = parse("astro", source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].span.source_text(source_text), "\nconst a: string = 'a';\n");
+        assert!(sources.iter().all(|s| s.source_type.is_typescript()));
+    }
+
+    #[test]
+    fn html() {
+        let source_text = r#"<html>
+<!-- <script>commented()</script> -->
+<script src="a.js"></script>
+<script>var a = 1;</script>
+<script type="module">import b from "b";</script>
+<script type="text/template"><div></div></script>
+<script/>
+</html>"#;
+        let sources = parse("html", source_text);
+        assert_eq!(sources.len(), 2);
+        assert!(sources[0].source_type.is_script());
+        assert_eq!(sources[0].span.source_text(source_text), "var a = 1;");
+        assert!(sources[1].source_type.is_module());
+
+        assert!(PartialLoader::parse("js", source_text).is_none());
+    }
+}
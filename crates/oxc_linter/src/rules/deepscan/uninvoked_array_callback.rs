@@ -50,7 +50,16 @@ impl Rule for UninvokedArrayCallback {
             && matches!(arg_expr, Expression::NumberLiteral(_))
             { new_expr } else { return };
 
-        let Some(member_expr_node) = ctx.parent_node(node) else { return };
+        let Some(member_node) = ctx.parent_node(node) else { return };
+        if !matches!(
+            member_node.get().kind(),
+            AstKind::StaticMemberExpression(_)
+                | AstKind::ComputedMemberExpression(_)
+                | AstKind::PrivateFieldExpression(_)
+        ) {
+            return;
+        }
+        let Some(member_expr_node) = ctx.parent_node(member_node) else { return };
 
         let AstKind::MemberExpression(member_expr) = member_expr_node.get().kind() else {
             return
@@ -456,7 +456,7 @@ fn check_module_declaration<'a>(
         ModuleKind::Script => {
             ctx.diagnostic(ModuleCode(text, span));
         }
-        ModuleKind::Module => {
+        ModuleKind::Module | ModuleKind::Unambiguous => {
             if matches!(ctx.parent_kind(node), AstKind::Program(_)) {
                 return;
             }
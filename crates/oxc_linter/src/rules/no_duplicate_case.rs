@@ -1,4 +1,4 @@
-use oxc_ast::{AstKind, GetSpan, Span};
+use oxc_ast::{AstKind, ContentEq, ContentOptions, GetSpan, Span};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -6,7 +6,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use rustc_hash::FxHashMap;
 
-use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::calculate_content_hash, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-duplicate-case): Disallow duplicate case labels")]
@@ -47,14 +47,18 @@ declare_oxc_lint!(
 impl Rule for NoDuplicateCase {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::SwitchStatement(ss) = node.get().kind() {
+            // `case (a):` is the same as `case a:`
+            let options = ContentOptions { ignore_parentheses: true };
             let mut map = FxHashMap::default();
             map.reserve(ss.cases.len());
             for case in ss.cases.iter() {
                 if let Some(test) = case.test.as_ref() {
-                    let hash = calculate_hash(test);
+                    let hash = calculate_content_hash(test, options);
 
-                    if let Some(prev_span) = map.insert(hash, test.span()) {
-                        ctx.diagnostic(NoDuplicateCaseDiagnostic(prev_span, test.span()));
+                    if let Some(prev) = map.insert(hash, test) {
+                        if prev.content_eq_with(test, options) {
+                            ctx.diagnostic(NoDuplicateCaseDiagnostic(prev.span(), test.span()));
+                        }
                     }
                 }
             }
@@ -166,6 +170,7 @@ fn test() {
             "var a = 1, f = function(s) { return { p1: s } }; switch (a) {case f(\na + 1 // comment\n).p1: break; case f(a+1)\n.p1: break; default: break;}",
             None,
         ),
+        ("var a = 1; switch (a) {case (a): break; case a: break; default: break;}", None),
     ];
 
     Tester::new(NoDuplicateCase::NAME, pass, fail).test_and_snapshot();
@@ -49,20 +49,15 @@ impl Rule for NoEmpty {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.get().kind() {
             AstKind::BlockStatement(block) if block.body.is_empty() => {
-                if ctx.semantic().trivias().has_comments_between(block.span) {
+                if self.allow_empty_catch
+                    && matches!(ctx.parent_kind(node), AstKind::CatchClause(_))
+                {
                     return;
                 }
-                ctx.diagnostic(NoEmptyDiagnostic("block", block.span));
-            }
-            // The visitor does not visit the `BlockStatement` inside the `CatchClause`.
-            // See `Visit::visit_catch_clause`.
-            AstKind::CatchClause(catch_clause)
-                if !self.allow_empty_catch && catch_clause.body.body.is_empty() =>
-            {
-                if ctx.semantic().trivias().has_comments_between(catch_clause.body.span) {
+                if ctx.semantic().trivias().has_comments_between(block.span) {
                     return;
                 }
-                ctx.diagnostic(NoEmptyDiagnostic("block", catch_clause.body.span));
+                ctx.diagnostic(NoEmptyDiagnostic("block", block.span));
             }
             AstKind::SwitchStatement(switch) if switch.cases.is_empty() => {
                 ctx.diagnostic(NoEmptyDiagnostic("switch", switch.span));
@@ -1,11 +1,11 @@
-use oxc_ast::{AstKind, GetSpan, Span};
+use oxc_ast::{AstKind, ContentEq, ContentOptions, GetSpan, Span};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
 
-use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-self-compare): Disallow comparisons where both sides are exactly the same")]
@@ -45,10 +45,9 @@ impl Rule for NoSelfCompare {
         if !binary_expr.operator.is_compare() && !binary_expr.operator.is_equality() {
             return;
         }
-        let left = calculate_hash(&binary_expr.left);
-        let right = calculate_hash(&binary_expr.right);
-
-        if left == right {
+        // `(x) === x` compares `x` to itself too
+        let options = ContentOptions { ignore_parentheses: true };
+        if binary_expr.left.content_eq_with(&binary_expr.right, options) {
             ctx.diagnostic(NoSelfCompareDiagnostic(
                 binary_expr.left.span(),
                 binary_expr.right.span(),
@@ -86,6 +85,7 @@ fn test() {
         ("x <= x", None),
         ("foo.bar().baz.qux >= foo.bar ().baz .qux", None),
         ("class C { #field; foo() { this.#field === this.#field; } }", None),
+        ("(x) === x", None),
     ];
 
     Tester::new(NoSelfCompare::NAME, pass, fail).test_and_snapshot();
@@ -1,8 +1,11 @@
-use std::{path::PathBuf, rc::Rc};
+use std::{path::PathBuf, rc::Rc, sync::Arc};
 
 use oxc_allocator::Allocator;
 use oxc_ast::SourceType;
-use oxc_diagnostics::miette::{GraphicalReportHandler, GraphicalTheme, NamedSource};
+use oxc_diagnostics::{
+    miette::{GraphicalReportHandler, GraphicalTheme},
+    IndexedSource,
+};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use serde_json::Value;
@@ -90,12 +93,9 @@ impl Tester {
             return true;
         }
         let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+        let source = Arc::new(IndexedSource::new(path.to_string_lossy(), source_text.to_string()));
         for diagnostic in result {
-            let diagnostic = diagnostic.error.with_source_code(source_text.to_string());
-            let diagnostic = diagnostic.with_source_code(NamedSource::new(
-                path.to_string_lossy(),
-                source_text.to_string(),
-            ));
+            let diagnostic = diagnostic.error.with_source_code(Arc::clone(&source));
             handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
         }
         false
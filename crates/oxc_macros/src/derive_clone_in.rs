@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, GenericParam, Result};
+
+/// Construct `path` from the clones of `fields`, which are bound to `bindings`
+fn clone_fields(path: &TokenStream, fields: &Fields, bindings: &[syn::Ident]) -> TokenStream {
+    let clones = bindings.iter().map(|binding| {
+        quote! { crate::CloneIn::clone_in(#binding, allocator) }
+    });
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote! { #path { #(#names: #clones),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path ( #(#clones),* ) },
+        Fields::Unit => quote! { #path },
+    }
+}
+
+/// Destructure `fields` of `path` into `bindings`
+fn bind_fields(path: &TokenStream, fields: &Fields) -> (TokenStream, Vec<syn::Ident>) {
+    let bindings: Vec<_> = (0..fields.len()).map(|i| format_ident!("field_{i}")).collect();
+    let pattern = match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote! { #path { #(#names: #bindings),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path ( #(#bindings),* ) },
+        Fields::Unit => quote! { #path },
+    };
+    (pattern, bindings)
+}
+
+pub fn derive_clone_in(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let mut lifetimes = 0;
+    for param in &input.generics.params {
+        match param {
+            GenericParam::Lifetime(_) => lifetimes += 1,
+            _ => {
+                return Err(Error::new_spanned(
+                    param,
+                    "`CloneIn` only supports a lifetime parameter",
+                ));
+            }
+        }
+    }
+    // The lifetime of the allocator is replaced with the lifetime of the new allocator
+    let (impl_generics, ty, cloned) = match lifetimes {
+        0 => (quote! { <'alloc> }, quote! { #name }, quote! { #name }),
+        1 => (
+            quote! { <'old_alloc, 'new_alloc> },
+            quote! { #name<'old_alloc> },
+            quote! { #name<'new_alloc> },
+        ),
+        _ => {
+            return Err(Error::new_spanned(
+                &input.generics,
+                "`CloneIn` only supports one lifetime parameter",
+            ));
+        }
+    };
+    let alloc = if lifetimes == 0 {
+        quote! { 'alloc }
+    } else {
+        quote! { 'new_alloc }
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, bindings) = bind_fields(&quote! { #name }, &data.fields);
+            let clone = clone_fields(&quote! { #name }, &data.fields, &bindings);
+            quote! {
+                let #pattern = self;
+                #clone
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let path = quote! { #name::#ident };
+                let (pattern, bindings) = bind_fields(&path, &variant.fields);
+                let clone = clone_fields(&path, &variant.fields, &bindings);
+                quote! { #pattern => #clone }
+            });
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(&input.ident, "`CloneIn` does not support unions"));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics crate::CloneIn<#alloc> for #ty {
+            type Cloned = #cloned;
+
+            #[allow(unused_variables)]
+            fn clone_in(&self, allocator: &#alloc oxc_allocator::Allocator) -> Self::Cloned {
+                #body
+            }
+        }
+    })
+}
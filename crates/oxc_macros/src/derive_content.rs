@@ -0,0 +1,170 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, Meta, NestedMeta, Result};
+
+/// Whether `attrs` contain `#[content(name)]`
+fn has_content_attr(attrs: &[Attribute], name: &str) -> Result<bool> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("content")) {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(syn::Error::new_spanned(attr, "expected `#[content(...)]`"));
+        };
+        for nested in &list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path))
+                    if path.is_ident("skip") || path.is_ident("skip_parentheses") =>
+                {
+                    if path.is_ident(name) {
+                        return Ok(true);
+                    }
+                }
+                _ => return Err(syn::Error::new_spanned(nested, "unknown `content` attribute")),
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Destructure `fields` of `path`, binding each field to `{prefix}_{index}`
+fn bind_fields(path: &TokenStream, fields: &Fields, prefix: &str) -> (TokenStream, Vec<Ident>) {
+    let bindings: Vec<_> = (0..fields.len()).map(|i| format_ident!("{prefix}_{i}")).collect();
+    let pattern = match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote! { #path { #(#names: #bindings),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path ( #(#bindings),* ) },
+        Fields::Unit => quote! { #path },
+    };
+    (pattern, bindings)
+}
+
+/// Indices of the fields which are compared, all fields without `#[content(skip)]`
+fn content_fields(fields: &Fields) -> Result<Vec<usize>> {
+    let mut content = vec![];
+    for (i, field) in fields.iter().enumerate() {
+        if !has_content_attr(&field.attrs, "skip")? {
+            content.push(i);
+        }
+    }
+    Ok(content)
+}
+
+/// Unwrap parenthesized expressions first with `#[content(skip_parentheses)]`
+fn unwrap_parentheses(input: &DeriveInput, values: &[TokenStream]) -> Result<TokenStream> {
+    if !has_content_attr(&input.attrs, "skip_parentheses")? {
+        return Ok(quote! {});
+    }
+    Ok(quote! {
+        #(
+            let #values = if options.ignore_parentheses { #values.without_parenthesized() } else { #values };
+        )*
+    })
+}
+
+pub fn derive_content_eq(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let unwrap = unwrap_parentheses(input, &[quote! { this }, quote! { other }])?;
+
+    let compare = |path: &TokenStream, fields: &Fields| -> Result<TokenStream> {
+        let (this_pattern, this_bindings) = bind_fields(path, fields, "this");
+        let (other_pattern, other_bindings) = bind_fields(path, fields, "other");
+        let comparisons = content_fields(fields)?.into_iter().map(|i| {
+            let (this, other) = (&this_bindings[i], &other_bindings[i]);
+            quote! { && crate::ContentEq::content_eq_with(#this, #other, options) }
+        });
+        Ok(quote! { (#this_pattern, #other_pattern) => true #(#comparisons)* })
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let arm = compare(&quote! { #name }, &data.fields)?;
+            quote! { match (this, other) { #arm } }
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    compare(&quote! { #name::#ident }, &variant.fields)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let fallback = (data.variants.len() > 1).then(|| quote! { _ => false, });
+            quote! {
+                match (this, other) {
+                    #(#arms,)*
+                    #fallback
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(name, "`ContentEq` does not support unions"));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics crate::ContentEq for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn content_eq_with(&self, other: &Self, options: crate::ContentOptions) -> bool {
+                let this = self;
+                #unwrap
+                #body
+            }
+        }
+    })
+}
+
+pub fn derive_content_hash(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let unwrap = unwrap_parentheses(input, &[quote! { this }])?;
+
+    let hash = |path: &TokenStream, fields: &Fields| -> Result<TokenStream> {
+        let (pattern, bindings) = bind_fields(path, fields, "this");
+        let hashes = content_fields(fields)?.into_iter().map(|i| {
+            let this = &bindings[i];
+            quote! { crate::ContentHash::content_hash_with(#this, state, options); }
+        });
+        Ok(quote! { #pattern => { #(#hashes)* } })
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let arm = hash(&quote! { #name }, &data.fields)?;
+            quote! { match this { #arm } }
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    hash(&quote! { #name::#ident }, &variant.fields)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                std::hash::Hash::hash(&std::mem::discriminant(this), state);
+                match this { #(#arms)* }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(name, "`ContentHash` does not support unions"));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics crate::ContentHash for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn content_hash_with<H: std::hash::Hasher>(
+                &self,
+                state: &mut H,
+                options: crate::ContentOptions,
+            ) {
+                let this = self;
+                #unwrap
+                #body
+            }
+        }
+    })
+}
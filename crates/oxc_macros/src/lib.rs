@@ -1,60 +1,96 @@
-use syn::parse_macro_input;
-
-mod declare_all_lint_rules;
-mod declare_oxc_lint;
-
-/// Macro used to declare an oxc lint rule
-///
-/// Every lint declaration consists of 2 parts:
-///
-/// 1. The documentation
-/// 2. The lint's struct
-///
-/// # Example
-///
-/// ```
-/// use oxc_macros::declare_oxc_lint;
-///
-/// declare_oxc_lint! {
-///     /// ### What it does
-///     /// Checks for usage of the `debugger` statement
-///     ///
-///     /// ### Why is this bad?
-///     /// `debugger` statements do not affect functionality when a debugger isn't attached.
-///     /// They're most commonly an accidental debugging leftover.
-///     ///
-///     ///
-///     /// ### Example
-///     /// ```javascript
-///     /// const data = await getData();
-///     /// const result = complexCalculation(data);
-///     /// debugger;
-///     /// ```
-///     ///
-///     /// ```
-///     pub struct NoDebugger
-/// }
-/// ```
-#[proc_macro]
-pub fn declare_oxc_lint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let metadata = parse_macro_input!(input as declare_oxc_lint::LintRuleMeta);
-
-    declare_oxc_lint::declare_oxc_lint(metadata).into()
-}
-
-/// Same as `declare_oxc_lint`, but doesn't do imports.
-/// Enables multiple usages in a single file.
-#[proc_macro]
-pub fn declare_oxc_lint_test(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let mut metadata = parse_macro_input!(input as declare_oxc_lint::LintRuleMeta);
-    metadata.used_in_test = true;
-
-    declare_oxc_lint::declare_oxc_lint(metadata).into()
-}
-
-#[proc_macro]
-pub fn declare_all_lint_rules(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let metadata = parse_macro_input!(input as declare_all_lint_rules::AllLintRulesMeta);
-
-    declare_all_lint_rules::declare_all_lint_rules(metadata).into()
-}
+use syn::{parse_macro_input, DeriveInput};
+
+mod declare_all_lint_rules;
+mod declare_oxc_lint;
+mod derive_clone_in;
+mod derive_content;
+
+/// Macro used to declare an oxc lint rule
+///
+/// Every lint declaration consists of 2 parts:
+///
+/// 1. The documentation
+/// 2. The lint's struct
+///
+/// # Example
+///
+/// ```
+/// use oxc_macros::declare_oxc_lint;
+///
+/// declare_oxc_lint! {
+///     /// ### What it does
+///     /// Checks for usage of the `debugger` statement
+///     ///
+///     /// ### Why is this bad?
+///     /// `debugger` statements do not affect functionality when a debugger isn't attached.
+///     /// They're most commonly an accidental debugging leftover.
+///     ///
+///     ///
+///     /// ### Example
+///     /// ```javascript
+///     /// const data = await getData();
+///     /// const result = complexCalculation(data);
+///     /// debugger;
+///     /// ```
+///     ///
+///     /// ```
+///     pub struct NoDebugger
+/// }
+/// ```
+#[proc_macro]
+pub fn declare_oxc_lint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let metadata = parse_macro_input!(input as declare_oxc_lint::LintRuleMeta);
+
+    declare_oxc_lint::declare_oxc_lint(metadata).into()
+}
+
+/// Same as `declare_oxc_lint`, but doesn't do imports.
+/// Enables multiple usages in a single file.
+#[proc_macro]
+pub fn declare_oxc_lint_test(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut metadata = parse_macro_input!(input as declare_oxc_lint::LintRuleMeta);
+    metadata.used_in_test = true;
+
+    declare_oxc_lint::declare_oxc_lint(metadata).into()
+}
+
+#[proc_macro]
+pub fn declare_all_lint_rules(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let metadata = parse_macro_input!(input as declare_all_lint_rules::AllLintRulesMeta);
+
+    declare_all_lint_rules::declare_all_lint_rules(metadata).into()
+}
+
+/// Derive `CloneIn` for an AST node in `oxc_ast`,
+/// which clones every field with `CloneIn::clone_in`.
+///
+/// The type can have at most one lifetime parameter, the lifetime of the allocator.
+#[proc_macro_derive(CloneIn)]
+pub fn derive_clone_in(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive_clone_in::derive_clone_in(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derive `ContentEq` for an AST node in `oxc_ast`,
+/// which compares every field with `ContentEq::content_eq_with`.
+///
+/// Fields marked with `#[content(skip)]` are not compared,
+/// and `#[content(skip_parentheses)]` on an expression type unwraps parenthesized expressions
+/// before comparing when `ContentOptions::ignore_parentheses` is set.
+#[proc_macro_derive(ContentEq, attributes(content))]
+pub fn derive_content_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive_content::derive_content_eq(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derive `ContentHash` for an AST node in `oxc_ast`, consistent with `ContentEq`.
+#[proc_macro_derive(ContentHash, attributes(content))]
+pub fn derive_content_hash(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive_content::derive_content_hash(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -30,10 +30,10 @@ fn main() {
     let program = allocator.alloc(ret.program);
 
     let minifier_options = MinifierOptions::default();
-    Minifier::new(&allocator, minifier_options).build(program);
+    Minifier::new(&allocator, minifier_options).build(program).unwrap();
 
     let printer_options = PrinterOptions::default();
-    let printed = Printer::new(source_text.len(), printer_options).build(program);
+    let printed = Printer::new(source_text.len(), printer_options).build(program).unwrap();
 
     println!("{printed}");
 }
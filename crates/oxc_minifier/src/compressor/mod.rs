@@ -1,6 +1,9 @@
 use oxc_allocator::{Allocator, Vec};
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, visit_mut::VisitMut, AstBuilder, Span};
+use oxc_ast::{
+    ast::*, constant_evaluation::number_to_string, visit_mut::VisitMut, AstBuilder, Atom,
+    ConstantEvaluation, ConstantValue, GetSpan, SideEffects, Span,
+};
 
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, Copy)]
@@ -9,10 +12,18 @@ pub struct CompressOptions {
     /// Default true
     pub booleans: bool,
 
+    /// Remove branches which are never taken, for example `if (false) a; else b;` → `b;`
+    /// Default true
+    pub dead_code: bool,
+
     /// Remove `debugger;` statements
     /// Default true
     pub drop_debugger: bool,
 
+    /// Fold constant expressions, for example `1 + 2` → `3`
+    /// Default true
+    pub evaluate: bool,
+
     /// Join consecutive var statements
     /// Default true
     pub join_vars: bool,
@@ -21,6 +32,10 @@ pub struct CompressOptions {
     /// Default: true
     pub loops: bool,
 
+    /// Drop expression statements without side effects, for example `1 + 2;` or `(function () {});`
+    /// Default true
+    pub side_effects: bool,
+
     /// Transforms `typeof foo == "undefined" into `foo === void 0`
     /// Default true
     pub typeofs: bool,
@@ -28,23 +43,38 @@ pub struct CompressOptions {
 
 impl Default for CompressOptions {
     fn default() -> Self {
-        Self { booleans: true, drop_debugger: true, join_vars: true, loops: true, typeofs: true }
+        Self {
+            booleans: true,
+            dead_code: true,
+            drop_debugger: true,
+            evaluate: true,
+            join_vars: true,
+            loops: true,
+            side_effects: true,
+            typeofs: true,
+        }
     }
 }
 
 pub struct Compressor<'a> {
     ast: AstBuilder<'a>,
     options: CompressOptions,
+    shadowed_globals: ShadowedGlobals,
 }
 
 const SPAN: Span = Span::new(0, 0);
 
 impl<'a> Compressor<'a> {
     pub fn new(allocator: &'a Allocator, options: CompressOptions) -> Self {
-        Self { ast: AstBuilder::new(allocator), options }
+        Self {
+            ast: AstBuilder::new(allocator),
+            options,
+            shadowed_globals: ShadowedGlobals::default(),
+        }
     }
 
     pub fn build<'b>(mut self, program: &'b mut Program<'a>) {
+        self.shadowed_globals.visit_program(program);
         self.visit_program(program);
     }
 
@@ -60,6 +90,45 @@ impl<'a> Compressor<'a> {
         self.ast.unary_expression(SPAN, UnaryOperator::Void, true, num)
     }
 
+    /// Expression for `value`, `None` for `NaN` and `Infinity` which are not shorter as expressions
+    fn create_constant(&self, value: ConstantValue) -> Option<Expression<'a>> {
+        let expr = match value {
+            ConstantValue::Number(n) if !n.is_finite() => return None,
+            ConstantValue::Number(n) => {
+                let raw = self.ast.new_str(&number_to_string(n.abs()));
+                let num = self.ast.literal_number_expression(NumberLiteral::new(
+                    SPAN,
+                    n.abs(),
+                    raw,
+                    NumberBase::Decimal,
+                ));
+                if n.is_sign_negative() {
+                    self.ast.unary_expression(SPAN, UnaryOperator::UnaryNegation, true, num)
+                } else {
+                    num
+                }
+            }
+            ConstantValue::BigInt(n) => {
+                let literal = BigintLiteral { span: SPAN, value: n.magnitude().clone() };
+                let num = self.ast.literal_bigint_expression(literal);
+                if n.sign() == num_bigint::Sign::Minus {
+                    self.ast.unary_expression(SPAN, UnaryOperator::UnaryNegation, true, num)
+                } else {
+                    num
+                }
+            }
+            ConstantValue::String(value) => {
+                self.ast.literal_string_expression(StringLiteral { span: SPAN, value })
+            }
+            ConstantValue::Boolean(value) => {
+                self.ast.literal_boolean_expression(BooleanLiteral { span: SPAN, value })
+            }
+            ConstantValue::Null => self.ast.literal_null_expression(NullLiteral { span: SPAN }),
+            ConstantValue::Undefined => self.create_void_0(),
+        };
+        Some(expr)
+    }
+
     /* Statements */
 
     #[allow(clippy::unused_self)]
@@ -73,6 +142,15 @@ impl<'a> Compressor<'a> {
         matches!(stmt, Statement::DebuggerStatement(_)) && self.options.drop_debugger
     }
 
+    /// Drop expression statements which have no side effects and do not throw.
+    /// Enabled by `compress.side_effects`
+    fn drop_unused_expression<'b>(&mut self, stmt: &'b Statement<'a>) -> bool {
+        matches!(stmt, Statement::ExpressionStatement(stmt)
+            if self.options.side_effects
+                && !self.expression_has_side_effects(&stmt.expression)
+                && !self.expression_may_throw(&stmt.expression))
+    }
+
     /// Join consecutive var statements
     fn join_vars<'b>(&mut self, stmts: &'b mut Vec<'a, Statement<'a>>) {
         // Collect all the consecutive ranges that contain joinable vars.
@@ -130,11 +208,118 @@ impl<'a> Compressor<'a> {
         }
     }
 
+    /// Transforms `if (true) a; else b;` to `a;` and `if (false) a;` to `;`
+    /// Enabled by `compress.dead_code`
+    fn remove_dead_branch<'b>(&mut self, stmt: &'b mut Statement<'a>) {
+        if let Statement::IfStatement(if_stmt) = stmt
+            && self.options.dead_code
+            && let Some(test) = self.eval_expression(&if_stmt.test) {
+            let test = test.to_boolean();
+            let dead = if test { if_stmt.alternate.as_ref() } else { Some(&if_stmt.consequent) };
+            if dead.map_or(false, has_hoisted_declaration) {
+                return;
+            }
+            let taken = if test {
+                let dummy = self.ast.empty_statement(SPAN);
+                Some(std::mem::replace(&mut if_stmt.consequent, dummy))
+            } else {
+                if_stmt.alternate.take()
+            };
+            *stmt = taken.unwrap_or_else(|| self.ast.empty_statement(SPAN));
+        }
+    }
+
     /* Expressions */
 
+    /// Transforms constant expressions to their value, e.g. `1 + 2` => `3`
+    /// Enabled by `compress.evaluate`
+    fn fold_constant<'b>(&mut self, expr: &'b mut Expression<'a>) -> bool {
+        if !self.options.evaluate || is_constant_literal(expr) {
+            return false;
+        }
+        let Some(value) = self.eval_expression(expr) else { return false };
+        self.fold_value(expr, value)
+    }
+
+    /// Transforms `expr` to its constant `value`
+    fn fold_value<'b>(&mut self, expr: &'b mut Expression<'a>, value: ConstantValue) -> bool {
+        // Only fold when the value is not longer than the expression
+        let len = match &value {
+            ConstantValue::String(s) => {
+                s.chars().map(|c| c.escape_default().len()).sum::<usize>() + 2
+            }
+            ConstantValue::BigInt(n) => n.to_string().len() + 1,
+            // `!0` and `!1`
+            ConstantValue::Boolean(_) if self.options.booleans => 2,
+            ConstantValue::Undefined => "void 0".len(),
+            value => value.to_js_string().len(),
+        };
+        if len > expr.span().len() as usize {
+            return false;
+        }
+        if let Some(folded) = self.create_constant(value) {
+            *expr = folded;
+            return true;
+        }
+        false
+    }
+
+    /// Transforms `true ? a : b` => `a` and `1 && a` => `a`
+    /// Enabled by `compress.dead_code`
+    fn remove_dead_expression_branch<'b>(&mut self, expr: &'b mut Expression<'a>) -> bool {
+        if !self.options.dead_code {
+            return false;
+        }
+        match expr {
+            Expression::ConditionalExpression(cond_expr) => {
+                let Some(test) = self.eval_expression(&cond_expr.test) else { return false };
+                let taken = if test.to_boolean() {
+                    &mut cond_expr.consequent
+                } else {
+                    &mut cond_expr.alternate
+                };
+                if !can_replace_parent(taken) {
+                    return false;
+                }
+                let dummy = self.ast.this_expression(SPAN);
+                *expr = std::mem::replace(taken, dummy);
+                true
+            }
+            Expression::LogicalExpression(logical_expr) => {
+                let Some(left) = self.eval_expression(&logical_expr.left) else { return false };
+                self.remove_dead_logical_branch(expr, &left).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// Transforms `1 && a` => `a` for the logical expression `expr` whose left operand has the value `left`,
+    /// returns whether the left operand was taken when the branch is removed
+    fn remove_dead_logical_branch<'b>(
+        &mut self,
+        expr: &'b mut Expression<'a>,
+        left: &ConstantValue,
+    ) -> Option<bool> {
+        let Expression::LogicalExpression(logical_expr) = expr else { return None };
+        let short_circuit = match logical_expr.operator {
+            LogicalOperator::And => !left.to_boolean(),
+            LogicalOperator::Or => left.to_boolean(),
+            LogicalOperator::Coalesce => {
+                !matches!(left, ConstantValue::Null | ConstantValue::Undefined)
+            }
+        };
+        let taken = if short_circuit { &mut logical_expr.left } else { &mut logical_expr.right };
+        if !can_replace_parent(taken) {
+            return None;
+        }
+        let dummy = self.ast.this_expression(SPAN);
+        *expr = std::mem::replace(taken, dummy);
+        Some(short_circuit)
+    }
+
     /// Transforms `undefined` => `void 0`
     fn compress_undefined<'b>(&mut self, expr: &'b mut Expression<'a>) -> bool {
-        if expr.is_undefined() {
+        if expr.is_undefined() && !self.shadowed_globals.contains("undefined") {
             *expr = self.create_void_0();
             return true;
         }
@@ -176,6 +361,220 @@ impl<'a> Compressor<'a> {
         }
         false
     }
+
+    /// Visits the chain of binary and logical expressions `a + b || c`, which is nested as
+    /// `(a + b) || c`, in a loop with the same transforms as `visit_expression`,
+    /// long chains such as generated string concatenations would overflow the stack in a recursion.
+    ///
+    /// The values of the expressions in the chain are evaluated once from the leftmost operand,
+    /// the expressions are transformed from the outermost one and the transforms do not change
+    /// the left operands.
+    fn visit_binary_chain<'b>(&mut self, expr: &'b mut Expression<'a>) {
+        let values = self.eval_binary_chain(expr);
+        let mut rights = vec![];
+        let mut expr = expr;
+        let mut depth = 0;
+        loop {
+            if !matches!(expr, Expression::BinaryExpression(_) | Expression::LogicalExpression(_)) {
+                self.visit_expression(expr);
+                break;
+            }
+            if self.options.evaluate
+                && let Some(value) = values[depth].clone()
+                && self.fold_value(expr, value)
+            {
+                self.compress_boolean(expr);
+                break;
+            }
+            if self.options.dead_code
+                && let Some(left) = &values[depth + 1]
+                && let Some(short_circuit) = self.remove_dead_logical_branch(expr, left)
+            {
+                if short_circuit {
+                    // The left operand took the place of the expression
+                    depth += 1;
+                    continue;
+                }
+                self.visit_expression(expr);
+                break;
+            }
+            match expr {
+                Expression::BinaryExpression(binary_expr) => {
+                    if self.compress_typeof_undefined(binary_expr) {
+                        break;
+                    }
+                    let binary_expr = &mut **binary_expr;
+                    rights.push(&mut binary_expr.right);
+                    expr = &mut binary_expr.left;
+                }
+                Expression::LogicalExpression(logical_expr) => {
+                    let logical_expr = &mut **logical_expr;
+                    rights.push(&mut logical_expr.right);
+                    expr = &mut logical_expr.left;
+                }
+                _ => unreachable!(),
+            }
+            depth += 1;
+        }
+        for right in rights.into_iter().rev() {
+            self.visit_expression(right);
+        }
+    }
+
+    /// Values of the expressions in the chain `expr` from the outermost one to the leftmost operand,
+    /// all `None` when neither `compress.evaluate` nor `compress.dead_code` is enabled
+    fn eval_binary_chain(&self, expr: &Expression<'a>) -> std::vec::Vec<Option<ConstantValue>> {
+        let mut chain = vec![];
+        let mut left = expr;
+        loop {
+            match left {
+                Expression::BinaryExpression(binary_expr) => {
+                    chain.push(left);
+                    left = &binary_expr.left;
+                }
+                Expression::LogicalExpression(logical_expr) => {
+                    chain.push(left);
+                    left = &logical_expr.left;
+                }
+                _ => break,
+            }
+        }
+        if !self.options.evaluate && !self.options.dead_code {
+            return vec![None; chain.len() + 1];
+        }
+        let mut value = self.eval_expression(left);
+        let mut values = vec![value.clone()];
+        for expr in chain.into_iter().rev() {
+            value = value.and_then(|left| self.eval_with_left(expr, left));
+            values.push(value.clone());
+        }
+        values.reverse();
+        values
+    }
+}
+
+/// Resolves the globals `undefined`, `NaN` and `Infinity` unless they are declared in the program.
+impl<'a> ConstantEvaluation<'a> for Compressor<'a> {
+    fn resolve_binding(&self, ident: &IdentifierReference) -> Option<ConstantValue> {
+        if self.shadowed_globals.contains(&ident.name) {
+            return None;
+        }
+        ConstantValue::global(&ident.name)
+    }
+}
+
+impl<'a> SideEffects<'a> for Compressor<'a> {
+    fn is_known_global(&self, name: &str) -> bool {
+        !self.shadowed_globals.contains(name) && matches!(name, "undefined" | "NaN" | "Infinity")
+    }
+}
+
+/// The globals `undefined`, `NaN` and `Infinity` which may be shadowed somewhere in the program.
+///
+/// Scopes are not resolved, so a declaration anywhere in the program shadows the global everywhere,
+/// and a `with` statement shadows all of them.
+#[derive(Debug, Default)]
+struct ShadowedGlobals {
+    names: std::vec::Vec<Atom>,
+    with_statement: bool,
+}
+
+impl ShadowedGlobals {
+    fn contains(&self, name: &str) -> bool {
+        self.with_statement || self.names.iter().any(|shadowed| shadowed.as_str() == name)
+    }
+}
+
+impl<'a, 'b> VisitMut<'a, 'b> for ShadowedGlobals {
+    fn visit_expression(&mut self, expr: &'b mut Expression<'a>) {
+        crate::walk_binary_chain(self, expr);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &'b mut BindingIdentifier) {
+        if matches!(ident.name.as_str(), "undefined" | "NaN" | "Infinity")
+            && !self.names.contains(&ident.name)
+        {
+            self.names.push(ident.name.clone());
+        }
+    }
+
+    fn visit_with_statement(&mut self, stmt: &'b mut WithStatement<'a>) {
+        self.with_statement = true;
+        self.visit_expression(&mut stmt.object);
+        self.visit_statement(&mut stmt.body);
+    }
+}
+
+/// Whether `expr` is already as short as its value, e.g. `1`, `-1` and `void 0`
+/// Whether the branch `taken` of a conditional or logical expression can replace the expression,
+/// `(true ? a.b : c)()` calls `b` with `this` undefined, and `(true ? eval : f)(s)` is an indirect eval
+fn can_replace_parent(taken: &Expression) -> bool {
+    !matches!(
+        taken.without_parenthesized(),
+        Expression::MemberExpression(_) | Expression::ChainExpression(_)
+    ) && !taken.without_parenthesized().is_specific_id("eval")
+}
+
+fn is_constant_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::NumberLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::Identifier(_) => true,
+        Expression::UnaryExpression(unary_expr) => matches!(
+            unary_expr.argument,
+            Expression::NumberLiteral(_) | Expression::BigintLiteral(_)
+        ),
+        _ => false,
+    }
+}
+
+/// Whether `stmt` contains a `var` or function declaration which is hoisted out of it,
+/// so `stmt` can not be removed
+fn has_hoisted_declaration(stmt: &Statement) -> bool {
+    let is_var = |decl: &VariableDeclaration| decl.kind == VariableDeclarationKind::Var;
+    match stmt {
+        Statement::Declaration(Declaration::VariableDeclaration(decl)) => is_var(decl),
+        Statement::Declaration(Declaration::FunctionDeclaration(_)) => true,
+        Statement::BlockStatement(block) => block.body.iter().any(has_hoisted_declaration),
+        Statement::IfStatement(stmt) => {
+            has_hoisted_declaration(&stmt.consequent)
+                || stmt.alternate.as_ref().map_or(false, has_hoisted_declaration)
+        }
+        Statement::ForStatement(stmt) => {
+            matches!(&stmt.init, Some(ForStatementInit::VariableDeclaration(decl)) if is_var(decl))
+                || has_hoisted_declaration(&stmt.body)
+        }
+        Statement::ForInStatement(stmt) => {
+            matches!(&stmt.left, ForStatementLeft::VariableDeclaration(decl) if is_var(decl))
+                || has_hoisted_declaration(&stmt.body)
+        }
+        Statement::ForOfStatement(stmt) => {
+            matches!(&stmt.left, ForStatementLeft::VariableDeclaration(decl) if is_var(decl))
+                || has_hoisted_declaration(&stmt.body)
+        }
+        Statement::WhileStatement(stmt) => has_hoisted_declaration(&stmt.body),
+        Statement::DoWhileStatement(stmt) => has_hoisted_declaration(&stmt.body),
+        Statement::LabeledStatement(stmt) => has_hoisted_declaration(&stmt.body),
+        Statement::WithStatement(stmt) => has_hoisted_declaration(&stmt.body),
+        Statement::TryStatement(stmt) => {
+            stmt.block.body.iter().any(has_hoisted_declaration)
+                || stmt
+                    .handler
+                    .as_ref()
+                    .map_or(false, |handler| handler.body.body.iter().any(has_hoisted_declaration))
+                || stmt
+                    .finalizer
+                    .as_ref()
+                    .map_or(false, |finalizer| finalizer.body.iter().any(has_hoisted_declaration))
+        }
+        Statement::SwitchStatement(stmt) => {
+            stmt.cases.iter().any(|case| case.consequent.iter().any(has_hoisted_declaration))
+        }
+        _ => false,
+    }
 }
 
 impl<'a, 'b> VisitMut<'a, 'b> for Compressor<'a> {
@@ -187,14 +586,30 @@ impl<'a, 'b> VisitMut<'a, 'b> for Compressor<'a> {
         for stmt in stmts.iter_mut() {
             self.visit_statement(stmt);
         }
+
+        // Removed dead branches, and expressions which became constants
+        stmts.retain(|stmt| !self.drop_empty(stmt) && !self.drop_unused_expression(stmt));
     }
 
     fn visit_statement(&mut self, stmt: &'b mut Statement<'a>) {
+        self.remove_dead_branch(stmt);
         self.compress_while(stmt);
         self.visit_statement_match(stmt);
     }
 
     fn visit_expression(&mut self, expr: &'b mut Expression<'a>) {
+        if matches!(expr, Expression::BinaryExpression(_) | Expression::LogicalExpression(_)) {
+            self.visit_binary_chain(expr);
+            return;
+        }
+        if self.fold_constant(expr) {
+            self.compress_boolean(expr);
+            return;
+        }
+        if self.remove_dead_expression_branch(expr) {
+            self.visit_expression(expr);
+            return;
+        }
         if self.compress_undefined(expr) {
             return;
         }
@@ -204,6 +619,20 @@ impl<'a, 'b> VisitMut<'a, 'b> for Compressor<'a> {
         self.visit_expression_match(expr);
     }
 
+    fn visit_unary_expression(&mut self, expr: &'b mut UnaryExpression<'a>) {
+        // The operand of `delete` is kept as is, `delete (true ? a : b)` must not become `delete a`,
+        // which deletes a global variable in sloppy mode and is a SyntaxError in strict mode code.
+        if expr.operator == UnaryOperator::Delete {
+            let mut argument = &mut expr.argument;
+            while let Expression::ParenthesizedExpression(paren_expr) = argument {
+                argument = &mut paren_expr.expression;
+            }
+            self.visit_expression_match(argument);
+            return;
+        }
+        self.visit_expression(&mut expr.argument);
+    }
+
     fn visit_binary_expression(&mut self, expr: &'b mut BinaryExpression<'a>) {
         if self.compress_typeof_undefined(expr) {
             return;
@@ -5,7 +5,12 @@
 mod compressor;
 
 use oxc_allocator::Allocator;
-use oxc_ast::ast::Program;
+use oxc_ast::{
+    ast::{Expression, InvalidExpression, InvalidStatement, Program},
+    visit_mut::VisitMut,
+    Span,
+};
+use oxc_diagnostics::InvalidNode;
 
 pub use crate::compressor::CompressOptions;
 use crate::compressor::Compressor;
@@ -24,7 +29,64 @@ impl<'a> Minifier<'a> {
         Self { compressor: Compressor::new(allocator, options.compress) }
     }
 
-    pub fn build<'b>(self, program: &'b mut Program<'a>) {
+    /// # Errors
+    ///
+    /// * the program contains an `InvalidStatement` or `InvalidExpression`,
+    ///   the program is left unchanged
+    pub fn build<'b>(self, program: &'b mut Program<'a>) -> Result<(), InvalidNode> {
+        let mut finder = InvalidNodeFinder::default();
+        finder.visit_program(program);
+        if let Some(span) = finder.span {
+            return Err(InvalidNode(span));
+        }
         self.compressor.build(program);
+        Ok(())
+    }
+}
+
+/// Find the first `InvalidStatement` or `InvalidExpression` left by a parser recovering from errors
+#[derive(Default)]
+struct InvalidNodeFinder {
+    span: Option<Span>,
+}
+
+impl<'a, 'b> VisitMut<'a, 'b> for InvalidNodeFinder {
+    fn visit_expression(&mut self, expr: &'b mut Expression<'a>) {
+        walk_binary_chain(self, expr);
+    }
+
+    fn visit_invalid_statement(&mut self, stmt: &'b mut InvalidStatement) {
+        self.span.get_or_insert(stmt.span);
+    }
+
+    fn visit_invalid_expression(&mut self, expr: &'b mut InvalidExpression) {
+        self.span.get_or_insert(expr.span);
+    }
+}
+
+/// Visits the operands of a chain of binary and logical expressions `a + b || c` in a loop,
+/// long chains such as generated string concatenations would overflow the stack in a recursion.
+/// Other expressions are visited with `visit_expression_match`.
+fn walk_binary_chain<'a, 'b, V: VisitMut<'a, 'b>>(visitor: &mut V, expr: &'b mut Expression<'a>) {
+    let mut rights = vec![];
+    let mut expr = expr;
+    loop {
+        match expr {
+            Expression::BinaryExpression(binary_expr) => {
+                let binary_expr = &mut **binary_expr;
+                rights.push(&mut binary_expr.right);
+                expr = &mut binary_expr.left;
+            }
+            Expression::LogicalExpression(logical_expr) => {
+                let logical_expr = &mut **logical_expr;
+                rights.push(&mut logical_expr.right);
+                expr = &mut logical_expr.left;
+            }
+            _ => break,
+        }
+    }
+    visitor.visit_expression_match(expr);
+    for right in rights.into_iter().rev() {
+        visitor.visit_expression(right);
     }
 }
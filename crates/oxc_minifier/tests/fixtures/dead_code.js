@@ -0,0 +1,80 @@
+if_statement: {
+    options = { dead_code: true }
+    input: {
+        if (true) a(); else b();
+        if (1 > 2) c(); else { d(); }
+        if (0) e();
+        f();
+    }
+    expect: {
+        a();
+        { d(); }
+        f();
+    }
+}
+
+keep_hoisted_declarations: {
+    options = { dead_code: true }
+    input: {
+        if (false) { var a = 1; }
+        if (true) b(); else function c() {}
+    }
+    expect: {
+        if (!1) { var a = 1; }
+        if (!0) { b(); } else function c() {}
+    }
+}
+
+unknown_test: {
+    options = { dead_code: true }
+    input: {
+        if (x) a(); else b();
+        y = x ? a : b;
+    }
+    expect: {
+        if (x) { a(); } else b();
+        y = x ? a : b;
+    }
+}
+
+conditional_expression: {
+    options = { dead_code: true }
+    input: {
+        a = true ? b : c;
+        d = "" ? e() : f();
+        g = 1 && h;
+        i = null ?? j;
+        k = (true ? l.m : n)();
+    }
+    expect: {
+        a = b;
+        d = f();
+        g = h;
+        i = j;
+        k = (!0 ? l.m : n)();
+    }
+}
+
+no_dead_code: {
+    options = { dead_code: false, evaluate: false }
+    input: {
+        if (false) a();
+    }
+    expect: {
+        if (!1) { a(); }
+    }
+}
+
+delete_operand: {
+    options = { dead_code: true }
+    input: {
+        delete (true ? a : b);
+        delete (false || c.d);
+        delete (0 ? e : f).g;
+    }
+    expect: {
+        delete (!0 ? a : b);
+        delete (!1 || c.d);
+        delete (f).g;
+    }
+}
@@ -0,0 +1,134 @@
+fold_arithmetic: {
+    options = { evaluate: true }
+    input: {
+        a = 1 + 2 * 3;
+        b = 10 % 4 - 2 ** 3;
+        c = 1 << 4 | 1;
+        d = -(5 - 2);
+        e = 2n ** 3n;
+        f = x + (1 + 2);
+    }
+    expect: {
+        a = 7;
+        b = -6;
+        c = 17;
+        d = -3;
+        e = 8n;
+        f = x + 3;
+    }
+}
+
+fold_strings: {
+    options = { evaluate: true }
+    input: {
+        a = "a" + "b";
+        b = `x${1 + 1}y`;
+        c = typeof "";
+        d = "1" + 2 + 3;
+    }
+    expect: {
+        a = 'ab';
+        b = 'x2y';
+        c = 'string';
+        d = '123';
+    }
+}
+
+fold_booleans: {
+    options = { evaluate: true }
+    input: {
+        a = 1 === 1;
+        b = "1" == 1 && null == undefined;
+        c = !"";
+    }
+    expect: {
+        a = !0;
+        b = !0;
+        c = !0;
+    }
+}
+
+keep_longer_values: {
+    options = { evaluate: true }
+    input: {
+        a = 1 / 3;
+        b = 1 / 0;
+        c = f() + 1;
+        d = 2 ** 100;
+    }
+    expect: {
+        a = 1 / 3;
+        b = 1 / 0;
+        c = f() + 1;
+        d = 2 ** 100;
+    }
+}
+
+no_evaluate: {
+    options = { evaluate: false }
+    input: {
+        a = 1 + 2;
+    }
+    expect: {
+        a = 1 + 2;
+    }
+}
+
+keep_nan_exponent: {
+    options = { evaluate: true }
+    input: {
+        a = 1 ** NaN;
+        b = 1 ** undefined;
+    }
+    expect: {
+        a = 1 ** NaN;
+        b = 1 ** void 0;
+    }
+}
+
+shadowed_globals: {
+    options = { evaluate: true }
+    input: {
+        function f(NaN) { return NaN === NaN; }
+        function g(Infinity) { return Infinity > 1; }
+        function h(undefined) { return undefined ? 1 : 2; }
+    }
+    expect: {
+        function f(NaN) { return NaN === NaN; }
+        function g(Infinity) { return Infinity > 1; }
+        function h(undefined) { return undefined ? 1 : 2; }
+    }
+}
+
+unshadowed_globals: {
+    options = { evaluate: true }
+    input: {
+        function f(a) { return NaN === NaN; }
+        function g(a) { return Infinity > 1; }
+    }
+    expect: {
+        function f(a) { return !1; }
+        function g(a) { return !0; }
+    }
+}
+
+with_statement_globals: {
+    options = { evaluate: true }
+    input: {
+        with (a) { b = NaN === NaN; }
+    }
+    expect: {
+        with (a) { b = NaN === NaN; }
+    }
+}
+
+shadowed_undefined: {
+    input: {
+        a = undefined;
+        function f(undefined) { return undefined; }
+    }
+    expect: {
+        a = undefined;
+        function f(undefined) { return undefined; }
+    }
+}
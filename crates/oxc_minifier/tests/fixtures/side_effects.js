@@ -0,0 +1,76 @@
+drop_pure_expressions: {
+    options = { side_effects: true }
+    input: {
+        1 + 2;
+        "a" + 1;
+        (function () {});
+        () => {};
+        [1, "b", null];
+        ({ a: 1, b: [] });
+        typeof x;
+        void 0;
+        NaN;
+        a();
+    }
+    expect: {
+        a();
+    }
+}
+
+keep_side_effects: {
+    options = { side_effects: true }
+    input: {
+        a();
+        new A();
+        b.c;
+        d = 1;
+        e++;
+        delete f.g;
+        [...h];
+        ({ ...i });
+        "j" in k;
+        l + 1;
+        1n + 1;
+        class M extends N {}
+        (class { static [o] = 1 });
+    }
+    expect: {
+        a();
+        new A();
+        b.c;
+        d = 1;
+        e++;
+        delete f.g;
+        [...h];
+        ({ ...i });
+        'j' in k;
+        l + 1;
+        1n + 1;
+        class M extends N {}
+        (class { static [o] = 1; });
+    }
+}
+
+keep_undeclared_globals: {
+    options = { side_effects: true }
+    input: {
+        a;
+        typeof a;
+        undefined;
+    }
+    expect: {
+        a;
+    }
+}
+
+disabled: {
+    options = { side_effects: false }
+    input: {
+        1 + 2;
+        (function () {});
+    }
+    expect: {
+        3;
+        (function () {});
+    }
+}
@@ -2,7 +2,7 @@
 
 use oxc_allocator::Allocator;
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, SourceType};
+use oxc_ast::{ast::*, SourceType, Span};
 use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
 use oxc_parser::Parser;
 use oxc_printer::{Printer, PrinterOptions};
@@ -25,6 +25,49 @@ fn test() {
     }
 }
 
+#[test]
+fn invalid_nodes() {
+    let allocator = Allocator::default();
+    let source_text = "debugger; let = ;";
+    let ret = Parser::new(&allocator, source_text, SourceType::default())
+        .recover_from_errors(true)
+        .parse();
+    let program = allocator.alloc(ret.program);
+    let result = Minifier::new(&allocator, MinifierOptions::default()).build(program);
+    assert_eq!(
+        result.map_err(|e| e.0),
+        Err(Span::new(10, 17)),
+        "the invalid statement is reported"
+    );
+    assert!(
+        matches!(program.body[0], Statement::DebuggerStatement(_)),
+        "the program is not compressed"
+    );
+}
+
+#[test]
+fn long_chains() {
+    // The chains are compressed in a loop, a recursion would overflow the stack
+    let chain = |operand: &str, operator: &str| vec![operand; 100_000].join(operator);
+    let source_text = format!(
+        "x = {}; y = {}; z = {}; {};",
+        chain("a", " + "),
+        chain("a", " || "),
+        chain("1", " + "),
+        chain("typeof a", " + ")
+    );
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, SourceType::default()).parse();
+    assert!(ret.errors.is_empty());
+    let program = allocator.alloc(ret.program);
+    Minifier::new(&allocator, MinifierOptions::default()).build(program).unwrap();
+    let printed =
+        Printer::new(source_text.len(), PrinterOptions::default()).build(program).unwrap();
+    let expected = format!("x={};y={};z=100000;", chain("a", "+"), chain("a", "||"));
+    // The constant chain is folded and the chain without side effects is dropped
+    assert!(printed.replace(['\n', ' '], "") == expected);
+}
+
 #[derive(Debug, Default)]
 struct TestSuite {
     tests: Vec<TestCase>,
@@ -68,9 +111,10 @@ impl TestCase {
         let parser_return = Parser::new(&allocator, self.input.as_ref(), source_type).parse();
         let program = allocator.alloc(parser_return.program);
         let minifier_options = MinifierOptions { compress: self.compress_options };
-        Minifier::new(&allocator, minifier_options).build(program);
+        Minifier::new(&allocator, minifier_options).build(program).unwrap();
         let printer_options = PrinterOptions::default();
-        let minified_source_text = Printer::new(self.input.len(), printer_options).build(program);
+        let minified_source_text =
+            Printer::new(self.input.len(), printer_options).build(program).unwrap();
         assert_eq!(
             remove_whitespace(minified_source_text.as_str()),
             remove_whitespace(self.expect.as_ref()),
@@ -120,7 +164,6 @@ impl TestCase {
         Self { name, compress_options: options, input, expect }
     }
 
-    #[allow(clippy::single_match)]
     fn parse_options<'a>(object_expr: &'a ObjectExpression<'a>) -> CompressOptions {
         let mut options = CompressOptions::default();
         for object_property in &object_expr.properties {
@@ -128,9 +171,18 @@ impl TestCase {
                 && let PropertyValue::Expression(value_expr) = &property.value
                 && let Some(name) = property.key.static_name() {
                 match name.as_str() {
+                    "dead_code" => {
+                        options.dead_code = Self::get_boolean(value_expr);
+                    }
                     "drop_debugger" => {
                         options.drop_debugger = Self::get_boolean(value_expr);
                     }
+                    "evaluate" => {
+                        options.evaluate = Self::get_boolean(value_expr);
+                    }
+                    "side_effects" => {
+                        options.side_effects = Self::get_boolean(value_expr);
+                    }
                     _ => {}
                 }
             }
@@ -44,6 +44,9 @@ bitflags! {
         ///   * ambient variable declaration => `declare var $: any`
         ///   * ambient class declaration => `declare class C { foo(); } , etc..`
         const Ambient = 1 << 5;
+
+        /// Outside of any function, for reporting top-level await
+        const TopLevel = 1 << 6;
     }
 }
 
@@ -90,6 +93,12 @@ impl Context {
         self.contains(Self::Ambient)
     }
 
+    #[must_use]
+    #[inline]
+    pub(crate) fn has_top_level(self) -> bool {
+        self.contains(Self::TopLevel)
+    }
+
     #[must_use]
     #[inline]
     pub(crate) fn union_await_if(self, include: bool) -> Self {
@@ -126,6 +135,12 @@ impl Context {
         self.and(Self::Await, include)
     }
 
+    #[must_use]
+    #[inline]
+    pub(crate) fn and_top_level(self, include: bool) -> Self {
+        self.and(Self::TopLevel, include)
+    }
+
     #[must_use]
     #[inline]
     pub(crate) fn and_return(self, include: bool) -> Self {
@@ -0,0 +1,359 @@
+//! Lossless concrete syntax tree for formatters and codemods, see [`SyntaxTree`]
+
+use std::{fmt, rc::Rc};
+
+use oxc_ast::{ast::Program, visit::Visit, AstKind, GetSpan, ModuleKind, Span, Trivias};
+use oxc_diagnostics::Error;
+
+use crate::tokenizer::SourceToken;
+
+/// Return value of [`Parser::parse_syntax_tree`](crate::Parser::parse_syntax_tree),
+/// the same as [`ParserReturn`](crate::ParserReturn) with the program inside the tree
+#[derive(Debug)]
+pub struct SyntaxTreeReturn<'a> {
+    pub tree: SyntaxTree<'a>,
+    pub errors: Vec<Error>,
+    pub trivias: Rc<Trivias>,
+    pub panicked: bool,
+    pub module_kind: ModuleKind,
+}
+
+/// Index of a node in a [`SyntaxTree`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SyntaxNodeId(usize);
+
+impl SyntaxNodeId {
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A child of a node, in source order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxElement {
+    Node(SyntaxNodeId),
+    /// A token which is not part of a child node, including comments and whitespace
+    Token(SourceToken),
+}
+
+#[derive(Debug)]
+struct SyntaxNode<'a> {
+    kind: AstKind<'a>,
+    parent: Option<SyntaxNodeId>,
+    /// Range of the tokens inside the node, always within the range of the parent
+    token_start: usize,
+    token_end: usize,
+    /// Sorted by the position of their tokens
+    children: Vec<SyntaxNodeId>,
+}
+
+/// The AST with every token of the source text attached to the innermost node containing it.
+///
+/// Tokens include comments, whitespace and line breaks, and cover the whole source text without gaps,
+/// so printing the tokens reproduces the source text byte for byte.
+/// The parentheses, token positions and comment placement dropped by the AST can be read from
+/// the [`SyntaxElement`]s of a node, e.g. to format the leading comments of a statement
+/// or to replace a single node while preserving the rest of the code.
+///
+/// The nodes are the ones visited with an [`AstKind`], nodes without an `AstKind` are part of their parent.
+pub struct SyntaxTree<'a> {
+    source_text: &'a str,
+    program: &'a Program<'a>,
+    tokens: Vec<SourceToken>,
+    nodes: Vec<SyntaxNode<'a>>,
+}
+
+impl<'a> SyntaxTree<'a> {
+    pub(crate) fn new(
+        source_text: &'a str,
+        program: &'a Program<'a>,
+        tokens: Vec<SourceToken>,
+    ) -> Self {
+        let mut builder = SyntaxTreeBuilder { tokens: &tokens, nodes: vec![], stack: vec![] };
+        builder.visit_program(program);
+        let mut nodes = builder.nodes;
+        // Children are visited in evaluation order, which is not always the source order,
+        // e.g. the decorators of a class
+        for i in 0..nodes.len() {
+            let mut children = std::mem::take(&mut nodes[i].children);
+            children.sort_by_key(|id| (nodes[id.0].token_start, nodes[id.0].token_end, id.0));
+            nodes[i].children = children;
+        }
+        Self { source_text, program, tokens, nodes }
+    }
+
+    #[must_use]
+    pub fn source_text(&self) -> &'a str {
+        self.source_text
+    }
+
+    #[must_use]
+    pub fn program(&self) -> &'a Program<'a> {
+        self.program
+    }
+
+    /// All tokens in source order, including comments and whitespace
+    #[must_use]
+    pub fn tokens(&self) -> &[SourceToken] {
+        &self.tokens
+    }
+
+    /// The `Program` node, which contains all tokens
+    #[must_use]
+    pub fn root(&self) -> SyntaxNodeId {
+        SyntaxNodeId(0)
+    }
+
+    #[must_use]
+    pub fn kind(&self, id: SyntaxNodeId) -> AstKind<'a> {
+        self.nodes[id.0].kind
+    }
+
+    #[must_use]
+    pub fn parent(&self, id: SyntaxNodeId) -> Option<SyntaxNodeId> {
+        self.nodes[id.0].parent
+    }
+
+    /// Child nodes in source order
+    #[must_use]
+    pub fn children(&self, id: SyntaxNodeId) -> &[SyntaxNodeId] {
+        &self.nodes[id.0].children
+    }
+
+    /// Tokens of the node and its descendants
+    #[must_use]
+    pub fn node_tokens(&self, id: SyntaxNodeId) -> &[SourceToken] {
+        let node = &self.nodes[id.0];
+        &self.tokens[node.token_start..node.token_end]
+    }
+
+    /// Source text of the node, from the start of its first token to the end of its last token
+    #[must_use]
+    pub fn node_text(&self, id: SyntaxNodeId) -> &'a str {
+        match self.node_tokens(id) {
+            [] => "",
+            [first, .., last] => {
+                Span::new(first.span.start, last.span.end).source_text(self.source_text)
+            }
+            [token] => token.span.source_text(self.source_text),
+        }
+    }
+
+    /// Child nodes and the tokens between them in source order
+    #[must_use]
+    pub fn elements(&self, id: SyntaxNodeId) -> SyntaxElements<'_, 'a> {
+        let node = &self.nodes[id.0];
+        SyntaxElements {
+            tree: self,
+            children: &node.children,
+            pos: node.token_start,
+            end: node.token_end,
+        }
+    }
+
+    /// The innermost node containing `span`
+    #[must_use]
+    pub fn covering_node(&self, span: Span) -> SyntaxNodeId {
+        let mut id = self.root();
+        while let Some(child) = self.children(id).iter().copied().find(|&child| {
+            let child_span = self.kind(child).span();
+            child_span.start <= span.start && span.end <= child_span.end
+        }) {
+            id = child;
+        }
+        id
+    }
+}
+
+impl<'a> fmt::Debug for SyntaxTree<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntaxTree").field("tokens", &self.tokens).finish_non_exhaustive()
+    }
+}
+
+/// Prints the tokens, which is the original source text
+impl<'a> fmt::Display for SyntaxTree<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.tokens
+            .iter()
+            .try_for_each(|token| f.write_str(token.span.source_text(self.source_text)))
+    }
+}
+
+/// Iterator of [`SyntaxTree::elements`]
+pub struct SyntaxElements<'t, 'a> {
+    tree: &'t SyntaxTree<'a>,
+    children: &'t [SyntaxNodeId],
+    pos: usize,
+    end: usize,
+}
+
+impl<'t, 'a> Iterator for SyntaxElements<'t, 'a> {
+    type Item = SyntaxElement;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((&child, rest)) = self.children.split_first() {
+            let node = &self.tree.nodes[child.0];
+            if node.token_start > self.pos {
+                break;
+            }
+            self.children = rest;
+            // Nodes sharing tokens with a previous sibling, e.g. the value of a shorthand property,
+            // are only reachable through `children`
+            if node.token_start == self.pos {
+                self.pos = node.token_end.max(self.pos);
+                return Some(SyntaxElement::Node(child));
+            }
+        }
+        (self.pos < self.end).then(|| {
+            self.pos += 1;
+            SyntaxElement::Token(self.tree.tokens[self.pos - 1])
+        })
+    }
+}
+
+struct SyntaxTreeBuilder<'t, 'a> {
+    tokens: &'t [SourceToken],
+    nodes: Vec<SyntaxNode<'a>>,
+    stack: Vec<SyntaxNodeId>,
+}
+
+impl<'t, 'a> Visit<'a> for SyntaxTreeBuilder<'t, 'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let id = SyntaxNodeId(self.nodes.len());
+        let parent = self.stack.last().copied();
+        let (token_start, token_end) = if let Some(parent) = parent {
+            let span = kind.span();
+            let parent = &self.nodes[parent.0];
+            let start = self.tokens.partition_point(|token| token.span.start < span.start);
+            let end = self.tokens.partition_point(|token| token.span.end <= span.end);
+            // Clamp to the parent, so the ranges are always nested
+            let start = start.clamp(parent.token_start, parent.token_end);
+            (start, end.clamp(start, parent.token_end))
+        } else {
+            (0, self.tokens.len())
+        };
+        if let Some(parent) = parent {
+            self.nodes[parent.0].children.push(id);
+        }
+        self.nodes.push(SyntaxNode { kind, parent, token_start, token_end, children: vec![] });
+        self.stack.push(id);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        self.stack.pop();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstKind, SourceType, Span};
+
+    use super::{SyntaxElement, SyntaxNodeId, SyntaxTree};
+    use crate::{Parser, TokenKind};
+
+    const SOURCE: &str = "#!/usr/bin/env node\r\n// leading\nconst { a } = ((b)) /* c */ ;\n\n  f(a, ) // trailing\n";
+
+    fn parse(allocator: &Allocator) -> SyntaxTree<'_> {
+        let ret = Parser::new(allocator, SOURCE, SourceType::default()).parse_syntax_tree();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        ret.tree
+    }
+
+    /// The node whose first token starts at the first occurrence of `text`
+    fn node_at(tree: &SyntaxTree, text: &str) -> SyntaxNodeId {
+        let offset = u32::try_from(SOURCE.find(text).unwrap()).unwrap();
+        tree.covering_node(Span::new(offset, offset + 1))
+    }
+
+    #[test]
+    fn lossless() {
+        fn print(tree: &SyntaxTree, id: SyntaxNodeId, out: &mut String) {
+            for element in tree.elements(id) {
+                match element {
+                    SyntaxElement::Node(child) => print(tree, child, out),
+                    SyntaxElement::Token(token) => {
+                        out.push_str(token.span.source_text(tree.source_text()));
+                    }
+                }
+            }
+        }
+
+        let allocator = Allocator::default();
+        let tree = parse(&allocator);
+        assert_eq!(tree.to_string(), SOURCE, "`Display` prints the source text");
+        let mut printed = String::new();
+        print(&tree, tree.root(), &mut printed);
+        assert_eq!(printed, SOURCE, "the elements of all nodes cover the source text");
+    }
+
+    #[test]
+    fn program() {
+        let allocator = Allocator::default();
+        let tree = parse(&allocator);
+        let root = tree.root();
+        assert!(matches!(tree.kind(root), AstKind::Program(_)), "the root is the program");
+        let statements = tree.children(root);
+        assert_eq!(statements.len(), 2, "the program has two statements");
+        assert_eq!(
+            tree.node_text(statements[0]),
+            "const { a } = ((b)) /* c */ ;",
+            "the text of a node includes its inner comments"
+        );
+        let comments = tree
+            .elements(root)
+            .filter_map(|element| match element {
+                SyntaxElement::Token(token) if token.kind == TokenKind::Comment => {
+                    Some(token.span.source_text(SOURCE))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            comments,
+            ["#!/usr/bin/env node", "// leading", "// trailing"],
+            "comments between statements belong to the program"
+        );
+    }
+
+    #[test]
+    fn parentheses() {
+        let allocator = Allocator::default();
+        let tree = parse(&allocator);
+        let init = node_at(&tree, "b)");
+        assert!(matches!(tree.kind(init), AstKind::IdentifierReference(_)), "`b` is a reference");
+        let paren = tree.parent(init).unwrap();
+        assert_eq!(tree.node_text(paren), "(b)", "the inner parentheses are a node");
+        assert_eq!(
+            tree.node_text(tree.parent(paren).unwrap()),
+            "((b))",
+            "the outer parentheses are a node"
+        );
+    }
+
+    #[test]
+    fn trailing_comma() {
+        let allocator = Allocator::default();
+        let tree = parse(&allocator);
+        let call = tree.parent(node_at(&tree, "f")).unwrap();
+        assert_eq!(tree.node_text(call), "f(a, )", "the call expression");
+        assert!(
+            tree.elements(call).any(|element| {
+                matches!(element, SyntaxElement::Token(token) if token.span.source_text(SOURCE) == ",")
+            }),
+            "the trailing comma is a token of the call expression"
+        );
+    }
+
+    #[test]
+    fn unrecoverable_error() {
+        let allocator = Allocator::default();
+        let source = "a = ; b";
+        let ret = Parser::new(&allocator, source, SourceType::default()).parse_syntax_tree();
+        assert!(ret.panicked);
+        assert_eq!(ret.tree.to_string(), source, "the tokens after the error are preserved");
+        assert!(ret.tree.children(ret.tree.root()).is_empty(), "the program has no statements");
+    }
+}
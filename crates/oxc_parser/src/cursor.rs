@@ -1,16 +1,18 @@
 //! Code related to navigating `Token`s from the lexer
 
 use oxc_ast::Span;
-use oxc_diagnostics::Result;
+use oxc_diagnostics::{NestingTooDeep, Result};
 
 use crate::lexer::{Kind, LexerCheckpoint, LexerContext, Token};
-use crate::{diagnostics, Context, Parser};
+use crate::{diagnostics, Context, Parser, SourceToken, TokenFlags, TokenKind};
 
 pub struct ParserCheckpoint<'a> {
     lexer: LexerCheckpoint<'a>,
     cur_token: Token<'a>,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_pos: usize,
+    has_module_syntax: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -109,6 +111,7 @@ impl<'a> Parser<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.record_token(kind);
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_token();
     }
@@ -117,10 +120,29 @@ impl<'a> Parser<'a> {
     /// Checks if the current token is escaped if it is a keyword
     fn advance_for_jsx_child(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.record_token(kind);
         self.prev_token_end = self.token.end;
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Record the current token as `kind` for the [`crate::Tokenizer`],
+    /// the initial token before the first `advance` is an `Eof`
+    fn record_token(&mut self, kind: Kind) {
+        if self.at(Kind::Eof) {
+            return;
+        }
+        if let Some(tokens) = &mut self.tokens {
+            let mut flags = TokenFlags::empty();
+            flags.set(TokenFlags::ON_NEW_LINE, self.token.is_on_new_line);
+            flags.set(TokenFlags::ESCAPED, self.token.escaped);
+            tokens.push(SourceToken {
+                kind: TokenKind::from_kind(kind),
+                span: self.token.span(),
+                flags,
+            });
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     #[must_use]
     pub(crate) fn eat(&mut self, kind: Kind) -> bool {
@@ -153,6 +175,10 @@ impl<'a> Parser<'a> {
     pub(crate) fn asi(&mut self) -> Result<()> {
         if !self.can_insert_semicolon() {
             let span = Span::new(self.prev_token_end, self.cur_token().start);
+            if self.recover {
+                self.error(diagnostics::AutoSemicolonInsertion(span));
+                return Ok(());
+            }
             return Err(diagnostics::AutoSemicolonInsertion(span).into());
         }
         if self.at(Kind::Semicolon) {
@@ -261,17 +287,29 @@ impl<'a> Parser<'a> {
             cur_token: self.token.clone(),
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_pos: self.tokens.as_ref().map_or(0, Vec::len),
+            has_module_syntax: self.state.has_module_syntax,
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos: errors_lens } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos: errors_lens,
+            tokens_pos,
+            has_module_syntax,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_lens);
+        if let Some(tokens) = &mut self.tokens {
+            tokens.truncate(tokens_pos);
+        }
+        self.state.has_module_syntax = has_module_syntax;
     }
 
     /// # Errors
@@ -281,7 +319,7 @@ impl<'a> Parser<'a> {
     ) -> Result<T> {
         let checkpoint = self.checkpoint();
         let ctx = self.ctx;
-        let result = func(self);
+        let result = self.without_recovery(func);
         if result.is_err() {
             self.ctx = ctx;
             self.rewind(checkpoint);
@@ -291,7 +329,7 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn lookahead<U>(&mut self, predicate: impl Fn(&mut Parser<'a>) -> U) -> U {
         let checkpoint = self.checkpoint();
-        let answer = predicate(self);
+        let answer = self.without_recovery(predicate);
         self.rewind(checkpoint);
         answer
     }
@@ -310,6 +348,19 @@ impl<'a> Parser<'a> {
         cb(self)
     }
 
+    /// Run `f` one nesting level deeper, see [`Parser::max_nesting_depth`]
+    /// # Errors
+    pub(crate) fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if self.depth >= self.max_nesting_depth {
+            let span = self.cur_token().span();
+            return Err(NestingTooDeep(self.max_nesting_depth, span).into());
+        }
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+
     pub(crate) fn with_context<F, T>(&mut self, flags: Context, cb: F) -> T
     where
         F: FnOnce(&mut Self) -> T,
@@ -324,3 +375,27 @@ impl<'a> Parser<'a> {
         cb(self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+
+    use crate::Parser;
+
+    #[test]
+    fn rewind_module_syntax() {
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_unambiguous(true);
+        let mut parser = Parser::new(&allocator, "import.meta", source_type);
+        parser.bump_any();
+        let checkpoint = parser.checkpoint();
+        parser.parse_expr().unwrap();
+        assert!(parser.state.has_module_syntax);
+        parser.rewind(checkpoint);
+        assert!(
+            !parser.state.has_module_syntax,
+            "module syntax in a rewound speculative parse does not count"
+        );
+    }
+}
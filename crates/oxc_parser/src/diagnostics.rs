@@ -4,11 +4,28 @@ use oxc_diagnostics::{
     thiserror::{self, Error},
 };
 
+use crate::EcmaVersion;
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Flow is not supported")]
 #[diagnostic()]
 pub struct Flow(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Flow variance sigils are only allowed on properties")]
+#[diagnostic()]
+pub struct FlowVariance(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Flow type spreads are only allowed in object types")]
+#[diagnostic()]
+pub struct FlowTypeSpread(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("{0} is not available in {1}")]
+#[diagnostic()]
+pub struct UnavailableSyntax(pub &'static str, pub EcmaVersion, #[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Unexpected token")]
 #[diagnostic()]
@@ -302,3 +319,22 @@ pub struct JSXExpressionsMayNotUseTheCommaOperator(#[label] pub Span);
 pub struct BadLeadingDecorator(
     #[label("Leading decorators must be attached to a class declaration")] pub Span,
 );
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: {0}")]
+#[diagnostic()]
+pub struct InvalidRegExp(pub &'static str, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Duplicate capture group name `{0}`")]
+#[diagnostic()]
+pub struct RegExpDuplicateCaptureGroupName(
+    pub Atom,
+    #[label("`{0}` has already been declared here")] pub Span,
+    #[label("It can not be redeclared here")] pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid named capture `{0}` referenced")]
+#[diagnostic()]
+pub struct RegExpInvalidNamedReference(pub Atom, #[label] pub Span);
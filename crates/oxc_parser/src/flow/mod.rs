@@ -0,0 +1,413 @@
+//! [Flow](https://flow.org/en/docs/types/) syntax, see [`oxc_ast::SourceType::with_flow`]
+//!
+//! Flow shares most of its type grammar with TypeScript, so the TypeScript parser is used
+//! for type annotations, `type`, `interface` and `declare` statements.
+//! This module handles the syntax which differs.
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, Span};
+use oxc_diagnostics::Result;
+
+use crate::{diagnostics, lexer::Kind, Parser};
+
+impl<'a> Parser<'a> {
+    /// `type`, `opaque type`, `interface` or `declare` statement
+    pub(crate) fn at_start_of_flow_declaration(&mut self) -> bool {
+        self.lookahead(Self::at_start_of_flow_declaration_worker)
+    }
+
+    fn at_start_of_flow_declaration_worker(&mut self) -> bool {
+        if self.at(Kind::Declare) {
+            self.bump_any();
+            if self.cur_token().is_on_new_line {
+                return false;
+            }
+            if matches!(
+                self.cur_kind(),
+                Kind::Var
+                    | Kind::Let
+                    | Kind::Const
+                    | Kind::Function
+                    | Kind::Class
+                    | Kind::Module
+                    | Kind::Export
+            ) {
+                return true;
+            }
+        }
+        match self.cur_kind() {
+            Kind::Type | Kind::Interface => {
+                self.bump_any();
+                self.cur_kind().is_binding_identifier() && !self.cur_token().is_on_new_line
+            }
+            _ => self.at_flow_opaque_type(),
+        }
+    }
+
+    pub(crate) fn at_flow_opaque_type(&mut self) -> bool {
+        self.at(Kind::Ident)
+            && self.cur_src() == "opaque"
+            && self.peek_at(Kind::Type)
+            && !self.peek_token().is_on_new_line
+    }
+
+    /// `opaque type ID: Super = string;`
+    /// `declare opaque type ID: Super;`
+    pub(crate) fn parse_flow_opaque_type_declaration(
+        &mut self,
+        span: Span,
+        modifiers: Modifiers<'a>,
+    ) -> Result<Declaration<'a>> {
+        self.bump_any(); // bump `opaque`
+        self.expect(Kind::Type)?;
+        let id = self.parse_binding_identifier()?;
+        let type_parameters = self.parse_ts_type_parameters()?;
+        let supertype = if self.eat(Kind::Colon) { Some(self.parse_type()?) } else { None };
+        let impltype = if self.ctx.has_ambient() {
+            None
+        } else {
+            self.expect(Kind::Eq)?;
+            Some(self.parse_type()?)
+        };
+        self.asi()?;
+        Ok(self.ast.flow_opaque_type_declaration(
+            self.end_span(span),
+            id,
+            type_parameters,
+            supertype,
+            impltype,
+            modifiers,
+        ))
+    }
+
+    /// `declare module.exports: T;`
+    pub(crate) fn parse_flow_declare_module_exports(
+        &mut self,
+        span: Span,
+    ) -> Result<Declaration<'a>> {
+        self.bump_any(); // bump `module`
+        self.expect(Kind::Dot)?;
+        if !self.at(Kind::Ident) || self.cur_src() != "exports" {
+            return Err(self.unexpected());
+        }
+        self.bump_any();
+        let Some(type_annotation) = self.parse_ts_type_annotation()? else {
+            return Err(self.unexpected());
+        };
+        self.asi()?;
+        Ok(self.ast.flow_declare_module_exports(self.end_span(span), type_annotation))
+    }
+
+    /// `{|` or `{||}`
+    pub(crate) fn at_flow_exact_object_type(&mut self) -> bool {
+        self.at(Kind::LCurly) && matches!(self.peek_kind(), Kind::Pipe | Kind::Pipe2)
+    }
+
+    /// `|}` closing an exact object type, which is not a union
+    pub(crate) fn at_flow_exact_object_type_end(&mut self) -> bool {
+        self.flow_enabled() && self.at(Kind::Pipe) && self.peek_at(Kind::RCurly)
+    }
+
+    /// `{| a: string, b: number |}`
+    pub(crate) fn parse_flow_exact_object_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly)?;
+        let mut members = self.ast.new_vec();
+        if self.eat(Kind::Pipe2) {
+            self.expect(Kind::RCurly)?;
+            return Ok(self.ast.flow_exact_object_type(self.end_span(span), members));
+        }
+        self.expect(Kind::Pipe)?;
+        let mut spreads = self.ast.new_vec();
+        while !self.at_flow_exact_object_type_end() && !self.at(Kind::Eof) {
+            if self.at(Kind::Dot3) {
+                spreads.extend(self.parse_flow_object_type_spread()?);
+            } else {
+                members.push(self.parse_ts_type_signature()?);
+            }
+        }
+        self.expect(Kind::Pipe)?;
+        self.expect(Kind::RCurly)?;
+        let span = self.end_span(span);
+        let object_type = self.ast.flow_exact_object_type(span, members);
+        Ok(self.flow_object_type_with_spreads(span, object_type, spreads))
+    }
+
+    /// `...A` in an object type, or the explicit inexact marker `...` which is dropped
+    pub(crate) fn parse_flow_object_type_spread(&mut self) -> Result<Option<TSType<'a>>> {
+        self.bump_any(); // bump `...`
+        let spread = if matches!(self.cur_kind(), Kind::Comma | Kind::Semicolon | Kind::RCurly)
+            || self.at_flow_exact_object_type_end()
+        {
+            None
+        } else {
+            Some(self.parse_type()?)
+        };
+        self.bump(Kind::Comma);
+        self.bump(Kind::Semicolon);
+        Ok(spread)
+    }
+
+    /// `{ ...A, b: B }` is mapped to the intersection `A & { b: B }`
+    pub(crate) fn flow_object_type_with_spreads(
+        &self,
+        span: Span,
+        object_type: TSType<'a>,
+        mut spreads: Vec<'a, TSType<'a>>,
+    ) -> TSType<'a> {
+        if spreads.is_empty() {
+            return object_type;
+        }
+        spreads.push(object_type);
+        self.ast.ts_intersection_type(span, spreads)
+    }
+
+    /// Variance sigil `+` (covariant) or `-` (contravariant) before a property
+    pub(crate) fn at_flow_variance(&mut self) -> bool {
+        self.flow_enabled()
+            && matches!(self.cur_kind(), Kind::Plus | Kind::Minus)
+            && self.peek_kind().is_class_element_name_start()
+    }
+
+    /// `static +x: T`
+    pub(crate) fn peek_at_flow_variance(&mut self) -> bool {
+        self.flow_enabled() && matches!(self.peek_kind(), Kind::Plus | Kind::Minus)
+    }
+
+    /// Eat a variance sigil, returns `true` for `+`.
+    /// Covariant properties are read-only and mapped to `readonly`,
+    /// contravariant (write-only) properties have no TypeScript counterpart and are kept as is.
+    pub(crate) fn eat_flow_variance(&mut self) -> bool {
+        if !self.at_flow_variance() {
+            return false;
+        }
+        let covariant = self.at(Kind::Plus);
+        self.bump_any();
+        covariant
+    }
+
+    /// `+x: T`, `-x: T` or `+[k: K]: V` in an object type
+    pub(crate) fn parse_flow_variance_member(&mut self) -> Result<TSSignature<'a>> {
+        let span = self.start_span();
+        let covariant = self.eat_flow_variance();
+        let mut signature = if self.is_at_ts_index_signature_member() {
+            self.parse_ts_index_signature_member()?
+        } else {
+            self.parse_ts_property_or_method_signature_member()?
+        };
+        match &mut signature {
+            TSSignature::TSPropertySignature(property) => {
+                property.span.start = span.start;
+                property.readonly = covariant;
+            }
+            TSSignature::TSIndexSignature(index) => index.span.start = span.start,
+            _ => self.error(diagnostics::FlowVariance(self.end_span(span))),
+        }
+        Ok(signature)
+    }
+
+    /// Variance sigil before a type parameter `<+T, -U>`, returns `Some(true)` for `+`.
+    /// Covariant and contravariant type parameters are mapped to TypeScript's `out T` and `in U`.
+    pub(crate) fn eat_flow_type_parameter_variance(&mut self) -> Option<bool> {
+        if !self.flow_enabled() || !matches!(self.cur_kind(), Kind::Plus | Kind::Minus) {
+            return None;
+        }
+        let covariant = self.at(Kind::Plus);
+        self.bump_any();
+        Some(covariant)
+    }
+
+    /// Predicate function `%checks` or `%checks(expression)` after a return type,
+    /// it only guides Flow's type refinement and is dropped
+    pub(crate) fn eat_flow_predicate(&mut self) -> Result<bool> {
+        if !self.flow_enabled() || !self.at(Kind::Percent) || !self.peek_at(Kind::Ident) {
+            return Ok(false);
+        }
+        let checks = self.peek_token().span();
+        if &self.source_text[checks.start as usize..checks.end as usize] != "checks" {
+            return Ok(false);
+        }
+        self.bump_any(); // bump `%`
+        self.bump_any(); // bump `checks`
+        if self.eat(Kind::LParen) {
+            self.parse_expr()?;
+            self.expect(Kind::RParen)?;
+        }
+        Ok(true)
+    }
+
+    /// `*`
+    pub(crate) fn parse_flow_existential_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.bump_any();
+        self.ast.flow_existential_type(self.end_span(span))
+    }
+
+    /// `(expression)` or a type cast `(expression: Type)`,
+    /// the type cast is parsed as a `TSAsExpression`
+    pub(crate) fn parse_flow_parenthesized_expression(
+        &mut self,
+        span: Span,
+    ) -> Result<Expression<'a>> {
+        self.expect(Kind::LParen)?;
+        let has_in = self.ctx.has_in();
+        self.ctx = self.ctx.and_in(true);
+        let expression = self.parse_flow_type_cast();
+        self.ctx = self.ctx.and_in(has_in);
+        let expression = expression?;
+        self.expect_closing(Kind::RParen)?;
+        Ok(self.ast.parenthesized_expression(self.end_span(span), expression))
+    }
+
+    fn parse_flow_type_cast(&mut self) -> Result<Expression<'a>> {
+        let span = self.start_span();
+        let expression = self.parse_expr()?;
+        if !self.eat(Kind::Colon) {
+            return Ok(expression);
+        }
+        let type_annotation = self.parse_type()?;
+        Ok(self.ast.ts_as_expression(self.end_span(span), expression, type_annotation))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{
+        ast::{
+            Declaration, Expression, ImportOrExportKind, ModuleDeclarationKind, Statement,
+            TSSignature, TSType,
+        },
+        SourceType, Span,
+    };
+
+    use crate::Parser;
+
+    #[test]
+    fn flow() {
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_module(true).with_flow(true);
+        let parse = |source| Parser::new(&allocator, source, source_type).parse();
+
+        let pass = [
+            "// @flow\nfunction f(x: number, y?: ?string): Array<string> { return []; }",
+            "const f = <T: Object>(x: T): T => x;",
+            "class A<T> extends B<T> { x: number = 1; static y: string; }",
+            "type A = {| a: string, b: number | void |};",
+            "type B = {||}; type C = { [key: string]: mixed }; type D = Array<*>;",
+            "export type E = 'a' | 'b'; export interface F { f(): void }",
+            "import type { A } from 'a'; import typeof B from 'b'; export type { A };",
+            "declare var x: number; declare function f(x: number): string; declare class C { m(): void }",
+            "declare module 'm' { declare export function f(): void; }",
+            "const x = (y: any); foo((a: string), b);",
+            "useState<string>('');",
+            "type A = { +a: string, -b: number, +[k: string]: mixed }; class B { +x: T; static -y: U }",
+            "type A = { ...B, c: C, ... }; type D = {| ...E, f: F |}; type G = { ... };",
+            "function f(x: mixed): boolean %checks { return !!x; } const g = (x): %checks => !!x;",
+            "declare function f(x: mixed): boolean %checks(typeof x === 'string');",
+            "import { typeof A, typeof B as C, type D, E } from 'a';",
+            "class K<+T> {} type T<-A> = A; function f<+A, -B, C>() {}",
+            "declare module.exports: { f(): void };",
+            "declare module 'm' { declare module.exports: { a: string }; }",
+        ];
+        for source in pass {
+            let ret = parse(source);
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        }
+
+        let ret = parse("opaque type A: B = string; declare opaque type C;");
+        assert!(ret.errors.is_empty());
+        let opaque = ret
+            .program
+            .body
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::Declaration(Declaration::FlowOpaqueTypeDeclaration(decl)) => {
+                    (decl.supertype.is_some(), decl.impltype.is_some())
+                }
+                _ => unreachable!(),
+            })
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(opaque, [(true, true), (false, false)]);
+
+        let ret = parse("type A = {| a: string |}");
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) = &ret.program.body[0] else { unreachable!() };
+        assert!(matches!(decl.type_annotation, TSType::FlowExactObjectType(_)));
+
+        let ret = parse("type A = { +a: string, -b: number }");
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) = &ret.program.body[0] else { unreachable!() };
+        let TSType::TSTypeLiteral(literal) = &decl.type_annotation else { unreachable!() };
+        let readonly = literal
+            .members
+            .iter()
+            .map(|member| match member {
+                TSSignature::TSPropertySignature(property) => property.readonly,
+                _ => unreachable!(),
+            })
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(readonly, [true, false]);
+
+        let ret = parse("type A = { ...B, c: C }");
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) = &ret.program.body[0] else { unreachable!() };
+        let TSType::TSIntersectionType(intersection) = &decl.type_annotation else { unreachable!() };
+        assert!(matches!(
+            intersection.types[..],
+            [TSType::TSTypeReference(_), TSType::TSTypeLiteral(_)]
+        ));
+
+        let ret = parse("function f(x): boolean %checks { return !!x; }");
+        let Statement::Declaration(Declaration::FunctionDeclaration(func)) = &ret.program.body[0] else { unreachable!() };
+        assert!(func.return_type.is_some());
+
+        for (source, message) in [
+            ("type A = { +m(): void }", "Flow variance sigils are only allowed on properties"),
+            ("class A { +m() {} }", "Flow variance sigils are only allowed on properties"),
+            ("interface A { ...B }", "Flow type spreads are only allowed in object types"),
+        ] {
+            let errors = parse(source).errors;
+            assert_eq!(errors.len(), 1, "{source}");
+            assert_eq!(errors[0].to_string(), message, "{source}");
+        }
+
+        let ret = parse("(x: number)");
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::ParenthesizedExpression(expr) = &stmt.expression else { unreachable!() };
+        assert!(matches!(expr.expression, Expression::TSAsExpression(_)));
+
+        let ret = parse("import typeof A from 'a'");
+        let Statement::ModuleDeclaration(decl) = &ret.program.body[0] else { unreachable!() };
+        let ModuleDeclarationKind::ImportDeclaration(decl) = &decl.kind else { unreachable!() };
+        assert_eq!(decl.import_kind, Some(ImportOrExportKind::Typeof));
+
+        // TypeScript only syntax is still rejected
+        for source in ["enum A {}", "x!", "namespace N {}"] {
+            assert!(!parse(source).errors.is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn flow_variance_and_module_exports() {
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_module(true).with_flow(true);
+        let parse = |source| Parser::new(&allocator, source, source_type).parse();
+
+        let ret = parse("type T<+A, -B, C> = A;");
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) = &ret.program.body[0] else { unreachable!() };
+        let variance = decl
+            .type_parameters
+            .as_ref()
+            .unwrap()
+            .params
+            .iter()
+            .map(|param| (param.r#in, param.out))
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(variance, [(false, true), (true, false), (false, false)]);
+
+        let ret = parse("declare module.exports: { a: string };");
+        assert!(ret.errors.is_empty());
+        let Statement::Declaration(Declaration::FlowDeclareModuleExports(decl)) = &ret.program.body[0] else { unreachable!() };
+        assert_eq!(decl.span, Span::new(0, 38));
+        assert!(matches!(decl.type_annotation.type_annotation, TSType::TSTypeLiteral(_)));
+        assert!(!parse("declare module.foo: T;").errors.is_empty());
+    }
+}
@@ -0,0 +1,364 @@
+//! Incremental parsing, see [`Parser::reparse`]
+
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, visit_mut::VisitMut, GetSpan, ShiftSpan, Span};
+use oxc_diagnostics::Result;
+
+use crate::{
+    lexer::{Kind, Lexer, Token},
+    state::ParserState,
+    Context, Parser, ParserReturn, StatementContext,
+};
+
+/// A change to the source text, `span` of the previous source text is replaced by `text`
+#[derive(Debug, Clone, Copy)]
+pub struct TextEdit<'e> {
+    pub span: Span,
+    pub text: &'e str,
+}
+
+impl<'e> TextEdit<'e> {
+    #[must_use]
+    pub fn new(span: Span, text: &'e str) -> Self {
+        Self { span, text }
+    }
+
+    /// Length difference between the inserted and the removed text
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn offset(&self) -> i64 {
+        self.text.len() as i64 - i64::from(self.span.len())
+    }
+}
+
+/// The statements to re-parse, in the coordinates of the previous source text
+#[derive(Debug, Clone, Copy)]
+struct Region {
+    /// Span of the enclosing function body, `None` for the top level
+    body: Option<Span>,
+    /// Grammar context of the statement list
+    ctx: Context,
+    /// Range of the replaced statements in the statement list
+    start_index: usize,
+    end_index: usize,
+    /// Source text covered by the replaced statements
+    span: Span,
+    /// Start of the statement following the region, or the end of the statement list
+    next_start: u32,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse the source text after `edit` was applied to the source text of `previous`.
+    ///
+    /// Only the statements around the edit are parsed again,
+    /// inside the innermost function body containing the edit or at the top level.
+    /// All other nodes and comments of `previous` are reused with their spans moved by the edit.
+    ///
+    /// Falls back to a full [`Parser::parse`] when `previous` has errors,
+    /// for [`oxc_ast::ModuleKind::Unambiguous`] source types, or when the re-parsed statements do not line up with the reused ones,
+    /// e.g. after inserting an unmatched `{`.
+    #[must_use]
+    pub fn reparse(mut self, previous: ParserReturn<'a>, edit: TextEdit) -> ParserReturn<'a> {
+        let ParserReturn { mut program, errors, trivias, panicked, module_kind } = previous;
+        let offset = edit.offset();
+        #[allow(clippy::cast_possible_wrap)]
+        let new_len = self.source_text.len() as i64;
+        if panicked
+            || self.unambiguous
+            || !errors.is_empty()
+            || program.source_type != self.source_type
+            || edit.span.end > program.span.end
+            || i64::from(program.span.end) + offset != new_len
+        {
+            return self.parse();
+        }
+
+        let ctx = self.ctx;
+        let Some(region) = Self::find_region(&mut program, edit, ctx) else {
+            return self.parse();
+        };
+
+        program.shift_span(region.span.end, offset);
+        // The start of an empty region, e.g. in `function f() {}`, is before the inserted text
+        let new_span =
+            Span::new(region.span.start, shifted(region.span, region.span.end, offset).end);
+        let next_start = Span::new(region.next_start, region.next_start);
+        let next_start = shifted(next_start, region.span.end, offset).start;
+
+        let Ok(Some(statements)) = self.parse_region(new_span, next_start, region.ctx) else {
+            self.reset(ctx);
+            return self.parse();
+        };
+        // `"use strict"` may have become a directive
+        if region.start_index == 0 && Self::starts_with_string_literal(&statements) {
+            self.reset(ctx);
+            return self.parse();
+        }
+
+        let mut replace = ReplaceStatements {
+            target: region.body.map(|span| shifted(span, region.span.end, offset)),
+            start_index: region.start_index,
+            end_index: region.end_index,
+            statements: Some(statements),
+        };
+        replace.visit_program(&mut program);
+
+        let mut trivias = Rc::try_unwrap(trivias).unwrap_or_else(|trivias| (*trivias).clone());
+        let new_trivias = std::mem::take(&mut self.lexer.trivia_builder).build();
+        trivias.splice(region.span, offset, &new_trivias);
+
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        ParserReturn { program, errors, trivias: Rc::new(trivias), panicked: false, module_kind }
+    }
+
+    /// Find the statements affected by `edit`
+    fn find_region(program: &mut Program<'a>, edit: TextEdit, ctx: Context) -> Option<Region> {
+        let mut finder = EnclosingFunctionBody { edit: edit.span, ctx, region: None };
+        finder.visit_program(program);
+        if finder.region.is_some() {
+            return finder.region;
+        }
+        if !program.directives.is_empty()
+            && program.directives.iter().any(|d| d.span.end >= edit.span.start)
+        {
+            return None;
+        }
+        Some(Region::new(&program.body, edit.span, None, program.span, ctx))
+    }
+
+    /// Parse the statements in `span`, returns `None` if they do not end at `next_start`
+    fn parse_region(
+        &mut self,
+        span: Span,
+        next_start: u32,
+        ctx: Context,
+    ) -> Result<Option<Vec<'a, Statement<'a>>>> {
+        self.ctx = ctx;
+        self.lexer.seek(span.start);
+        self.bump_any();
+        let mut statements = self.ast.new_vec();
+        while self.cur_token().start < span.end && !self.at(Kind::Eof) {
+            let stmt = self.parse_statement_or_recover(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            })?;
+            statements.push(stmt);
+        }
+        Ok((self.cur_token().start == next_start).then_some(statements))
+    }
+
+    fn starts_with_string_literal(statements: &[Statement<'a>]) -> bool {
+        matches!(
+            statements.first(),
+            Some(Statement::ExpressionStatement(stmt))
+                if matches!(stmt.expression, Expression::StringLiteral(_))
+        )
+    }
+
+    /// Start over for a full parse
+    fn reset(&mut self, ctx: Context) {
+        let allocator = self.ast.allocator;
+        self.lexer = Lexer::new(allocator, self.source_text, self.source_type);
+        self.errors.clear();
+        self.token = Token::default();
+        self.prev_token_end = 0;
+        self.state = ParserState::new(allocator);
+        self.ctx = ctx;
+    }
+}
+
+impl Region {
+    /// The statements of `statements` around `edit`, with one extra statement on each side
+    /// because automatic semicolon insertion may join them with the edited text.
+    fn new(
+        statements: &[Statement],
+        edit: Span,
+        body: Option<Span>,
+        list_span: Span,
+        ctx: Context,
+    ) -> Self {
+        let len = statements.len();
+        let first = statements.iter().position(|stmt| stmt.span().end >= edit.start).unwrap_or(len);
+        let last = statements.iter().rposition(|stmt| stmt.span().start <= edit.end);
+        let start_index = first.saturating_sub(1);
+        let end_index = last.map_or(1, |last| last + 2).min(len);
+        let start =
+            if start_index == 0 { list_span.start } else { statements[start_index].span().start };
+        let (end, next_start) = if end_index == len {
+            (list_span.end, list_span.end)
+        } else {
+            let end = statements[end_index - 1].span().end.max(edit.end);
+            (end, statements[end_index].span().start)
+        };
+        Self { body, ctx, start_index, end_index, span: Span::new(start, end), next_start }
+    }
+}
+
+fn shifted(mut span: Span, from: u32, offset: i64) -> Span {
+    span.shift_span(from, offset);
+    span
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Find the innermost function body containing the edit, excluding its `{` and `}`
+struct EnclosingFunctionBody {
+    edit: Span,
+    ctx: Context,
+    region: Option<Region>,
+}
+
+impl EnclosingFunctionBody {
+    fn visit_body(&mut self, body: &FunctionBody, r#async: bool, generator: bool) -> bool {
+        let inner = Span::new(body.span.start + 1, body.span.end - 1);
+        if !contains(inner, self.edit)
+            || body.directives.iter().any(|d| d.span.end >= self.edit.start)
+        {
+            return false;
+        }
+        let ctx =
+            self.ctx.and_return(true).and_await(r#async).and_yield(generator).and_top_level(false);
+        self.region = Some(Region::new(&body.statements, self.edit, Some(body.span), inner, ctx));
+        true
+    }
+}
+
+impl<'a, 'b> VisitMut<'a, 'b> for EnclosingFunctionBody {
+    fn visit_statement(&mut self, stmt: &'b mut Statement<'a>) {
+        if contains(stmt.span(), self.edit) {
+            self.visit_statement_match(stmt);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &'b mut Expression<'a>) {
+        if contains(expr.span(), self.edit) {
+            self.visit_expression_match(expr);
+        }
+    }
+
+    fn visit_function(&mut self, func: &'b mut Function<'a>) {
+        if let Some(body) = &mut func.body {
+            if self.visit_body(body, func.r#async, func.generator) {
+                self.visit_function_body(body);
+            }
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &'b mut ArrowExpression<'a>) {
+        if !expr.expression && self.visit_body(&expr.body, expr.r#async, false) {
+            self.visit_function_body(&mut expr.body);
+        }
+    }
+}
+
+/// Replace the statements of a region with the re-parsed ones
+struct ReplaceStatements<'a> {
+    /// Span of the function body in the new source text, `None` for the top level
+    target: Option<Span>,
+    start_index: usize,
+    end_index: usize,
+    statements: Option<Vec<'a, Statement<'a>>>,
+}
+
+impl<'a> ReplaceStatements<'a> {
+    fn replace(&mut self, list: &mut Vec<'a, Statement<'a>>) {
+        if let Some(statements) = self.statements.take() {
+            list.splice(self.start_index..self.end_index, statements);
+        }
+    }
+}
+
+impl<'a, 'b> VisitMut<'a, 'b> for ReplaceStatements<'a> {
+    fn visit_program(&mut self, program: &'b mut Program<'a>) {
+        match self.target {
+            None => self.replace(&mut program.body),
+            Some(_) => self.visit_statements(&mut program.body),
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &'b mut Statement<'a>) {
+        if self.statements.is_some()
+            && self.target.map_or(false, |target| contains(stmt.span(), target))
+        {
+            self.visit_statement_match(stmt);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &'b mut Expression<'a>) {
+        if self.statements.is_some()
+            && self.target.map_or(false, |target| contains(expr.span(), target))
+        {
+            self.visit_expression_match(expr);
+        }
+    }
+
+    fn visit_function_body(&mut self, body: &'b mut FunctionBody<'a>) {
+        if self.target == Some(body.span) {
+            self.replace(&mut body.statements);
+        } else {
+            self.visit_statements(&mut body.statements);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{SourceType, Span};
+
+    use super::TextEdit;
+    use crate::Parser;
+
+    #[test]
+    fn reparse() {
+        let js = SourceType::default();
+        let ts = *SourceType::default().with_typescript(true);
+        let cases = [
+            // inside a function body
+            (
+                js,
+                "function f() {\n  a();\n  let b = 1;\n  c();\n  return b; // b\n}\nfoo(); /* foo */",
+                "1",
+                "22",
+            ),
+            (js, "const g = async () => {\n  await a;\n  b;\n};\nbar();", "b", "await c"),
+            // top level, before and after other statements
+            (js, "a;\nb;\nc;\nd;\ne; // e", "c", "x + y"),
+            (js, "a;\nb;\n", "\n", "\nc();"),
+            (js, "// first\nfoo();\nbar();", "foo", "foo.bar"),
+            // statements joined by automatic semicolon insertion
+            (js, "a\nb\nc", "b", "(b)"),
+            // unmatched `{` requires a full parse
+            (js, "function f() {\n  a;\n}\nb;", "a;", "if (a) {"),
+            // a new directive
+            (js, "x;\ny;", "x", "'use strict'"),
+            // TypeScript declarations after the edit
+            (ts, "a;\nenum E { A = 1 }\nexport enum F { B }\nc;", "a", "aa"),
+            (ts, "a;\nexport type T = { b: B };\ntype U = 1;\nc;", "a", "x.y"),
+            (ts, "a;\nexport const enum E { A }\ndeclare enum F {}\nc;", "c", "c = 1"),
+            (ts, "a;\ndeclare module 'm' { export type T = 1; }\ndeclare global {}", "a", "aa"),
+            (ts, "a;\nnamespace N { enum E {} }\ntype C<T> = T extends U ? 1 : 2;", "a", "b"),
+        ];
+        let assert_reparse = |source_type, source: &str, edit: TextEdit| {
+            let allocator = Allocator::default();
+            let (start, end) = (edit.span.start as usize, edit.span.end as usize);
+            let new_source = format!("{}{}{}", &source[..start], edit.text, &source[end..]);
+            let previous = Parser::new(&allocator, source, source_type).parse();
+            let ret = Parser::new(&allocator, &new_source, source_type).reparse(previous, edit);
+            let expected = Parser::new(&allocator, &new_source, source_type).parse();
+            assert_eq!(ret.program, expected.program, "{new_source}");
+            assert_eq!(ret.errors.len(), expected.errors.len(), "{new_source}");
+            assert_eq!(ret.trivias.comments(), expected.trivias.comments(), "{new_source}");
+        };
+        for (source_type, source, from, to) in cases {
+            let start = u32::try_from(source.find(from).unwrap()).unwrap();
+            let end = start + u32::try_from(from.len()).unwrap();
+            assert_reparse(source_type, source, TextEdit::new(Span::new(start, end), to));
+        }
+        // inserting into an empty function body
+        assert_reparse(js, "function f() {}\nb;", TextEdit::new(Span::new(14, 14), "x"));
+    }
+}
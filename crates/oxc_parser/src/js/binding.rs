@@ -3,7 +3,7 @@ use oxc_ast::{ast::*, Span};
 use oxc_diagnostics::Result;
 
 use super::list::{ArrayPatternList, ObjectPatternProperties};
-use crate::{diagnostics, lexer::Kind, list::SeparatedList, Context, Parser};
+use crate::{diagnostics, lexer::Kind, list::SeparatedList, Context, EcmaVersion, Parser};
 
 impl<'a> Parser<'a> {
     /// Destructuring Binding Patterns
@@ -14,12 +14,16 @@ impl<'a> Parser<'a> {
     ///     `ObjectBindingPattern`
     ///     `ArrayBindingPattern`
     pub(crate) fn parse_binding_pattern(&mut self) -> Result<(BindingPattern<'a>, bool)> {
+        self.nested(Self::parse_binding_pattern_impl)
+    }
+
+    fn parse_binding_pattern_impl(&mut self) -> Result<(BindingPattern<'a>, bool)> {
         let kind = match self.cur_kind() {
             Kind::LCurly => self.parse_object_binding_pattern(),
             Kind::LBrack => self.parse_array_binding_pattern(),
             _ => self.parse_binding_pattern_identifier(),
         }?;
-        if self.ts_enabled() {
+        if self.types_enabled() {
             let optional = self.eat(Kind::Question);
             let (type_annotation, definite) = self.parse_ts_variable_annotation()?;
             Ok((self.ast.binding_pattern(kind, type_annotation, optional), definite))
@@ -36,14 +40,18 @@ impl<'a> Parser<'a> {
     fn parse_object_binding_pattern(&mut self) -> Result<BindingPatternKind<'a>> {
         let span = self.start_span();
         let properties = ObjectPatternProperties::parse(self)?.elements;
-        Ok(self.ast.object_pattern(self.end_span(span), properties))
+        let span = self.end_span(span);
+        self.check_ecma_version(EcmaVersion::ES2015, "Destructuring", span);
+        Ok(self.ast.object_pattern(span, properties))
     }
 
     /// Section 14.3.3 Array Binding Pattern
     fn parse_array_binding_pattern(&mut self) -> Result<BindingPatternKind<'a>> {
         let span = self.start_span();
         let elements = ArrayPatternList::parse(self)?.elements;
-        Ok(self.ast.array_pattern(self.end_span(span), elements))
+        let span = self.end_span(span);
+        self.check_ecma_version(EcmaVersion::ES2015, "Destructuring", span);
+        Ok(self.ast.array_pattern(span, elements))
     }
 
     /// Section 14.3.3 Binding Rest Property
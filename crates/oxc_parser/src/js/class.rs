@@ -1,9 +1,9 @@
 use oxc_allocator::{Box, Vec};
-use oxc_ast::{ast::*, syntax_directed_operations::PropName, Span};
+use oxc_ast::{ast::*, syntax_directed_operations::PropName, GetSpan, Span};
 use oxc_diagnostics::Result;
 
 use super::list::ClassElements;
-use crate::{diagnostics, lexer::Kind, list::NormalList, Parser, StatementContext};
+use crate::{diagnostics, lexer::Kind, list::NormalList, EcmaVersion, Parser, StatementContext};
 
 type Extends<'a> =
     Vec<'a, (Expression<'a>, Option<Box<'a, TSTypeParameterInstantiation<'a>>>, Span)>;
@@ -54,6 +54,7 @@ impl<'a> Parser<'a> {
         r#type: ClassType,
         modifiers: Modifiers<'a>,
     ) -> Result<Box<'a, Class<'a>>> {
+        self.check_ecma_version(EcmaVersion::ES2015, "Classes", self.cur_token().span());
         self.bump_any(); // advance `class`
 
         let decorators = self.state.consume_decorators();
@@ -66,7 +67,7 @@ impl<'a> Parser<'a> {
         };
 
         let type_parameters =
-            if self.ts_enabled() { self.parse_ts_type_parameters()? } else { None };
+            if self.types_enabled() { self.parse_ts_type_parameters()? } else { None };
         let (extends, implements) = self.parse_heritage_clause()?;
         let mut super_class = None;
         let mut super_type_parameters = None;
@@ -177,7 +178,7 @@ impl<'a> Parser<'a> {
         let accessibility = modifier.accessibility();
 
         let declare = modifier.declare();
-        let readonly = modifier.readonly();
+        let mut readonly = modifier.readonly();
         let r#override = modifier.r#override();
         let r#abstract = modifier.r#abstract();
         let mut r#static = modifier.r#static();
@@ -190,7 +191,10 @@ impl<'a> Parser<'a> {
             }
 
             // static ...
-            if self.peek_kind().is_class_element_name_start() || self.peek_at(Kind::Star) {
+            if self.peek_kind().is_class_element_name_start()
+                || self.peek_at(Kind::Star)
+                || self.peek_at_flow_variance()
+            {
                 self.bump(Kind::Static);
                 r#static = true;
             } else {
@@ -198,6 +202,10 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // Flow +x: T / -x: T
+        let variance = key_name.is_none() && self.at_flow_variance();
+        readonly |= variance && self.eat_flow_variance();
+
         // async ...
         if key_name.is_none() && self.at(Kind::Async) && !self.peek_at(Kind::Question) {
             if self.peek_kind().is_class_element_name_start() || self.peek_at(Kind::Star) {
@@ -261,6 +269,9 @@ impl<'a> Parser<'a> {
         // LAngle for start of type parameters `foo<T>`
         //                                         ^
         if self.at(Kind::LParen) || self.at(Kind::LAngle) || r#async || generator {
+            if variance {
+                self.error(diagnostics::FlowVariance(self.end_span(span)));
+            }
             let definition = self.parse_class_method_definition(
                 span,
                 kind,
@@ -321,6 +332,11 @@ impl<'a> Parser<'a> {
         match self.cur_kind() {
             Kind::PrivateIdentifier => {
                 let private_ident = self.parse_private_identifier();
+                self.check_ecma_version(
+                    EcmaVersion::ES2022,
+                    "Private class members",
+                    private_ident.span,
+                );
                 Ok((PropertyKey::PrivateIdentifier(self.ast.alloc(private_ident)), false))
             }
             _ => self.parse_property_name(),
@@ -409,12 +425,15 @@ impl<'a> Parser<'a> {
         optional: bool,
         definite: bool,
     ) -> Result<ClassElement<'a>> {
+        if !declare && !r#abstract {
+            self.check_ecma_version(EcmaVersion::ES2022, "Class fields", key.span());
+        }
         let type_annotation =
-            if self.ts_enabled() { self.parse_ts_type_annotation()? } else { None };
+            if self.types_enabled() { self.parse_ts_type_annotation()? } else { None };
         let value = if self.eat(Kind::Eq) {
             // let current_flags = self.scope.current_flags();
             // self.scope.set_current_flags(self.scope.current_flags());
-            let expr = self.parse_expression()?;
+            let expr = self.parse_expr()?;
             // self.scope.set_current_flags(current_flags);
             Some(expr)
         } else {
@@ -450,12 +469,20 @@ impl<'a> Parser<'a> {
     /// `ClassStaticBlockStatementList` :
     ///    `StatementList`[~Yield, +Await, ~Return]
     fn parse_class_static_block(&mut self, span: Span) -> Result<ClassElement<'a>> {
+        let static_span = Span::new(span.start, self.prev_token_end);
+        self.check_ecma_version(EcmaVersion::ES2022, "Class static blocks", static_span);
         let has_await = self.ctx.has_await();
         let has_yield = self.ctx.has_yield();
         let has_return = self.ctx.has_return();
-        self.ctx = self.ctx.and_await(true).and_yield(false).and_return(false);
+        let top_level = self.ctx.has_top_level();
+        self.ctx = self.ctx.and_await(true).and_yield(false).and_return(false).and_top_level(false);
         let block = self.parse_block()?;
-        self.ctx = self.ctx.and_await(has_await).and_yield(has_yield).and_return(has_return);
+        self.ctx = self
+            .ctx
+            .and_await(has_await)
+            .and_yield(has_yield)
+            .and_return(has_return)
+            .and_top_level(top_level);
         Ok(self.ast.static_block(self.end_span(span), block.unbox().body))
     }
 
@@ -2,7 +2,7 @@ use oxc_allocator::Box;
 use oxc_ast::{ast::*, GetSpan, Span};
 use oxc_diagnostics::Result;
 
-use crate::{diagnostics, lexer::Kind, Parser, StatementContext};
+use crate::{diagnostics, lexer::Kind, EcmaVersion, Parser, StatementContext};
 
 #[derive(Clone, Debug, Copy, Eq, PartialEq)]
 pub enum VariableDeclarationParent {
@@ -53,6 +53,10 @@ impl<'a> Parser<'a> {
             Kind::Let => VariableDeclarationKind::Let,
             _ => return Err(self.unexpected()),
         };
+        if kind != VariableDeclarationKind::Var {
+            let feature = if kind == VariableDeclarationKind::Let { "`let`" } else { "`const`" };
+            self.check_ecma_version(EcmaVersion::ES2015, feature, self.cur_token().span());
+        }
         self.bump_any();
 
         let mut declarations = self.ast.new_vec();
@@ -13,19 +13,20 @@ use crate::{
     diagnostics,
     lexer::{Kind, TokenValue},
     list::SeparatedList,
-    Context, Parser,
+    regexp::PatternParser,
+    Context, EcmaVersion, Parser,
 };
 
 impl<'a> Parser<'a> {
     pub(crate) fn parse_paren_expression(&mut self) -> Result<Expression<'a>> {
         self.expect(Kind::LParen)?;
-        let expression = self.parse_expression()?;
-        self.expect(Kind::RParen)?;
+        let expression = self.parse_expr()?;
+        self.expect_closing(Kind::RParen)?;
         Ok(expression)
     }
 
     /// Section [Expression](https://tc39.es/ecma262/#sec-ecmascript-language-expressions)
-    pub(crate) fn parse_expression(&mut self) -> Result<Expression<'a>> {
+    pub(crate) fn parse_expr(&mut self) -> Result<Expression<'a>> {
         let span = self.start_span();
 
         let lhs = self.parse_assignment_expression_base()?;
@@ -93,6 +94,9 @@ impl<'a> Parser<'a> {
             TokenValue::String(value) => value,
             _ => "",
         };
+        if self.cur_token().escaped {
+            self.check_code_point_escapes();
+        }
         self.bump_remap(kind);
         (self.end_span(span), Atom::from(name))
     }
@@ -172,7 +176,10 @@ impl<'a> Parser<'a> {
                 let span = self.start_span();
                 let identifier = self.parse_keyword_identifier(Kind::Import);
                 match self.cur_kind() {
-                    Kind::Dot => self.parse_meta_property(span, identifier),
+                    Kind::Dot => {
+                        self.state.has_module_syntax = true;
+                        self.parse_meta_property(span, identifier)
+                    }
                     Kind::LParen => self.parse_import_expression(span),
                     _ => Err(self.unexpected()),
                 }
@@ -190,6 +197,9 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_parenthesized_expression(&mut self, span: Span) -> Result<Expression<'a>> {
+        if self.flow_enabled() && !self.peek_at(Kind::RParen) {
+            return self.parse_flow_parenthesized_expression(span);
+        }
         let has_in = self.ctx.has_in();
         self.ctx = self.ctx.and_in(true);
         let list = SequenceExpressionList::parse(self)?;
@@ -279,6 +289,14 @@ impl<'a> Parser<'a> {
         };
         let value = self.cur_token().value.as_number();
         let raw = self.cur_src();
+        // `0o17` and `0b1`, `017` is a legacy octal literal
+        if matches!(base, NumberBase::Binary | NumberBase::Octal)
+            && matches!(raw.as_bytes().get(1), Some(b'o' | b'O' | b'b' | b'B'))
+        {
+            let span = self.cur_token().span();
+            self.check_ecma_version(EcmaVersion::ES2015, "Binary and octal literals", span);
+        }
+        self.check_numeric_separators(raw);
         self.bump_any();
         Ok(NumberLiteral::new(self.end_span(span), value, raw, base))
     }
@@ -289,23 +307,68 @@ impl<'a> Parser<'a> {
             kind if kind.is_number() => self.cur_token().value.as_bigint(),
             _ => return Err(self.unexpected()),
         };
+        self.check_ecma_version(EcmaVersion::ES2020, "BigInt", self.cur_token().span());
+        self.check_numeric_separators(self.cur_src());
         self.bump_any();
         Ok(BigintLiteral { span: self.end_span(span), value })
     }
 
-    pub(crate) fn parse_literal_regexp(&mut self) -> Result<RegExpLiteral> {
+    fn check_numeric_separators(&mut self, raw: &str) {
+        if raw.contains('_') {
+            let span = self.cur_token().span();
+            self.check_ecma_version(EcmaVersion::ES2021, "Numeric separators", span);
+        }
+    }
+
+    /// Report `\u{41}` escapes in the current string or identifier
+    fn check_code_point_escapes(&mut self) {
+        let mut chars = self.cur_src().chars();
+        while let Some(c) = chars.next() {
+            // The character after a backslash is escaped, `\\u{41}` is not an escape sequence
+            if c == '\\' && chars.next() == Some('u') && chars.as_str().starts_with('{') {
+                let span = self.cur_token().span();
+                self.check_ecma_version(EcmaVersion::ES2015, "Unicode code point escapes", span);
+                return;
+            }
+        }
+    }
+
+    pub(crate) fn parse_literal_regexp(&mut self) -> Result<RegExpLiteral<'a>> {
         let span = self.start_span();
         let r = match self.cur_kind() {
             Kind::RegExp => self.cur_token().value.as_regex(),
             _ => return Err(self.unexpected()),
         };
-        let pattern = Atom::from(r.pattern);
+        let pattern_text = r.pattern;
         let flags = r.flags;
+        for (flag, version, feature) in [
+            (RegExpFlags::U, EcmaVersion::ES2015, "RegExp flag `u`"),
+            (RegExpFlags::Y, EcmaVersion::ES2015, "RegExp flag `y`"),
+            (RegExpFlags::S, EcmaVersion::ES2018, "RegExp flag `s`"),
+            (RegExpFlags::D, EcmaVersion::ES2022, "RegExp flag `d`"),
+            (RegExpFlags::V, EcmaVersion::ES2024, "RegExp flag `v`"),
+        ] {
+            if flags.contains(flag) {
+                self.check_ecma_version(version, feature, self.cur_token().span());
+            }
+        }
         self.bump_any();
+        let pattern =
+            match PatternParser::new(self.ast.allocator, pattern_text, flags, span.start + 1)
+                .target(self.target)
+                .parse()
+            {
+                Ok(pattern) => Some(self.ast.alloc(pattern)),
+                Err(errors) => {
+                    self.errors.extend(errors);
+                    None
+                }
+            };
         Ok(RegExpLiteral {
             span: self.end_span(span),
             value: EmptyObject {},
-            regex: RegExp { pattern, flags },
+            regex: RegExp { pattern: Atom::from(pattern_text), flags },
+            pattern,
         })
     }
 
@@ -317,6 +380,7 @@ impl<'a> Parser<'a> {
             unreachable!()
         };
         let span = self.start_span();
+        self.check_code_point_escapes();
         self.bump_any();
         Ok(StringLiteral { span: self.end_span(span), value: value.into() })
     }
@@ -341,6 +405,7 @@ impl<'a> Parser<'a> {
     ///     `SubstitutionTemplate`[?Yield, ?Await, ?Tagged]
     fn parse_template_literal(&mut self, tagged: bool) -> Result<TemplateLiteral<'a>> {
         let span = self.start_span();
+        self.check_ecma_version(EcmaVersion::ES2015, "Template literals", self.cur_token().span());
         let mut expressions = self.ast.new_vec();
         let mut quasis = self.ast.new_vec();
         match self.cur_kind() {
@@ -350,7 +415,7 @@ impl<'a> Parser<'a> {
             Kind::TemplateHead => {
                 quasis.push(self.parse_template_element(tagged));
                 // TemplateHead Expression[+In, ?Yield, ?Await]
-                let expr = self.with_context(Context::In, Self::parse_expression)?;
+                let expr = self.with_context(Context::In, Self::parse_expr)?;
                 expressions.push(expr);
                 self.re_lex_template_substitution_tail();
                 loop {
@@ -365,7 +430,7 @@ impl<'a> Parser<'a> {
                         }
                         _ => {
                             // TemplateMiddle Expression[+In, ?Yield, ?Await]
-                            let expr = self.with_context(Context::In, Self::parse_expression)?;
+                            let expr = self.with_context(Context::In, Self::parse_expr)?;
                             expressions.push(expr);
                             self.re_lex_template_substitution_tail();
                         }
@@ -444,6 +509,11 @@ impl<'a> Parser<'a> {
             _ => self.parse_identifier_name()?,
         };
         let span = self.end_span(span);
+        match meta.name.as_str() {
+            "new" => self.check_ecma_version(EcmaVersion::ES2015, "`new.target`", span),
+            "import" => self.check_ecma_version(EcmaVersion::ES2020, "`import.meta`", span),
+            _ => {}
+        }
         Ok(self.ast.meta_property(span, meta, property))
     }
 
@@ -529,6 +599,13 @@ impl<'a> Parser<'a> {
                 Kind::LBrack => self.parse_computed_member_expression(lhs_span, lhs, false)?,
                 Kind::Dot => self.parse_static_member_expression(lhs_span, lhs, false)?,
                 Kind::QuestionDot => {
+                    if !self.peek_at(Kind::LParen) {
+                        self.check_ecma_version(
+                            EcmaVersion::ES2020,
+                            "Optional chaining",
+                            self.cur_token().span(),
+                        );
+                    }
                     *in_optional_chain = true;
                     match self.peek_kind() {
                         Kind::LBrack => {
@@ -558,7 +635,7 @@ impl<'a> Parser<'a> {
                         };
                     self.parse_tagged_template(lhs_span, expr, *in_optional_chain, type_parameters)?
                 }
-                Kind::LAngle | Kind::ShiftLeft if self.ts_enabled() => {
+                Kind::LAngle | Kind::ShiftLeft if self.types_enabled() => {
                     if let Some(arguments) = self.parse_ts_type_arguments_in_expression() {
                         lhs = Expression::TSInstantiationExpression(self.ast.alloc(
                             TSInstantiationExpression {
@@ -612,7 +689,7 @@ impl<'a> Parser<'a> {
         self.bump_any(); // advance `[`
         let has_in = self.ctx.has_in();
         self.ctx = self.ctx.and_in(true);
-        let property = self.parse_expression()?;
+        let property = self.parse_expr()?;
         self.ctx = self.ctx.and_in(has_in);
         self.expect(Kind::RBrack)?;
         Ok(self.ast.computed_member_expression(self.end_span(lhs_span), lhs, property, optional))
@@ -670,7 +747,11 @@ impl<'a> Parser<'a> {
         loop {
             let mut type_arguments = None;
             lhs = self.parse_member_expression_rhs(lhs_span, lhs, in_optional_chain)?;
+            let question_dot = self.cur_token().span();
             let optional_call = self.eat(Kind::QuestionDot);
+            if optional_call {
+                self.check_ecma_version(EcmaVersion::ES2020, "Optional chaining", question_dot);
+            }
             *in_optional_chain = if optional_call { true } else { *in_optional_chain };
 
             if optional_call {
@@ -793,7 +874,7 @@ impl<'a> Parser<'a> {
         if self.cur_kind().is_update_operator() {
             let operator = map_update_operator(self.cur_kind());
             self.bump_any();
-            let argument = self.parse_unary_expression_base(lhs_span)?;
+            let argument = self.nested(|p| p.parse_unary_expression_base(lhs_span))?;
             let argument = SimpleAssignmentTarget::cover(argument, self)?;
             return Ok(self.ast.update_expression(
                 self.end_span(lhs_span),
@@ -815,7 +896,7 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         let operator = map_unary_operator(self.cur_kind());
         self.bump_any();
-        let argument = self.parse_unary_expression_base(span)?;
+        let argument = self.nested(|p| p.parse_unary_expression_base(span))?;
         Ok(self.ast.unary_expression(self.end_span(span), operator, true, argument))
     }
 
@@ -827,6 +908,7 @@ impl<'a> Parser<'a> {
 
         let lhs = if self.ctx.has_in() && self.at(Kind::PrivateIdentifier) {
             let left = self.parse_private_identifier();
+            self.check_ecma_version(EcmaVersion::ES2022, "Private field `in` checks", left.span);
             self.expect(Kind::In)?;
             let right = self.parse_unary_expression_base(lhs_span)?;
             Expression::PrivateInExpression(self.ast.alloc(PrivateInExpression {
@@ -881,7 +963,7 @@ impl<'a> Parser<'a> {
                     break;
                 }
                 self.bump_any();
-                let type_annotation = self.parse_ts_type()?;
+                let type_annotation = self.parse_type()?;
                 let span = self.end_span(lhs_span);
                 lhs = if kind == Kind::As {
                     self.ast.ts_as_expression(span, lhs, type_annotation)
@@ -891,8 +973,23 @@ impl<'a> Parser<'a> {
                 continue;
             }
 
+            match kind {
+                Kind::Star2 => self.check_ecma_version(
+                    EcmaVersion::ES2016,
+                    "Exponentiation operator",
+                    self.cur_token().span(),
+                ),
+                Kind::Question2 => self.check_ecma_version(
+                    EcmaVersion::ES2020,
+                    "Nullish coalescing",
+                    self.cur_token().span(),
+                ),
+                _ => {}
+            }
             self.bump_any(); // bump operator
-            let rhs = self.parse_binary_or_logical_expression_base(left_binding_power)?;
+            // Only the right operand is nested, not the left operands of a chain such as `a + b + c`
+            let rhs =
+                self.nested(|p| p.parse_binary_or_logical_expression_base(left_binding_power))?;
 
             lhs = if kind.is_logical_operator() {
                 self.ast.logical_expression(
@@ -938,6 +1035,10 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn parse_assignment_expression_base(&mut self) -> Result<Expression<'a>> {
+        self.nested(Self::parse_assignment_expression_impl)
+    }
+
+    fn parse_assignment_expression_impl(&mut self) -> Result<Expression<'a>> {
         match self.is_parenthesized_arrow_function() {
             IsParenthesizedArrowFunction::True => {
                 return self.parse_parenthesized_arrow_function();
@@ -1013,6 +1114,19 @@ impl<'a> Parser<'a> {
         //    ArrayAssignmentPattern
         let left = AssignmentTarget::cover(lhs, self)?;
 
+        match self.cur_kind() {
+            Kind::Star2Eq => self.check_ecma_version(
+                EcmaVersion::ES2016,
+                "Exponentiation operator",
+                self.cur_token().span(),
+            ),
+            Kind::Amp2Eq | Kind::Pipe2Eq | Kind::Question2Eq => self.check_ecma_version(
+                EcmaVersion::ES2021,
+                "Logical assignment",
+                self.cur_token().span(),
+            ),
+            _ => {}
+        }
         self.bump_any();
 
         let right = self.parse_assignment_expression_base()?;
@@ -1041,10 +1155,17 @@ impl<'a> Parser<'a> {
         let has_await = self.ctx.has_await();
         if !has_await {
             self.error(diagnostics::AwaitExpression(Span::new(span.start, span.start + 5)));
+        } else if self.ctx.has_top_level() {
+            let span = Span::new(span.start, span.start + 5);
+            self.check_ecma_version(EcmaVersion::ES2022, "Top-level await", span);
         }
         self.ctx = self.ctx.and_await(true);
-        let argument = self.parse_unary_expression_base(lhs_span)?;
+        let argument = self.nested(|p| p.parse_unary_expression_base(lhs_span))?;
         self.ctx = self.ctx.and_await(has_await);
+        // `await` followed by an operand, not `await` used as an identifier in a script
+        if has_await && self.ctx.has_top_level() {
+            self.state.has_module_syntax = true;
+        }
         Ok(self.ast.await_expression(self.end_span(span), argument))
     }
 
@@ -3,7 +3,9 @@ use oxc_ast::{ast::*, AstBuilder, GetSpan, Span};
 use oxc_diagnostics::Result;
 
 use super::list::FormalParameterList;
-use crate::{diagnostics, lexer::Kind, list::SeparatedList, Context, Parser, StatementContext};
+use crate::{
+    diagnostics, lexer::Kind, list::SeparatedList, Context, EcmaVersion, Parser, StatementContext,
+};
 
 type ArrowFunctionHead<'a> = (
     Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
@@ -51,14 +53,19 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn parse_function_body(&mut self) -> Result<Box<'a, FunctionBody<'a>>> {
+        if self.module_lexer.is_some() {
+            return self.skip_function_body();
+        }
         let span = self.start_span();
         self.expect(Kind::LCurly)?;
 
         let (directives, statements) = self.with_context(Context::Return, |p| {
-            p.parse_directives_and_statements(/* is_top_level */ false)
+            p.parse_directives_and_statements(
+                /* is_top_level */ false, /* has_directives */ true,
+            )
         })?;
 
-        self.expect(Kind::RCurly)?;
+        self.expect_closing(Kind::RCurly)?;
         Ok(self.ast.function_body(self.end_span(span), directives, statements))
     }
 
@@ -71,6 +78,15 @@ impl<'a> Parser<'a> {
         Ok(self.ast.formal_parameters(self.end_span(span), params_kind, elements))
     }
 
+    /// Report the trailing comma of a parameter or argument list, e.g. `f(a,)`
+    pub(crate) fn check_trailing_comma(&mut self) {
+        if self.target < EcmaVersion::ES2017 && self.at(Kind::Comma) && self.peek_at(Kind::RParen) {
+            let span = self.cur_token().span();
+            let feature = "Trailing commas in parameter and argument lists";
+            self.check_ecma_version(EcmaVersion::ES2017, feature, span);
+        }
+    }
+
     pub(crate) fn parse_function(
         &mut self,
         span: Span,
@@ -82,7 +98,19 @@ impl<'a> Parser<'a> {
     ) -> Result<Box<'a, Function<'a>>> {
         let has_await = self.ctx.has_await();
         let has_yield = self.ctx.has_yield();
-        self.ctx = self.ctx.and_await(r#async).and_yield(generator);
+        let top_level = self.ctx.has_top_level();
+        self.ctx = self.ctx.and_await(r#async).and_yield(generator).and_top_level(false);
+
+        let feature = match (r#async, generator) {
+            (true, true) => Some((EcmaVersion::ES2018, "Async generators")),
+            (true, false) => Some((EcmaVersion::ES2017, "Async functions")),
+            (false, true) => Some((EcmaVersion::ES2015, "Generators")),
+            (false, false) => None,
+        };
+        if let Some((version, feature)) = feature {
+            let span = Span::new(span.start, self.prev_token_end);
+            self.check_ecma_version(version, feature, span);
+        }
 
         let type_parameters = self.parse_ts_type_parameters()?;
 
@@ -92,9 +120,11 @@ impl<'a> Parser<'a> {
 
         let body = if self.at(Kind::LCurly) { Some(self.parse_function_body()?) } else { None };
 
-        self.ctx = self.ctx.and_await(has_await).and_yield(has_yield);
+        self.ctx = self.ctx.and_await(has_await).and_yield(has_yield).and_top_level(top_level);
 
-        if !self.ts_enabled() && body.is_none() {
+        // Flow only allows functions without a body in `declare` statements
+        let allow_no_body = self.ts_enabled() || (self.flow_enabled() && self.ctx.has_ambient());
+        if !allow_no_body && body.is_none() {
             return Err(self.unexpected());
         }
 
@@ -228,9 +258,11 @@ impl<'a> Parser<'a> {
             self.ast.new_vec_single(formal_parameter),
         );
 
+        self.check_arrow_function_version(span, r#async);
         self.expect(Kind::Arrow)?;
 
-        self.ctx = self.ctx.and_await(r#async).and_yield(generator);
+        let top_level = self.ctx.has_top_level();
+        self.ctx = self.ctx.and_await(r#async).and_yield(generator).and_top_level(false);
         let expression = !self.at(Kind::LCurly);
         let body = if expression {
             let expr = self.parse_assignment_expression_base()?;
@@ -240,7 +272,7 @@ impl<'a> Parser<'a> {
         } else {
             self.parse_function_body()?
         };
-        self.ctx = self.ctx.and_await(has_await).and_yield(has_yield);
+        self.ctx = self.ctx.and_await(has_await).and_yield(has_yield).and_top_level(top_level);
 
         Ok(self.ast.arrow_expression(
             self.end_span(span),
@@ -353,7 +385,7 @@ impl<'a> Parser<'a> {
                 // Otherwise, a parenthesized expression with a missing inner expression
                 Kind::RParen => {
                     let kind = self.nth_kind(offset + 2);
-                    if self.ts_enabled() && kind == Kind::Colon {
+                    if self.types_enabled() && kind == Kind::Colon {
                         IsParenthesizedArrowFunction::Maybe
                     } else if matches!(kind, Kind::Arrow | Kind::LCurly) {
                         IsParenthesizedArrowFunction::True
@@ -375,6 +407,8 @@ impl<'a> Parser<'a> {
                     || self.nth_at(offset + 1, Kind::This) =>
                 {
                     match self.nth_kind(offset + 2) {
+                        // '(a: ' is a Flow type cast or a type annotation
+                        Kind::Colon if self.flow_enabled() => IsParenthesizedArrowFunction::Maybe,
                         // '(a: ' must be a type annotation
                         Kind::Colon => IsParenthesizedArrowFunction::True,
                         // * '(a = ': an initializer or a parenthesized assignment expression
@@ -461,11 +495,21 @@ impl<'a> Parser<'a> {
             self.error(diagnostics::LineterminatorBeforeArrow(self.cur_token().span()));
         }
 
+        self.check_arrow_function_version(span, r#async);
         self.expect(Kind::Arrow)?;
 
         Ok((type_parameters, params, return_type, r#async, span))
     }
 
+    fn check_arrow_function_version(&mut self, span: Span, r#async: bool) {
+        let span = Span::new(span.start, self.cur_token().end);
+        if r#async {
+            self.check_ecma_version(EcmaVersion::ES2017, "Async arrow functions", span);
+        } else {
+            self.check_ecma_version(EcmaVersion::ES2015, "Arrow functions", span);
+        }
+    }
+
     /// [ConciseBody](https://tc39.es/ecma262/#prod-ConciseBody)
     ///     [lookahead ≠ {] `ExpressionBody`[?In, ~Await]
     ///     { `FunctionBody`[~Yield, ~Await] }
@@ -481,7 +525,8 @@ impl<'a> Parser<'a> {
     ) -> Result<Expression<'a>> {
         let has_await = self.ctx.has_await();
         let has_yield = self.ctx.has_yield();
-        self.ctx = self.ctx.and_await(r#async).and_yield(false);
+        let top_level = self.ctx.has_top_level();
+        self.ctx = self.ctx.and_await(r#async).and_yield(false).and_top_level(false);
 
         let expression = !self.at(Kind::LCurly);
         let body = if expression {
@@ -493,7 +538,7 @@ impl<'a> Parser<'a> {
             self.parse_function_body()?
         };
 
-        self.ctx = self.ctx.and_await(has_await).and_yield(has_yield);
+        self.ctx = self.ctx.and_await(has_await).and_yield(has_yield).and_top_level(top_level);
 
         Ok(self.ast.arrow_expression(
             self.end_span(span),
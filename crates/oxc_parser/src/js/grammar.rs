@@ -3,7 +3,7 @@
 use oxc_ast::{ast::*, GetSpan};
 use oxc_diagnostics::Result;
 
-use crate::{diagnostics, Parser};
+use crate::{diagnostics, EcmaVersion, Parser};
 
 pub trait CoverGrammar<'a, T>: Sized {
     fn cover(value: T, p: &mut Parser<'a>) -> Result<Self>;
@@ -11,6 +11,9 @@ pub trait CoverGrammar<'a, T>: Sized {
 
 impl<'a> CoverGrammar<'a, Expression<'a>> for AssignmentTarget<'a> {
     fn cover(expr: Expression<'a>, p: &mut Parser<'a>) -> Result<Self> {
+        if matches!(expr, Expression::ArrayExpression(_) | Expression::ObjectExpression(_)) {
+            p.check_ecma_version(EcmaVersion::ES2015, "Destructuring", expr.span());
+        }
         match expr {
             Expression::ArrayExpression(array_expr) => {
                 ArrayAssignmentTarget::cover(array_expr.unbox(), p)
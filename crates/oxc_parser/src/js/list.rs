@@ -6,7 +6,7 @@ use rustc_hash::FxHashMap;
 use crate::diagnostics;
 use crate::lexer::Kind;
 use crate::list::{NormalList, SeparatedList};
-use crate::Parser;
+use crate::{EcmaVersion, Parser};
 
 /// ObjectExpression.properties
 pub struct ObjectExpressionProperties<'a> {
@@ -29,7 +29,11 @@ impl<'a> SeparatedList<'a> for ObjectExpressionProperties<'a> {
 
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
         let element = match p.cur_kind() {
-            Kind::Dot3 => p.parse_spread_element().map(ObjectProperty::SpreadProperty),
+            Kind::Dot3 => {
+                let span = p.cur_token().span();
+                p.check_ecma_version(EcmaVersion::ES2018, "Object rest/spread properties", span);
+                p.parse_spread_element().map(ObjectProperty::SpreadProperty)
+            }
             _ => p.parse_property_definition().map(ObjectProperty::Property),
         }?;
 
@@ -63,6 +67,8 @@ impl<'a> SeparatedList<'a> for ObjectPatternProperties<'a> {
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
         let element = match p.cur_kind() {
             Kind::Dot3 => {
+                let span = p.cur_token().span();
+                p.check_ecma_version(EcmaVersion::ES2018, "Object rest/spread properties", span);
                 let rest_element = p.parse_rest_element()?;
 
                 if !matches!(rest_element.argument.kind, BindingPatternKind::BindingIdentifier(_)) {
@@ -103,7 +109,10 @@ impl<'a> SeparatedList<'a> for ArrayExpressionList<'a> {
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
         let element = match p.cur_kind() {
             Kind::Comma => Ok(None),
-            Kind::Dot3 => p.parse_spread_element().map(Argument::SpreadElement).map(Some),
+            Kind::Dot3 => {
+                p.check_ecma_version(EcmaVersion::ES2015, "Spread elements", p.cur_token().span());
+                p.parse_spread_element().map(Argument::SpreadElement).map(Some)
+            }
             _ => p.parse_assignment_expression_base().map(Argument::Expression).map(Some),
         };
 
@@ -168,6 +177,7 @@ impl<'a> SeparatedList<'a> for CallArguments<'a> {
 
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
         let element = if p.at(Kind::Dot3) {
+            p.check_ecma_version(EcmaVersion::ES2015, "Spread elements", p.cur_token().span());
             let result = p.parse_spread_element().map(Argument::SpreadElement);
             if p.at(Kind::Comma) {
                 if let Ok(Argument::SpreadElement(argument)) = &result {
@@ -179,8 +189,13 @@ impl<'a> SeparatedList<'a> for CallArguments<'a> {
             p.parse_assignment_expression_base().map(Argument::Expression)
         };
         self.elements.push(element?);
+        p.check_trailing_comma();
         Ok(())
     }
+
+    fn recover_element(&mut self, p: &mut Parser<'a>, span: Span) {
+        self.elements.push(Argument::Expression(p.invalid_expression(span)));
+    }
 }
 
 pub struct SequenceExpressionList<'a> {
@@ -237,19 +252,33 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
         let readonly = modifiers.readonly();
 
         let pattern = match p.cur_kind() {
-            Kind::Dot3 => p.parse_rest_element().map(|rest| p.ast.rest_element_pattern(rest))?,
-            Kind::This if p.ts_enabled() => {
+            Kind::Dot3 => {
+                p.check_ecma_version(EcmaVersion::ES2015, "Rest parameters", p.cur_token().span());
+                p.parse_rest_element().map(|rest| p.ast.rest_element_pattern(rest))?
+            }
+            Kind::This if p.types_enabled() => {
                 p.parse_ts_this_parameter()?;
                 // don't add this to ast fow now, the ast span shouldn't be in BindingIdentifier
                 return Ok(());
             }
-            _ => p.parse_binding_element()?,
+            _ => {
+                let pattern = p.parse_binding_element()?;
+                if let BindingPatternKind::AssignmentPattern(assignment) = &pattern.kind {
+                    p.check_ecma_version(
+                        EcmaVersion::ES2015,
+                        "Default parameters",
+                        assignment.span,
+                    );
+                }
+                pattern
+            }
         };
 
         let decorators = p.state.consume_decorators();
         let formal_parameter =
             p.ast.formal_parameter(p.end_span(span), pattern, accessibility, readonly, decorators);
         self.elements.push(formal_parameter);
+        p.check_trailing_comma();
 
         Ok(())
     }
@@ -322,7 +351,7 @@ impl<'a> SeparatedList<'a> for ExportNamedSpecifiers<'a> {
         // export { type as as }       // name: `type`    type-export: `false` (aliased to `as`)
         // export { type as as as }    // name: `as`      type-export: `true`, aliased to `as`
         let mut export_kind = ImportOrExportKind::Value;
-        if p.ts_enabled() && p.at(Kind::Type) {
+        if p.types_enabled() && p.at(Kind::Type) {
             if p.peek_at(Kind::As) {
                 if p.nth_at(2, Kind::As) {
                     if p.nth_at(3, Kind::Str) || p.nth_kind(3).is_identifier_name() {
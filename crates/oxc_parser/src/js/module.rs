@@ -4,7 +4,7 @@ use oxc_diagnostics::Result;
 
 use super::function::FunctionKind;
 use super::list::{AssertEntries, ExportNamedSpecifiers, ImportSpecifierList};
-use crate::{diagnostics, lexer::Kind, list::SeparatedList, Context, Parser};
+use crate::{diagnostics, lexer::Kind, list::SeparatedList, Context, EcmaVersion, Parser};
 
 impl<'a> Parser<'a> {
     /// [Import Call](https://tc39.es/ecma262/#sec-import-calls)
@@ -25,11 +25,14 @@ impl<'a> Parser<'a> {
         self.ctx = self.ctx.and_in(has_in);
         self.bump(Kind::Comma);
         self.expect(Kind::RParen)?;
-        Ok(self.ast.import_expression(self.end_span(span), expression, arguments))
+        let span = self.end_span(span);
+        self.check_ecma_version(EcmaVersion::ES2020, "`import()`", span);
+        Ok(self.ast.import_expression(span, expression, arguments))
     }
 
     /// Section 16.2.2 Import Declaration
     pub(crate) fn parse_import_declaration(&mut self) -> Result<Statement<'a>> {
+        self.state.has_module_syntax = true;
         let span = self.start_span();
 
         self.bump_any(); // advance `import`
@@ -44,6 +47,9 @@ impl<'a> Parser<'a> {
             return Ok(Statement::Declaration(decl));
         }
 
+        let keyword = self.end_span(span);
+        self.check_ecma_version(EcmaVersion::ES2015, "`import` declarations", keyword);
+
         // `import type ...`
         let import_kind = self.parse_import_or_export_kind();
 
@@ -175,8 +181,10 @@ impl<'a> Parser<'a> {
 
     /// [Exports](https://tc39.es/ecma262/#sec-exports)
     pub(crate) fn parse_export_declaration(&mut self) -> Result<Statement<'a>> {
+        self.state.has_module_syntax = true;
         let start_span = self.start_span();
         self.bump_any(); // advance `export`
+        let keyword = self.end_span(start_span);
 
         let kind = match self.cur_kind() {
             Kind::Eq if self.ts_enabled() => self
@@ -194,7 +202,7 @@ impl<'a> Parser<'a> {
             Kind::LCurly => self
                 .parse_export_named_specifiers()
                 .map(ModuleDeclarationKind::ExportNamedDeclaration),
-            Kind::Type if self.peek_at(Kind::LCurly) && self.ts_enabled() => self
+            Kind::Type if self.peek_at(Kind::LCurly) && self.types_enabled() => self
                 .parse_export_named_specifiers()
                 .map(ModuleDeclarationKind::ExportNamedDeclaration),
             Kind::Type if self.peek_at(Kind::Star) => {
@@ -204,6 +212,13 @@ impl<'a> Parser<'a> {
                 .parse_export_named_declaration()
                 .map(ModuleDeclarationKind::ExportNamedDeclaration),
         }?;
+        if !matches!(
+            kind,
+            ModuleDeclarationKind::TSExportAssignment(_)
+                | ModuleDeclarationKind::TSNamespaceExportDeclaration(_)
+        ) {
+            self.check_ecma_version(EcmaVersion::ES2015, "`export` declarations", keyword);
+        }
         Ok(self.ast.module_declaration(self.end_span(start_span), kind))
     }
 
@@ -324,7 +339,12 @@ impl<'a> Parser<'a> {
     fn parse_export_all_declaration(&mut self) -> Result<Box<'a, ExportAllDeclaration<'a>>> {
         let export_kind = self.parse_import_or_export_kind();
         self.bump_any(); // bump `star`
+        let span = self.start_span();
         let exported = self.eat(Kind::As).then(|| self.parse_module_export_name()).transpose()?;
+        if exported.is_some() {
+            let span = self.end_span(span);
+            self.check_ecma_version(EcmaVersion::ES2020, "`export * as ns`", span);
+        }
         self.expect(Kind::From)?;
         let source = self.parse_literal_string()?;
         let assertions = self.parse_import_attributes()?;
@@ -339,7 +359,7 @@ impl<'a> Parser<'a> {
         let specifier_span = self.start_span();
         let peek_kind = self.peek_kind();
         let mut import_kind = ImportOrExportKind::Value;
-        if self.ts_enabled() && self.at(Kind::Type) {
+        if self.types_enabled() && self.at(Kind::Type) {
             if self.peek_at(Kind::As) {
                 if self.nth_at(2, Kind::As) {
                     if self.nth_kind(3).is_identifier_name() {
@@ -353,7 +373,12 @@ impl<'a> Parser<'a> {
             }
         }
 
-        if import_kind == ImportOrExportKind::Type {
+        // Flow `import { typeof A } from 'a'`
+        if self.flow_enabled() && self.at(Kind::Typeof) && peek_kind.is_identifier_name() {
+            import_kind = ImportOrExportKind::Typeof;
+        }
+
+        if import_kind != ImportOrExportKind::Value {
             self.bump_any();
         }
         let (imported, local) = if self.peek_at(Kind::As) {
@@ -388,10 +413,18 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_import_or_export_kind(&mut self) -> Option<ImportOrExportKind> {
-        if !self.ts_enabled() {
+        if !self.types_enabled() {
             return None;
         }
 
+        // import typeof ident from 'foo';
+        if self.flow_enabled()
+            && matches!(self.peek_kind(), Kind::LCurly | Kind::Star | Kind::Ident)
+            && self.eat(Kind::Typeof)
+        {
+            return Some(ImportOrExportKind::Typeof);
+        }
+
         // import type { bar } from 'foo';
         // import type * as React from 'react';
         // import type ident from 'foo';
@@ -3,7 +3,7 @@ use oxc_ast::{ast::*, Span};
 use oxc_diagnostics::Result;
 
 use super::list::ObjectExpressionProperties;
-use crate::{diagnostics, lexer::Kind, list::SeparatedList, Parser};
+use crate::{diagnostics, lexer::Kind, list::SeparatedList, EcmaVersion, Parser};
 
 impl<'a> Parser<'a> {
     /// [Object Expression](https://tc39.es/ecma262/#sec-object-initializer)
@@ -65,6 +65,8 @@ impl<'a> Parser<'a> {
                 }
 
                 if matches!(self.cur_kind(), Kind::LParen | Kind::LAngle | Kind::ShiftLeft) {
+                    let key_span = self.end_span(span);
+                    self.check_ecma_version(EcmaVersion::ES2015, "Shorthand methods", key_span);
                     let method = self.parse_method(false, false)?;
                     return Ok(self.ast.property(
                         self.end_span(span),
@@ -97,6 +99,7 @@ impl<'a> Parser<'a> {
     fn parse_property_definition_shorthand(&mut self) -> Result<Box<'a, Property<'a>>> {
         let span = self.start_span();
         let identifier = self.parse_identifier_reference()?;
+        self.check_ecma_version(EcmaVersion::ES2015, "Shorthand properties", identifier.span);
         // CoverInitializedName ({ foo = bar })
         let value = if self.eat(Kind::Eq) {
             let right = self.parse_assignment_expression_base()?;
@@ -162,7 +165,11 @@ impl<'a> Parser<'a> {
             // { [foo]() {} }
             Kind::LBrack => {
                 computed = true;
-                self.parse_computed_property_name().map(PropertyKey::Expression)?
+                let span = self.start_span();
+                let key = self.parse_computed_property_name()?;
+                let span = self.end_span(span);
+                self.check_ecma_version(EcmaVersion::ES2015, "Computed property names", span);
+                PropertyKey::Expression(key)
             }
             _ => {
                 let ident = self.parse_identifier_name()?;
@@ -192,6 +199,7 @@ impl<'a> Parser<'a> {
         let r#async = self.eat(Kind::Async);
         let generator = self.eat(Kind::Star);
         let (key, computed) = self.parse_property_name()?;
+        self.check_ecma_version(EcmaVersion::ES2015, "Shorthand methods", self.end_span(span));
         let method = self.parse_method(r#async, generator)?;
         let value = PropertyValue::Expression(self.ast.function_expression(method));
         Ok(self.ast.property(
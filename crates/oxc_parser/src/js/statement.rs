@@ -7,30 +7,36 @@ use super::{
     grammar::CoverGrammar,
     list::SwitchCases,
 };
-use crate::{diagnostics, lexer::Kind, list::NormalList, Context, Parser, StatementContext};
+use crate::{
+    diagnostics, lexer::Kind, list::NormalList, Context, EcmaVersion, Parser, StatementContext,
+};
 
 impl<'a> Parser<'a> {
     /// <https://tc39.es/ecma262/#prod-StatementList>
     /// `StatementList`[Yield, Await, Return] :
     ///     `StatementListItem`[?Yield, ?Await, ?Return]
     ///     `StatementList`[?Yield, ?Await, ?Return] `StatementListItem`[?Yield, ?Await, ?Return]
+    ///
+    /// The leading string literal statements are directives if `has_directives`,
+    /// which is the case for scripts, modules and function bodies.
     pub(crate) fn parse_directives_and_statements(
         &mut self,
         is_top_level: bool,
+        has_directives: bool,
     ) -> Result<(Vec<'a, Directive<'a>>, Vec<'a, Statement<'a>>)> {
         let mut directives = self.ast.new_vec();
         let mut statements = self.ast.new_vec();
 
-        let mut expecting_diretives = true;
+        let mut expecting_diretives = has_directives;
         while !self.at(Kind::Eof) {
             match self.cur_kind() {
                 Kind::RCurly if !is_top_level => break,
                 Kind::Import if !matches!(self.peek_kind(), Kind::Dot | Kind::LParen) => {
-                    let stmt = self.parse_import_declaration()?;
+                    let stmt = self.parse_statement_or_recover(Self::parse_import_declaration)?;
                     statements.push(stmt);
                 }
                 Kind::Export => {
-                    let stmt = self.parse_export_declaration()?;
+                    let stmt = self.parse_statement_or_recover(Self::parse_export_declaration)?;
                     statements.push(stmt);
                 }
                 Kind::At => {
@@ -38,7 +44,9 @@ impl<'a> Parser<'a> {
                     continue;
                 }
                 _ => {
-                    let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+                    let stmt = self.parse_statement_or_recover(|p| {
+                        p.parse_statement_list_item(StatementContext::StatementList)
+                    })?;
 
                     // Section 11.2.1 Directive Prologue
                     // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
@@ -71,6 +79,13 @@ impl<'a> Parser<'a> {
     pub(crate) fn parse_statement_list_item(
         &mut self,
         stmt_ctx: StatementContext,
+    ) -> Result<Statement<'a>> {
+        self.nested(|p| p.parse_statement_list_item_impl(stmt_ctx))
+    }
+
+    fn parse_statement_list_item_impl(
+        &mut self,
+        stmt_ctx: StatementContext,
     ) -> Result<Statement<'a>> {
         let start_span = self.start_span();
 
@@ -107,13 +122,16 @@ impl<'a> Parser<'a> {
             _ if self.ts_enabled() && self.at_start_of_ts_declaration() => {
                 self.parse_ts_declaration_statement(start_span)
             }
+            _ if self.flow_enabled() && self.at_start_of_flow_declaration() => {
+                self.parse_ts_declaration_statement(start_span)
+            }
             _ => self.parse_expression_or_labeled_statement(),
         }
     }
 
     fn parse_expression_or_labeled_statement(&mut self) -> Result<Statement<'a>> {
         let span = self.start_span();
-        let expr = self.parse_expression()?;
+        let expr = self.parse_expr()?;
         if let Expression::Identifier(ident) = &expr {
             // Section 14.13 Labelled Statement
             // Avoids lookahead for a labeled statement, which is on a hot path
@@ -132,10 +150,12 @@ impl<'a> Parser<'a> {
         self.expect(Kind::LCurly)?;
         let mut body = self.ast.new_vec();
         while !self.at(Kind::RCurly) && !self.at(Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_or_recover(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            })?;
             body.push(stmt);
         }
-        self.expect(Kind::RCurly)?;
+        self.expect_closing(Kind::RCurly)?;
         Ok(self.ast.block(self.end_span(span), body))
     }
 
@@ -259,7 +279,7 @@ impl<'a> Parser<'a> {
             return self.parse_for_loop(span, None, r#await);
         }
 
-        let init_expression = self.without_context(Context::In, Parser::parse_expression)?;
+        let init_expression = self.without_context(Context::In, Parser::parse_expr)?;
 
         // for (a.b in ...), for ([a] in ..), for ({a} in ..)
         if self.at(Kind::In) || self.at(Kind::Of) {
@@ -286,7 +306,7 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement<'a>> {
         self.expect(Kind::Semicolon)?;
         let test = if !self.at(Kind::Semicolon) && !self.at(Kind::RParen) {
-            Some(self.with_context(Context::In, Parser::parse_expression)?)
+            Some(self.with_context(Context::In, Parser::parse_expr)?)
         } else {
             None
         };
@@ -294,9 +314,9 @@ impl<'a> Parser<'a> {
         let update = if self.at(Kind::RParen) {
             None
         } else {
-            Some(self.with_context(Context::In, Parser::parse_expression)?)
+            Some(self.with_context(Context::In, Parser::parse_expr)?)
         };
-        self.expect(Kind::RParen)?;
+        self.expect_closing(Kind::RParen)?;
         if r#await {
             self.error(diagnostics::ForAwait(self.end_span(span)));
         }
@@ -311,13 +331,18 @@ impl<'a> Parser<'a> {
         left: ForStatementLeft<'a>,
     ) -> Result<Statement<'a>> {
         let is_for_in = self.at(Kind::In);
+        if !is_for_in {
+            let (version, feature) = if r#await {
+                (EcmaVersion::ES2018, "`for await...of`")
+            } else {
+                (EcmaVersion::ES2015, "`for...of`")
+            };
+            self.check_ecma_version(version, feature, self.cur_token().span());
+        }
         self.bump_any(); // bump `in` or `of`
-        let right = if is_for_in {
-            self.parse_expression()
-        } else {
-            self.parse_assignment_expression_base()
-        }?;
-        self.expect(Kind::RParen)?;
+        let right =
+            if is_for_in { self.parse_expr() } else { self.parse_assignment_expression_base() }?;
+        self.expect_closing(Kind::RParen)?;
 
         if r#await && is_for_in {
             self.error(diagnostics::ForAwait(self.end_span(span)));
@@ -360,7 +385,7 @@ impl<'a> Parser<'a> {
         let argument = if self.eat(Kind::Semicolon) || self.can_insert_semicolon() {
             None
         } else {
-            let expr = self.with_context(Context::In, Parser::parse_expression)?;
+            let expr = self.with_context(Context::In, Parser::parse_expr)?;
             self.asi()?;
             Some(expr)
         };
@@ -405,7 +430,7 @@ impl<'a> Parser<'a> {
             }
             Kind::Case => {
                 self.bump_any();
-                let expression = self.parse_expression()?;
+                let expression = self.parse_expr()?;
                 Some(expression)
             }
             _ => return Err(self.unexpected()),
@@ -413,7 +438,9 @@ impl<'a> Parser<'a> {
         self.expect(Kind::Colon)?;
         let mut consequent = self.ast.new_vec();
         while !matches!(self.cur_kind(), Kind::Case | Kind::Default | Kind::RCurly | Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_or_recover(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            })?;
             consequent.push(stmt);
         }
         Ok(self.ast.switch_case(self.end_span(span), test, consequent))
@@ -430,7 +457,7 @@ impl<'a> Parser<'a> {
                 self.cur_token().span(),
             ));
         }
-        let argument = self.parse_expression()?;
+        let argument = self.parse_expr()?;
         self.asi()?;
         Ok(self.ast.throw_statement(self.end_span(span), argument))
     }
@@ -459,9 +486,11 @@ impl<'a> Parser<'a> {
         self.bump_any(); // advance `catch`
         let param = if self.eat(Kind::LParen) {
             let pattern = self.parse_binding_pattern()?.0;
-            self.expect(Kind::RParen)?;
+            self.expect_closing(Kind::RParen)?;
             Some(pattern)
         } else {
+            let span = self.end_span(span);
+            self.check_ecma_version(EcmaVersion::ES2019, "Optional catch binding", span);
             None
         };
         let body = self.parse_block()?;
@@ -0,0 +1,400 @@
+//! `JSDoc` Parser
+//!
+//! Parses a `/** ... */` comment from [`oxc_ast::Trivias`] into [`JSDoc`],
+//! type expressions such as `{Array<string>}` are parsed by the TypeScript type parser.
+//! See [JSDoc block tags](https://jsdoc.app/#block-tags).
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::*, AstBuilder, Atom, SourceType, Span};
+use oxc_diagnostics::{Error, Result};
+
+use crate::{lexer::Kind, Parser};
+
+/// Return value of [`JSDocParser::parse`]
+pub struct JSDocParserReturn<'a> {
+    pub jsdoc: JSDoc<'a>,
+    /// Syntax errors from type expressions, the tag is kept without its type
+    pub errors: Vec<Error>,
+}
+
+pub struct JSDocParser<'a> {
+    allocator: &'a Allocator,
+
+    ast: AstBuilder<'a>,
+
+    source_text: &'a str,
+
+    /// Span of the comment text between `/*` and `*/`, as stored in [`oxc_ast::Trivias`]
+    span: Span,
+
+    errors: Vec<Error>,
+}
+
+impl<'a> JSDocParser<'a> {
+    /// `span` is the span of a comment from [`oxc_ast::Trivias::comments`],
+    /// which starts at the second `*` of `/**`
+    #[must_use]
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, span: Span) -> Self {
+        Self { allocator, ast: AstBuilder::new(allocator), source_text, span, errors: vec![] }
+    }
+
+    /// Main entry point
+    #[must_use]
+    pub fn parse(mut self) -> JSDocParserReturn<'a> {
+        // skip the second `*` of `/**`
+        let start = self.span.start + u32::from(self.text(self.span).starts_with('*'));
+        let tag_starts = self.find_tag_starts(start);
+
+        let description_end = tag_starts.first().copied().unwrap_or(self.span.end);
+        let description = self.description(Span::new(start, description_end));
+
+        let mut tags = self.ast.new_vec();
+        for (i, tag_start) in tag_starts.iter().enumerate() {
+            let tag_end = tag_starts.get(i + 1).copied().unwrap_or(self.span.end);
+            tags.push(self.parse_tag(Span::new(*tag_start, tag_end)));
+        }
+
+        let jsdoc = JSDoc { span: self.span, description, tags };
+        JSDocParserReturn { jsdoc, errors: self.errors }
+    }
+
+    fn text(&self, span: Span) -> &'a str {
+        &self.source_text[span.start as usize..span.end as usize]
+    }
+
+    /// Offsets of each `@` that starts a line
+    #[allow(clippy::cast_possible_truncation)]
+    fn find_tag_starts(&self, start: u32) -> Vec<u32> {
+        let mut starts = vec![];
+        let mut line_start = start;
+        for line in self.text(Span::new(start, self.span.end)).split('\n') {
+            let content = strip_line_prefix(line);
+            let offset = line_start + (line.len() - content.len()) as u32;
+            if content.starts_with('@')
+                && content[1..].chars().next().map_or(false, |c| c.is_ascii_alphabetic())
+            {
+                starts.push(offset);
+            }
+            line_start += line.len() as u32 + 1;
+        }
+        starts
+    }
+
+    /// Text in `span` with the leading `*` and indentation of each line removed
+    fn description(&self, span: Span) -> Atom {
+        let text = self.text(span);
+        let mut lines = text.split('\n');
+        let mut description = lines.next().unwrap_or_default().trim_end().to_string();
+        for line in lines {
+            description.push('\n');
+            description.push_str(strip_line_prefix(line).trim());
+        }
+        Atom::from(description.trim())
+    }
+
+    /// Skip whitespace and the leading `*` of the following lines
+    #[allow(clippy::cast_possible_truncation)]
+    fn skip_whitespace(&self, pos: u32, end: u32) -> u32 {
+        let text = self.text(Span::new(pos, end));
+        let mut skipped = text.len() - text.trim_start_matches([' ', '\t']).len();
+        while text[skipped..].starts_with(['\n', '\r']) {
+            let rest = &text[skipped..];
+            let line = rest.trim_start_matches(['\n', '\r']);
+            let line = line.trim_start_matches([' ', '\t']);
+            let line = line.strip_prefix('*').unwrap_or(line);
+            let line = line.trim_start_matches([' ', '\t']);
+            skipped += rest.len() - line.len();
+        }
+        pos + skipped as u32
+    }
+
+    /// Read a word up to whitespace, returns its span
+    #[allow(clippy::cast_possible_truncation)]
+    fn read_word(&self, pos: u32, end: u32, stop: impl Fn(char) -> bool) -> Span {
+        let text = self.text(Span::new(pos, end));
+        let len = text.find(|c: char| c.is_whitespace() || stop(c)).unwrap_or(text.len());
+        Span::new(pos, pos + len as u32)
+    }
+
+    fn identifier_name(&self, span: Span) -> IdentifierName {
+        IdentifierName { span, name: Atom::from(self.text(span)) }
+    }
+
+    /// Span of the tag without trailing whitespace and line prefixes
+    #[allow(clippy::cast_possible_truncation)]
+    fn trim_span(&self, span: Span) -> Span {
+        let text = self.text(span).trim_end_matches(|c: char| c.is_whitespace() || c == '*');
+        Span::new(span.start, span.start + text.len() as u32)
+    }
+
+    fn parse_tag(&mut self, span: Span) -> JSDocTag<'a> {
+        let end = span.end;
+        let name_span = self.read_word(span.start + 1, end, |c| c == '{');
+        let tag_name = IdentifierName {
+            span: Span::new(span.start, name_span.end),
+            name: Atom::from(self.text(name_span)),
+        };
+        let span = self.trim_span(span);
+        let mut pos = self.skip_whitespace(name_span.end, end);
+
+        match tag_name.name.as_str() {
+            "param" | "arg" | "argument" => {
+                let mut type_expression = self.parse_type_expression(&mut pos, end);
+                let (name, optional) = self.parse_parameter_name(&mut pos, end);
+                // `@param name {T}`
+                if type_expression.is_none() {
+                    type_expression = self.parse_type_expression(&mut pos, end);
+                }
+                let description = self.description(Span::new(pos, end));
+                let tag = JSDocParameterTag {
+                    span,
+                    tag_name,
+                    type_expression,
+                    name,
+                    optional,
+                    description,
+                };
+                JSDocTag::Param(self.ast.alloc(tag))
+            }
+            "returns" | "return" => {
+                let type_expression = self.parse_type_expression(&mut pos, end);
+                let description = self.description(Span::new(pos, end));
+                let tag = JSDocReturnsTag { span, tag_name, type_expression, description };
+                JSDocTag::Returns(self.ast.alloc(tag))
+            }
+            "deprecated" => {
+                let description = self.description(Span::new(pos, end));
+                JSDocTag::Deprecated(self.ast.alloc(JSDocDeprecatedTag {
+                    span,
+                    tag_name,
+                    description,
+                }))
+            }
+            "type" => {
+                let type_expression = self.parse_type_expression(&mut pos, end);
+                JSDocTag::Type(self.ast.alloc(JSDocTypeTag { span, tag_name, type_expression }))
+            }
+            "template" => {
+                let constraint = self.parse_type_expression(&mut pos, end);
+                let mut type_parameters = self.ast.new_vec();
+                loop {
+                    let name = self.read_word(pos, end, |c| c == ',');
+                    if name.start == name.end {
+                        break;
+                    }
+                    type_parameters.push(self.identifier_name(name));
+                    pos = self.skip_whitespace(name.end, end);
+                    if !self.text(Span::new(pos, end)).starts_with(',') {
+                        break;
+                    }
+                    pos = self.skip_whitespace(pos + 1, end);
+                }
+                let description = self.description(Span::new(pos, end));
+                let tag =
+                    JSDocTemplateTag { span, tag_name, constraint, type_parameters, description };
+                JSDocTag::Template(self.ast.alloc(tag))
+            }
+            _ => {
+                let description = self.description(Span::new(pos, end));
+                JSDocTag::Unknown(self.ast.alloc(JSDocUnknownTag { span, tag_name, description }))
+            }
+        }
+    }
+
+    /// `name`, `[name]` or `[name=default]`
+    fn parse_parameter_name(&mut self, pos: &mut u32, end: u32) -> (IdentifierName, bool) {
+        let text = self.text(Span::new(*pos, end));
+        if text.starts_with('[') {
+            if let Some(close) = find_closing(text, '[', ']') {
+                let inner = &text[1..close];
+                let name = inner.split('=').next().unwrap_or_default().trim();
+                #[allow(clippy::cast_possible_truncation)]
+                let name_start = *pos + 1 + (inner.len() - inner.trim_start().len()) as u32;
+                #[allow(clippy::cast_possible_truncation)]
+                let name_span = Span::new(name_start, name_start + name.len() as u32);
+                #[allow(clippy::cast_possible_truncation)]
+                let close = *pos + close as u32 + 1;
+                *pos = self.skip_whitespace(close, end);
+                return (self.identifier_name(name_span), true);
+            }
+        }
+        let name_span = self.read_word(*pos, end, |c| c == '{');
+        *pos = self.skip_whitespace(name_span.end, end);
+        (self.identifier_name(name_span), false)
+    }
+
+    /// `{T}`, returns `None` when there is no type expression or it has a syntax error
+    fn parse_type_expression(
+        &mut self,
+        pos: &mut u32,
+        end: u32,
+    ) -> Option<JSDocTypeExpression<'a>> {
+        let text = self.text(Span::new(*pos, end));
+        if !text.starts_with('{') {
+            return None;
+        }
+        let close = find_closing(text, '{', '}')?;
+        #[allow(clippy::cast_possible_truncation)]
+        let close = *pos + close as u32;
+        let span = Span::new(*pos, close + 1);
+        *pos = self.skip_whitespace(span.end, end);
+        match self.parse_ts_type(Span::new(span.start + 1, close)) {
+            Ok(type_annotation) => Some(JSDocTypeExpression { span, type_annotation }),
+            Err(errors) => {
+                self.errors.extend(errors);
+                None
+            }
+        }
+    }
+
+    /// Parse `span` with the TypeScript type parser, spans are kept relative to the whole source text
+    fn parse_ts_type(&self, span: Span) -> std::result::Result<TSType<'a>, Vec<Error>> {
+        let source_text = &self.source_text[..span.end as usize];
+        let source_type = *SourceType::default().with_typescript(true);
+        let mut parser = Parser::new(self.allocator, source_text, source_type);
+        parser.lexer.seek(span.start);
+        let result: Result<TSType<'a>> = (|| {
+            parser.bump_any();
+            let ts_type = parser.parse_type()?;
+            parser.expect(Kind::Eof)?;
+            Ok(ts_type)
+        })();
+        let mut errors: Vec<Error> =
+            std::mem::take(&mut parser.lexer.errors).into_iter().chain(parser.errors).collect();
+        match result {
+            Ok(ts_type) if errors.is_empty() => Ok(ts_type),
+            Ok(_) => Err(errors),
+            Err(error) => {
+                errors.push(error);
+                Err(errors)
+            }
+        }
+    }
+}
+
+/// Index of the bracket closing the one at the start of `text`
+fn find_closing(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    text.char_indices().find_map(|(i, c)| {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+        None
+    })
+}
+
+/// Remove leading whitespace, `*` and a single space from a comment line
+fn strip_line_prefix(line: &str) -> &str {
+    let line = line.trim_start();
+    line.strip_prefix('*').map_or(line, |line| line.strip_prefix(' ').unwrap_or(line))
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::*, SourceType, Span};
+
+    use super::{JSDocParser, JSDocParserReturn};
+    use crate::Parser;
+
+    fn parse<'a>(allocator: &'a Allocator, source_text: &'a str) -> JSDocParserReturn<'a> {
+        let ret = Parser::new(allocator, source_text, SourceType::default()).parse();
+        let (start, comment) = ret.trivias.comments().iter().next().unwrap();
+        let span = Span::new(*start, comment.end());
+        JSDocParser::new(allocator, source_text, span).parse()
+    }
+
+    #[test]
+    fn parameters() {
+        let source_text = "/**
+ * Add two numbers.
+ * Second line.
+ * @template {number} T, U
+ * @param {T} a - the first
+ * @param {Array<U>} [b=[]] the second
+ *   continued
+ * @param {T)} broken
+ */
+function add(a, b) {}";
+        let allocator = Allocator::default();
+        let ret = parse(&allocator, source_text);
+        let jsdoc = ret.jsdoc;
+
+        assert_eq!(jsdoc.description, "Add two numbers.\nSecond line.");
+        assert_eq!(jsdoc.tags.len(), 4);
+        assert_eq!(ret.errors.len(), 1);
+
+        let JSDocTag::Template(tag) = &jsdoc.tags[0] else { unreachable!() };
+        assert!(tag.constraint.is_some());
+        assert_eq!(
+            tag.type_parameters.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            ["T", "U"]
+        );
+
+        let JSDocTag::Param(tag) = &jsdoc.tags[1] else { unreachable!() };
+        assert_eq!(tag.name.name, "a");
+        assert!(!tag.optional);
+        assert_eq!(tag.description, "- the first");
+        let type_expression = tag.type_expression.as_ref().unwrap();
+        assert_eq!(type_expression.span.source_text(source_text), "{T}");
+        assert!(matches!(type_expression.type_annotation, TSType::TSTypeReference(_)));
+
+        let JSDocTag::Param(tag) = &jsdoc.tags[2] else { unreachable!() };
+        assert_eq!(tag.name.name, "b");
+        assert_eq!(tag.name.span.source_text(source_text), "b");
+        assert!(tag.optional);
+        assert_eq!(tag.description, "the second\ncontinued");
+
+        let JSDocTag::Param(tag) = &jsdoc.tags[3] else { unreachable!() };
+        assert!(tag.type_expression.is_none());
+    }
+
+    #[test]
+    fn tags() {
+        let source_text = "/**
+ * @returns {T | U} sum
+ * @deprecated use `plus`
+ * @type {(a: T) => void}
+ * @example add(1, 2)
+ */";
+        let allocator = Allocator::default();
+        let ret = parse(&allocator, source_text);
+        let jsdoc = ret.jsdoc;
+        assert!(ret.errors.is_empty());
+
+        let JSDocTag::Returns(tag) = &jsdoc.tags[0] else { unreachable!() };
+        let type_expression = tag.type_expression.as_ref().unwrap();
+        assert!(matches!(type_expression.type_annotation, TSType::TSUnionType(_)));
+        assert_eq!(tag.description, "sum");
+
+        let JSDocTag::Deprecated(tag) = &jsdoc.tags[1] else { unreachable!() };
+        assert_eq!(tag.description, "use `plus`");
+        assert_eq!(tag.span.source_text(source_text), "@deprecated use `plus`");
+
+        let JSDocTag::Type(tag) = &jsdoc.tags[2] else { unreachable!() };
+        let type_expression = tag.type_expression.as_ref().unwrap();
+        assert!(matches!(type_expression.type_annotation, TSType::TSFunctionType(_)));
+
+        let JSDocTag::Unknown(tag) = &jsdoc.tags[3] else { unreachable!() };
+        assert_eq!(tag.tag_name.name, "example");
+        assert_eq!(tag.description, "add(1, 2)");
+    }
+
+    #[test]
+    fn single_line() {
+        let source_text = "/** @type {string} */ let a;";
+        let allocator = Allocator::default();
+        let ret = parse(&allocator, source_text);
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.jsdoc.description, "");
+        let JSDocTag::Type(tag) = &ret.jsdoc.tags[0] else { unreachable!() };
+        assert_eq!(tag.span.source_text(source_text), "@type {string}");
+        let type_expression = tag.type_expression.as_ref().unwrap();
+        assert!(matches!(type_expression.type_annotation, TSType::TSStringKeyword(_)));
+    }
+}
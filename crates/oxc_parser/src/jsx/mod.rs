@@ -57,6 +57,10 @@ impl<'a> Parser<'a> {
     ///     used for telling `JSXClosingElement` to parse the next jsx child or not
     ///     true when inside jsx element, false when at top level expression
     fn parse_jsx_element(&mut self, in_jsx_child: bool) -> Result<Box<'a, JSXElement<'a>>> {
+        self.nested(|p| p.parse_jsx_element_impl(in_jsx_child))
+    }
+
+    fn parse_jsx_element_impl(&mut self, in_jsx_child: bool) -> Result<Box<'a, JSXElement<'a>>> {
         let span = self.start_span();
         let opening_element = self.parse_jsx_opening_element(span, in_jsx_child)?;
         let children = if opening_element.self_closing {
@@ -253,7 +257,7 @@ impl<'a> Parser<'a> {
     fn parse_jsx_assignment_expression(&mut self) -> Result<Expression<'a>> {
         let ctx = self.ctx;
         self.ctx = Context::default();
-        let expr = self.parse_expression();
+        let expr = self.parse_expr();
         if let Ok(Expression::SequenceExpression(seq)) = &expr {
             return Err(diagnostics::JSXExpressionsMayNotUseTheCommaOperator(seq.span).into());
         }
@@ -21,12 +21,12 @@ use oxc_diagnostics::Error;
 use simd::{SkipMultilineComment, SkipWhitespace};
 pub use token::{RegExp, Token, TokenValue};
 
+pub use self::constants::{is_identifier_part, is_identifier_start_all};
 pub use self::kind::Kind;
 use self::{
     constants::{
-        is_identifier_part, is_identifier_start_all, is_identifier_start_ascii,
-        is_irregular_line_terminator, is_irregular_whitespace, is_line_terminator, EOF,
-        SINGLE_CHAR_TOKENS,
+        is_identifier_start_ascii, is_irregular_line_terminator, is_irregular_whitespace,
+        is_line_terminator, EOF, SINGLE_CHAR_TOKENS,
     },
     number::{parse_big_int, parse_float, parse_int},
     string_builder::AutoCow,
@@ -197,6 +197,7 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn next_right_angle(&mut self) -> Token<'a> {
+        self.current.token.start = self.offset() - 1;
         let kind = self.read_right_angle();
         self.lookahead.clear();
         self.finish_next(kind)
@@ -247,6 +248,12 @@ impl<'a> Lexer<'a> {
         self.finish_next(kind)
     }
 
+    /// Continue lexing from `offset`, for parsing a fragment of the source text such as a `JSDoc` type
+    pub fn seek(&mut self, offset: u32) {
+        self.current.chars = self.source[offset as usize..].chars();
+        self.lookahead.clear();
+    }
+
     // ---------- Private Methods ---------- //
     fn error<T: Into<Error>>(&mut self, error: T) {
         self.errors.push(error.into());
@@ -498,7 +505,11 @@ impl<'a> Lexer<'a> {
             }
         }
         self.current.token.is_on_new_line = true;
-        self.trivia_builder.add_single_line_comment(self.current.token.start, self.offset());
+        self.trivia_builder.add_single_line_comment(
+            self.current.token.start,
+            self.offset(),
+            self.source,
+        );
         Kind::Comment
     }
 
@@ -523,7 +534,11 @@ impl<'a> Lexer<'a> {
             return Kind::Eof;
         }
 
-        self.trivia_builder.add_multi_line_comment(self.current.token.start, self.offset());
+        self.trivia_builder.add_multi_line_comment(
+            self.current.token.start,
+            self.offset(),
+            self.source,
+        );
         Kind::MultiLineComment
     }
 
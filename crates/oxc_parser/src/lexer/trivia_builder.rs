@@ -12,13 +12,17 @@ impl TriviaBuilder {
         Rc::new(self.trivias)
     }
 
-    pub fn add_single_line_comment(&mut self, start: u32, end: u32) {
+    pub fn add_single_line_comment(&mut self, start: u32, end: u32, source_text: &str) {
         // skip leading `//`
-        self.trivias.add_comment(Span::new(start + 2, end), CommentKind::SingleLine);
+        let span = Span::new(start + 2, end);
+        let text = &source_text[span.start as usize..span.end as usize];
+        self.trivias.add_comment(span, CommentKind::SingleLine, text);
     }
 
-    pub fn add_multi_line_comment(&mut self, start: u32, end: u32) {
+    pub fn add_multi_line_comment(&mut self, start: u32, end: u32, source_text: &str) {
         // skip leading `/*` and trailing `*/`
-        self.trivias.add_comment(Span::new(start + 2, end - 2), CommentKind::MultiLine);
+        let span = Span::new(start + 2, end - 2);
+        let text = &source_text[span.start as usize..span.end as usize];
+        self.trivias.add_comment(span, CommentKind::MultiLine, text);
     }
 }
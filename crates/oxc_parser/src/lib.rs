@@ -34,6 +34,11 @@
 //! let parser_return = Parser::new(&allocator, &source_text, source_type).parse();
 //! ```
 //!
+//! Code fragments are parsed with [Parser::parse_expression], [Parser::parse_ts_type] and [Parser::parse_statement_list].
+//!
+//! [Parser::parse_syntax_tree] returns a lossless [SyntaxTree] with all tokens, comments and whitespace,
+//! for formatters and codemods which need to preserve the original code.
+//!
 //! # Example
 //! <https://github.com/Boshen/oxc/blob/main/crates/oxc_parser/examples/parser.rs>
 //!
@@ -65,26 +70,45 @@
 #![feature(slice_as_chunks)]
 
 mod context;
+mod cst;
 mod cursor;
 mod list;
+mod recovery;
 mod state;
 
+mod flow;
 mod js;
 mod jsx;
 mod ts;
 
 mod diagnostics;
+mod incremental;
+mod jsdoc;
 mod lexer;
+mod module_lexer;
+mod regexp;
+mod tokenizer;
+mod version;
 
 use std::rc::Rc;
 
 use context::{Context, StatementContext};
 use oxc_allocator::Allocator;
-use oxc_ast::{ast::Program, AstBuilder, ModuleKind, SourceType, Span, Trivias};
+use oxc_ast::{
+    ast::{Expression, Program, Statement, TSType},
+    AstBuilder, ModuleKind, SourceType, Span, Trivias, MAX_NESTING_DEPTH,
+};
 use oxc_diagnostics::{Error, Result};
 
+pub use crate::cst::{SyntaxElement, SyntaxElements, SyntaxNodeId, SyntaxTree, SyntaxTreeReturn};
+pub use crate::incremental::TextEdit;
+pub use crate::jsdoc::{JSDocParser, JSDocParserReturn};
+pub use crate::module_lexer::{DynamicImport, ModuleLexer, ModuleLexerReturn};
+pub use crate::tokenizer::{SourceToken, TokenFlags, TokenKind, Tokenizer, TokenizerReturn};
+pub use crate::version::EcmaVersion;
 use crate::{
     lexer::{Kind, Lexer, Token},
+    module_lexer::ModuleLexerState,
     state::ParserState,
 };
 
@@ -92,6 +116,7 @@ use crate::{
 ///
 /// The parser always return a valid AST.
 /// When `panicked = true`, then program will always be empty.
+/// Panics are rare with [`Parser::recover_from_errors`] enabled.
 /// When `errors.len() > 0`, then program may or may not be empty due to error recovery.
 #[derive(Debug)]
 pub struct ParserReturn<'a> {
@@ -99,6 +124,19 @@ pub struct ParserReturn<'a> {
     pub errors: Vec<Error>,
     pub trivias: Rc<Trivias>,
     pub panicked: bool,
+    /// Script or Module, also for [`ModuleKind::Unambiguous`] source types
+    pub module_kind: ModuleKind,
+}
+
+/// Return value of [`Parser::parse_expression`], [`Parser::parse_ts_type`] and [`Parser::parse_statement_list`]
+#[derive(Debug)]
+pub struct FragmentReturn<T> {
+    /// `None` on unrecoverable error
+    pub node: Option<T>,
+    pub errors: Vec<Error>,
+    /// The node ended before the end of the source text, e.g. `a b` is parsed as the expression `a`.
+    /// The remaining tokens are not reported in `errors`.
+    pub has_trailing_tokens: bool,
 }
 
 /// Recursive Descent Parser for ECMAScript and TypeScript
@@ -131,12 +169,38 @@ pub struct Parser<'a> {
 
     /// Ast builder for creating AST spans
     ast: AstBuilder<'a>,
+
+    /// Keep parsing after syntax errors, see [`Parser::recover_from_errors`]
+    recover: bool,
+
+    /// Consumed tokens, only collected by [`Tokenizer`]
+    tokens: Option<Vec<SourceToken>>,
+
+    /// Syntax newer than this version is reported, see [`Parser::target`]
+    target: EcmaVersion,
+
+    /// Parsing as a Module for a [`ModuleKind::Unambiguous`] source type
+    unambiguous: bool,
+
+    /// Current nesting depth of recursive parse functions
+    depth: u32,
+
+    /// See [`Parser::max_nesting_depth`]
+    max_nesting_depth: u32,
+
+    /// Skip function bodies and collect `import()` and `require()` calls, only set by [`ModuleLexer`]
+    module_lexer: Option<ModuleLexerState>,
 }
 
 impl<'a> Parser<'a> {
     /// Create a new parser
     #[must_use]
     pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
+        let unambiguous = source_type.is_unambiguous();
+        let mut source_type = source_type;
+        if unambiguous {
+            source_type = *source_type.with_module(true);
+        }
         Self {
             lexer: Lexer::new(allocator, source_text, source_type),
             source_type,
@@ -147,6 +211,13 @@ impl<'a> Parser<'a> {
             state: ParserState::new(allocator),
             ctx: Self::default_context(source_type),
             ast: AstBuilder::new(allocator),
+            recover: false,
+            tokens: None,
+            target: EcmaVersion::default(),
+            unambiguous,
+            depth: 0,
+            max_nesting_depth: MAX_NESTING_DEPTH,
+            module_lexer: None,
         }
     }
 
@@ -160,16 +231,79 @@ impl<'a> Parser<'a> {
         self
     }
 
+    #[must_use]
+    /// Recover from syntax errors
+    ///
+    /// By default, the first unrecoverable syntax error stops parsing and returns an empty `Program`.
+    /// Set this to true to record the error and continue with the next statement or list element,
+    /// leaving an `InvalidStatement` or `InvalidExpression` in place of the broken code.
+    /// Missing `;`, `)`, `]` and `}` are reported without discarding the surrounding node.
+    /// The returned program can be used for semantic analysis and linting.
+    pub fn recover_from_errors(mut self, recover: bool) -> Self {
+        self.recover = recover;
+        self
+    }
+
+    #[must_use]
+    /// Target ECMAScript version
+    ///
+    /// By default, all syntax up to `ESNext` is accepted.
+    /// Set this to report an error for syntax introduced after `target`,
+    /// e.g. "Optional chaining is not available in ES2019".
+    pub fn target(mut self, target: EcmaVersion) -> Self {
+        self.target = target;
+        self
+    }
+
+    #[must_use]
+    /// Maximum nesting depth
+    ///
+    /// By default, [`MAX_NESTING_DEPTH`] levels of nested statements, expressions, patterns and types are accepted.
+    /// Deeper input, e.g. `[[[[...]]]]` in generated code, is reported as "The code is too deeply nested"
+    /// instead of overflowing the stack.
+    pub fn max_nesting_depth(mut self, depth: u32) -> Self {
+        self.max_nesting_depth = depth;
+        self
+    }
+
     /// Main entry point
     ///
     /// Returns an empty `Program` on unrecoverable error,
     /// Recoverable errors are stored inside `errors`.
     #[must_use]
-    pub fn parse(mut self) -> ParserReturn<'a> {
+    pub fn parse(self) -> ParserReturn<'a> {
+        self.parse_with_tokens().0
+    }
+
+    /// Parse into a lossless [`SyntaxTree`], which links the tokens, comments and whitespace
+    /// of the source text to the AST nodes.
+    ///
+    /// The tree prints back to the source text byte for byte,
+    /// for formatters and codemods which need to preserve the original code.
+    #[must_use]
+    pub fn parse_syntax_tree(mut self) -> SyntaxTreeReturn<'a> {
+        let allocator = self.ast.allocator;
+        let source_text = self.source_text;
+        self.tokens = Some(vec![]);
+        let (ret, tokens) = self.parse_with_tokens();
+        let ParserReturn { program, errors, trivias, panicked, module_kind } = ret;
+        let tokens = tokenizer::with_trivia(source_text, &tokens, &trivias);
+        let tree = SyntaxTree::new(source_text, allocator.alloc(program), tokens);
+        SyntaxTreeReturn { tree, errors, trivias, panicked, module_kind }
+    }
+
+    /// Parse the program, returning the consumed tokens if they are collected
+    fn parse_with_tokens(mut self) -> (ParserReturn<'a>, Vec<SourceToken>) {
         let (program, panicked) = match self.parse_program() {
             Ok(program) => (program, false),
             Err(error) => {
                 self.error(self.flow_error().unwrap_or(error));
+                // The tokens after an unrecoverable error are read without context
+                if self.tokens.is_some() {
+                    while !self.at(Kind::Eof) {
+                        self.bump_any();
+                    }
+                }
                 let program = self.ast.program(
                     Span::default(),
                     self.ast.new_vec(),
@@ -179,9 +313,97 @@ impl<'a> Parser<'a> {
                 (program, true)
             }
         };
+        if self.unambiguous && !self.state.has_module_syntax {
+            return self.reparse_as_script(program, panicked);
+        }
+        self.finish(program, panicked)
+    }
+
+    fn finish(
+        mut self,
+        program: Program<'a>,
+        panicked: bool,
+    ) -> (ParserReturn<'a>, Vec<SourceToken>) {
+        let tokens = self.tokens.take().unwrap_or_default();
         let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
         let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias, panicked }
+        let module_kind = self.source_type.module_kind();
+        (ParserReturn { program, errors, trivias, panicked, module_kind }, tokens)
+    }
+
+    /// Parse the source text as a single expression, e.g. `$a.foo($b)` or `process.env.NODE_ENV === "production"`
+    #[must_use]
+    pub fn parse_expression(self) -> FragmentReturn<Expression<'a>> {
+        self.parse_fragment(Self::parse_expr)
+    }
+
+    /// Parse the source text as a single TypeScript type, e.g. `Record<string, number[]>`.
+    /// The source type should be TypeScript.
+    #[must_use]
+    pub fn parse_ts_type(self) -> FragmentReturn<TSType<'a>> {
+        self.parse_fragment(Self::parse_type)
+    }
+
+    /// Parse the source text as a list of statements, up to the end of the source text or an unmatched `}`.
+    ///
+    /// Unlike [`Parser::parse`], leading string literals are expression statements instead of directives.
+    #[must_use]
+    pub fn parse_statement_list(self) -> FragmentReturn<oxc_allocator::Vec<'a, Statement<'a>>> {
+        self.parse_fragment(|p| {
+            p.parse_directives_and_statements(
+                /* is_top_level */ false, /* has_directives */ false,
+            )
+            .map(|(_, statements)| statements)
+        })
+    }
+
+    fn parse_fragment<T>(
+        mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> FragmentReturn<T> {
+        self.bump_any();
+        let node = match parse(&mut self) {
+            Ok(node) => Some(node),
+            Err(error) => {
+                self.error(error);
+                None
+            }
+        };
+        let has_trailing_tokens = node.is_some() && !self.at(Kind::Eof);
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
+        FragmentReturn { node, errors, has_trailing_tokens }
+    }
+
+    /// Treat the source text as a Script, for a [`ModuleKind::Unambiguous`] source type
+    /// without any module syntax.
+    ///
+    /// A Module is parsed with more restrictions than a Script, so a program without errors is kept
+    /// and only marked as a Script. The source text is parsed again if there are errors,
+    /// or if it may contain HTML-like comments, which are only comments in a Script.
+    fn reparse_as_script(
+        mut self,
+        mut program: Program<'a>,
+        panicked: bool,
+    ) -> (ParserReturn<'a>, Vec<SourceToken>) {
+        let source_type = *self.source_type.clone().with_script(true);
+        let has_html_like_comments =
+            self.source_text.contains("<!--") || self.source_text.contains("-->");
+        if !panicked
+            && self.errors.is_empty()
+            && self.lexer.errors.is_empty()
+            && !has_html_like_comments
+        {
+            self.source_type = source_type;
+            program.source_type = source_type;
+            return self.finish(program, panicked);
+        }
+        let mut parser = Parser::new(self.ast.allocator, self.source_text, source_type)
+            .recover_from_errors(self.recover)
+            .target(self.target)
+            .max_nesting_depth(self.max_nesting_depth);
+        parser.ctx = parser.ctx.and_return(self.ctx.has_return());
+        parser.tokens = self.tokens.map(|_| vec![]);
+        parser.parse_with_tokens()
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -189,8 +411,9 @@ impl<'a> Parser<'a> {
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
 
-        let (directives, statements) =
-            self.parse_directives_and_statements(/* is_top_level */ true)?;
+        let (directives, statements) = self.parse_directives_and_statements(
+            /* is_top_level */ true, /* has_directives */ true,
+        )?;
 
         let span = Span::new(0, self.source_text.len() as u32);
         Ok(self.ast.program(span, directives, statements, self.source_type))
@@ -198,11 +421,13 @@ impl<'a> Parser<'a> {
 
     #[must_use]
     fn default_context(source_type: SourceType) -> Context {
-        let ctx = Context::default().and_ambient(source_type.is_typescript_definition());
+        let ctx = Context::default()
+            .and_ambient(source_type.is_typescript_definition())
+            .and_top_level(true);
         match source_type.module_kind() {
             ModuleKind::Script => ctx,
             // for [top-level-await](https://tc39.es/proposal-top-level-await/)
-            ModuleKind::Module => ctx.and_await(true),
+            ModuleKind::Module | ModuleKind::Unambiguous => ctx.and_await(true),
         }
     }
 
@@ -210,6 +435,7 @@ impl<'a> Parser<'a> {
     /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
     fn flow_error(&self) -> Option<Error> {
         if self.source_type.is_javascript()
+            && !self.source_type.is_flow()
             && (self.source_text.starts_with("// @flow")
                 || self.source_text.starts_with("/* @flow */"))
         {
@@ -239,6 +465,17 @@ impl<'a> Parser<'a> {
     fn ts_enabled(&self) -> bool {
         self.source_type.is_typescript()
     }
+
+    #[must_use]
+    fn flow_enabled(&self) -> bool {
+        self.source_type.is_flow()
+    }
+
+    /// Type annotations shared by TypeScript and Flow
+    #[must_use]
+    fn types_enabled(&self) -> bool {
+        self.ts_enabled() || self.flow_enabled()
+    }
 }
 
 #[cfg(test)]
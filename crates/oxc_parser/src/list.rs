@@ -1,6 +1,7 @@
+use oxc_ast::Span;
 use oxc_diagnostics::Result;
 
-use crate::{lexer::Kind, Parser};
+use crate::{diagnostics, lexer::Kind, Parser};
 
 pub trait NormalList<'a> {
     /// Open element, e.g.. `{` `[` `(`
@@ -15,9 +16,18 @@ pub trait NormalList<'a> {
     fn parse(&mut self, p: &mut Parser<'a>) -> Result<()> {
         p.expect(self.open())?;
         while !p.at(self.close()) && !p.at(Kind::Eof) {
-            self.parse_element(p)?;
+            let span = p.start_span();
+            let ctx = p.ctx;
+            if let Err(error) = self.parse_element(p) {
+                if !p.recover {
+                    return Err(error);
+                }
+                p.recover_from(ctx, error);
+                p.skip_invalid_tokens(span.start, &[self.close(), Kind::Semicolon], true);
+                p.bump(Kind::Semicolon);
+            }
         }
-        p.expect(self.close())?;
+        p.expect_closing(self.close())?;
         Ok(())
     }
 }
@@ -44,6 +54,10 @@ pub trait SeparatedList<'a>: Sized {
 
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()>;
 
+    /// Called with the span of the skipped tokens when an element fails to parse during error recovery,
+    /// e.g. to put an `InvalidExpression` in place of the element
+    fn recover_element(&mut self, _p: &mut Parser<'a>, _span: Span) {}
+
     /// Main entry point, parse the list
     fn parse_list(&mut self, p: &mut Parser<'a>) -> Result<()> {
         p.expect(self.open())?;
@@ -53,6 +67,21 @@ pub trait SeparatedList<'a>: Sized {
         while !p.at(self.close()) && !p.at(Kind::Eof) {
             if first {
                 first = false;
+            } else if p.recover && !p.at(self.separator()) {
+                // A `;` or a closing bracket of the enclosing node ends the list,
+                // anything else is an element with a missing separator
+                if matches!(
+                    p.cur_kind(),
+                    Kind::Semicolon | Kind::RCurly | Kind::RParen | Kind::RBrack
+                ) {
+                    break;
+                }
+                let range = p.cur_token().span();
+                p.error(diagnostics::ExpectToken(
+                    self.separator().to_str(),
+                    p.cur_kind().to_str(),
+                    range,
+                ));
             } else {
                 p.expect(self.separator())?;
                 if p.at(self.close()) {
@@ -60,10 +89,20 @@ pub trait SeparatedList<'a>: Sized {
                 }
             }
 
-            self.parse_element(p)?;
+            let span = p.start_span();
+            let ctx = p.ctx;
+            if let Err(error) = self.parse_element(p) {
+                if !p.recover {
+                    return Err(error);
+                }
+                p.recover_from(ctx, error);
+                let terminators = [self.separator(), self.close(), Kind::Semicolon, Kind::RCurly];
+                p.skip_invalid_tokens(span.start, &terminators, false);
+                self.recover_element(p, span);
+            }
         }
 
-        p.expect(self.close())?;
+        p.expect_closing(self.close())?;
         Ok(())
     }
 }
@@ -0,0 +1,249 @@
+//! Import and export scanner for dependency graphs, see [`ModuleLexer`]
+
+mod skip;
+
+use oxc_allocator::{Allocator, Box};
+use oxc_ast::{
+    ast::*,
+    module_record::{ModuleRecord, ModuleRecordBuilder, NameSpan},
+    visit::Visit,
+    AstKind, Atom, GetSpan, SourceType, Span,
+};
+use oxc_diagnostics::{Error, Result};
+
+use self::skip::{Call, Skipper};
+use crate::{
+    lexer::{Kind, Lexer},
+    Parser,
+};
+
+/// A dynamic `import()` or `require()` call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicImport {
+    /// Span of the `import` keyword or the `require` identifier
+    pub span: Span,
+    /// The string literal argument, `None` for any other expression
+    pub module_request: Option<NameSpan>,
+}
+
+/// Return value of [`ModuleLexer::scan`]
+#[derive(Debug)]
+pub struct ModuleLexerReturn {
+    /// Module requests, import entries and export entries of the top level declarations,
+    /// the same as the `ModuleRecordBuilder` of the full AST
+    pub module_record: ModuleRecord,
+    /// `import()` calls in source order
+    pub dynamic_imports: std::vec::Vec<DynamicImport>,
+    /// `require()` calls in source order
+    pub requires: std::vec::Vec<DynamicImport>,
+    pub errors: std::vec::Vec<Error>,
+}
+
+/// Calls and errors in the function bodies skipped by the [`ModuleLexer`]
+#[derive(Debug, Default)]
+pub struct ModuleLexerState {
+    calls: std::vec::Vec<Call>,
+}
+
+/// Scan the import and export declarations, `import()` calls and `require()` calls of a module,
+/// for dependency graph tools which do not need the full AST.
+///
+/// Only the import and export declarations at the top level are parsed.
+/// All other code, including the function bodies in the declarations, is skipped byte by byte
+/// without creating tokens: strings, template literals, regular expressions, comments and JSX elements
+/// are skipped over and only braces and parentheses are tracked.
+/// Whether a `/` starts a regular expression is decided from the previous token, the same as `es-module-lexer`.
+///
+/// Run the `module_lexer` group of `cargo benchmark` to compare it with the parser.
+pub struct ModuleLexer<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+}
+
+impl<'a> ModuleLexer<'a> {
+    #[must_use]
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
+        Self { allocator, source_text, source_type }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn scan(self) -> ModuleLexerReturn {
+        let mut parser = Parser::new(self.allocator, self.source_text, self.source_type)
+            .recover_from_errors(true);
+        parser.module_lexer = Some(ModuleLexerState::default());
+        let mut skipper = Skipper::new(self.source_text, self.source_type.is_jsx());
+        let mut statements = parser.ast.new_vec();
+        let mut offset = 0;
+        while let Some(start) = skipper.next_declaration(offset) {
+            parser.lexer.seek(start);
+            parser.bump_any();
+            let declaration = if parser.at(Kind::Import) {
+                parser.parse_import_declaration()
+            } else {
+                parser.parse_export_declaration()
+            };
+            match declaration {
+                Ok(declaration) => statements.push(declaration),
+                Err(error) => parser.error(error),
+            }
+            offset = parser.prev_token_end.max(start + 1);
+        }
+
+        let span = Span::new(0, self.source_text.len() as u32);
+        let program =
+            parser.ast.program(span, parser.ast.new_vec(), statements, parser.source_type);
+        let program = self.allocator.alloc(program);
+
+        let mut dynamic_imports = vec![];
+        let mut requires = vec![];
+        let state = parser.module_lexer.take().unwrap_or_default();
+        for call in state.calls.iter().chain(&skipper.calls) {
+            let module_request =
+                call.argument.map(|span| NameSpan::new(self.string_value(span), span));
+            let import = DynamicImport { span: call.span, module_request };
+            if call.is_import {
+                dynamic_imports.push(import);
+            } else {
+                requires.push(import);
+            }
+        }
+        RequestCollector { dynamic_imports: &mut dynamic_imports, requires: &mut requires }
+            .visit_program(program);
+        dynamic_imports.sort_by_key(|import| import.span);
+        requires.sort_by_key(|require| require.span);
+
+        let module_record = ModuleRecordBuilder::default().build(program);
+        let errors =
+            parser.lexer.errors.into_iter().chain(parser.errors).chain(skipper.errors).collect();
+        ModuleLexerReturn { module_record, dynamic_imports, requires, errors }
+    }
+
+    /// The value of the string literal at `span`
+    fn string_value(&self, span: Span) -> Atom {
+        let raw = &self.source_text[span.start as usize + 1..span.end as usize - 1];
+        if !raw.contains('\\') {
+            return raw.into();
+        }
+        let mut lexer = Lexer::new(self.allocator, self.source_text, self.source_type);
+        lexer.seek(span.start);
+        lexer.next_token().value.get_string().unwrap_or_default().into()
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Skip a function body for the [`ModuleLexer`], returning an empty body
+    pub(crate) fn skip_function_body(&mut self) -> Result<Box<'a, FunctionBody<'a>>> {
+        let span = self.start_span();
+        self.expect_without_advance(Kind::LCurly)?;
+        let mut skipper = Skipper::new(self.source_text, self.source_type.is_jsx());
+        let end = skipper.skip_block(span.start + 1);
+        #[allow(clippy::cast_possible_truncation)]
+        self.lexer.seek(end.unwrap_or(self.source_text.len() as u32));
+        self.bump_any();
+        if let Some(state) = &mut self.module_lexer {
+            state.calls.extend(skipper.calls);
+        }
+        self.errors.extend(skipper.errors);
+        self.expect(Kind::RCurly)?;
+        Ok(self.ast.function_body(self.end_span(span), self.ast.new_vec(), self.ast.new_vec()))
+    }
+}
+
+/// Collect the calls in parsed declarations
+struct RequestCollector<'s> {
+    dynamic_imports: &'s mut std::vec::Vec<DynamicImport>,
+    requires: &'s mut std::vec::Vec<DynamicImport>,
+}
+
+impl<'a, 's> Visit<'a> for RequestCollector<'s> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if let AstKind::CallExpression(call) = kind {
+            if call.callee.is_specific_id("require") {
+                let module_request = match call.arguments.first() {
+                    Some(Argument::Expression(Expression::StringLiteral(literal))) => {
+                        Some(NameSpan::new(literal.value.clone(), literal.span))
+                    }
+                    _ => None,
+                };
+                self.requires.push(DynamicImport { span: call.callee.span(), module_request });
+            }
+        }
+    }
+
+    fn visit_import_expression(&mut self, expr: &'a ImportExpression<'a>) {
+        let module_request = match &expr.source {
+            Expression::StringLiteral(literal) => {
+                Some(NameSpan::new(literal.value.clone(), literal.span))
+            }
+            _ => None,
+        };
+        let span = Span::new(expr.span.start, expr.span.start + 6);
+        self.dynamic_imports.push(DynamicImport { span, module_request });
+        self.visit_expression(&expr.source);
+        for arg in &expr.arguments {
+            self.visit_expression(arg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{module_record::ModuleRecordBuilder, SourceType};
+
+    use super::{DynamicImport, ModuleLexer};
+    use crate::Parser;
+
+    #[test]
+    fn module_lexer() {
+        fn names(imports: &[DynamicImport]) -> Vec<Option<&str>> {
+            imports
+                .iter()
+                .map(|import| import.module_request.as_ref().map(|request| request.name().as_str()))
+                .collect()
+        }
+
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_module(true).with_jsx(true);
+        let source = r#"
+import a, { b as c } from "a";
+import * as ns from "ns";
+import "side-effect";
+export { a, c as d };
+export * from "star";
+export * as all from "all";
+export { e } from "e";
+export const f = 1, { g, h: [i] } = {};
+export function j() {
+const re = /}import("fake")/;
+return import("j");
+}
+export default class K { method() { return require("k") } }
+function l() {
+if (x) /{/.test(y);
+const t = `${ { a: 1 } }${ require(`template`) }`;
+return <div attr={() => import("jsx")}>{"}"}</div>;
+}
+const m = import(name), n = require("n"), o = a.require("o");
+const p = { import: 1, export: 2 } / 2;
+    "#;
+        let ret = ModuleLexer::new(&allocator, source, source_type).scan();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+
+        let program = allocator.alloc(Parser::new(&allocator, source, source_type).parse().program);
+        let expected = ModuleRecordBuilder::default().build(program);
+        let record = &ret.module_record;
+        assert_eq!(record.import_entries, expected.import_entries);
+        assert_eq!(record.local_export_entries, expected.local_export_entries);
+        assert_eq!(record.indirect_export_entries, expected.indirect_export_entries);
+        assert_eq!(record.star_export_entries, expected.star_export_entries);
+        assert_eq!(record.module_requests, expected.module_requests);
+        assert_eq!(record.exported_bindings, expected.exported_bindings);
+        assert_eq!(record.export_default, expected.export_default);
+
+        assert_eq!(names(&ret.dynamic_imports), [Some("j"), Some("jsx"), None]);
+        assert_eq!(names(&ret.requires), [Some("k"), None, Some("n")]);
+    }
+}
@@ -0,0 +1,771 @@
+//! Byte level scanner for the code skipped by the [`super::ModuleLexer`]
+//!
+//! Strings, template literals, regular expressions, comments and JSX elements are skipped over
+//! without creating tokens, only the braces and parentheses which decide
+//! whether a `/` starts a regular expression are tracked, the same as `es-module-lexer`.
+
+use oxc_ast::{Span, MAX_NESTING_DEPTH};
+use oxc_diagnostics::Error;
+
+use crate::diagnostics;
+
+/// Keywords after which a `/` starts a regular expression instead of a division
+const REGEX_KEYWORDS: &[&str] = &[
+    "return",
+    "typeof",
+    "case",
+    "do",
+    "else",
+    "in",
+    "instanceof",
+    "new",
+    "delete",
+    "void",
+    "throw",
+    "yield",
+    "await",
+    "extends",
+    "default",
+];
+
+/// An `import(` or `require(` call in skipped code
+#[derive(Debug, Clone, Copy)]
+pub struct Call {
+    pub is_import: bool,
+    /// Span of the `import` keyword or the `require` identifier
+    pub span: Span,
+    /// Span of the string literal argument, including the quotes
+    pub argument: Option<Span>,
+}
+
+/// A `{` being scanned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Brace {
+    /// Block statement, class body or function body, a `/` after it starts a regular expression
+    Block,
+    /// Object literal, a `/` after it is a division
+    Expression,
+    /// `${` of a template literal
+    Template,
+}
+
+/// The previous token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Prev<'a> {
+    /// The start of the scanned code or a `;`
+    Semicolon,
+    LCurly,
+    /// `}`, `true` if it closes a block
+    RCurly(bool),
+    /// `)`, `true` if it closes the head of an `if`, `for`, `while` or `with` statement
+    RParen(bool),
+    Arrow,
+    /// `.` or `?.`, the next identifier is a property name
+    Dot,
+    /// Any other punctuator
+    Punctuator,
+    /// Identifier or keyword without escapes
+    Word(&'a str),
+    /// Literal, escaped identifier, property name, `]`, `++` or `--`
+    Operand,
+}
+
+impl<'a> Prev<'a> {
+    /// Whether a `/` after this token starts a regular expression
+    fn is_regex_allowed(self) -> bool {
+        match self {
+            Self::Semicolon | Self::LCurly | Self::Arrow | Self::Punctuator => true,
+            Self::RCurly(is_block) => is_block,
+            Self::RParen(is_statement_head) => is_statement_head,
+            Self::Dot | Self::Operand => false,
+            Self::Word(word) => REGEX_KEYWORDS.contains(&word),
+        }
+    }
+
+    /// Whether a `{` after this token starts an object literal
+    fn is_expression_start(self) -> bool {
+        self.is_regex_allowed()
+            && !matches!(
+                self,
+                Self::Semicolon
+                    | Self::LCurly
+                    | Self::RCurly(_)
+                    | Self::RParen(_)
+                    | Self::Arrow
+                    | Self::Word("else" | "do")
+            )
+    }
+}
+
+pub struct Skipper<'a> {
+    source_text: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    jsx: bool,
+    /// Nesting depth of JSX elements
+    depth: u32,
+    pub calls: Vec<Call>,
+    pub errors: Vec<Error>,
+}
+
+impl<'a> Skipper<'a> {
+    pub fn new(source_text: &'a str, jsx: bool) -> Self {
+        Self {
+            source_text,
+            bytes: source_text.as_bytes(),
+            pos: 0,
+            jsx,
+            depth: 0,
+            calls: vec![],
+            errors: vec![],
+        }
+    }
+
+    /// Skip from `offset` at the top level to the next import or export declaration,
+    /// returns the offset of its `import` or `export` keyword, `None` at the end of the file
+    pub fn next_declaration(&mut self, offset: u32) -> Option<u32> {
+        self.pos = offset as usize;
+        self.scan(/* is_top_level */ true).map(offset_u32)
+    }
+
+    /// Skip from `offset` after a `{` to the matching `}`, returns the offset of the `}`
+    pub fn skip_block(&mut self, offset: u32) -> Option<u32> {
+        self.pos = offset as usize;
+        self.scan(/* is_top_level */ false).map(offset_u32)
+    }
+
+    /// Scan until an import or export declaration at the top level,
+    /// or until an unmatched `}` when not at the top level
+    fn scan(&mut self, is_top_level: bool) -> Option<usize> {
+        let mut braces = vec![];
+        // Whether each `(` is the head of an `if`, `for`, `while` or `with` statement
+        let mut parens = vec![];
+        let mut prev = Prev::Semicolon;
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            let start = self.pos;
+            prev = match byte {
+                b' ' | b'\t' | b'\n' | b'\r' | 0x0B | 0x0C => {
+                    self.pos += 1;
+                    continue;
+                }
+                b'/' => match self.scan_slash(prev) {
+                    Some(prev) => prev,
+                    None => continue,
+                },
+                b'\'' | b'"' => {
+                    self.pos = self.skip_string(start);
+                    Prev::Operand
+                }
+                b'`' => {
+                    self.pos += 1;
+                    self.scan_template(&mut braces)
+                }
+                b'{' => {
+                    self.pos += 1;
+                    braces.push(if prev.is_expression_start() {
+                        Brace::Expression
+                    } else {
+                        Brace::Block
+                    });
+                    Prev::LCurly
+                }
+                b'}' => {
+                    self.pos += 1;
+                    match braces.pop() {
+                        Some(Brace::Template) => self.scan_template(&mut braces),
+                        Some(brace) => Prev::RCurly(brace == Brace::Block),
+                        None if !is_top_level => return Some(start),
+                        None => Prev::RCurly(true),
+                    }
+                }
+                b'(' => {
+                    self.pos += 1;
+                    parens.push(matches!(prev, Prev::Word("if" | "for" | "while" | "with")));
+                    Prev::Punctuator
+                }
+                b')' => {
+                    self.pos += 1;
+                    Prev::RParen(parens.pop().unwrap_or_default())
+                }
+                b'<' if self.jsx && prev.is_regex_allowed() && self.skip_jsx_element() => {
+                    Prev::Operand
+                }
+                b'#' if start == 0 && self.bytes.get(1) == Some(&b'!') => {
+                    self.skip_line_comment();
+                    continue;
+                }
+                b'#' => {
+                    self.pos += 1;
+                    self.read_identifier();
+                    Prev::Operand
+                }
+                b'0'..=b'9' => {
+                    self.skip_number();
+                    Prev::Operand
+                }
+                _ if byte >= 0x80 && self.skip_unicode_whitespace() => continue,
+                _ if is_identifier_start(byte) => {
+                    let (word, escaped) = self.read_identifier();
+                    if escaped || prev == Prev::Dot {
+                        Prev::Operand
+                    } else if is_top_level
+                        && braces.is_empty()
+                        && parens.is_empty()
+                        && self.is_declaration(word)
+                    {
+                        return Some(start);
+                    } else {
+                        self.scan_word(prev, word, start)
+                    }
+                }
+                _ => self.scan_punctuator(byte),
+            };
+        }
+        None
+    }
+
+    /// Scan a `/` which starts a comment, a regular expression or a division,
+    /// returns `None` for a comment
+    fn scan_slash(&mut self, prev: Prev<'a>) -> Option<Prev<'a>> {
+        match self.bytes.get(self.pos + 1) {
+            Some(b'/') => {
+                self.skip_line_comment();
+                None
+            }
+            Some(b'*') => {
+                self.skip_block_comment();
+                None
+            }
+            _ if prev.is_regex_allowed() => {
+                self.skip_regex();
+                Some(Prev::Operand)
+            }
+            _ => {
+                self.pos += 1;
+                Some(Prev::Punctuator)
+            }
+        }
+    }
+
+    /// Scan the rest of a template literal, pushing a `${` of a substitution
+    fn scan_template(&mut self, braces: &mut Vec<Brace>) -> Prev<'a> {
+        if self.skip_template() {
+            braces.push(Brace::Template);
+            Prev::Punctuator
+        } else {
+            Prev::Operand
+        }
+    }
+
+    /// Whether the `import` or `export` keyword at the top level starts a declaration,
+    /// rather than an `import()` call or `import.meta`
+    fn is_declaration(&self, word: &str) -> bool {
+        match word {
+            "export" => true,
+            "import" => !matches!(self.peek(), Some(b'(' | b'.')),
+            _ => false,
+        }
+    }
+
+    /// Scan an identifier or keyword which is not a property name, recording `import(` and `require(` calls
+    fn scan_word(&mut self, prev: Prev<'a>, word: &'a str, start: usize) -> Prev<'a> {
+        if matches!(word, "import" | "require")
+            && prev != Prev::Word("function")
+            && self.peek() == Some(b'(')
+        {
+            self.record_call(word == "import", start);
+        }
+        Prev::Word(word)
+    }
+
+    /// Scan a punctuator other than a brace, a parenthesis or a `/`
+    fn scan_punctuator(&mut self, byte: u8) -> Prev<'a> {
+        let start = self.pos;
+        let next = self.bytes.get(start + 1).copied();
+        match byte {
+            b']' => {
+                self.pos += 1;
+                Prev::Operand
+            }
+            b';' => {
+                self.pos += 1;
+                Prev::Semicolon
+            }
+            b'.' if matches!(next, Some(b'0'..=b'9')) => {
+                self.skip_number();
+                Prev::Operand
+            }
+            b'.' if self.source_text[start..].starts_with("...") => {
+                self.pos += 3;
+                Prev::Punctuator
+            }
+            b'.' => {
+                self.pos += 1;
+                Prev::Dot
+            }
+            b'?' if next == Some(b'.')
+                && !matches!(self.bytes.get(start + 2), Some(b'0'..=b'9')) =>
+            {
+                self.pos += 2;
+                Prev::Dot
+            }
+            b'+' | b'-' if next == Some(byte) => {
+                self.pos += 2;
+                Prev::Operand
+            }
+            b'=' if next == Some(b'>') => {
+                self.pos += 2;
+                Prev::Arrow
+            }
+            _ => {
+                self.pos += 1;
+                Prev::Punctuator
+            }
+        }
+    }
+
+    /// The next byte after whitespace and comments
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.skip_trivia(self.pos)).copied()
+    }
+
+    /// The offset after the whitespace and comments at `pos`
+    fn skip_trivia(&self, mut pos: usize) -> usize {
+        loop {
+            match self.bytes.get(pos) {
+                Some(b' ' | b'\t' | b'\n' | b'\r' | 0x0B | 0x0C) => pos += 1,
+                Some(b'/') if self.bytes.get(pos + 1) == Some(&b'/') => pos = self.line_end(pos),
+                Some(b'/') if self.bytes.get(pos + 1) == Some(&b'*') => {
+                    pos = self.source_text[pos + 2..]
+                        .find("*/")
+                        .map_or(self.bytes.len(), |end| pos + 2 + end + 2);
+                }
+                _ => return pos,
+            }
+        }
+    }
+
+    /// Record an `import(` or `require(` call at `start`, the identifier is already read
+    fn record_call(&mut self, is_import: bool, start: usize) {
+        let span = Span::new(offset_u32(start), offset_u32(self.pos));
+        let paren = self.skip_trivia(self.pos);
+        let quote = self.skip_trivia(paren + 1);
+        let argument = matches!(self.bytes.get(quote), Some(b'\'' | b'"'))
+            .then(|| self.string_end(quote))
+            .filter(|&(end, is_terminated)| {
+                is_terminated && matches!(self.bytes.get(self.skip_trivia(end)), Some(b')' | b','))
+            })
+            .map(|(end, _)| Span::new(offset_u32(quote), offset_u32(end)));
+        self.calls.push(Call { is_import, span, argument });
+    }
+
+    fn skip_line_comment(&mut self) {
+        self.pos = self.line_end(self.pos);
+    }
+
+    /// The offset of the line terminator after `pos`,
+    /// `\u{2028}` and `\u{2029}` are the only line terminators which start with `0xE2`
+    fn line_end(&self, mut pos: usize) -> usize {
+        while let Some(&byte) = self.bytes.get(pos) {
+            match byte {
+                b'\n' | b'\r' => return pos,
+                0xE2 if matches!(self.bytes.get(pos + 1..pos + 3), Some([0x80, 0xA8 | 0xA9])) => {
+                    return pos;
+                }
+                _ => pos += 1,
+            }
+        }
+        pos
+    }
+
+    fn skip_block_comment(&mut self) {
+        let start = self.pos;
+        if let Some(end) = self.source_text[start + 2..].find("*/") {
+            self.pos = start + 2 + end + 2;
+        } else {
+            self.pos = self.bytes.len();
+            self.errors.push(diagnostics::UnterminatedMultiLineComment(self.span(start)).into());
+        }
+    }
+
+    /// Skip the string literal at `start`, reporting an unterminated string
+    fn skip_string(&mut self, start: usize) -> usize {
+        let (end, is_terminated) = self.string_end(start);
+        if !is_terminated {
+            let span = Span::new(offset_u32(start), offset_u32(end));
+            self.errors.push(diagnostics::UnterminatedString(span).into());
+        }
+        end
+    }
+
+    /// The end of the string literal at `start` and whether it is terminated,
+    /// an unterminated string ends at the end of its line
+    fn string_end(&self, start: usize) -> (usize, bool) {
+        let quote = self.bytes[start];
+        let mut pos = start + 1;
+        while let Some(&byte) = self.bytes.get(pos) {
+            match byte {
+                b'\\' if self.source_text[pos + 1..].starts_with("\r\n") => pos += 3,
+                b'\\' => pos += 2,
+                b'\n' | b'\r' => return (pos, false),
+                _ if byte == quote => return (pos + 1, true),
+                _ => pos += 1,
+            }
+        }
+        (self.bytes.len(), false)
+    }
+
+    /// Skip the rest of a template literal after `` ` `` or the `}` of a substitution,
+    /// returns `true` at a `${`
+    fn skip_template(&mut self) -> bool {
+        let start = self.pos;
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            match byte {
+                b'`' => {
+                    self.pos += 1;
+                    return false;
+                }
+                b'\\' => self.pos += 2,
+                b'$' if self.bytes.get(self.pos + 1) == Some(&b'{') => {
+                    self.pos += 2;
+                    return true;
+                }
+                _ => self.pos += 1,
+            }
+        }
+        self.pos = self.bytes.len();
+        self.errors.push(diagnostics::UnterminatedString(self.span(start)).into());
+        false
+    }
+
+    /// Skip a regular expression literal at `/`, including its flags
+    fn skip_regex(&mut self) {
+        let start = self.pos;
+        let mut in_class = false;
+        self.pos += 1;
+        loop {
+            match self.bytes.get(self.pos) {
+                Some(b'\\') => self.pos += 2,
+                Some(b'[') => {
+                    in_class = true;
+                    self.pos += 1;
+                }
+                Some(b']') => {
+                    in_class = false;
+                    self.pos += 1;
+                }
+                Some(b'/') if !in_class => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\n' | b'\r') | None => {
+                    self.pos = self.pos.min(self.bytes.len());
+                    self.errors.push(diagnostics::UnterminatedRegExp(self.span(start)).into());
+                    return;
+                }
+                Some(_) => self.pos += 1,
+            }
+        }
+        while matches!(self.bytes.get(self.pos), Some(byte) if byte.is_ascii_alphanumeric()) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_number(&mut self) {
+        let start = self.pos;
+        let is_hex = matches!(self.bytes.get(start..start + 2), Some(b"0x" | b"0X"));
+        self.pos += 1;
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            let is_exponent_sign = matches!(byte, b'+' | b'-')
+                && !is_hex
+                && matches!(self.bytes[self.pos - 1], b'e' | b'E');
+            if !(byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.') || is_exponent_sign) {
+                break;
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// Skip a non-ASCII whitespace character, e.g. a no-break space
+    fn skip_unicode_whitespace(&mut self) -> bool {
+        match self.source_text[self.pos..].chars().next() {
+            Some(c) if c.is_whitespace() || c == '\u{FEFF}' => {
+                self.pos += c.len_utf8();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Read an identifier, returns it and whether it contains escapes
+    fn read_identifier(&mut self) -> (&'a str, bool) {
+        let start = self.pos;
+        let mut escaped = false;
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            match byte {
+                b'\\' => {
+                    escaped = true;
+                    // `\u{...}` or `\uXXXX`
+                    self.pos += if self.bytes.get(self.pos + 2) == Some(&b'{') {
+                        self.source_text[self.pos..].find('}').map_or(2, |end| end + 1)
+                    } else {
+                        6
+                    };
+                    self.pos = self.pos.min(self.bytes.len());
+                    while !self.source_text.is_char_boundary(self.pos) {
+                        self.pos += 1;
+                    }
+                }
+                _ if byte >= 0x80 => {
+                    let c = self.source_text[self.pos..].chars().next().unwrap();
+                    if c.is_whitespace() || c == '\u{FEFF}' {
+                        break;
+                    }
+                    self.pos += c.len_utf8();
+                }
+                _ if is_identifier_part(byte) => self.pos += 1,
+                _ => break,
+            }
+        }
+        (&self.source_text[start..self.pos], escaped)
+    }
+
+    /// Skip a JSX element or fragment at `<`, returns `false` and does not move if it is not JSX,
+    /// e.g. the type parameters of a generic arrow function `<T,>() => {}`
+    fn skip_jsx_element(&mut self) -> bool {
+        let start = self.pos;
+        let (calls, errors) = (self.calls.len(), self.errors.len());
+        if self.depth < MAX_NESTING_DEPTH {
+            self.depth += 1;
+            let is_jsx = self.skip_jsx_element_impl();
+            self.depth -= 1;
+            if is_jsx {
+                return true;
+            }
+        }
+        self.pos = start;
+        self.calls.truncate(calls);
+        self.errors.truncate(errors);
+        false
+    }
+
+    fn skip_jsx_element_impl(&mut self) -> bool {
+        self.pos = self.skip_trivia(self.pos + 1);
+        // `<>`
+        if self.bytes.get(self.pos) == Some(&b'>') {
+            self.pos += 1;
+            return self.skip_jsx_children();
+        }
+        if !self.skip_jsx_name() {
+            return false;
+        }
+        let next = self.skip_trivia(self.pos);
+        if self.bytes.get(next) == Some(&b',') || self.source_text[next..].starts_with("extends ") {
+            return false;
+        }
+        loop {
+            self.pos = self.skip_trivia(self.pos);
+            match self.bytes.get(self.pos) {
+                Some(b'/') => {
+                    self.pos = self.skip_trivia(self.pos + 1);
+                    if self.bytes.get(self.pos) != Some(&b'>') {
+                        return false;
+                    }
+                    self.pos += 1;
+                    return true;
+                }
+                Some(b'>') => {
+                    self.pos += 1;
+                    return self.skip_jsx_children();
+                }
+                // `{...props}`
+                Some(b'{') => {
+                    if !self.skip_jsx_expression() {
+                        return false;
+                    }
+                }
+                Some(_) if self.skip_jsx_name() => {
+                    let next = self.skip_trivia(self.pos);
+                    if self.bytes.get(next) != Some(&b'=') {
+                        continue;
+                    }
+                    self.pos = self.skip_trivia(next + 1);
+                    let is_value = match self.bytes.get(self.pos) {
+                        Some(&quote @ (b'"' | b'\'')) => {
+                            match self.bytes[self.pos + 1..].iter().position(|&b| b == quote) {
+                                Some(end) => {
+                                    self.pos += end + 2;
+                                    true
+                                }
+                                None => false,
+                            }
+                        }
+                        Some(b'{') => self.skip_jsx_expression(),
+                        Some(b'<') => self.skip_jsx_element(),
+                        _ => false,
+                    };
+                    if !is_value {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    /// Skip the children and the closing tag of an element
+    fn skip_jsx_children(&mut self) -> bool {
+        loop {
+            match self.bytes.get(self.pos) {
+                None => return false,
+                Some(b'{') => {
+                    if !self.skip_jsx_expression() {
+                        return false;
+                    }
+                }
+                Some(b'<') => {
+                    let next = self.skip_trivia(self.pos + 1);
+                    if self.bytes.get(next) == Some(&b'/') {
+                        return match self.source_text[next..].find('>') {
+                            Some(end) => {
+                                self.pos = next + end + 1;
+                                true
+                            }
+                            None => false,
+                        };
+                    }
+                    if !self.skip_jsx_element() {
+                        return false;
+                    }
+                }
+                Some(_) => self.pos += 1,
+            }
+        }
+    }
+
+    /// Skip an element name or an attribute name, e.g. `a`, `a.b` or `a:b-c`
+    fn skip_jsx_name(&mut self) -> bool {
+        let start = self.pos;
+        while let Some(&byte) = self.bytes.get(self.pos) {
+            if !(is_identifier_part(byte) || matches!(byte, b'-' | b':' | b'.') || byte >= 0x80) {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    /// Skip a `{` with JavaScript code in JSX, up to and including its `}`
+    fn skip_jsx_expression(&mut self) -> bool {
+        self.pos += 1;
+        self.scan(/* is_top_level */ false).is_some()
+    }
+
+    /// Span from `start` to the current position
+    fn span(&self, start: usize) -> Span {
+        Span::new(offset_u32(start), offset_u32(self.pos))
+    }
+}
+
+fn is_identifier_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || matches!(byte, b'$' | b'_' | b'\\') || byte >= 0x80
+}
+
+fn is_identifier_part(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'$' | b'_')
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn offset_u32(offset: usize) -> u32 {
+    offset as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::Skipper;
+
+    /// The `import()` and `require()` calls found in `source_text`,
+    /// as the string argument or `None`
+    fn requires(source_text: &str, jsx: bool) -> Vec<Option<&str>> {
+        let mut skipper = Skipper::new(source_text, jsx);
+        assert_eq!(skipper.next_declaration(0), None, "declaration found in {source_text:?}");
+        assert!(skipper.errors.is_empty(), "{source_text:?}: {:?}", skipper.errors);
+        skipper
+            .calls
+            .iter()
+            .map(|call| {
+                call.argument.map(|span| &source_text[span.start as usize..span.end as usize])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn regex_or_division() {
+        for (source_text, expected) in [
+            ("a = b / c; require('x') / 2", 1),
+            ("if (a) /require('fake')/.test(b)", 0),
+            ("a = (b) / require('x') / 2", 1),
+            ("return /'/; require('x')", 1),
+            ("x.return / 2; require('x') / 2", 1),
+            ("a++ / 2; require('x') / 2", 1),
+            ("function f() {} /require('fake')/", 0),
+            ("({} / 2); require('x')", 1),
+            ("const re = /[/]require('fake')/g", 0),
+        ] {
+            assert_eq!(requires(source_text, false).len(), expected, "{source_text:?}");
+        }
+    }
+
+    #[test]
+    fn templates() {
+        let source_text = "`${ { a: `}${require('a')}` } }` + `require('fake')` + require(`b`)";
+        assert_eq!(requires(source_text, false), [Some("'a'"), None]);
+    }
+
+    #[test]
+    fn string_arguments() {
+        let source_text = r#"require("a\"b"); require('c' + d); require ( /* c */ 'e' , f)"#;
+        assert_eq!(requires(source_text, false), [Some(r#""a\"b""#), None, Some("'e'")]);
+    }
+
+    #[test]
+    fn property_names_and_declarations() {
+        let source_text = "a.require('x'); a?.import('x'); function require() {} ({ import: 1 })";
+        assert_eq!(requires(source_text, false), [], "property names are not calls");
+
+        let mut skipper =
+            Skipper::new("import('a'); import.meta; { export } import a from 'a'", false);
+        assert_eq!(
+            skipper.next_declaration(0),
+            Some(37),
+            "only top level declarations stop the scan"
+        );
+    }
+
+    #[test]
+    fn jsx() {
+        let source_text = "<a b='}' c={require('c')}>{'}'} / </a>; <T,>() => require('t')";
+        assert_eq!(requires(source_text, true), [Some("'c'"), Some("'t'")]);
+    }
+
+    #[test]
+    fn blocks() {
+        let source_text = "{ { b } /}/ } require('fake')";
+        let mut skipper = Skipper::new(source_text, false);
+        assert_eq!(
+            skipper.skip_block(1),
+            Some(12),
+            "`}}` in a regular expression does not end the block"
+        );
+        assert!(skipper.calls.is_empty());
+    }
+
+    #[test]
+    fn unterminated() {
+        for source_text in ["'a", "`${a}", "/* a", "a = /b"] {
+            let mut skipper = Skipper::new(source_text, false);
+            assert_eq!(skipper.next_declaration(0), None);
+            assert_eq!(skipper.errors.len(), 1, "{source_text:?}");
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Error recovery, see [`Parser::recover_from_errors`]
+//!
+//! When recovery is enabled, syntax errors inside a statement list or a delimited list
+//! are recorded in `errors` and parsing resumes at the next synchronization point,
+//! leaving an [`InvalidStatement`] or [`InvalidExpression`] in place of the broken node.
+
+use oxc_ast::{ast::*, Span};
+use oxc_diagnostics::{Error, Result};
+
+use crate::{diagnostics, lexer::Kind, Context, Parser};
+
+impl<'a> Parser<'a> {
+    /// Run `f` with error recovery disabled,
+    /// used for speculative parsing where the error decides which branch to take.
+    pub(crate) fn without_recovery<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let recover = self.recover;
+        self.recover = false;
+        let result = f(self);
+        self.recover = recover;
+        result
+    }
+
+    /// Parse a statement, replacing it with an `InvalidStatement` on error when recovering.
+    ///
+    /// Skips to the end of the statement: a `;` or a line break outside of any brackets,
+    /// or the `}` closing the enclosing block.
+    /// # Errors
+    pub(crate) fn parse_statement_or_recover(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<Statement<'a>>,
+    ) -> Result<Statement<'a>> {
+        let span = self.start_span();
+        let ctx = self.ctx;
+        match parse(self) {
+            Ok(stmt) => Ok(stmt),
+            Err(error) if self.recover => {
+                self.recover_from(ctx, error);
+                self.skip_invalid_tokens(span.start, &[Kind::Semicolon, Kind::RCurly], true);
+                self.bump(Kind::Semicolon);
+                // nothing is consumed at the end of file
+                let end = self.prev_token_end.max(span.start);
+                Ok(self.ast.invalid_statement(Span::new(span.start, end)))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Record `error` and restore the state of the parser before the failed parse
+    pub(crate) fn recover_from(&mut self, ctx: Context, error: Error) {
+        self.ctx = ctx;
+        self.state.decorators.clear();
+        self.error(error);
+    }
+
+    /// Skip the tokens of a node which failed to parse.
+    ///
+    /// Stops before one of `terminators` (or a line break if `stop_at_new_line`) outside of brackets.
+    /// If the node did not consume any tokens, the offending token (or the bracketed group it opens)
+    /// is skipped instead so the caller always makes progress.
+    pub(crate) fn skip_invalid_tokens(
+        &mut self,
+        start: u32,
+        terminators: &[Kind],
+        stop_at_new_line: bool,
+    ) {
+        let skip_one = self.prev_token_end <= start;
+        let mut depth = 0u32;
+        while !self.at(Kind::Eof) {
+            let kind = self.cur_kind();
+            if depth == 0 {
+                if skip_one {
+                    if self.prev_token_end > start {
+                        break;
+                    }
+                } else if terminators.contains(&kind)
+                    || (stop_at_new_line && self.cur_token().is_on_new_line)
+                {
+                    break;
+                }
+            }
+            match kind {
+                Kind::LCurly | Kind::LParen | Kind::LBrack => depth += 1,
+                Kind::RCurly | Kind::RParen | Kind::RBrack => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
+    /// Expect a closing `)`, `]` or `}`.
+    ///
+    /// When recovering, a missing token is recorded as an error and parsing continues as if it was present.
+    /// # Errors
+    pub(crate) fn expect_closing(&mut self, kind: Kind) -> Result<()> {
+        if self.recover && !self.at(kind) {
+            let range = self.cur_token().span();
+            self.error(diagnostics::ExpectToken(kind.to_str(), self.cur_kind().to_str(), range));
+            return Ok(());
+        }
+        self.expect(kind)
+    }
+
+    /// An `InvalidExpression` for the tokens skipped from `span`
+    pub(crate) fn invalid_expression(&self, span: Span) -> Expression<'a> {
+        let end = self.prev_token_end.max(span.start);
+        self.ast.invalid_expression(Span::new(span.start, end))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{
+        ast::{Argument, ClassElement, Declaration, Expression, Statement},
+        SourceType,
+    };
+
+    use crate::Parser;
+
+    #[test]
+    fn recover_from_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let parse =
+            |source| Parser::new(&allocator, source, source_type).recover_from_errors(true).parse();
+
+        // missing `;`
+        let ret = parse("a b");
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.program.body.len(), 2);
+
+        // stray tokens are replaced by an invalid statement
+        let ret = parse("let a = ;\nfoo();");
+        assert_eq!(ret.errors.len(), 1);
+        assert!(matches!(ret.program.body[0], Statement::InvalidStatement(_)));
+        assert!(matches!(ret.program.body[1], Statement::ExpressionStatement(_)));
+
+        // missing `)` and `}`
+        let ret = parse("if (a { b }\nfunction f() { c");
+        assert_eq!(ret.errors.len(), 2);
+        assert!(matches!(ret.program.body[0], Statement::IfStatement(_)));
+        assert!(matches!(ret.program.body[1], Statement::Declaration(_)));
+
+        // stray tokens inside argument lists
+        let ret = parse("f(a b, ], c)");
+        assert_eq!(ret.errors.len(), 2);
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        let Expression::CallExpression(call) = &stmt.expression else { unreachable!() };
+        assert_eq!(call.arguments.len(), 4);
+        assert!(matches!(
+            &call.arguments[2],
+            Argument::Expression(Expression::InvalidExpression(_))
+        ));
+
+        // stray tokens inside class bodies
+        let ret = parse("class A { ! foo() {} bar() {} }");
+        assert_eq!(ret.errors.len(), 1);
+        let Statement::Declaration(Declaration::ClassDeclaration(class)) = &ret.program.body[0]
+        else {
+            unreachable!()
+        };
+        assert_eq!(class.body.body.len(), 2);
+        assert!(matches!(class.body.body[0], ClassElement::MethodDefinition(_)));
+
+        // without recovery the program is empty
+        let ret = Parser::new(&allocator, "let a = ;", source_type).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.is_empty());
+    }
+}
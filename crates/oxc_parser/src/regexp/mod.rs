@@ -0,0 +1,1319 @@
+//! Regular Expression Pattern Parser
+//!
+//! Parses the `pattern` of a `RegularExpressionLiteral` into [`oxc_ast::ast::regexp::Pattern`].
+//! * [22.2.1 Patterns](https://tc39.es/ecma262/#sec-patterns)
+//! * [22.2.1.1 Static Semantics: Early Errors](https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors)
+//! * [B.1.2 Regular Expressions Patterns](https://tc39.es/ecma262/#sec-regular-expressions-patterns)
+//!   when neither the `u` nor the `v` flag is present
+//! * [RegExp v flag](https://github.com/tc39/proposal-regexp-v-flag)
+//!
+//! Outside of unicode mode the pattern is read as UTF-16 code units, so a character outside of the BMP
+//! is read as a surrogate pair, the same way as the JavaScript engine would.
+
+mod unicode_property;
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::{
+    ast::{regexp::*, RegExpFlags},
+    AstBuilder, Atom, Span,
+};
+use oxc_diagnostics::{Error, Result};
+
+use crate::{
+    diagnostics,
+    lexer::{is_identifier_part, is_identifier_start_all},
+    EcmaVersion,
+};
+
+/// `(disjunction, alternative)` indices from the outermost to the innermost alternative
+type Alternatives = std::vec::Vec<(u32, u32)>;
+
+pub struct PatternParser<'a> {
+    ast: AstBuilder<'a>,
+
+    /// Pattern source text, without the surrounding `/`
+    source: &'a str,
+
+    /// Offset of `source` in the JavaScript source text
+    offset: u32,
+
+    position: Position,
+
+    /// `u` or `v` flag
+    unicode_mode: bool,
+
+    /// `v` flag
+    unicode_sets_mode: bool,
+
+    /// `\k` is a named reference in unicode mode or when the pattern has a named group
+    named_capture_groups: bool,
+
+    /// Total number of capturing groups in the pattern, for deciding whether `\N` is a back reference
+    capturing_group_count: u32,
+
+    /// Group names with the alternatives they are nested in
+    group_names: std::vec::Vec<(Atom, Span, Alternatives)>,
+
+    /// Alternatives enclosing the current position,
+    /// a group name can be reused in another alternative of the same disjunction
+    alternatives: Alternatives,
+
+    disjunction_count: u32,
+
+    named_references: std::vec::Vec<(Atom, Span)>,
+
+    /// Syntax newer than this version is reported
+    target: EcmaVersion,
+
+    /// Early errors, syntax errors are returned as `Err`
+    errors: std::vec::Vec<Error>,
+}
+
+/// Position in `source`, `low_surrogate` is set while in the middle of a surrogate pair
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    index: usize,
+    low_surrogate: bool,
+}
+
+enum ClassAtom<'a> {
+    Character(Character),
+    Escape(Term<'a>),
+}
+
+impl<'a> PatternParser<'a> {
+    /// `offset` is the source offset of the first character of the pattern
+    pub fn new(allocator: &'a Allocator, source: &'a str, flags: RegExpFlags, offset: u32) -> Self {
+        let unicode_mode = flags.intersects(RegExpFlags::U | RegExpFlags::V);
+        let (capturing_group_count, has_named_group) =
+            count_capturing_groups(source, flags.contains(RegExpFlags::V));
+        Self {
+            ast: AstBuilder::new(allocator),
+            source,
+            offset,
+            position: Position { index: 0, low_surrogate: false },
+            unicode_mode,
+            unicode_sets_mode: flags.contains(RegExpFlags::V),
+            named_capture_groups: unicode_mode || has_named_group,
+            capturing_group_count,
+            group_names: vec![],
+            alternatives: vec![],
+            disjunction_count: 0,
+            named_references: vec![],
+            target: EcmaVersion::default(),
+            errors: vec![],
+        }
+    }
+
+    /// Target ECMAScript version, see [`crate::Parser::target`]
+    #[must_use]
+    pub fn target(mut self, target: EcmaVersion) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Main entry point
+    ///
+    /// # Errors
+    /// Returns all syntax errors and early errors, the pattern is discarded if there are any.
+    pub fn parse(mut self) -> std::result::Result<Pattern<'a>, std::vec::Vec<Error>> {
+        let pattern = match self.parse_pattern() {
+            Ok(pattern) => pattern,
+            Err(error) => {
+                self.errors.push(error);
+                return Err(self.errors);
+            }
+        };
+        for (name, span) in std::mem::take(&mut self.named_references) {
+            if !self.group_names.iter().any(|(group_name, _, _)| *group_name == name) {
+                self.errors.push(diagnostics::RegExpInvalidNamedReference(name, span).into());
+            }
+        }
+        if self.errors.is_empty() { Ok(pattern) } else { Err(self.errors) }
+    }
+
+    fn parse_pattern(&mut self) -> Result<Pattern<'a>> {
+        let start = self.offset();
+        let body = self.parse_disjunction()?;
+        if !self.at_end() {
+            // `parse_disjunction` only stops at `)` or the end of the pattern
+            return Err(self.error("Unmatched ')'", self.offset()));
+        }
+        Ok(Pattern { span: self.span(start), body })
+    }
+
+    /* ---------- Reader ---------- */
+
+    fn read(&self, position: Position) -> Option<(u32, Position)> {
+        let c = self.source[position.index..].chars().next()?;
+        let code_point = c as u32;
+        if self.unicode_mode || code_point <= 0xFFFF {
+            return Some((
+                code_point,
+                Position { index: position.index + c.len_utf8(), low_surrogate: false },
+            ));
+        }
+        let code_point = code_point - 0x10000;
+        let high = 0xD800 + (code_point >> 10);
+        let low = 0xDC00 + (code_point & 0x3FF);
+        if position.low_surrogate {
+            Some((low, Position { index: position.index + c.len_utf8(), low_surrogate: false }))
+        } else {
+            Some((high, Position { index: position.index, low_surrogate: true }))
+        }
+    }
+
+    fn peek(&self) -> Option<u32> {
+        self.read(self.position).map(|(c, _)| c)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<u32> {
+        let mut position = self.position;
+        for _ in 0..n {
+            position = self.read(position)?.1;
+        }
+        self.read(position).map(|(c, _)| c)
+    }
+
+    fn at(&self, c: char) -> bool {
+        self.peek() == Some(c as u32)
+    }
+
+    fn nth_at(&self, n: usize, c: char) -> bool {
+        self.peek_nth(n) == Some(c as u32)
+    }
+
+    fn at_end(&self) -> bool {
+        self.position.index >= self.source.len()
+    }
+
+    fn advance(&mut self) -> Option<u32> {
+        let (c, position) = self.read(self.position)?;
+        self.position = position;
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.at(c) {
+            self.advance();
+            return true;
+        }
+        false
+    }
+
+    /// Source offset of the current position
+    #[allow(clippy::cast_possible_truncation)]
+    fn offset(&self) -> u32 {
+        let mut index = self.position.index;
+        if self.position.low_surrogate {
+            index += self.source[index..].chars().next().map_or(0, char::len_utf8);
+        }
+        self.offset + index as u32
+    }
+
+    fn span(&self, start: u32) -> Span {
+        Span::new(start, self.offset())
+    }
+
+    fn error(&self, message: &'static str, start: u32) -> Error {
+        diagnostics::InvalidRegExp(message, self.span(start)).into()
+    }
+
+    fn check_ecma_version(&mut self, version: EcmaVersion, feature: &'static str, span: Span) {
+        if self.target < version {
+            self.errors.push(diagnostics::UnavailableSyntax(feature, self.target, span).into());
+        }
+    }
+
+    /* ---------- Disjunction ---------- */
+
+    fn parse_disjunction(&mut self) -> Result<Disjunction<'a>> {
+        let start = self.offset();
+        let mut body = self.ast.new_vec();
+        let disjunction = self.disjunction_count;
+        self.disjunction_count += 1;
+        for alternative in 0.. {
+            self.alternatives.push((disjunction, alternative));
+            let result = self.parse_alternative();
+            self.alternatives.pop();
+            body.push(result?);
+            if !self.eat('|') {
+                break;
+            }
+        }
+        Ok(Disjunction { span: self.span(start), body })
+    }
+
+    fn parse_alternative(&mut self) -> Result<Alternative<'a>> {
+        let start = self.offset();
+        let mut body = self.ast.new_vec();
+        while !self.at_end() && !self.at('|') && !self.at(')') {
+            body.push(self.parse_term()?);
+        }
+        Ok(Alternative { span: self.span(start), body })
+    }
+
+    /// `Term` :: Assertion | Atom Quantifier?
+    fn parse_term(&mut self) -> Result<Term<'a>> {
+        let start = self.offset();
+        if let Some(assertion) = self.parse_assertion()? {
+            // Annex B `QuantifiableAssertion`: lookaheads can be quantified outside of unicode mode
+            let quantifiable = !self.unicode_mode
+                && matches!(&assertion, Term::LookAroundAssertion(assertion) if !assertion.kind.is_lookbehind());
+            if quantifiable {
+                return self.parse_optional_quantifier(start, assertion);
+            }
+            if self.at_quantifier() {
+                let start = self.offset();
+                self.advance();
+                return Err(self.error("Nothing to repeat", start));
+            }
+            return Ok(assertion);
+        }
+        let atom = self.parse_atom()?;
+        self.parse_optional_quantifier(start, atom)
+    }
+
+    fn parse_assertion(&mut self) -> Result<Option<Term<'a>>> {
+        let start = self.offset();
+        let boundary = |p: &mut Self, kind, len| {
+            for _ in 0..len {
+                p.advance();
+            }
+            Term::BoundaryAssertion(p.ast.alloc(BoundaryAssertion { span: p.span(start), kind }))
+        };
+        let term = match self.peek() {
+            Some(c) if c == '^' as u32 => boundary(self, BoundaryAssertionKind::Start, 1),
+            Some(c) if c == '$' as u32 => boundary(self, BoundaryAssertionKind::End, 1),
+            Some(c) if c == '\\' as u32 && self.nth_at(1, 'b') => {
+                boundary(self, BoundaryAssertionKind::Boundary, 2)
+            }
+            Some(c) if c == '\\' as u32 && self.nth_at(1, 'B') => {
+                boundary(self, BoundaryAssertionKind::NegativeBoundary, 2)
+            }
+            Some(c) if c == '(' as u32 && self.nth_at(1, '?') => {
+                let kind = if self.nth_at(2, '=') {
+                    LookAroundAssertionKind::Lookahead
+                } else if self.nth_at(2, '!') {
+                    LookAroundAssertionKind::NegativeLookahead
+                } else if self.nth_at(2, '<') && self.nth_at(3, '=') {
+                    LookAroundAssertionKind::Lookbehind
+                } else if self.nth_at(2, '<') && self.nth_at(3, '!') {
+                    LookAroundAssertionKind::NegativeLookbehind
+                } else {
+                    return Ok(None);
+                };
+                let len = if kind.is_lookbehind() { 4 } else { 3 };
+                for _ in 0..len {
+                    self.advance();
+                }
+                let body = self.parse_disjunction()?;
+                if !self.eat(')') {
+                    return Err(self.error("Unterminated group", start));
+                }
+                let span = self.span(start);
+                if kind.is_lookbehind() {
+                    self.check_ecma_version(
+                        EcmaVersion::ES2018,
+                        "RegExp lookbehind assertions",
+                        span,
+                    );
+                }
+                Term::LookAroundAssertion(self.ast.alloc(LookAroundAssertion { span, kind, body }))
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(term))
+    }
+
+    /* ---------- Quantifier ---------- */
+
+    fn at_quantifier(&self) -> bool {
+        match self.peek() {
+            Some(c) if c == '*' as u32 || c == '+' as u32 || c == '?' as u32 => true,
+            Some(c) if c == '{' as u32 => self.peek_quantifier_bounds().is_some(),
+            _ => false,
+        }
+    }
+
+    /// `{n}` `{n,}` `{n,m}`, returns the bounds and the position after `}` without advancing
+    fn peek_quantifier_bounds(&self) -> Option<((u32, Option<u32>), Position)> {
+        let mut position = self.read(self.position)?.1; // `{`
+        let (min, next) = self.read_decimal_digits(position)?;
+        position = next;
+        let (c, next) = self.read(position)?;
+        position = next;
+        let max = if c == ',' as u32 {
+            let max = match self.read_decimal_digits(position) {
+                Some((max, next)) => {
+                    position = next;
+                    Some(max)
+                }
+                None => None,
+            };
+            let (c, next) = self.read(position)?;
+            position = next;
+            if c != '}' as u32 {
+                return None;
+            }
+            max
+        } else if c == '}' as u32 {
+            Some(min)
+        } else {
+            return None;
+        };
+        Some(((min, max), position))
+    }
+
+    /// Reads one or more decimal digits, saturating at `u32::MAX`
+    fn read_decimal_digits(&self, mut position: Position) -> Option<(u32, Position)> {
+        let mut value: Option<u32> = None;
+        while let Some((c, next)) = self.read(position) {
+            let Some(digit) = char::from_u32(c).and_then(|c| c.to_digit(10)) else {
+                break;
+            };
+            value = Some(value.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+            position = next;
+        }
+        value.map(|value| (value, position))
+    }
+
+    fn parse_optional_quantifier(&mut self, start: u32, body: Term<'a>) -> Result<Term<'a>> {
+        let quantifier_start = self.offset();
+        let (min, max) = match self.peek() {
+            Some(c) if c == '*' as u32 => {
+                self.advance();
+                (0, None)
+            }
+            Some(c) if c == '+' as u32 => {
+                self.advance();
+                (1, None)
+            }
+            Some(c) if c == '?' as u32 => {
+                self.advance();
+                (0, Some(1))
+            }
+            Some(c) if c == '{' as u32 => {
+                let Some((bounds, position)) = self.peek_quantifier_bounds() else {
+                    if self.unicode_mode {
+                        self.advance();
+                        return Err(self.error("Incomplete quantifier", quantifier_start));
+                    }
+                    // Annex B `ExtendedPatternCharacter`, parsed as a character by the next `Term`
+                    return Ok(body);
+                };
+                self.position = position;
+                if let (min, Some(max)) = bounds {
+                    if min > max {
+                        return Err(
+                            self.error("numbers out of order in {} quantifier", quantifier_start)
+                        );
+                    }
+                }
+                bounds
+            }
+            _ => return Ok(body),
+        };
+        let greedy = !self.eat('?');
+        let span = self.span(start);
+        Ok(Term::Quantifier(self.ast.alloc(Quantifier { span, min, max, greedy, body })))
+    }
+
+    /* ---------- Atom ---------- */
+
+    fn parse_atom(&mut self) -> Result<Term<'a>> {
+        let start = self.offset();
+        let Some(c) = self.peek() else {
+            return Err(self.error("Unexpected end of pattern", start));
+        };
+        match char::from_u32(c) {
+            Some('.') => {
+                self.advance();
+                Ok(Term::Dot(self.ast.alloc(Dot { span: self.span(start) })))
+            }
+            Some('(') => self.parse_group(),
+            Some('[') => {
+                let (class, _) = self.parse_character_class()?;
+                Ok(Term::CharacterClass(self.ast.alloc(class)))
+            }
+            Some('\\') => self.parse_atom_escape(),
+            Some('*' | '+' | '?') => {
+                self.advance();
+                Err(self.error("Nothing to repeat", start))
+            }
+            Some('{') if self.unicode_mode => {
+                self.advance();
+                Err(self.error("Lone quantifier brackets", start))
+            }
+            Some('{') if self.peek_quantifier_bounds().is_some() => {
+                self.advance();
+                Err(self.error("Nothing to repeat", start))
+            }
+            Some('}') if self.unicode_mode => {
+                self.advance();
+                Err(self.error("Lone quantifier brackets", start))
+            }
+            Some(']') if self.unicode_mode => {
+                self.advance();
+                Err(self.error("Lone ']'", start))
+            }
+            _ => {
+                self.advance();
+                let character =
+                    Character { span: self.span(start), kind: CharacterKind::Symbol, value: c };
+                Ok(Term::Character(self.ast.alloc(character)))
+            }
+        }
+    }
+
+    /// `(...)` `(?:...)` `(?<name>...)`
+    fn parse_group(&mut self) -> Result<Term<'a>> {
+        let start = self.offset();
+        self.advance(); // `(`
+        let mut name = None;
+        let is_capturing = if self.eat('?') {
+            if self.eat(':') {
+                false
+            } else if self.eat('<') {
+                let name_start = self.offset();
+                let group_name = self.parse_group_name()?;
+                let span = self.span(name_start);
+                self.check_ecma_version(EcmaVersion::ES2018, "RegExp named capture groups", span);
+                let previous = self
+                    .group_names
+                    .iter()
+                    .filter(|(previous, _, _)| *previous == group_name)
+                    .map(|(_, previous, alternatives)| {
+                        (*previous, can_both_participate(alternatives, &self.alternatives))
+                    })
+                    // A duplicate that can match together with this group is an error
+                    .reduce(|a, b| if a.1 { a } else { b });
+                match previous {
+                    Some((previous, true)) => self.errors.push(
+                        diagnostics::RegExpDuplicateCaptureGroupName(
+                            group_name.clone(),
+                            previous,
+                            span,
+                        )
+                        .into(),
+                    ),
+                    Some((_, false)) => self.check_ecma_version(
+                        EcmaVersion::ESNext,
+                        "RegExp duplicate named capture groups",
+                        span,
+                    ),
+                    None => {}
+                }
+                self.group_names.push((group_name.clone(), span, self.alternatives.clone()));
+                name = Some(group_name);
+                true
+            } else {
+                return Err(self.error("Invalid group", start));
+            }
+        } else {
+            true
+        };
+        let body = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return Err(self.error("Unterminated group", start));
+        }
+        let span = self.span(start);
+        Ok(if is_capturing {
+            Term::CapturingGroup(self.ast.alloc(CapturingGroup { span, name, body }))
+        } else {
+            Term::IgnoreGroup(self.ast.alloc(IgnoreGroup { span, body }))
+        })
+    }
+
+    /// `RegExpIdentifierName` followed by `>`, the leading `<` is already consumed
+    fn parse_group_name(&mut self) -> Result<Atom> {
+        let start = self.offset();
+        let mut name = String::new();
+        loop {
+            if self.eat('>') {
+                break;
+            }
+            let c = if self.eat('\\') {
+                if !self.eat('u') {
+                    return Err(self.error("Invalid capture group name", start));
+                }
+                // escapes in group names are always read in unicode mode
+                self.parse_unicode_escape(true)
+            } else {
+                self.advance().map(|c| self.combine_surrogate_pair(c))
+            };
+            let c = c.and_then(char::from_u32);
+            let is_valid = c.map_or(false, |c| {
+                if name.is_empty() { is_identifier_start_all(c) } else { is_identifier_part(c) }
+            });
+            match c {
+                Some(c) if is_valid => name.push(c),
+                _ => return Err(self.error("Invalid capture group name", start)),
+            }
+        }
+        if name.is_empty() {
+            return Err(self.error("Invalid capture group name", start));
+        }
+        Ok(Atom::from(name))
+    }
+
+    /// Combine a surrogate pair read as two code units outside of unicode mode
+    fn combine_surrogate_pair(&mut self, high: u32) -> u32 {
+        if (0xD800..=0xDBFF).contains(&high) {
+            if let Some(low) = self.peek().filter(|low| (0xDC00..=0xDFFF).contains(low)) {
+                self.advance();
+                return (high - 0xD800) * 0x400 + low - 0xDC00 + 0x10000;
+            }
+        }
+        high
+    }
+
+    /* ---------- Escapes ---------- */
+
+    /// `\` `AtomEscape`
+    fn parse_atom_escape(&mut self) -> Result<Term<'a>> {
+        let start = self.offset();
+        self.advance(); // `\`
+        let Some(c) = self.peek().and_then(char::from_u32) else {
+            return Err(self.error("\\ at end of pattern", start));
+        };
+        match c {
+            '1'..='9' => {
+                let checkpoint = self.position;
+                let (index, position) = self.read_decimal_digits(self.position).unwrap();
+                if self.unicode_mode || index <= self.capturing_group_count {
+                    self.position = position;
+                    if index > self.capturing_group_count {
+                        return Err(self.error("Invalid escape", start));
+                    }
+                    let span = self.span(start);
+                    return Ok(Term::IndexedReference(
+                        self.ast.alloc(IndexedReference { span, index }),
+                    ));
+                }
+                // Annex B: not a back reference, read as a legacy octal escape or identity escape
+                self.position = checkpoint;
+                let character = self.parse_character_escape(start, false)?;
+                Ok(Term::Character(self.ast.alloc(character)))
+            }
+            'k' if self.named_capture_groups => {
+                self.advance();
+                if !self.eat('<') {
+                    return Err(self.error("Invalid named reference", start));
+                }
+                let name = self.parse_group_name()?;
+                let span = self.span(start);
+                self.named_references.push((name.clone(), span));
+                Ok(Term::NamedReference(self.ast.alloc(NamedReference { span, name })))
+            }
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => {
+                let escape = self.parse_character_class_escape(start, c);
+                Ok(Term::CharacterClassEscape(self.ast.alloc(escape)))
+            }
+            'p' | 'P' if self.unicode_mode => {
+                let escape = self.parse_unicode_property_escape(start, c == 'P')?;
+                Ok(Term::UnicodePropertyEscape(self.ast.alloc(escape)))
+            }
+            _ => {
+                let character = self.parse_character_escape(start, false)?;
+                Ok(Term::Character(self.ast.alloc(character)))
+            }
+        }
+    }
+
+    fn parse_character_class_escape(&mut self, start: u32, c: char) -> CharacterClassEscape {
+        self.advance();
+        let kind = match c {
+            'd' => CharacterClassEscapeKind::D,
+            'D' => CharacterClassEscapeKind::NegativeD,
+            's' => CharacterClassEscapeKind::S,
+            'S' => CharacterClassEscapeKind::NegativeS,
+            'w' => CharacterClassEscapeKind::W,
+            'W' => CharacterClassEscapeKind::NegativeW,
+            _ => unreachable!(),
+        };
+        CharacterClassEscape { span: self.span(start), kind }
+    }
+
+    /// `\p{Name}` `\p{Name=Value}`, the leading `\` is already consumed
+    fn parse_unicode_property_escape(
+        &mut self,
+        start: u32,
+        negative: bool,
+    ) -> Result<UnicodePropertyEscape> {
+        self.advance(); // `p` or `P`
+        if !self.eat('{') {
+            return Err(self.error("Invalid property name", start));
+        }
+        let name = self.read_property_word();
+        let value = if self.eat('=') { Some(self.read_property_word()) } else { None };
+        if !self.eat('}') {
+            return Err(self.error("Invalid property name", start));
+        }
+        let mut strings = false;
+        let is_valid = match &value {
+            Some(value) => unicode_property::is_valid_property_name_and_value(&name, value),
+            None if unicode_property::is_property_of_strings(&name) => {
+                strings = true;
+                self.unicode_sets_mode && !negative
+            }
+            None => unicode_property::is_valid_lone_property(&name),
+        };
+        if !is_valid {
+            return Err(self.error("Invalid property name", start));
+        }
+        let span = self.span(start);
+        self.check_ecma_version(EcmaVersion::ES2018, "RegExp Unicode property escapes", span);
+        Ok(UnicodePropertyEscape {
+            span,
+            negative,
+            strings,
+            name: Atom::from(name),
+            value: value.map(Atom::from),
+        })
+    }
+
+    fn read_property_word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(c) = self.peek().and_then(char::from_u32) {
+            if !(c.is_ascii_alphanumeric() || c == '_') {
+                break;
+            }
+            self.advance();
+            word.push(c);
+        }
+        word
+    }
+
+    /// `CharacterEscape` and Annex B extensions, the leading `\` is already consumed.
+    /// `in_class` enables `\-`, `\b` and `\c` followed by a digit or `_`.
+    fn parse_character_escape(&mut self, start: u32, in_class: bool) -> Result<Character> {
+        let Some(c) = self.advance() else {
+            return Err(self.error("\\ at end of pattern", start));
+        };
+        let character = |p: &Self, kind, value| Character { span: p.span(start), kind, value };
+        let (kind, value) = match char::from_u32(c) {
+            Some('f') => (CharacterKind::SingleEscape, 0x0C),
+            Some('n') => (CharacterKind::SingleEscape, 0x0A),
+            Some('r') => (CharacterKind::SingleEscape, 0x0D),
+            Some('t') => (CharacterKind::SingleEscape, 0x09),
+            Some('v') => (CharacterKind::SingleEscape, 0x0B),
+            Some('b') if in_class => (CharacterKind::SingleEscape, 0x08),
+            Some('-') if in_class && self.unicode_mode => (CharacterKind::Identifier, c),
+            Some('c') => {
+                let letter = self.peek().and_then(char::from_u32);
+                match letter {
+                    Some(l) if l.is_ascii_alphabetic() => {
+                        self.advance();
+                        (CharacterKind::ControlLetter, l as u32 % 32)
+                    }
+                    // Annex B `ClassControlLetter`
+                    Some(l)
+                        if in_class && !self.unicode_mode && (l.is_ascii_digit() || l == '_') =>
+                    {
+                        self.advance();
+                        (CharacterKind::ControlLetter, l as u32 % 32)
+                    }
+                    _ if self.unicode_mode => {
+                        return Err(self.error("Invalid unicode escape", start));
+                    }
+                    _ => {
+                        // Annex B: `\c` is read as `\` and the `c` is read as the next character
+                        self.position = self.read(self.position_at(start)).unwrap().1;
+                        return Ok(character(self, CharacterKind::Symbol, '\\' as u32));
+                    }
+                }
+            }
+            Some('0') if !self.peek().map_or(false, |c| ('0' as u32..='9' as u32).contains(&c)) => {
+                (CharacterKind::Null, 0)
+            }
+            Some('0'..='7') if !self.unicode_mode => {
+                (CharacterKind::Octal, self.read_legacy_octal_escape(c - '0' as u32))
+            }
+            Some('0'..='9') if self.unicode_mode => {
+                return Err(self.error("Invalid decimal escape", start));
+            }
+            Some('x') => {
+                let checkpoint = self.position;
+                match self.read_hex_digits(2) {
+                    Some(value) => (CharacterKind::HexadecimalEscape, value),
+                    None if self.unicode_mode => return Err(self.error("Invalid escape", start)),
+                    None => {
+                        self.position = checkpoint;
+                        (CharacterKind::Identifier, c)
+                    }
+                }
+            }
+            Some('u') => {
+                let checkpoint = self.position;
+                match self.parse_unicode_escape(self.unicode_mode) {
+                    Some(value) => (CharacterKind::UnicodeEscape, value),
+                    None if self.unicode_mode => {
+                        return Err(self.error("Invalid Unicode escape", start));
+                    }
+                    None => {
+                        self.position = checkpoint;
+                        (CharacterKind::Identifier, c)
+                    }
+                }
+            }
+            _ if self.unicode_mode => {
+                // `IdentityEscape[+UnicodeMode]` :: `SyntaxCharacter` | `/`
+                if !char::from_u32(c).map_or(false, |c| "^$\\.*+?()[]{}|/".contains(c)) {
+                    return Err(self.error("Invalid escape", start));
+                }
+                (CharacterKind::Identifier, c)
+            }
+            Some('k') if self.named_capture_groups => {
+                return Err(self.error("Invalid escape", start));
+            }
+            _ => (CharacterKind::Identifier, c),
+        };
+        Ok(character(self, kind, value))
+    }
+
+    /// Recover the position of a source offset that is known to be on a character boundary
+    fn position_at(&self, offset: u32) -> Position {
+        Position { index: (offset - self.offset) as usize, low_surrogate: false }
+    }
+
+    /// Annex B `LegacyOctalEscapeSequence`, the first digit is already consumed
+    fn read_legacy_octal_escape(&mut self, first: u32) -> u32 {
+        let octal_digit = |c: Option<u32>| {
+            c.filter(|c| ('0' as u32..='7' as u32).contains(c)).map(|c| c - '0' as u32)
+        };
+        let mut value = first;
+        if let Some(second) = octal_digit(self.peek()) {
+            self.advance();
+            value = value * 8 + second;
+            if first <= 3 {
+                if let Some(third) = octal_digit(self.peek()) {
+                    self.advance();
+                    value = value * 8 + third;
+                }
+            }
+        }
+        value
+    }
+
+    fn read_hex_digits(&mut self, len: usize) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..len {
+            let digit = self.peek().and_then(char::from_u32).and_then(|c| c.to_digit(16))?;
+            self.advance();
+            value = (value << 4) | digit;
+        }
+        Some(value)
+    }
+
+    /// `RegExpUnicodeEscapeSequence`, the leading `\u` is already consumed.
+    /// In unicode mode, `\u{...}` and surrogate pairs written as two escapes are accepted.
+    fn parse_unicode_escape(&mut self, unicode_mode: bool) -> Option<u32> {
+        if unicode_mode && self.eat('{') {
+            let mut value: u32 = 0;
+            let mut has_digit = false;
+            while let Some(digit) =
+                self.peek().and_then(char::from_u32).and_then(|c| c.to_digit(16))
+            {
+                self.advance();
+                has_digit = true;
+                value = (value << 4) | digit;
+                if value > 0x10_FFFF {
+                    return None;
+                }
+            }
+            return (has_digit && self.eat('}')).then_some(value);
+        }
+        let high = self.read_hex_digits(4)?;
+        if unicode_mode && (0xD800..=0xDBFF).contains(&high) && self.at('\\') && self.nth_at(1, 'u')
+        {
+            let checkpoint = self.position;
+            self.advance();
+            self.advance();
+            match self.read_hex_digits(4) {
+                Some(low) if (0xDC00..=0xDFFF).contains(&low) => {
+                    return Some((high - 0xD800) * 0x400 + low - 0xDC00 + 0x10000);
+                }
+                _ => self.position = checkpoint,
+            }
+        }
+        Some(high)
+    }
+
+    /* ---------- Character Class ---------- */
+
+    /// Returns the class and whether it may contain strings, which is only possible with the `v` flag
+    fn parse_character_class(&mut self) -> Result<(CharacterClass<'a>, bool)> {
+        let start = self.offset();
+        self.advance(); // `[`
+        let negative = self.eat('^');
+        let (kind, body, may_contain_strings) = if self.unicode_sets_mode {
+            self.parse_class_set_expression(start)?
+        } else {
+            (CharacterClassContentsKind::Union, self.parse_class_ranges(start)?, false)
+        };
+        if negative && may_contain_strings {
+            return Err(self.error("Negated character class may contain strings", start));
+        }
+        let class = CharacterClass { span: self.span(start), negative, kind, body };
+        Ok((class, may_contain_strings && !negative))
+    }
+
+    /// `ClassRanges` without the `v` flag, up to and including the closing `]`
+    fn parse_class_ranges(&mut self, start: u32) -> Result<Vec<'a, CharacterClassContents<'a>>> {
+        let mut body = self.ast.new_vec();
+        loop {
+            if self.at_end() {
+                return Err(self.error("Unterminated character class", start));
+            }
+            if self.eat(']') {
+                break;
+            }
+            let atom_start = self.offset();
+            let min = self.parse_class_atom()?;
+            if !(self.at('-') && self.peek_nth(1).is_some() && !self.nth_at(1, ']')) {
+                body.push(self.class_atom_to_contents(min));
+                continue;
+            }
+            let dash_start = self.offset();
+            self.advance(); // `-`
+            let dash = Character {
+                span: self.span(dash_start),
+                kind: CharacterKind::Symbol,
+                value: '-' as u32,
+            };
+            let max = self.parse_class_atom()?;
+            match (min, max) {
+                (ClassAtom::Character(min), ClassAtom::Character(max)) => {
+                    if min.value > max.value {
+                        return Err(self.error("Range out of order in character class", atom_start));
+                    }
+                    let range = CharacterClassRange { span: self.span(atom_start), min, max };
+                    body.push(CharacterClassContents::CharacterClassRange(self.ast.alloc(range)));
+                }
+                (min, max) => {
+                    if self.unicode_mode {
+                        return Err(self.error("Invalid character class", atom_start));
+                    }
+                    // Annex B: a class escape on either side of `-` makes the `-` a literal
+                    body.push(self.class_atom_to_contents(min));
+                    body.push(CharacterClassContents::Character(self.ast.alloc(dash)));
+                    body.push(self.class_atom_to_contents(max));
+                }
+            }
+        }
+        Ok(body)
+    }
+
+    fn class_atom_to_contents(&self, atom: ClassAtom<'a>) -> CharacterClassContents<'a> {
+        match atom {
+            ClassAtom::Character(character) => {
+                CharacterClassContents::Character(self.ast.alloc(character))
+            }
+            ClassAtom::Escape(Term::CharacterClassEscape(escape)) => {
+                CharacterClassContents::CharacterClassEscape(escape)
+            }
+            ClassAtom::Escape(Term::UnicodePropertyEscape(escape)) => {
+                CharacterClassContents::UnicodePropertyEscape(escape)
+            }
+            ClassAtom::Escape(_) => unreachable!(),
+        }
+    }
+
+    /// `ClassAtom` without the `v` flag
+    fn parse_class_atom(&mut self) -> Result<ClassAtom<'a>> {
+        let start = self.offset();
+        let Some(c) = self.advance() else {
+            return Err(self.error("Unterminated character class", start));
+        };
+        if c != '\\' as u32 {
+            return Ok(ClassAtom::Character(Character {
+                span: self.span(start),
+                kind: CharacterKind::Symbol,
+                value: c,
+            }));
+        }
+        self.parse_class_escape(start)
+    }
+
+    /// `ClassEscape`, the leading `\` is already consumed
+    fn parse_class_escape(&mut self, start: u32) -> Result<ClassAtom<'a>> {
+        match self.peek().and_then(char::from_u32) {
+            Some(c @ ('d' | 'D' | 's' | 'S' | 'w' | 'W')) => {
+                let escape = self.parse_character_class_escape(start, c);
+                Ok(ClassAtom::Escape(Term::CharacterClassEscape(self.ast.alloc(escape))))
+            }
+            Some(c @ ('p' | 'P')) if self.unicode_mode => {
+                let escape = self.parse_unicode_property_escape(start, c == 'P')?;
+                Ok(ClassAtom::Escape(Term::UnicodePropertyEscape(self.ast.alloc(escape))))
+            }
+            _ => Ok(ClassAtom::Character(self.parse_character_escape(start, true)?)),
+        }
+    }
+
+    /// `ClassSetExpression` with the `v` flag, up to and including the closing `]`
+    fn parse_class_set_expression(
+        &mut self,
+        start: u32,
+    ) -> Result<(CharacterClassContentsKind, Vec<'a, CharacterClassContents<'a>>, bool)> {
+        let mut body = self.ast.new_vec();
+        if self.eat(']') {
+            return Ok((CharacterClassContentsKind::Union, body, false));
+        }
+        let (first, first_strings) = self.parse_class_set_operand_or_range()?;
+        let is_range = matches!(first, CharacterClassContents::CharacterClassRange(_));
+        body.push(first);
+
+        let kind = if self.at('&') && self.nth_at(1, '&') {
+            CharacterClassContentsKind::Intersection
+        } else if self.at('-') && self.nth_at(1, '-') {
+            CharacterClassContentsKind::Subtraction
+        } else {
+            CharacterClassContentsKind::Union
+        };
+
+        if kind == CharacterClassContentsKind::Union {
+            let mut may_contain_strings = first_strings;
+            loop {
+                if self.at_end() {
+                    return Err(self.error("Unterminated character class", start));
+                }
+                if self.eat(']') {
+                    break;
+                }
+                if (self.at('&') && self.nth_at(1, '&')) || (self.at('-') && self.nth_at(1, '-')) {
+                    let operator_start = self.offset();
+                    self.advance();
+                    self.advance();
+                    return Err(
+                        self.error("Invalid set operation in character class", operator_start)
+                    );
+                }
+                let (operand, strings) = self.parse_class_set_operand_or_range()?;
+                may_contain_strings |= strings;
+                body.push(operand);
+            }
+            return Ok((kind, body, may_contain_strings));
+        }
+
+        if is_range {
+            return Err(self.error("Invalid set operation in character class", start));
+        }
+
+        let (operator, other) = match kind {
+            CharacterClassContentsKind::Intersection => ('&', '-'),
+            _ => ('-', '&'),
+        };
+        // intersection may contain strings when all operands may, subtraction when the first one may
+        let mut may_contain_strings = first_strings;
+        loop {
+            if self.at_end() {
+                return Err(self.error("Unterminated character class", start));
+            }
+            if self.eat(']') {
+                break;
+            }
+            let operator_start = self.offset();
+            if !(self.at(operator) && self.nth_at(1, operator)) {
+                if self.at(other) && self.nth_at(1, other) {
+                    self.advance();
+                    self.advance();
+                }
+                return Err(self.error("Invalid set operation in character class", operator_start));
+            }
+            self.advance();
+            self.advance();
+            // `&&&` is not allowed
+            if operator == '&' && self.at('&') {
+                self.advance();
+                return Err(self.error("Invalid character in character class", operator_start));
+            }
+            let (operand, strings) = self.parse_class_set_operand()?;
+            if kind == CharacterClassContentsKind::Intersection {
+                may_contain_strings &= strings;
+            }
+            body.push(operand);
+        }
+        Ok((kind, body, may_contain_strings))
+    }
+
+    /// `ClassSetRange` or `ClassSetOperand`
+    fn parse_class_set_operand_or_range(&mut self) -> Result<(CharacterClassContents<'a>, bool)> {
+        let start = self.offset();
+        let (operand, strings) = self.parse_class_set_operand()?;
+        let CharacterClassContents::Character(min) = operand else {
+            return Ok((operand, strings));
+        };
+        // `--` is the subtraction operator
+        if !self.at('-') || self.nth_at(1, '-') {
+            return Ok((CharacterClassContents::Character(min), false));
+        }
+        self.advance(); // `-`
+        let (max, _) = self.parse_class_set_operand()?;
+        let CharacterClassContents::Character(max) = max else {
+            return Err(self.error("Invalid character class", start));
+        };
+        if min.value > max.value {
+            return Err(self.error("Range out of order in character class", start));
+        }
+        let range =
+            CharacterClassRange { span: self.span(start), min: min.unbox(), max: max.unbox() };
+        Ok((CharacterClassContents::CharacterClassRange(self.ast.alloc(range)), false))
+    }
+
+    /// `ClassSetOperand` :: `NestedClass` | `ClassStringDisjunction` | `ClassSetCharacter`
+    fn parse_class_set_operand(&mut self) -> Result<(CharacterClassContents<'a>, bool)> {
+        let start = self.offset();
+        let Some(c) = self.peek() else {
+            return Err(self.error("Unterminated character class", start));
+        };
+        match char::from_u32(c) {
+            Some('[') => {
+                let (class, strings) = self.parse_character_class()?;
+                Ok((CharacterClassContents::NestedCharacterClass(self.ast.alloc(class)), strings))
+            }
+            Some('\\') if self.nth_at(1, 'q') => {
+                self.advance();
+                self.advance();
+                let disjunction = self.parse_class_string_disjunction(start)?;
+                let strings = disjunction.strings;
+                Ok((
+                    CharacterClassContents::ClassStringDisjunction(self.ast.alloc(disjunction)),
+                    strings,
+                ))
+            }
+            Some('\\') => {
+                self.advance();
+                match self.parse_class_set_escape(start)? {
+                    ClassAtom::Character(character) => {
+                        Ok((CharacterClassContents::Character(self.ast.alloc(character)), false))
+                    }
+                    ClassAtom::Escape(Term::UnicodePropertyEscape(escape)) => {
+                        let strings = escape.strings;
+                        Ok((CharacterClassContents::UnicodePropertyEscape(escape), strings))
+                    }
+                    atom @ ClassAtom::Escape(_) => Ok((self.class_atom_to_contents(atom), false)),
+                }
+            }
+            _ => {
+                let character = self.parse_class_set_character(start)?;
+                Ok((CharacterClassContents::Character(self.ast.alloc(character)), false))
+            }
+        }
+    }
+
+    /// `ClassSetCharacter` without the leading `\`
+    fn parse_class_set_character(&mut self, start: u32) -> Result<Character> {
+        let c = self.advance().unwrap();
+        let is_syntax_character = char::from_u32(c).map_or(false, |c| "()[]{}/-\\|".contains(c));
+        let is_reserved_double_punctuator = self.peek() == Some(c)
+            && char::from_u32(c).map_or(false, |c| "&!#$%*+,.:;<=>?@^`~".contains(c));
+        if is_syntax_character || is_reserved_double_punctuator {
+            return Err(self.error("Invalid character in character class", start));
+        }
+        Ok(Character { span: self.span(start), kind: CharacterKind::Symbol, value: c })
+    }
+
+    /// `ClassSetCharacter` escapes with the `v` flag, the leading `\` is already consumed
+    fn parse_class_set_escape(&mut self, start: u32) -> Result<ClassAtom<'a>> {
+        let reserved_punctuator =
+            self.peek().and_then(char::from_u32).filter(|c| "&-!#%,:;<=>@`~".contains(*c));
+        if let Some(c) = reserved_punctuator {
+            self.advance();
+            let character = Character {
+                span: self.span(start),
+                kind: CharacterKind::Identifier,
+                value: c as u32,
+            };
+            return Ok(ClassAtom::Character(character));
+        }
+        self.parse_class_escape(start)
+    }
+
+    /// `\q{abc|d}`, the leading `\q` is already consumed
+    fn parse_class_string_disjunction(&mut self, start: u32) -> Result<ClassStringDisjunction<'a>> {
+        if !self.eat('{') {
+            return Err(self.error("Invalid escape", start));
+        }
+        let mut body = self.ast.new_vec();
+        let mut strings = false;
+        loop {
+            let string_start = self.offset();
+            let mut characters = self.ast.new_vec();
+            while !self.at('|') && !self.at('}') {
+                if self.at_end() {
+                    return Err(self.error("Unterminated character class", start));
+                }
+                let character_start = self.offset();
+                let character = if self.eat('\\') {
+                    match self.parse_class_set_escape(character_start)? {
+                        ClassAtom::Character(character) => character,
+                        ClassAtom::Escape(_) => {
+                            return Err(self.error("Invalid escape", character_start));
+                        }
+                    }
+                } else {
+                    self.parse_class_set_character(character_start)?
+                };
+                characters.push(character);
+            }
+            strings |= characters.len() != 1;
+            body.push(ClassString { span: self.span(string_start), body: characters });
+            if self.eat('}') {
+                break;
+            }
+            self.advance(); // `|`
+        }
+        Ok(ClassStringDisjunction { span: self.span(start), strings, body })
+    }
+}
+
+/// Count the capturing groups and find out whether there is a named group in `source`,
+/// so forward references such as `\2(a)(b)` and `\k<a>(?<a>)` can be recognized
+/// Whether two groups can both match, which is the case unless they are in different alternatives
+/// of the same disjunction, given the alternatives enclosing each group
+fn can_both_participate(a: &[(u32, u32)], b: &[(u32, u32)]) -> bool {
+    a.iter()
+        .zip(b)
+        .find(|(a, b)| a != b)
+        .map_or(true, |((a_disjunction, _), (b_disjunction, _))| a_disjunction != b_disjunction)
+}
+
+fn count_capturing_groups(source: &str, unicode_sets_mode: bool) -> (u32, bool) {
+    let bytes = source.as_bytes();
+    let mut count = 0;
+    let mut has_named_group = false;
+    let mut class_depth = 0u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'[' if class_depth == 0 || unicode_sets_mode => class_depth += 1,
+            b']' if class_depth > 0 => class_depth -= 1,
+            b'(' if class_depth == 0 => {
+                if bytes.get(i + 1) != Some(&b'?') {
+                    count += 1;
+                } else if bytes.get(i + 2) == Some(&b'<')
+                    && !matches!(bytes.get(i + 3), Some(b'=' | b'!'))
+                {
+                    count += 1;
+                    has_named_group = true;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    (count, has_named_group)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::RegExpFlags;
+
+    use super::PatternParser;
+
+    fn parse(source: &str, flags: &str) -> Result<(), String> {
+        let allocator = Allocator::default();
+        let mut regexp_flags = RegExpFlags::empty();
+        for flag in flags.chars() {
+            regexp_flags |= match flag {
+                'u' => RegExpFlags::U,
+                'v' => RegExpFlags::V,
+                'i' => RegExpFlags::I,
+                _ => unreachable!(),
+            };
+        }
+        PatternParser::new(&allocator, source, regexp_flags, 0)
+            .parse()
+            .map(|_| ())
+            .map_err(|errors| errors[0].to_string())
+    }
+
+    #[test]
+    fn pass() {
+        let pass = [
+            ("", ""),
+            ("a|b|", ""),
+            ("^abc$", ""),
+            ("a*b+?c??d{1}e{1,}f{1,2}?", ""),
+            ("(a)(?:b)(?<name>c)\\k<name>\\1", ""),
+            ("(?<a>x)|(?<a>y)\\k<a>", ""),
+            ("(?:(?<a>x)|(?<a>y))|(?:(?<a>z)|(?<b>w))", ""),
+            ("(?=a)(?!b)(?<=c)(?<!d)", ""),
+            ("[a-z\\d\\-][^\\]]", ""),
+            ("\\u{1F600}\\p{L}\\P{Script=Greek}", "u"),
+            ("\\p{sc=Grek}\\p{Script_Extensions=Latin}\\p{scx=Zyyy}\\p{gc=Lu}", "u"),
+            ("\\uD83D\\uDE00", "u"),
+            ("[\\p{L}--\\p{Lu}][\\w&&\\d][\\q{abc|d}]", "v"),
+            ("[[a-z]--[aeiou]]\\p{RGI_Emoji}", "v"),
+            // Annex B
+            ("{", ""),
+            ("a{", ""),
+            ("a{1", ""),
+            ("}", ""),
+            ("]", ""),
+            ("\\c", ""),
+            ("[\\c1]", ""),
+            ("\\1", ""),
+            ("\\8", ""),
+            ("\\k", ""),
+            ("\\p{L}", ""),
+            ("[\\d-z]", ""),
+            ("(?=a)*", ""),
+            ("\\07", ""),
+            ("😀{2}", ""),
+        ];
+        for (source, flags) in pass {
+            assert_eq!(parse(source, flags), Ok(()), "/{source}/{flags}");
+        }
+    }
+
+    #[test]
+    fn fail() {
+        let fail = [
+            ("(?<a>x)(?<a>y)", ""),
+            ("(?<a>x)|(?<a>y)(?<a>z)", ""),
+            ("(?:(?<a>x)|y)(?<a>z)", ""),
+            ("(?<a>(?<a>x)|y)", ""),
+            ("[z-a]", ""),
+            ("a{2,1}", ""),
+            ("*", ""),
+            ("a**", ""),
+            ("^*", ""),
+            ("(?<=a)*", ""),
+            ("(", ""),
+            (")", ""),
+            ("(?a)", ""),
+            ("[a", ""),
+            ("\\", ""),
+            ("(?<a>.)\\k<b>", ""),
+            ("\\k<a>", "u"),
+            ("(?<1>a)", ""),
+            ("{", "u"),
+            ("}", "u"),
+            ("]", "u"),
+            ("\\1", "u"),
+            ("\\c", "u"),
+            ("\\a", "u"),
+            ("\\u{110000}", "u"),
+            ("[\\d-z]", "u"),
+            ("\\p{Unknown}", "u"),
+            ("\\p{Script=Nope}", "u"),
+            ("\\p{sc=greek}", "u"),
+            ("\\p{General_Category=Greek}", "u"),
+            ("\\p{RGI_Emoji}", "u"),
+            ("[^\\q{ab}]", "v"),
+            ("[a-z&&b]", "v"),
+            ("[a&&b--c]", "v"),
+            ("[a&&&b]", "v"),
+            ("[(]", "v"),
+            ("[a!!b]", "v"),
+            ("[😀-😁]", ""),
+        ];
+        for (source, flags) in fail {
+            assert!(parse(source, flags).is_err(), "/{source}/{flags}");
+        }
+    }
+}
@@ -9,6 +9,9 @@ pub struct ParserState<'a> {
     pub not_parenthesized_arrow: HashSet<u32>,
 
     pub decorators: Vec<'a, Decorator<'a>>,
+
+    /// Seen `import`, `export`, `import.meta` or top-level await
+    pub has_module_syntax: bool,
 }
 
 impl<'a> ParserState<'a> {
@@ -17,6 +20,7 @@ impl<'a> ParserState<'a> {
             allocator,
             not_parenthesized_arrow: HashSet::new(),
             decorators: Vec::new_in(allocator),
+            has_module_syntax: false,
         }
     }
 
@@ -0,0 +1,313 @@
+//! Public token stream for syntax highlighters and other tools, see [`Tokenizer`]
+
+use bitflags::bitflags;
+use oxc_allocator::Allocator;
+use oxc_ast::{SourceType, Span, Trivias};
+use oxc_diagnostics::Error;
+
+use crate::{lexer::Kind, Parser};
+
+bitflags! {
+    /// Properties of a [`SourceToken`]
+    #[derive(Default)]
+    pub struct TokenFlags: u8 {
+        /// There is a line break between this token and the previous one
+        const ON_NEW_LINE = 1 << 0;
+        /// The identifier or keyword contains a unicode escape sequence, e.g. `\u0061`
+        const ESCAPED = 1 << 1;
+    }
+}
+
+/// The kind of a [`SourceToken`], the keyword or punctuator itself is the source text of its span
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenKind {
+    /// Identifier, including keywords used as identifiers, e.g. `of` in `let of = 1`
+    Identifier,
+    /// Reserved word, or a contextual keyword such as `async` or `type` where it is not an identifier
+    Keyword,
+    /// `#name`
+    PrivateIdentifier,
+    /// `null`
+    Null,
+    /// `true` or `false`
+    Boolean,
+    /// Numeric or BigInt literal
+    Numeric,
+    /// String literal, also a string attribute value in JSX
+    String,
+    /// Regular expression literal, including its flags
+    RegExp,
+    /// A template literal without substitutions,
+    /// or the part of one up to `${`, between `}` and `${`, or from `}` to the end
+    Template,
+    Punctuator,
+    JSXText,
+    /// A character which does not start a token, reported in [`TokenizerReturn::errors`]
+    Unknown,
+    /// `// comment`, only with [`Tokenizer::with_trivia`]
+    Comment,
+    /// `/* comment */`, only with [`Tokenizer::with_trivia`]
+    MultiLineComment,
+    /// A run of whitespace on one line, only with [`Tokenizer::with_trivia`]
+    WhiteSpace,
+    /// A line break, only with [`Tokenizer::with_trivia`]
+    NewLine,
+}
+
+impl TokenKind {
+    pub(crate) fn from_kind(kind: Kind) -> Self {
+        match kind {
+            Kind::Ident => Self::Identifier,
+            Kind::PrivateIdentifier => Self::PrivateIdentifier,
+            Kind::Null => Self::Null,
+            Kind::True | Kind::False => Self::Boolean,
+            Kind::Str => Self::String,
+            Kind::RegExp => Self::RegExp,
+            Kind::NoSubstitutionTemplate
+            | Kind::TemplateHead
+            | Kind::TemplateMiddle
+            | Kind::TemplateTail => Self::Template,
+            Kind::JSXText => Self::JSXText,
+            Kind::Undetermined | Kind::Eof => Self::Unknown,
+            Kind::Comment => Self::Comment,
+            Kind::MultiLineComment => Self::MultiLineComment,
+            Kind::WhiteSpace => Self::WhiteSpace,
+            Kind::NewLine => Self::NewLine,
+            _ if kind.is_number() => Self::Numeric,
+            _ if kind.is_all_keyword() => Self::Keyword,
+            _ => Self::Punctuator,
+        }
+    }
+
+    #[must_use]
+    pub fn is_trivia(self) -> bool {
+        matches!(self, Self::Comment | Self::MultiLineComment | Self::WhiteSpace | Self::NewLine)
+    }
+}
+
+/// A token of the source text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceToken {
+    pub kind: TokenKind,
+    pub span: Span,
+    pub flags: TokenFlags,
+}
+
+impl SourceToken {
+    #[must_use]
+    pub fn is_on_new_line(&self) -> bool {
+        self.flags.contains(TokenFlags::ON_NEW_LINE)
+    }
+
+    #[must_use]
+    pub fn is_trivia(&self) -> bool {
+        self.kind.is_trivia()
+    }
+}
+
+/// Return value of [`Tokenizer::tokenize`]
+///
+/// After an error the parser cannot recover from, the rest of the source text is read token by token
+/// without the parser, so a `/` is always read as a division and `}` never continues a template literal,
+/// unlike in the AST. Regular expressions and template literals after such an error have wrong tokens.
+#[derive(Debug)]
+pub struct TokenizerReturn {
+    /// Tokens in source order, without the final `Eof`
+    pub tokens: Vec<SourceToken>,
+    pub errors: Vec<Error>,
+}
+
+/// Split the source text into tokens.
+///
+/// The source text is tokenized by the parser, so regular expressions versus `/` and `/=`,
+/// template literal continuations, JSX text and TypeScript `>` are resolved in the same way as in the AST.
+/// Syntax errors are recovered from, the tokens after an unrecoverable error are read without context,
+/// see [`TokenizerReturn`].
+pub struct Tokenizer<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+    trivia: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    #[must_use]
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
+        Self { allocator, source_text, source_type, trivia: false }
+    }
+
+    #[must_use]
+    /// Also emit comment, whitespace and line break tokens,
+    /// with the [`TokenKind::Comment`], [`TokenKind::MultiLineComment`], [`TokenKind::WhiteSpace`]
+    /// and [`TokenKind::NewLine`] kinds.
+    pub fn with_trivia(mut self, trivia: bool) -> Self {
+        self.trivia = trivia;
+        self
+    }
+
+    #[must_use]
+    pub fn tokenize(self) -> TokenizerReturn {
+        let mut parser = Parser::new(self.allocator, self.source_text, self.source_type)
+            .recover_from_errors(true);
+        parser.tokens = Some(vec![]);
+        if let Err(error) = parser.parse_program() {
+            parser.error(error);
+        }
+        while !parser.at(Kind::Eof) {
+            parser.bump_any();
+        }
+
+        let tokens = parser.tokens.take().unwrap_or_default();
+        let trivias = parser.lexer.trivia_builder.build();
+        let errors = parser.lexer.errors.into_iter().chain(parser.errors).collect();
+        let tokens =
+            if self.trivia { with_trivia(self.source_text, &tokens, &trivias) } else { tokens };
+        TokenizerReturn { tokens, errors }
+    }
+}
+
+/// Insert comment and whitespace tokens into the gaps between `tokens`
+pub fn with_trivia(
+    source_text: &str,
+    tokens: &[SourceToken],
+    trivias: &Trivias,
+) -> Vec<SourceToken> {
+    let mut result = Vec::with_capacity(tokens.len() * 2);
+    let mut comments = trivias.comments().iter().map(|(start, comment)| {
+        let kind =
+            if comment.is_single_line() { TokenKind::Comment } else { TokenKind::MultiLineComment };
+        (kind, comment.full_span(*start, source_text))
+    });
+    let mut next_comment = comments.next();
+    let mut pos = 0;
+    // `None` is the end of the file
+    for token in tokens.iter().map(Some).chain(std::iter::once(None)) {
+        #[allow(clippy::cast_possible_truncation)]
+        let token_start = token.map_or(source_text.len() as u32, |token| token.span.start);
+        while let Some((kind, span)) = next_comment.filter(|(_, span)| span.start < token_start) {
+            push_whitespace(source_text, Span::new(pos, span.start), &mut result);
+            result.push(SourceToken { kind, span, flags: TokenFlags::empty() });
+            pos = span.end;
+            next_comment = comments.next();
+        }
+        push_whitespace(source_text, Span::new(pos, token_start), &mut result);
+        if let Some(token) = token {
+            result.push(*token);
+            pos = token.span.end;
+        }
+    }
+    result
+}
+
+/// Split `span` into runs of whitespace and single line breaks
+fn push_whitespace(source_text: &str, span: Span, result: &mut Vec<SourceToken>) {
+    let mut start = span.start;
+    let mut chars = span.source_text(source_text).char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        #[allow(clippy::cast_possible_truncation)]
+        let end = span.start + (i + c.len_utf8()) as u32;
+        let kind = match c {
+            '\r' if chars.peek().map_or(false, |(_, c)| *c == '\n') => continue,
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => TokenKind::NewLine,
+            _ if chars
+                .peek()
+                .map_or(false, |(_, c)| !matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')) =>
+            {
+                continue;
+            }
+            _ => TokenKind::WhiteSpace,
+        };
+        result.push(SourceToken { kind, span: Span::new(start, end), flags: TokenFlags::empty() });
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{SourceType, Span};
+
+    use super::{TokenKind, Tokenizer};
+
+    #[test]
+    fn tokenizer() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let tokenize = |source| Tokenizer::new(&allocator, source, source_type).tokenize();
+        let kinds = |source| {
+            let ret = tokenize(source);
+            assert!(ret.errors.is_empty(), "{source}");
+            ret.tokens.iter().map(|token| token.kind).collect::<Vec<_>>()
+        };
+
+        // regular expression versus division
+        assert_eq!(
+            kinds("a / b / c"),
+            [
+                TokenKind::Identifier,
+                TokenKind::Punctuator,
+                TokenKind::Identifier,
+                TokenKind::Punctuator,
+                TokenKind::Identifier
+            ]
+        );
+        assert_eq!(
+            kinds("x = /b/g"),
+            [TokenKind::Identifier, TokenKind::Punctuator, TokenKind::RegExp]
+        );
+
+        // template literal continuation
+        assert_eq!(
+            kinds("`a${b}c${d}e`"),
+            [
+                TokenKind::Template,
+                TokenKind::Identifier,
+                TokenKind::Template,
+                TokenKind::Identifier,
+                TokenKind::Template
+            ]
+        );
+
+        // contextual keywords used as identifiers
+        assert_eq!(
+            kinds("let of = 1"),
+            [TokenKind::Keyword, TokenKind::Identifier, TokenKind::Punctuator, TokenKind::Numeric]
+        );
+
+        // tokens of a speculative parse are not duplicated
+        assert_eq!(kinds("(a) => a").len(), 5);
+
+        // re-lexed `>` operators
+        let ret = tokenize("a >>= b");
+        assert_eq!(ret.tokens[1].kind, TokenKind::Punctuator);
+        assert_eq!(ret.tokens[1].span, Span::new(2, 5));
+
+        let source = "a // b\r\n  /* c */ b";
+        let ret = Tokenizer::new(&allocator, source, source_type).with_trivia(true).tokenize();
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| (token.kind, token.span.source_text(source)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (TokenKind::Identifier, "a"),
+                (TokenKind::WhiteSpace, " "),
+                (TokenKind::Comment, "// b"),
+                (TokenKind::NewLine, "\r\n"),
+                (TokenKind::WhiteSpace, "  "),
+                (TokenKind::MultiLineComment, "/* c */"),
+                (TokenKind::WhiteSpace, " "),
+                (TokenKind::Identifier, "b"),
+            ]
+        );
+        assert!(ret.tokens[7].is_on_new_line());
+
+        // tokens after a syntax error are still returned
+        let ret = tokenize("a b c");
+        assert!(!ret.errors.is_empty());
+        assert_eq!(ret.tokens.len(), 3);
+    }
+}
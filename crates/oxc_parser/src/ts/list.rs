@@ -55,7 +55,7 @@ impl<'a> SeparatedList<'a> for TSTupleElementList<'a> {
             let optional = p.eat(Kind::Question);
             p.expect(Kind::Colon)?;
 
-            let element_type = p.parse_ts_type()?;
+            let element_type = p.parse_type()?;
             self.elements.push(TSTupleElement::TSNamedTupleMember(p.ast.alloc(
                 TSNamedTupleMember { span: p.end_span(span), element_type, label, optional },
             )));
@@ -64,14 +64,14 @@ impl<'a> SeparatedList<'a> for TSTupleElementList<'a> {
         }
 
         if p.eat(Kind::Dot3) {
-            let type_annotation = p.parse_ts_type()?;
+            let type_annotation = p.parse_type()?;
             self.elements.push(TSTupleElement::TSRestType(
                 p.ast.alloc(TSRestType { span: p.end_span(span), type_annotation }),
             ));
             return Ok(());
         }
 
-        let type_annotation = p.parse_ts_type()?;
+        let type_annotation = p.parse_type()?;
         if p.eat(Kind::Question) {
             self.elements.push(TSTupleElement::TSOptionalType(
                 p.ast.alloc(TSOptionalType { span: p.end_span(span), type_annotation }),
@@ -111,11 +111,13 @@ impl<'a> SeparatedList<'a> for TSTypeParameterList<'a> {
 
 pub struct TSInterfaceOrObjectBodyList<'a> {
     pub body: Vec<'a, TSSignature<'a>>,
+    /// Flow object type spreads `{ ...A }`
+    pub spreads: Vec<'a, TSType<'a>>,
 }
 
 impl<'a> TSInterfaceOrObjectBodyList<'a> {
     pub(crate) fn new(p: &Parser<'a>) -> Self {
-        Self { body: p.ast.new_vec() }
+        Self { body: p.ast.new_vec(), spreads: p.ast.new_vec() }
     }
 }
 
@@ -129,6 +131,12 @@ impl<'a> NormalList<'a> for TSInterfaceOrObjectBodyList<'a> {
     }
 
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
+        if p.flow_enabled() && p.at(Kind::Dot3) {
+            if let Some(spread) = p.parse_flow_object_type_spread()? {
+                self.spreads.push(spread);
+            }
+            return Ok(());
+        }
         let property = p.parse_ts_type_signature()?;
         self.body.push(property);
         Ok(())
@@ -153,7 +161,7 @@ impl<'a> SeparatedList<'a> for TSTypeArgumentList<'a> {
     }
 
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
-        let ty = p.parse_ts_type()?;
+        let ty = p.parse_type()?;
         p.re_lex_ts_r_angle();
         self.params.push(ty);
         Ok(())
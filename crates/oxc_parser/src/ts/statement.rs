@@ -1,5 +1,5 @@
 use oxc_allocator::Box;
-use oxc_ast::{ast::*, Span};
+use oxc_ast::{ast::*, GetSpan, Span};
 use oxc_diagnostics::Result;
 
 use super::{
@@ -7,6 +7,7 @@ use super::{
     types::ModifierFlags,
 };
 use crate::{
+    diagnostics,
     js::declaration::{VariableDeclarationContext, VariableDeclarationParent},
     js::function::FunctionKind,
     lexer::Kind,
@@ -31,7 +32,7 @@ impl<'a> Parser<'a> {
 
         let id = self.parse_binding_identifier()?;
         let members = TSEnumMemberList::parse(self)?.members;
-        Ok(self.ast.ts_enum_declaration(span, id, members, modifiers))
+        Ok(self.ast.ts_enum_declaration(self.end_span(span), id, members, modifiers))
     }
 
     pub(crate) fn parse_ts_enum_member(&mut self) -> Result<TSEnumMember<'a>> {
@@ -65,7 +66,7 @@ impl<'a> Parser<'a> {
         if self.at(Kind::Colon) {
             let span = self.start_span();
             self.bump_any(); // bump ':'
-            let type_annotation = self.parse_ts_type()?;
+            let type_annotation = self.parse_type()?;
             Ok(Some(self.ast.ts_type_annotation(self.end_span(span), type_annotation)))
         } else {
             Ok(None)
@@ -87,7 +88,7 @@ impl<'a> Parser<'a> {
         self.bump(Kind::Bang);
 
         if self.eat(Kind::Colon) {
-            let type_annotation = self.parse_ts_type()?;
+            let type_annotation = self.parse_type()?;
             Ok((Some(self.ast.ts_type_annotation(self.end_span(span), type_annotation)), true))
         } else {
             Err(self.unexpected())
@@ -105,10 +106,16 @@ impl<'a> Parser<'a> {
         let params = self.parse_ts_type_parameters()?;
         self.expect(Kind::Eq)?;
 
-        let annotation = self.parse_ts_type()?;
+        let annotation = self.parse_type()?;
 
         self.asi()?;
-        Ok(self.ast.ts_type_alias_declaration(span, id, annotation, params, modifiers))
+        Ok(self.ast.ts_type_alias_declaration(
+            self.end_span(span),
+            id,
+            annotation,
+            params,
+            modifiers,
+        ))
     }
 
     /** ---------------------  Interface  ------------------------ */
@@ -138,6 +145,9 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         let mut body_list = TSInterfaceOrObjectBodyList::new(self);
         body_list.parse(self)?;
+        if let Some(spread) = body_list.spreads.first() {
+            self.error(diagnostics::FlowTypeSpread(spread.span()));
+        }
         Ok(self.ast.ts_interface_body(self.end_span(span), body_list.body))
     }
 
@@ -150,6 +160,10 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn parse_ts_type_signature(&mut self) -> Result<TSSignature<'a>> {
+        if self.at_flow_variance() {
+            return self.parse_flow_variance_member();
+        }
+
         if self.is_at_ts_index_signature_member() {
             return self.parse_ts_index_signature_member();
         }
@@ -269,11 +283,11 @@ impl<'a> Parser<'a> {
 
     pub(crate) fn parse_ts_namespace_or_module_declaration(
         &mut self,
+        start_span: Span,
         modifiers: Modifiers<'a>,
     ) -> Result<Box<'a, TSModuleDeclaration<'a>>> {
-        let span = self.start_span();
         self.expect(Kind::Namespace).or_else(|_| self.expect(Kind::Module))?;
-        self.parse_ts_namespace_or_module_declaration_body(span, modifiers)
+        self.parse_ts_namespace_or_module_declaration_body(start_span, modifiers)
     }
 
     pub(crate) fn parse_ts_global_declaration(
@@ -309,8 +323,11 @@ impl<'a> Parser<'a> {
         modifiers: Modifiers<'a>,
     ) -> Result<Declaration<'a>> {
         match self.cur_kind() {
+            Kind::Module if self.flow_enabled() && self.peek_at(Kind::Dot) => {
+                self.parse_flow_declare_module_exports(start_span)
+            }
             Kind::Namespace | Kind::Module => self
-                .parse_ts_namespace_or_module_declaration(modifiers)
+                .parse_ts_namespace_or_module_declaration(start_span, modifiers)
                 .map(Declaration::TSModuleDeclaration),
             Kind::Global => {
                 let decl = if self.peek_at(Kind::LCurly) {
@@ -334,6 +351,9 @@ impl<'a> Parser<'a> {
                 self.bump_any();
                 self.parse_ts_import_equals_declaration(start_span, true)
             }
+            Kind::Ident if self.flow_enabled() && self.at_flow_opaque_type() => {
+                self.parse_flow_opaque_type_declaration(start_span, modifiers)
+            }
             kind if kind.is_variable_declaration() => self
                 .parse_variable_declaration(
                     start_span,
@@ -378,10 +398,10 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         self.re_lex_ts_l_angle();
         self.expect(Kind::LAngle)?;
-        let type_annotation = self.parse_ts_type()?;
+        let type_annotation = self.parse_type()?;
         self.expect(Kind::RAngle)?;
         let lhs_span = self.start_span();
-        let expression = self.parse_unary_expression_base(lhs_span)?;
+        let expression = self.nested(|p| p.parse_unary_expression_base(lhs_span))?;
         Ok(self.ast.ts_type_assertion(self.end_span(span), type_annotation, expression))
     }
 
@@ -1,6 +1,6 @@
 use bitflags::bitflags;
 use oxc_allocator::{Box, Vec};
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, Span};
 use oxc_diagnostics::Result;
 
 use super::list::{
@@ -105,24 +105,29 @@ impl ModifierFlags {
 }
 
 impl<'a> Parser<'a> {
-    pub(crate) fn parse_ts_type(&mut self) -> Result<TSType<'a>> {
+    pub(crate) fn parse_type(&mut self) -> Result<TSType<'a>> {
+        self.nested(Self::parse_ts_type_impl)
+    }
+
+    fn parse_ts_type_impl(&mut self) -> Result<TSType<'a>> {
         if self.is_at_constructor_type() {
             return self.parse_ts_constructor_type();
         }
 
-        if self.is_at_function_type() {
+        if self.without_recovery(Self::is_at_function_type) {
             return self.parse_ts_function_type();
         }
 
+        let span = self.start_span();
         let left = self.parse_ts_union_type()?;
 
-        self.parse_ts_conditional_type(left)
+        self.parse_ts_conditional_type(span, left)
     }
 
     pub(crate) fn parse_ts_type_parameters(
         &mut self,
     ) -> Result<Option<Box<'a, TSTypeParameterDeclaration<'a>>>> {
-        if !self.ts_enabled() {
+        if !self.types_enabled() {
             return Ok(None);
         }
         if !self.at(Kind::LAngle) {
@@ -151,18 +156,10 @@ impl<'a> Parser<'a> {
     pub(crate) fn parse_ts_type_parameter(&mut self) -> Result<Box<'a, TSTypeParameter<'a>>> {
         let span = self.start_span();
 
-        let r#in = if self.at(Kind::In) && self.peek_kind().is_identifier_name() {
-            self.bump_any();
-            true
-        } else {
-            false
-        };
-        let out = if self.at(Kind::Out) && self.peek_kind().is_identifier_name() {
-            self.bump_any();
-            true
-        } else {
-            false
-        };
+        let (r#in, out) = self.eat_flow_type_parameter_variance().map_or_else(
+            || (self.eat_ts_variance_modifier(Kind::In), self.eat_ts_variance_modifier(Kind::Out)),
+            |covariant| (!covariant, covariant),
+        );
 
         if self.at(Kind::In) && self.peek_kind().is_identifier_name() {
             // TODO error
@@ -175,12 +172,26 @@ impl<'a> Parser<'a> {
         Ok(self.ast.ts_type_parameter(self.end_span(span), name, constraint, default, r#in, out))
     }
 
+    /// `in` or `out` before a type parameter
+    fn eat_ts_variance_modifier(&mut self, kind: Kind) -> bool {
+        if self.at(kind) && self.peek_kind().is_identifier_name() {
+            self.bump_any();
+            true
+        } else {
+            false
+        }
+    }
+
     fn parse_ts_type_constraint(&mut self) -> Result<Option<TSType<'a>>> {
+        // Flow bound `<T: Bound>`
+        if self.flow_enabled() && self.eat(Kind::Colon) {
+            return Ok(Some(self.parse_type()?));
+        }
         if !self.at(Kind::Extends) {
             return Ok(None);
         }
         self.bump_any();
-        Ok(Some(self.parse_ts_type()?))
+        Ok(Some(self.parse_type()?))
     }
 
     fn parse_ts_default_type(&mut self) -> Result<Option<TSType<'a>>> {
@@ -188,27 +199,26 @@ impl<'a> Parser<'a> {
             return Ok(None);
         }
         self.bump_any();
-        Ok(Some(self.parse_ts_type()?))
+        Ok(Some(self.parse_type()?))
     }
 
-    fn parse_ts_conditional_type(&mut self, left: TSType<'a>) -> Result<TSType<'a>> {
-        let span = self.start_span();
+    fn parse_ts_conditional_type(&mut self, span: Span, left: TSType<'a>) -> Result<TSType<'a>> {
         if !self.ctx.has_disallow_conditional_types()
             && !self.cur_token().is_on_new_line
             && self.eat(Kind::Extends)
         {
             let extends_type =
-                self.with_context(Context::DisallowConditionalTypes, Self::parse_ts_type)?;
+                self.with_context(Context::DisallowConditionalTypes, Self::parse_type)?;
 
             self.expect(Kind::Question)?;
 
             let true_type =
-                self.without_context(Context::DisallowConditionalTypes, Self::parse_ts_type)?;
+                self.without_context(Context::DisallowConditionalTypes, Self::parse_type)?;
 
             self.expect(Kind::Colon)?;
 
             let false_type =
-                self.without_context(Context::DisallowConditionalTypes, Self::parse_ts_type)?;
+                self.without_context(Context::DisallowConditionalTypes, Self::parse_type)?;
 
             return Ok(self.ast.ts_conditional_type(
                 self.end_span(span),
@@ -234,16 +244,16 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         if self.at(Kind::Pipe) {
             let mut types = self.ast.new_vec();
-            while self.eat(Kind::Pipe) {
+            while !self.at_flow_exact_object_type_end() && self.eat(Kind::Pipe) {
                 types.push(self.parse_ts_intersection_type()?);
             }
             Ok(self.ast.ts_union_type(self.end_span(span), types))
         } else {
             let first = self.parse_ts_intersection_type()?;
-            if self.at(Kind::Pipe) {
+            if self.at(Kind::Pipe) && !self.at_flow_exact_object_type_end() {
                 let mut types = self.ast.new_vec();
                 types.push(first);
-                while self.eat(Kind::Pipe) {
+                while !self.at_flow_exact_object_type_end() && self.eat(Kind::Pipe) {
                     types.push(self.parse_ts_intersection_type()?);
                 }
                 Ok(self.ast.ts_union_type(self.end_span(span), types))
@@ -320,7 +330,7 @@ impl<'a> Parser<'a> {
                 // test ts ts_indexed_access_type
                 // type A = string[number];
                 // type B = string[number][number][number][];
-                let index_type = self.parse_ts_type()?;
+                let index_type = self.parse_type()?;
                 self.expect(Kind::RBrack)?;
                 left = self.ast.ts_indexed_access_type(self.end_span(span), left, index_type);
             }
@@ -345,11 +355,15 @@ impl<'a> Parser<'a> {
         match self.cur_kind() {
             Kind::LParen => {
                 self.bump_any();
-                let result = self.parse_ts_type();
+                let result = self.parse_type();
                 self.expect(Kind::RParen)?;
                 result
             }
             Kind::LBrack => self.parse_ts_tuple_type(),
+            Kind::LCurly if self.flow_enabled() && self.at_flow_exact_object_type() => {
+                self.parse_flow_exact_object_type()
+            }
+            Kind::Star if self.flow_enabled() => Ok(self.parse_flow_existential_type()),
             Kind::LCurly => {
                 if self.is_at_mapped_type() {
                     self.parse_ts_mapped_type()
@@ -608,7 +622,7 @@ impl<'a> Parser<'a> {
         }
         let name = self.parse_binding_identifier()?;
         self.expect(Kind::In)?;
-        let constraint = self.parse_ts_type()?;
+        let constraint = self.parse_type()?;
         let type_parameter = self.ast.ts_type_parameter(
             self.end_span(type_parameter_span),
             name,
@@ -618,7 +632,7 @@ impl<'a> Parser<'a> {
             false,
         );
 
-        let name_type = if self.eat(Kind::As) { Some(self.parse_ts_type()?) } else { None };
+        let name_type = if self.eat(Kind::As) { Some(self.parse_type()?) } else { None };
         self.expect(Kind::RBrack)?;
 
         let optional = match self.cur_kind() {
@@ -640,7 +654,7 @@ impl<'a> Parser<'a> {
         };
 
         self.expect(Kind::Colon)?;
-        let type_annotation = self.parse_ts_type()?;
+        let type_annotation = self.parse_type()?;
 
         self.bump(Kind::Semicolon);
         self.expect(Kind::RCurly)?;
@@ -668,8 +682,9 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         let mut member_list = TSInterfaceOrObjectBodyList::new(self);
         member_list.parse(self)?;
-
-        Ok(self.ast.ts_type_literal(self.end_span(span), member_list.body))
+        let span = self.end_span(span);
+        let object_type = self.ast.ts_type_literal(span, member_list.body);
+        Ok(self.flow_object_type_with_spreads(span, object_type, member_list.spreads))
     }
 
     fn parse_ts_literal_type(&mut self) -> Result<TSType<'a>> {
@@ -710,7 +725,7 @@ impl<'a> Parser<'a> {
             }
             Kind::TemplateHead => {
                 quasis.push(self.parse_template_element(tagged));
-                types.push(self.parse_ts_type()?);
+                types.push(self.parse_type()?);
                 self.re_lex_template_substitution_tail();
                 loop {
                     match self.cur_kind() {
@@ -723,7 +738,7 @@ impl<'a> Parser<'a> {
                             quasis.push(self.parse_template_element(tagged));
                         }
                         _ => {
-                            types.push(self.parse_ts_type()?);
+                            types.push(self.parse_type()?);
                             self.re_lex_template_substitution_tail();
                         }
                     }
@@ -748,7 +763,7 @@ impl<'a> Parser<'a> {
         let is_type_of = self.eat(Kind::Typeof);
         self.expect(Kind::Import)?;
         self.expect(Kind::LParen)?;
-        let parameter = self.parse_ts_type()?;
+        let parameter = self.parse_type()?;
         self.expect(Kind::RParen)?;
 
         let qualifier =
@@ -820,7 +835,7 @@ impl<'a> Parser<'a> {
     fn parse_constraint_of_infer_type(&mut self) -> Result<Option<TSType<'a>>> {
         if self.eat(Kind::Extends) {
             let constraint =
-                self.with_context(Context::DisallowConditionalTypes, Self::parse_ts_type)?;
+                self.with_context(Context::DisallowConditionalTypes, Self::parse_type)?;
             if self.ctx.has_disallow_conditional_types() || !self.at(Kind::Question) {
                 return Ok(Some(constraint));
             }
@@ -831,7 +846,7 @@ impl<'a> Parser<'a> {
     pub(crate) fn parse_ts_return_type_annotation(
         &mut self,
     ) -> Result<Option<Box<'a, TSTypeAnnotation<'a>>>> {
-        if !self.ts_enabled() {
+        if !self.types_enabled() {
             return Ok(None);
         }
         if !self.at(Kind::Colon) {
@@ -839,8 +854,14 @@ impl<'a> Parser<'a> {
         }
         let span = self.start_span();
         self.bump_any(); // bump colon
+        // Flow `function f(x): %checks {}`
+        if self.eat_flow_predicate()? {
+            return Ok(None);
+        }
         let return_type = self.parse_ts_return_type()?;
-        Ok(Some(self.ast.ts_type_annotation(self.end_span(span), return_type)))
+        let span = self.end_span(span);
+        self.eat_flow_predicate()?;
+        Ok(Some(self.ast.ts_type_annotation(span, return_type)))
     }
 
     fn parse_ts_type_predicate(&mut self) -> Result<TSType<'a>> {
@@ -867,7 +888,7 @@ impl<'a> Parser<'a> {
         }
 
         let type_span = self.start_span();
-        let type_annotation = self.parse_ts_type()?;
+        let type_annotation = self.parse_type()?;
         let type_annotation =
             Some(self.ast.ts_type_annotation(self.end_span(type_span), type_annotation));
 
@@ -887,7 +908,7 @@ impl<'a> Parser<'a> {
         if !self.peek_token().is_on_new_line && (asserts || is_predicate) {
             self.parse_ts_type_predicate()
         } else {
-            self.without_context(Context::DisallowConditionalTypes, Self::parse_ts_type)
+            self.without_context(Context::DisallowConditionalTypes, Self::parse_type)
         }
     }
 
@@ -1090,7 +1111,7 @@ impl<'a> Parser<'a> {
     fn parse_js_doc_unknown_or_nullable_type(&mut self) -> Result<TSType<'a>> {
         let span = self.start_span();
         self.bump_any(); // bump `?`
-        let type_annotation = self.parse_ts_type()?;
+        let type_annotation = self.parse_type()?;
         let span = self.end_span(span);
         if matches!(
             self.cur_kind(),
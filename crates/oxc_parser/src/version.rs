@@ -0,0 +1,230 @@
+//! ECMAScript version gating, see [`Parser::target`]
+
+use std::fmt;
+
+use oxc_ast::Span;
+
+use crate::{diagnostics, Parser};
+
+/// ECMAScript edition, ordered from oldest to newest
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EcmaVersion {
+    ES5,
+    ES2015,
+    ES2016,
+    ES2017,
+    ES2018,
+    ES2019,
+    ES2020,
+    ES2021,
+    ES2022,
+    ES2023,
+    ES2024,
+    /// Finished and stage 3 proposals
+    #[default]
+    ESNext,
+}
+
+impl fmt::Display for EcmaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::ES5 => "ES5",
+            Self::ES2015 => "ES2015",
+            Self::ES2016 => "ES2016",
+            Self::ES2017 => "ES2017",
+            Self::ES2018 => "ES2018",
+            Self::ES2019 => "ES2019",
+            Self::ES2020 => "ES2020",
+            Self::ES2021 => "ES2021",
+            Self::ES2022 => "ES2022",
+            Self::ES2023 => "ES2023",
+            Self::ES2024 => "ES2024",
+            Self::ESNext => "ESNext",
+        };
+        f.write_str(name)
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Report `feature` at `span` if it was introduced after the target version
+    pub(crate) fn check_ecma_version(
+        &mut self,
+        version: EcmaVersion,
+        feature: &'static str,
+        span: Span,
+    ) {
+        if self.target < version {
+            self.error(diagnostics::UnavailableSyntax(feature, self.target, span));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+
+    use super::EcmaVersion;
+    use crate::Parser;
+
+    /// Errors of parsing the module `source` for `target`
+    fn parse(source: &str, target: EcmaVersion) -> Vec<String> {
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_module(true);
+        let ret = Parser::new(&allocator, source, source_type).target(target).parse();
+        ret.errors.iter().map(ToString::to_string).collect()
+    }
+
+    /// Check each `(source, target, error)` case is reported for the target and not for the latest version
+    fn assert_unavailable(cases: &[(&str, EcmaVersion, &str)]) {
+        for &(source, target, message) in cases {
+            assert_eq!(parse(source, target), [message], "{source}");
+            assert!(parse(source, EcmaVersion::default()).is_empty(), "{source}");
+        }
+    }
+
+    #[test]
+    fn target() {
+        assert_unavailable(&[
+            ("a?.b", EcmaVersion::ES2019, "Optional chaining is not available in ES2019"),
+            ("a?.()", EcmaVersion::ES2019, "Optional chaining is not available in ES2019"),
+            ("class A { x = 1 }", EcmaVersion::ES2021, "Class fields is not available in ES2021"),
+            ("a ??= b", EcmaVersion::ES2020, "Logical assignment is not available in ES2020"),
+            ("await a", EcmaVersion::ES2021, "Top-level await is not available in ES2021"),
+            ("/a/s", EcmaVersion::ES2017, "RegExp flag `s` is not available in ES2017"),
+            ("/a/d", EcmaVersion::ES2021, "RegExp flag `d` is not available in ES2021"),
+            ("let a = 1", EcmaVersion::ES5, "`let` is not available in ES5"),
+            ("a => a", EcmaVersion::ES5, "Arrow functions is not available in ES5"),
+            ("a ** b", EcmaVersion::ES2015, "Exponentiation operator is not available in ES2015"),
+            (
+                "try {} catch {}",
+                EcmaVersion::ES2018,
+                "Optional catch binding is not available in ES2018",
+            ),
+            ("var [a] = b", EcmaVersion::ES5, "Destructuring is not available in ES5"),
+            ("var { a: b } = c", EcmaVersion::ES5, "Destructuring is not available in ES5"),
+            ("[a] = b", EcmaVersion::ES5, "Destructuring is not available in ES5"),
+            ("f(...a)", EcmaVersion::ES5, "Spread elements is not available in ES5"),
+            ("[...a]", EcmaVersion::ES5, "Spread elements is not available in ES5"),
+            ("function f(...a) {}", EcmaVersion::ES5, "Rest parameters is not available in ES5"),
+            (
+                "function f(a = 1) {}",
+                EcmaVersion::ES5,
+                "Default parameters is not available in ES5",
+            ),
+            ("for (a of b);", EcmaVersion::ES5, "`for...of` is not available in ES5"),
+            ("function* g() {}", EcmaVersion::ES5, "Generators is not available in ES5"),
+            ("({ [a]: 1 })", EcmaVersion::ES5, "Computed property names is not available in ES5"),
+            ("({ a })", EcmaVersion::ES5, "Shorthand properties is not available in ES5"),
+            ("({ m() {} })", EcmaVersion::ES5, "Shorthand methods is not available in ES5"),
+            (
+                "import a from 'a'",
+                EcmaVersion::ES5,
+                "`import` declarations is not available in ES5",
+            ),
+            ("export var a", EcmaVersion::ES5, "`export` declarations is not available in ES5"),
+            (
+                "function f() { new.target }",
+                EcmaVersion::ES5,
+                "`new.target` is not available in ES5",
+            ),
+            (
+                "({ ...a })",
+                EcmaVersion::ES2017,
+                "Object rest/spread properties is not available in ES2017",
+            ),
+            (
+                "var { ...a } = b",
+                EcmaVersion::ES2017,
+                "Object rest/spread properties is not available in ES2017",
+            ),
+            (
+                "async function* f() {}",
+                EcmaVersion::ES2017,
+                "Async generators is not available in ES2017",
+            ),
+            (
+                "async function f() { for await (a of b); }",
+                EcmaVersion::ES2017,
+                "`for await...of` is not available in ES2017",
+            ),
+            (
+                "/(?<a>b)/",
+                EcmaVersion::ES2017,
+                "RegExp named capture groups is not available in ES2017",
+            ),
+            (
+                "/(?<=a)b/",
+                EcmaVersion::ES2017,
+                "RegExp lookbehind assertions is not available in ES2017",
+            ),
+            (
+                "/\\p{L}/u",
+                EcmaVersion::ES2017,
+                "RegExp Unicode property escapes is not available in ES2017",
+            ),
+            ("import('a')", EcmaVersion::ES2019, "`import()` is not available in ES2019"),
+            ("import.meta", EcmaVersion::ES2019, "`import.meta` is not available in ES2019"),
+            (
+                "export * as ns from 'a'",
+                EcmaVersion::ES2019,
+                "`export * as ns` is not available in ES2019",
+            ),
+            ("1_000", EcmaVersion::ES2020, "Numeric separators is not available in ES2020"),
+            (
+                "/(?<a>b)|(?<a>c)/",
+                EcmaVersion::ES2024,
+                "RegExp duplicate named capture groups is not available in ES2024",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn target_literals_and_trailing_commas() {
+        assert_unavailable(&[
+            ("0o17", EcmaVersion::ES5, "Binary and octal literals is not available in ES5"),
+            ("0b1", EcmaVersion::ES5, "Binary and octal literals is not available in ES5"),
+            ("'\\u{41}'", EcmaVersion::ES5, "Unicode code point escapes is not available in ES5"),
+            ("\\u{61}", EcmaVersion::ES5, "Unicode code point escapes is not available in ES5"),
+            (
+                "function f(a,) {}",
+                EcmaVersion::ES2016,
+                "Trailing commas in parameter and argument lists is not available in ES2016",
+            ),
+            (
+                "(a,) => a",
+                EcmaVersion::ES2016,
+                "Trailing commas in parameter and argument lists is not available in ES2016",
+            ),
+            (
+                "f(a,)",
+                EcmaVersion::ES2015,
+                "Trailing commas in parameter and argument lists is not available in ES2015",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn target_supported() {
+        let pass = [
+            ("async function f() { await a; }", EcmaVersion::ES2017),
+            ("async () => { await a; }", EcmaVersion::ES2017),
+            ("class A { async m() { await a; } }", EcmaVersion::ES2017),
+            ("017", EcmaVersion::ES5),
+            ("0o17", EcmaVersion::ES2015),
+            ("0b1", EcmaVersion::ES2015),
+            ("'\\\\u{41}'", EcmaVersion::ES5),
+            ("'\\u0041'", EcmaVersion::ES5),
+            ("'\\u{41}'", EcmaVersion::ES2015),
+            ("\\u{61}", EcmaVersion::ES2015),
+            ("f(a, b)", EcmaVersion::ES2016),
+            ("function f(a,) {}", EcmaVersion::ES2017),
+            ("f(a,)", EcmaVersion::ES2017),
+            ("/(?<a>b)|(?<a>c)/", EcmaVersion::ESNext),
+        ];
+        for (source, target) in pass {
+            let errors = parse(source, target);
+            assert!(errors.is_empty(), "{source}: {errors:?}");
+        }
+    }
+}
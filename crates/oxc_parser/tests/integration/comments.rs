@@ -0,0 +1,80 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Expression, Statement},
+    CommentAnnotation, GetSpan, SourceType, Span,
+};
+use oxc_parser::Parser;
+
+#[test]
+fn comment_attachment() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let source =
+        "/** doc */\nfoo(); // trailing\n/* a */ /*#__PURE__*/ bar();\n{\n  // @ts-ignore\n}";
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    let spans = ret.program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+    let trivias = &ret.trivias;
+
+    let text = |span: Span| &source[span.start as usize..span.end as usize];
+
+    let leading = trivias.leading_comments(source, spans[0]);
+    assert_eq!(leading.len(), 1);
+    assert_eq!(text(leading[0].span), "* doc ");
+    assert!(leading[0].comment.is_jsdoc());
+    assert!(leading[0].has_newline);
+
+    let trailing = trivias.trailing_comments(source, spans[0]);
+    assert_eq!(trailing.len(), 1);
+    assert_eq!(text(trailing[0].span), " trailing");
+    assert!(!trailing[0].has_newline);
+
+    let leading = trivias.leading_comments(source, spans[1]);
+    assert_eq!(leading.len(), 2);
+    assert!(leading[1].comment.is_pure());
+    assert!(!leading[1].has_newline);
+    assert!(trivias.trailing_comments(source, spans[1]).is_empty());
+
+    let dangling = trivias.dangling_comments(source, spans[2], &[]).collect::<Vec<_>>();
+    assert_eq!(dangling.len(), 1);
+    assert_eq!(dangling[0].1.annotation(), Some(CommentAnnotation::TsDirective));
+    assert_eq!(text(dangling[0].0), " @ts-ignore");
+}
+
+#[test]
+fn dangling_comments_exclude_children() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    let source = "{\n  /* leading */\n  foo(/* inside */); // trailing\n}\nbar(/* dangling */);";
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    let trivias = &ret.trivias;
+
+    let Statement::BlockStatement(block) = &ret.program.body[0] else { unreachable!() };
+    let children = block.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+    assert_eq!(trivias.dangling_comments(source, block.span, &children).count(), 0);
+    assert_eq!(trivias.dangling_comments(source, block.span, &[]).count(), 3);
+
+    let Statement::ExpressionStatement(stmt) = &ret.program.body[1] else { unreachable!() };
+    let Expression::CallExpression(call) = &stmt.expression else { unreachable!() };
+    let dangling = trivias.dangling_comments(source, call.span, &[call.callee.span()]);
+    let texts = dangling.map(|(span, _)| span.source_text(source)).collect::<Vec<_>>();
+    assert_eq!(texts, [" dangling "]);
+}
+
+#[test]
+fn single_line_comment_end() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default();
+    for (source, end, text) in [
+        ("// a\nb", 5, " a"),
+        ("// a\r\nb", 5, " a"),
+        ("// a\u{2028}b", 7, " a"),
+        ("// a", 4, " a"),
+    ] {
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let (start, comment) = ret.trivias.comments().iter().next().unwrap();
+        assert_eq!(comment.end(), end, "the lexer includes the line terminator in {source:?}");
+        let span = comment.text_span(*start, source);
+        assert_eq!(&source[span.start as usize..span.end as usize], text, "{source:?}");
+        assert_eq!(comment.full_span(*start, source), Span::new(0, span.end), "{source:?}");
+    }
+}
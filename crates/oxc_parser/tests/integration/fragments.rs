@@ -0,0 +1,55 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Expression, Statement, TSType},
+    GetSpan, SourceType, Span,
+};
+use oxc_parser::Parser;
+
+#[test]
+fn expression() {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, "$a.foo($b)", SourceType::default()).parse_expression();
+    assert!(ret.errors.is_empty() && !ret.has_trailing_tokens, "the whole source is parsed");
+    assert!(matches!(ret.node, Some(Expression::CallExpression(_))), "a call expression");
+}
+
+#[test]
+fn trailing_tokens() {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, "a b", SourceType::default()).parse_expression();
+    assert!(ret.errors.is_empty() && ret.has_trailing_tokens, "`b` is left over");
+    assert_eq!(ret.node.map(|expr| expr.span()), Some(Span::new(0, 1)), "the expression is `a`");
+}
+
+#[test]
+fn syntax_error() {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, "a +", SourceType::default()).parse_expression();
+    assert!(ret.node.is_none(), "no expression");
+    assert_eq!(ret.errors.len(), 1, "one error");
+    assert!(!ret.has_trailing_tokens, "the error is not reported as trailing tokens");
+}
+
+#[test]
+fn ts_type() {
+    let allocator = Allocator::default();
+    let source_type = *SourceType::default().with_typescript(true);
+    let ret = Parser::new(&allocator, "Record<string, number[]>", source_type).parse_ts_type();
+    assert!(ret.errors.is_empty() && !ret.has_trailing_tokens, "the whole source is parsed");
+    assert!(matches!(ret.node, Some(TSType::TSTypeReference(_))), "a type reference");
+}
+
+#[test]
+fn statement_list() {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, "'use strict'; a(); } b", SourceType::default())
+        .recover_from_errors(true)
+        .parse_statement_list();
+    assert!(ret.errors.is_empty() && ret.has_trailing_tokens, "the list ends at the stray `}}`");
+    let statements = ret.node.unwrap();
+    assert_eq!(statements.len(), 2, "the directive is a statement in a fragment");
+    assert!(
+        matches!(statements[0], Statement::ExpressionStatement(_)),
+        "the directive is an expression statement"
+    );
+}
@@ -0,0 +1,7 @@
+//! Tests of the parser's public entry points
+
+mod comments;
+mod fragments;
+mod nesting_depth;
+mod ts_declarations;
+mod unambiguous;
@@ -0,0 +1,68 @@
+use oxc_allocator::Allocator;
+use oxc_ast::SourceType;
+use oxc_parser::Parser;
+
+/// `open` and `close` repeated `n` times around `inner`
+fn nested(open: &str, inner: &str, close: &str, n: usize) -> String {
+    format!("{}{inner}{}", open.repeat(n), close.repeat(n))
+}
+
+fn source_type() -> SourceType {
+    *SourceType::default().with_typescript(true).with_jsx(true)
+}
+
+#[test]
+fn too_deeply_nested() {
+    let allocator = Allocator::default();
+    let sources = [
+        nested("[", "", "]", 10_000),
+        nested("(", "a", ")", 10_000),
+        nested("a ** ", "a", "", 10_000),
+        nested("a => ", "a", "", 10_000),
+        nested("!", "a", "", 10_000),
+        nested("{", "", "}", 10_000),
+        nested("let a: ", "", "", 1) + &nested("A<", "a", ">", 10_000),
+        nested("let ", "", "", 1) + &nested("[", "a", "]", 10_000) + " = b",
+        nested("<a>", "", "</a>", 10_000),
+    ];
+    for source in &sources {
+        let ret = Parser::new(&allocator, source, source_type()).parse();
+        assert_eq!(ret.errors.len(), 1, "{}", &source[..10]);
+        assert_eq!(ret.errors[0].to_string(), "The code is too deeply nested", "{}", &source[..10]);
+        let ret = Parser::new(&allocator, source, source_type()).recover_from_errors(true).parse();
+        assert!(!ret.errors.is_empty(), "recovery does not hide the error: {}", &source[..10]);
+    }
+}
+
+#[test]
+fn one_level_per_syntactic_nesting() {
+    let allocator = Allocator::default();
+    let sources = [
+        nested("[", "", "]", 500),
+        nested("(", "a", ")", 500),
+        nested("f(", "a", ")", 500),
+        nested("a ** ", "a", "", 500),
+        nested("a => ", "a", "", 500),
+        nested("!", "a", "", 500),
+        format!("async () => {}", nested("await ", "a", "", 500)),
+        nested("{", "", "}", 500),
+        nested("let a: ", "", "", 1) + &nested("A<", "a", ">", 500),
+        nested("<a>", "", "</a>", 500),
+        format!("a{}", " + a".repeat(10_000)),
+    ];
+    for source in &sources {
+        let ret = Parser::new(&allocator, source, source_type()).parse();
+        assert!(ret.errors.is_empty(), "{}: {:?}", &source[..10], ret.errors);
+    }
+}
+
+#[test]
+fn custom_depth() {
+    let allocator = Allocator::default();
+    // the statement, its expression and the 9 arrays inside of the outer array
+    let source = nested("[", "", "]", 10);
+    let ret = Parser::new(&allocator, &source, source_type()).max_nesting_depth(10).parse();
+    assert!(!ret.errors.is_empty(), "11 levels are deeper than 10");
+    let ret = Parser::new(&allocator, &source, source_type()).max_nesting_depth(11).parse();
+    assert!(ret.errors.is_empty(), "11 levels are within 11");
+}
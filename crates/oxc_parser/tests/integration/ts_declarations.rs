@@ -0,0 +1,39 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Declaration, Statement},
+    GetSpan, SourceType,
+};
+use oxc_parser::Parser;
+
+#[test]
+fn ts_declaration_spans() {
+    let source_type = *SourceType::default().with_typescript(true);
+    let cases = [
+        ("enum E { A = 1 }", 0..16),
+        ("declare enum E {}", 0..17),
+        ("type T = { b: B };", 0..18),
+        ("namespace N { type T = 1; }", 0..27),
+        ("declare module 'm' {}", 0..21),
+    ];
+    for (source, expected) in cases {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty(), "{source}");
+        let Statement::Declaration(declaration) = &ret.program.body[0] else {
+            unreachable!("{source}")
+        };
+        let span = declaration.span();
+        assert_eq!(span.start..span.end, expected, "{source}");
+    }
+
+    let allocator = Allocator::default();
+    let source = "type C<T> = T extends U ? 1 : 2;";
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    let Statement::Declaration(Declaration::TSTypeAliasDeclaration(alias)) =
+        &ret.program.body[0]
+    else {
+        unreachable!()
+    };
+    let span = alias.type_annotation.span();
+    assert_eq!(&source[span.start as usize..span.end as usize], "T extends U ? 1 : 2");
+}
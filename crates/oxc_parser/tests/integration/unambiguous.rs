@@ -0,0 +1,48 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ModuleKind, SourceType};
+use oxc_parser::{Parser, ParserReturn};
+
+fn parse<'a>(allocator: &'a Allocator, source: &'a str) -> ParserReturn<'a> {
+    let source_type = *SourceType::default().with_unambiguous(true);
+    Parser::new(allocator, source, source_type).parse()
+}
+
+#[test]
+fn modules() {
+    let allocator = Allocator::default();
+    let modules = [
+        "import a from 'a'",
+        "export const a = 1",
+        "console.log(import.meta.url)",
+        "await foo()",
+        "with (a) {}\nexport {}",
+    ];
+    for source in modules {
+        let ret = parse(&allocator, source);
+        assert_eq!(ret.module_kind, ModuleKind::Module, "{source}");
+        assert!(ret.program.source_type.is_module(), "{source}");
+    }
+}
+
+#[test]
+fn scripts() {
+    let allocator = Allocator::default();
+    let scripts = [
+        "const a = require('a'); module.exports = a;",
+        "with (a) {}",
+        "var await = 1; await;",
+        "async function f() { await foo(); }",
+        "import('a')",
+        "a <!-- b\n--> c\nd",
+        "x = 010;",
+    ];
+    for source in scripts {
+        let ret = parse(&allocator, source);
+        assert_eq!(ret.module_kind, ModuleKind::Script, "{source}");
+        assert!(ret.program.source_type.is_script(), "{source}");
+        assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+    }
+
+    let ret = parse(&allocator, "a <!-- b");
+    assert_eq!(ret.program.body.len(), 1, "HTML comments are parsed in scripts");
+}
@@ -27,6 +27,6 @@ fn main() {
     }
 
     let printer_options = PrinterOptions::default();
-    let printed = Printer::new(source_text.len(), printer_options).build(&ret.program);
+    let printed = Printer::new(source_text.len(), printer_options).build(&ret.program).unwrap();
     println!("{printed}");
 }
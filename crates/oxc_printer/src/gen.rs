@@ -1,6 +1,6 @@
 use oxc_allocator::{Box, Vec};
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, GetSpan};
 
 use crate::{Printer, Separator};
 
@@ -42,6 +42,9 @@ impl<'a> Gen for Directive<'a> {
 
 impl<'a> Gen for Statement<'a> {
     fn gen(&self, p: &mut Printer) {
+        if !p.enter_nesting(self.span()) {
+            return;
+        }
         match self {
             Self::BlockStatement(stmt) => stmt.gen(p),
             Self::BreakStatement(stmt) => stmt.gen(p),
@@ -63,7 +66,9 @@ impl<'a> Gen for Statement<'a> {
             Self::WhileStatement(stmt) => stmt.gen(p),
             Self::WithStatement(stmt) => stmt.gen(p),
             Self::Declaration(decl) => decl.gen(p),
+            Self::InvalidStatement(stmt) => p.invalid_node(stmt.span),
         }
+        p.leave_nesting();
     }
 }
 
@@ -457,7 +462,9 @@ impl<'a> Gen for Declaration<'a> {
             | Self::TSInterfaceDeclaration(_)
             | Self::TSEnumDeclaration(_)
             | Self::TSModuleDeclaration(_)
-            | Self::TSImportEqualsDeclaration(_) => {}
+            | Self::TSImportEqualsDeclaration(_)
+            | Self::FlowOpaqueTypeDeclaration(_)
+            | Self::FlowDeclareModuleExports(_) => {}
         }
     }
 }
@@ -743,6 +750,9 @@ impl<'a> Gen for ExportDefaultDeclarationKind<'a> {
 
 impl<'a> Gen for Expression<'a> {
     fn gen(&self, p: &mut Printer) {
+        if !p.enter_nesting(self.span()) {
+            return;
+        }
         match self {
             Self::BooleanLiteral(lit) => lit.gen(p),
             Self::NullLiteral(lit) => lit.gen(p),
@@ -761,9 +771,8 @@ impl<'a> Gen for Expression<'a> {
             Self::YieldExpression(expr) => expr.gen(p),
             Self::UpdateExpression(expr) => expr.gen(p),
             Self::UnaryExpression(expr) => expr.gen(p),
-            Self::BinaryExpression(expr) => expr.gen(p),
+            Self::BinaryExpression(_) | Self::LogicalExpression(_) => gen_binary_chain(self, p),
             Self::PrivateInExpression(expr) => expr.gen(p),
-            Self::LogicalExpression(expr) => expr.gen(p),
             Self::ConditionalExpression(expr) => expr.gen(p),
             Self::AssignmentExpression(expr) => expr.gen(p),
             Self::SequenceExpression(expr) => expr.gen(p),
@@ -784,7 +793,9 @@ impl<'a> Gen for Expression<'a> {
             Self::TSTypeAssertion(expr) => expr.expression.gen(p),
             Self::TSNonNullExpression(expr) => expr.expression.gen(p),
             Self::TSInstantiationExpression(expr) => expr.expression.gen(p),
+            Self::InvalidExpression(expr) => p.invalid_node(expr.span),
         }
+        p.leave_nesting();
     }
 }
 
@@ -837,7 +848,7 @@ impl Gen for BigintLiteral {
     }
 }
 
-impl Gen for RegExpLiteral {
+impl<'a> Gen for RegExpLiteral<'a> {
     fn gen(&self, p: &mut Printer) {
         p.print(b'/');
         p.print_str(self.regex.pattern.as_bytes());
@@ -1177,10 +1188,48 @@ impl<'a> Gen for PrivateInExpression<'a> {
 impl<'a> Gen for LogicalExpression<'a> {
     fn gen(&self, p: &mut Printer) {
         self.left.gen(p);
+        self.operator.gen(p);
+        self.right.gen(p);
+    }
+}
+
+impl Gen for LogicalOperator {
+    fn gen(&self, p: &mut Printer) {
         p.print_space();
-        p.print_str(self.operator.as_str().as_bytes());
+        p.print_str(self.as_str().as_bytes());
         p.print_space();
-        self.right.gen(p);
+    }
+}
+
+/// Print the left operands of a binary or logical expression in a loop.
+///
+/// `a + b + c` is nested as `(a + b) + c` in the AST but not in the source code,
+/// long chains such as generated string concatenations must not count towards the nesting depth.
+fn gen_binary_chain(expr: &Expression<'_>, p: &mut Printer) {
+    let mut chain = vec![];
+    let mut left = expr;
+    loop {
+        let next = match left {
+            Expression::BinaryExpression(expr) => &expr.left,
+            Expression::LogicalExpression(expr) => &expr.left,
+            _ => break,
+        };
+        chain.push(left);
+        left = next;
+    }
+    left.gen(p);
+    for expr in chain.into_iter().rev() {
+        match expr {
+            Expression::BinaryExpression(expr) => {
+                expr.operator.gen(p);
+                expr.right.gen(p);
+            }
+            Expression::LogicalExpression(expr) => {
+                expr.operator.gen(p);
+                expr.right.gen(p);
+            }
+            _ => unreachable!(),
+        }
     }
 }
 
@@ -7,6 +7,8 @@ mod gen;
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_ast::{Span, MAX_NESTING_DEPTH};
+use oxc_diagnostics::{Error, InvalidNode, NestingTooDeep};
 
 pub use crate::gen::Gen;
 
@@ -14,11 +16,13 @@ pub use crate::gen::Gen;
 pub struct PrinterOptions {
     pub minify_whitespace: bool,
     pub indentation: u8,
+    /// [`Printer::build`] returns an error for statements and expressions nested deeper than this
+    pub max_nesting_depth: u32,
 }
 
 impl Default for PrinterOptions {
     fn default() -> Self {
-        Self { minify_whitespace: false, indentation: 4 }
+        Self { minify_whitespace: false, indentation: 4, max_nesting_depth: MAX_NESTING_DEPTH }
     }
 }
 
@@ -35,6 +39,11 @@ pub struct Printer {
     needs_semicolon: bool,
     prev_op_end: usize,
     prev_op: Option<Operator>,
+    depth: u32,
+    /// The first node nested deeper than `max_nesting_depth`
+    too_deeply_nested: Option<Span>,
+    /// The first `InvalidStatement` or `InvalidExpression` left by a parser recovering from errors
+    invalid_node: Option<Span>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -59,13 +68,27 @@ impl Printer {
             needs_semicolon: false,
             prev_op_end: 0,
             prev_op: None,
+            depth: 0,
+            too_deeply_nested: None,
+            invalid_node: None,
         }
     }
 
-    #[must_use]
-    pub fn build(mut self, program: &Program<'_>) -> String {
+    /// # Errors
+    ///
+    /// * the program contains an `InvalidStatement` or `InvalidExpression`,
+    ///   which the printer has no source text for
+    /// * the program is nested deeper than [`PrinterOptions::max_nesting_depth`],
+    ///   the incomplete output is discarded
+    pub fn build(mut self, program: &Program<'_>) -> Result<String, Error> {
         program.gen(&mut self);
-        self.into_code()
+        if let Some(span) = self.invalid_node {
+            return Err(InvalidNode(span).into());
+        }
+        match self.too_deeply_nested {
+            Some(span) => Err(NestingTooDeep(self.options.max_nesting_depth, span).into()),
+            None => Ok(self.into_code()),
+        }
     }
 
     #[must_use]
@@ -105,6 +128,26 @@ impl Printer {
         }
     }
 
+    /// Returns `false` if the next nesting level exceeds `max_nesting_depth`,
+    /// the node at `span` must then be skipped without calling [`Printer::leave_nesting`]
+    pub fn enter_nesting(&mut self, span: Span) -> bool {
+        if self.depth >= self.options.max_nesting_depth {
+            self.too_deeply_nested.get_or_insert(span);
+            return false;
+        }
+        self.depth += 1;
+        true
+    }
+
+    /// Record an `InvalidStatement` or `InvalidExpression`, see [`Printer::build`]
+    pub fn invalid_node(&mut self, span: Span) {
+        self.invalid_node.get_or_insert(span);
+    }
+
+    pub fn leave_nesting(&mut self) {
+        self.depth -= 1;
+    }
+
     #[inline]
     pub fn indent(&mut self) {
         self.indentation += self.options.indentation;
@@ -276,3 +319,55 @@ impl Printer {
         self.code.last()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+
+    use crate::{Printer, PrinterOptions};
+
+    fn print(source_text: &str, options: PrinterOptions) -> Result<String, String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        assert!(ret.errors.is_empty());
+        Printer::new(source_text.len(), options).build(&ret.program).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn flat_binary_chain() {
+        let options = PrinterOptions { minify_whitespace: true, ..PrinterOptions::default() };
+        for separator in ["+", "||", "-"] {
+            let source_text = format!("x={}", vec!["a"; 100_000].join(separator));
+            assert_eq!(print(&source_text, options), Ok(source_text));
+        }
+        assert_eq!(print("x=a+ +a- -a", options), Ok("x=a+ +a- -a".to_string()));
+    }
+
+    #[test]
+    fn max_nesting_depth() {
+        let options = PrinterOptions { max_nesting_depth: 4, ..PrinterOptions::default() };
+        assert_eq!(print("[[a]];", options), Ok("[[a]];\n".to_string()));
+        assert_eq!(print("[[[a]]];", options), Err("The code is too deeply nested".to_string()));
+    }
+
+    #[test]
+    fn invalid_nodes() {
+        let allocator = Allocator::default();
+        for source_text in ["a; let = ; b;", "f(a, +, b);"] {
+            let ret = Parser::new(&allocator, source_text, SourceType::default())
+                .recover_from_errors(true)
+                .parse();
+            assert!(!ret.errors.is_empty(), "{source_text:?} is recovered from");
+            let printed = Printer::new(source_text.len(), PrinterOptions::default())
+                .build(&ret.program)
+                .map_err(|e| e.to_string());
+            assert_eq!(
+                printed,
+                Err("The program contains code which failed to parse".to_string()),
+                "{source_text:?} is not printed without the code which failed to parse"
+            );
+        }
+    }
+}
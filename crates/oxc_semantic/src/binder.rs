@@ -67,10 +67,17 @@ impl<'a> Binder for VariableDeclarator<'a> {
 impl<'a> Binder for Class<'a> {
     fn bind(&self, builder: &mut SemanticBuilder) {
         if let Some(ident) = &self.id && !self.modifiers.contains(ModifierKind::Declare) {
+            // The visitor is already inside the class scope,
+            // class declarations bind to the parent scope.
+            let scope_id = if self.r#type == ClassType::ClassDeclaration {
+                builder.scope.parent_scope_id()
+            } else {
+                builder.scope.current_scope_id
+            };
             builder.declare_symbol(
                 &ident.name,
                 ident.span,
-                builder.scope.current_scope_id,
+                scope_id,
                 SymbolFlags::Class,
                 SymbolFlags::ClassExcludes,
             );
@@ -91,7 +98,6 @@ fn function_as_var(scope: &Scope, source_type: SourceType) -> bool {
 impl<'a> Binder for Function<'a> {
     fn bind(&self, builder: &mut SemanticBuilder) {
         if let Some(ident) = &self.id {
-            let current_scope_id = builder.scope.current_scope_id;
             let scope = builder.scope.current_scope();
             if !scope.strict_mode && matches!(builder.parent_kind(), AstKind::IfStatement(_)) {
                 // Do not declare in if single statements,
@@ -99,19 +105,21 @@ impl<'a> Binder for Function<'a> {
             } else if self.r#type == FunctionType::FunctionDeclaration {
                 // The visitor is already inside the function scope,
                 // retrieve the parent scope for the function id to bind to.
-                let parent_scope_id =
-                    builder.scope.scopes[*current_scope_id].parent().unwrap().into();
+                let parent_scope_id = builder.scope.parent_scope_id();
                 let parent_scope: &Scope = &builder.scope.scopes[parent_scope_id];
 
                 let (includes, excludes) =
                     if (parent_scope.strict_mode || self.r#async || self.generator)
                         && !function_as_var(parent_scope, builder.source_type)
                     {
-                        (SymbolFlags::BlockScopedVariable, SymbolFlags::BlockScopedVariableExcludes)
+                        (
+                            SymbolFlags::BlockScopedVariable | SymbolFlags::Function,
+                            SymbolFlags::BlockScopedFunctionExcludes,
+                        )
                     } else {
                         (
-                            SymbolFlags::FunctionScopedVariable,
-                            SymbolFlags::FunctionScopedVariableExcludes,
+                            SymbolFlags::FunctionScopedVariable | SymbolFlags::Function,
+                            SymbolFlags::FunctionExcludes,
                         )
                     };
 
@@ -173,14 +181,135 @@ impl<'a> Binder for CatchClause<'a> {
 
 impl<'a> Binder for ModuleDeclaration<'a> {
     fn bind(&self, builder: &mut SemanticBuilder) {
+        // `import type` names only have a meaning in types
+        let includes = match &self.kind {
+            ModuleDeclarationKind::ImportDeclaration(decl)
+                if decl.import_kind == Some(ImportOrExportKind::Type) =>
+            {
+                SymbolFlags::TypeImport
+            }
+            _ => SymbolFlags::empty(),
+        };
         self.bound_names(&mut |ident| {
             builder.declare_symbol(
                 &ident.name,
                 ident.span,
                 builder.scope.current_scope_id,
-                SymbolFlags::empty(),
+                includes,
                 SymbolFlags::empty(),
             );
         });
     }
 }
+
+impl<'a> Binder for TSInterfaceDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        // The visitor is already inside the scope of the type parameters
+        builder.declare_symbol(
+            &self.id.name,
+            self.id.span,
+            builder.scope.parent_scope_id(),
+            SymbolFlags::Interface,
+            SymbolFlags::InterfaceExcludes,
+        );
+    }
+}
+
+impl<'a> Binder for TSTypeAliasDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        // The visitor is already inside the scope of the type parameters
+        builder.declare_symbol(
+            &self.id.name,
+            self.id.span,
+            builder.scope.parent_scope_id(),
+            SymbolFlags::TypeAlias,
+            SymbolFlags::TypeAliasExcludes,
+        );
+    }
+}
+
+impl<'a> Binder for TSEnumDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        let (includes, excludes) = if self.modifiers.contains(ModifierKind::Const) {
+            (SymbolFlags::ConstEnum, SymbolFlags::ConstEnumExcludes)
+        } else {
+            (SymbolFlags::RegularEnum, SymbolFlags::RegularEnumExcludes)
+        };
+        builder.declare_symbol(
+            &self.id.name,
+            self.id.span,
+            builder.scope.current_scope_id,
+            includes,
+            excludes,
+        );
+    }
+}
+
+impl<'a> Binder for TSModuleDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        // `declare module 'foo' {}` and `declare global {}` augment other modules,
+        // `B` in `namespace A.B {}` is a member of `A`.
+        let TSModuleDeclarationName::Identifier(ident) = &self.id else { return };
+        if ident.name == "global" && self.modifiers.contains(ModifierKind::Declare)
+            || matches!(builder.parent_kind(), AstKind::TSModuleDeclaration(_))
+        {
+            return;
+        }
+        let current_scope_id = builder.scope.current_scope_id;
+        let (includes, excludes) = if is_instantiated(&self.body) {
+            // Namespaces merge with functions, which are also variables
+            let is_function = builder.scope.scopes[current_scope_id]
+                .get_variable_symbol_id(&ident.name)
+                .is_some_and(|symbol_id| {
+                    builder.symbols[symbol_id].flags().contains(SymbolFlags::Function)
+                });
+            let excludes = if is_function {
+                SymbolFlags::ValueModuleExcludes - SymbolFlags::Variable
+            } else {
+                SymbolFlags::ValueModuleExcludes
+            };
+            (SymbolFlags::ValueModule, excludes)
+        } else {
+            (SymbolFlags::NamespaceModule, SymbolFlags::NamespaceModuleExcludes)
+        };
+        builder.declare_symbol(&ident.name, ident.span, current_scope_id, includes, excludes);
+    }
+}
+
+/// Whether a namespace with `body` has a value at runtime, namespaces with only types do not
+fn is_instantiated(body: &TSModuleDeclarationBody) -> bool {
+    fn is_declaration_instantiated(decl: &Declaration) -> bool {
+        match decl {
+            Declaration::TSInterfaceDeclaration(_) | Declaration::TSTypeAliasDeclaration(_) => {
+                false
+            }
+            Declaration::TSModuleDeclaration(decl) => is_instantiated(&decl.body),
+            _ => true,
+        }
+    }
+    match body {
+        TSModuleDeclarationBody::TSModuleDeclaration(decl) => is_instantiated(&decl.body),
+        TSModuleDeclarationBody::TSModuleBlock(block) => block.body.iter().any(|stmt| match stmt {
+            Statement::Declaration(decl) => is_declaration_instantiated(decl),
+            Statement::ModuleDeclaration(decl) => match &decl.kind {
+                ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                    decl.declaration.as_ref().map_or(true, is_declaration_instantiated)
+                }
+                _ => true,
+            },
+            _ => true,
+        }),
+    }
+}
+
+impl<'a> Binder for TSTypeParameter<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        builder.declare_symbol(
+            &self.name.name,
+            self.name.span,
+            builder.scope.current_scope_id,
+            SymbolFlags::TypeParameter,
+            SymbolFlags::TypeParameterExcludes,
+        );
+    }
+}
@@ -6,16 +6,18 @@ use std::rc::Rc;
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{
-    ast::*, module_record::ModuleRecord, visit::Visit, AstKind, Atom, GetSpan, SourceType, Span,
-    Trivias,
+    ast::*,
+    module_record::{ModuleRecord, ModuleRecordBuilder},
+    visit::Visit,
+    AstKind, Atom, GetSpan, SourceType, Span, Trivias, MAX_NESTING_DEPTH,
 };
-use oxc_diagnostics::{Error, Redeclaration};
+use oxc_diagnostics::{Error, NestingTooDeep, Redeclaration};
 
 use crate::{
     binder::Binder,
-    module_record::ModuleRecordBuilder,
+    jsdoc::JSDocComments,
     node::{AstNodeId, AstNodes, NodeFlags, SemanticNode},
-    scope::{ScopeBuilder, ScopeId},
+    scope::{ScopeBuilder, ScopeFlags, ScopeId},
     symbol::{Reference, ReferenceFlag, SymbolFlags, SymbolId, SymbolTable},
     Semantic,
 };
@@ -34,6 +36,8 @@ pub struct SemanticBuilder<'a> {
     pub current_node_id: AstNodeId,
     pub current_node_flags: NodeFlags,
     pub current_symbol_flags: SymbolFlags,
+    depth: u32,
+    max_nesting_depth: u32,
 
     // builders
     pub nodes: AstNodes<'a>,
@@ -42,6 +46,8 @@ pub struct SemanticBuilder<'a> {
 
     with_module_record_builder: bool,
     module_record_builder: ModuleRecordBuilder,
+
+    jsdoc: JSDocComments,
 }
 
 pub struct SemanticBuilderReturn<'a> {
@@ -65,11 +71,14 @@ impl<'a> SemanticBuilder<'a> {
             current_node_id,
             current_node_flags: NodeFlags::empty(),
             current_symbol_flags: SymbolFlags::empty(),
+            depth: 0,
+            max_nesting_depth: MAX_NESTING_DEPTH,
             nodes,
             scope,
             symbols: SymbolTable::default(),
             with_module_record_builder: false,
             module_record_builder: ModuleRecordBuilder::default(),
+            jsdoc: JSDocComments::default(),
         }
     }
 
@@ -79,6 +88,13 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Statements and expressions nested deeper than `depth` are reported and not visited
+    #[must_use]
+    pub fn with_max_nesting_depth(mut self, depth: u32) -> Self {
+        self.max_nesting_depth = depth;
+        self
+    }
+
     #[must_use]
     pub fn build(mut self, program: &'a Program<'a>) -> SemanticBuilderReturn<'a> {
         // First AST pass
@@ -99,6 +115,7 @@ impl<'a> SemanticBuilder<'a> {
             scopes: self.scope.scopes,
             symbols: self.symbols,
             module_record,
+            jsdoc: self.jsdoc,
         };
         SemanticBuilderReturn { semantic, errors: self.errors }
     }
@@ -118,6 +135,16 @@ impl<'a> SemanticBuilder<'a> {
         parent_node.kind()
     }
 
+    /// Returns `false` and reports an error if the next nesting level exceeds `max_nesting_depth`
+    fn enter_nesting(&mut self, span: Span) -> bool {
+        if self.depth >= self.max_nesting_depth {
+            self.error(NestingTooDeep(self.max_nesting_depth, span));
+            return false;
+        }
+        self.depth += 1;
+        true
+    }
+
     fn create_ast_node(&mut self, kind: AstKind<'a>) {
         let ast_node =
             SemanticNode::new(kind, self.scope.current_scope_id, self.current_node_flags);
@@ -131,16 +158,25 @@ impl<'a> SemanticBuilder<'a> {
             self.nodes[self.current_node_id.indextree_id()].parent().unwrap().into();
     }
 
+    /// Flags of the scope created by `kind`, whose parent is the current node
+    fn scope_flags(&self, kind: AstKind<'a>) -> Option<ScopeFlags> {
+        // The body of a catch clause shares the scope of the catch clause with the parameter
+        if matches!(kind, AstKind::BlockStatement(_))
+            && matches!(self.nodes[*self.current_node_id].get().kind(), AstKind::CatchClause(_))
+        {
+            return None;
+        }
+        ScopeBuilder::scope_flags_from_ast_kind(kind)
+    }
+
     fn try_enter_scope(&mut self, kind: AstKind<'a>) {
-        if let Some(flags) = ScopeBuilder::scope_flags_from_ast_kind(kind) {
+        if let Some(flags) = self.scope_flags(kind) {
             self.scope.enter(flags);
         }
     }
 
     fn try_leave_scope(&mut self, kind: AstKind<'a>) {
-        if ScopeBuilder::scope_flags_from_ast_kind(kind).is_some()
-            || matches!(kind, AstKind::Program(_))
-        {
+        if self.scope_flags(kind).is_some() || matches!(kind, AstKind::Program(_)) {
             self.scope.resolve_reference(&mut self.symbols);
             self.scope.leave();
         }
@@ -158,10 +194,11 @@ impl<'a> SemanticBuilder<'a> {
         // The flags which node cannot be declared alongside in a symbol table. Used to report forbidden declarations.
         excludes: SymbolFlags,
     ) -> SymbolId {
+        let includes = includes | self.current_symbol_flags;
         if let Some(symbol_id) = self.check_redeclaration(scope_id, name, span, excludes) {
+            self.symbols[symbol_id].merge_flags(includes);
             return symbol_id;
         }
-        let includes = includes | self.current_symbol_flags;
         let symbol_id = self.symbols.create(self.current_node_id, name.clone(), span, includes);
         self.scope.scopes[scope_id].variables.insert(name.clone(), symbol_id);
         symbol_id
@@ -208,6 +245,15 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         // create new self.current_node_id
         self.create_ast_node(kind);
 
+        let ancestors = self.current_node_id.ancestors(&self.nodes).skip(1);
+        self.jsdoc.attach(
+            self.source_text,
+            &self.trivias,
+            self.current_node_id,
+            kind,
+            ancestors.map(|id| self.nodes[id].get().kind()),
+        );
+
         self.enter_kind(kind);
     }
 
@@ -216,9 +262,56 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.pop_ast_node();
         self.try_leave_scope(kind);
     }
+
+    fn visit_statement(&mut self, stmt: &'a Statement<'a>) {
+        if self.enter_nesting(stmt.span()) {
+            self.visit_statement_match(stmt);
+            self.depth -= 1;
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &'a Expression<'a>) {
+        if self.enter_nesting(expr.span()) {
+            match expr {
+                Expression::BinaryExpression(_) | Expression::LogicalExpression(_) => {
+                    self.visit_binary_chain(expr);
+                }
+                _ => self.visit_expression_match(expr),
+            }
+            self.depth -= 1;
+        }
+    }
 }
 
 impl<'a> SemanticBuilder<'a> {
+    /// Visit the left operands of a binary or logical expression in a loop.
+    ///
+    /// `a + b + c` is nested as `(a + b) + c` in the AST but not in the source code,
+    /// long chains such as generated string concatenations must not count towards the nesting depth.
+    fn visit_binary_chain(&mut self, expr: &'a Expression<'a>) {
+        let mut chain = vec![];
+        let mut left = expr;
+        loop {
+            let (kind, next, right) = match left {
+                Expression::BinaryExpression(expr) => {
+                    (AstKind::BinaryExpression(expr), &expr.left, &expr.right)
+                }
+                Expression::LogicalExpression(expr) => {
+                    (AstKind::LogicalExpression(expr), &expr.left, &expr.right)
+                }
+                _ => break,
+            };
+            self.enter_node(kind);
+            chain.push((kind, right));
+            left = next;
+        }
+        self.visit_expression_match(left);
+        for (kind, right) in chain.into_iter().rev() {
+            self.visit_expression(right);
+            self.leave_node(kind);
+        }
+    }
+
     fn enter_kind(&mut self, kind: AstKind<'a>) {
         match kind {
             AstKind::ModuleDeclaration(decl) => {
@@ -241,9 +334,34 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::CatchClause(clause) => {
                 clause.bind(self);
             }
+            AstKind::TSInterfaceDeclaration(decl) => {
+                decl.bind(self);
+            }
+            AstKind::TSTypeAliasDeclaration(decl) => {
+                decl.bind(self);
+            }
+            AstKind::TSEnumDeclaration(decl) => {
+                decl.bind(self);
+            }
+            AstKind::TSModuleDeclaration(decl) => {
+                decl.bind(self);
+            }
+            AstKind::TSTypeParameter(param) => {
+                param.bind(self);
+            }
             AstKind::IdentifierReference(ident) => {
                 self.reference_identifier(ident);
             }
+            AstKind::TSTypeReference(ty) => {
+                self.reference_type_name(&ty.type_name, ReferenceFlag::Type);
+            }
+            AstKind::TSClassImplements(implements) => {
+                self.reference_type_name(&implements.expression, ReferenceFlag::Type);
+            }
+            // `typeof a` in types refers to the value
+            AstKind::TSTypeQuery(query) => {
+                self.reference_type_name(&query.expr_name, ReferenceFlag::Read);
+            }
             AstKind::JSXElementName(elem) => {
                 self.reference_jsx_element_name(elem);
             }
@@ -271,18 +389,38 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     fn reference_identifier(&mut self, ident: &IdentifierReference) {
-        let flag = if matches!(
-            self.parent_kind(),
-            AstKind::SimpleAssignmentTarget(_) | AstKind::AssignmentTarget(_)
-        ) {
-            ReferenceFlag::Write
-        } else {
-            ReferenceFlag::Read
+        let flag = match self.parent_kind() {
+            AstKind::SimpleAssignmentTarget(_) | AstKind::AssignmentTarget(_) => {
+                ReferenceFlag::Write
+            }
+            // `a` in `({ a = b } = c)`
+            AstKind::AssignmentTargetPropertyIdentifier(target)
+                if std::ptr::eq(&target.binding, ident) =>
+            {
+                ReferenceFlag::Write
+            }
+            // `B` in `interface A extends B {}`
+            AstKind::TSInterfaceHeritage(_) => ReferenceFlag::Type,
+            _ => ReferenceFlag::Read,
         };
         let reference = Reference::new(self.current_node_id, ident.span, flag);
         self.scope.reference_identifier(&ident.name, reference);
     }
 
+    /// Reference the leftmost name of `name`,
+    /// which is a namespace in qualified type names such as `A` in `A.B`
+    fn reference_type_name(&mut self, mut name: &TSTypeName, mut flag: ReferenceFlag) {
+        while let TSTypeName::QualifiedName(qualified) = name {
+            name = &qualified.left;
+            if flag == ReferenceFlag::Type {
+                flag = ReferenceFlag::Namespace;
+            }
+        }
+        let TSTypeName::IdentifierName(ident) = name else { unreachable!() };
+        let reference = Reference::new(self.current_node_id, ident.span, flag);
+        self.scope.reference_identifier(&ident.name, reference);
+    }
+
     fn reference_jsx_element_name(&mut self, elem: &JSXElementName) {
         if matches!(self.parent_kind(), AstKind::JSXOpeningElement(_)) {
             if let Some(ident) = match elem {
@@ -309,3 +447,342 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstKind, SourceType, Span};
+    use oxc_diagnostics::Redeclaration;
+    use oxc_parser::Parser;
+
+    use crate::SemanticBuilder;
+
+    #[test]
+    fn max_nesting_depth() {
+        let source_text = "[[[[[a]]]]]; [[b]];";
+        let source_type = SourceType::default();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_text, source_type, &ret.trivias)
+            .with_max_nesting_depth(4)
+            .build(program);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "The code is too deeply nested");
+        let references = ret
+            .semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.get().kind() {
+                AstKind::IdentifierReference(ident) => Some(ident.name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(references, ["b"]);
+    }
+
+    #[test]
+    fn flat_binary_chain() {
+        let terms = vec!["a"; 100_000];
+        let source_text = format!("x = {}; y = {};", terms.join(" + "), terms.join(" || "));
+        let source_type = SourceType::default();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(&source_text, source_type, &ret.trivias).build(program);
+        assert!(ret.errors.is_empty());
+        let nodes = ret.semantic.nodes();
+        let count =
+            |f: fn(AstKind) -> bool| nodes.iter().filter(|node| f(node.get().kind())).count();
+        assert_eq!(count(|kind| matches!(kind, AstKind::BinaryExpression(_))), terms.len() - 1);
+        assert_eq!(count(|kind| matches!(kind, AstKind::LogicalExpression(_))), terms.len() - 1);
+        assert_eq!(
+            count(|kind| matches!(kind, AstKind::IdentifierReference(_))),
+            terms.len() * 2 + 2
+        );
+
+        // The right operand of a binary expression is still nested
+        let source_text = "x = a + (b + (c + (d + e)));";
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_text, source_type, &ret.trivias)
+            .with_max_nesting_depth(6)
+            .build(program);
+        assert_eq!(ret.errors.len(), 1);
+    }
+
+    #[test]
+    fn typescript_nodes() {
+        let source_text = "enum E { A = 1 } let x: Foo<string> = y;";
+        let source_type = *SourceType::default().with_typescript(true);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+        let nodes = ret.semantic.nodes();
+        let find = |f: fn(AstKind) -> bool| nodes.iter().find(|node| f(node.get().kind())).unwrap();
+
+        let member = find(|kind| matches!(kind, AstKind::TSEnumMember(_)));
+        assert!(matches!(nodes.parent_kind(member), AstKind::TSEnumDeclaration(_)));
+
+        let reference = find(|kind| matches!(kind, AstKind::TSTypeReference(_)));
+        assert!(matches!(nodes.parent_kind(reference), AstKind::TSTypeAnnotation(_)));
+        assert!(nodes.iter().any(|node| matches!(node.get().kind(), AstKind::TSStringKeyword(_))));
+    }
+
+    #[test]
+    fn constant_evaluation() {
+        use oxc_ast::{ast::Argument, ConstantEvaluation, ConstantValue};
+
+        let source_text = "
+            const a = 1, b = a + 1, c = `${b}px`;
+            let d = 1;
+            const { e } = f;
+            const g = h, NaN2 = NaN;
+            [a, b, c, d, e, g, i, NaN2, undefined];
+            const i = 2;
+            { const a = 'x'; [a, b]; }
+            function f(undefined) { [undefined]; }
+        ";
+        let source_type = SourceType::default();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+        let semantic = ret.semantic;
+        let values = semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.get().kind() {
+                AstKind::ArrayExpression(array) => Some(array),
+                _ => None,
+            })
+            .map(|array| {
+                array
+                    .elements
+                    .iter()
+                    .map(|element| {
+                        let Some(Argument::Expression(expr)) = element else {
+                            unreachable!()
+                        };
+                        semantic.eval_expression(expr).as_ref().map(ConstantValue::to_js_string)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let expected: [&[Option<&str>]; 3] = [
+            &[
+                Some("1"),
+                Some("2"),
+                Some("2px"),
+                None,
+                None,
+                None,
+                None,
+                Some("NaN"),
+                Some("undefined"),
+            ],
+            &[Some("x"), Some("2")],
+            &[None],
+        ];
+        for (values, expected) in values.iter().zip(expected) {
+            assert_eq!(values.iter().map(Option::as_deref).collect::<Vec<_>>(), expected);
+        }
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn side_effects() {
+        use oxc_ast::SideEffects;
+
+        let source_text = "
+            let a;
+            a;
+            b;
+            undefined;
+            /*#__PURE__*/ f(a);
+            /*#__PURE__*/ f(b);
+            f();
+            function g(b) { return /*#__PURE__*/ f(b); }
+        ";
+        let source_type = SourceType::default();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+        let semantic = ret.semantic;
+        let results = semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.get().kind() {
+                AstKind::ExpressionStatement(stmt) => Some(&stmt.expression),
+                AstKind::ReturnStatement(stmt) => stmt.argument.as_ref(),
+                _ => None,
+            })
+            .map(|expr| {
+                (semantic.expression_has_side_effects(expr), semantic.expression_may_throw(expr))
+            })
+            .collect::<Vec<_>>();
+        // Reading the undeclared global `b` throws
+        let expected = [
+            (false, false),
+            (false, true),
+            (false, false),
+            (false, false),
+            (false, true),
+            (true, true),
+            (false, false),
+        ];
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn typescript_symbols() {
+        use crate::{Symbol, SymbolFlags};
+
+        let source_text = "
+            interface A { a: B }
+            interface A { b: string }
+            type B = number;
+            const A = 1;
+            function f() {}
+            namespace f { export const x = 1; }
+            enum E { X }
+            enum E { Y }
+            namespace N { export type T = 1; }
+            import type { I } from 'i';
+            let a: A = A;
+            let n: N.T = E.X;
+            let t: typeof f;
+            class C<T> implements A { m(): T { let x: T; { let y: T; } } }
+            function g<U>(u: U): I {}
+            let i: I = I;
+            type M<K> = { [P in keyof K]: K[P] };
+        ";
+        let source_type = *SourceType::default().with_typescript(true);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let symbols = ret.semantic.symbols();
+
+        let flags = |name: &str| {
+            symbols
+                .iter()
+                .filter(|symbol| symbol.name().as_str() == name)
+                .map(Symbol::flags)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            flags("A"),
+            [SymbolFlags::Interface
+                | SymbolFlags::BlockScopedVariable
+                | SymbolFlags::ConstVariable]
+        );
+        assert_eq!(
+            flags("f"),
+            [SymbolFlags::FunctionScopedVariable
+                | SymbolFlags::Function
+                | SymbolFlags::ValueModule]
+        );
+        assert_eq!(flags("E"), [SymbolFlags::RegularEnum]);
+        assert_eq!(flags("N"), [SymbolFlags::NamespaceModule]);
+        assert_eq!(flags("I"), [SymbolFlags::Import | SymbolFlags::TypeImport]);
+        assert_eq!(
+            flags("T"),
+            [SymbolFlags::Export | SymbolFlags::TypeAlias, SymbolFlags::TypeParameter]
+        );
+        assert_eq!(flags("U"), [SymbolFlags::TypeParameter]);
+
+        // (reference, flags of the symbol it resolves to)
+        let mut references = symbols
+            .resolved_references()
+            .iter()
+            .map(|reference| {
+                let symbol = &symbols[reference.resolved_symbol_id];
+                (reference.span().source_text(source_text), symbol.flags())
+            })
+            .collect::<Vec<_>>();
+        references.sort_by_key(|(name, _)| *name);
+        let type_parameter = SymbolFlags::TypeParameter;
+        assert_eq!(
+            references
+                .iter()
+                .map(|(name, flags)| (
+                    *name,
+                    flags.intersects(SymbolFlags::Type),
+                    *flags == type_parameter
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("A", true, false),
+                ("A", true, false),
+                ("A", true, false),
+                ("B", true, false),
+                ("E", true, false),
+                ("I", false, false),
+                ("I", false, false),
+                ("K", true, true),
+                ("K", true, true),
+                ("N", false, false),
+                ("P", true, true),
+                ("T", true, true),
+                ("T", true, true),
+                ("T", true, true),
+                ("U", true, true),
+                ("f", false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn typescript_redeclarations() {
+        let source_text = "
+            type X = 1; type X = 2;
+            const enum Y {} enum Y {}
+            let z; namespace z { export const a = 1; }
+            interface Q {} type Q = 1;
+            var v; namespace v { export const a = 1; }
+            class K {} interface K {} namespace K { export const a = 1; }
+            function h() {} namespace h { export type T = 1; }
+        ";
+        let source_type = *SourceType::default().with_typescript(true);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+        let errors = ret
+            .errors
+            .iter()
+            .map(|error| {
+                let Redeclaration(name, previous, span) = error.downcast_ref().unwrap();
+                (name.as_str(), *previous, *span)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                ("X", Span::new(18, 19), Span::new(30, 31)),
+                ("Y", Span::new(60, 61), Span::new(70, 71)),
+                ("z", Span::new(91, 92), Span::new(104, 105)),
+                ("Q", Span::new(152, 153), Span::new(162, 163)),
+                ("v", Span::new(185, 186), Span::new(198, 199)),
+            ]
+        );
+
+        // Declarations that merge
+        let source_text = "
+            interface I { a: 1 } interface I { b: 2 }
+            function F() {} namespace F { export const a = 1; }
+            class C {} namespace C { export const a = 1; }
+            enum E { A } namespace E { export const a = 1; }
+            type T = 1; const T = 1;
+        ";
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    }
+}
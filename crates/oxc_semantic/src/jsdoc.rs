@@ -0,0 +1,155 @@
+use oxc_ast::{ast::JSDoc, AstKind, GetSpan, Span, Trivias};
+use rustc_hash::FxHashMap;
+
+use crate::node::AstNodeId;
+
+/// `JSDoc` comments attached to functions, classes and variable declarations
+///
+/// The comments are stored as spans from [`Trivias::comments`],
+/// [`JSDocComments::get_parsed_by_node`] parses them into a description and tags.
+#[derive(Debug, Default)]
+pub struct JSDocComments {
+    attached: FxHashMap<AstNodeId, Vec<Span>>,
+}
+
+impl JSDocComments {
+    /// All `JSDoc` comments before the node, in source order
+    #[must_use]
+    pub fn get_all_by_node(&self, node_id: AstNodeId) -> Option<&[Span]> {
+        self.attached.get(&node_id).map(Vec::as_slice)
+    }
+
+    /// The `JSDoc` comment closest to the node
+    #[must_use]
+    pub fn get_by_node(&self, node_id: AstNodeId) -> Option<Span> {
+        self.get_all_by_node(node_id).and_then(|spans| spans.last().copied())
+    }
+
+    /// The `JSDoc` comment closest to the node, parsed by `parse`.
+    ///
+    /// This crate does not depend on the parser, pass
+    /// `|span| JSDocParser::new(&allocator, source_text, span).parse().jsdoc`
+    /// with `oxc_parser::JSDocParser`.
+    pub fn get_parsed_by_node<'a>(
+        &self,
+        node_id: AstNodeId,
+        parse: impl FnOnce(Span) -> JSDoc<'a>,
+    ) -> Option<JSDoc<'a>> {
+        self.get_by_node(node_id).map(parse)
+    }
+
+    /// Attach the leading `JSDoc` comments of `kind` if it is a function, class or variable declaration.
+    /// The parent is used for finding comments written before `export` or before the key of a method,
+    /// the span of a method's function starts at its parameters.
+    pub fn attach<'a>(
+        &mut self,
+        source_text: &str,
+        trivias: &Trivias,
+        node_id: AstNodeId,
+        kind: AstKind<'a>,
+        mut ancestors: impl Iterator<Item = AstKind<'a>>,
+    ) {
+        if !matches!(
+            kind,
+            AstKind::Function(_) | AstKind::Class(_) | AstKind::VariableDeclaration(_)
+        ) {
+            return;
+        }
+        // `({ m: function() {} })` has the value between the property and the function
+        let parent = ancestors.find(|kind| !matches!(kind, AstKind::PropertyValue(_)));
+        let span = match parent {
+            Some(AstKind::ModuleDeclaration(decl)) => decl.span,
+            Some(AstKind::MethodDefinition(method)) => method.span,
+            Some(AstKind::Property(property)) => property.span,
+            Some(AstKind::PropertyDefinition(property)) => property.span,
+            _ => kind.span(),
+        };
+        let comments = trivias
+            .leading_comments(source_text, span)
+            .into_iter()
+            .filter(|comment| comment.comment.is_jsdoc())
+            .map(|comment| comment.span)
+            .collect::<Vec<_>>();
+        if !comments.is_empty() {
+            self.attached.insert(node_id, comments);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::JSDocTag, AstKind, SourceType};
+    use oxc_parser::{JSDocParser, Parser};
+
+    use crate::SemanticBuilder;
+
+    #[test]
+    fn attached_jsdoc() {
+        let source_text = "/** foo */\nfunction foo() {}\n/** bar */\nexport class Bar {}\n/* not jsdoc */\nconst baz = 1;";
+        let source_type = *SourceType::default().with_module(true);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program).semantic;
+
+        let jsdoc_text = |kind: fn(&AstKind) -> bool| {
+            let node = semantic.nodes().iter().find(|node| kind(&node.get().kind())).unwrap();
+            let id = semantic.nodes().get_node_id(node).unwrap();
+            semantic.jsdoc().get_by_node(id.into()).map(|span| span.source_text(source_text))
+        };
+        assert_eq!(jsdoc_text(|kind| matches!(kind, AstKind::Function(_))), Some("* foo "));
+        assert_eq!(jsdoc_text(|kind| matches!(kind, AstKind::Class(_))), Some("* bar "));
+        assert_eq!(jsdoc_text(|kind| matches!(kind, AstKind::VariableDeclaration(_))), None);
+    }
+
+    #[test]
+    fn attached_jsdoc_methods() {
+        let source_text = "class A { /** m */ m() {} /** p */ p = () => {} }\n({ /** n */ n() {}, /** o */ o: function() {} });";
+        let source_type = SourceType::default();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program).semantic;
+
+        let jsdoc_texts = semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| {
+                let id = semantic.nodes().get_node_id(node).unwrap();
+                semantic.jsdoc().get_by_node(id.into()).map(|span| span.source_text(source_text))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(jsdoc_texts, ["* m ", "* n ", "* o "]);
+    }
+
+    #[test]
+    fn parsed_jsdoc() {
+        let source_text = "/**\n * Add\n * @param {number} a first\n * @returns {number}\n */\nfunction add(a) {}";
+        let source_type = SourceType::default();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program).semantic;
+
+        let node = semantic
+            .nodes()
+            .iter()
+            .find(|node| matches!(node.get().kind(), AstKind::Function(_)))
+            .unwrap();
+        let id = semantic.nodes().get_node_id(node).unwrap();
+        let jsdoc = semantic
+            .jsdoc()
+            .get_parsed_by_node(id.into(), |span| {
+                JSDocParser::new(&allocator, source_text, span).parse().jsdoc
+            })
+            .unwrap();
+        assert_eq!(jsdoc.description.as_str(), "Add");
+        let JSDocTag::Param(param) = &jsdoc.tags[0] else { unreachable!() };
+        assert_eq!((param.name.name.as_str(), param.description.as_str()), ("a", "first"));
+        assert!(matches!(jsdoc.tags[1], JSDocTag::Returns(_)));
+    }
+}
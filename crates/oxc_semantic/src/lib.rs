@@ -3,6 +3,7 @@
 
 mod binder;
 mod builder;
+mod jsdoc;
 mod module_record;
 mod node;
 mod scope;
@@ -11,9 +12,14 @@ mod symbol;
 use std::rc::Rc;
 
 pub use builder::SemanticBuilder;
+pub use jsdoc::JSDocComments;
 use node::AstNodeId;
 pub use node::{AstNode, AstNodes, SemanticNode};
-use oxc_ast::{module_record::ModuleRecord, AstKind, SourceType, Trivias};
+use oxc_ast::{
+    ast::{BindingPatternKind, IdentifierReference, VariableDeclarator},
+    module_record::ModuleRecord,
+    AstKind, ConstantEvaluation, ConstantValue, SideEffects, SourceType, Span, Trivias,
+};
 pub use scope::{Scope, ScopeFlags, ScopeTree};
 pub use symbol::{Reference, ResolvedReference, Symbol, SymbolFlags, SymbolTable};
 
@@ -31,6 +37,8 @@ pub struct Semantic<'a> {
     trivias: Rc<Trivias>,
 
     module_record: ModuleRecord,
+
+    jsdoc: JSDocComments,
 }
 
 impl<'a> Semantic<'a> {
@@ -64,6 +72,11 @@ impl<'a> Semantic<'a> {
         &self.module_record
     }
 
+    #[must_use]
+    pub fn jsdoc(&self) -> &JSDocComments {
+        &self.jsdoc
+    }
+
     #[must_use]
     pub fn symbols(&self) -> &SymbolTable {
         &self.symbols
@@ -77,3 +90,44 @@ impl<'a> Semantic<'a> {
         scope.unresolved_references.contains_key(&id.name)
     }
 }
+
+/// Resolves references to `const` bindings which are declared before the reference
+/// and initialized with a constant, and references to the globals `undefined`, `NaN` and `Infinity`.
+impl<'a> ConstantEvaluation<'a> for Semantic<'a> {
+    fn resolve_binding(&self, ident: &IdentifierReference) -> Option<ConstantValue> {
+        let Some(reference) = self.symbols.get_resolved_reference_by_span(ident.span) else {
+            return ConstantValue::global(&ident.name);
+        };
+        let symbol = &self.symbols[reference.resolved_symbol_id];
+        if !symbol.is_const() {
+            return None;
+        }
+        let AstKind::VariableDeclarator(VariableDeclarator {
+            span,
+            id,
+            init: Some(init),
+            ..
+        }) = self.nodes.kind(symbol.declaration())
+        else {
+            return None;
+        };
+        // The binding is in its TDZ before the declaration,
+        // requiring the declaration to come first also rules out cycles.
+        if !matches!(id.kind, BindingPatternKind::BindingIdentifier(_))
+            || span.end > ident.span.start
+        {
+            return None;
+        }
+        self.eval_expression(init)
+    }
+}
+
+impl<'a> SideEffects<'a> for Semantic<'a> {
+    fn has_pure_annotation(&self, span: Span) -> bool {
+        self.trivias.has_pure_annotation(self.source_text, span)
+    }
+
+    fn is_global_reference(&self, ident: &IdentifierReference) -> bool {
+        self.symbols.get_resolved_reference_by_span(ident.span).is_none()
+    }
+}
@@ -1,7 +1,3 @@
-mod builder;
-
-pub use builder::ModuleRecordBuilder;
-
 #[cfg(test)]
 mod module_record_tests {
     use oxc_allocator::Allocator;
@@ -1,8 +1,8 @@
-use oxc_ast::{ast::ClassType, AstKind, Atom, SourceType};
+use oxc_ast::{AstKind, Atom, SourceType};
 use rustc_hash::FxHashMap;
 
 use super::{Scope, ScopeFlags, ScopeId, ScopeTree};
-use crate::{symbol::Reference, SymbolTable};
+use crate::{symbol::Reference, SymbolFlags, SymbolTable};
 
 #[derive(Debug)]
 pub struct ScopeBuilder {
@@ -31,6 +31,14 @@ impl ScopeBuilder {
         &mut self.scopes[self.current_scope_id]
     }
 
+    /// # Panics
+    /// The root scope does not have a parent,
+    /// it is a logic error to call this in the root scope.
+    #[must_use]
+    pub fn parent_scope_id(&self) -> ScopeId {
+        self.scopes[self.current_scope_id.indextree_id()].parent().unwrap().into()
+    }
+
     pub fn enter(&mut self, flags: ScopeFlags) {
         // Inherit strict mode for functions
         // https://tc39.es/ecma262/#sec-strict-mode-code
@@ -64,21 +72,41 @@ impl ScopeBuilder {
             let current_scope = self.current_scope_mut();
             std::mem::take(&mut current_scope.unresolved_references)
         };
-        let mut unresolved_references = FxHashMap::default();
-
-        'outer: for (variable, reference) in all_references {
-            // The reference resolves to the first matching symbol in the scope chain
-            let scope_chain = self.scopes.ancestors(self.current_scope_id);
-            for scope in scope_chain {
-                let scope = &self.scopes[scope];
-                if let Some(symbol_id) = scope.get().get_variable_symbol_id(&variable) {
-                    // We have resolved this reference.
-                    symbol_table.resolve_reference(reference, symbol_id);
-                    continue 'outer;
+        let mut unresolved_references: FxHashMap<Atom, Vec<Reference>> = FxHashMap::default();
+        let parent_scope_id = self.scopes[self.current_scope_id.indextree_id()].parent();
+
+        for (variable, mut references) in all_references {
+            // A reference resolves to the first symbol in the scope chain with the meaning of the reference,
+            // e.g. `A` in `let a: A` skips a variable `A` and resolves to an interface `A` in a parent scope.
+            for meaning in [SymbolFlags::Value, SymbolFlags::Type, SymbolFlags::Namespace] {
+                let (with_meaning, rest): (Vec<_>, Vec<_>) =
+                    references.into_iter().partition(|reference| reference.meaning() == meaning);
+                references = rest;
+                if with_meaning.is_empty() {
+                    continue;
+                }
+                let symbol_id = self.scopes.ancestors(self.current_scope_id).find_map(|scope| {
+                    self.scopes[scope]
+                        .get()
+                        .get_variable_symbol_id(&variable)
+                        .filter(|symbol_id| symbol_table[*symbol_id].flags().has_meaning(meaning))
+                });
+                if let Some(symbol_id) = symbol_id {
+                    // We have resolved these references.
+                    symbol_table.resolve_reference(with_meaning, symbol_id);
+                } else if meaning != SymbolFlags::Value && let Some(parent_scope_id) = parent_scope_id {
+                    // Type declarations are hoisted, retry when leaving the parent scope,
+                    // e.g. `B` in `interface A { b: B } type B = 1;`
+                    self.scopes[parent_scope_id]
+                        .get_mut()
+                        .unresolved_references
+                        .entry(variable.clone())
+                        .or_default()
+                        .extend(with_meaning);
+                } else {
+                    unresolved_references.entry(variable.clone()).or_default().extend(with_meaning);
                 }
             }
-
-            unresolved_references.insert(variable, reference);
         }
 
         let current_scope = self.current_scope_mut();
@@ -100,17 +128,27 @@ impl ScopeBuilder {
             AstKind::ArrowExpression(_) => Some(ScopeFlags::Function | ScopeFlags::Arrow),
             AstKind::StaticBlock(_) => Some(ScopeFlags::ClassStaticBlock),
             AstKind::TSModuleBlock(_) => Some(ScopeFlags::TsModuleBlock),
-            AstKind::Class(class) if matches!(class.r#type, ClassType::ClassExpression) => {
-                // Class expression creates a temporary scope with the class name as its only variable
-                // E.g., `let c = class A { foo() { console.log(A) } }`
-                Some(ScopeFlags::empty())
-            }
             AstKind::BlockStatement(_)
             | AstKind::CatchClause(_)
             | AstKind::ForStatement(_)
             | AstKind::ForInStatement(_)
             | AstKind::ForOfStatement(_)
-            | AstKind::SwitchStatement(_) => Some(ScopeFlags::empty()),
+            | AstKind::SwitchStatement(_)
+            // Class expression creates a temporary scope with the class name as its only variable
+            // E.g., `let c = class A { foo() { console.log(A) } }`
+            // Class declarations bind their name in the parent scope, the scope has the type parameters.
+            | AstKind::Class(_)
+            // Scopes of type parameters, and of `infer` types in conditional types
+            | AstKind::TSInterfaceDeclaration(_)
+            | AstKind::TSTypeAliasDeclaration(_)
+            | AstKind::TSMethodSignature(_)
+            | AstKind::TSCallSignatureDeclaration(_)
+            | AstKind::TSConstructSignatureDeclaration(_)
+            | AstKind::TSFunctionType(_)
+            | AstKind::TSConstructorType(_)
+            | AstKind::TSMappedType(_)
+            | AstKind::TSConditionalType(_)
+            | AstKind::FlowOpaqueTypeDeclaration(_) => Some(ScopeFlags::empty()),
             _ => None,
         }
     }
@@ -37,7 +37,7 @@ fn symbol_size() {
 
 bitflags! {
     #[derive(Default)]
-    pub struct SymbolFlags: u16 {
+    pub struct SymbolFlags: u32 {
         const None                    = 0;
         /// Variable (var) or parameter
         const FunctionScopedVariable  = 1 << 0;
@@ -51,9 +51,29 @@ bitflags! {
         const Export                  = 1 << 4;
         const Class                   = 1 << 5;
         const CatchVariable           = 1 << 6; // try {} catch(catch_variable) {}
+        /// A function declaration, which is also a function or block scoped variable
+        const Function                = 1 << 7;
+        const Interface               = 1 << 8;
+        const TypeAlias               = 1 << 9;
+        const RegularEnum             = 1 << 10;
+        const ConstEnum               = 1 << 11;
+        /// An instantiated namespace, which has a value at runtime
+        const ValueModule             = 1 << 12;
+        /// A namespace with only types, e.g. `namespace A { type B = 1 }`
+        const NamespaceModule         = 1 << 13;
+        const TypeParameter           = 1 << 14;
+        /// Is this symbol inside an `import type` declaration
+        const TypeImport              = 1 << 15;
 
         const Variable = Self::FunctionScopedVariable.bits | Self::BlockScopedVariable.bits;
-        const Value = Self::Variable.bits | Self::Class.bits;
+        const Enum = Self::RegularEnum.bits | Self::ConstEnum.bits;
+
+        /// Symbols with a meaning in expressions
+        const Value = Self::Variable.bits | Self::Class.bits | Self::Enum.bits | Self::ValueModule.bits;
+        /// Symbols with a meaning in types
+        const Type = Self::Class.bits | Self::Interface.bits | Self::Enum.bits | Self::TypeAlias.bits | Self::TypeParameter.bits;
+        /// Symbols with a meaning on the left of qualified names, e.g. `A` in `A.B`
+        const Namespace = Self::ValueModule.bits | Self::NamespaceModule.bits | Self::Enum.bits;
 
         /// Variables can be redeclared, but can not redeclare a block-scoped declaration with the
         /// same name, or any other value that is not a variable, e.g. ValueModule or Class
@@ -63,7 +83,38 @@ bitflags! {
         /// they can not merge with anything in the value space
         const BlockScopedVariableExcludes = Self::Value.bits;
 
-        const ClassExcludes = Self::Value.bits;
+        /// Functions are variables which can also merge with namespaces
+        const FunctionExcludes = Self::FunctionScopedVariableExcludes.bits - Self::ValueModule.bits;
+        const BlockScopedFunctionExcludes = Self::BlockScopedVariableExcludes.bits - Self::ValueModule.bits;
+
+        /// Classes can merge with interfaces and namespaces
+        const ClassExcludes = (Self::Value.bits | Self::Type.bits) & !(Self::ValueModule.bits | Self::Interface.bits);
+        /// Interfaces can merge with interfaces, classes and any value
+        const InterfaceExcludes = Self::Type.bits & !(Self::Interface.bits | Self::Class.bits);
+        const TypeAliasExcludes = Self::Type.bits;
+        /// Enums can merge with enums of the same kind, regular enums also with namespaces
+        const RegularEnumExcludes = (Self::Value.bits | Self::Type.bits) & !(Self::RegularEnum.bits | Self::ValueModule.bits);
+        const ConstEnumExcludes = (Self::Value.bits | Self::Type.bits) & !Self::ConstEnum.bits;
+        /// Namespaces can merge with namespaces, functions, classes and regular enums,
+        /// functions are checked by the binder as they are also variables
+        const ValueModuleExcludes = Self::Value.bits & !(Self::Class.bits | Self::RegularEnum.bits | Self::ValueModule.bits);
+        const NamespaceModuleExcludes = 0;
+        const TypeParameterExcludes = Self::Type.bits & !Self::TypeParameter.bits;
+    }
+}
+
+impl SymbolFlags {
+    /// Whether a symbol with these flags can be referenced with `meaning`,
+    /// one of [`SymbolFlags::Value`], [`SymbolFlags::Type`] and [`SymbolFlags::Namespace`].
+    ///
+    /// Imports can have any meaning, the meaning of the imported symbol is not known,
+    /// except for `import type` which has no value.
+    #[must_use]
+    pub fn has_meaning(self, meaning: Self) -> bool {
+        if self.contains(Self::TypeImport) {
+            return !meaning.contains(Self::Value);
+        }
+        self.contains(Self::Import) || self.intersects(meaning)
     }
 }
 
@@ -99,6 +150,11 @@ impl Symbol {
         self.flags
     }
 
+    /// Merge the flags of another declaration of the symbol, e.g. of `interface A {}` into `class A {}`
+    pub(crate) fn merge_flags(&mut self, flags: SymbolFlags) {
+        self.flags |= flags;
+    }
+
     #[must_use]
     pub fn is_const(&self) -> bool {
         self.flags.contains(SymbolFlags::ConstVariable)
@@ -115,6 +171,11 @@ impl Symbol {
         self.flags.contains(SymbolFlags::Class)
     }
 
+    #[must_use]
+    pub fn is_type(&self) -> bool {
+        self.flags.intersects(SymbolFlags::Type)
+    }
+
     #[must_use]
     pub fn is_export(&self) -> bool {
         self.flags.contains(SymbolFlags::Export)
@@ -5,7 +5,7 @@ use std::num::NonZeroUsize;
 use bitflags::bitflags;
 use oxc_ast::Span;
 
-use super::SymbolId;
+use super::{SymbolFlags, SymbolId};
 use crate::node::AstNodeId;
 
 #[derive(Debug, Clone)]
@@ -22,6 +22,10 @@ bitflags! {
         const Read = 1 << 0;
         const Write = 1 << 1;
         const ReadWrite = Self::Read.bits | Self::Write.bits;
+        /// A reference in a type, e.g. `A` in `let a: A`
+        const Type = 1 << 2;
+        /// The leftmost name of a qualified name in a type, e.g. `A` in `let a: A.B`
+        const Namespace = 1 << 3;
     }
 }
 
@@ -46,6 +50,24 @@ impl Reference {
         self.flag.intersects(ReferenceFlag::ReadWrite)
     }
 
+    #[must_use]
+    pub const fn is_type(&self) -> bool {
+        self.flag.intersects(ReferenceFlag::Type.union(ReferenceFlag::Namespace))
+    }
+
+    /// The meaning of the symbols this reference can resolve to,
+    /// one of [`SymbolFlags::Value`], [`SymbolFlags::Type`] and [`SymbolFlags::Namespace`]
+    #[must_use]
+    pub fn meaning(&self) -> SymbolFlags {
+        if self.flag.contains(ReferenceFlag::Type) {
+            SymbolFlags::Type
+        } else if self.flag.contains(ReferenceFlag::Namespace) {
+            SymbolFlags::Namespace
+        } else {
+            SymbolFlags::Value
+        }
+    }
+
     #[must_use]
     pub fn resolve_to(self, symbol: SymbolId) -> ResolvedReference {
         ResolvedReference::new(self, symbol)
@@ -1,6 +1,7 @@
 use std::ops::{Deref, Index, IndexMut};
 
 use oxc_ast::{Atom, Span};
+use rustc_hash::FxHashMap;
 
 use super::reference::ResolvedReferenceId;
 use super::{Symbol, SymbolFlags, SymbolId};
@@ -18,6 +19,8 @@ pub struct SymbolTable {
     symbols: Vec<Symbol>,
     /// Stores all the resolved references indexed by `ResolvedReferenceId`
     resolved_references: Vec<ResolvedReference>,
+    /// Resolved references indexed by the start of their span
+    resolved_references_by_start: FxHashMap<u32, ResolvedReferenceId>,
 }
 
 impl Index<SymbolId> for SymbolTable {
@@ -91,6 +94,13 @@ impl SymbolTable {
         self.resolved_references.get(id.index0())
     }
 
+    /// The resolved reference at `span`, `None` when there is no reference or it is unresolved
+    #[must_use]
+    pub fn get_resolved_reference_by_span(&self, span: Span) -> Option<&ResolvedReference> {
+        let id = self.resolved_references_by_start.get(&span.start)?;
+        self.get_resolved_reference(*id).filter(|reference| reference.span() == span)
+    }
+
     /// Resolve all `references` to `symbol_id`
     pub(crate) fn resolve_reference(&mut self, references: Vec<Reference>, symbol_id: SymbolId) {
         let additional_len = references.len();
@@ -102,6 +112,7 @@ impl SymbolTable {
         for reference in references {
             let resolved_reference_id =
                 ResolvedReferenceId::new(self.resolved_references.len() + 1);
+            self.resolved_references_by_start.insert(reference.span.start, resolved_reference_id);
             let resolved_reference = reference.resolve_to(symbol_id);
             self.resolved_references.push(resolved_reference);
             // explicitly push to vector here in correspondence to the previous reserve call
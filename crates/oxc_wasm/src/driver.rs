@@ -1,13 +1,67 @@
 use std::{rc::Rc, sync::Arc};
 
-use miette::NamedSource;
 use oxc_allocator::Allocator;
-use oxc_ast::SourceType;
+use oxc_ast::{estree::ESTreeOptions, LineIndex, SourceType};
+use oxc_diagnostics::{miette::Severity, Error, IndexedSource};
 use oxc_linter::Linter;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
+use serde::Serialize;
 use wasm_bindgen::JsValue;
 
+/// A diagnostic with the positions of its labels, for displaying in an editor
+#[derive(Serialize)]
+pub struct Diagnostic {
+    pub severity: &'static str,
+    pub message: String,
+    pub labels: Vec<Label>,
+    /// The diagnostic rendered with its source code
+    pub rendered: String,
+}
+
+/// Positions are zero-based and in UTF-16 code units, the same as JavaScript strings
+#[derive(Serialize)]
+pub struct Label {
+    pub label: Option<String>,
+    pub start: u32,
+    pub end: u32,
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+}
+
+impl Diagnostic {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(error: &Error, line_index: &LineIndex) -> Self {
+        let severity = match error.severity() {
+            Some(Severity::Advice) => "advice",
+            Some(Severity::Warning) => "warning",
+            Some(Severity::Error) | None => "error",
+        };
+        let labels = error
+            .labels()
+            .into_iter()
+            .flatten()
+            .map(|label| {
+                let (start, end) = (label.offset() as u32, (label.offset() + label.len()) as u32);
+                let (start_position, end_position) =
+                    (line_index.line_column(start), line_index.line_column(end));
+                Label {
+                    label: label.label().map(ToString::to_string),
+                    start: line_index.utf16_offset(start),
+                    end: line_index.utf16_offset(end),
+                    start_line: start_position.line,
+                    start_column: start_position.column_utf16,
+                    end_line: end_position.line,
+                    end_column: end_position.column_utf16,
+                }
+            })
+            .collect();
+        Self { severity, message: error.to_string(), labels, rendered: format!("{error:?}") }
+    }
+}
+
 pub struct Driver {
     allocator: Allocator,
 }
@@ -27,6 +81,7 @@ impl Driver {
     ) -> JsValue {
         let ret = Parser::new(&self.allocator, source_text, source_type)
             .allow_return_outside_function(true)
+            .recover_from_errors(true)
             .parse();
 
         let mut diagnostics = ret.errors;
@@ -37,26 +92,29 @@ impl Driver {
             SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
         diagnostics.extend(semantic_ret.errors);
 
-        let source = Arc::new(NamedSource::new(path, source_text.to_string()));
-
         let messages =
             Linter::from_json_str(eslintrc).with_fix(false).run(&Rc::new(semantic_ret.semantic));
 
-        diagnostics
-            .extend(messages.into_iter().map(|m| m.error.with_source_code(Arc::clone(&source))));
+        diagnostics.extend(messages.into_iter().map(|m| m.error));
 
         if diagnostics.is_empty() {
-            if let Ok(ast) = JsValue::from_serde(program) {
-                return ast;
+            // ESTree with UTF-16 positions, the same as JavaScript parsers
+            let options = ESTreeOptions { range: true, loc: true, utf16: true };
+            let json = program.to_estree_json(source_text, options);
+            if let Ok(ast) = serde_json::from_str::<serde_json::Value>(&json) {
+                return JsValue::from_serde(&ast).unwrap_or(JsValue::NULL);
             }
         }
 
+        let source = Arc::new(IndexedSource::new(path, source_text.to_string()));
         let diagnostics = diagnostics
             .into_iter()
-            .map(|error| format!("{error:?}"))
-            .collect::<Vec<String>>()
-            .join("\n");
+            .map(|error| {
+                let error = error.with_source_code(Arc::clone(&source));
+                Diagnostic::new(&error, source.line_index())
+            })
+            .collect::<Vec<_>>();
 
-        JsValue::from_str(&diagnostics)
+        JsValue::from_serde(&diagnostics).unwrap_or(JsValue::NULL)
     }
 }
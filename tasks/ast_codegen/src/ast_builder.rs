@@ -0,0 +1,47 @@
+//! Generate the constructors of `oxc_ast::AstBuilder`
+
+use std::fmt::Write;
+
+use crate::{
+    schema::{Schema, TypeKind},
+    HEADER,
+};
+
+pub fn generate_ast_builder(schema: &Schema) -> String {
+    let mut constructors = String::new();
+    for ty in &schema.nodes {
+        let TypeKind::Struct(fields) = &ty.kind else { continue };
+        if ty.has_private_fields {
+            continue;
+        }
+        let params: Vec<_> =
+            fields.iter().map(|field| format!("{}: {}", field.name, field.source)).collect();
+        let names: Vec<_> = fields.iter().map(|field| field.name.as_str()).collect();
+        writeln!(
+            constructors,
+            "#[must_use]\n\
+            #[inline]\n\
+            pub fn new_{}(&self, {}) -> {} {{\n\
+            {} {{ {} }}\n}}\n",
+            ty.snake_name(),
+            params.join(", "),
+            ty.ty(),
+            ty.name,
+            names.join(", "),
+        )
+        .unwrap();
+    }
+
+    format!(
+        "{HEADER}\
+        #![allow(clippy::unused_self, clippy::too_many_arguments, clippy::fn_params_excessive_bools)]\n\n\
+        use num_bigint::BigUint;\n\
+        use ordered_float::NotNan;\n\
+        use oxc_allocator::{{Box, Vec}};\n\n\
+        #[allow(clippy::wildcard_imports)]\n\
+        use crate::{{ast::*, Atom, SourceType, Span}};\n\n\
+        use super::AstBuilder;\n\n\
+        /// A constructor for every node of the AST, `new_*` takes all fields of the node.\n\
+        impl<'a> AstBuilder<'a> {{\n{constructors}}}\n"
+    )
+}
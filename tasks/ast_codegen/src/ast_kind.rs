@@ -0,0 +1,110 @@
+//! Generate `oxc_ast::AstKind`
+
+use std::fmt::Write;
+
+use crate::{
+    schema::{FieldType, Schema, TypeDef, TypeKind},
+    HEADER,
+};
+
+/// Enums with an `AstKind`, the other enums are only matched on to visit their variants
+const ENUM_KINDS: &[&str] = &[
+    "ForStatementInit",
+    "MemberExpression",
+    "PropertyKey",
+    "PropertyValue",
+    "Argument",
+    "AssignmentTarget",
+    "SimpleAssignmentTarget",
+    "JSXElementName",
+];
+
+/// Kinds for a field of a struct, `(struct, field, kind)`,
+/// to tell the field apart from other nodes of the same type,
+/// e.g. the `finally` block of a `try` statement is a `FinallyClause` around a `BlockStatement`.
+pub const FIELD_KINDS: &[(&str, &str, &str)] =
+    &[("TryStatement", "finalizer", "FinallyClause"), ("Class", "super_class", "ClassHeritage")];
+
+/// Whether nodes of `ty` are visited with an `AstKind`, the enums in [`ENUM_KINDS`] and all structs with a span.
+/// Structs without a span, e.g. `ImportDeclaration`, are part of the node which contains them.
+pub fn has_kind(ty: &TypeDef) -> bool {
+    match ty.kind {
+        TypeKind::Struct(_) => ty.has_span,
+        TypeKind::Enum(_) => ENUM_KINDS.contains(&ty.name.as_str()),
+    }
+}
+
+/// The kind of `field` of `ty` in [`FIELD_KINDS`], with the type it contains
+pub fn field_kind<'s>(
+    schema: &'s Schema,
+    ty: &TypeDef,
+    field: &str,
+) -> Option<(&'s str, &'s TypeDef)> {
+    FIELD_KINDS.iter().find(|(name, f, _)| *name == ty.name && *f == field).map(|(_, _, kind)| {
+        let TypeKind::Struct(fields) = &ty.kind else { unreachable!() };
+        let field = fields.iter().find(|f| f.name == field).unwrap();
+        (*kind, schema.get(field.ty.name().unwrap()).unwrap())
+    })
+}
+
+/// `x.span` for structs with a `span` field, `x.span()` for everything else
+fn span(ty: &TypeDef) -> &'static str {
+    match &ty.kind {
+        TypeKind::Struct(fields) if fields.iter().any(|field| field.name == "span") => "x.span",
+        _ => "x.span()",
+    }
+}
+
+pub fn generate_ast_kind(schema: &Schema) -> String {
+    let mut variants = String::new();
+    let mut spans = String::new();
+    let mut types = vec![];
+    let mut jsx = vec![];
+    for ty in schema.nodes.iter().filter(|ty| has_kind(ty)) {
+        writeln!(variants, "{}(&'a {}),", ty.name, ty.ty()).unwrap();
+        writeln!(spans, "Self::{}(x) => {},", ty.name, span(ty)).unwrap();
+        if ty.name.starts_with("JSX") {
+            jsx.push(format!("Self::{}(_)", ty.name));
+        }
+        for (name, field, kind) in FIELD_KINDS.iter().filter(|(name, ..)| *name == ty.name) {
+            let (_, inner) = field_kind(schema, ty, field)
+                .unwrap_or_else(|| panic!("unknown field kind {name}::{field}"));
+            writeln!(variants, "{kind}(&'a {}),", inner.ty()).unwrap();
+            writeln!(spans, "Self::{kind}(x) => {},", span(inner)).unwrap();
+        }
+    }
+
+    // Types are the variants of `TSType`
+    let ts_type = schema.get("TSType").unwrap();
+    let TypeKind::Enum(ts_types) = &ts_type.kind else { unreachable!() };
+    for variant in ts_types {
+        if let Some(name) = variant.fields.first().and_then(FieldType::name) {
+            types.push(format!("Self::{name}(_)"));
+        }
+    }
+
+    format!(
+        "{HEADER}\
+        #[allow(clippy::wildcard_imports)]\n\
+        use crate::{{ast::*, GetSpan, Span}};\n\n\
+        /// Untyped AST Node Kind\n\
+        #[derive(Debug, Clone, Copy, PartialEq)]\n\
+        pub enum AstKind<'a> {{\n\
+        Root,\n\n{variants}}}\n\n\
+        impl<'a> AstKind<'a> {{\n\
+        /// Whether this is a type, a variant of [`TSType`]\n\
+        #[must_use]\n\
+        pub fn is_type(self) -> bool {{\n\
+        matches!(self, {})\n}}\n\n\
+        #[must_use]\n\
+        pub fn is_jsx(self) -> bool {{\n\
+        matches!(self, {})\n}}\n}}\n\n\
+        impl<'a> GetSpan for AstKind<'a> {{\n\
+        #[allow(clippy::match_same_arms, clippy::too_many_lines)]\n\
+        fn span(&self) -> Span {{\n\
+        match self {{\n\
+        Self::Root => Span::default(),\n{spans}}}\n}}\n}}\n",
+        types.join(" | "),
+        jsx.join(" | "),
+    )
+}
@@ -0,0 +1,47 @@
+//! Generate code for `oxc_ast` from the AST definitions in `crates/oxc_ast/src/ast`
+//!
+//! Run with `cargo run -p ast_codegen` after changing the AST.
+
+mod ast_builder;
+mod ast_kind;
+mod schema;
+mod shift_span;
+mod traverse;
+mod visit;
+
+use std::{fs, path::Path, process::Command};
+
+use schema::Schema;
+
+const HEADER: &str = "// Auto-generated by `cargo run -p ast_codegen`, do not edit.\n\n";
+
+fn write(path: &Path, source: &str) {
+    fs::write(path, source)
+        .unwrap_or_else(|err| panic!("failed to write {}: {err}", path.display()));
+    let status = Command::new("rustfmt")
+        .arg("--edition=2021")
+        .arg(path)
+        .status()
+        .expect("failed to run rustfmt");
+    assert!(status.success(), "failed to format {}", path.display());
+    println!("wrote {}", path.display());
+}
+
+fn main() {
+    let oxc_ast = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../crates/oxc_ast/src");
+    let schema = Schema::load(&oxc_ast.join("ast"));
+    let regexp = Schema::load_regexp(&oxc_ast.join("ast"));
+
+    write(&oxc_ast.join("ast_kind/kind.rs"), &ast_kind::generate_ast_kind(&schema));
+    write(&oxc_ast.join("visit.rs"), &visit::generate_visit(&schema));
+    write(
+        &oxc_ast.join("ast_builder/constructors.rs"),
+        &ast_builder::generate_ast_builder(&schema),
+    );
+    write(&oxc_ast.join("shift/nodes.rs"), &shift_span::generate_shift_span(&schema, &regexp));
+
+    let traverse = oxc_ast.join("traverse");
+    write(&traverse.join("ancestor.rs"), &traverse::generate_ancestor(&schema));
+    write(&traverse.join("hooks.rs"), &traverse::generate_hooks(&schema));
+    write(&traverse.join("walk.rs"), &traverse::generate_walk(&schema));
+}
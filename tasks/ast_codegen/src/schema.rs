@@ -0,0 +1,276 @@
+//! The AST schema, read from the type definitions in `oxc_ast::ast`
+
+use std::{collections::HashSet, fs, path::Path};
+
+use convert_case::{Case, Casing};
+use quote::ToTokens;
+use syn::{Fields, GenericArgument, Item, PathArguments, Type, TypePath, Visibility};
+
+/// Files in `crates/oxc_ast/src/ast` with node definitions, `regexp.rs` is a separate AST
+const AST_FILES: &[&str] = &["js.rs", "literal.rs", "jsx.rs", "ts.rs", "flow.rs", "jsdoc.rs"];
+
+/// File in `crates/oxc_ast/src` with the implementations of `GetSpan` for types without a `span` field
+const SPAN_FILE: &str = "../span.rs";
+
+/// The root of the AST, types which are not reachable from it are not nodes
+const ROOT: &str = "Program";
+
+/// File in `crates/oxc_ast/src/ast` with the regular expression AST, and its root
+const REGEXP_FILE: &str = "regexp.rs";
+const REGEXP_ROOT: &str = "Pattern";
+
+/// The type of a field or an enum variant
+#[derive(Debug, Clone)]
+pub enum FieldType {
+    /// A type defined in the AST
+    Named(String),
+    Box(std::boxed::Box<FieldType>),
+    Vec(std::boxed::Box<FieldType>),
+    Option(std::boxed::Box<FieldType>),
+    /// Anything else, e.g. `Atom`, `&'a str` or `bool`
+    Other,
+}
+
+impl FieldType {
+    fn parse(ty: &Type) -> Self {
+        let Type::Path(TypePath { qself: None, path }) = ty else { return Self::Other };
+        let Some(segment) = path.segments.last() else { return Self::Other };
+        // `regexp::Pattern` is in the regular expression AST
+        if path.segments.len() > 1 {
+            return Self::Other;
+        }
+        let name = segment.ident.to_string();
+        let inner = match &segment.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(Self::parse(ty)),
+                _ => None,
+            }),
+            _ => None,
+        };
+        match (name.as_str(), inner) {
+            ("Box", Some(inner)) => Self::Box(inner.into()),
+            ("Vec", Some(inner)) => Self::Vec(inner.into()),
+            ("Option", Some(inner)) => Self::Option(inner.into()),
+            _ => Self::Named(name),
+        }
+    }
+
+    /// The AST type inside of `Box`, `Vec` and `Option`
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Named(name) => Some(name),
+            Self::Box(inner) | Self::Vec(inner) | Self::Option(inner) => inner.name(),
+            Self::Other => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Field {
+    pub name: String,
+    pub ty: FieldType,
+    /// The type as written in the definition, e.g. `Vec<'a, Argument<'a>>`
+    pub source: String,
+}
+
+#[derive(Debug)]
+pub struct Variant {
+    pub name: String,
+    /// Types of the unnamed fields, empty for unit variants
+    pub fields: Vec<FieldType>,
+}
+
+#[derive(Debug)]
+pub enum TypeKind {
+    Struct(Vec<Field>),
+    Enum(Vec<Variant>),
+}
+
+#[derive(Debug)]
+pub struct TypeDef {
+    pub name: String,
+    pub has_lifetime: bool,
+    /// Structs with private fields can only be constructed in their own module
+    pub has_private_fields: bool,
+    /// A `span` field or an implementation of `GetSpan`
+    pub has_span: bool,
+    pub kind: TypeKind,
+}
+
+impl TypeDef {
+    /// e.g. `CallExpression<'a>`
+    pub fn ty(&self) -> String {
+        if self.has_lifetime { format!("{}<'a>", self.name) } else { self.name.clone() }
+    }
+
+    /// Types of the fields of a struct, or of the fields of all variants of an enum
+    pub fn field_types(&self) -> impl Iterator<Item = &FieldType> {
+        let (fields, variants) = match &self.kind {
+            TypeKind::Struct(fields) => (fields.as_slice(), [].as_slice()),
+            TypeKind::Enum(variants) => ([].as_slice(), variants.as_slice()),
+        };
+        fields
+            .iter()
+            .map(|field| &field.ty)
+            .chain(variants.iter().flat_map(|variant| &variant.fields))
+    }
+
+    /// e.g. `call_expression`
+    pub fn snake_name(&self) -> String {
+        snake_case(&self.name)
+    }
+}
+
+/// All nodes of the AST, in the order of their definitions
+#[derive(Debug)]
+pub struct Schema {
+    pub nodes: Vec<TypeDef>,
+}
+
+impl Schema {
+    pub fn load(ast_dir: &Path) -> Self {
+        let mut types: Vec<_> = AST_FILES
+            .iter()
+            .flat_map(|file| parse_file(&ast_dir.join(file)).items)
+            .filter_map(|item| parse_item(&item))
+            .collect();
+
+        for item in &parse_file(&ast_dir.join(SPAN_FILE)).items {
+            let Item::Impl(item) = item else { continue };
+            let (Some((_, trait_, _)), Type::Path(TypePath { path, .. })) =
+                (&item.trait_, &*item.self_ty)
+            else {
+                continue;
+            };
+            if !trait_.is_ident("GetSpan") {
+                continue;
+            }
+            let name = path.segments.last().unwrap().ident.to_string();
+            if let Some(ty) = types.iter_mut().find(|ty| ty.name == name) {
+                ty.has_span = true;
+            }
+        }
+
+        Self::from_types(types, ROOT)
+    }
+
+    /// The regular expression AST in `oxc_ast::ast::regexp`
+    pub fn load_regexp(ast_dir: &Path) -> Self {
+        let types =
+            parse_file(&ast_dir.join(REGEXP_FILE)).items.iter().filter_map(parse_item).collect();
+        Self::from_types(types, REGEXP_ROOT)
+    }
+
+    fn from_types(mut types: Vec<TypeDef>, root: &str) -> Self {
+        // Structs with a span are nodes, and all types which contain nodes
+        let mut nodes: HashSet<String> = types
+            .iter()
+            .filter(|ty| match &ty.kind {
+                TypeKind::Struct(fields) => fields.iter().any(|field| field.name == "span"),
+                TypeKind::Enum(_) => false,
+            })
+            .map(|ty| ty.name.clone())
+            .collect();
+        loop {
+            let contains_nodes: Vec<_> = types
+                .iter()
+                .filter(|ty| !nodes.contains(&ty.name))
+                .filter(|ty| {
+                    ty.field_types()
+                        .any(|field| field.name().map_or(false, |name| nodes.contains(name)))
+                })
+                .map(|ty| ty.name.clone())
+                .collect();
+            if contains_nodes.is_empty() {
+                break;
+            }
+            nodes.extend(contains_nodes);
+        }
+
+        // Only keep the nodes reachable from the root
+        let mut reachable = HashSet::new();
+        let mut stack = vec![root.to_string()];
+        while let Some(name) = stack.pop() {
+            if !nodes.contains(&name) || !reachable.insert(name.clone()) {
+                continue;
+            }
+            let ty = types.iter().find(|ty| ty.name == name).unwrap();
+            stack.extend(ty.field_types().filter_map(FieldType::name).map(String::from));
+        }
+        types.retain(|ty| reachable.contains(&ty.name));
+
+        Self { nodes: types }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TypeDef> {
+        self.nodes.iter().find(|ty| ty.name == name)
+    }
+}
+
+fn parse_file(path: &Path) -> syn::File {
+    let source = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    syn::parse_file(&source)
+        .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display()))
+}
+
+fn parse_item(item: &Item) -> Option<TypeDef> {
+    let mut has_private_fields = false;
+    let mut has_span = false;
+    let (name, generics, kind) = match item {
+        Item::Struct(item) => {
+            let Fields::Named(fields) = &item.fields else { return None };
+            has_private_fields =
+                fields.named.iter().any(|field| !matches!(field.vis, Visibility::Public(_)));
+            has_span = fields
+                .named
+                .iter()
+                .any(|field| field.ident.as_ref().map_or(false, |ident| ident == "span"));
+            let fields = fields
+                .named
+                .iter()
+                // Private fields are not accessible outside of the module
+                .filter(|field| matches!(field.vis, Visibility::Public(_)))
+                .map(|field| Field {
+                    name: field.ident.as_ref().unwrap().to_string(),
+                    ty: FieldType::parse(&field.ty),
+                    source: field.ty.to_token_stream().to_string(),
+                })
+                .collect();
+            (&item.ident, &item.generics, TypeKind::Struct(fields))
+        }
+        Item::Enum(item) => {
+            let variants = item
+                .variants
+                .iter()
+                .map(|variant| Variant {
+                    name: variant.ident.to_string(),
+                    fields: variant
+                        .fields
+                        .iter()
+                        .map(|field| FieldType::parse(&field.ty))
+                        .collect(),
+                })
+                .collect();
+            (&item.ident, &item.generics, TypeKind::Enum(variants))
+        }
+        _ => return None,
+    };
+    Some(TypeDef {
+        name: name.to_string(),
+        has_lifetime: generics.lifetimes().next().is_some(),
+        has_private_fields,
+        has_span,
+        kind,
+    })
+}
+
+/// `TSAsExpression` -> `ts_as_expression`
+pub fn snake_case(name: &str) -> String {
+    name.to_case(Case::Snake)
+}
+
+/// `type_annotation` -> `TypeAnnotation`
+pub fn pascal_case(name: &str) -> String {
+    name.to_case(Case::Pascal)
+}
@@ -0,0 +1,90 @@
+//! Generate the implementations of `oxc_ast::ShiftSpan` for all nodes
+
+use std::fmt::Write;
+
+use crate::{
+    schema::{FieldType, Schema, TypeDef, TypeKind},
+    HEADER,
+};
+
+/// Types which are not nodes but implement `ShiftSpan` in `shift/mod.rs`
+const SHIFTED_TYPES: &[&str] = &["Span", "Modifiers"];
+
+/// The root of the regular expression AST, as written in field types of the JavaScript AST
+const REGEXP_PATTERN: &str = "regexp :: Pattern";
+
+fn is_shifted(schema: &Schema, ty: &FieldType) -> bool {
+    ty.name().map_or(false, |name| schema.get(name).is_some() || SHIFTED_TYPES.contains(&name))
+}
+
+fn shift_struct(schema: &Schema, ty: &TypeDef) -> String {
+    let TypeKind::Struct(fields) = &ty.kind else { unreachable!() };
+    let mut body = String::new();
+    for field in fields {
+        if is_shifted(schema, &field.ty) || field.source.contains(REGEXP_PATTERN) {
+            writeln!(body, "self.{}.shift_span(from, offset);", field.name).unwrap();
+        }
+    }
+    assert!(!body.is_empty(), "{} has no positions", ty.name);
+    body
+}
+
+fn shift_enum(schema: &Schema, ty: &TypeDef) -> String {
+    let TypeKind::Enum(variants) = &ty.kind else { unreachable!() };
+    let mut arms = String::new();
+    for variant in variants {
+        let path = format!("Self::{}", variant.name);
+        match variant.fields.as_slice() {
+            [] => writeln!(arms, "{path} => {{}}").unwrap(),
+            [field] if is_shifted(schema, field) => {
+                writeln!(arms, "{path}(it) => it.shift_span(from, offset),").unwrap();
+            }
+            fields => {
+                assert!(
+                    fields.iter().all(|field| !is_shifted(schema, field)),
+                    "nodes in variants with several fields are not supported: {}::{}",
+                    ty.name,
+                    variant.name
+                );
+                writeln!(arms, "{path}(..) => {{}}").unwrap();
+            }
+        }
+    }
+    format!("match self {{\n{arms}}}\n")
+}
+
+fn generate_impls(schema: &Schema) -> String {
+    let mut impls = String::new();
+    for ty in &schema.nodes {
+        let body = match ty.kind {
+            TypeKind::Struct(_) => shift_struct(schema, ty),
+            TypeKind::Enum(_) => shift_enum(schema, ty),
+        };
+        let generics = if ty.has_lifetime { "<'a>" } else { "" };
+        writeln!(
+            impls,
+            "impl{generics} ShiftSpan for {} {{\n\
+            fn shift_span(&mut self, from: u32, offset: i64) {{\n{body}}}\n}}\n",
+            ty.ty()
+        )
+        .unwrap();
+    }
+    impls
+}
+
+pub fn generate_shift_span(schema: &Schema, regexp: &Schema) -> String {
+    format!(
+        "{HEADER}\
+        #![allow(clippy::match_same_arms)]\n\n\
+        #[allow(clippy::wildcard_imports)]\n\
+        use crate::{{ast::*, ShiftSpan}};\n\n\
+        {}\n\
+        /// Nodes of [`RegExpLiteral::pattern`]\n\
+        mod regexp {{\n\
+        #[allow(clippy::wildcard_imports)]\n\
+        use crate::{{ast::regexp::*, ShiftSpan}};\n\n\
+        {}}}\n",
+        generate_impls(schema),
+        generate_impls(regexp),
+    )
+}
@@ -0,0 +1,226 @@
+//! Generate `oxc_ast::traverse`: the `Traverse` hooks, the walk functions and the ancestors
+
+use std::fmt::Write;
+
+use crate::{
+    schema::{pascal_case, FieldType, Schema, TypeDef, TypeKind},
+    HEADER,
+};
+
+/// A mutable reference to a node, or a place which is borrowed mutably
+enum Expr<'s> {
+    Place(&'s str),
+    Ref(&'s str),
+}
+
+impl<'s> Expr<'s> {
+    fn as_mut(&self) -> String {
+        match self {
+            Self::Place(place) => format!("&mut {place}"),
+            Self::Ref(reference) => (*reference).to_string(),
+        }
+    }
+}
+
+/// The variant of `Ancestor` for `field` of `ty`, e.g. `CallExpressionCallee`
+fn ancestor(ty: &TypeDef, field: &str) -> String {
+    format!("{}{}", ty.name, pascal_case(field))
+}
+
+/// Statements to walk `expr` of type `field_type`, `None` when it does not contain nodes
+fn walk_expr(schema: &Schema, field_type: &FieldType, expr: &Expr) -> Option<String> {
+    match field_type {
+        FieldType::Named(name) => schema
+            .get(name)
+            .map(|node| format!("walk_{}(traverser, {}, ctx);", node.snake_name(), expr.as_mut())),
+        // `&mut Box<T>` is coerced to `&mut T`
+        FieldType::Box(inner) => walk_expr(schema, inner, expr),
+        FieldType::Option(inner) => walk_expr(schema, inner, &Expr::Ref("item"))
+            .map(|body| format!("if let Some(item) = {} {{ {body} }}", expr.as_mut())),
+        FieldType::Vec(inner) => {
+            // Skip the holes of `Vec<Option<T>>`
+            let (inner, flatten) = match &**inner {
+                FieldType::Option(inner) => (&**inner, ".flatten()"),
+                inner => (inner, ""),
+            };
+            walk_expr(schema, inner, &Expr::Ref("item")).map(|body| {
+                let iter = match expr {
+                    Expr::Place(place) => format!("{place}.iter_mut(){flatten}"),
+                    Expr::Ref(reference) => format!("{reference}.iter_mut(){flatten}"),
+                };
+                format!("for item in {iter} {{ {body} }}")
+            })
+        }
+        FieldType::Other => None,
+    }
+}
+
+/// Fields of a struct which contain nodes, with the statements to walk them
+fn walked_fields<'s>(schema: &'s Schema, ty: &'s TypeDef) -> Vec<(&'s str, String)> {
+    let TypeKind::Struct(fields) = &ty.kind else { return vec![] };
+    fields
+        .iter()
+        .filter_map(|field| {
+            let place = format!("node.{}", field.name);
+            walk_expr(schema, &field.ty, &Expr::Place(&place))
+                .map(|walk| (field.name.as_str(), walk))
+        })
+        .collect()
+}
+
+pub fn generate_ancestor(schema: &Schema) -> String {
+    let mut types = String::new();
+    let mut ancestors = String::new();
+    let mut arms = String::new();
+    for ty in &schema.nodes {
+        writeln!(types, "{},", ty.name).unwrap();
+        let fields = walked_fields(schema, ty);
+        if fields.is_empty() {
+            continue;
+        }
+        let variants: Vec<_> = fields.iter().map(|(field, _)| ancestor(ty, field)).collect();
+        for variant in &variants {
+            writeln!(ancestors, "{variant},").unwrap();
+        }
+        let patterns: Vec<_> = variants.iter().map(|variant| format!("Self::{variant}")).collect();
+        writeln!(arms, "{} => Some(AstType::{}),", patterns.join(" | "), ty.name).unwrap();
+    }
+
+    format!(
+        "{HEADER}\
+        /// Type of an AST node\n\
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n\
+        pub enum AstType {{\n{types}}}\n\n\
+        /// An ancestor of the current node during [`traverse_mut`](super::traverse_mut),\n\
+        /// the type of the ancestor and the field which contains the current node,\n\
+        /// e.g. [`Ancestor::CallExpressionCallee`] for `f` in `f()`.\n\
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n\
+        pub enum Ancestor {{\n\
+        /// No ancestor, the parent of `Program`\n\
+        None,\n{ancestors}}}\n\n\
+        impl Ancestor {{\n\
+        /// Type of the ancestor, `None` for [`Ancestor::None`]\n\
+        #[must_use]\n\
+        #[allow(clippy::too_many_lines)]\n\
+        pub fn ty(self) -> Option<AstType> {{\n\
+        match self {{\n\
+        Self::None => None,\n{arms}}}\n}}\n}}\n"
+    )
+}
+
+pub fn generate_hooks(schema: &Schema) -> String {
+    let mut hooks = String::new();
+    for ty in &schema.nodes {
+        let (name, node) = (ty.snake_name(), ty.ty());
+        writeln!(
+            hooks,
+            "fn enter_{name}(&mut self, _node: &mut {node}, _ctx: &mut TraverseCtx<'a>) {{}}\n\
+            fn exit_{name}(&mut self, _node: &mut {node}, _ctx: &mut TraverseCtx<'a>) {{}}\n"
+        )
+        .unwrap();
+    }
+
+    format!(
+        "{HEADER}\
+        #[allow(clippy::wildcard_imports)]\n\
+        use crate::ast::*;\n\n\
+        use super::TraverseCtx;\n\n\
+        /// Hooks called by [`traverse_mut`](super::traverse_mut) for every node of the AST.\n\
+        ///\n\
+        /// `enter_*` is called before the children of a node are walked and `exit_*` after.\n\
+        /// A hook can replace the node it is given, in `exit_*` this is always safe\n\
+        /// because the children of the node have been walked already,\n\
+        /// a node replaced in `enter_*` is walked instead of the original node.\n\
+        /// Use [`TraverseCtx`] to look at the ancestors of the node and to build new nodes.\n\
+        pub trait Traverse<'a> {{\n{hooks}}}\n"
+    )
+}
+
+pub fn generate_walk(schema: &Schema) -> String {
+    let mut walks = String::new();
+    for ty in &schema.nodes {
+        let (name, node) = (ty.snake_name(), ty.ty());
+        let body = match &ty.kind {
+            TypeKind::Struct(_) => {
+                let fields = walked_fields(schema, ty);
+                let mut body = String::new();
+                for (i, (field, walk)) in fields.iter().enumerate() {
+                    let ancestor = ancestor(ty, field);
+                    if i == 0 {
+                        writeln!(body, "ctx.push_stack(Ancestor::{ancestor});").unwrap();
+                    } else {
+                        writeln!(body, "ctx.retag_stack(Ancestor::{ancestor});").unwrap();
+                    }
+                    writeln!(body, "{walk}").unwrap();
+                }
+                if !fields.is_empty() {
+                    writeln!(body, "ctx.pop_stack();").unwrap();
+                }
+                body
+            }
+            TypeKind::Enum(variants) => {
+                // Variants with the same walk of the same type share an arm, same arms are linted
+                let mut arms: Vec<(Vec<String>, String, String)> = vec![];
+                let mut no_op = vec![];
+                for variant in variants {
+                    let path = format!("{}::{}", ty.name, variant.name);
+                    let walk = match variant.fields.as_slice() {
+                        [field] => walk_expr(schema, field, &Expr::Ref("node"))
+                            .map(|walk| (walk, format!("{field:?}"))),
+                        fields => {
+                            assert!(
+                                fields.iter().all(|field| walk_expr(
+                                    schema,
+                                    field,
+                                    &Expr::Ref("node")
+                                )
+                                .is_none()),
+                                "nodes in variants with several fields are not supported: {path}"
+                            );
+                            None
+                        }
+                    };
+                    match walk {
+                        Some((walk, field_type)) => {
+                            let pattern = format!("{path}(node)");
+                            match arms.iter_mut().find(|(_, w, t)| *w == walk && *t == field_type) {
+                                Some((patterns, _, _)) => patterns.push(pattern),
+                                None => arms.push((vec![pattern], walk, field_type)),
+                            }
+                        }
+                        None if variant.fields.is_empty() => no_op.push(path),
+                        None => no_op.push(format!("{path}(..)")),
+                    }
+                }
+                let mut body = String::from("match node {\n");
+                for (patterns, walk, _) in &arms {
+                    writeln!(body, "{} => {{ {walk} }}", patterns.join(" | ")).unwrap();
+                }
+                if !no_op.is_empty() {
+                    writeln!(body, "{} => {{}}", no_op.join(" | ")).unwrap();
+                }
+                body.push_str("}\n");
+                body
+            }
+        };
+        writeln!(
+            walks,
+            "pub(super) fn walk_{name}<'a, Tr: Traverse<'a>>(\
+                traverser: &mut Tr, node: &mut {node}, ctx: &mut TraverseCtx<'a>) {{\n\
+            traverser.enter_{name}(node, ctx);\n\
+            {body}\
+            traverser.exit_{name}(node, ctx);\n\
+            }}\n"
+        )
+        .unwrap();
+    }
+
+    format!(
+        "{HEADER}\
+        #![allow(clippy::too_many_lines)]\n\n\
+        #[allow(clippy::wildcard_imports)]\n\
+        use crate::ast::*;\n\n\
+        use super::{{Ancestor, Traverse, TraverseCtx}};\n\n\
+        {walks}"
+    )
+}
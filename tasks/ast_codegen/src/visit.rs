@@ -0,0 +1,216 @@
+//! Generate `oxc_ast::visit::Visit`
+
+use std::fmt::Write;
+
+use crate::{
+    ast_kind::{field_kind, has_kind, FIELD_KINDS},
+    schema::{snake_case, FieldType, Schema, TypeDef, TypeKind},
+    HEADER,
+};
+
+/// Enums visited through `visit_*_match`, so visitors can override `visit_*` and still visit the variants
+const MATCH: &[&str] = &["Statement", "Expression"];
+
+/// Fields which are visited before the node is entered, `(struct, field, reason)`
+const BEFORE_ENTER: &[(&str, &str, &str)] = &[(
+    "Class",
+    "decorators",
+    "Class level decorators are transpiled as functions outside of the class taking the class\n\
+    itself as argument. They should be visited before class is entered. E.g., they inherit\n\
+    strict mode from the enclosing scope rather than from class.",
+)];
+
+/// Fields of this type are visited right after the node is entered
+const VISIT_FIRST: &str = "TSTypeParameterDeclaration";
+
+/// Statement to visit `expr` of type `field_type` with `method`, `None` when it does not contain nodes.
+/// `expr` is a place when `is_place`, otherwise a reference.
+fn visit_expr(
+    schema: &Schema,
+    field_type: &FieldType,
+    expr: &str,
+    is_place: bool,
+    method: Option<&str>,
+) -> Option<String> {
+    let reference = if is_place { format!("&{expr}") } else { expr.to_string() };
+    match field_type {
+        FieldType::Named(name) => schema.get(name).map(|node| {
+            let method = method.map_or_else(|| node.snake_name(), String::from);
+            format!("self.visit_{method}({reference});")
+        }),
+        // `&Box<T>` is coerced to `&T`
+        FieldType::Box(inner) => visit_expr(schema, inner, expr, is_place, method),
+        FieldType::Option(inner) => visit_expr(schema, inner, "item", false, method)
+            .map(|body| format!("if let Some(item) = {reference} {{ {body} }}")),
+        FieldType::Vec(inner) => match &**inner {
+            FieldType::Named(name) if name == "Statement" && method.is_none() => {
+                Some(format!("self.visit_statements({reference});"))
+            }
+            // Skip the holes of `Vec<Option<T>>`
+            FieldType::Option(inner) => visit_expr(schema, inner, "item", false, method)
+                .map(|body| format!("for item in {expr}.iter().flatten() {{ {body} }}")),
+            inner => visit_expr(schema, inner, "item", false, method)
+                .map(|body| format!("for item in {reference} {{ {body} }}")),
+        },
+        FieldType::Other => None,
+    }
+}
+
+fn visit_struct(schema: &Schema, ty: &TypeDef) -> String {
+    let TypeKind::Struct(fields) = &ty.kind else { unreachable!() };
+    let mut before = String::new();
+    let mut first = String::new();
+    let mut body = String::new();
+    for field in fields {
+        let place = format!("node.{}", field.name);
+        let method = field_kind(schema, ty, &field.name).map(|(kind, _)| snake_case(kind));
+        let Some(visit) = visit_expr(schema, &field.ty, &place, true, method.as_deref()) else {
+            continue;
+        };
+        match BEFORE_ENTER.iter().find(|(name, f, _)| *name == ty.name && *f == field.name) {
+            Some((_, _, reason)) => {
+                for line in reason.lines() {
+                    writeln!(before, "// {line}").unwrap();
+                }
+                writeln!(before, "{visit}").unwrap();
+            }
+            None if field.ty.name() == Some(VISIT_FIRST) => {
+                // Type parameters are in scope in the other fields, e.g. the heritage and the body of a class
+                writeln!(first, "{visit}").unwrap();
+            }
+            None => writeln!(body, "{visit}").unwrap(),
+        }
+    }
+    let body = format!("{first}{body}");
+    if !has_kind(ty) {
+        return format!("{before}{body}");
+    }
+    format!(
+        "{before}let kind = AstKind::{}(node);\n\
+        self.enter_node(kind);\n\
+        {body}\
+        self.leave_node(kind);\n",
+        ty.name
+    )
+}
+
+fn visit_enum(schema: &Schema, ty: &TypeDef) -> String {
+    let TypeKind::Enum(variants) = &ty.kind else { unreachable!() };
+    // Variants with the same visit of the same type share an arm, same arms are linted
+    let mut arms: Vec<(Vec<String>, String, String)> = vec![];
+    let mut no_op = vec![];
+    for variant in variants {
+        let path = format!("{}::{}", ty.name, variant.name);
+        let visit = match variant.fields.as_slice() {
+            [field] => visit_expr(schema, field, "it", false, None)
+                .map(|visit| (visit, format!("{field:?}"))),
+            fields => {
+                assert!(
+                    fields
+                        .iter()
+                        .all(|field| visit_expr(schema, field, "it", false, None).is_none()),
+                    "nodes in variants with several fields are not supported: {path}"
+                );
+                None
+            }
+        };
+        match visit {
+            Some((visit, field_type)) => {
+                let pattern = format!("{path}(it)");
+                match arms.iter_mut().find(|(_, v, t)| *v == visit && *t == field_type) {
+                    Some((patterns, _, _)) => patterns.push(pattern),
+                    None => arms.push((vec![pattern], visit, field_type)),
+                }
+            }
+            None if variant.fields.is_empty() => no_op.push(path),
+            None => no_op.push(format!("{path}(_)")),
+        }
+    }
+    let mut body = String::from("match node {\n");
+    for (patterns, visit, _) in &arms {
+        writeln!(body, "{} => {{ {visit} }}", patterns.join(" | ")).unwrap();
+    }
+    if !no_op.is_empty() {
+        writeln!(body, "{} => {{}}", no_op.join(" | ")).unwrap();
+    }
+    body.push_str("}\n");
+    if has_kind(ty) {
+        body = format!(
+            "let kind = AstKind::{}(node);\n\
+            self.enter_node(kind);\n\
+            {body}\
+            self.leave_node(kind);\n",
+            ty.name
+        );
+    }
+    body
+}
+
+pub fn generate_visit(schema: &Schema) -> String {
+    let mut methods = String::new();
+    for ty in &schema.nodes {
+        let (name, node) = (ty.snake_name(), ty.ty());
+        let body = match ty.kind {
+            TypeKind::Struct(_) => visit_struct(schema, ty),
+            TypeKind::Enum(_) => visit_enum(schema, ty),
+        };
+        if ty.name == "Statement" {
+            writeln!(
+                methods,
+                "fn visit_statements(&mut self, node: &'a Vec<'a, Statement<'a>>) {{\n\
+                for item in node {{ self.visit_statement(item); }}\n}}\n"
+            )
+            .unwrap();
+        }
+        if MATCH.contains(&ty.name.as_str()) {
+            writeln!(
+                methods,
+                "fn visit_{name}(&mut self, node: &'a {node}) {{\n\
+                self.visit_{name}_match(node);\n}}\n\n\
+                fn visit_{name}_match(&mut self, node: &'a {node}) {{\n{body}}}\n"
+            )
+            .unwrap();
+        } else {
+            writeln!(methods, "fn visit_{name}(&mut self, node: &'a {node}) {{\n{body}}}\n")
+                .unwrap();
+        }
+
+        for (_, field, kind) in FIELD_KINDS.iter().filter(|(name, ..)| *name == ty.name) {
+            let (_, inner) = field_kind(schema, ty, field).unwrap();
+            writeln!(
+                methods,
+                "fn visit_{}(&mut self, node: &'a {}) {{\n\
+                let kind = AstKind::{kind}(node);\n\
+                self.enter_node(kind);\n\
+                self.visit_{}(node);\n\
+                self.leave_node(kind);\n}}\n",
+                snake_case(kind),
+                inner.ty(),
+                inner.snake_name(),
+            )
+            .unwrap();
+        }
+    }
+
+    format!(
+        "{HEADER}\
+        //! AST Visitor Pattern\n\
+        //!\n\
+        //! See:\n\
+        //! * [visitor pattern](https://rust-unofficial.github.io/patterns/patterns/behavioural/visitor.html)\n\
+        //! * [rustc visitor](https://github.com/rust-lang/rust/blob/master/compiler/rustc_ast/src/visit.rs)\n\n\
+        #![allow(clippy::too_many_lines)]\n\n\
+        use oxc_allocator::Vec;\n\n\
+        #[allow(clippy::wildcard_imports)]\n\
+        use crate::{{ast::*, ast_kind::AstKind}};\n\n\
+        /// Visits every node of the AST in the order of the fields of the nodes.\n\
+        ///\n\
+        /// `visit_*` is called for every node, the default implementations visit the children of the node.\n\
+        /// [`enter_node`](Visit::enter_node) and [`leave_node`](Visit::leave_node) are called\n\
+        /// around the children of every node with an [`AstKind`].\n\
+        pub trait Visit<'a>: Sized {{\n\
+        fn enter_node(&mut self, _kind: AstKind<'a>) {{}}\n\
+        fn leave_node(&mut self, _kind: AstKind<'a>) {{}}\n\n\
+        {methods}}}\n"
+    )
+}
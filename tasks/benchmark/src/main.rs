@@ -15,7 +15,7 @@ use criterion::{BenchmarkId, Criterion, Throughput};
 use oxc_allocator::Allocator;
 use oxc_ast::SourceType;
 use oxc_benchmark::Code;
-use oxc_parser::Parser;
+use oxc_parser::{ModuleLexer, Parser};
 use oxc_semantic::SemanticBuilder;
 use pico_args::Arguments;
 
@@ -53,6 +53,7 @@ pub fn main() -> Result<(), String> {
     }
 
     bench_parser(&mut criterion, &codes);
+    bench_module_lexer(&mut criterion, &codes);
     bench_semantic(&mut criterion, &codes);
     drop(criterion);
 
@@ -80,6 +81,27 @@ fn bench_parser(criterion: &mut Criterion, codes: &[Code]) {
     group.finish();
 }
 
+fn bench_module_lexer(criterion: &mut Criterion, codes: &[Code]) {
+    let mut group = criterion.benchmark_group("module_lexer");
+    for code in codes {
+        group.throughput(Throughput::Bytes(code.source_text.len() as u64));
+        group.measurement_time(code.measurement_time);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&code.file_name),
+            &code.source_text,
+            |b, source_text| {
+                let allocator = Allocator::default();
+                b.iter(|| {
+                    let _drop =
+                        ModuleLexer::new(&allocator, black_box(source_text), SourceType::default())
+                            .scan();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
 fn bench_semantic(criterion: &mut Criterion, codes: &[Code]) {
     let mut group = criterion.benchmark_group("semantic");
     for code in codes {
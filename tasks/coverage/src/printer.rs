@@ -58,9 +58,15 @@ impl PrinterTest262Case {
         let is_module = self.base.meta().flags.contains(&TestFlag::Module);
         let source_type = *SourceType::default().with_module(is_module);
         let program1 = Parser::new(&allocator, &source_text, source_type).parse().program;
-        let source_text1 = Printer::new(source_text.len(), options).build(&program1);
+        let source_text1 = match Printer::new(source_text.len(), options).build(&program1) {
+            Ok(source_text) => source_text,
+            Err(error) => return TestResult::ParseError(error.to_string(), false),
+        };
         let program2 = Parser::new(&allocator, &source_text1, source_type).parse().program;
-        let source_text2 = Printer::new(source_text1.len(), options).build(&program2);
+        let source_text2 = match Printer::new(source_text1.len(), options).build(&program2) {
+            Ok(source_text) => source_text,
+            Err(error) => return TestResult::ParseError(error.to_string(), false),
+        };
         if source_text1 == source_text2 {
             TestResult::Passed
         } else {
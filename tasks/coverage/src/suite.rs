@@ -5,6 +5,7 @@ use std::{
     path::{Path, PathBuf},
     rc::Rc,
     result::Result,
+    sync::Arc,
 };
 
 use console::Style;
@@ -12,7 +13,10 @@ use encoding_rs::UTF_16LE;
 use encoding_rs_io::DecodeReaderBytesBuilder;
 use oxc_allocator::Allocator;
 use oxc_ast::SourceType;
-use oxc_diagnostics::miette::{GraphicalReportHandler, GraphicalTheme, NamedSource};
+use oxc_diagnostics::{
+    miette::{GraphicalReportHandler, GraphicalTheme},
+    IndexedSource,
+};
 use oxc_linter::Linter;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -290,11 +294,12 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
         } else {
             let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
             let mut output = String::new();
+            let source = Arc::new(IndexedSource::new(
+                self.path().to_string_lossy(),
+                source_text.to_string(),
+            ));
             for error in errors {
-                let error = error.with_source_code(NamedSource::new(
-                    self.path().to_string_lossy(),
-                    source_text.to_string(),
-                ));
+                let error = error.with_source_code(Arc::clone(&source));
                 handler.render_report(&mut output, error.as_ref()).unwrap();
             }
             Err(output)
@@ -92,9 +92,7 @@ impl<T: Case> Suite<T> for Test262Suite<T> {
     fn skip_test_path(&self, path: &Path) -> bool {
         let path = path.to_string_lossy();
         // ignore fixtures
-        path.contains("_FIXTURE") ||
-        // ignore regexp as we don't have a regexp parser for now
-        path.contains("literals/regexp")
+        path.contains("_FIXTURE")
     }
 
     fn save_test_cases(&mut self, cases: Vec<T>) {
@@ -162,7 +160,6 @@ impl Case for Test262Case {
 
     fn skip_test_case(&self) -> bool {
         [
-            "regexp-unicode-property-escapes",
             // Stage 3 `https://github.com/tc39/proposal-json-modules`
             "json-modules",
             // Stage 3 `https://github.com/tc39/proposal-decorators`